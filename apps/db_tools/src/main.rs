@@ -49,13 +49,9 @@ fn main() -> Result<()> {
     let db = match &db_key {
         Some(key) => {
             tracing::info!("Db Key: \"{}...\"", &key[..4]);
-            let key_bytes = hex::decode(key)?;
-            if key_bytes.len() != 32 {
-                bail!("Encryption key must be exactly 32 bytes (64 hex characters)");
-            }
             NativeDb::builder()
                 .persistent(args.db.clone())
-                .key(EncryptionKey::try_from(key_bytes).unwrap())
+                .key(parse_key(key)?)
                 .build()
         }
         None => {
@@ -113,6 +109,24 @@ fn main() -> Result<()> {
             let group_ids: Vec<_> = arg_group_ids.iter().map(GroupId::as_slice).collect();
             tasks::disable_groups(&manager.store.db(), &group_ids)?;
         }
+        Task::DbRekey => {
+            let new_key = args.target("This will be the new hex encoded database encryption key.");
+            confirm_destructive()?;
+            manager.store.rekey(parse_key(new_key)?)?;
+            info!("Database re-encrypted with the new key.");
+        }
+        Task::DbEncrypt => {
+            let key = args.target("This will be the hex encoded database encryption key.");
+            confirm_destructive()?;
+            manager.store.encrypt_in_place(parse_key(key)?)?;
+            info!("Database encrypted.");
+        }
+        Task::DbStorageReport => {
+            tasks::storage_report(&manager.store.db())?;
+        }
+        Task::DbPrune => {
+            tasks::prune(&manager.store.db(), args.retain_days)?;
+        }
         Task::DbListMigrations => {
             let conn = manager.store.conn();
             let db = DbConnection::new(&conn);
@@ -141,6 +155,14 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+fn parse_key(key: &str) -> Result<EncryptionKey> {
+    let key_bytes = hex::decode(key)?;
+    if key_bytes.len() != 32 {
+        bail!("Encryption key must be exactly 32 bytes (64 hex characters)");
+    }
+    Ok(EncryptionKey::try_from(key_bytes)?)
+}
+
 fn confirm_destructive() -> Result<()> {
     print!(
         "Please confirm that you have backed up your database. This action can result in loss of data. (y/n): "
@@ -244,4 +266,15 @@ enum Task {
     EnableGroup,
     /// List all available migrations and their status.
     DbListMigrations,
+    /// Re-encrypt the database with a new key.
+    /// Requires the new hex-encoded key as --target param.
+    DbRekey,
+    /// Encrypt an unencrypted database.
+    /// Requires the hex-encoded key as --target param.
+    DbEncrypt,
+    /// Report the rows and bytes used by each table and group.
+    DbStorageReport,
+    /// Remove processed commit logs and icebox entries, and mark stale key packages for deletion.
+    /// Key packages older than --retain-days (default 90) are considered stale.
+    DbPrune,
 }
@@ -361,7 +361,7 @@ where
             get_consent_record(group_id.clone(), ConsentType::ConversationId)
         )?;
         bench!(self, consent_records())?;
-        bench!(self, consent_records_paged(100, 0))?;
+        bench!(self, consent_records_paged(100, 0, &Default::default()))?;
         bench!(self, insert_newer_consent_record(new_consent.clone()))?;
         bench!(
             self,
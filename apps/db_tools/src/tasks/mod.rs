@@ -3,9 +3,11 @@ mod db_bench;
 mod group_management;
 mod migrations;
 mod snapshot_to_persistent;
+mod storage;
 
 pub use clear_messages::*;
 pub use db_bench::*;
 pub use group_management::*;
 pub use migrations::*;
 pub use snapshot_to_persistent::*;
+pub use storage::*;
@@ -0,0 +1,72 @@
+use crate::confirm_destructive;
+use anyhow::Result;
+use tracing::info;
+use xmtp_common::NS_IN_DAY;
+use xmtp_db::{
+    ConnectionExt, DbConnection,
+    storage::{QueryStorage, RetentionPolicy},
+};
+
+/// Number of groups listed in the storage report
+const REPORTED_GROUPS: usize = 20;
+
+pub fn storage_report<C>(conn: &DbConnection<C>) -> Result<()>
+where
+    C: ConnectionExt,
+{
+    let report = conn.storage_report()?;
+
+    println!(
+        "Database: {} bytes ({} bytes free)",
+        report.database_bytes, report.free_bytes
+    );
+    println!("Tables:");
+    for table in &report.tables {
+        println!(
+            "  {:<40} {:>10} rows {:>14} bytes",
+            table.table, table.rows, table.bytes
+        );
+    }
+
+    println!(
+        "Largest groups ({} of {}):",
+        report.groups.len().min(REPORTED_GROUPS),
+        report.groups.len()
+    );
+    for group in report.groups.iter().take(REPORTED_GROUPS) {
+        println!(
+            "  {} {:>10} rows {:>14} bytes",
+            hex::encode(&group.group_id),
+            group.rows,
+            group.bytes
+        );
+        for table in &group.tables {
+            println!(
+                "    {:<38} {:>10} rows {:>14} bytes",
+                table.table, table.rows, table.bytes
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub fn prune<C>(conn: &DbConnection<C>, retain_days: Option<u32>) -> Result<()>
+where
+    C: ConnectionExt,
+{
+    let mut policy = RetentionPolicy::default();
+    if let Some(days) = retain_days {
+        policy.key_package_retention_ns = days as i64 * NS_IN_DAY;
+    }
+
+    confirm_destructive()?;
+    let report = conn.prune(policy)?;
+    info!(
+        "Pruned {} local commit log rows, {} remote commit log rows and {} icebox entries. \
+         Marked {} key packages for deletion.",
+        report.local_commit_log, report.remote_commit_log, report.icebox, report.key_packages
+    );
+
+    Ok(())
+}
@@ -24,6 +24,8 @@ pub(crate) struct Args {
     pub(crate) health_check_port: u32,
 
     // A path to a json file in the same format as chain_urls_default.json in the codebase.
+    // A chain may instead map to `{ "urls": [...], "quorum": M }` to require M of the urls to agree.
+    // M must be a majority of the urls.
     #[arg(long)]
     pub(crate) chain_urls: Option<String>,
 
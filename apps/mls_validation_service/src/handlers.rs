@@ -30,6 +30,7 @@ use xmtp_proto::xmtp::{
         ValidateGroupMessagesResponse,
         ValidateInboxIdKeyPackagesResponse,
         ValidateKeyPackagesRequest, // VerifySmartContractWalletSignaturesRequest, VerifySmartContractWalletSignaturesResponse,
+        get_association_state_request::PointInTime,
         validate_group_messages_response::ValidationResponse as ValidateGroupMessageValidationResponse,
         validate_inbox_id_key_packages_response::Response as ValidateInboxIdKeyPackageResponse,
         validation_api_server::ValidationApi,
@@ -139,9 +140,10 @@ impl ValidationApi for ValidationService {
         let GetAssociationStateRequest {
             old_updates,
             new_updates,
+            point_in_time,
         } = request.into_inner();
 
-        get_association_state(old_updates, new_updates, &self.scw_verifier)
+        get_association_state(old_updates, new_updates, point_in_time, &self.scw_verifier)
             .await
             .map(Response::new)
             .map_err(Into::into)
@@ -267,13 +269,38 @@ async fn verify_smart_contract_wallet_signatures(
     }))
 }
 
+/// Truncates `old_updates` followed by `new_updates` to the prefix that falls at or before `point_in_time`
+fn truncate_to_point_in_time(
+    old_updates: &mut Vec<UnverifiedIdentityUpdate>,
+    new_updates: &mut Vec<UnverifiedIdentityUpdate>,
+    point_in_time: Option<PointInTime>,
+) {
+    let count = match point_in_time {
+        None => return,
+        Some(PointInTime::UpdateCount(count)) => usize::try_from(count).unwrap_or(usize::MAX),
+        Some(PointInTime::TimestampNs(timestamp_ns)) => old_updates
+            .iter()
+            .chain(new_updates.iter())
+            .take_while(|update| update.client_timestamp_ns <= timestamp_ns)
+            .count(),
+    };
+    new_updates.truncate(count.saturating_sub(old_updates.len()));
+    old_updates.truncate(count);
+}
+
 async fn get_association_state(
     old_updates: Vec<IdentityUpdateProto>,
     new_updates: Vec<IdentityUpdateProto>,
+    point_in_time: Option<PointInTime>,
     scw_verifier: impl SmartContractSignatureVerifier,
 ) -> Result<GetAssociationStateResponse, GrpcServerError> {
-    let old_unverified_updates: Vec<UnverifiedIdentityUpdate> = try_map_vec(old_updates)?;
-    let new_unverified_updates: Vec<UnverifiedIdentityUpdate> = try_map_vec(new_updates)?;
+    let mut old_unverified_updates: Vec<UnverifiedIdentityUpdate> = try_map_vec(old_updates)?;
+    let mut new_unverified_updates: Vec<UnverifiedIdentityUpdate> = try_map_vec(new_updates)?;
+    truncate_to_point_in_time(
+        &mut old_unverified_updates,
+        &mut new_unverified_updates,
+        point_in_time,
+    );
 
     let old_updates = try_join_all(
         old_unverified_updates
@@ -437,11 +464,35 @@ mod tests {
                     .into_iter()
                     .map(IdentityUpdateProto::from)
                     .collect::<Vec<_>>(),
+                point_in_time: None,
             }))
             .await
             .unwrap();
     }
 
+    #[test]
+    fn test_truncate_to_point_in_time() {
+        let updates = |timestamps: &[u64]| {
+            timestamps
+                .iter()
+                .map(|ts| UnverifiedIdentityUpdate::new("inbox".to_string(), *ts, vec![]))
+                .collect::<Vec<_>>()
+        };
+        let truncated = |point_in_time| {
+            let (mut old, mut new) = (updates(&[10, 20]), updates(&[30, 40]));
+            truncate_to_point_in_time(&mut old, &mut new, point_in_time);
+            (old.len(), new.len())
+        };
+
+        assert_eq!(truncated(None), (2, 2));
+        assert_eq!(truncated(Some(PointInTime::UpdateCount(3))), (2, 1));
+        assert_eq!(truncated(Some(PointInTime::UpdateCount(1))), (1, 0));
+        assert_eq!(truncated(Some(PointInTime::UpdateCount(100))), (2, 2));
+        assert_eq!(truncated(Some(PointInTime::TimestampNs(30))), (2, 1));
+        assert_eq!(truncated(Some(PointInTime::TimestampNs(15))), (1, 0));
+        assert_eq!(truncated(Some(PointInTime::TimestampNs(5))), (0, 0));
+    }
+
     #[tokio::test]
     async fn test_validate_inbox_id_key_package_happy_path() {
         let (inbox_id, keypair) = generate_inbox_id_credential();
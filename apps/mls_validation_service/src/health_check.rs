@@ -1,14 +1,19 @@
-use std::future::Future;
+use std::{collections::HashMap, fmt::Write, future::Future, sync::Arc};
 
 use warp::Filter;
+use xmtp_id::scw_verifier::QuorumMetrics;
 
 use crate::wait_for_quit;
 
-pub async fn health_check_server(port: u16) -> impl Future<Output = ()> {
+pub async fn health_check_server(
+    port: u16,
+    quorum_metrics: HashMap<String, Arc<QuorumMetrics>>,
+) -> impl Future<Output = ()> {
     let health_route =
         warp::path("health").map(|| warp::reply::with_status("ok", warp::http::StatusCode::OK));
+    let metrics_route = warp::path("metrics").map(move || render_quorum_metrics(&quorum_metrics));
 
-    warp::serve(health_route)
+    warp::serve(health_route.or(metrics_route))
         .bind(([0, 0, 0, 0], port))
         .await
         .graceful(async {
@@ -17,3 +22,45 @@ pub async fn health_check_server(port: u16) -> impl Future<Output = ()> {
         })
         .run()
 }
+
+/// Renders the chain RPC quorum counters in the Prometheus text format
+fn render_quorum_metrics(quorum_metrics: &HashMap<String, Arc<QuorumMetrics>>) -> String {
+    let counters: [(&str, fn(&QuorumMetrics) -> usize); 4] = [
+        ("scw_quorum_checks_total", QuorumMetrics::checks),
+        (
+            "scw_quorum_disagreements_total",
+            QuorumMetrics::disagreements,
+        ),
+        (
+            "scw_quorum_provider_errors_total",
+            QuorumMetrics::provider_errors,
+        ),
+        ("scw_quorum_failures_total", QuorumMetrics::quorum_failures),
+    ];
+
+    let mut chain_ids: Vec<_> = quorum_metrics.keys().collect();
+    chain_ids.sort();
+
+    let mut out = String::new();
+    for (name, counter) in counters {
+        let _ = writeln!(out, "# TYPE {name} counter");
+        for chain_id in &chain_ids {
+            let value = counter(&quorum_metrics[*chain_id]);
+            let _ = writeln!(out, "{name}{{chain_id=\"{chain_id}\"}} {value}");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_quorum_metrics() {
+        let metrics = HashMap::from([("eip155:1".to_string(), Arc::new(QuorumMetrics::default()))]);
+        let rendered = render_quorum_metrics(&metrics);
+        assert!(rendered.contains("# TYPE scw_quorum_checks_total counter"));
+        assert!(rendered.contains("scw_quorum_disagreements_total{chain_id=\"eip155:1\"} 0"));
+    }
+}
@@ -57,12 +57,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Starting health check on port {:?}", args.health_check_port);
     info!("Cache size: {:?}", args.cache_size);
 
-    let health_server = health_check_server(args.health_check_port as u16).await;
     tracing::info!("Chain Urls: {:?}", args.chain_urls);
     let verifier = match args.chain_urls {
         Some(path) => MultiSmartContractSignatureVerifier::new_from_file(path)?,
         None => MultiSmartContractSignatureVerifier::new_from_env()?,
     };
+    let health_server = health_check_server(
+        args.health_check_port as u16,
+        verifier.quorum_metrics().clone(),
+    )
+    .await;
 
     let cached_verifier: CachedSmartContractSignatureVerifier =
         CachedSmartContractSignatureVerifier::new(verifier, args.cache_size)?;
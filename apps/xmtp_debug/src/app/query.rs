@@ -95,6 +95,7 @@ impl Query {
                             Some(xmtp_proto::xmtp::identity::associations::member_identifier::Kind::EthereumAddress(address)) => format!("eth: {address}"),
                             Some(xmtp_proto::xmtp::identity::associations::member_identifier::Kind::InstallationPublicKey(public_key)) => format!("installation pubkey: {}", hex::encode(public_key)),
                             Some(xmtp_proto::xmtp::identity::associations::member_identifier::Kind::Passkey(passkey)) => format!("passkey: {}", hex::encode(passkey.key)),
+                            Some(xmtp_proto::xmtp::identity::associations::member_identifier::Kind::SolanaAddress(address)) => format!("solana: {address}"),
                             None => String::new(),
                           };
                           // TODO: maybe add signatures
@@ -105,6 +106,7 @@ impl Query {
                             Some(xmtp_proto::xmtp::identity::associations::member_identifier::Kind::EthereumAddress(address)) => format!("eth: {address}"),
                             Some(xmtp_proto::xmtp::identity::associations::member_identifier::Kind::InstallationPublicKey(public_key)) => format!("installation pubkey: {}", hex::encode(public_key)),
                             Some(xmtp_proto::xmtp::identity::associations::member_identifier::Kind::Passkey(passkey)) => format!("passkey: {}", hex::encode(passkey.key)),
+                            Some(xmtp_proto::xmtp::identity::associations::member_identifier::Kind::SolanaAddress(address)) => format!("solana: {address}"),
                             None => String::new(),
                           };
                           println!("    revoke_association: member_to_revoke: {revoke_member_identifier}");
@@ -14,6 +14,7 @@ pub struct FfiIdentifier {
 pub enum FfiIdentifierKind {
     Ethereum,
     Passkey,
+    Solana,
 }
 
 impl FfiIdentifier {
@@ -28,6 +29,7 @@ impl Display for FfiIdentifier {
         match self.identifier_kind {
             FfiIdentifierKind::Ethereum => write!(f, "{}", self.identifier),
             FfiIdentifierKind::Passkey => write!(f, "{}", hex::encode(&self.identifier)),
+            FfiIdentifierKind::Solana => write!(f, "{}", self.identifier),
         }
     }
 }
@@ -53,6 +55,10 @@ impl From<Identifier> for FfiIdentifier {
                 identifier: hex::encode(key),
                 identifier_kind: FfiIdentifierKind::Passkey,
             },
+            Identifier::Solana(ident::Solana(addr)) => Self {
+                identifier: addr,
+                identifier_kind: FfiIdentifierKind::Solana,
+            },
         }
     }
 }
@@ -63,6 +69,7 @@ impl TryFrom<FfiIdentifier> for Identifier {
         let ident = match ident.identifier_kind {
             FfiIdentifierKind::Ethereum => Self::eth(ident.identifier)?,
             FfiIdentifierKind::Passkey => Self::passkey_str(&ident.identifier, None)?,
+            FfiIdentifierKind::Solana => Self::solana(ident.identifier)?,
         };
         Ok(ident)
     }
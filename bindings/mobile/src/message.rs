@@ -14,16 +14,16 @@ use xmtp_content_types::{
 };
 use xmtp_db::group_message::{DeliveryStatus, GroupMessageKind};
 use xmtp_mls::messages::decoded_message::{
-    DecodedMessage, DecodedMessageMetadata, DeletedBy, Markdown, MessageBody,
-    Reply as ProcessedReply, Text,
+    DecodedMessage, DecodedMessageMetadata, DeletedBy, Markdown, MessageBody, MessageEdit,
+    Poll as EnrichedPoll, PollOption, Reply as ProcessedReply, Text,
 };
 use xmtp_proto::xmtp::mls::message_contents::{
     ContentTypeId, EncodedContent, GroupUpdated, group_updated::MetadataFieldChange,
 };
 use xmtp_proto::xmtp::mls::message_contents::{
     content_types::{
-        DeleteMessage, LeaveRequest, MultiRemoteAttachment, ReactionAction, ReactionSchema,
-        ReactionV2,
+        DeleteMessage, EditMessage, LeaveRequest, MultiRemoteAttachment, Poll, PollVote,
+        ReactionAction, ReactionSchema, ReactionV2,
     },
     group_updated::Inbox,
 };
@@ -56,6 +56,7 @@ pub enum FfiDecodedMessageBody {
     Intent(FfiIntent),
     Actions(FfiActions),
     LeaveRequest(FfiLeaveRequest),
+    Poll(FfiEnrichedPoll),
     DeletedMessage(FfiDeletedMessage),
     Custom(FfiEncodedContent),
 }
@@ -286,6 +287,61 @@ pub struct FfiDeleteMessage {
     pub message_id: String,
 }
 
+/// Represents a request to edit a message.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct FfiEditMessage {
+    /// The ID of the message to edit
+    pub message_id: String,
+    /// The content that replaces the original message content
+    pub edited_content: FfiEncodedContent,
+}
+
+/// A poll asking members to choose between options.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct FfiPoll {
+    pub question: String,
+    pub options: Vec<String>,
+    /// Whether a single vote may select more than one option
+    pub allows_multiple_choices: bool,
+}
+
+/// A vote on a poll. Replaces any earlier vote from the same sender.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct FfiPollVote {
+    /// The ID of the poll message being voted on
+    pub poll_message_id: String,
+    /// The selected option indexes. An empty list retracts the vote
+    pub option_indexes: Vec<u32>,
+}
+
+/// A poll option and the number of voters currently selecting it
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct FfiPollOption {
+    pub text: String,
+    pub vote_count: u32,
+}
+
+/// A poll with the tallies of each voter's latest vote
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct FfiEnrichedPoll {
+    pub question: String,
+    pub options: Vec<FfiPollOption>,
+    pub allows_multiple_choices: bool,
+    pub total_voters: u32,
+    /// The option indexes selected by the current user, empty if they haven't voted
+    pub my_vote: Vec<u32>,
+}
+
+/// One version of an edited message's content
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct FfiMessageEdit {
+    /// The ID of the message that introduced this version
+    pub message_id: Vec<u8>,
+    pub sent_at_ns: i64,
+    pub content_type: FfiContentTypeId,
+    pub content: FfiDecodedMessageContent,
+}
+
 #[derive(uniffi::Record, Clone, Debug)]
 pub struct FfiWalletSendCalls {
     pub version: String,
@@ -383,6 +439,7 @@ pub struct FfiDecodedMessageMetadata {
     pub conversation_id: Vec<u8>,
     pub inserted_at_ns: i64,
     pub expires_at_ns: Option<i64>,
+    pub view_once: bool,
 }
 
 #[derive(uniffi::Enum, Clone, Debug)]
@@ -401,6 +458,7 @@ pub enum FfiDecodedMessageContent {
     Intent(Option<FfiIntent>),
     Actions(Option<FfiActions>),
     LeaveRequest(FfiLeaveRequest),
+    Poll(FfiEnrichedPoll),
     DeletedMessage(FfiDeletedMessage),
     Custom(FfiEncodedContent),
 }
@@ -726,6 +784,101 @@ impl From<FfiDeleteMessage> for DeleteMessage {
     }
 }
 
+impl TryFrom<EditMessage> for FfiEditMessage {
+    type Error = GenericError;
+
+    fn try_from(value: EditMessage) -> Result<Self, Self::Error> {
+        Ok(FfiEditMessage {
+            message_id: value.message_id,
+            edited_content: value
+                .edited_content
+                .ok_or(GenericError::Generic {
+                    err: "EditMessage is missing edited content".to_string(),
+                })?
+                .into(),
+        })
+    }
+}
+
+impl From<FfiEditMessage> for EditMessage {
+    fn from(value: FfiEditMessage) -> Self {
+        EditMessage {
+            message_id: value.message_id,
+            edited_content: Some(value.edited_content.into()),
+        }
+    }
+}
+
+impl From<Poll> for FfiPoll {
+    fn from(value: Poll) -> Self {
+        FfiPoll {
+            question: value.question,
+            options: value.options,
+            allows_multiple_choices: value.allows_multiple_choices,
+        }
+    }
+}
+
+impl From<FfiPoll> for Poll {
+    fn from(value: FfiPoll) -> Self {
+        Poll {
+            question: value.question,
+            options: value.options,
+            allows_multiple_choices: value.allows_multiple_choices,
+        }
+    }
+}
+
+impl From<PollVote> for FfiPollVote {
+    fn from(value: PollVote) -> Self {
+        FfiPollVote {
+            poll_message_id: value.poll_message_id,
+            option_indexes: value.option_indexes,
+        }
+    }
+}
+
+impl From<FfiPollVote> for PollVote {
+    fn from(value: FfiPollVote) -> Self {
+        PollVote {
+            poll_message_id: value.poll_message_id,
+            option_indexes: value.option_indexes,
+        }
+    }
+}
+
+impl From<PollOption> for FfiPollOption {
+    fn from(value: PollOption) -> Self {
+        FfiPollOption {
+            text: value.text,
+            vote_count: value.vote_count,
+        }
+    }
+}
+
+impl From<EnrichedPoll> for FfiEnrichedPoll {
+    fn from(value: EnrichedPoll) -> Self {
+        FfiEnrichedPoll {
+            question: value.question,
+            options: value.options.into_iter().map(Into::into).collect(),
+            allows_multiple_choices: value.allows_multiple_choices,
+            total_voters: value.total_voters,
+            my_vote: value.my_vote,
+        }
+    }
+}
+
+impl From<MessageEdit> for FfiMessageEdit {
+    fn from(value: MessageEdit) -> Self {
+        FfiMessageEdit {
+            message_id: value.message_id,
+            sent_at_ns: value.sent_at_ns,
+            content_type: value.content_type.into(),
+            content: value.content.into(),
+        }
+    }
+}
+
 impl From<WalletSendCalls> for FfiWalletSendCalls {
     fn from(value: WalletSendCalls) -> Self {
         FfiWalletSendCalls {
@@ -1026,6 +1179,7 @@ impl From<DecodedMessageMetadata> for FfiDecodedMessageMetadata {
             content_type: metadata.content_type.into(),
             inserted_at_ns: metadata.inserted_at_ns,
             expires_at_ns: metadata.expires_at_ns,
+            edit_history: item.edit_history.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -1099,6 +1253,7 @@ impl From<MessageBody> for FfiDecodedMessageContent {
             MessageBody::LeaveRequest(leave_request) => {
                 FfiDecodedMessageContent::LeaveRequest(leave_request.into())
             }
+            MessageBody::Poll(poll) => FfiDecodedMessageContent::Poll(poll.into()),
             MessageBody::DeletedMessage { deleted_by } => {
                 FfiDecodedMessageContent::DeletedMessage(FfiDeletedMessage {
                     deleted_by: deleted_by.into(),
@@ -1173,6 +1328,7 @@ pub fn content_to_optional_body(content: MessageBody) -> Option<FfiDecodedMessag
         MessageBody::LeaveRequest(leave_request) => {
             Some(FfiDecodedMessageBody::LeaveRequest(leave_request.into()))
         }
+        MessageBody::Poll(poll) => Some(FfiDecodedMessageBody::Poll(poll.into())),
         MessageBody::DeletedMessage { deleted_by } => {
             Some(FfiDecodedMessageBody::DeletedMessage(FfiDeletedMessage {
                 deleted_by: deleted_by.into(),
@@ -1201,6 +1357,7 @@ pub struct FfiDecodedMessage {
     num_replies: u64,
     inserted_at_ns: i64,
     expires_at_ns: Option<i64>,
+    edit_history: Vec<FfiMessageEdit>,
 }
 
 #[uniffi::export]
@@ -1270,6 +1427,20 @@ impl FfiDecodedMessage {
     pub fn expires_at_ns(&self) -> Option<i64> {
         self.expires_at_ns
     }
+
+    /// Every version of the content, oldest first, starting with the original.
+    /// Empty if the message was never edited.
+    pub fn edit_history(&self) -> Vec<FfiMessageEdit> {
+        self.edit_history.clone()
+    }
+
+    pub fn is_edited(&self) -> bool {
+        !self.edit_history.is_empty()
+    }
+
+    pub fn edited_at_ns(&self) -> Option<i64> {
+        self.edit_history.last().map(|edit| edit.sent_at_ns)
+    }
 }
 
 impl From<DecodedMessage> for FfiDecodedMessage {
@@ -1299,6 +1470,7 @@ impl From<DecodedMessage> for FfiDecodedMessage {
             num_replies: item.num_replies as u64,
             inserted_at_ns: metadata.inserted_at_ns,
             expires_at_ns: metadata.expires_at_ns,
+            view_once: metadata.view_once,
         }
     }
 }
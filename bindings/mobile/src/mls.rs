@@ -7,7 +7,9 @@ use crate::message::{
 };
 use crate::worker::{FfiDeviceSyncMode, FfiSyncWorker};
 use crate::worker_config::FfiWorkerConfig;
-use crate::{FfiError, FfiGroupUpdated, FfiReply, FfiWalletSendCalls, GenericError};
+use crate::{
+    FfiEncodedContent, FfiError, FfiGroupUpdated, FfiReply, FfiWalletSendCalls, GenericError,
+};
 use futures::future::try_join_all;
 use prost::Message;
 use std::{collections::HashMap, convert::TryInto, sync::Arc};
@@ -21,11 +23,13 @@ use xmtp_content_types::actions::{Actions, ActionsCodec};
 use xmtp_content_types::attachment::Attachment;
 use xmtp_content_types::attachment::AttachmentCodec;
 use xmtp_content_types::delete_message::DeleteMessageCodec;
+use xmtp_content_types::edit_message::EditMessageCodec;
 use xmtp_content_types::group_updated::GroupUpdatedCodec;
 use xmtp_content_types::intent::{Intent, IntentCodec};
 use xmtp_content_types::leave_request::LeaveRequestCodec;
 use xmtp_content_types::markdown::MarkdownCodec;
 use xmtp_content_types::multi_remote_attachment::MultiRemoteAttachmentCodec;
+use xmtp_content_types::poll::{PollCodec, PollVoteCodec};
 use xmtp_content_types::reaction::ReactionCodec;
 use xmtp_content_types::read_receipt::ReadReceipt;
 use xmtp_content_types::read_receipt::ReadReceiptCodec;
@@ -39,10 +43,12 @@ use xmtp_content_types::transaction_reference::TransactionReferenceCodec;
 use xmtp_content_types::wallet_send_calls::WalletSendCallsCodec;
 use xmtp_content_types::{ContentCodec, encoded_content_to_bytes};
 use xmtp_db::NativeDb;
+use xmtp_db::conversation_settings::StoredConversationSettings;
 use xmtp_db::group::DmIdExt;
 use xmtp_db::group::{ConversationType, GroupMembershipState, GroupQueryOrderBy};
 use xmtp_db::group_message::{ContentType, MsgQueryArgs};
 use xmtp_db::group_message::{SortBy, SortDirection, StoredGroupMessageWithReactions};
+use xmtp_db::message_search::MessageSearchHit;
 use xmtp_db::user_preferences::HmacKey;
 use xmtp_db::{
     EncryptedMessageStore, EncryptionKey,
@@ -66,7 +72,8 @@ use xmtp_mls::context::XmtpSharedContext;
 use xmtp_mls::cursor_store::SqliteCursorStore;
 use xmtp_mls::groups::{
     ConversationDebugInfo, GroupMembershipCapabilities, InboxCapabilities,
-    InstallationCapabilities, MlsExtensionType,
+    InstallationCapabilities, MlsExtensionType, drafts::Draft, ephemeral::EphemeralMessage,
+    scheduled_messages::ScheduledMessage,
 };
 use xmtp_mls::identity_updates::revoke_installations_with_verifier;
 use xmtp_mls::identity_updates::{
@@ -77,6 +84,7 @@ use xmtp_mls::mls_common::group::GroupMetadataOptions;
 use xmtp_mls::mls_common::group_metadata::GroupMetadata;
 use xmtp_mls::mls_common::group_mutable_metadata::MessageDisappearingSettings;
 use xmtp_mls::mls_common::group_mutable_metadata::MetadataField;
+use xmtp_mls::mls_common::invite::group_invites::GroupInvite;
 use xmtp_mls::subscriptions::router_callbacks::stream_conversation_messages_with_callback_dispatch;
 use xmtp_mls::{
     client::Client as MlsClient,
@@ -89,6 +97,7 @@ use xmtp_mls::{
         },
         intents::{PermissionPolicyOption, PermissionUpdateType, UpdateGroupMembershipResult},
         members::PermissionLevel,
+        moderators::UpdateModeratorListType,
     },
     identity::IdentityStrategy,
     subscriptions::SubscribeError,
@@ -101,14 +110,17 @@ use xmtp_proto::api_client::IdentityStats;
 use xmtp_proto::types::Cursor;
 use xmtp_proto::types::{ApiIdentifier, GroupMessageMetadata};
 use xmtp_proto::xmtp::mls::message_contents::EncodedContent;
+use xmtp_proto::xmtp::mls::message_contents::ExternalInvitePayload;
 use xmtp_proto::xmtp::mls::message_contents::content_types::DeleteMessage;
+use xmtp_proto::xmtp::mls::message_contents::content_types::EditMessage;
 use xmtp_proto::xmtp::mls::message_contents::content_types::LeaveRequest;
 use xmtp_proto::xmtp::mls::message_contents::content_types::{MultiRemoteAttachment, ReactionV2};
+use xmtp_proto::xmtp::mls::message_contents::content_types::{Poll, PollVote};
 
 // Re-export types from message module that are used in public APIs
 pub use crate::message::{
-    FfiAttachment, FfiDeleteMessage, FfiLeaveRequest, FfiMultiRemoteAttachment, FfiReadReceipt,
-    FfiRemoteAttachment, FfiTransactionReference,
+    FfiAttachment, FfiDeleteMessage, FfiEditMessage, FfiLeaveRequest, FfiMultiRemoteAttachment,
+    FfiPoll, FfiPollVote, FfiReadReceipt, FfiRemoteAttachment, FfiTransactionReference,
 };
 
 pub mod change_callbacks;
@@ -355,6 +367,12 @@ pub struct DbOptions {
     /// foreign callers that construct `DbOptions` without this field still compile.
     #[uniffi(default = None)]
     pub use_single_connection: Option<bool>,
+    /// A second 32 byte key to open the database with if `encryption_key` is
+    /// incorrect, such as the other key of an interrupted
+    /// `FfiXmtpClient::rekey_database`. See
+    /// `FfiXmtpClient::database_opened_with_fallback_key`.
+    #[uniffi(default = None)]
+    pub fallback_encryption_key: Option<Vec<u8>>,
 }
 
 impl DbOptions {
@@ -371,6 +389,7 @@ impl DbOptions {
             max_db_pool_size,
             min_db_pool_size,
             use_single_connection,
+            fallback_encryption_key: None,
         }
     }
 }
@@ -431,6 +450,7 @@ pub async fn create_client(
         max_db_pool_size,
         min_db_pool_size,
         use_single_connection,
+        fallback_encryption_key,
     } = db;
 
     log::info!(
@@ -441,6 +461,12 @@ pub async fn create_client(
     );
 
     let single = use_single_connection.unwrap_or(false);
+    let fallback_key: Option<EncryptionKey> = fallback_encryption_key
+        .map(|key| {
+            key.try_into()
+                .map_err(|_| "Malformed 32 byte fallback encryption key".to_string())
+        })
+        .transpose()?;
 
     let base = if let Some(path) = db {
         NativeDb::builder().persistent(path)
@@ -457,7 +483,7 @@ pub async fn create_client(
             let key: EncryptionKey = key
                 .try_into()
                 .map_err(|_| "Malformed 32 byte encryption key".to_string())?;
-            b.key(key).build()
+            b.key(key).maybe_fallback_key(fallback_key).build()
         } else {
             b.build_unencrypted()
         }
@@ -469,7 +495,7 @@ pub async fn create_client(
             let key: EncryptionKey = key
                 .try_into()
                 .map_err(|_| "Malformed 32 byte encryption key".to_string())?;
-            b.key(key).build()
+            b.key(key).maybe_fallback_key(fallback_key).build()
         } else {
             b.build_unencrypted()
         }
@@ -601,6 +627,26 @@ impl FfiSignatureRequest {
         Ok(())
     }
 
+    // Signature that's signed by a Solana (ed25519) wallet
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn add_solana_signature(
+        &self,
+        signature_bytes: Vec<u8>,
+        address: String,
+    ) -> Result<(), FfiError> {
+        let mut inner = self.inner.lock().await;
+        let address = ident::Solana(address).sanitize()?;
+
+        inner
+            .add_signature(
+                UnverifiedSignature::new_solana(signature_bytes, &address)?,
+                &self.scw_verifier,
+            )
+            .await?;
+
+        Ok(())
+    }
+
     // Signature that's signed by smart contract wallet
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn add_scw_signature(
@@ -726,6 +772,22 @@ impl FfiXmtpClient {
         Ok(message.into())
     }
 
+    /// Full-text search over the messages of every conversation, ordered by relevance.
+    #[tracing::instrument(skip_all)]
+    pub fn search_messages(
+        &self,
+        query: String,
+        opts: FfiListMessagesOptions,
+    ) -> Result<Vec<FfiMessageSearchHit>, FfiError> {
+        let hits = self
+            .inner_client
+            .search_messages(&query, &opts.into())?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(hits)
+    }
+
     #[tracing::instrument(skip_all)]
     pub fn delete_message(&self, message_id: Vec<u8>) -> Result<u32, FfiError> {
         let deleted_count = self.inner_client.delete_message(message_id)?;
@@ -770,6 +832,44 @@ impl FfiXmtpClient {
         Ok(self.inner_client.reconnect_db()?)
     }
 
+    /// Re-encrypt the database with a new 32 byte key.
+    ///
+    /// If the process is killed during the swap, the database is left encrypted with either key.
+    /// Keep the old key until this returns, and pass the other key as
+    /// `DbOptions::fallback_encryption_key` when creating the client;
+    /// [`Self::database_opened_with_fallback_key`] then tells which one the database uses.
+    #[tracing::instrument(skip_all)]
+    pub async fn rekey_database(&self, new_key: Vec<u8>) -> Result<(), FfiError> {
+        let key: EncryptionKey = new_key
+            .try_into()
+            .map_err(|_| "Malformed 32 byte encryption key".to_string())?;
+        // copies the whole database and waits for connections in use, so keep it off the runtime
+        let store = self.inner_client.context.store().clone();
+        tokio::task::spawn_blocking(move || store.rekey(key))
+            .await
+            .map_err(|e| e.to_string())??;
+        Ok(())
+    }
+
+    /// Encrypt a database that was created without an encryption key with a 32 byte key.
+    #[tracing::instrument(skip_all)]
+    pub async fn encrypt_database(&self, key: Vec<u8>) -> Result<(), FfiError> {
+        let key: EncryptionKey = key
+            .try_into()
+            .map_err(|_| "Malformed 32 byte encryption key".to_string())?;
+        let store = self.inner_client.context.store().clone();
+        tokio::task::spawn_blocking(move || store.encrypt_in_place(key))
+            .await
+            .map_err(|e| e.to_string())??;
+        Ok(())
+    }
+
+    /// Whether the database was opened with `DbOptions::fallback_encryption_key` because
+    /// `encryption_key` was incorrect.
+    pub fn database_opened_with_fallback_key(&self) -> bool {
+        self.inner_client.context.store().opened_with_fallback_key()
+    }
+
     /// Cleanly shut down this client: cancel in-flight workers and detached
     /// streams, then release the DB connection. Idempotent — a second call
     /// resolves to `Ok`.
@@ -1345,6 +1445,8 @@ pub enum FfiSignatureKind {
     LegacyDelegated,
     /// P256 passkey signature
     P256,
+    /// Ed25519 wallet signature (Solana)
+    Ed25519Wallet,
 }
 
 impl From<xmtp_id::associations::SignatureKind> for FfiSignatureKind {
@@ -1359,6 +1461,7 @@ impl From<xmtp_id::associations::SignatureKind> for FfiSignatureKind {
                 FfiSignatureKind::LegacyDelegated
             }
             xmtp_id::associations::SignatureKind::P256 => FfiSignatureKind::P256,
+            xmtp_id::associations::SignatureKind::Ed25519Wallet => FfiSignatureKind::Ed25519Wallet,
         }
     }
 }
@@ -1425,6 +1528,7 @@ impl From<AssociationState> for FfiInboxState {
                 .filter_map(|m| match m.identifier {
                     MemberIdentifier::Ethereum(_) => None,
                     MemberIdentifier::Passkey(_) => None,
+                    MemberIdentifier::Solana(_) => None,
                     MemberIdentifier::Installation(ident::Installation(id)) => {
                         Some(FfiInstallation {
                             id,
@@ -1463,6 +1567,17 @@ pub struct FfiSendMessageOpts {
     /// `FfiSendMessageOpts` without this field still compile.
     #[uniffi(default = None)]
     pub idempotency_key: Option<String>,
+    /// Delete the message this long after it was sent, regardless of the
+    /// conversation's disappearing messages settings.
+    #[uniffi(default = None)]
+    pub expire_after_ns: Option<i64>,
+    /// Delete the message on receivers once they have viewed it.
+    ///
+    /// Either option first raises the conversation's minimum protocol version so
+    /// older clients can't keep the message; optimistic and scheduled sends
+    /// fail until a regular send has done so.
+    #[uniffi(default = false)]
+    pub view_once: bool,
 }
 
 impl From<FfiSendMessageOpts> for xmtp_mls::groups::send_message_opts::SendMessageOpts {
@@ -1470,6 +1585,8 @@ impl From<FfiSendMessageOpts> for xmtp_mls::groups::send_message_opts::SendMessa
         xmtp_mls::groups::send_message_opts::SendMessageOpts {
             should_push: opts.should_push,
             idempotency_key: opts.idempotency_key,
+            expire_after_ns: opts.expire_after_ns,
+            view_once: opts.view_once,
         }
     }
 }
@@ -1484,6 +1601,15 @@ pub struct FfiListConversationsOptions {
     pub limit: Option<i64>,
     pub consent_states: Option<Vec<FfiConsentState>>,
     pub include_duplicate_dms: bool,
+    /// Only archived (`true`) or unarchived (`false`) conversations
+    #[uniffi(default = None)]
+    pub archived: Option<bool>,
+    /// Only pinned (`true`) or unpinned (`false`) conversations
+    #[uniffi(default = None)]
+    pub pinned: Option<bool>,
+    /// Only conversations that are (`true`) or are not (`false`) muted right now
+    #[uniffi(default = None)]
+    pub muted: Option<bool>,
 }
 
 impl From<FfiListConversationsOptions> for GroupQueryArgs {
@@ -1499,6 +1625,9 @@ impl From<FfiListConversationsOptions> for GroupQueryArgs {
             last_activity_before_ns: opts.last_activity_before_ns,
             last_activity_after_ns: opts.last_activity_after_ns,
             order_by: opts.order_by.map(Into::into),
+            archived: opts.archived,
+            pinned: opts.pinned,
+            muted: opts.muted,
             ..Default::default()
         }
     }
@@ -1545,6 +1674,7 @@ pub enum FfiPermissionPolicy {
     SuperAdmin,
     DoesNotExist,
     Other,
+    Moderator,
 }
 
 impl TryInto<PermissionPolicyOption> for FfiPermissionPolicy {
@@ -1556,6 +1686,7 @@ impl TryInto<PermissionPolicyOption> for FfiPermissionPolicy {
             FfiPermissionPolicy::Deny => Ok(PermissionPolicyOption::Deny),
             FfiPermissionPolicy::Admin => Ok(PermissionPolicyOption::AdminOnly),
             FfiPermissionPolicy::SuperAdmin => Ok(PermissionPolicyOption::SuperAdminOnly),
+            FfiPermissionPolicy::Moderator => Ok(PermissionPolicyOption::ModeratorOrAbove),
             _ => Err(GroupMutablePermissionsError::InvalidPermissionPolicyOption),
         }
     }
@@ -1570,6 +1701,7 @@ impl TryInto<MembershipPolicies> for FfiPermissionPolicy {
             FfiPermissionPolicy::Deny => Ok(MembershipPolicies::deny()),
             FfiPermissionPolicy::Admin => Ok(MembershipPolicies::allow_if_actor_admin()),
             FfiPermissionPolicy::SuperAdmin => Ok(MembershipPolicies::allow_if_actor_super_admin()),
+            FfiPermissionPolicy::Moderator => Ok(MembershipPolicies::allow_if_actor_moderator()),
             _ => Err(GroupMutablePermissionsError::InvalidPermissionPolicyOption),
         }
     }
@@ -1584,6 +1716,7 @@ impl TryInto<MetadataPolicies> for FfiPermissionPolicy {
             FfiPermissionPolicy::Deny => Ok(MetadataPolicies::deny()),
             FfiPermissionPolicy::Admin => Ok(MetadataPolicies::allow_if_actor_admin()),
             FfiPermissionPolicy::SuperAdmin => Ok(MetadataPolicies::allow_if_actor_super_admin()),
+            FfiPermissionPolicy::Moderator => Ok(MetadataPolicies::allow_if_actor_moderator()),
             _ => Err(GroupMutablePermissionsError::InvalidPermissionPolicyOption),
         }
     }
@@ -1613,6 +1746,7 @@ impl From<&MembershipPolicies> for FfiPermissionPolicy {
                 BasePolicies::AllowSameMember => FfiPermissionPolicy::Other,
                 BasePolicies::AllowIfAdminOrSuperAdmin => FfiPermissionPolicy::Admin,
                 BasePolicies::AllowIfSuperAdmin => FfiPermissionPolicy::SuperAdmin,
+                BasePolicies::AllowIfModeratorOrAbove => FfiPermissionPolicy::Moderator,
             }
         } else {
             FfiPermissionPolicy::Other
@@ -1628,6 +1762,9 @@ impl From<&MetadataPolicies> for FfiPermissionPolicy {
                 MetadataBasePolicies::Deny => FfiPermissionPolicy::Deny,
                 MetadataBasePolicies::AllowIfActorAdminOrSuperAdmin => FfiPermissionPolicy::Admin,
                 MetadataBasePolicies::AllowIfActorSuperAdmin => FfiPermissionPolicy::SuperAdmin,
+                MetadataBasePolicies::AllowIfActorModeratorOrAbove => {
+                    FfiPermissionPolicy::Moderator
+                }
             }
         } else {
             FfiPermissionPolicy::Other
@@ -1751,6 +1888,8 @@ pub enum FfiMetadataField {
     // so the three bindings expose the same field set.
     MessageExpirationFromNs,
     MessageExpirationInNs,
+    PinnedMessageIds,
+    ExternalInvites,
 }
 
 impl From<&FfiMetadataField> for MetadataField {
@@ -1762,6 +1901,8 @@ impl From<&FfiMetadataField> for MetadataField {
             FfiMetadataField::AppData => MetadataField::AppData,
             FfiMetadataField::MessageExpirationFromNs => MetadataField::MessageDisappearFromNS,
             FfiMetadataField::MessageExpirationInNs => MetadataField::MessageDisappearInNS,
+            FfiMetadataField::PinnedMessageIds => MetadataField::PinnedMessageIds,
+            FfiMetadataField::ExternalInvites => MetadataField::ExternalInvites,
         }
     }
 }
@@ -1897,6 +2038,22 @@ impl FfiConversations {
             .map_err(Into::into)
     }
 
+    /// Join a group with the serialized `ExternalInvitePayload` returned by
+    /// [`FfiConversation::create_invite`].
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn join_with_invite(
+        &self,
+        invite: Vec<u8>,
+    ) -> Result<Arc<FfiConversation>, FfiError> {
+        let invite = ExternalInvitePayload::decode(invite.as_slice())
+            .map_err(|e| FfiError::generic(e.to_string()))?;
+        self.inner_client
+            .join_with_invite(&invite)
+            .await
+            .map(|g| Arc::new(g.into()))
+            .map_err(Into::into)
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn find_or_create_dm(
         &self,
@@ -1959,6 +2116,12 @@ impl FfiConversations {
                         .last_message
                         .map(|stored_message| stored_message.into()),
                     is_commit_log_forked: conversation_item.is_commit_log_forked,
+                    has_draft: conversation_item.has_draft,
+                    unread_count: conversation_item.unread_count as u64,
+                    first_unread_message_id: conversation_item.first_unread_message_id,
+                    muted_until_ns: conversation_item.muted_until_ns,
+                    is_archived: conversation_item.is_archived,
+                    is_pinned: conversation_item.is_pinned,
                 })
             })
             .collect();
@@ -1985,6 +2148,12 @@ impl FfiConversations {
                         .last_message
                         .map(|stored_message| stored_message.into()),
                     is_commit_log_forked: conversation_item.is_commit_log_forked,
+                    has_draft: conversation_item.has_draft,
+                    unread_count: conversation_item.unread_count as u64,
+                    first_unread_message_id: conversation_item.first_unread_message_id,
+                    muted_until_ns: conversation_item.muted_until_ns,
+                    is_archived: conversation_item.is_archived,
+                    is_pinned: conversation_item.is_pinned,
                 })
             })
             .collect();
@@ -2011,6 +2180,12 @@ impl FfiConversations {
                         .last_message
                         .map(|stored_message| stored_message.into()),
                     is_commit_log_forked: conversation_item.is_commit_log_forked,
+                    has_draft: conversation_item.has_draft,
+                    unread_count: conversation_item.unread_count as u64,
+                    first_unread_message_id: conversation_item.first_unread_message_id,
+                    muted_until_ns: conversation_item.muted_until_ns,
+                    is_archived: conversation_item.is_archived,
+                    is_pinned: conversation_item.is_pinned,
                 })
             })
             .collect();
@@ -2197,6 +2372,15 @@ impl TryFrom<PreferenceUpdate> for FfiPreferenceUpdate {
     fn try_from(value: PreferenceUpdate) -> Result<Self, Self::Error> {
         match value {
             PreferenceUpdate::Hmac { key, .. } => Ok(FfiPreferenceUpdate::HMAC { key }),
+            PreferenceUpdate::ReadCursor(cursor) => Ok(FfiPreferenceUpdate::ReadCursor {
+                conversation_id: cursor.group_id.to_vec(),
+                message_id: cursor.message_id,
+            }),
+            PreferenceUpdate::ConversationSettings(settings) => {
+                Ok(FfiPreferenceUpdate::ConversationSettings {
+                    settings: settings.into(),
+                })
+            }
             // These are filtered out in the stream and should not be here
             // We're keeping preference update and consent streams separate right now.
             PreferenceUpdate::Consent(_) => Err(GenericError::Generic {
@@ -2216,6 +2400,12 @@ pub struct FfiConversationListItem {
     conversation: FfiConversation,
     last_message: Option<FfiMessage>,
     is_commit_log_forked: Option<bool>,
+    has_draft: bool,
+    unread_count: u64,
+    first_unread_message_id: Option<Vec<u8>>,
+    muted_until_ns: Option<i64>,
+    is_archived: bool,
+    is_pinned: bool,
 }
 
 #[uniffi::export]
@@ -2230,6 +2420,30 @@ impl FfiConversationListItem {
     pub fn is_commit_log_forked(&self) -> Option<bool> {
         self.is_commit_log_forked
     }
+
+    pub fn has_draft(&self) -> bool {
+        self.has_draft
+    }
+
+    pub fn unread_count(&self) -> u64 {
+        self.unread_count
+    }
+
+    pub fn first_unread_message_id(&self) -> Option<Vec<u8>> {
+        self.first_unread_message_id.clone()
+    }
+
+    pub fn muted_until_ns(&self) -> Option<i64> {
+        self.muted_until_ns
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.is_archived
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.is_pinned
+    }
 }
 
 #[derive(uniffi::Record, Debug)]
@@ -2656,6 +2870,7 @@ pub enum FfiContentType {
     Actions,
     Intent,
     MultiRemoteAttachment,
+    Poll,
 }
 
 impl From<FfiContentType> for ContentType {
@@ -2677,6 +2892,7 @@ impl From<FfiContentType> for ContentType {
             FfiContentType::Actions => ContentType::Actions,
             FfiContentType::Intent => ContentType::Intent,
             FfiContentType::MultiRemoteAttachment => ContentType::MultiRemoteAttachment,
+            FfiContentType::Poll => ContentType::Poll,
         }
     }
 }
@@ -2749,7 +2965,7 @@ impl FfiConversation {
             encoded_content_to_bytes(content),
             FfiSendMessageOpts {
                 should_push: true,
-                idempotency_key: None,
+                ..Default::default()
             },
         )
         .await
@@ -2769,6 +2985,14 @@ impl FfiConversation {
         Ok(id)
     }
 
+    /// Send a short-lived signal, such as a typing indicator. Ephemeral messages are
+    /// only delivered to live `stream_ephemeral` listeners and are never stored.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn send_ephemeral(&self, content_bytes: Vec<u8>) -> Result<(), FfiError> {
+        self.inner.send_ephemeral(content_bytes.as_slice()).await?;
+        Ok(())
+    }
+
     /// Delete a message by its ID. Returns the ID of the deletion message.
     #[tracing::instrument(level = "debug", skip_all)]
     pub fn delete_message(&self, message_id: Vec<u8>) -> Result<Vec<u8>, FfiError> {
@@ -2776,6 +3000,25 @@ impl FfiConversation {
         Ok(deletion_id)
     }
 
+    /// Edit a message sent by this inbox. Returns the ID of the edit message.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn edit_message(
+        &self,
+        message_id: Vec<u8>,
+        edited_content: FfiEncodedContent,
+    ) -> Result<Vec<u8>, FfiError> {
+        let edit_id = self.inner.edit_message(message_id, edited_content.into())?;
+        Ok(edit_id)
+    }
+
+    /// Mark a message as viewed. View-once messages from other members are
+    /// deleted from this installation. Returns whether the message was deleted.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn mark_viewed(&self, message_id: Vec<u8>) -> Result<bool, FfiError> {
+        let deleted = self.inner.mark_viewed(&message_id)?;
+        Ok(deleted)
+    }
+
     /// Publish all unpublished messages
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn publish_messages(&self) -> Result<(), FfiError> {
@@ -2807,6 +3050,92 @@ impl FfiConversation {
         Ok(())
     }
 
+    /// Store a message now and publish it in the background once `publish_at_ns`
+    /// has passed, including after an app restart. Returns the message ID.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn send_at(
+        &self,
+        content_bytes: Vec<u8>,
+        publish_at_ns: i64,
+        opts: FfiSendMessageOpts,
+    ) -> Result<Vec<u8>, FfiError> {
+        let id =
+            self.inner
+                .send_message_at(content_bytes.as_slice(), publish_at_ns, opts.into())?;
+        Ok(id)
+    }
+
+    /// Messages waiting to be published, ordered by publish time.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn scheduled_messages(&self) -> Result<Vec<FfiScheduledMessage>, FfiError> {
+        let scheduled = self
+            .inner
+            .scheduled_messages()?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(scheduled)
+    }
+
+    /// Cancel a scheduled message that has not been published yet.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn cancel_scheduled_message(&self, message_id: Vec<u8>) -> Result<(), FfiError> {
+        self.inner.cancel_scheduled_message(&message_id)?;
+        Ok(())
+    }
+
+    /// Save the unsent draft for this conversation. Drafts are stored locally
+    /// and never sent.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn set_draft(
+        &self,
+        content: FfiEncodedContent,
+        reply_to_message_id: Option<Vec<u8>>,
+    ) -> Result<(), FfiError> {
+        self.inner.set_draft(content.into(), reply_to_message_id)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn get_draft(&self) -> Result<Option<FfiDraft>, FfiError> {
+        Ok(self.inner.get_draft()?.map(Into::into))
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn clear_draft(&self) -> Result<(), FfiError> {
+        self.inner.clear_draft()?;
+        Ok(())
+    }
+
+    /// Mark every message up to and including `up_to_message_id` as read.
+    /// The read cursor is synced to the user's other installations.
+    pub fn mark_read(&self, up_to_message_id: Vec<u8>) -> Result<(), FfiError> {
+        self.inner.mark_read(&up_to_message_id)?;
+        Ok(())
+    }
+
+    /// How the user has organized this conversation in their inbox.
+    pub fn conversation_settings(&self) -> Result<FfiConversationSettings, FfiError> {
+        Ok(self.inner.conversation_settings()?.into())
+    }
+
+    /// Mute notifications until `muted_until_ns`, or unmute with `None`.
+    /// Settings are synced to the user's other installations.
+    pub fn set_muted_until(&self, muted_until_ns: Option<i64>) -> Result<(), FfiError> {
+        self.inner.set_muted_until(muted_until_ns)?;
+        Ok(())
+    }
+
+    pub fn set_archived(&self, archived: bool) -> Result<(), FfiError> {
+        self.inner.set_archived(archived)?;
+        Ok(())
+    }
+
+    pub fn set_pinned(&self, pinned: bool) -> Result<(), FfiError> {
+        self.inner.set_pinned(pinned)?;
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn sync(&self) -> Result<(), FfiError> {
         self.inner.sync().await?;
@@ -2836,6 +3165,22 @@ impl FfiConversation {
         Ok(count)
     }
 
+    /// Full-text search over the messages of this conversation, ordered by relevance.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn search_messages(
+        &self,
+        query: String,
+        opts: FfiListMessagesOptions,
+    ) -> Result<Vec<FfiMessageSearchHit>, FfiError> {
+        let hits = self
+            .inner
+            .search_messages(&query, &opts.into())?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(hits)
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     pub fn find_messages_with_reactions(
         &self,
@@ -3071,6 +3416,46 @@ impl FfiConversation {
         Ok(self.inner.group_description()?)
     }
 
+    /// Pin a message for every member of the group.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn pin_message(&self, message_id: Vec<u8>) -> Result<(), FfiError> {
+        self.inner.pin_message(&message_id).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn unpin_message(&self, message_id: Vec<u8>) -> Result<(), FfiError> {
+        self.inner.unpin_message(&message_id).await?;
+        Ok(())
+    }
+
+    /// The ids of the group's pinned messages, in the order they were pinned.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn pinned_messages(&self) -> Result<Vec<Vec<u8>>, FfiError> {
+        Ok(self.inner.pinned_messages()?)
+    }
+
+    /// Create an invite and return the serialized `ExternalInvitePayload` to share.
+    /// The invite admits joiners until `expires_at_ns`, if given, or until it is
+    /// revoked.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn create_invite(&self, expires_at_ns: Option<i64>) -> Result<Vec<u8>, FfiError> {
+        let invite = self.inner.create_invite(expires_at_ns).await?;
+        Ok(invite.encode_to_vec())
+    }
+
+    /// The group's invites that still admit joiners.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn invites(&self) -> Result<Vec<FfiGroupInvite>, FfiError> {
+        Ok(self.inner.invites()?.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn revoke_invite(&self, external_group_id: Vec<u8>) -> Result<(), FfiError> {
+        self.inner.revoke_invite(&external_group_id).await?;
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn update_conversation_message_disappearing_settings(
         &self,
@@ -3170,6 +3555,34 @@ impl FfiConversation {
             .map_err(Into::into)
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn moderator_list(&self) -> Result<Vec<String>, FfiError> {
+        self.inner.moderator_list().map_err(Into::into)
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub fn is_moderator(&self, inbox_id: &String) -> Result<bool, FfiError> {
+        self.inner
+            .is_moderator(inbox_id.clone())
+            .map_err(Into::into)
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn add_moderator(&self, inbox_id: String) -> Result<(), FfiError> {
+        self.inner
+            .update_moderator_list(UpdateModeratorListType::Add, inbox_id)
+            .await
+            .map_err(Into::into)
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn remove_moderator(&self, inbox_id: String) -> Result<(), FfiError> {
+        self.inner
+            .update_moderator_list(UpdateModeratorListType::Remove, inbox_id)
+            .await
+            .map_err(Into::into)
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     pub fn group_permissions(&self) -> Result<Arc<FfiGroupPermissions>, FfiError> {
         let permissions = self.inner.permissions()?;
@@ -3210,6 +3623,24 @@ impl FfiConversation {
         FfiStreamCloser::new(handle)
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn stream_ephemeral(
+        &self,
+        callback: Arc<dyn FfiEphemeralMessageCallback>,
+    ) -> FfiStreamCloser {
+        let close_cb = callback.clone();
+        let handle = MlsGroup::stream_ephemeral_with_callback(
+            self.inner.context.clone(),
+            self.inner.group_id,
+            move |message| match message {
+                Ok(m) => callback.on_ephemeral_message(m.into()),
+                Err(e) => callback.on_error(e.into()),
+            },
+            move || close_cb.on_close(),
+        );
+        FfiStreamCloser::new(handle)
+    }
+
     pub fn created_at_ns(&self) -> i64 {
         self.inner.created_at_ns
     }
@@ -3388,6 +3819,26 @@ impl From<StoredGroupMessageWithReactions> for FfiMessageWithReactions {
     }
 }
 
+/// A message matching a full-text search. Matched terms in `snippet` are wrapped in
+/// `<mark>` and `</mark>`.
+#[derive(uniffi::Record)]
+pub struct FfiMessageSearchHit {
+    pub message: FfiMessage,
+    /// BM25 relevance of the hit. Lower is more relevant.
+    pub rank: f64,
+    pub snippet: String,
+}
+
+impl From<MessageSearchHit> for FfiMessageSearchHit {
+    fn from(hit: MessageSearchHit) -> Self {
+        Self {
+            message: hit.message.into(),
+            rank: hit.rank,
+            snippet: hit.snippet,
+        }
+    }
+}
+
 #[uniffi::export]
 #[tracing::instrument(skip_all)]
 pub fn encode_reaction(reaction: FfiReactionPayload) -> Result<Vec<u8>, FfiError> {
@@ -3719,6 +4170,91 @@ pub fn decode_delete_message(bytes: Vec<u8>) -> Result<FfiDeleteMessage, FfiErro
         .map_err(|e| FfiError::generic(e.to_string()))
 }
 
+// EditMessage FFI encode function
+#[uniffi::export]
+#[tracing::instrument(skip_all)]
+pub fn encode_edit_message(request: FfiEditMessage) -> Result<Vec<u8>, FfiError> {
+    let edit_message: EditMessage = request.into();
+
+    let encoded =
+        EditMessageCodec::encode(edit_message).map_err(|e| FfiError::generic(e.to_string()))?;
+
+    let mut buf = Vec::new();
+    encoded
+        .encode(&mut buf)
+        .map_err(|e| FfiError::generic(e.to_string()))?;
+
+    Ok(buf)
+}
+
+// EditMessage FFI decode function
+#[uniffi::export]
+#[tracing::instrument(skip_all)]
+pub fn decode_edit_message(bytes: Vec<u8>) -> Result<FfiEditMessage, FfiError> {
+    let encoded_content =
+        EncodedContent::decode(bytes.as_slice()).map_err(|e| FfiError::generic(e.to_string()))?;
+
+    let edit_message =
+        EditMessageCodec::decode(encoded_content).map_err(|e| FfiError::generic(e.to_string()))?;
+    Ok(edit_message.try_into()?)
+}
+
+// Poll FFI encode function
+#[uniffi::export]
+#[tracing::instrument(skip_all)]
+pub fn encode_poll(poll: FfiPoll) -> Result<Vec<u8>, FfiError> {
+    let poll: Poll = poll.into();
+
+    let encoded = PollCodec::encode(poll).map_err(|e| FfiError::generic(e.to_string()))?;
+
+    let mut buf = Vec::new();
+    encoded
+        .encode(&mut buf)
+        .map_err(|e| FfiError::generic(e.to_string()))?;
+
+    Ok(buf)
+}
+
+// Poll FFI decode function
+#[uniffi::export]
+#[tracing::instrument(skip_all)]
+pub fn decode_poll(bytes: Vec<u8>) -> Result<FfiPoll, FfiError> {
+    let encoded_content =
+        EncodedContent::decode(bytes.as_slice()).map_err(|e| FfiError::generic(e.to_string()))?;
+
+    PollCodec::decode(encoded_content)
+        .map(Into::into)
+        .map_err(|e| FfiError::generic(e.to_string()))
+}
+
+// PollVote FFI encode function
+#[uniffi::export]
+#[tracing::instrument(skip_all)]
+pub fn encode_poll_vote(vote: FfiPollVote) -> Result<Vec<u8>, FfiError> {
+    let vote: PollVote = vote.into();
+
+    let encoded = PollVoteCodec::encode(vote).map_err(|e| FfiError::generic(e.to_string()))?;
+
+    let mut buf = Vec::new();
+    encoded
+        .encode(&mut buf)
+        .map_err(|e| FfiError::generic(e.to_string()))?;
+
+    Ok(buf)
+}
+
+// PollVote FFI decode function
+#[uniffi::export]
+#[tracing::instrument(skip_all)]
+pub fn decode_poll_vote(bytes: Vec<u8>) -> Result<FfiPollVote, FfiError> {
+    let encoded_content =
+        EncodedContent::decode(bytes.as_slice()).map_err(|e| FfiError::generic(e.to_string()))?;
+
+    PollVoteCodec::decode(encoded_content)
+        .map(Into::into)
+        .map_err(|e| FfiError::generic(e.to_string()))
+}
+
 #[uniffi::export]
 #[tracing::instrument(skip_all)]
 pub fn decode_group_updated(bytes: Vec<u8>) -> Result<FfiGroupUpdated, FfiError> {
@@ -3814,6 +4350,8 @@ pub struct FfiMessage {
     pub originator_id: u32,
     pub inserted_at_ns: i64,
     pub expire_at_ns: Option<i64>,
+    /// Deleted on this installation once passed to `mark_viewed`.
+    pub view_once: bool,
 }
 
 impl From<StoredGroupMessage> for FfiMessage {
@@ -3830,6 +4368,57 @@ impl From<StoredGroupMessage> for FfiMessage {
             originator_id: msg.originator_id as u32,
             inserted_at_ns: msg.inserted_at_ns,
             expire_at_ns: msg.expire_at_ns,
+            view_once: msg.view_once,
+        }
+    }
+}
+
+#[derive(uniffi::Record, Clone)]
+pub struct FfiScheduledMessage {
+    pub publish_at_ns: i64,
+    pub message: FfiMessage,
+}
+
+impl From<ScheduledMessage> for FfiScheduledMessage {
+    fn from(scheduled: ScheduledMessage) -> Self {
+        Self {
+            publish_at_ns: scheduled.publish_at_ns,
+            message: scheduled.message.into(),
+        }
+    }
+}
+
+#[derive(uniffi::Record, Clone)]
+pub struct FfiGroupInvite {
+    pub external_group_id: Vec<u8>,
+    /// `0` if the invite does not expire.
+    pub expires_at_ns: u64,
+    pub created_by_inbox_id: String,
+}
+
+impl From<GroupInvite> for FfiGroupInvite {
+    fn from(invite: GroupInvite) -> Self {
+        Self {
+            external_group_id: invite.policy.external_group_id,
+            expires_at_ns: invite.policy.expires_at_ns,
+            created_by_inbox_id: invite.created_by_inbox_id,
+        }
+    }
+}
+
+#[derive(uniffi::Record, Clone)]
+pub struct FfiDraft {
+    pub content: FfiEncodedContent,
+    pub reply_to_message_id: Option<Vec<u8>>,
+    pub updated_at_ns: i64,
+}
+
+impl From<Draft> for FfiDraft {
+    fn from(draft: Draft) -> Self {
+        Self {
+            content: draft.content.into(),
+            reply_to_message_id: draft.reply_to_message_id,
+            updated_at_ns: draft.updated_at_ns,
         }
     }
 }
@@ -4003,9 +4592,70 @@ pub trait FfiMessageDeletionCallback: Send + Sync {
     fn on_message_deleted(&self, message: Arc<FfiDecodedMessage>);
 }
 
+#[uniffi::export(with_foreign)]
+pub trait FfiEphemeralMessageCallback: Send + Sync {
+    fn on_ephemeral_message(&self, message: FfiEphemeralMessage);
+    fn on_error(&self, error: FfiError);
+    fn on_close(&self);
+}
+
+/// A short-lived signal, such as a typing indicator, that is never stored.
+#[derive(uniffi::Record, Debug, Clone)]
+pub struct FfiEphemeralMessage {
+    pub conversation_id: Vec<u8>,
+    pub sender_inbox_id: String,
+    pub sender_installation_id: Vec<u8>,
+    pub content: Vec<u8>,
+    pub sent_at_ns: i64,
+    pub expires_at_ns: i64,
+}
+
+impl From<EphemeralMessage> for FfiEphemeralMessage {
+    fn from(message: EphemeralMessage) -> Self {
+        Self {
+            conversation_id: message.group_id.to_vec(),
+            sender_inbox_id: message.sender_inbox_id,
+            sender_installation_id: message.sender_installation_id,
+            content: message.content,
+            sent_at_ns: message.sent_at_ns,
+            expires_at_ns: message.expires_at_ns,
+        }
+    }
+}
+
 #[derive(uniffi::Enum, Debug)]
 pub enum FfiPreferenceUpdate {
-    HMAC { key: Vec<u8> },
+    HMAC {
+        key: Vec<u8>,
+    },
+    ReadCursor {
+        conversation_id: Vec<u8>,
+        message_id: Vec<u8>,
+    },
+    ConversationSettings {
+        settings: FfiConversationSettings,
+    },
+}
+
+/// How the user has organized a conversation in their inbox.
+#[derive(uniffi::Record, Debug, Clone, PartialEq)]
+pub struct FfiConversationSettings {
+    pub conversation_id: Vec<u8>,
+    /// Notifications are muted until this time. `None` when not muted.
+    pub muted_until_ns: Option<i64>,
+    pub archived: bool,
+    pub pinned: bool,
+}
+
+impl From<StoredConversationSettings> for FfiConversationSettings {
+    fn from(settings: StoredConversationSettings) -> Self {
+        Self {
+            conversation_id: settings.group_id.to_vec(),
+            muted_until_ns: settings.muted_until_ns,
+            archived: settings.archived,
+            pinned: settings.pinned,
+        }
+    }
 }
 
 #[derive(uniffi::Object)]
@@ -1,13 +1,13 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{FfiError, FfiGroupSyncSummary, FfiXmtpClient};
+use crate::{FfiConsentState, FfiError, FfiGroupSyncSummary, FfiXmtpClient};
 use xmtp_id::associations::DeserializationError;
 use xmtp_mls::worker::device_sync::{
     ArchiveOptions, AvailableArchive, BackupElementSelection, DeviceSyncError,
     archive::{
-        ArchiveImporter, BACKUP_VERSION, BackupMetadata, ENC_KEY_SIZE, exporter::ArchiveExporter,
-        insert_importer,
+        ArchiveImporter, ArchiveReport, BACKUP_VERSION, BackupMetadata, ENC_KEY_SIZE,
+        RAW_KEY_BACKUP_VERSION, exporter::ArchiveExporter, insert_importer, insert_importer_chain,
     },
 };
 use xmtp_proto::xmtp::device_sync::BackupElementSelection as BackupElementSelectionProto;
@@ -85,6 +85,83 @@ impl FfiXmtpClient {
             .await
             .map_err(DeviceSyncError::Archive)?;
 
+        Ok(BackupMetadata::from_metadata_save(metadata, RAW_KEY_BACKUP_VERSION).into())
+    }
+
+    /// Archive application elements to file, encrypted with a key derived from `passphrase`.
+    /// Restore it with `import_archive_with_passphrase`.
+    #[xmtp_common::err_span]
+    pub async fn create_archive_with_passphrase(
+        &self,
+        path: String,
+        opts: FfiArchiveOptions,
+        passphrase: String,
+    ) -> Result<FfiBackupMetadata, FfiError> {
+        let db = self.inner_client.context.db();
+        let options: ArchiveOptions = opts.into();
+        let metadata =
+            ArchiveExporter::export_to_file_with_passphrase(options, db, path, &passphrase)
+                .await
+                .map_err(DeviceSyncError::Archive)?;
+
+        Ok(BackupMetadata::from_metadata_save(metadata, BACKUP_VERSION).into())
+    }
+
+    /// Archive only what changed since the archive at `previous_path` was created.
+    /// The new archive must be imported after the previous one.
+    #[xmtp_common::err_span]
+    pub async fn create_incremental_archive(
+        &self,
+        path: String,
+        previous_path: String,
+        opts: FfiArchiveOptions,
+        key: Vec<u8>,
+    ) -> Result<FfiBackupMetadata, FfiError> {
+        let key = check_key(key)?;
+        let previous = ArchiveImporter::from_file(previous_path, &key)
+            .await
+            .map_err(DeviceSyncError::Archive)?;
+        let db = self.inner_client.context.db();
+        let options: ArchiveOptions = opts.into();
+        let metadata = ArchiveExporter::export_incremental_to_file(
+            options,
+            &previous.metadata,
+            db,
+            path,
+            &key,
+        )
+        .await
+        .map_err(DeviceSyncError::Archive)?;
+
+        Ok(BackupMetadata::from_metadata_save(metadata, RAW_KEY_BACKUP_VERSION).into())
+    }
+
+    /// Archive only what changed since the passphrase archive at `previous_path` was created,
+    /// encrypted with a key derived from `passphrase`.
+    /// The new archive must be imported after the previous one.
+    #[xmtp_common::err_span]
+    pub async fn create_incremental_archive_with_passphrase(
+        &self,
+        path: String,
+        previous_path: String,
+        opts: FfiArchiveOptions,
+        passphrase: String,
+    ) -> Result<FfiBackupMetadata, FfiError> {
+        let previous = ArchiveImporter::from_file_with_passphrase(previous_path, &passphrase)
+            .await
+            .map_err(DeviceSyncError::Archive)?;
+        let db = self.inner_client.context.db();
+        let options: ArchiveOptions = opts.into();
+        let metadata = ArchiveExporter::export_incremental_to_file_with_passphrase(
+            options,
+            &previous.metadata,
+            db,
+            path,
+            &passphrase,
+        )
+        .await
+        .map_err(DeviceSyncError::Archive)?;
+
         Ok(BackupMetadata::from_metadata_save(metadata, BACKUP_VERSION).into())
     }
 
@@ -99,6 +176,64 @@ impl FfiXmtpClient {
         Ok(())
     }
 
+    /// Import an archive that was created with a passphrase.
+    #[xmtp_common::err_span]
+    pub async fn import_archive_with_passphrase(
+        &self,
+        path: String,
+        passphrase: String,
+    ) -> Result<(), FfiError> {
+        let mut importer = ArchiveImporter::from_file_with_passphrase(path, &passphrase)
+            .await
+            .map_err(DeviceSyncError::Archive)?;
+        insert_importer(&mut importer, &self.inner_client.context).await?;
+
+        Ok(())
+    }
+
+    /// Import a full archive followed by the incremental archives chained to it, in order.
+    /// Fails without importing anything if the chain has a gap or is out of order.
+    #[xmtp_common::err_span]
+    pub async fn import_archive_chain(
+        &self,
+        paths: Vec<String>,
+        key: Vec<u8>,
+    ) -> Result<(), FfiError> {
+        let key = check_key(key)?;
+        let mut importers = Vec::with_capacity(paths.len());
+        for path in paths {
+            importers.push(
+                ArchiveImporter::from_file(path, &key)
+                    .await
+                    .map_err(DeviceSyncError::Archive)?,
+            );
+        }
+        insert_importer_chain(&mut importers, None, &self.inner_client.context).await?;
+
+        Ok(())
+    }
+
+    /// Import a full passphrase archive followed by the incremental archives chained to it,
+    /// in order. Fails without importing anything if the chain has a gap or is out of order.
+    #[xmtp_common::err_span]
+    pub async fn import_archive_chain_with_passphrase(
+        &self,
+        paths: Vec<String>,
+        passphrase: String,
+    ) -> Result<(), FfiError> {
+        let mut importers = Vec::with_capacity(paths.len());
+        for path in paths {
+            importers.push(
+                ArchiveImporter::from_file_with_passphrase(path, &passphrase)
+                    .await
+                    .map_err(DeviceSyncError::Archive)?,
+            );
+        }
+        insert_importer_chain(&mut importers, None, &self.inner_client.context).await?;
+
+        Ok(())
+    }
+
     /// Load the metadata for an archive to see what it contains.
     /// Reads only the metadata without loading the entire file, so this function is quick.
     #[xmtp_common::err_span]
@@ -113,6 +248,20 @@ impl FfiXmtpClient {
         Ok(importer.metadata.into())
     }
 
+    /// Read through a whole archive without importing it,
+    /// to check that it's intact before restoring from it.
+    #[xmtp_common::err_span]
+    pub async fn verify_archive(
+        &self,
+        path: String,
+        key: Vec<u8>,
+    ) -> Result<FfiArchiveReport, FfiError> {
+        let importer = ArchiveImporter::from_file(path, &check_key(key)?)
+            .await
+            .map_err(DeviceSyncError::Archive)?;
+        Ok(importer.verify().await.into())
+    }
+
     /// Manually sync all device sync groups.
     #[xmtp_common::err_span]
     pub async fn sync_all_device_sync_groups(&self) -> Result<FfiGroupSyncSummary, FfiError> {
@@ -128,6 +277,15 @@ pub struct FfiArchiveOptions {
     pub end_ns: Option<i64>,
     pub elements: Vec<FfiBackupElementSelection>,
     pub exclude_disappearing_messages: bool,
+    /// Only export these conversations. Empty exports all of them.
+    #[uniffi(default = [])]
+    pub include_group_ids: Vec<Vec<u8>>,
+    /// Never export these conversations.
+    #[uniffi(default = [])]
+    pub exclude_group_ids: Vec<Vec<u8>>,
+    /// Only export conversations with one of these consent states. Empty exports all of them.
+    #[uniffi(default = [])]
+    pub consent_states: Vec<FfiConsentState>,
 }
 impl Default for FfiArchiveOptions {
     fn default() -> Self {
@@ -139,6 +297,9 @@ impl Default for FfiArchiveOptions {
             end_ns: None,
             start_ns: None,
             exclude_disappearing_messages: false,
+            include_group_ids: vec![],
+            exclude_group_ids: vec![],
+            consent_states: vec![],
         }
     }
 }
@@ -149,6 +310,12 @@ impl From<FfiArchiveOptions> for ArchiveOptions {
             end_ns: value.end_ns,
             elements: value.elements.into_iter().map(|el| el.into()).collect(),
             exclude_disappearing_messages: value.exclude_disappearing_messages,
+            include_group_ids: value.include_group_ids,
+            exclude_group_ids: value.exclude_group_ids,
+            consent_states: value.consent_states.into_iter().map(Into::into).collect(),
+            archive_id: value.archive_id,
+            previous_archive_id: value.previous_archive_id,
+            since_ns: value.since_ns,
         }
     }
 }
@@ -157,11 +324,13 @@ impl From<FfiArchiveOptions> for ArchiveOptions {
 pub enum FfiBackupElementSelection {
     Messages,
     Consent,
+    Drafts,
 }
 impl From<FfiBackupElementSelection> for BackupElementSelection {
     fn from(value: FfiBackupElementSelection) -> Self {
         match value {
             FfiBackupElementSelection::Consent => Self::Consent,
+            FfiBackupElementSelection::Drafts => Self::Drafts,
             FfiBackupElementSelection::Messages => Self::Messages,
         }
     }
@@ -171,6 +340,7 @@ impl From<FfiBackupElementSelection> for BackupElementSelectionProto {
     fn from(value: FfiBackupElementSelection) -> Self {
         match value {
             FfiBackupElementSelection::Consent => Self::Consent,
+            FfiBackupElementSelection::Drafts => Self::Drafts,
             FfiBackupElementSelection::Messages => Self::Messages,
         }
     }
@@ -181,6 +351,7 @@ impl TryFrom<BackupElementSelection> for FfiBackupElementSelection {
     fn try_from(value: BackupElementSelection) -> Result<Self, Self::Error> {
         let v = match value {
             BackupElementSelection::Consent => Self::Consent,
+            BackupElementSelection::Drafts => Self::Drafts,
             BackupElementSelection::Messages => Self::Messages,
             _ => {
                 return Err(DeserializationError::Unspecified(
@@ -197,6 +368,7 @@ impl TryFrom<BackupElementSelectionProto> for FfiBackupElementSelection {
     fn try_from(value: BackupElementSelectionProto) -> Result<Self, Self::Error> {
         let v = match value {
             BackupElementSelectionProto::Consent => Self::Consent,
+            BackupElementSelectionProto::Drafts => Self::Drafts,
             BackupElementSelectionProto::Messages => Self::Messages,
             _ => {
                 return Err(DeserializationError::Unspecified(
@@ -226,6 +398,12 @@ pub struct FfiBackupMetadata {
     exported_at_ns: i64,
     start_ns: Option<i64>,
     end_ns: Option<i64>,
+    include_group_ids: Vec<Vec<u8>>,
+    exclude_group_ids: Vec<Vec<u8>>,
+    consent_states: Vec<FfiConsentState>,
+    archive_id: Vec<u8>,
+    previous_archive_id: Option<Vec<u8>>,
+    since_ns: Option<i64>,
 }
 impl From<BackupMetadata> for FfiBackupMetadata {
     fn from(value: BackupMetadata) -> Self {
@@ -239,6 +417,38 @@ impl From<BackupMetadata> for FfiBackupMetadata {
             start_ns: value.start_ns,
             end_ns: value.end_ns,
             exported_at_ns: value.exported_at_ns,
+            include_group_ids: value.include_group_ids,
+            exclude_group_ids: value.exclude_group_ids,
+            consent_states: value.consent_states.into_iter().map(Into::into).collect(),
+            archive_id: value.archive_id,
+            previous_archive_id: value.previous_archive_id,
+            since_ns: value.since_ns,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiArchiveReport {
+    metadata: FfiBackupMetadata,
+    group_count: u64,
+    group_message_count: u64,
+    consent_count: u64,
+    earliest_message_ns: Option<i64>,
+    latest_message_ns: Option<i64>,
+    manifest_verified: bool,
+    failures: Vec<String>,
+}
+impl From<ArchiveReport> for FfiArchiveReport {
+    fn from(value: ArchiveReport) -> Self {
+        Self {
+            metadata: value.metadata.into(),
+            group_count: value.group_count,
+            group_message_count: value.group_message_count,
+            consent_count: value.consent_count,
+            earliest_message_ns: value.earliest_message_ns,
+            latest_message_ns: value.latest_message_ns,
+            manifest_verified: value.manifest_verified,
+            failures: value.failures,
         }
     }
 }
@@ -262,6 +472,7 @@ impl From<AvailableArchive> for FfiAvailableArchive {
 #[cfg(test)]
 mod unit_tests {
     use super::*;
+    use xmtp_db::consent_record::ConsentState;
 
     #[test]
     fn test_check_key_too_short() {
@@ -361,6 +572,9 @@ mod unit_tests {
                 FfiBackupElementSelection::Consent,
             ],
             exclude_disappearing_messages: true,
+            include_group_ids: vec![vec![1, 2]],
+            exclude_group_ids: vec![vec![3, 4]],
+            consent_states: vec![FfiConsentState::Allowed],
         };
 
         let backup_options: ArchiveOptions = ffi_options.into();
@@ -368,6 +582,9 @@ mod unit_tests {
         assert_eq!(backup_options.end_ns, Some(2000));
         assert_eq!(backup_options.elements.len(), 2);
         assert!(backup_options.exclude_disappearing_messages);
+        assert_eq!(backup_options.include_group_ids, vec![vec![1, 2]]);
+        assert_eq!(backup_options.exclude_group_ids, vec![vec![3, 4]]);
+        assert_eq!(backup_options.consent_states, vec![ConsentState::Allowed]);
     }
 
     #[test]
@@ -377,6 +594,9 @@ mod unit_tests {
             end_ns: None,
             elements: vec![],
             exclude_disappearing_messages: false,
+            include_group_ids: vec![],
+            exclude_group_ids: vec![],
+            consent_states: vec![],
         };
 
         let backup_options: ArchiveOptions = ffi_options.into();
@@ -397,6 +617,12 @@ mod unit_tests {
             exported_at_ns: 12345,
             start_ns: Some(100),
             end_ns: Some(200),
+            include_group_ids: vec![vec![1]],
+            exclude_group_ids: vec![],
+            consent_states: vec![ConsentState::Denied],
+            archive_id: vec![2],
+            previous_archive_id: Some(vec![1]),
+            since_ns: Some(50),
         };
 
         let ffi_metadata: FfiBackupMetadata = metadata.into();
@@ -405,6 +631,11 @@ mod unit_tests {
         assert_eq!(ffi_metadata.exported_at_ns, 12345);
         assert_eq!(ffi_metadata.start_ns, Some(100));
         assert_eq!(ffi_metadata.end_ns, Some(200));
+        assert_eq!(ffi_metadata.include_group_ids, vec![vec![1]]);
+        assert_eq!(ffi_metadata.consent_states, vec![FfiConsentState::Denied]);
+        assert_eq!(ffi_metadata.archive_id, vec![2]);
+        assert_eq!(ffi_metadata.previous_archive_id, Some(vec![1]));
+        assert_eq!(ffi_metadata.since_ns, Some(50));
     }
 
     #[test]
@@ -417,8 +648,7 @@ mod unit_tests {
                 BackupElementSelection::Consent,
             ],
             exported_at_ns: 12345,
-            start_ns: None,
-            end_ns: None,
+            ..Default::default()
         };
 
         let ffi_metadata: FfiBackupMetadata = metadata.into();
@@ -434,8 +664,7 @@ mod unit_tests {
                 backup_version: 1,
                 elements: vec![BackupElementSelection::Messages],
                 exported_at_ns: 12345,
-                start_ns: None,
-                end_ns: None,
+                ..Default::default()
             },
             sent_by_installation: vec![1, 2, 3, 4],
         };
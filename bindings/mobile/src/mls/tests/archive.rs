@@ -90,6 +90,7 @@ async fn test_archive_excludes_disappearing_messages() {
             end_ns: None,
             elements: vec![FfiBackupElementSelection::Messages],
             exclude_disappearing_messages: true,
+            ..Default::default()
         },
         archive_key.clone(),
     )
@@ -141,6 +142,7 @@ async fn test_archive_excludes_disappearing_messages() {
             end_ns: None,
             elements: vec![FfiBackupElementSelection::Messages],
             exclude_disappearing_messages: false,
+            ..Default::default()
         },
         archive_key2.clone(),
     )
@@ -1394,3 +1394,158 @@ async fn test_delete_message_encode_decode() {
     let result = decode_delete_message(invalid_bytes);
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_edit_message_encode_decode() {
+    let edited_content: FfiEncodedContent =
+        TextCodec::encode("fixed typo".to_string()).unwrap().into();
+    let ffi_edit_message = FfiEditMessage {
+        message_id: "test-message-id-123".to_string(),
+        edited_content: edited_content.clone(),
+    };
+    let encoded = encode_edit_message(ffi_edit_message).unwrap();
+    let decoded = decode_edit_message(encoded).unwrap();
+    assert_eq!(decoded.message_id, "test-message-id-123");
+    assert_eq!(decoded.edited_content, edited_content);
+
+    // Test decoding invalid bytes
+    let invalid_bytes = vec![0xFF, 0xFF, 0xFF, 0xFF];
+    let result = decode_edit_message(invalid_bytes);
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 5)]
+async fn test_can_edit_message() {
+    let alix = new_test_client().await;
+    let bo = new_test_client().await;
+
+    let alix_conversation = alix
+        .conversations()
+        .create_group_by_identity(
+            vec![bo.account_identifier.clone()],
+            FfiCreateGroupOptions::default(),
+        )
+        .await
+        .unwrap();
+    let message_id = alix_conversation
+        .send(
+            encode_text("helo".to_string()).unwrap(),
+            FfiSendMessageOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let edited_content: FfiEncodedContent = TextCodec::encode("hello".to_string()).unwrap().into();
+    alix_conversation
+        .edit_message(message_id.clone(), edited_content)
+        .unwrap();
+    alix_conversation.publish_messages().await.unwrap();
+
+    bo.conversations().sync().await.unwrap();
+    let bo_conversation = bo.conversation(alix_conversation.id()).unwrap();
+    bo_conversation.sync().await.unwrap();
+
+    let messages = bo_conversation
+        .find_enriched_messages(FfiListMessagesOptions::default())
+        .unwrap();
+    let edited = messages
+        .iter()
+        .find(|message| message.id() == message_id)
+        .unwrap();
+    assert!(edited.is_edited());
+    assert_eq!(edited.edit_history().len(), 2);
+    match edited.content() {
+        FfiDecodedMessageContent::Text(text) => assert_eq!(text.content, "hello"),
+        other => panic!("Expected text content, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_poll_encode_decode() {
+    let poll = FfiPoll {
+        question: "Lunch?".to_string(),
+        options: vec!["Pizza".to_string(), "Tacos".to_string()],
+        allows_multiple_choices: false,
+    };
+    let decoded = decode_poll(encode_poll(poll).unwrap()).unwrap();
+    assert_eq!(decoded.question, "Lunch?");
+    assert_eq!(decoded.options, vec!["Pizza", "Tacos"]);
+    assert!(!decoded.allows_multiple_choices);
+
+    let single_option = FfiPoll {
+        question: "Lunch?".to_string(),
+        options: vec!["Pizza".to_string()],
+        allows_multiple_choices: false,
+    };
+    assert!(encode_poll(single_option).is_err());
+
+    let vote = FfiPollVote {
+        poll_message_id: "test-message-id-123".to_string(),
+        option_indexes: vec![1],
+    };
+    let decoded = decode_poll_vote(encode_poll_vote(vote).unwrap()).unwrap();
+    assert_eq!(decoded.poll_message_id, "test-message-id-123");
+    assert_eq!(decoded.option_indexes, vec![1]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 5)]
+async fn test_poll_votes_are_tallied() {
+    let alix = new_test_client().await;
+    let bo = new_test_client().await;
+
+    let alix_conversation = alix
+        .conversations()
+        .create_group_by_identity(
+            vec![bo.account_identifier.clone()],
+            FfiCreateGroupOptions::default(),
+        )
+        .await
+        .unwrap();
+    let poll_id = alix_conversation
+        .send(
+            encode_poll(FfiPoll {
+                question: "Lunch?".to_string(),
+                options: vec!["Pizza".to_string(), "Tacos".to_string()],
+                allows_multiple_choices: false,
+            })
+            .unwrap(),
+            FfiSendMessageOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    bo.conversations().sync().await.unwrap();
+    let bo_conversation = bo.conversation(alix_conversation.id()).unwrap();
+    bo_conversation.sync().await.unwrap();
+    for option in [0, 1] {
+        bo_conversation
+            .send(
+                encode_poll_vote(FfiPollVote {
+                    poll_message_id: hex::encode(&poll_id),
+                    option_indexes: vec![option],
+                })
+                .unwrap(),
+                FfiSendMessageOpts::default(),
+            )
+            .await
+            .unwrap();
+    }
+
+    alix_conversation.sync().await.unwrap();
+    let messages = alix_conversation
+        .find_enriched_messages(FfiListMessagesOptions::default())
+        .unwrap();
+    let poll = messages
+        .iter()
+        .find(|message| message.id() == poll_id)
+        .unwrap();
+    match poll.content() {
+        FfiDecodedMessageContent::Poll(poll) => {
+            let counts: Vec<_> = poll.options.iter().map(|o| o.vote_count).collect();
+            assert_eq!(counts, vec![0, 1]);
+            assert_eq!(poll.total_voters, 1);
+            assert!(poll.my_vote.is_empty());
+        }
+        other => panic!("Expected poll content, got {:?}", other),
+    }
+}
@@ -17,18 +17,20 @@ use crate::{
     FfiReactionPayload, FfiReactionSchema, FfiReadReceipt, FfiRemoteAttachment, FfiReply,
     FfiSendMessageOpts, FfiSignatureKind, FfiTransactionReference, GenericError,
     apply_signature_request, connect_to_backend, decode_actions, decode_attachment,
-    decode_delete_message, decode_group_updated, decode_intent, decode_leave_request,
-    decode_multi_remote_attachment, decode_reaction, decode_read_receipt, decode_remote_attachment,
-    decode_reply, decode_text, decode_transaction_reference, encode_actions, encode_attachment,
-    encode_delete_message, encode_intent, encode_leave_request, encode_multi_remote_attachment,
-    encode_reaction, encode_read_receipt, encode_remote_attachment, encode_reply, encode_text,
-    encode_transaction_reference, get_inbox_id_for_identifier, get_newest_message_metadata,
+    decode_delete_message, decode_edit_message, decode_group_updated, decode_intent,
+    decode_leave_request, decode_multi_remote_attachment, decode_poll, decode_poll_vote,
+    decode_reaction, decode_read_receipt, decode_remote_attachment, decode_reply, decode_text,
+    decode_transaction_reference, encode_actions, encode_attachment, encode_delete_message,
+    encode_edit_message, encode_intent, encode_leave_request, encode_multi_remote_attachment,
+    encode_poll, encode_poll_vote, encode_reaction, encode_read_receipt, encode_remote_attachment,
+    encode_reply, encode_text, encode_transaction_reference, get_inbox_id_for_identifier,
+    get_newest_message_metadata,
     identity::FfiIdentifier,
     inbox_owner::FfiInboxOwner,
     inbox_state_from_inbox_ids, is_connected,
     message::{
-        FfiDeleteMessage, FfiEncodedContent, FfiGroupUpdated, FfiInbox, FfiLeaveRequest,
-        FfiMetadataFieldChange, FfiTransactionMetadata,
+        FfiDeleteMessage, FfiEditMessage, FfiEncodedContent, FfiGroupUpdated, FfiInbox,
+        FfiLeaveRequest, FfiMetadataFieldChange, FfiPoll, FfiPollVote, FfiTransactionMetadata,
     },
     mls::{
         inbox_owner::FfiWalletInboxOwner,
@@ -125,6 +125,10 @@ pub struct DbOptions {
   /// (one file descriptor). Pool-size options are ignored. Intended for
   /// services running many clients in one process.
   pub use_single_connection: Option<bool>,
+  /// A second 32 byte key to open the database with if `encryptionKey` is
+  /// incorrect, such as the other key of an interrupted `Client.rekeyDatabase`.
+  /// See `Client.databaseOpenedWithFallbackKey`.
+  pub fallback_encryption_key: Option<Uint8Array>,
 }
 
 impl DbOptions {
@@ -141,6 +145,7 @@ impl DbOptions {
       max_db_pool_size,
       min_db_pool_size,
       use_single_connection,
+      fallback_encryption_key: None,
     }
   }
 }
@@ -152,9 +157,18 @@ fn build_store(db: DbOptions) -> Result<EncryptedMessageStore<NativeDb>> {
     max_db_pool_size,
     min_db_pool_size,
     use_single_connection,
+    fallback_encryption_key,
   } = db;
 
   let single = use_single_connection.unwrap_or(false);
+  let fallback_key: Option<EncryptionKey> = fallback_encryption_key
+    .map(|key| {
+      let key: Vec<u8> = key.deref().into();
+      key
+        .try_into()
+        .map_err(|_| Error::from_reason("Malformed 32 byte fallback encryption key"))
+    })
+    .transpose()?;
 
   let base = if let Some(path) = db_path {
     NativeDb::builder().persistent(path)
@@ -176,7 +190,7 @@ fn build_store(db: DbOptions) -> Result<EncryptedMessageStore<NativeDb>> {
       let key: EncryptionKey = key
         .try_into()
         .map_err(|_| Error::from_reason("Malformed 32 byte encryption key"))?;
-      b.key(key).build()
+      b.key(key).maybe_fallback_key(fallback_key).build()
     } else {
       b.build_unencrypted()
     }
@@ -189,7 +203,7 @@ fn build_store(db: DbOptions) -> Result<EncryptedMessageStore<NativeDb>> {
       let key: EncryptionKey = key
         .try_into()
         .map_err(|_| Error::from_reason("Malformed 32 byte encryption key"))?;
-      b.key(key).build()
+      b.key(key).maybe_fallback_key(fallback_key).build()
     } else {
       b.build_unencrypted()
     }
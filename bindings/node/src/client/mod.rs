@@ -2,10 +2,12 @@ use crate::ErrorWrapper;
 use crate::conversations::Conversations;
 use crate::device_sync::DeviceSync;
 use crate::identity::{Identifier, IdentityExt};
-use napi::bindgen_prelude::{Error, Result};
+use napi::bindgen_prelude::{Error, Result, Uint8Array};
 use napi_derive::napi;
 use std::collections::HashMap;
+use std::ops::Deref;
 use std::sync::Arc;
+use xmtp_db::EncryptionKey;
 use xmtp_mls::Client as MlsClient;
 use xmtp_mls::groups::MlsGroup;
 
@@ -101,6 +103,51 @@ impl Client {
     Ok(())
   }
 
+  /// Re-encrypt the database with a new 32 byte key.
+  ///
+  /// If the process is killed during the swap, the database is left encrypted with either key.
+  /// Keep the old key until this resolves, and pass the other key as
+  /// `DbOptions.fallbackEncryptionKey` when creating the client;
+  /// `databaseOpenedWithFallbackKey` then tells which one the database uses.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn rekey_database(&self, new_key: Uint8Array) -> Result<()> {
+    let key: Vec<u8> = new_key.deref().into();
+    let key: EncryptionKey = key
+      .try_into()
+      .map_err(|_| Error::from_reason("Malformed 32 byte encryption key"))?;
+    // copies the whole database and waits for connections in use, so keep it off the runtime
+    let store = self.inner_client.context.store().clone();
+    tokio::task::spawn_blocking(move || store.rekey(key))
+      .await
+      .map_err(|e| Error::from_reason(e.to_string()))?
+      .map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  /// Encrypt a database that was created without an encryption key with a 32 byte key.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn encrypt_database(&self, key: Uint8Array) -> Result<()> {
+    let key: Vec<u8> = key.deref().into();
+    let key: EncryptionKey = key
+      .try_into()
+      .map_err(|_| Error::from_reason("Malformed 32 byte encryption key"))?;
+    let store = self.inner_client.context.store().clone();
+    tokio::task::spawn_blocking(move || store.encrypt_in_place(key))
+      .await
+      .map_err(|e| Error::from_reason(e.to_string()))?
+      .map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  /// Whether the database was opened with `DbOptions.fallbackEncryptionKey` because
+  /// `encryptionKey` was incorrect.
+  #[napi]
+  pub fn database_opened_with_fallback_key(&self) -> bool {
+    self.inner_client.context.store().opened_with_fallback_key()
+  }
+
   /// Cleanly shut down this client: cancel in-flight workers and streams, then
   /// release the DB connection. Idempotent — a second call resolves to `Ok`.
   ///
@@ -5,6 +5,7 @@ use super::group_updated::GroupUpdated;
 use super::intent::Intent;
 use super::leave_request::LeaveRequest;
 use super::multi_remote_attachment::MultiRemoteAttachment;
+use super::poll::EnrichedPoll;
 use super::reaction::Reaction;
 use super::read_receipt::ReadReceipt;
 use super::remote_attachment::RemoteAttachment;
@@ -27,6 +28,7 @@ pub enum DecodedMessageContentType {
   LeaveRequest,
   Markdown,
   MultiRemoteAttachment,
+  Poll,
   Reaction,
   ReadReceipt,
   RemoteAttachment,
@@ -47,6 +49,7 @@ pub enum DecodedMessageContentInner {
   LeaveRequest(LeaveRequest),
   Markdown(String),
   MultiRemoteAttachment(MultiRemoteAttachment),
+  Poll(EnrichedPoll),
   Reaction(Reaction),
   ReadReceipt(ReadReceipt),
   RemoteAttachment(RemoteAttachment),
@@ -78,6 +81,7 @@ impl DecodedMessageContent {
       DecodedMessageContentInner::MultiRemoteAttachment(_) => {
         DecodedMessageContentType::MultiRemoteAttachment
       }
+      DecodedMessageContentInner::Poll(_) => DecodedMessageContentType::Poll,
       DecodedMessageContentInner::Reaction(_) => DecodedMessageContentType::Reaction,
       DecodedMessageContentInner::ReadReceipt(_) => DecodedMessageContentType::ReadReceipt,
       DecodedMessageContentInner::RemoteAttachment(_) => {
@@ -149,6 +153,14 @@ impl DecodedMessageContent {
     }
   }
 
+  #[napi(getter)]
+  pub fn poll(&self) -> Option<EnrichedPoll> {
+    match &self.inner {
+      DecodedMessageContentInner::Poll(p) => Some(p.clone()),
+      _ => None,
+    }
+  }
+
   #[napi(getter)]
   pub fn transaction_reference(&self) -> Option<TransactionReference> {
     match &self.inner {
@@ -242,6 +254,7 @@ impl TryFrom<MessageBody> for DecodedMessageContent {
       MessageBody::GroupUpdated(gu) => DecodedMessageContentInner::GroupUpdated(gu.into()),
       MessageBody::ReadReceipt(rr) => DecodedMessageContentInner::ReadReceipt(rr.into()),
       MessageBody::LeaveRequest(lr) => DecodedMessageContentInner::LeaveRequest(lr.into()),
+      MessageBody::Poll(p) => DecodedMessageContentInner::Poll(p.into()),
       MessageBody::WalletSendCalls(wsc) => {
         DecodedMessageContentInner::WalletSendCalls(wsc.try_into()?)
       }
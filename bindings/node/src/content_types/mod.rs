@@ -10,6 +10,7 @@ pub mod intent;
 pub mod leave_request;
 pub mod markdown;
 pub mod multi_remote_attachment;
+pub mod poll;
 pub mod reaction;
 pub mod read_receipt;
 pub mod remote_attachment;
@@ -29,6 +30,7 @@ pub enum ContentType {
   LeaveRequest,
   Markdown,
   MultiRemoteAttachment,
+  Poll,
   Reaction,
   ReadReceipt,
   RemoteAttachment,
@@ -50,6 +52,7 @@ impl From<ContentType> for XmtpContentType {
       ContentType::LeaveRequest => XmtpContentType::LeaveRequest,
       ContentType::Markdown => XmtpContentType::Markdown,
       ContentType::MultiRemoteAttachment => XmtpContentType::MultiRemoteAttachment,
+      ContentType::Poll => XmtpContentType::Poll,
       ContentType::Text => XmtpContentType::Text,
       ContentType::Reaction => XmtpContentType::Reaction,
       ContentType::ReadReceipt => XmtpContentType::ReadReceipt,
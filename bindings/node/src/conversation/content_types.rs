@@ -36,6 +36,8 @@ impl SendMessageOpts {
       should_push,
       optimistic: opts.optimistic,
       idempotency_key: opts.idempotency_key,
+      expire_after_ns: None,
+      view_once: None,
     }
   }
 }
@@ -0,0 +1,65 @@
+use crate::{ErrorWrapper, conversation::Conversation};
+use napi::bindgen_prelude::{BigInt, Result};
+use napi_derive::napi;
+use xmtp_db::conversation_settings::StoredConversationSettings;
+
+/// How the user has organized a conversation in their inbox.
+#[napi(object)]
+#[derive(Clone)]
+pub struct ConversationSettings {
+  pub conversation_id: String,
+  /// Notifications are muted until this time. Not set when not muted.
+  pub muted_until_ns: Option<BigInt>,
+  pub archived: bool,
+  pub pinned: bool,
+}
+
+impl From<StoredConversationSettings> for ConversationSettings {
+  fn from(settings: StoredConversationSettings) -> Self {
+    Self {
+      conversation_id: hex::encode(settings.group_id),
+      muted_until_ns: settings.muted_until_ns.map(BigInt::from),
+      archived: settings.archived,
+      pinned: settings.pinned,
+    }
+  }
+}
+
+#[napi]
+impl Conversation {
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn conversation_settings(&self) -> Result<ConversationSettings> {
+    let group = self.create_mls_group();
+    let settings = group.conversation_settings().map_err(ErrorWrapper::from)?;
+    Ok(settings.into())
+  }
+
+  /// Mute notifications until `mutedUntilNs`, or unmute when not set.
+  /// Settings are synced to the user's other installations.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn set_muted_until(&self, muted_until_ns: Option<BigInt>) -> Result<()> {
+    let group = self.create_mls_group();
+    group
+      .set_muted_until(muted_until_ns.map(|ns| ns.get_i64().0))
+      .map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn set_archived(&self, archived: bool) -> Result<()> {
+    let group = self.create_mls_group();
+    group.set_archived(archived).map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn set_pinned(&self, pinned: bool) -> Result<()> {
+    let group = self.create_mls_group();
+    group.set_pinned(pinned).map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+}
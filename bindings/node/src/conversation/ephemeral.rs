@@ -0,0 +1,90 @@
+use crate::{
+  ErrorWrapper, conversation::Conversation, messages::encoded_content::EncodedContent,
+  streams::StreamCloser,
+};
+use napi::{
+  bindgen_prelude::{BigInt, Result},
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+};
+use napi_derive::napi;
+use prost::Message as ProstMessage;
+use xmtp_mls::groups::{MlsGroup, ephemeral::EphemeralMessage as XmtpEphemeralMessage};
+use xmtp_proto::xmtp::mls::message_contents::EncodedContent as XmtpEncodedContent;
+
+/// A short-lived signal, such as a typing indicator, that is never stored.
+#[napi(object)]
+pub struct EphemeralMessage {
+  pub conversation_id: String,
+  pub sender_inbox_id: String,
+  pub sender_installation_id: String,
+  pub content: EncodedContent,
+  pub sent_at_ns: BigInt,
+  pub expires_at_ns: BigInt,
+}
+
+impl TryFrom<XmtpEphemeralMessage> for EphemeralMessage {
+  type Error = napi::Error;
+  fn try_from(message: XmtpEphemeralMessage) -> Result<Self> {
+    let content = XmtpEncodedContent::decode(message.content.as_slice())
+      .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    Ok(Self {
+      conversation_id: hex::encode(message.group_id),
+      sender_inbox_id: message.sender_inbox_id,
+      sender_installation_id: hex::encode(message.sender_installation_id),
+      content: content.into(),
+      sent_at_ns: BigInt::from(message.sent_at_ns),
+      expires_at_ns: BigInt::from(message.expires_at_ns),
+    })
+  }
+}
+
+#[napi]
+impl Conversation {
+  /// Send a short-lived signal, such as a typing indicator. Ephemeral messages are
+  /// only delivered to live `streamEphemeral` listeners and are never stored.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn send_ephemeral(&self, encoded_content: EncodedContent) -> Result<()> {
+    let encoded_content: XmtpEncodedContent = encoded_content.into();
+    let group = self.create_mls_group();
+    group
+      .send_ephemeral(encoded_content.encode_to_vec().as_slice())
+      .await
+      .map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn stream_ephemeral(
+    &self,
+    callback: ThreadsafeFunction<EphemeralMessage, ()>,
+    on_close: ThreadsafeFunction<(), ()>,
+  ) -> Result<StreamCloser> {
+    let group = self.create_mls_group();
+    let on_message = move |message: std::result::Result<
+      XmtpEphemeralMessage,
+      xmtp_mls::subscriptions::SubscribeError,
+    >| {
+      let status = callback.call(
+        message
+          .map_err(ErrorWrapper::from)
+          .map_err(napi::Error::from)
+          .and_then(EphemeralMessage::try_from),
+        ThreadsafeFunctionCallMode::Blocking,
+      );
+      tracing::info!("Stream status: {:?}", status);
+    };
+    let on_close = move || {
+      on_close.call(Ok(()), ThreadsafeFunctionCallMode::Blocking);
+    };
+
+    let handle = MlsGroup::stream_ephemeral_with_callback(
+      group.context.clone(),
+      group.group_id,
+      on_message,
+      on_close,
+    );
+    Ok(StreamCloser::new(handle))
+  }
+}
@@ -11,7 +11,7 @@ use std::collections::HashMap;
 use xmtp_db::group::GroupMembershipState as XmtpGroupMembershipState;
 use xmtp_mls::groups::{
   UpdateAdminListType, UpdateGroupMembershipResult as XmtpUpdateGroupMembershipResult,
-  members::PermissionLevel as XmtpPermissionLevel,
+  members::PermissionLevel as XmtpPermissionLevel, moderators::UpdateModeratorListType,
 };
 
 #[napi]
@@ -240,6 +240,47 @@ impl Conversation {
     Ok(())
   }
 
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn list_moderators(&self) -> Result<Vec<String>> {
+    let group = self.create_mls_group();
+
+    let moderator_list = group.moderator_list().map_err(ErrorWrapper::from)?;
+
+    Ok(moderator_list)
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn is_moderator(&self, inbox_id: String) -> Result<bool> {
+    let moderator_list = self.list_moderators()?;
+    Ok(moderator_list.contains(&inbox_id))
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn add_moderator(&self, inbox_id: String) -> Result<()> {
+    let group = self.create_mls_group();
+    group
+      .update_moderator_list(UpdateModeratorListType::Add, inbox_id)
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn remove_moderator(&self, inbox_id: String) -> Result<()> {
+    let group = self.create_mls_group();
+    group
+      .update_moderator_list(UpdateModeratorListType::Remove, inbox_id)
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(())
+  }
+
   #[napi]
   #[xmtp_common::err_span]
   pub fn group_permissions(&self) -> Result<GroupPermissions> {
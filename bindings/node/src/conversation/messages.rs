@@ -3,9 +3,9 @@ use crate::{
   conversation::Conversation,
   messages::decoded_message::DecodedMessage,
   messages::encoded_content::EncodedContent,
-  messages::{ListMessagesOptions, Message},
+  messages::{ListMessagesOptions, Message, MessageSearchHit},
 };
-use napi::bindgen_prelude::{Result, Uint8Array};
+use napi::bindgen_prelude::{BigInt, Result, Uint8Array};
 use napi_derive::napi;
 use prost::Message as ProstMessage;
 use std::{collections::HashMap, ops::Deref};
@@ -19,6 +19,15 @@ pub struct SendMessageOpts {
   /// Optional idempotency key. Re-sending identical content with the same key
   /// produces the same message id and is deduplicated. Defaults to a timestamp.
   pub idempotency_key: Option<String>,
+  /// Delete the message this long after it was sent, regardless of the
+  /// conversation's disappearing messages settings.
+  pub expire_after_ns: Option<BigInt>,
+  /// Delete the message on receivers once they have viewed it.
+  ///
+  /// Either option first raises the conversation's minimum protocol version so
+  /// older clients can't keep the message; optimistic and scheduled sends
+  /// fail until a regular send has done so.
+  pub view_once: Option<bool>,
 }
 
 /// Options for the top-level `send_*` convenience helpers. `should_push` is
@@ -38,6 +47,41 @@ impl From<SendMessageOpts> for xmtp_mls::groups::send_message_opts::SendMessageO
     xmtp_mls::groups::send_message_opts::SendMessageOpts {
       should_push: opts.should_push,
       idempotency_key: opts.idempotency_key,
+      expire_after_ns: opts.expire_after_ns.map(|ns| ns.get_i64().0),
+      view_once: opts.view_once.unwrap_or_default(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct ScheduledMessage {
+  pub publish_at_ns: BigInt,
+  pub message: Message,
+}
+
+impl From<xmtp_mls::groups::scheduled_messages::ScheduledMessage> for ScheduledMessage {
+  fn from(scheduled: xmtp_mls::groups::scheduled_messages::ScheduledMessage) -> Self {
+    Self {
+      publish_at_ns: BigInt::from(scheduled.publish_at_ns),
+      message: scheduled.message.into(),
+    }
+  }
+}
+
+/// An unsent draft. Drafts are stored locally and never sent.
+#[napi(object)]
+pub struct Draft {
+  pub content: EncodedContent,
+  pub reply_to_message_id: Option<String>,
+  pub updated_at_ns: BigInt,
+}
+
+impl From<xmtp_mls::groups::drafts::Draft> for Draft {
+  fn from(draft: xmtp_mls::groups::drafts::Draft) -> Self {
+    Self {
+      content: draft.content.into(),
+      reply_to_message_id: draft.reply_to_message_id.map(hex::encode),
+      updated_at_ns: BigInt::from(draft.updated_at_ns),
     }
   }
 }
@@ -67,6 +111,31 @@ impl Conversation {
     Ok(hex::encode(message_id))
   }
 
+  /// Edit a message sent by this inbox. Returns the ID of the edit message.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn edit_message(&self, message_id: String, edited_content: EncodedContent) -> Result<String> {
+    let message_id = hex::decode(message_id).map_err(ErrorWrapper::from)?;
+    let group = self.create_mls_group();
+    let edit_id = group
+      .edit_message(message_id, edited_content.into())
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(hex::encode(edit_id))
+  }
+
+  /// Mark a message as viewed. View-once messages from other members are
+  /// deleted from this installation. Returns whether the message was deleted.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn mark_viewed(&self, message_id: String) -> Result<bool> {
+    let message_id = hex::decode(message_id).map_err(ErrorWrapper::from)?;
+    let group = self.create_mls_group();
+    let deleted = group.mark_viewed(&message_id).map_err(ErrorWrapper::from)?;
+
+    Ok(deleted)
+  }
+
   #[napi]
   #[xmtp_common::err_span]
   pub async fn publish_messages(&self) -> Result<()> {
@@ -104,6 +173,25 @@ impl Conversation {
     Ok(count)
   }
 
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn search_messages(
+    &self,
+    query: String,
+    opts: Option<ListMessagesOptions>,
+  ) -> Result<Vec<MessageSearchHit>> {
+    let opts = opts.unwrap_or_default();
+    let group = self.create_mls_group();
+    let hits = group
+      .search_messages(&query, &opts.into())
+      .map_err(ErrorWrapper::from)?
+      .into_iter()
+      .map(Into::into)
+      .collect();
+
+    Ok(hits)
+  }
+
   #[napi]
   #[xmtp_common::err_span]
   pub async fn process_streamed_group_message(
@@ -180,4 +268,100 @@ impl Conversation {
       .map_err(ErrorWrapper::from)?;
     Ok(())
   }
+
+  /// Store a message now and publish it in the background once `publishAtNs`
+  /// has passed, including after an app restart. Returns the message ID.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn send_at(
+    &self,
+    encoded_content: EncodedContent,
+    publish_at_ns: BigInt,
+    opts: SendMessageOpts,
+  ) -> Result<String> {
+    let encoded_content: XmtpEncodedContent = encoded_content.into();
+    let group = self.create_mls_group();
+    let message_id = group
+      .send_message_at(
+        encoded_content.encode_to_vec().as_slice(),
+        publish_at_ns.get_i64().0,
+        opts.into(),
+      )
+      .map_err(ErrorWrapper::from)?;
+    Ok(hex::encode(message_id))
+  }
+
+  /// Messages waiting to be published, ordered by publish time.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn scheduled_messages(&self) -> Result<Vec<ScheduledMessage>> {
+    let group = self.create_mls_group();
+    let scheduled = group
+      .scheduled_messages()
+      .map_err(ErrorWrapper::from)?
+      .into_iter()
+      .map(Into::into)
+      .collect();
+    Ok(scheduled)
+  }
+
+  /// Cancel a scheduled message that has not been published yet.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn cancel_scheduled_message(&self, message_id: String) -> Result<()> {
+    let group = self.create_mls_group();
+    let message_id_bytes = hex::decode(&message_id).map_err(ErrorWrapper::from)?;
+    group
+      .cancel_scheduled_message(&message_id_bytes)
+      .map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  /// Save the unsent draft for this conversation, replacing any previous one.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn set_draft(
+    &self,
+    encoded_content: EncodedContent,
+    reply_to_message_id: Option<String>,
+  ) -> Result<()> {
+    let group = self.create_mls_group();
+    let reply_to_message_id = reply_to_message_id
+      .map(hex::decode)
+      .transpose()
+      .map_err(ErrorWrapper::from)?;
+    group
+      .set_draft(encoded_content.into(), reply_to_message_id)
+      .map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn get_draft(&self) -> Result<Option<Draft>> {
+    let group = self.create_mls_group();
+    let draft = group.get_draft().map_err(ErrorWrapper::from)?;
+    Ok(draft.map(Into::into))
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn clear_draft(&self) -> Result<()> {
+    let group = self.create_mls_group();
+    group.clear_draft().map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  /// Mark every message up to and including `upToMessageId` as read. The read
+  /// cursor is synced to the user's other installations.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn mark_read(&self, up_to_message_id: String) -> Result<()> {
+    let up_to_message_id = hex::decode(up_to_message_id).map_err(ErrorWrapper::from)?;
+    let group = self.create_mls_group();
+    group
+      .mark_read(&up_to_message_id)
+      .map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
 }
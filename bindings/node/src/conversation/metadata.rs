@@ -1,7 +1,9 @@
 use crate::{ErrorWrapper, conversation::Conversation, conversations::ConversationType};
-use napi::bindgen_prelude::Result;
+use napi::bindgen_prelude::{BigInt, Result, Uint8Array};
 use napi_derive::napi;
+use prost::Message;
 use xmtp_mls::mls_common::group_metadata::GroupMetadata as XmtpGroupMetadata;
+use xmtp_mls::mls_common::invite::group_invites::GroupInvite as XmtpGroupInvite;
 
 #[napi]
 pub struct GroupMetadata {
@@ -135,6 +137,99 @@ impl Conversation {
 
     Ok(())
   }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn pin_message(&self, message_id: String) -> Result<()> {
+    let message_id = hex::decode(message_id).map_err(ErrorWrapper::from)?;
+    let group = self.create_mls_group();
+
+    group
+      .pin_message(&message_id)
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn unpin_message(&self, message_id: String) -> Result<()> {
+    let message_id = hex::decode(message_id).map_err(ErrorWrapper::from)?;
+    let group = self.create_mls_group();
+
+    group
+      .unpin_message(&message_id)
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn pinned_messages(&self) -> Result<Vec<String>> {
+    let group = self.create_mls_group();
+    let pinned = group.pinned_messages().map_err(ErrorWrapper::from)?;
+
+    Ok(pinned.into_iter().map(hex::encode).collect())
+  }
+
+  /// Create an invite and return the serialized payload to share. The invite
+  /// admits joiners until `expiresAtNs`, if given, or until it is revoked.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn create_invite(&self, expires_at_ns: Option<BigInt>) -> Result<Uint8Array> {
+    let group = self.create_mls_group();
+
+    let invite = group
+      .create_invite(expires_at_ns.map(|ns| ns.get_i64().0))
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(invite.encode_to_vec().into())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn invites(&self) -> Result<Vec<GroupInvite>> {
+    let group = self.create_mls_group();
+    let invites = group.invites().map_err(ErrorWrapper::from)?;
+
+    Ok(invites.into_iter().map(Into::into).collect())
+  }
+
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn revoke_invite(&self, external_group_id: String) -> Result<()> {
+    let external_group_id = hex::decode(external_group_id).map_err(ErrorWrapper::from)?;
+    let group = self.create_mls_group();
+
+    group
+      .revoke_invite(&external_group_id)
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(())
+  }
+}
+
+#[napi(object)]
+pub struct GroupInvite {
+  pub external_group_id: String,
+  /// `0` if the invite does not expire.
+  pub expires_at_ns: BigInt,
+  pub created_by_inbox_id: String,
+}
+
+impl From<XmtpGroupInvite> for GroupInvite {
+  fn from(invite: XmtpGroupInvite) -> Self {
+    Self {
+      external_group_id: hex::encode(invite.policy.external_group_id),
+      expires_at_ns: BigInt::from(invite.policy.expires_at_ns),
+      created_by_inbox_id: invite.created_by_inbox_id,
+    }
+  }
 }
 
 /// Options for [`Conversation::updateAppData`]. An object (rather than
@@ -6,9 +6,11 @@ use xmtp_proto::types::GroupId;
 
 pub mod consent_state;
 pub mod content_types;
+pub mod conversation_settings;
 pub mod debug;
 pub mod disappearing_messages;
 pub mod dm;
+pub mod ephemeral;
 pub mod hmac_key;
 pub mod membership;
 pub mod messages;
@@ -4,10 +4,12 @@ use crate::conversation::disappearing_messages::MessageDisappearingSettings;
 use crate::conversations::Conversations;
 use crate::identity::Identifier;
 use crate::permissions::{GroupPermissionsOptions, PermissionPolicySet};
-use napi::bindgen_prelude::{Error, Result};
+use napi::bindgen_prelude::{Error, Result, Uint8Array};
 use napi_derive::napi;
+use prost::Message;
 use xmtp_mls::groups::PreconfiguredPolicies;
 use xmtp_mls::mls_common::group::GroupMetadataOptions;
+use xmtp_proto::xmtp::mls::message_contents::ExternalInvitePayload;
 
 #[napi(object)]
 #[derive(Clone)]
@@ -125,4 +127,20 @@ impl Conversations {
 
     Ok(convo)
   }
+
+  /// Join a group with the serialized invite returned by `Conversation.createInvite`.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn join_with_invite(&self, invite: Uint8Array) -> Result<Conversation> {
+    let invite = ExternalInvitePayload::decode(invite.as_ref())
+      .map_err(|e| Error::from_reason(e.to_string()))?;
+
+    let group = self
+      .inner_client
+      .join_with_invite(&invite)
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(group.into())
+  }
 }
@@ -1,8 +1,8 @@
 use crate::ErrorWrapper;
 use crate::conversation::Conversation;
 use crate::conversations::Conversations;
-use crate::messages::Message;
 use crate::messages::decoded_message::DecodedMessage;
+use crate::messages::{ListMessagesOptions, Message, MessageSearchHit};
 use napi::bindgen_prelude::{Result, Uint8Array};
 use napi_derive::napi;
 use std::ops::Deref;
@@ -35,6 +35,25 @@ impl Conversations {
     message.try_into()
   }
 
+  #[napi]
+  #[xmtp_common::err_span]
+  pub fn search_messages(
+    &self,
+    query: String,
+    opts: Option<ListMessagesOptions>,
+  ) -> Result<Vec<MessageSearchHit>> {
+    let opts = opts.unwrap_or_default();
+    let hits = self
+      .inner_client
+      .search_messages(&query, &opts.into())
+      .map_err(ErrorWrapper::from)?
+      .into_iter()
+      .map(Into::into)
+      .collect();
+
+    Ok(hits)
+  }
+
   #[napi]
   #[xmtp_common::err_span]
   pub fn delete_message_by_id(&self, message_id: String) -> Result<u32> {
@@ -73,6 +73,12 @@ pub struct ListConversationsOptions {
   pub include_duplicate_dms: Option<bool>,
   pub limit: Option<i64>,
   pub order_by: Option<ListConversationsOrderBy>,
+  /// Only archived (`true`) or unarchived (`false`) conversations
+  pub archived: Option<bool>,
+  /// Only pinned (`true`) or unpinned (`false`) conversations
+  pub pinned: Option<bool>,
+  /// Only conversations that are (`true`) or are not (`false`) muted right now
+  pub muted: Option<bool>,
 }
 
 impl From<ListConversationsOptions> for GroupQueryArgs {
@@ -92,6 +98,12 @@ impl From<ListConversationsOptions> for GroupQueryArgs {
       last_activity_after_ns: None,
       should_publish_commit_log: None,
       order_by: opts.order_by.map(Into::into),
+      group_ids: None,
+      exclude_group_ids: None,
+      changed_after_ns: None,
+      archived: opts.archived,
+      pinned: opts.pinned,
+      muted: opts.muted,
     }
   }
 }
@@ -101,6 +113,12 @@ pub struct ConversationListItem {
   conversation: Conversation,
   last_message: Option<Message>,
   is_commit_log_forked: Option<bool>,
+  has_draft: bool,
+  unread_count: u32,
+  first_unread_message_id: Option<String>,
+  muted_until_ns: Option<BigInt>,
+  is_archived: bool,
+  is_pinned: bool,
 }
 
 #[napi]
@@ -119,6 +137,36 @@ impl ConversationListItem {
   pub fn is_commit_log_forked(&self) -> Option<bool> {
     self.is_commit_log_forked
   }
+
+  #[napi(getter)]
+  pub fn has_draft(&self) -> bool {
+    self.has_draft
+  }
+
+  #[napi(getter)]
+  pub fn unread_count(&self) -> u32 {
+    self.unread_count
+  }
+
+  #[napi(getter)]
+  pub fn first_unread_message_id(&self) -> Option<String> {
+    self.first_unread_message_id.clone()
+  }
+
+  #[napi(getter)]
+  pub fn muted_until_ns(&self) -> Option<BigInt> {
+    self.muted_until_ns.clone()
+  }
+
+  #[napi(getter)]
+  pub fn is_archived(&self) -> bool {
+    self.is_archived
+  }
+
+  #[napi(getter)]
+  pub fn is_pinned(&self) -> bool {
+    self.is_pinned
+  }
 }
 
 #[napi(object)]
@@ -217,6 +265,12 @@ impl Conversations {
           .last_message
           .map(|stored_message| stored_message.into()),
         is_commit_log_forked: conversation_item.is_commit_log_forked,
+        has_draft: conversation_item.has_draft,
+        unread_count: conversation_item.unread_count as u32,
+        first_unread_message_id: conversation_item.first_unread_message_id.map(hex::encode),
+        muted_until_ns: conversation_item.muted_until_ns.map(BigInt::from),
+        is_archived: conversation_item.is_archived,
+        is_pinned: conversation_item.is_pinned,
       })
       .collect();
 
@@ -1,6 +1,7 @@
 use crate::ErrorWrapper;
 use crate::consent_state::{Consent, ConsentState};
 use crate::conversation::Conversation;
+use crate::conversation::conversation_settings::ConversationSettings;
 use crate::conversations::{ConversationType, Conversations};
 use crate::messages::Message;
 use crate::messages::decoded_message::DecodedMessage;
@@ -13,8 +14,19 @@ use xmtp_mls::worker::device_sync::preference_sync::PreferenceUpdate as XmtpUser
 
 #[napi(discriminant = "type")]
 pub enum UserPreferenceUpdate {
-  ConsentUpdate { consent: Consent },
-  HmacKeyUpdate { key: Uint8Array },
+  ConsentUpdate {
+    consent: Consent,
+  },
+  HmacKeyUpdate {
+    key: Uint8Array,
+  },
+  ReadCursorUpdate {
+    conversation_id: String,
+    message_id: String,
+  },
+  ConversationSettingsUpdate {
+    settings: ConversationSettings,
+  },
 }
 
 impl From<XmtpUserPreferenceUpdate> for UserPreferenceUpdate {
@@ -24,6 +36,15 @@ impl From<XmtpUserPreferenceUpdate> for UserPreferenceUpdate {
       XmtpUserPreferenceUpdate::Consent(consent) => Self::ConsentUpdate {
         consent: consent.into(),
       },
+      XmtpUserPreferenceUpdate::ReadCursor(cursor) => Self::ReadCursorUpdate {
+        conversation_id: hex::encode(cursor.group_id),
+        message_id: hex::encode(cursor.message_id),
+      },
+      XmtpUserPreferenceUpdate::ConversationSettings(settings) => {
+        Self::ConversationSettingsUpdate {
+          settings: settings.into(),
+        }
+      }
     }
   }
 }
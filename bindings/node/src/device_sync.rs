@@ -1,3 +1,4 @@
+use crate::consent_state::ConsentState;
 use crate::conversations::GroupSyncSummary;
 use crate::{ErrorWrapper, client::RustXmtpClient};
 use napi::bindgen_prelude::{BigInt, Result, Uint8Array};
@@ -7,7 +8,8 @@ use xmtp_id::associations::DeserializationError;
 use xmtp_mls::worker::device_sync::{
   ArchiveOptions as XmtpArchiveOptions, AvailableArchive, BackupElementSelection, DeviceSyncError,
   archive::{
-    ArchiveImporter, BackupMetadata, ENC_KEY_SIZE, exporter::ArchiveExporter, insert_importer,
+    ArchiveImporter, ArchiveReport as XmtpArchiveReport, BackupMetadata, ENC_KEY_SIZE,
+    exporter::ArchiveExporter, insert_importer,
   },
 };
 use xmtp_proto::xmtp::device_sync::BackupElementSelection as BackupElementSelectionProto;
@@ -19,16 +21,41 @@ pub struct ArchiveOptions {
   pub end_ns: Option<BigInt>,
   pub elements: Vec<BackupElementSelectionOption>,
   pub exclude_disappearing_messages: bool,
+  /// Only export these conversations (hex-encoded ids)
+  pub include_group_ids: Option<Vec<String>>,
+  /// Never export these conversations (hex-encoded ids)
+  pub exclude_group_ids: Option<Vec<String>>,
+  /// Only export conversations with one of these consent states
+  pub consent_states: Option<Vec<ConsentState>>,
 }
 
-impl From<ArchiveOptions> for XmtpArchiveOptions {
-  fn from(value: ArchiveOptions) -> Self {
-    Self {
+fn decode_group_ids(
+  group_ids: Option<Vec<String>>,
+) -> std::result::Result<Vec<Vec<u8>>, hex::FromHexError> {
+  group_ids
+    .unwrap_or_default()
+    .iter()
+    .map(hex::decode)
+    .collect()
+}
+
+impl TryFrom<ArchiveOptions> for XmtpArchiveOptions {
+  type Error = hex::FromHexError;
+  fn try_from(value: ArchiveOptions) -> std::result::Result<Self, Self::Error> {
+    Ok(Self {
       start_ns: value.start_ns.map(|n| n.get_i64().0),
       end_ns: value.end_ns.map(|n| n.get_i64().0),
       elements: value.elements.into_iter().map(|el| el.into()).collect(),
       exclude_disappearing_messages: value.exclude_disappearing_messages,
-    }
+      include_group_ids: decode_group_ids(value.include_group_ids)?,
+      exclude_group_ids: decode_group_ids(value.exclude_group_ids)?,
+      consent_states: value
+        .consent_states
+        .unwrap_or_default()
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+    })
   }
 }
 
@@ -37,12 +64,14 @@ impl From<ArchiveOptions> for XmtpArchiveOptions {
 pub enum BackupElementSelectionOption {
   Messages,
   Consent,
+  Drafts,
 }
 
 impl From<BackupElementSelectionOption> for BackupElementSelection {
   fn from(value: BackupElementSelectionOption) -> Self {
     match value {
       BackupElementSelectionOption::Consent => Self::Consent,
+      BackupElementSelectionOption::Drafts => Self::Drafts,
       BackupElementSelectionOption::Messages => Self::Messages,
     }
   }
@@ -52,6 +81,7 @@ impl From<BackupElementSelectionOption> for BackupElementSelectionProto {
   fn from(value: BackupElementSelectionOption) -> Self {
     match value {
       BackupElementSelectionOption::Consent => Self::Consent,
+      BackupElementSelectionOption::Drafts => Self::Drafts,
       BackupElementSelectionOption::Messages => Self::Messages,
     }
   }
@@ -62,6 +92,7 @@ impl TryFrom<BackupElementSelection> for BackupElementSelectionOption {
   fn try_from(value: BackupElementSelection) -> std::result::Result<Self, Self::Error> {
     let v = match value {
       BackupElementSelection::Consent => Self::Consent,
+      BackupElementSelection::Drafts => Self::Drafts,
       BackupElementSelection::Messages => Self::Messages,
       _ => {
         return Err(DeserializationError::Unspecified(
@@ -78,6 +109,7 @@ impl TryFrom<BackupElementSelectionProto> for BackupElementSelectionOption {
   fn try_from(value: BackupElementSelectionProto) -> std::result::Result<Self, Self::Error> {
     let v = match value {
       BackupElementSelectionProto::Consent => Self::Consent,
+      BackupElementSelectionProto::Drafts => Self::Drafts,
       BackupElementSelectionProto::Messages => Self::Messages,
       _ => {
         return Err(DeserializationError::Unspecified(
@@ -97,6 +129,12 @@ pub struct ArchiveMetadata {
   pub exported_at_ns: BigInt,
   pub start_ns: Option<BigInt>,
   pub end_ns: Option<BigInt>,
+  pub include_group_ids: Vec<String>,
+  pub exclude_group_ids: Vec<String>,
+  pub consent_states: Vec<ConsentState>,
+  pub archive_id: String,
+  pub previous_archive_id: Option<String>,
+  pub since_ns: Option<BigInt>,
 }
 
 impl From<BackupMetadata> for ArchiveMetadata {
@@ -111,6 +149,42 @@ impl From<BackupMetadata> for ArchiveMetadata {
       start_ns: value.start_ns.map(BigInt::from),
       end_ns: value.end_ns.map(BigInt::from),
       exported_at_ns: BigInt::from(value.exported_at_ns),
+      include_group_ids: value.include_group_ids.iter().map(hex::encode).collect(),
+      exclude_group_ids: value.exclude_group_ids.iter().map(hex::encode).collect(),
+      consent_states: value.consent_states.into_iter().map(Into::into).collect(),
+      archive_id: hex::encode(&value.archive_id),
+      previous_archive_id: value.previous_archive_id.as_ref().map(hex::encode),
+      since_ns: value.since_ns.map(BigInt::from),
+    }
+  }
+}
+
+/// What an archive holds, read without importing it
+#[napi(object)]
+pub struct ArchiveReport {
+  pub metadata: ArchiveMetadata,
+  pub group_count: i64,
+  pub group_message_count: i64,
+  pub consent_count: i64,
+  pub earliest_message_ns: Option<BigInt>,
+  pub latest_message_ns: Option<BigInt>,
+  /// Whether the archive ended with a manifest whose MAC and contents check out
+  pub manifest_verified: bool,
+  /// Decryption, decode and manifest failures, in the order they were found
+  pub failures: Vec<String>,
+}
+
+impl From<XmtpArchiveReport> for ArchiveReport {
+  fn from(value: XmtpArchiveReport) -> Self {
+    Self {
+      metadata: value.metadata.into(),
+      group_count: value.group_count as i64,
+      group_message_count: value.group_message_count as i64,
+      consent_count: value.consent_count as i64,
+      earliest_message_ns: value.earliest_message_ns.map(BigInt::from),
+      latest_message_ns: value.latest_message_ns.map(BigInt::from),
+      manifest_verified: value.manifest_verified,
+      failures: value.failures,
     }
   }
 }
@@ -164,7 +238,10 @@ impl DeviceSync {
     self
       .inner_client
       .device_sync_client()
-      .send_sync_request(options.into(), server_url)
+      .send_sync_request(
+        XmtpArchiveOptions::try_from(options).map_err(ErrorWrapper::from)?,
+        server_url,
+      )
       .await
       .map_err(ErrorWrapper::from)?;
 
@@ -181,10 +258,11 @@ impl DeviceSync {
     server_url: String,
     pin: String,
   ) -> Result<()> {
+    let options = XmtpArchiveOptions::try_from(options).map_err(ErrorWrapper::from)?;
     self
       .inner_client
       .device_sync_client()
-      .send_sync_archive(&options.into(), &server_url, &pin)
+      .send_sync_archive(&options, &server_url, &pin)
       .await
       .map_err(ErrorWrapper::from)?;
     Ok(())
@@ -229,8 +307,27 @@ impl DeviceSync {
     key: Uint8Array,
   ) -> Result<()> {
     let key = check_key(&key)?;
+    let opts = XmtpArchiveOptions::try_from(opts).map_err(ErrorWrapper::from)?;
     let db = self.inner_client.context.db();
-    ArchiveExporter::export_to_file(opts.into(), db, path, &key)
+    ArchiveExporter::export_to_file(opts, db, path, &key)
+      .await
+      .map_err(DeviceSyncError::Archive)
+      .map_err(ErrorWrapper::from)?;
+    Ok(())
+  }
+
+  /// Archive application elements to file, encrypted with a key derived from `passphrase`.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn create_archive_with_passphrase(
+    &self,
+    path: String,
+    opts: ArchiveOptions,
+    passphrase: String,
+  ) -> Result<()> {
+    let opts = XmtpArchiveOptions::try_from(opts).map_err(ErrorWrapper::from)?;
+    let db = self.inner_client.context.db();
+    ArchiveExporter::export_to_file_with_passphrase(opts, db, path, &passphrase)
       .await
       .map_err(DeviceSyncError::Archive)
       .map_err(ErrorWrapper::from)?;
@@ -254,6 +351,26 @@ impl DeviceSync {
     Ok(())
   }
 
+  /// Import an archive that was created with a passphrase.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn import_archive_with_passphrase(
+    &self,
+    path: String,
+    passphrase: String,
+  ) -> Result<()> {
+    let mut importer = ArchiveImporter::from_file_with_passphrase(path, &passphrase)
+      .await
+      .map_err(DeviceSyncError::Archive)
+      .map_err(ErrorWrapper::from)?;
+
+    insert_importer(&mut importer, &self.inner_client.context)
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(())
+  }
+
   /// Load the metadata for an archive to see what it contains.
   /// Reads only the metadata without loading the entire file, so this function is quick.
   #[napi]
@@ -268,6 +385,20 @@ impl DeviceSync {
     Ok(importer.metadata.into())
   }
 
+  /// Read through a whole archive without importing it,
+  /// to check that it's intact before restoring from it.
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn verify_archive(&self, path: String, key: Uint8Array) -> Result<ArchiveReport> {
+    let key = check_key(&key)?;
+    let importer = ArchiveImporter::from_file(path, &key)
+      .await
+      .map_err(DeviceSyncError::Archive)
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(importer.verify().await.into())
+  }
+
   /// Manually sync all device sync groups.
   #[napi]
   #[xmtp_common::err_span]
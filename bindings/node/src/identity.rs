@@ -15,6 +15,7 @@ pub struct Identifier {
 pub enum IdentifierKind {
   Ethereum,
   Passkey,
+  Solana,
   // more to come...
 }
 
@@ -29,6 +30,10 @@ impl From<XmtpIdentifier> for Identifier {
         identifier: hex::encode(key),
         identifier_kind: IdentifierKind::Passkey,
       },
+      XmtpIdentifier::Solana(ident::Solana(addr)) => Self {
+        identifier: addr,
+        identifier_kind: IdentifierKind::Solana,
+      },
     }
   }
 }
@@ -39,6 +44,7 @@ impl TryFrom<Identifier> for XmtpIdentifier {
     let ident = match ident.identifier_kind {
       IdentifierKind::Ethereum => Self::eth(ident.identifier)?,
       IdentifierKind::Passkey => Self::passkey_str(&ident.identifier, None)?,
+      IdentifierKind::Solana => Self::solana(ident.identifier)?,
     };
     Ok(ident)
   }
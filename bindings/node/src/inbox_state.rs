@@ -52,6 +52,7 @@ impl From<AssociationState> for InboxState {
         .filter_map(|m| match m.identifier {
           MemberIdentifier::Ethereum(_) => None,
           MemberIdentifier::Passkey(_) => None,
+          MemberIdentifier::Solana(_) => None,
           MemberIdentifier::Installation(ident::Installation(key)) => Some(Installation {
             bytes: Uint8Array::from(key.as_slice()),
             client_timestamp_ns: m.client_timestamp_ns.map(BigInt::from),
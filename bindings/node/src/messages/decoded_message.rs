@@ -4,7 +4,50 @@ use crate::messages::{DeliveryStatus, GroupMessageKind};
 use napi::Error;
 use napi::bindgen_prelude::{BigInt, Result};
 use napi_derive::napi;
-use xmtp_mls::messages::decoded_message::DecodedMessage as XmtpDecodedMessage;
+use xmtp_mls::messages::decoded_message::{
+  DecodedMessage as XmtpDecodedMessage, MessageEdit as XmtpMessageEdit,
+};
+
+/// One version of an edited message's content
+#[derive(Clone)]
+#[napi]
+pub struct MessageEdit {
+  inner: Box<XmtpMessageEdit>,
+  /// The ID of the message that introduced this version
+  pub message_id: String,
+  sent_at_ns: BigInt,
+  content_type: ContentTypeId,
+}
+
+#[napi]
+impl MessageEdit {
+  #[napi(getter)]
+  pub fn sent_at_ns(&self) -> BigInt {
+    self.sent_at_ns.clone()
+  }
+
+  #[napi(getter)]
+  pub fn content_type(&self) -> ContentTypeId {
+    self.content_type.clone()
+  }
+
+  #[napi(getter)]
+  #[xmtp_common::err_span]
+  pub fn content(&self) -> Result<DecodedMessageContent> {
+    self.inner.content.clone().try_into()
+  }
+}
+
+impl From<XmtpMessageEdit> for MessageEdit {
+  fn from(edit: XmtpMessageEdit) -> Self {
+    Self {
+      message_id: hex::encode(&edit.message_id),
+      sent_at_ns: BigInt::from(edit.sent_at_ns),
+      content_type: edit.content_type.clone().into(),
+      inner: Box::new(edit),
+    }
+  }
+}
 
 #[derive(Clone)]
 #[napi]
@@ -21,6 +64,7 @@ pub struct DecodedMessage {
   pub delivery_status: DeliveryStatus,
   pub num_replies: i64,
   expires_at_ns: Option<BigInt>,
+  pub view_once: bool,
 }
 
 #[napi]
@@ -56,6 +100,24 @@ impl DecodedMessage {
   pub fn content(&self) -> Result<DecodedMessageContent> {
     self.inner.content.clone().try_into()
   }
+
+  /// Every version of the content, oldest first, starting with the original.
+  /// Empty if the message was never edited.
+  #[napi(getter)]
+  pub fn edit_history(&self) -> Vec<MessageEdit> {
+    self
+      .inner
+      .edit_history
+      .iter()
+      .cloned()
+      .map(Into::into)
+      .collect()
+  }
+
+  #[napi(getter)]
+  pub fn edited_at_ns(&self) -> Option<BigInt> {
+    self.inner.edited_at_ns().map(BigInt::from)
+  }
 }
 
 impl TryFrom<XmtpDecodedMessage> for DecodedMessage {
@@ -74,6 +136,7 @@ impl TryFrom<XmtpDecodedMessage> for DecodedMessage {
       delivery_status: msg.metadata.delivery_status.into(),
       num_replies: msg.num_replies as i64,
       expires_at_ns: msg.metadata.expires_at_ns.map(BigInt::from),
+      view_once: msg.metadata.view_once,
       inner: Box::new(msg),
     })
   }
@@ -6,6 +6,7 @@ use xmtp_db::group_message::{
   DeliveryStatus as XmtpDeliveryStatus, GroupMessageKind as XmtpGroupMessageKind, MsgQueryArgs,
   SortBy as XmtpMessageSortBy, SortDirection as XmtpSortDirection, StoredGroupMessage,
 };
+use xmtp_db::message_search::MessageSearchHit as XmtpMessageSearchHit;
 use xmtp_proto::xmtp::mls::message_contents::EncodedContent as XmtpEncodedContent;
 
 pub mod decoded_message;
@@ -151,6 +152,7 @@ pub struct Message {
   pub kind: GroupMessageKind,
   pub delivery_status: DeliveryStatus,
   pub inserted_at_ns: BigInt,
+  pub view_once: bool,
 }
 
 impl From<StoredGroupMessage> for Message {
@@ -181,6 +183,28 @@ impl From<StoredGroupMessage> for Message {
       kind: msg.kind.into(),
       delivery_status: msg.delivery_status.into(),
       inserted_at_ns: BigInt::from(msg.inserted_at_ns),
+      view_once: msg.view_once,
+    }
+  }
+}
+
+/// A message matching a full-text search. Matched terms in `snippet` are wrapped in
+/// `<mark>` and `</mark>`.
+#[napi(object)]
+#[derive(Clone)]
+pub struct MessageSearchHit {
+  pub message: Message,
+  /// BM25 relevance of the hit. Lower is more relevant.
+  pub rank: f64,
+  pub snippet: String,
+}
+
+impl From<XmtpMessageSearchHit> for MessageSearchHit {
+  fn from(hit: XmtpMessageSearchHit) -> Self {
+    Self {
+      message: hit.message.into(),
+      rank: hit.rank,
+      snippet: hit.snippet,
     }
   }
 }
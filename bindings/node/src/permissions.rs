@@ -52,6 +52,7 @@ pub enum PermissionPolicy {
   SuperAdmin,
   DoesNotExist,
   Other,
+  Moderator,
 }
 
 impl TryInto<PermissionPolicyOption> for PermissionPolicy {
@@ -63,6 +64,7 @@ impl TryInto<PermissionPolicyOption> for PermissionPolicy {
       PermissionPolicy::Deny => Ok(PermissionPolicyOption::Deny),
       PermissionPolicy::Admin => Ok(PermissionPolicyOption::AdminOnly),
       PermissionPolicy::SuperAdmin => Ok(PermissionPolicyOption::SuperAdminOnly),
+      PermissionPolicy::Moderator => Ok(PermissionPolicyOption::ModeratorOrAbove),
       _ => Err(GroupMutablePermissionsError::InvalidPermissionPolicyOption),
     }
   }
@@ -77,6 +79,7 @@ impl From<&MembershipPolicies> for PermissionPolicy {
         BasePolicies::AllowSameMember => PermissionPolicy::Other,
         BasePolicies::AllowIfAdminOrSuperAdmin => PermissionPolicy::Admin,
         BasePolicies::AllowIfSuperAdmin => PermissionPolicy::SuperAdmin,
+        BasePolicies::AllowIfModeratorOrAbove => PermissionPolicy::Moderator,
       }
     } else {
       PermissionPolicy::Other
@@ -92,6 +95,7 @@ impl From<&MetadataPolicies> for PermissionPolicy {
         MetadataBasePolicies::Deny => PermissionPolicy::Deny,
         MetadataBasePolicies::AllowIfActorAdminOrSuperAdmin => PermissionPolicy::Admin,
         MetadataBasePolicies::AllowIfActorSuperAdmin => PermissionPolicy::SuperAdmin,
+        MetadataBasePolicies::AllowIfActorModeratorOrAbove => PermissionPolicy::Moderator,
       }
     } else {
       PermissionPolicy::Other
@@ -122,6 +126,7 @@ impl TryInto<MetadataPolicies> for PermissionPolicy {
       PermissionPolicy::Deny => Ok(MetadataPolicies::deny()),
       PermissionPolicy::Admin => Ok(MetadataPolicies::allow_if_actor_admin()),
       PermissionPolicy::SuperAdmin => Ok(MetadataPolicies::allow_if_actor_super_admin()),
+      PermissionPolicy::Moderator => Ok(MetadataPolicies::allow_if_actor_moderator()),
       _ => Err(GroupMutablePermissionsError::InvalidPermissionPolicyOption),
     }
   }
@@ -149,6 +154,7 @@ impl TryInto<MembershipPolicies> for PermissionPolicy {
       PermissionPolicy::Deny => Ok(MembershipPolicies::deny()),
       PermissionPolicy::Admin => Ok(MembershipPolicies::allow_if_actor_admin()),
       PermissionPolicy::SuperAdmin => Ok(MembershipPolicies::allow_if_actor_super_admin()),
+      PermissionPolicy::Moderator => Ok(MembershipPolicies::allow_if_actor_moderator()),
       _ => Err(GroupMutablePermissionsError::InvalidPermissionPolicyOption),
     }
   }
@@ -273,6 +279,8 @@ pub enum MetadataField {
   GroupImageUrlSquare,
   MessageExpirationFromNs,
   MessageExpirationInNs,
+  PinnedMessageIds,
+  ExternalInvites,
 }
 
 impl From<&MetadataField> for XmtpMetadataField {
@@ -284,6 +292,8 @@ impl From<&MetadataField> for XmtpMetadataField {
       MetadataField::GroupImageUrlSquare => XmtpMetadataField::GroupImageUrlSquare,
       MetadataField::MessageExpirationFromNs => XmtpMetadataField::MessageDisappearFromNS,
       MetadataField::MessageExpirationInNs => XmtpMetadataField::MessageDisappearInNS,
+      MetadataField::PinnedMessageIds => XmtpMetadataField::PinnedMessageIds,
+      MetadataField::ExternalInvites => XmtpMetadataField::ExternalInvites,
     }
   }
 }
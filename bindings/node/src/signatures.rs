@@ -151,6 +151,30 @@ impl SignatureRequestHandle {
     Ok(())
   }
 
+  #[napi]
+  #[xmtp_common::err_span]
+  pub async fn add_solana_signature(
+    &self,
+    account_identifier: Identifier,
+    signature_bytes: Uint8Array,
+  ) -> Result<()> {
+    let xmtp_id::associations::Identifier::Solana(address) = account_identifier.try_into()? else {
+      return Err(Error::from_reason(
+        "Account identifier must be Solana-based.",
+      ));
+    };
+    let signature = UnverifiedSignature::new_solana(signature_bytes.to_vec(), &address)
+      .map_err(ErrorWrapper::from)?;
+    let mut inner = self.inner.lock().await;
+
+    inner
+      .add_signature(signature, &self.scw_verifier)
+      .await
+      .map_err(ErrorWrapper::from)?;
+
+    Ok(())
+  }
+
   #[napi]
   #[xmtp_common::err_span]
   pub async fn add_scw_signature(
@@ -6,8 +6,8 @@ use xmtp_mls::messages::decoded_message::MessageBody;
 use super::{
   actions::Actions, attachment::Attachment, deleted_message::DeletedMessage,
   group_updated::GroupUpdated, intent::Intent, leave_request::LeaveRequest,
-  multi_remote_attachment::MultiRemoteAttachment, reaction::Reaction, read_receipt::ReadReceipt,
-  remote_attachment::RemoteAttachment, reply::EnrichedReply,
+  multi_remote_attachment::MultiRemoteAttachment, poll::EnrichedPoll, reaction::Reaction,
+  read_receipt::ReadReceipt, remote_attachment::RemoteAttachment, reply::EnrichedReply,
   transaction_reference::TransactionReference, wallet_send_calls::WalletSendCalls,
 };
 use crate::encoded_content::EncodedContent;
@@ -24,6 +24,7 @@ pub enum DecodedMessageContent {
   LeaveRequest { content: LeaveRequest },
   Markdown { content: String },
   MultiRemoteAttachment { content: MultiRemoteAttachment },
+  Poll { content: EnrichedPoll },
   Reaction { content: Reaction },
   ReadReceipt { content: ReadReceipt },
   RemoteAttachment { content: RemoteAttachment },
@@ -57,6 +58,7 @@ impl TryFrom<MessageBody> for DecodedMessageContent {
       MessageBody::MultiRemoteAttachment(mra) => Ok(DecodedMessageContent::MultiRemoteAttachment {
         content: mra.into(),
       }),
+      MessageBody::Poll(p) => Ok(DecodedMessageContent::Poll { content: p.into() }),
       MessageBody::Reaction(r) => Ok(DecodedMessageContent::Reaction { content: r.into() }),
       MessageBody::ReadReceipt(rr) => Ok(DecodedMessageContent::ReadReceipt { content: rr.into() }),
       MessageBody::RemoteAttachment(ra) => {
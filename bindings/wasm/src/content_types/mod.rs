@@ -51,6 +51,7 @@ pub mod intent;
 pub mod leave_request;
 pub mod markdown;
 pub mod multi_remote_attachment;
+pub mod poll;
 pub mod reaction;
 pub mod read_receipt;
 pub mod remote_attachment;
@@ -77,6 +78,7 @@ pub enum ContentType {
   Text = 13,
   TransactionReference = 14,
   WalletSendCalls = 15,
+  Poll = 16,
 }
 
 impl From<ContentType> for XmtpContentType {
@@ -98,6 +100,7 @@ impl From<ContentType> for XmtpContentType {
       ContentType::RemoteAttachment => XmtpContentType::RemoteAttachment,
       ContentType::TransactionReference => XmtpContentType::TransactionReference,
       ContentType::WalletSendCalls => XmtpContentType::WalletSendCalls,
+      ContentType::Poll => XmtpContentType::Poll,
     }
   }
 }
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::JsError;
+use wasm_bindgen::prelude::wasm_bindgen;
+use xmtp_content_types::ContentCodec;
+use xmtp_content_types::poll::{PollCodec, PollVoteCodec};
+use xmtp_mls::messages::decoded_message::{Poll as XmtpEnrichedPoll, PollOption as XmtpPollOption};
+use xmtp_proto::xmtp::mls::message_contents::content_types::{
+  Poll as XmtpPoll, PollVote as XmtpPollVote,
+};
+
+use crate::ErrorWrapper;
+use crate::encoded_content::{ContentTypeId, EncodedContent};
+
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct Poll {
+  pub question: String,
+  pub options: Vec<String>,
+  pub allows_multiple_choices: bool,
+}
+
+impl From<Poll> for XmtpPoll {
+  fn from(poll: Poll) -> Self {
+    XmtpPoll {
+      question: poll.question,
+      options: poll.options,
+      allows_multiple_choices: poll.allows_multiple_choices,
+    }
+  }
+}
+
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct PollVote {
+  /// Hex-encoded ID of the poll message
+  pub poll_message_id: String,
+  /// Selected option indexes. An empty list retracts the vote
+  pub option_indexes: Vec<u32>,
+}
+
+impl From<PollVote> for XmtpPollVote {
+  fn from(vote: PollVote) -> Self {
+    XmtpPollVote {
+      poll_message_id: vote.poll_message_id,
+      option_indexes: vote.option_indexes,
+    }
+  }
+}
+
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct PollOption {
+  pub text: String,
+  pub vote_count: u32,
+}
+
+impl From<XmtpPollOption> for PollOption {
+  fn from(option: XmtpPollOption) -> Self {
+    PollOption {
+      text: option.text,
+      vote_count: option.vote_count,
+    }
+  }
+}
+
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct EnrichedPoll {
+  pub question: String,
+  pub options: Vec<PollOption>,
+  pub allows_multiple_choices: bool,
+  pub total_voters: u32,
+  /// Option indexes selected by the current user, empty if they haven't voted
+  pub my_vote: Vec<u32>,
+}
+
+impl From<XmtpEnrichedPoll> for EnrichedPoll {
+  fn from(poll: XmtpEnrichedPoll) -> Self {
+    EnrichedPoll {
+      question: poll.question,
+      options: poll.options.into_iter().map(Into::into).collect(),
+      allows_multiple_choices: poll.allows_multiple_choices,
+      total_voters: poll.total_voters,
+      my_vote: poll.my_vote,
+    }
+  }
+}
+
+#[wasm_bindgen(js_name = "contentTypePoll")]
+pub fn content_type_poll() -> ContentTypeId {
+  PollCodec::content_type().into()
+}
+
+#[wasm_bindgen(js_name = "contentTypePollVote")]
+pub fn content_type_poll_vote() -> ContentTypeId {
+  PollVoteCodec::content_type().into()
+}
+
+#[wasm_bindgen(js_name = "encodePoll")]
+pub fn encode_poll(poll: Poll) -> Result<EncodedContent, JsError> {
+  Ok(
+    PollCodec::encode(poll.into())
+      .map_err(ErrorWrapper::js)?
+      .into(),
+  )
+}
+
+#[wasm_bindgen(js_name = "encodePollVote")]
+pub fn encode_poll_vote(vote: PollVote) -> Result<EncodedContent, JsError> {
+  Ok(
+    PollVoteCodec::encode(vote.into())
+      .map_err(ErrorWrapper::js)?
+      .into(),
+  )
+}
@@ -21,7 +21,7 @@ use crate::conversations::{
 };
 use crate::encoded_content::EncodedContent;
 use crate::identity::{Identifier, IdentityExt};
-use crate::messages::{ListMessagesOptions, Message};
+use crate::messages::{Draft, ListMessagesOptions, Message, MessageSearchHit, ScheduledMessage};
 use crate::permissions::{MetadataField, PermissionPolicy, PermissionUpdateType};
 use crate::streams::{StreamCallback, StreamCloser};
 use crate::{
@@ -46,18 +46,19 @@ use xmtp_db::group::DmIdExt;
 use xmtp_mls::{
   groups::{
     MlsGroup, UpdateAdminListType, intents::PermissionUpdateType as XmtpPermissionUpdateType,
-    members::PermissionLevel as XmtpPermissionLevel,
+    members::PermissionLevel as XmtpPermissionLevel, moderators::UpdateModeratorListType,
   },
   mls_common::{
     group_metadata::GroupMetadata as XmtpGroupMetadata,
     group_mutable_metadata::MetadataField as XmtpMetadataField,
+    invite::group_invites::GroupInvite as XmtpGroupInvite,
   },
 };
 use xmtp_proto::types::GroupId;
 use xmtp_proto::xmtp::mls::message_contents::EncodedContent as XmtpEncodedContent;
 
 #[derive(Clone, Serialize, Deserialize, Tsify)]
-#[tsify(into_wasm_abi, from_wasm_abi)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
 pub struct SendMessageOpts {
   pub should_push: bool,
@@ -69,6 +70,19 @@ pub struct SendMessageOpts {
   #[tsify(optional)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub idempotency_key: Option<String>,
+  /// Delete the message this long after it was sent, regardless of the
+  /// conversation's disappearing messages settings.
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expire_after_ns: Option<i64>,
+  /// Delete the message on receivers once they have viewed it.
+  ///
+  /// Either option first raises the conversation's minimum protocol version so
+  /// older clients can't keep the message; optimistic and scheduled sends
+  /// fail until a regular send has done so.
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub view_once: Option<bool>,
 }
 
 impl From<SendMessageOpts> for xmtp_mls::groups::send_message_opts::SendMessageOpts {
@@ -76,6 +90,8 @@ impl From<SendMessageOpts> for xmtp_mls::groups::send_message_opts::SendMessageO
     xmtp_mls::groups::send_message_opts::SendMessageOpts {
       should_push: opts.should_push,
       idempotency_key: opts.idempotency_key,
+      expire_after_ns: opts.expire_after_ns,
+      view_once: opts.view_once.unwrap_or_default(),
     }
   }
 }
@@ -106,6 +122,8 @@ impl SendMessageOpts {
       should_push,
       optimistic: opts.optimistic,
       idempotency_key: opts.idempotency_key,
+      expire_after_ns: None,
+      view_once: None,
     }
   }
 }
@@ -214,6 +232,26 @@ pub enum PermissionLevel {
   SuperAdmin = 2,
 }
 
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupInvite {
+  pub external_group_id: String,
+  /// `0` if the invite does not expire.
+  pub expires_at_ns: u64,
+  pub created_by_inbox_id: String,
+}
+
+impl From<XmtpGroupInvite> for GroupInvite {
+  fn from(invite: XmtpGroupInvite) -> Self {
+    Self {
+      external_group_id: hex::encode(invite.policy.external_group_id),
+      expires_at_ns: invite.policy.expires_at_ns,
+      created_by_inbox_id: invite.created_by_inbox_id,
+    }
+  }
+}
+
 #[derive(Clone, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
@@ -337,6 +375,103 @@ impl Conversation {
     Ok(())
   }
 
+  /// Store a message now and publish it in the background once `publishAtNs`
+  /// has passed, including after an app restart. Returns the message ID.
+  #[wasm_bindgen(js_name = sendAt)]
+  pub fn send_at(
+    &self,
+    #[wasm_bindgen(js_name = encodedContent)] encoded_content: EncodedContent,
+    #[wasm_bindgen(js_name = publishAtNs)] publish_at_ns: i64,
+    opts: SendMessageOpts,
+  ) -> Result<String, JsError> {
+    let encoded_content: XmtpEncodedContent = encoded_content.into();
+    let group = self.to_mls_group();
+    let message_id = group
+      .send_message_at(
+        encoded_content.encode_to_vec().as_slice(),
+        publish_at_ns,
+        opts.into(),
+      )
+      .map_err(ErrorWrapper::js)?;
+    Ok(hex::encode(message_id))
+  }
+
+  /// Messages waiting to be published, ordered by publish time.
+  #[wasm_bindgen(js_name = scheduledMessages)]
+  pub fn scheduled_messages(&self) -> Result<Vec<ScheduledMessage>, JsError> {
+    let group = self.to_mls_group();
+    let scheduled = group
+      .scheduled_messages()
+      .map_err(ErrorWrapper::js)?
+      .into_iter()
+      .map(Into::into)
+      .collect();
+    Ok(scheduled)
+  }
+
+  /// Cancel a scheduled message that has not been published yet.
+  #[wasm_bindgen(js_name = cancelScheduledMessage)]
+  pub fn cancel_scheduled_message(
+    &self,
+    #[wasm_bindgen(js_name = messageId)] message_id: String,
+  ) -> Result<(), JsError> {
+    let group = self.to_mls_group();
+    let message_id_bytes =
+      hex::decode(&message_id).map_err(|e| JsError::new(&format!("Invalid hex: {}", e)))?;
+    group
+      .cancel_scheduled_message(&message_id_bytes)
+      .map_err(ErrorWrapper::js)?;
+    Ok(())
+  }
+
+  /// Save the unsent draft for this conversation, replacing any previous one.
+  /// Drafts are stored locally and never sent.
+  #[wasm_bindgen(js_name = setDraft)]
+  pub fn set_draft(
+    &self,
+    #[wasm_bindgen(js_name = encodedContent)] encoded_content: EncodedContent,
+    #[wasm_bindgen(js_name = replyToMessageId)] reply_to_message_id: Option<String>,
+  ) -> Result<(), JsError> {
+    let group = self.to_mls_group();
+    let reply_to_message_id = reply_to_message_id
+      .map(hex::decode)
+      .transpose()
+      .map_err(|e| JsError::new(&format!("Invalid hex: {}", e)))?;
+    group
+      .set_draft(encoded_content.into(), reply_to_message_id)
+      .map_err(ErrorWrapper::js)?;
+    Ok(())
+  }
+
+  #[wasm_bindgen(js_name = getDraft)]
+  pub fn get_draft(&self) -> Result<Option<Draft>, JsError> {
+    let group = self.to_mls_group();
+    let draft = group.get_draft().map_err(ErrorWrapper::js)?;
+    Ok(draft.map(Into::into))
+  }
+
+  #[wasm_bindgen(js_name = clearDraft)]
+  pub fn clear_draft(&self) -> Result<(), JsError> {
+    let group = self.to_mls_group();
+    group.clear_draft().map_err(ErrorWrapper::js)?;
+    Ok(())
+  }
+
+  /// Mark every message up to and including `upToMessageId` as read. The read
+  /// cursor is synced to the user's other installations.
+  #[wasm_bindgen(js_name = markRead)]
+  pub fn mark_read(
+    &self,
+    #[wasm_bindgen(js_name = upToMessageId)] up_to_message_id: String,
+  ) -> Result<(), JsError> {
+    let up_to_message_id = hex::decode(up_to_message_id).map_err(ErrorWrapper::js)?;
+    let group = self.to_mls_group();
+    group
+      .mark_read(&up_to_message_id)
+      .map_err(ErrorWrapper::js)?;
+    Ok(())
+  }
+
   #[wasm_bindgen(js_name = sendText)]
   pub async fn send_text(&self, text: String, opts: Option<SendOpts>) -> Result<String, JsError> {
     let encoded_content = TextCodec::encode(text).map_err(ErrorWrapper::js)?;
@@ -462,6 +597,36 @@ impl Conversation {
     self.send(encoded_content.into(), opts).await
   }
 
+  /// Edit a message sent by this inbox. Returns the ID of the edit message.
+  #[wasm_bindgen(js_name = editMessage)]
+  pub fn edit_message(
+    &self,
+    #[wasm_bindgen(js_name = messageId)] message_id: String,
+    #[wasm_bindgen(js_name = editedContent)] edited_content: EncodedContent,
+  ) -> Result<String, JsError> {
+    let message_id = hex::decode(message_id).map_err(ErrorWrapper::js)?;
+    let group = self.to_mls_group();
+    let edit_id = group
+      .edit_message(message_id, edited_content.into())
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(hex::encode(edit_id))
+  }
+
+  /// Mark a message as viewed. View-once messages from other members are
+  /// deleted from this installation. Returns whether the message was deleted.
+  #[wasm_bindgen(js_name = markViewed)]
+  pub fn mark_viewed(
+    &self,
+    #[wasm_bindgen(js_name = messageId)] message_id: String,
+  ) -> Result<bool, JsError> {
+    let message_id = hex::decode(message_id).map_err(ErrorWrapper::js)?;
+    let group = self.to_mls_group();
+    let deleted = group.mark_viewed(&message_id).map_err(ErrorWrapper::js)?;
+
+    Ok(deleted)
+  }
+
   /// Publish all unpublished messages
   #[wasm_bindgen(js_name = publishMessages)]
   pub async fn publish_messages(&self) -> Result<(), JsError> {
@@ -497,6 +662,24 @@ impl Conversation {
     Ok(messages)
   }
 
+  #[wasm_bindgen(js_name = searchMessages)]
+  pub async fn search_messages(
+    &self,
+    query: String,
+    opts: Option<ListMessagesOptions>,
+  ) -> Result<Vec<MessageSearchHit>, JsError> {
+    let opts = opts.unwrap_or_default();
+    let group = self.to_mls_group();
+    let hits: Vec<MessageSearchHit> = group
+      .search_messages(&query, &opts.into())
+      .map_err(ErrorWrapper::js)?
+      .into_iter()
+      .map(Into::into)
+      .collect();
+
+    Ok(hits)
+  }
+
   #[wasm_bindgen(js_name = countMessages)]
   pub async fn count_messages(&self, opts: Option<ListMessagesOptions>) -> Result<i64, JsError> {
     let opts = opts.unwrap_or_default();
@@ -657,6 +840,53 @@ impl Conversation {
     Ok(())
   }
 
+  #[wasm_bindgen(js_name = moderatorList)]
+  pub fn moderator_list(&self) -> Result<Vec<String>, JsError> {
+    let group = self.to_mls_group();
+    let moderator_list = group.moderator_list().map_err(ErrorWrapper::js)?;
+
+    Ok(moderator_list)
+  }
+
+  #[wasm_bindgen(js_name = isModerator)]
+  pub fn is_moderator(
+    &self,
+    #[wasm_bindgen(js_name = inboxId)] inbox_id: String,
+  ) -> Result<bool, JsError> {
+    let moderator_list = self.moderator_list()?;
+    Ok(moderator_list.contains(&inbox_id))
+  }
+
+  #[wasm_bindgen(js_name = addModerator)]
+  pub async fn add_moderator(
+    &self,
+    #[wasm_bindgen(js_name = inboxId)] inbox_id: String,
+  ) -> Result<(), JsError> {
+    let group = self.to_mls_group();
+
+    group
+      .update_moderator_list(UpdateModeratorListType::Add, inbox_id)
+      .await
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+
+  #[wasm_bindgen(js_name = removeModerator)]
+  pub async fn remove_moderator(
+    &self,
+    #[wasm_bindgen(js_name = inboxId)] inbox_id: String,
+  ) -> Result<(), JsError> {
+    let group = self.to_mls_group();
+
+    group
+      .update_moderator_list(UpdateModeratorListType::Remove, inbox_id)
+      .await
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+
   #[wasm_bindgen(js_name = groupPermissions)]
   pub fn group_permissions(&self) -> Result<GroupPermissions, JsError> {
     let group = self.to_mls_group();
@@ -776,6 +1006,89 @@ impl Conversation {
     Ok(app_data)
   }
 
+  #[wasm_bindgen(js_name = pinMessage)]
+  pub async fn pin_message(
+    &self,
+    #[wasm_bindgen(js_name = messageId)] message_id: String,
+  ) -> Result<(), JsError> {
+    let message_id = hex::decode(message_id).map_err(ErrorWrapper::js)?;
+    let group = self.to_mls_group();
+
+    group
+      .pin_message(&message_id)
+      .await
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+
+  #[wasm_bindgen(js_name = unpinMessage)]
+  pub async fn unpin_message(
+    &self,
+    #[wasm_bindgen(js_name = messageId)] message_id: String,
+  ) -> Result<(), JsError> {
+    let message_id = hex::decode(message_id).map_err(ErrorWrapper::js)?;
+    let group = self.to_mls_group();
+
+    group
+      .unpin_message(&message_id)
+      .await
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+
+  #[wasm_bindgen(js_name = pinnedMessages)]
+  pub fn pinned_messages(&self) -> Result<Vec<String>, JsError> {
+    let group = self.to_mls_group();
+
+    let pinned = group.pinned_messages().map_err(ErrorWrapper::js)?;
+
+    Ok(pinned.into_iter().map(hex::encode).collect())
+  }
+
+  /// Create an invite and return the serialized payload to share. The invite
+  /// admits joiners until `expiresAtNs`, if given, or until it is revoked.
+  #[wasm_bindgen(js_name = createInvite)]
+  pub async fn create_invite(
+    &self,
+    #[wasm_bindgen(js_name = expiresAtNs)] expires_at_ns: Option<i64>,
+  ) -> Result<Uint8Array, JsError> {
+    let group = self.to_mls_group();
+
+    let invite = group
+      .create_invite(expires_at_ns)
+      .await
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(Uint8Array::from(invite.encode_to_vec().as_slice()))
+  }
+
+  #[wasm_bindgen]
+  pub fn invites(&self) -> Result<Vec<GroupInvite>, JsError> {
+    let group = self.to_mls_group();
+
+    let invites = group.invites().map_err(ErrorWrapper::js)?;
+
+    Ok(invites.into_iter().map(Into::into).collect())
+  }
+
+  #[wasm_bindgen(js_name = revokeInvite)]
+  pub async fn revoke_invite(
+    &self,
+    #[wasm_bindgen(js_name = externalGroupId)] external_group_id: String,
+  ) -> Result<(), JsError> {
+    let external_group_id = hex::decode(external_group_id).map_err(ErrorWrapper::js)?;
+    let group = self.to_mls_group();
+
+    group
+      .revoke_invite(&external_group_id)
+      .await
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+
   #[wasm_bindgen(js_name = updateGroupImageUrlSquare)]
   pub async fn update_group_image_url_square(
     &self,
@@ -1091,6 +1404,8 @@ mod tests {
       expire_at_ns: None,
       should_push: true,
       idempotency_key: 1738354508964432000i64.to_string(),
+      expire_after_ns: None,
+      view_once: false,
     };
     crate::to_value(&stored_message).unwrap();
   }
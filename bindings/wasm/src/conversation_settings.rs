@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+use xmtp_db::conversation_settings::StoredConversationSettings;
+
+use crate::ErrorWrapper;
+use crate::conversation::Conversation;
+
+/// How the user has organized a conversation in their inbox.
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationSettings {
+  pub conversation_id: String,
+  /// Notifications are muted until this time. Not set when not muted.
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub muted_until_ns: Option<i64>,
+  pub archived: bool,
+  pub pinned: bool,
+}
+
+impl From<StoredConversationSettings> for ConversationSettings {
+  fn from(settings: StoredConversationSettings) -> Self {
+    Self {
+      conversation_id: hex::encode(settings.group_id),
+      muted_until_ns: settings.muted_until_ns,
+      archived: settings.archived,
+      pinned: settings.pinned,
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl Conversation {
+  #[wasm_bindgen(js_name = conversationSettings)]
+  pub fn conversation_settings(&self) -> Result<ConversationSettings, JsError> {
+    let group = self.to_mls_group();
+    let settings = group.conversation_settings().map_err(ErrorWrapper::js)?;
+
+    Ok(settings.into())
+  }
+
+  /// Mute notifications until `mutedUntilNs`, or unmute when not set.
+  /// Settings are synced to the user's other installations.
+  #[wasm_bindgen(js_name = setMutedUntil)]
+  pub fn set_muted_until(
+    &self,
+    #[wasm_bindgen(js_name = mutedUntilNs)] muted_until_ns: Option<i64>,
+  ) -> Result<(), JsError> {
+    let group = self.to_mls_group();
+    group
+      .set_muted_until(muted_until_ns)
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+
+  #[wasm_bindgen(js_name = setArchived)]
+  pub fn set_archived(&self, archived: bool) -> Result<(), JsError> {
+    let group = self.to_mls_group();
+    group.set_archived(archived).map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+
+  #[wasm_bindgen(js_name = setPinned)]
+  pub fn set_pinned(&self, pinned: bool) -> Result<(), JsError> {
+    let group = self.to_mls_group();
+    group.set_pinned(pinned).map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+}
@@ -1,4 +1,6 @@
 use bindings_wasm_macros::wasm_bindgen_numbered_enum;
+use js_sys::Uint8Array;
+use prost::Message as ProstMessage;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -16,12 +18,13 @@ use xmtp_mls::groups::PreconfiguredPolicies;
 use xmtp_mls::mls_common::group::{DMMetadataOptions, GroupMetadataOptions};
 use xmtp_mls::mls_common::group_mutable_metadata::MessageDisappearingSettings as XmtpMessageDisappearingSettings;
 use xmtp_proto::types::Cursor as XmtpCursor;
+use xmtp_proto::xmtp::mls::message_contents::ExternalInvitePayload;
 
 use crate::ErrorWrapper;
 use crate::consent_state::{Consent, ConsentState};
 use crate::enriched_message::DecodedMessage;
 use crate::identity::Identifier;
-use crate::messages::Message;
+use crate::messages::{ListMessagesOptions, Message, MessageSearchHit};
 use crate::permissions::{GroupPermissionsOptions, PermissionPolicySet};
 use crate::streams::{ConversationStream, StreamCallback, StreamCloser};
 use crate::user_preferences::UserPreferenceUpdate;
@@ -130,6 +133,18 @@ pub struct ListConversationsOptions {
   #[tsify(optional)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub limit: Option<i64>,
+  /// Only archived (`true`) or unarchived (`false`) conversations
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub archived: Option<bool>,
+  /// Only pinned (`true`) or unpinned (`false`) conversations
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pinned: Option<bool>,
+  /// Only conversations that are (`true`) or are not (`false`) muted right now
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub muted: Option<bool>,
 }
 
 impl From<ListConversationsOptions> for GroupQueryArgs {
@@ -149,6 +164,12 @@ impl From<ListConversationsOptions> for GroupQueryArgs {
       last_activity_after_ns: None,
       should_publish_commit_log: None,
       order_by: opts.order_by.map(Into::into),
+      group_ids: None,
+      exclude_group_ids: None,
+      changed_after_ns: None,
+      archived: opts.archived,
+      pinned: opts.pinned,
+      muted: opts.muted,
     }
   }
 }
@@ -298,6 +319,18 @@ pub struct ConversationListItem {
   pub last_message: Option<Message>,
   #[wasm_bindgen(js_name = isCommitLogForked)]
   pub is_commit_log_forked: Option<bool>,
+  #[wasm_bindgen(js_name = hasDraft)]
+  pub has_draft: bool,
+  #[wasm_bindgen(js_name = unreadCount)]
+  pub unread_count: u32,
+  #[wasm_bindgen(js_name = firstUnreadMessageId)]
+  pub first_unread_message_id: Option<String>,
+  #[wasm_bindgen(js_name = mutedUntilNs)]
+  pub muted_until_ns: Option<i64>,
+  #[wasm_bindgen(js_name = isArchived)]
+  pub is_archived: bool,
+  #[wasm_bindgen(js_name = isPinned)]
+  pub is_pinned: bool,
 }
 
 #[wasm_bindgen]
@@ -307,11 +340,23 @@ impl ConversationListItem {
     conversation: Conversation,
     #[wasm_bindgen(js_name = lastMessage)] last_message: Option<Message>,
     #[wasm_bindgen(js_name = isCommitLogForked)] is_commit_log_forked: Option<bool>,
+    #[wasm_bindgen(js_name = hasDraft)] has_draft: bool,
+    #[wasm_bindgen(js_name = unreadCount)] unread_count: u32,
+    #[wasm_bindgen(js_name = firstUnreadMessageId)] first_unread_message_id: Option<String>,
+    #[wasm_bindgen(js_name = mutedUntilNs)] muted_until_ns: Option<i64>,
+    #[wasm_bindgen(js_name = isArchived)] is_archived: bool,
+    #[wasm_bindgen(js_name = isPinned)] is_pinned: bool,
   ) -> Self {
     Self {
       conversation,
       last_message,
       is_commit_log_forked,
+      has_draft,
+      unread_count,
+      first_unread_message_id,
+      muted_until_ns,
+      is_archived,
+      is_pinned,
     }
   }
 }
@@ -413,6 +458,21 @@ impl Conversations {
     Ok(convo)
   }
 
+  /// Join a group with the serialized invite returned by `Conversation.createInvite`.
+  #[wasm_bindgen(js_name = joinWithInvite)]
+  pub async fn join_with_invite(&self, invite: Uint8Array) -> Result<Conversation, JsError> {
+    let invite = ExternalInvitePayload::decode(invite.to_vec().as_slice())
+      .map_err(|e| JsError::new(&e.to_string()))?;
+
+    let group = self
+      .inner_client
+      .join_with_invite(&invite)
+      .await
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(group.into())
+  }
+
   #[wasm_bindgen(js_name = createDm)]
   pub async fn find_or_create_dm(
     &self,
@@ -505,6 +565,24 @@ impl Conversations {
     message.try_into()
   }
 
+  #[wasm_bindgen(js_name = searchMessages)]
+  pub fn search_messages(
+    &self,
+    query: String,
+    opts: Option<ListMessagesOptions>,
+  ) -> Result<Vec<MessageSearchHit>, JsError> {
+    let opts = opts.unwrap_or_default();
+    let hits: Vec<MessageSearchHit> = self
+      .inner_client
+      .search_messages(&query, &opts.into())
+      .map_err(ErrorWrapper::js)?
+      .into_iter()
+      .map(Into::into)
+      .collect();
+
+    Ok(hits)
+  }
+
   #[wasm_bindgen(js_name = deleteMessageById)]
   pub fn delete_message_by_id(
     &self,
@@ -560,6 +638,12 @@ impl Conversations {
           group.group.into(),
           group.last_message.map(|m| m.into()),
           group.is_commit_log_forked,
+          group.has_draft,
+          group.unread_count as u32,
+          group.first_unread_message_id.map(hex::encode),
+          group.muted_until_ns,
+          group.is_archived,
+          group.is_pinned,
         ))
       })
       .collect();
@@ -1,5 +1,6 @@
 use crate::ErrorWrapper;
 use crate::client::{GroupSyncSummary, RustXmtpClient};
+use crate::consent_state::ConsentState;
 use bindings_wasm_macros::wasm_bindgen_numbered_enum;
 use js_sys::Uint8Array;
 use serde::{Deserialize, Serialize};
@@ -10,7 +11,8 @@ use xmtp_id::associations::DeserializationError;
 use xmtp_mls::worker::device_sync::{
   ArchiveOptions as XmtpArchiveOptions, AvailableArchive, BackupElementSelection,
   archive::{
-    ArchiveImporter, BackupMetadata, ENC_KEY_SIZE, exporter::ArchiveExporter, insert_importer,
+    ArchiveImporter, ArchiveReport as XmtpArchiveReport, BackupMetadata, ENC_KEY_SIZE,
+    exporter::ArchiveExporter, insert_importer,
   },
 };
 use xmtp_proto::xmtp::device_sync::BackupElementSelection as BackupElementSelectionProto;
@@ -28,16 +30,45 @@ pub struct ArchiveOptions {
   pub end_ns: Option<i64>,
   pub elements: Vec<BackupElementSelectionOption>,
   pub exclude_disappearing_messages: bool,
+  /// Only export these conversations (hex-encoded ids)
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub include_group_ids: Option<Vec<String>>,
+  /// Never export these conversations (hex-encoded ids)
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub exclude_group_ids: Option<Vec<String>>,
+  /// Only export conversations with one of these consent states
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub consent_states: Option<Vec<ConsentState>>,
 }
 
-impl From<ArchiveOptions> for XmtpArchiveOptions {
-  fn from(value: ArchiveOptions) -> Self {
-    Self {
+fn decode_group_ids(group_ids: Option<Vec<String>>) -> Result<Vec<Vec<u8>>, hex::FromHexError> {
+  group_ids
+    .unwrap_or_default()
+    .iter()
+    .map(hex::decode)
+    .collect()
+}
+
+impl TryFrom<ArchiveOptions> for XmtpArchiveOptions {
+  type Error = hex::FromHexError;
+  fn try_from(value: ArchiveOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
       start_ns: value.start_ns,
       end_ns: value.end_ns,
       elements: value.elements.into_iter().map(|el| el.into()).collect(),
       exclude_disappearing_messages: value.exclude_disappearing_messages,
-    }
+      include_group_ids: decode_group_ids(value.include_group_ids)?,
+      exclude_group_ids: decode_group_ids(value.exclude_group_ids)?,
+      consent_states: value
+        .consent_states
+        .unwrap_or_default()
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+    })
   }
 }
 
@@ -46,12 +77,14 @@ impl From<ArchiveOptions> for XmtpArchiveOptions {
 pub enum BackupElementSelectionOption {
   Messages = 0,
   Consent = 1,
+  Drafts = 2,
 }
 
 impl From<BackupElementSelectionOption> for BackupElementSelection {
   fn from(value: BackupElementSelectionOption) -> Self {
     match value {
       BackupElementSelectionOption::Consent => Self::Consent,
+      BackupElementSelectionOption::Drafts => Self::Drafts,
       BackupElementSelectionOption::Messages => Self::Messages,
     }
   }
@@ -61,6 +94,7 @@ impl From<BackupElementSelectionOption> for BackupElementSelectionProto {
   fn from(value: BackupElementSelectionOption) -> Self {
     match value {
       BackupElementSelectionOption::Consent => Self::Consent,
+      BackupElementSelectionOption::Drafts => Self::Drafts,
       BackupElementSelectionOption::Messages => Self::Messages,
     }
   }
@@ -71,6 +105,7 @@ impl TryFrom<BackupElementSelection> for BackupElementSelectionOption {
   fn try_from(value: BackupElementSelection) -> Result<Self, Self::Error> {
     let v = match value {
       BackupElementSelection::Consent => Self::Consent,
+      BackupElementSelection::Drafts => Self::Drafts,
       BackupElementSelection::Messages => Self::Messages,
       _ => {
         return Err(DeserializationError::Unspecified(
@@ -87,6 +122,7 @@ impl TryFrom<BackupElementSelectionProto> for BackupElementSelectionOption {
   fn try_from(value: BackupElementSelectionProto) -> Result<Self, Self::Error> {
     let v = match value {
       BackupElementSelectionProto::Consent => Self::Consent,
+      BackupElementSelectionProto::Drafts => Self::Drafts,
       BackupElementSelectionProto::Messages => Self::Messages,
       _ => {
         return Err(DeserializationError::Unspecified(
@@ -112,6 +148,16 @@ pub struct ArchiveMetadata {
   #[tsify(optional)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub end_ns: Option<i64>,
+  pub include_group_ids: Vec<String>,
+  pub exclude_group_ids: Vec<String>,
+  pub consent_states: Vec<ConsentState>,
+  pub archive_id: String,
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub previous_archive_id: Option<String>,
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub since_ns: Option<i64>,
 }
 
 impl From<BackupMetadata> for ArchiveMetadata {
@@ -126,6 +172,48 @@ impl From<BackupMetadata> for ArchiveMetadata {
       start_ns: value.start_ns,
       end_ns: value.end_ns,
       exported_at_ns: value.exported_at_ns,
+      include_group_ids: value.include_group_ids.iter().map(hex::encode).collect(),
+      exclude_group_ids: value.exclude_group_ids.iter().map(hex::encode).collect(),
+      consent_states: value.consent_states.into_iter().map(Into::into).collect(),
+      archive_id: hex::encode(&value.archive_id),
+      previous_archive_id: value.previous_archive_id.as_ref().map(hex::encode),
+      since_ns: value.since_ns,
+    }
+  }
+}
+
+/// What an archive holds, read without importing it
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveReport {
+  pub metadata: ArchiveMetadata,
+  pub group_count: u64,
+  pub group_message_count: u64,
+  pub consent_count: u64,
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub earliest_message_ns: Option<i64>,
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub latest_message_ns: Option<i64>,
+  /// Whether the archive ended with a manifest whose MAC and contents check out
+  pub manifest_verified: bool,
+  /// Decryption, decode and manifest failures, in the order they were found
+  pub failures: Vec<String>,
+}
+
+impl From<XmtpArchiveReport> for ArchiveReport {
+  fn from(value: XmtpArchiveReport) -> Self {
+    Self {
+      metadata: value.metadata.into(),
+      group_count: value.group_count,
+      group_message_count: value.group_message_count,
+      consent_count: value.consent_count,
+      earliest_message_ns: value.earliest_message_ns,
+      latest_message_ns: value.latest_message_ns,
+      manifest_verified: value.manifest_verified,
+      failures: value.failures,
     }
   }
 }
@@ -188,7 +276,10 @@ impl DeviceSync {
     self
       .inner_client
       .device_sync_client()
-      .send_sync_request(options.into(), server_url)
+      .send_sync_request(
+        XmtpArchiveOptions::try_from(options).map_err(ErrorWrapper::js)?,
+        server_url,
+      )
       .await
       .map_err(ErrorWrapper::js)?;
 
@@ -204,10 +295,11 @@ impl DeviceSync {
     #[wasm_bindgen(js_name = serverUrl)] server_url: String,
     pin: String,
   ) -> Result<(), JsError> {
+    let options = XmtpArchiveOptions::try_from(options).map_err(ErrorWrapper::js)?;
     self
       .inner_client
       .device_sync_client()
-      .send_sync_archive(&options.into(), &server_url, &pin)
+      .send_sync_archive(&options, &server_url, &pin)
       .await
       .map_err(ErrorWrapper::js)?;
     Ok(())
@@ -253,8 +345,33 @@ impl DeviceSync {
     use futures::AsyncReadExt;
 
     let key = check_key(&key)?;
+    let opts = XmtpArchiveOptions::try_from(opts).map_err(ErrorWrapper::js)?;
     let db = self.inner_client.context.db();
-    let mut exporter = ArchiveExporter::new(opts.into(), db, &key);
+    let mut exporter = ArchiveExporter::new(opts, db, &key);
+
+    let mut buffer = Vec::new();
+    exporter
+      .read_to_end(&mut buffer)
+      .await
+      .map_err(|e| JsError::new(&format!("Failed to export archive: {}", e)))?;
+
+    Ok(Uint8Array::from(buffer.as_slice()))
+  }
+
+  /// Export archive data to bytes, encrypted with a key derived from `passphrase`.
+  #[wasm_bindgen(js_name = createArchiveWithPassphrase)]
+  pub async fn create_archive_with_passphrase(
+    &self,
+    opts: ArchiveOptions,
+    passphrase: String,
+  ) -> Result<Uint8Array, JsError> {
+    use futures::AsyncReadExt;
+
+    let opts = XmtpArchiveOptions::try_from(opts).map_err(ErrorWrapper::js)?;
+    let db = self.inner_client.context.db();
+    let mut exporter = ArchiveExporter::with_passphrase(opts, db, &passphrase)
+      .await
+      .map_err(|e| JsError::new(&format!("Failed to export archive: {}", e)))?;
 
     let mut buffer = Vec::new();
     exporter
@@ -285,6 +402,29 @@ impl DeviceSync {
     Ok(())
   }
 
+  /// Import an archive that was created with a passphrase from bytes.
+  #[wasm_bindgen(js_name = importArchiveWithPassphrase)]
+  pub async fn import_archive_with_passphrase(
+    &self,
+    data: Uint8Array,
+    passphrase: String,
+  ) -> Result<(), JsError> {
+    use futures::io::{BufReader, Cursor};
+
+    let data = data.to_vec();
+
+    let reader = Box::pin(BufReader::new(Cursor::new(data)));
+    let mut importer = ArchiveImporter::load_with_passphrase(reader, &passphrase)
+      .await
+      .map_err(|e| JsError::new(&format!("Failed to load archive: {}", e)))?;
+
+    insert_importer(&mut importer, &self.inner_client.context)
+      .await
+      .map_err(|e| JsError::new(&format!("Failed to import archive: {}", e)))?;
+
+    Ok(())
+  }
+
   /// Load the metadata for an archive to see what it contains.
   #[wasm_bindgen(js_name = archiveMetadata)]
   pub async fn archive_metadata(
@@ -305,6 +445,27 @@ impl DeviceSync {
     Ok(importer.metadata.into())
   }
 
+  /// Read through a whole archive without importing it,
+  /// to check that it's intact before restoring from it.
+  #[wasm_bindgen(js_name = verifyArchive)]
+  pub async fn verify_archive(
+    &self,
+    data: Uint8Array,
+    key: Uint8Array,
+  ) -> Result<ArchiveReport, JsError> {
+    use futures::io::{BufReader, Cursor};
+
+    let key = check_key(&key)?;
+    let data = data.to_vec();
+
+    let reader = Box::pin(BufReader::new(Cursor::new(data)));
+    let importer = ArchiveImporter::load(reader, &key)
+      .await
+      .map_err(|e| JsError::new(&format!("Failed to load archive: {}", e)))?;
+
+    Ok(importer.verify().await.into())
+  }
+
   /// Manually sync all device sync groups.
   #[wasm_bindgen(js_name = syncAllDeviceSyncGroups)]
   pub async fn sync_all_device_sync_groups(&self) -> Result<GroupSyncSummary, JsError> {
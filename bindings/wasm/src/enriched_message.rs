@@ -1,12 +1,44 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::JsError;
-use xmtp_mls::messages::decoded_message::DecodedMessage as XmtpDecodedMessage;
+use xmtp_mls::messages::decoded_message::{
+  DecodedMessage as XmtpDecodedMessage, MessageEdit as XmtpMessageEdit,
+};
 
 use crate::content_types::decoded_message_content::DecodedMessageContent;
 use crate::encoded_content::ContentTypeId;
 use crate::messages::{DeliveryStatus, GroupMessageKind};
 
+/// One version of an edited message's content
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(
+  into_wasm_abi,
+  from_wasm_abi,
+  large_number_types_as_bigints,
+  hashmap_as_object
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageEdit {
+  /// The ID of the message that introduced this version
+  pub message_id: String,
+  pub sent_at_ns: i64,
+  pub content_type: ContentTypeId,
+  pub content: DecodedMessageContent,
+}
+
+impl TryFrom<XmtpMessageEdit> for MessageEdit {
+  type Error = JsError;
+
+  fn try_from(edit: XmtpMessageEdit) -> Result<Self, Self::Error> {
+    Ok(Self {
+      message_id: hex::encode(edit.message_id),
+      sent_at_ns: edit.sent_at_ns,
+      content_type: edit.content_type.into(),
+      content: edit.content.try_into()?,
+    })
+  }
+}
+
 #[derive(Clone, Serialize, Deserialize, Tsify)]
 #[tsify(
   into_wasm_abi,
@@ -31,6 +63,10 @@ pub struct DecodedMessage {
   pub delivery_status: DeliveryStatus,
   pub num_replies: i64,
   pub expires_at_ns: Option<i64>,
+  pub view_once: bool,
+  /// Every version of the content, oldest first, starting with the original.
+  /// Empty if the message was never edited.
+  pub edit_history: Vec<MessageEdit>,
 }
 
 impl TryFrom<XmtpDecodedMessage> for DecodedMessage {
@@ -39,6 +75,8 @@ impl TryFrom<XmtpDecodedMessage> for DecodedMessage {
   fn try_from(msg: XmtpDecodedMessage) -> Result<Self, Self::Error> {
     let content = msg.content.try_into()?;
     let reactions: Result<Vec<_>, _> = msg.reactions.into_iter().map(|r| r.try_into()).collect();
+    let edit_history: Result<Vec<_>, _> =
+      msg.edit_history.into_iter().map(|e| e.try_into()).collect();
 
     Ok(Self {
       id: hex::encode(msg.metadata.id),
@@ -54,6 +92,8 @@ impl TryFrom<XmtpDecodedMessage> for DecodedMessage {
       delivery_status: msg.metadata.delivery_status.into(),
       num_replies: msg.num_replies as i64,
       expires_at_ns: msg.metadata.expires_at_ns,
+      view_once: msg.metadata.view_once,
+      edit_history: edit_history?,
     })
   }
 }
@@ -0,0 +1,77 @@
+use prost::Message as ProstMessage;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+use xmtp_mls::groups::{MlsGroup, ephemeral::EphemeralMessage as XmtpEphemeralMessage};
+use xmtp_proto::xmtp::mls::message_contents::EncodedContent as XmtpEncodedContent;
+
+use crate::ErrorWrapper;
+use crate::conversation::Conversation;
+use crate::encoded_content::EncodedContent;
+use crate::streams::{StreamCallback, StreamCloser};
+
+/// A short-lived signal, such as a typing indicator, that is never stored.
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct EphemeralMessage {
+  pub conversation_id: String,
+  pub sender_inbox_id: String,
+  pub sender_installation_id: String,
+  pub content: EncodedContent,
+  pub sent_at_ns: i64,
+  pub expires_at_ns: i64,
+}
+
+impl TryFrom<XmtpEphemeralMessage> for EphemeralMessage {
+  type Error = prost::DecodeError;
+  fn try_from(message: XmtpEphemeralMessage) -> Result<Self, Self::Error> {
+    let content = XmtpEncodedContent::decode(message.content.as_slice())?;
+    Ok(Self {
+      conversation_id: hex::encode(message.group_id),
+      sender_inbox_id: message.sender_inbox_id,
+      sender_installation_id: hex::encode(message.sender_installation_id),
+      content: content.into(),
+      sent_at_ns: message.sent_at_ns,
+      expires_at_ns: message.expires_at_ns,
+    })
+  }
+}
+
+#[wasm_bindgen]
+impl Conversation {
+  /// Send a short-lived signal, such as a typing indicator. Ephemeral messages are
+  /// only delivered to live `streamEphemeral` listeners and are never stored.
+  #[wasm_bindgen(js_name = sendEphemeral)]
+  pub async fn send_ephemeral(
+    &self,
+    #[wasm_bindgen(js_name = encodedContent)] encoded_content: EncodedContent,
+  ) -> Result<(), JsError> {
+    let encoded_content: XmtpEncodedContent = encoded_content.into();
+    let group = self.to_mls_group();
+    group
+      .send_ephemeral(encoded_content.encode_to_vec().as_slice())
+      .await
+      .map_err(ErrorWrapper::js)?;
+
+    Ok(())
+  }
+
+  #[wasm_bindgen(js_name = streamEphemeral)]
+  pub fn stream_ephemeral(&self, callback: StreamCallback) -> Result<StreamCloser, JsError> {
+    let group = self.to_mls_group();
+    let on_close_cb = callback.clone();
+    let stream_closer = MlsGroup::stream_ephemeral_with_callback(
+      group.context.clone(),
+      group.group_id,
+      move |message| match message.map(EphemeralMessage::try_from) {
+        Ok(Ok(item)) => callback.on_ephemeral_message(item),
+        Ok(Err(e)) => callback.on_error(JsError::new(&e.to_string())),
+        Err(e) => callback.on_error(JsError::from(e)),
+      },
+      move || on_close_cb.on_close(),
+    );
+
+    Ok(StreamCloser::new(stream_closer))
+  }
+}
@@ -17,6 +17,7 @@ pub struct Identifier {
 pub enum IdentifierKind {
   Ethereum = 0,
   Passkey = 1,
+  Solana = 2,
 }
 
 impl From<XmtpIdentifier> for Identifier {
@@ -30,6 +31,10 @@ impl From<XmtpIdentifier> for Identifier {
         identifier: hex::encode(key),
         identifier_kind: IdentifierKind::Passkey,
       },
+      XmtpIdentifier::Solana(ident::Solana(addr)) => Self {
+        identifier: addr,
+        identifier_kind: IdentifierKind::Solana,
+      },
     }
   }
 }
@@ -40,6 +45,7 @@ impl TryFrom<Identifier> for XmtpIdentifier {
     let ident = match ident.identifier_kind {
       IdentifierKind::Ethereum => Self::eth(ident.identifier)?,
       IdentifierKind::Passkey => Self::passkey_str(&ident.identifier, None)?,
+      IdentifierKind::Solana => Self::solana(ident.identifier)?,
     };
     Ok(ident)
   }
@@ -6,9 +6,11 @@ xmtp_common::if_wasm! {
     pub mod consent_state;
     pub mod content_types;
     pub mod conversation;
+    pub mod conversation_settings;
     pub mod conversations;
     pub mod device_sync;
     pub mod encoded_content;
+    pub mod ephemeral;
     pub mod enriched_message;
     pub mod identity;
     pub mod inbox_id;
@@ -6,6 +6,9 @@ use xmtp_db::group_message::{
   DeliveryStatus as XmtpDeliveryStatus, GroupMessageKind as XmtpGroupMessageKind, MsgQueryArgs,
   SortBy as XmtpMessageSortBy, SortDirection as XmtpSortDirection, StoredGroupMessage,
 };
+use xmtp_db::message_search::MessageSearchHit as XmtpMessageSearchHit;
+use xmtp_mls::groups::drafts::Draft as XmtpDraft;
+use xmtp_mls::groups::scheduled_messages::ScheduledMessage as XmtpScheduledMessage;
 use xmtp_proto::xmtp::mls::message_contents::EncodedContent as XmtpEncodedContent;
 
 use crate::content_types::ContentType;
@@ -169,6 +172,8 @@ pub struct Message {
   pub content: EncodedContent,
   pub kind: GroupMessageKind,
   pub delivery_status: DeliveryStatus,
+  #[serde(default)]
+  pub view_once: bool,
 }
 
 impl From<StoredGroupMessage> for Message {
@@ -198,6 +203,69 @@ impl From<StoredGroupMessage> for Message {
       content,
       kind: msg.kind.into(),
       delivery_status: msg.delivery_status.into(),
+      view_once: msg.view_once,
+    }
+  }
+}
+
+/// A message matching a full-text search. Matched terms in `snippet` are wrapped in
+/// `<mark>` and `</mark>`.
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageSearchHit {
+  pub message: Message,
+  /// BM25 relevance of the hit. Lower is more relevant.
+  pub rank: f64,
+  pub snippet: String,
+}
+
+impl From<XmtpMessageSearchHit> for MessageSearchHit {
+  fn from(hit: XmtpMessageSearchHit) -> Self {
+    Self {
+      message: hit.message.into(),
+      rank: hit.rank,
+      snippet: hit.snippet,
+    }
+  }
+}
+
+/// A message waiting to be published at `publish_at_ns`.
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledMessage {
+  pub publish_at_ns: i64,
+  pub message: Message,
+}
+
+impl From<XmtpScheduledMessage> for ScheduledMessage {
+  fn from(scheduled: XmtpScheduledMessage) -> Self {
+    Self {
+      publish_at_ns: scheduled.publish_at_ns,
+      message: scheduled.message.into(),
+    }
+  }
+}
+
+/// An unsent draft. Drafts are stored locally and never sent.
+#[derive(Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct Draft {
+  pub content: EncodedContent,
+  #[tsify(optional)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reply_to_message_id: Option<String>,
+  pub updated_at_ns: i64,
+}
+
+impl From<XmtpDraft> for Draft {
+  fn from(draft: XmtpDraft) -> Self {
+    Self {
+      content: draft.content.into(),
+      reply_to_message_id: draft.reply_to_message_id.map(hex::encode),
+      updated_at_ns: draft.updated_at_ns,
     }
   }
 }
@@ -154,6 +154,27 @@ impl SignatureRequestHandle {
     Ok(())
   }
 
+  #[wasm_bindgen(js_name = addSolanaSignature)]
+  pub async fn add_solana_signature(
+    &self,
+    #[wasm_bindgen(js_name = accountIdentifier)] account_identifier: Identifier,
+    #[wasm_bindgen(js_name = signatureBytes)] signature_bytes: Uint8Array,
+  ) -> Result<(), JsError> {
+    let XmtpIdentifier::Solana(address) = account_identifier.try_into()? else {
+      return Err(JsError::new("Account identifier must be Solana-based"));
+    };
+    let sig = UnverifiedSignature::new_solana(signature_bytes.to_vec(), &address)
+      .map_err(ErrorWrapper::js)?;
+    self
+      .inner
+      .lock()
+      .await
+      .add_signature(sig, &self.scw_verifier)
+      .await
+      .map_err(ErrorWrapper::js)?;
+    Ok(())
+  }
+
   #[wasm_bindgen(js_name = addScwSignature)]
   pub async fn add_scw_signature(
     &self,
@@ -2,6 +2,7 @@ use crate::ErrorWrapper;
 use crate::client::RustMlsGroup;
 use crate::conversation::Conversation;
 use crate::enriched_message::DecodedMessage;
+use crate::ephemeral::EphemeralMessage;
 use crate::messages::Message;
 use crate::user_preferences::UserPreferenceUpdate;
 use futures::Stream;
@@ -34,6 +35,9 @@ extern "C" {
   #[wasm_bindgen(structural, method)]
   pub fn on_user_preference_update(this: &StreamCallback, item: Vec<UserPreferenceUpdate>);
 
+  #[wasm_bindgen(structural, method)]
+  pub fn on_ephemeral_message(this: &StreamCallback, item: EphemeralMessage);
+
   #[wasm_bindgen(structural, method)]
   pub fn on_conversation(this: &StreamCallback, item: Conversation);
 
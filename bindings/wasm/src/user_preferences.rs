@@ -1,4 +1,5 @@
 use crate::consent_state::Consent;
+use crate::conversation_settings::ConversationSettings;
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use xmtp_mls::worker::device_sync::preference_sync::PreferenceUpdate;
@@ -15,6 +16,14 @@ pub enum UserPreferenceUpdate {
     #[serde(with = "serde_bytes")]
     key: Vec<u8>,
   },
+  #[serde(rename_all = "camelCase")]
+  ReadCursorUpdate {
+    conversation_id: String,
+    message_id: String,
+  },
+  ConversationSettingsUpdate {
+    settings: ConversationSettings,
+  },
 }
 
 impl From<PreferenceUpdate> for UserPreferenceUpdate {
@@ -24,6 +33,15 @@ impl From<PreferenceUpdate> for UserPreferenceUpdate {
         consent: Consent::from(c),
       },
       PreferenceUpdate::Hmac { key, .. } => UserPreferenceUpdate::HmacKeyUpdate { key },
+      PreferenceUpdate::ReadCursor(cursor) => UserPreferenceUpdate::ReadCursorUpdate {
+        conversation_id: hex::encode(cursor.group_id),
+        message_id: hex::encode(cursor.message_id),
+      },
+      PreferenceUpdate::ConversationSettings(settings) => {
+        UserPreferenceUpdate::ConversationSettingsUpdate {
+          settings: settings.into(),
+        }
+      }
     }
   }
 }
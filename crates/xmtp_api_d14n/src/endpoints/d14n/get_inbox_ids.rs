@@ -15,6 +15,8 @@ pub struct GetInboxIds {
     addresses: Vec<String>,
     #[builder(setter(into), default)]
     passkeys: Vec<String>,
+    #[builder(setter(into), default)]
+    solana_addresses: Vec<String>,
 }
 
 impl GetInboxIds {
@@ -40,10 +42,16 @@ impl Endpoint for GetInboxIds {
             .iter()
             .cloned()
             .map(|p| (p, IdentifierKind::Passkey));
+        let solana_addresses = self
+            .solana_addresses
+            .iter()
+            .cloned()
+            .map(|s| (s, IdentifierKind::Solana));
 
         Ok(GetInboxIdsRequest {
             requests: addresses
                 .chain(passkeys)
+                .chain(solana_addresses)
                 .map(|(i, kind)| get_inbox_ids_request::Request {
                     identifier: i,
                     identifier_kind: kind as i32,
@@ -2,10 +2,16 @@ mod auth;
 pub use auth::{AuthCallback, AuthHandle, AuthMiddleware, Credential};
 
 mod multi_node_client;
-pub use multi_node_client::{MultiNodeClient, MultiNodeClientBuilderError, MultiNodeClientError};
+pub use multi_node_client::{
+    ActiveNodeInfo, MultiNodeClient, MultiNodeClientBuilderError, MultiNodeClientError, NodeSwitch,
+    NodeSwitchReason,
+};
 
 mod readonly_client;
 pub use readonly_client::*;
 
 mod read_write_client;
 pub use read_write_client::*;
+
+mod record_replay;
+pub use record_replay::*;
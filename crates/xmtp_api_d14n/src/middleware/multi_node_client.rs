@@ -6,3 +6,6 @@ pub use errors::MultiNodeClientBuilderError;
 pub use errors::MultiNodeClientError;
 
 mod gateway_api;
+
+mod node_health;
+pub use node_health::{ActiveNodeInfo, NodeSwitch, NodeSwitchReason};
@@ -1,24 +1,60 @@
 use crate::MultiNodeClientBuilderError;
 use crate::middleware::multi_node_client::gateway_api::*;
+use crate::middleware::multi_node_client::node_health::{
+    ActiveNodeInfo, NodeHealth, NodeSwitch, NodeSwitchReason, is_node_failure,
+};
+use arc_swap::ArcSwap;
 use derive_builder::Builder;
 use prost::bytes::Bytes;
+use std::sync::Arc;
 use tokio::sync::OnceCell;
 use xmtp_api_grpc::{ClientBuilder, GrpcClient};
-use xmtp_common::time::Duration;
-use xmtp_configuration::MULTI_NODE_TIMEOUT_MS;
+use xmtp_common::time::{Duration, Instant};
+use xmtp_configuration::{
+    MULTI_NODE_LATENCY_REGRESSION_FACTOR, MULTI_NODE_MAX_CONSECUTIVE_ERRORS,
+    MULTI_NODE_REPROBE_INTERVAL_MS, MULTI_NODE_TIMEOUT_MS,
+};
 use xmtp_proto::api::{ApiClientError, BytesStream, Client, IsConnectedCheck};
 
 /* MultiNodeClient struct and its implementations */
 
+/// A node chosen by health check, and the client used to reach it.
+struct ActiveNode {
+    info: ActiveNodeInfo,
+    client: GrpcClient,
+}
+
+/// Node selection state, shared between clones of a [`MultiNodeClient`].
+#[derive(Default)]
+struct NodeSelection {
+    active: OnceCell<ArcSwap<ActiveNode>>,
+    health: parking_lot::Mutex<NodeHealth>,
+    // serializes re-selection so concurrent requests probe the nodes once
+    mutex: tokio::sync::Mutex<()>,
+}
+
+/// A read client that sends each request to the fastest healthy xmtpd node.
+///
+/// The node is picked by health check on first use. The client moves to
+/// another node after `max_consecutive_errors` failed requests in a row, and
+/// re-queries the gateway and re-probes all nodes every `reprobe_interval`, or
+/// sooner if average request latency grows to `latency_regression_factor`
+/// times what it was after the node was selected.
 #[derive(Clone, Default, Builder)]
 #[builder(build_fn(validate = "Self::validate", error = "MultiNodeClientBuilderError"))]
 pub struct MultiNodeClient<T> {
     pub gateway_client: T,
-    #[builder(default)]
-    pub inner: OnceCell<GrpcClient>,
+    #[builder(setter(skip))]
+    selection: Arc<NodeSelection>,
     #[builder(default = Duration::from_millis(MULTI_NODE_TIMEOUT_MS))]
     pub timeout: Duration,
     pub node_client_template: ClientBuilder,
+    #[builder(default = MULTI_NODE_MAX_CONSECUTIVE_ERRORS)]
+    pub max_consecutive_errors: u32,
+    #[builder(default = Duration::from_millis(MULTI_NODE_REPROBE_INTERVAL_MS))]
+    pub reprobe_interval: Duration,
+    #[builder(default = MULTI_NODE_LATENCY_REGRESSION_FACTOR)]
+    pub latency_regression_factor: u32,
 }
 
 impl<T> MultiNodeClientBuilder<T> {
@@ -28,6 +64,19 @@ impl<T> MultiNodeClientBuilder<T> {
         {
             return Err(MultiNodeClientBuilderError::InvalidTimeout);
         }
+        if self.max_consecutive_errors == Some(0) {
+            return Err(MultiNodeClientBuilderError::InvalidMaxConsecutiveErrors);
+        }
+        if let Some(interval) = self.reprobe_interval
+            && interval.is_zero()
+        {
+            return Err(MultiNodeClientBuilderError::InvalidReprobeInterval);
+        }
+        if let Some(factor) = self.latency_regression_factor
+            && factor < 2
+        {
+            return Err(MultiNodeClientBuilderError::InvalidLatencyRegressionFactor);
+        }
         Ok(())
     }
 }
@@ -38,19 +87,121 @@ impl<T: Clone> MultiNodeClient<T> {
     }
 }
 
-// TODO: Future PR implements a refresh() method that updates the inner client.
-// In order to do so we need to use an OnceCell<ArcSwap<GrpcClient>>, so that
-// we can update swap the inner client inside an OnceCell.
+impl<T> MultiNodeClient<T> {
+    /// The node requests are currently sent to, or `None` before the first request.
+    pub fn active_node(&self) -> Option<ActiveNodeInfo> {
+        self.selection
+            .active
+            .get()
+            .map(|active| active.load().info.clone())
+    }
+
+    /// The most recent node switches, oldest first.
+    pub fn switch_history(&self) -> Vec<NodeSwitch> {
+        self.selection.health.lock().history()
+    }
+}
+
 impl<T: Client> MultiNodeClient<T> {
-    async fn init_inner(&self) -> Result<&GrpcClient, ApiClientError> {
-        self.inner
+    /// Re-query the gateway for nodes and move to the fastest one.
+    pub async fn refresh(&self) -> Result<ActiveNodeInfo, ApiClientError> {
+        self.selection.health.lock().request_reprobe();
+        Ok(self.node().await?.info.clone())
+    }
+
+    async fn init_inner(&self) -> Result<&ArcSwap<ActiveNode>, ApiClientError> {
+        self.selection
+            .active
             .get_or_try_init(|| async {
-                let nodes = get_nodes(&self.gateway_client, &self.node_client_template).await?;
-                let fastest_node = get_fastest_node(nodes, self.timeout).await?;
-                Ok(fastest_node)
+                let node = self.select_node(None).await?;
+                self.selection
+                    .health
+                    .lock()
+                    .selected(node.info.node_id, NodeSwitchReason::Initial);
+                Ok(ArcSwap::from_pointee(node))
             })
             .await
     }
+
+    /// The node to send the next request to, re-selecting it first if the
+    /// active node is failing, has slowed down or is due for a re-probe.
+    async fn node(&self) -> Result<Arc<ActiveNode>, ApiClientError> {
+        let active = self.init_inner().await?;
+        let reselect_reason = || {
+            self.selection
+                .health
+                .lock()
+                .reselect_reason(self.reprobe_interval)
+        };
+
+        if reselect_reason().is_some() {
+            let _guard = self.selection.mutex.lock().await;
+            // check again after acquiring the lock, another request may have
+            // already re-selected the node.
+            if let Some(reason) = reselect_reason() {
+                let exclude = self.selection.health.lock().failing_node();
+                match self.select_node(exclude).await {
+                    Ok(node) => {
+                        tracing::info!(
+                            "multi-node client selected node {} ({:?})",
+                            node.info.node_id,
+                            reason
+                        );
+                        self.selection
+                            .health
+                            .lock()
+                            .selected(node.info.node_id, reason);
+                        active.store(Arc::new(node));
+                    }
+                    Err(e) => {
+                        // keep the current node rather than failing the request
+                        tracing::warn!("failed to re-select node, keeping current node: {}", e);
+                        self.selection.health.lock().reprobe_failed();
+                    }
+                }
+            }
+        }
+
+        Ok(active.load_full())
+    }
+
+    /// Query the gateway for nodes and health check them, optionally skipping
+    /// a failing node when others are available.
+    async fn select_node(&self, exclude: Option<u32>) -> Result<ActiveNode, ApiClientError> {
+        let mut nodes = get_nodes(&self.gateway_client, &self.node_client_template).await?;
+        if let Some(node_id) = exclude
+            && nodes.len() > 1
+        {
+            nodes.remove(&node_id);
+        }
+        let (node_id, client, latency) = get_fastest_node(nodes, self.timeout).await?;
+        Ok(ActiveNode {
+            info: ActiveNodeInfo {
+                node_id,
+                url: client.host().to_string(),
+                probe_latency_ms: latency,
+            },
+            client,
+        })
+    }
+
+    /// Track the outcome of a request sent to `node`. Only unary request
+    /// latency is tracked, streams stay open for as long as the caller needs.
+    fn observe<R>(
+        &self,
+        node: &ActiveNode,
+        result: &Result<R, ApiClientError>,
+        latency: Option<Duration>,
+    ) {
+        let mut health = self.selection.health.lock();
+        match result {
+            Err(e) if is_node_failure(e) => {
+                tracing::warn!("request to node {} failed: {}", node.info.node_id, e);
+                health.record_error(node.info.node_id, self.max_consecutive_errors)
+            }
+            _ => health.record_success(node.info.node_id, latency, self.latency_regression_factor),
+        }
+    }
 }
 
 /// Implement the Client trait for the MultiNodeClient.
@@ -71,9 +222,12 @@ impl<T: Client> Client for MultiNodeClient<T> {
         path: http::uri::PathAndQuery,
         body: Bytes,
     ) -> Result<http::Response<Bytes>, ApiClientError> {
-        let inner = self.init_inner().await?;
+        let node = self.node().await?;
 
-        inner.request(request, path, body).await
+        let start = Instant::now();
+        let result = node.client.request(request, path, body).await;
+        self.observe(&node, &result, Some(start.elapsed()));
+        result
     }
 
     async fn stream(
@@ -82,9 +236,11 @@ impl<T: Client> Client for MultiNodeClient<T> {
         path: http::uri::PathAndQuery,
         body: Bytes,
     ) -> Result<http::Response<BytesStream>, ApiClientError> {
-        let inner = self.init_inner().await?;
+        let node = self.node().await?;
 
-        inner.stream(request, path, body).await
+        let result = node.client.stream(request, path, body).await;
+        self.observe(&node, &result, None);
+        result
     }
 
     async fn bidi_stream(
@@ -93,9 +249,11 @@ impl<T: Client> Client for MultiNodeClient<T> {
         path: http::uri::PathAndQuery,
         body: xmtp_common::BoxDynStream<'static, Bytes>,
     ) -> Result<http::Response<BytesStream>, ApiClientError> {
-        let inner = self.init_inner().await?;
+        let node = self.node().await?;
 
-        inner.bidi_stream(request, path, body).await
+        let result = node.client.bidi_stream(request, path, body).await;
+        self.observe(&node, &result, None);
+        result
     }
 }
 
@@ -221,6 +379,53 @@ mod tests {
             .expect("failed to build multi-node client");
     }
 
+    #[xmtp_common::test]
+    fn builder_rejects_invalid_health_settings() {
+        let err = create_multinode_client_builder()
+            .max_consecutive_errors(0)
+            .build();
+        assert!(matches!(
+            err,
+            Err(MultiNodeClientBuilderError::InvalidMaxConsecutiveErrors)
+        ));
+        let err = create_multinode_client_builder()
+            .reprobe_interval(Duration::ZERO)
+            .build();
+        assert!(matches!(
+            err,
+            Err(MultiNodeClientBuilderError::InvalidReprobeInterval)
+        ));
+        let err = create_multinode_client_builder()
+            .latency_regression_factor(1)
+            .build();
+        assert!(matches!(
+            err,
+            Err(MultiNodeClientBuilderError::InvalidLatencyRegressionFactor)
+        ));
+    }
+
+    #[xmtp_common::test]
+    async fn multinode_refresh_reselects_node() {
+        use crate::d14n::GetNewestEnvelopes;
+        let client = create_multinode_client();
+        assert!(client.active_node().is_none());
+
+        let mut endpoint = GetNewestEnvelopes::builder().topic(vec![]).build().unwrap();
+        endpoint.query(&client).await.unwrap();
+        let active = client
+            .active_node()
+            .expect("node selected on first request");
+        let history = client.switch_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].reason, NodeSwitchReason::Initial);
+        assert_eq!(history[0].to_node_id, active.node_id);
+
+        // Clones share the selected node
+        let refreshed = client.clone().refresh().await.unwrap();
+        assert_eq!(client.active_node(), Some(refreshed));
+        endpoint.query(&client).await.unwrap();
+    }
+
     #[xmtp_common::test]
     async fn d14n_request_latest_group_message() {
         let client = create_d14n_client();
@@ -54,6 +54,12 @@ pub enum MultiNodeClientBuilderError {
     GrpcBuilderError(#[from] GrpcBuilderError),
     #[error("timeout must be greater than 0")]
     InvalidTimeout,
+    #[error("max consecutive errors must be greater than 0")]
+    InvalidMaxConsecutiveErrors,
+    #[error("reprobe interval must be greater than 0")]
+    InvalidReprobeInterval,
+    #[error("latency regression factor must be at least 2")]
+    InvalidLatencyRegressionFactor,
     #[error("gateway builder is required")]
     MissingGatewayBuilder,
     #[error("required fields missing from MultiNodeClientBuilder {0}")]
@@ -81,11 +81,12 @@ pub(super) async fn get_nodes<C: Client>(
 }
 
 /// Get the fastest node from the list of endpoints.
+/// Returns the node id, its client and its health check latency in milliseconds.
 #[xmtp_common::rpc_span]
 pub async fn get_fastest_node(
     clients: HashMap<u32, GrpcClient>,
     timeout: Duration,
-) -> Result<GrpcClient, ApiClientError> {
+) -> Result<(u32, GrpcClient, u64), ApiClientError> {
     let endpoint = HealthCheck::builder().build().map_err(|e| {
         tracing::error!("failed to build healthcheck endpoint: {}", e);
         MultiNodeClientError::BodyError(e)
@@ -167,7 +168,7 @@ pub async fn get_fastest_node(
 
     tracing::info!("chosen node is {} with latency {}ms", node_id, latency);
 
-    Ok(client)
+    Ok((node_id, client, latency))
 }
 
 #[cfg(test)]
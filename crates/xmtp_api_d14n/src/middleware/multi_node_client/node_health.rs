@@ -0,0 +1,261 @@
+use std::collections::VecDeque;
+use xmtp_common::RetryableError;
+use xmtp_common::time::{Duration, Instant, now_ns};
+use xmtp_proto::api::ApiClientError;
+
+/// The number of switches kept for diagnostics.
+const MAX_SWITCH_HISTORY: usize = 32;
+/// The number of successful requests used to establish a node's latency baseline.
+const LATENCY_BASELINE_SAMPLES: u32 = 8;
+
+/// Why the multi-node client moved to a different node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeSwitchReason {
+    /// The first node selected by the client.
+    Initial,
+    /// The active node failed this many requests in a row.
+    ConsecutiveErrors(u32),
+    /// Average request latency on the active node grew well past its baseline.
+    LatencyRegression { baseline_ms: u64, observed_ms: u64 },
+    /// A periodic or manual re-probe found a faster node.
+    Reprobe,
+}
+
+/// A change of the active node, kept for diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeSwitch {
+    /// `None` for the initial selection.
+    pub from_node_id: Option<u32>,
+    pub to_node_id: u32,
+    pub reason: NodeSwitchReason,
+    pub at_ns: i64,
+}
+
+/// The node the multi-node client currently sends requests to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveNodeInfo {
+    pub node_id: u32,
+    pub url: String,
+    /// Health check latency measured when the node was last probed.
+    pub probe_latency_ms: u64,
+}
+
+/// Request outcomes observed on the active node since it was last probed.
+#[derive(Debug, Default)]
+pub(super) struct NodeHealth {
+    node_id: Option<u32>,
+    probed_at: Option<Instant>,
+    consecutive_errors: u32,
+    samples: u32,
+    average_latency_ms: u64,
+    baseline_ms: Option<u64>,
+    reselect: Option<NodeSwitchReason>,
+    history: VecDeque<NodeSwitch>,
+}
+
+impl NodeHealth {
+    /// Start tracking `node_id` after it was probed. Records a switch if the
+    /// node changed.
+    pub(super) fn selected(&mut self, node_id: u32, reason: NodeSwitchReason) {
+        if self.node_id != Some(node_id) {
+            if self.history.len() == MAX_SWITCH_HISTORY {
+                self.history.pop_front();
+            }
+            self.history.push_back(NodeSwitch {
+                from_node_id: self.node_id,
+                to_node_id: node_id,
+                reason,
+                at_ns: now_ns(),
+            });
+        }
+        *self = Self {
+            node_id: Some(node_id),
+            probed_at: Some(Instant::now()),
+            history: std::mem::take(&mut self.history),
+            ..Default::default()
+        };
+    }
+
+    /// Keep the current node but wait a full interval before probing again.
+    pub(super) fn reprobe_failed(&mut self) {
+        self.probed_at = Some(Instant::now());
+        self.reselect = None;
+        self.consecutive_errors = 0;
+    }
+
+    /// Returns why the client should select a node again, if it should.
+    pub(super) fn reselect_reason(&self, reprobe_interval: Duration) -> Option<NodeSwitchReason> {
+        if let Some(reason) = &self.reselect {
+            return Some(reason.clone());
+        }
+        self.probed_at
+            .is_some_and(|probed_at| probed_at.elapsed() >= reprobe_interval)
+            .then_some(NodeSwitchReason::Reprobe)
+    }
+
+    /// Force a re-probe on the next request.
+    pub(super) fn request_reprobe(&mut self) {
+        self.reselect.get_or_insert(NodeSwitchReason::Reprobe);
+    }
+
+    pub(super) fn record_error(&mut self, node_id: u32, max_consecutive_errors: u32) {
+        if self.node_id != Some(node_id) {
+            return;
+        }
+        self.consecutive_errors += 1;
+        if self.consecutive_errors >= max_consecutive_errors {
+            self.reselect = Some(NodeSwitchReason::ConsecutiveErrors(self.consecutive_errors));
+        }
+    }
+
+    pub(super) fn record_success(
+        &mut self,
+        node_id: u32,
+        latency: Option<Duration>,
+        regression_factor: u32,
+    ) {
+        if self.node_id != Some(node_id) {
+            return;
+        }
+        self.consecutive_errors = 0;
+        let Some(latency) = latency else {
+            return;
+        };
+        let latency_ms = latency.as_millis() as u64;
+        self.average_latency_ms = if self.samples == 0 {
+            latency_ms
+        } else {
+            // exponential moving average, weighting the newest sample by 1/8
+            (self.average_latency_ms * 7 + latency_ms) / 8
+        };
+        self.samples = self.samples.saturating_add(1);
+
+        match self.baseline_ms {
+            None if self.samples >= LATENCY_BASELINE_SAMPLES => {
+                self.baseline_ms = Some(self.average_latency_ms.max(1));
+            }
+            Some(baseline_ms)
+                if self.reselect.is_none()
+                    && self.average_latency_ms >= baseline_ms * regression_factor as u64 =>
+            {
+                self.reselect = Some(NodeSwitchReason::LatencyRegression {
+                    baseline_ms,
+                    observed_ms: self.average_latency_ms,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// The node that failed its requests, if the client is moving away because of errors.
+    pub(super) fn failing_node(&self) -> Option<u32> {
+        matches!(self.reselect, Some(NodeSwitchReason::ConsecutiveErrors(_)))
+            .then_some(self.node_id)
+            .flatten()
+    }
+
+    pub(super) fn history(&self) -> Vec<NodeSwitch> {
+        self.history.iter().cloned().collect()
+    }
+}
+
+/// Whether an error points at the node itself (unreachable, timed out,
+/// unavailable) rather than at the request.
+pub(super) fn is_node_failure(err: &ApiClientError) -> bool {
+    match err {
+        ApiClientError::Expired(_) | ApiClientError::Http(_) => true,
+        e => e.network_error().is_some_and(|e| e.is_retryable()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn health(node_id: u32) -> NodeHealth {
+        let mut health = NodeHealth::default();
+        health.selected(node_id, NodeSwitchReason::Initial);
+        health
+    }
+
+    #[xmtp_common::test]
+    fn consecutive_errors_trigger_reselection() {
+        let mut health = health(1);
+        let interval = Duration::from_secs(60);
+
+        health.record_error(1, 3);
+        health.record_error(1, 3);
+        health.record_success(1, None, 3);
+        health.record_error(1, 3);
+        health.record_error(1, 3);
+        assert_eq!(health.reselect_reason(interval), None);
+
+        health.record_error(1, 3);
+        assert_eq!(
+            health.reselect_reason(interval),
+            Some(NodeSwitchReason::ConsecutiveErrors(3))
+        );
+        assert_eq!(health.failing_node(), Some(1));
+
+        // Errors from a node that is no longer active are ignored
+        health.selected(2, NodeSwitchReason::ConsecutiveErrors(3));
+        for _ in 0..3 {
+            health.record_error(1, 3);
+        }
+        assert_eq!(health.reselect_reason(interval), None);
+    }
+
+    #[xmtp_common::test]
+    fn latency_regression_triggers_reselection() {
+        let mut health = health(1);
+        let interval = Duration::from_secs(60);
+
+        for _ in 0..LATENCY_BASELINE_SAMPLES {
+            health.record_success(1, Some(Duration::from_millis(10)), 3);
+        }
+        assert_eq!(health.reselect_reason(interval), None);
+
+        for _ in 0..32 {
+            health.record_success(1, Some(Duration::from_millis(100)), 3);
+        }
+        assert!(matches!(
+            health.reselect_reason(interval),
+            Some(NodeSwitchReason::LatencyRegression {
+                baseline_ms: 10,
+                ..
+            })
+        ));
+        assert_eq!(health.failing_node(), None);
+    }
+
+    #[xmtp_common::test]
+    fn reprobe_after_interval() {
+        let mut health = health(1);
+        assert_eq!(health.reselect_reason(Duration::from_secs(60)), None);
+        assert_eq!(
+            health.reselect_reason(Duration::ZERO),
+            Some(NodeSwitchReason::Reprobe)
+        );
+
+        health.request_reprobe();
+        health.reprobe_failed();
+        assert_eq!(health.reselect_reason(Duration::from_secs(60)), None);
+    }
+
+    #[xmtp_common::test]
+    fn switch_history_is_bounded() {
+        let mut health = health(0);
+        // Selecting the same node again is not a switch
+        health.selected(0, NodeSwitchReason::Reprobe);
+        assert_eq!(health.history().len(), 1);
+
+        for node_id in 1..=(MAX_SWITCH_HISTORY as u32 + 5) {
+            health.selected(node_id, NodeSwitchReason::Reprobe);
+        }
+        let history = health.history();
+        assert_eq!(history.len(), MAX_SWITCH_HISTORY);
+        let last = history.last().unwrap();
+        assert_eq!(last.to_node_id, MAX_SWITCH_HISTORY as u32 + 5);
+        assert_eq!(last.from_node_id, Some(MAX_SWITCH_HISTORY as u32 + 4));
+    }
+}
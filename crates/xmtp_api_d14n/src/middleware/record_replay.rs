@@ -0,0 +1,729 @@
+//! Record the traffic of a [`Client`] to a file and replay it without a network.
+//!
+//! A recording holds one JSON [`Interaction`] per line. In
+//! [`RecordReplayMode::Record`] every request, stream and bidi stream is forwarded to
+//! the inner client and appended to the recording once it completes. Streams are
+//! written when they end or are dropped. In [`RecordReplayMode::Replay`] and
+//! [`RecordReplayMode::Strict`] the inner client is never called: each call consumes
+//! the first unused interaction of the same kind and path, preferring one with the
+//! same request body. MLS messages carry fresh randomness and timestamps, so a body
+//! that matches no interaction falls back to the next one for the path. A call the
+//! recording holds nothing for fails; strict mode panics on it, so an unexpected
+//! request fails the test even when the caller, like a background worker, swallows
+//! the error.
+//!
+//! Clients opened on the same path share one recording, so every client of a test
+//! records to and replays from the same file.
+//!
+//! The v3 and d14n test clients are wrapped in this client. Setting
+//! `XMTP_API_RECORD_MODE` to `record`, `replay` or `strict` and `XMTP_API_RECORDINGS`
+//! to a directory records each test to, or replays it from, a file named after the
+//! test, so group and stream tests can run without a node. Checked-in recordings of
+//! `xmtp_mls` tests live in `crates/xmtp_mls/recordings`, with `just record` and
+//! `just replay` to produce and run them.
+
+xmtp_common::if_test! {
+    mod test;
+    pub use test::{RECORD_MODE_ENV, RECORDINGS_DIR_ENV};
+}
+
+use futures::{Stream, StreamExt, stream};
+use parking_lot::Mutex;
+use prost::bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, LazyLock, Weak},
+    task::{Context, Poll},
+};
+use thiserror::Error;
+use xmtp_proto::api::{ApiClientError, BytesStream, Client, IsConnectedCheck};
+use xmtp_proto::prelude::ApiBuilder;
+
+/// Recordings opened by this process, so clients on the same path share one.
+static RECORDINGS: LazyLock<Mutex<HashMap<PathBuf, Weak<Recording>>>> =
+    LazyLock::new(Default::default);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordReplayMode {
+    /// Forward every call to the inner client without recording it.
+    #[default]
+    Passthrough,
+    /// Forward every call to the inner client and record it.
+    Record,
+    /// Serve calls from the recording, matching on kind and path.
+    Replay,
+    /// Serve calls from the recording like [`Self::Replay`], panicking on any call
+    /// the recording holds nothing for.
+    Strict,
+}
+
+#[derive(Debug, Error)]
+pub enum RecordReplayError {
+    #[error("recording {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("malformed interaction on line {line} of recording {path}: {source}")]
+    Malformed {
+        path: PathBuf,
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[error("{0:?} mode requires a recording path")]
+    MissingPath(RecordReplayMode),
+    #[error("recording {0} is already open in another mode")]
+    ModeMismatch(PathBuf),
+    #[error("failed to build inner client: {0}")]
+    Inner(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InteractionKind {
+    Request,
+    Stream,
+    BidiStream,
+}
+
+/// A single recorded call. Bodies and frames are hex encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub kind: InteractionKind,
+    pub path: String,
+    /// The request body, or the outbound frames of a bidi stream
+    pub sent: Vec<String>,
+    /// The response body, or the inbound frames of a stream
+    pub received: Vec<String>,
+    /// The error the call or stream failed with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Whether the server closed the stream, rather than the client dropping it
+    #[serde(default)]
+    pub closed: bool,
+}
+
+impl Interaction {
+    fn new(kind: InteractionKind, path: &http::uri::PathAndQuery, sent: Vec<String>) -> Self {
+        Self {
+            kind,
+            path: path.to_string(),
+            sent,
+            received: vec![],
+            error: None,
+            closed: false,
+        }
+    }
+
+    fn received_bytes(&self) -> Result<Vec<Bytes>, ApiClientError> {
+        self.received
+            .iter()
+            .map(|frame| {
+                hex::decode(frame).map(Bytes::from).map_err(|e| {
+                    ApiClientError::OtherUnretryable(
+                        format!("malformed frame recorded for {}: {e}", self.path).into(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn recorded_error(&self) -> Option<ApiClientError> {
+        self.error
+            .as_ref()
+            .map(|e| ApiClientError::OtherUnretryable(e.clone().into()))
+    }
+}
+
+/// A recording file shared by every client opened on its path.
+struct Recording {
+    path: PathBuf,
+    mode: RecordReplayMode,
+    /// Interactions left to replay, `None` once served
+    interactions: Mutex<Vec<Option<Interaction>>>,
+    file: Mutex<Option<File>>,
+}
+
+impl Recording {
+    fn open(path: &Path, mode: RecordReplayMode) -> Result<Arc<Self>, RecordReplayError> {
+        let mut recordings = RECORDINGS.lock();
+        if let Some(recording) = recordings.get(path).and_then(Weak::upgrade) {
+            if recording.mode != mode {
+                return Err(RecordReplayError::ModeMismatch(path.to_path_buf()));
+            }
+            return Ok(recording);
+        }
+
+        let io_error = |source| RecordReplayError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+        let mut interactions = vec![];
+        let mut file = None;
+        if mode == RecordReplayMode::Record {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(io_error)?;
+            }
+            file = Some(File::create(path).map_err(io_error)?);
+        } else {
+            let reader = BufReader::new(File::open(path).map_err(io_error)?);
+            for (index, line) in reader.lines().enumerate() {
+                let line = line.map_err(io_error)?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let interaction =
+                    serde_json::from_str(&line).map_err(|source| RecordReplayError::Malformed {
+                        path: path.to_path_buf(),
+                        line: index + 1,
+                        source,
+                    })?;
+                interactions.push(Some(interaction));
+            }
+        }
+
+        let recording = Arc::new(Self {
+            path: path.to_path_buf(),
+            mode,
+            interactions: Mutex::new(interactions),
+            file: Mutex::new(file),
+        });
+        recordings.insert(path.to_path_buf(), Arc::downgrade(&recording));
+        Ok(recording)
+    }
+
+    fn append(&self, interaction: &Interaction) {
+        let mut file = self.file.lock();
+        let Some(file) = file.as_mut() else {
+            return;
+        };
+        let line = match serde_json::to_string(interaction) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::error!(path = %self.path.display(), "failed to encode interaction: {e}");
+                return;
+            }
+        };
+        if let Err(e) = writeln!(file, "{line}").and_then(|_| file.flush()) {
+            tracing::error!(path = %self.path.display(), "failed to record interaction: {e}");
+        }
+    }
+
+    /// Take the first unused interaction matching the call.
+    fn take(
+        &self,
+        kind: InteractionKind,
+        path: &http::uri::PathAndQuery,
+        sent: &[String],
+    ) -> Result<Interaction, ApiClientError> {
+        let path = path.to_string();
+        let mut interactions = self.interactions.lock();
+        let same_call =
+            |interaction: &Interaction| interaction.kind == kind && interaction.path == path;
+        // Bidi frames are only known once the stream is running, so only the path matches
+        let exact = interactions.iter().position(|i| {
+            i.as_ref().is_some_and(|i| {
+                same_call(i) && (kind == InteractionKind::BidiStream || i.sent == sent)
+            })
+        });
+        let position = exact.or_else(|| {
+            interactions
+                .iter()
+                .position(|i| i.as_ref().is_some_and(same_call))
+        });
+        if let Some(interaction) = position.and_then(|position| interactions[position].take()) {
+            return Ok(interaction);
+        }
+        let message = format!("no recorded {kind:?} for {path} in {}", self.path.display());
+        if self.mode == RecordReplayMode::Strict {
+            panic!("unexpected call in strict replay: {message}");
+        }
+        Err(ApiClientError::OtherUnretryable(message.into()))
+    }
+}
+
+/// A client that records the traffic of its inner client, or replays a recording
+/// in its place.
+#[derive(Clone)]
+pub struct RecordReplayClient<C> {
+    inner: C,
+    mode: RecordReplayMode,
+    recording: Option<Arc<Recording>>,
+}
+
+impl<C> RecordReplayClient<C> {
+    /// Wrap `inner`, recording to or replaying from `path` according to `mode`.
+    pub fn new(
+        inner: C,
+        mode: RecordReplayMode,
+        path: Option<&Path>,
+    ) -> Result<Self, RecordReplayError> {
+        let recording = match (mode, path) {
+            (RecordReplayMode::Passthrough, _) => None,
+            (_, Some(path)) => Some(Recording::open(path, mode)?),
+            (_, None) => return Err(RecordReplayError::MissingPath(mode)),
+        };
+        Ok(Self {
+            inner,
+            mode,
+            recording,
+        })
+    }
+
+    pub fn builder<B>(inner: B) -> RecordReplayBuilder<B> {
+        RecordReplayBuilder::new(inner)
+    }
+
+    pub fn mode(&self) -> RecordReplayMode {
+        self.mode
+    }
+
+    /// The recording to serve calls from, if replaying.
+    fn replaying(&self) -> Option<&Recording> {
+        match self.mode {
+            RecordReplayMode::Replay | RecordReplayMode::Strict => self.recording.as_deref(),
+            RecordReplayMode::Passthrough | RecordReplayMode::Record => None,
+        }
+    }
+
+    /// The recording to record calls to, if recording.
+    fn recording(&self) -> Option<&Arc<Recording>> {
+        match self.mode {
+            RecordReplayMode::Record => self.recording.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl<C> std::fmt::Debug for RecordReplayClient<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordReplayClient")
+            .field("mode", &self.mode)
+            .field("recording", &self.recording.as_ref().map(|r| &r.path))
+            .finish()
+    }
+}
+
+#[xmtp_common::async_trait]
+impl<C> Client for RecordReplayClient<C>
+where
+    C: Client,
+{
+    fn host(&self) -> &str {
+        self.inner.host()
+    }
+
+    async fn request(
+        &self,
+        request: http::request::Builder,
+        path: http::uri::PathAndQuery,
+        body: Bytes,
+    ) -> Result<http::Response<Bytes>, ApiClientError> {
+        let sent = vec![hex::encode(&body)];
+        if let Some(recording) = self.replaying() {
+            let interaction = recording.take(InteractionKind::Request, &path, &sent)?;
+            if let Some(e) = interaction.recorded_error() {
+                return Err(e);
+            }
+            let body = interaction.received_bytes()?.into_iter().next();
+            return Ok(http::Response::new(body.unwrap_or_default()));
+        }
+
+        let Some(recording) = self.recording() else {
+            return self.inner.request(request, path, body).await;
+        };
+        let mut interaction = Interaction::new(InteractionKind::Request, &path, sent);
+        let result = self.inner.request(request, path, body).await;
+        match &result {
+            Ok(response) => interaction.received = vec![hex::encode(response.body())],
+            Err(e) => interaction.error = Some(e.to_string()),
+        }
+        recording.append(&interaction);
+        result
+    }
+
+    async fn stream(
+        &self,
+        request: http::request::Builder,
+        path: http::uri::PathAndQuery,
+        body: Bytes,
+    ) -> Result<http::Response<BytesStream>, ApiClientError> {
+        let sent = vec![hex::encode(&body)];
+        if let Some(recording) = self.replaying() {
+            let interaction = recording.take(InteractionKind::Stream, &path, &sent)?;
+            return Ok(http::Response::new(replayed_stream(interaction)?));
+        }
+
+        let Some(recording) = self.recording() else {
+            return self.inner.stream(request, path, body).await;
+        };
+        let mut interaction = Interaction::new(InteractionKind::Stream, &path, sent);
+        match self.inner.stream(request, path, body).await {
+            Ok(response) => Ok(response.map(|inner| {
+                BytesStream::new(RecordingStream::new(inner, recording.clone(), interaction))
+            })),
+            Err(e) => {
+                interaction.error = Some(e.to_string());
+                recording.append(&interaction);
+                Err(e)
+            }
+        }
+    }
+
+    async fn bidi_stream(
+        &self,
+        request: http::request::Builder,
+        path: http::uri::PathAndQuery,
+        body: xmtp_common::BoxDynStream<'static, Bytes>,
+    ) -> Result<http::Response<BytesStream>, ApiClientError> {
+        if let Some(recording) = self.replaying() {
+            let interaction = recording.take(InteractionKind::BidiStream, &path, &[])?;
+            // Keep consuming outbound frames, so the sender sees an open stream
+            let outbound = body.filter_map(|_| async { None::<Result<Bytes, ApiClientError>> });
+            let inbound = replayed_stream(interaction)?;
+            return Ok(http::Response::new(BytesStream::new(stream::select(
+                inbound, outbound,
+            ))));
+        }
+
+        let Some(recording) = self.recording() else {
+            return self.inner.bidi_stream(request, path, body).await;
+        };
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let body = {
+            let sent = sent.clone();
+            Box::pin(body.inspect(move |frame| sent.lock().push(hex::encode(frame))))
+        };
+        let mut interaction = Interaction::new(InteractionKind::BidiStream, &path, vec![]);
+        match self.inner.bidi_stream(request, path, body).await {
+            Ok(response) => Ok(response.map(|inner| {
+                BytesStream::new(
+                    RecordingStream::new(inner, recording.clone(), interaction).with_sent(sent),
+                )
+            })),
+            Err(e) => {
+                interaction.sent = std::mem::take(&mut *sent.lock());
+                interaction.error = Some(e.to_string());
+                recording.append(&interaction);
+                Err(e)
+            }
+        }
+    }
+}
+
+#[xmtp_common::async_trait]
+impl<C> IsConnectedCheck for RecordReplayClient<C>
+where
+    C: IsConnectedCheck,
+{
+    async fn is_connected(&self) -> bool {
+        if self.replaying().is_some() {
+            return true;
+        }
+        self.inner.is_connected().await
+    }
+}
+
+/// Serve the frames of a recorded stream. Streams the client dropped while
+/// recording stay open once their frames are served, like an idle live stream.
+fn replayed_stream(interaction: Interaction) -> Result<BytesStream, ApiClientError> {
+    let mut items: Vec<_> = interaction.received_bytes()?.into_iter().map(Ok).collect();
+    let error = interaction.recorded_error();
+    let ended = error.is_some() || interaction.closed;
+    items.extend(error.map(Err));
+    let frames = stream::iter(items);
+    if ended {
+        Ok(BytesStream::new(frames))
+    } else {
+        Ok(BytesStream::new(frames.chain(stream::pending())))
+    }
+}
+
+/// Records the frames of a stream, writing the interaction once the stream ends
+/// or is dropped.
+struct RecordingStream {
+    inner: BytesStream,
+    recording: Arc<Recording>,
+    interaction: Option<Interaction>,
+    /// Outbound frames of a bidi stream
+    sent: Option<Arc<Mutex<Vec<String>>>>,
+}
+
+impl RecordingStream {
+    fn new(inner: BytesStream, recording: Arc<Recording>, interaction: Interaction) -> Self {
+        Self {
+            inner,
+            recording,
+            interaction: Some(interaction),
+            sent: None,
+        }
+    }
+
+    fn with_sent(mut self, sent: Arc<Mutex<Vec<String>>>) -> Self {
+        self.sent = Some(sent);
+        self
+    }
+
+    fn finish(&mut self) {
+        let Some(mut interaction) = self.interaction.take() else {
+            return;
+        };
+        if let Some(sent) = &self.sent {
+            interaction.sent = sent.lock().clone();
+        }
+        self.recording.append(&interaction);
+    }
+}
+
+impl Stream for RecordingStream {
+    type Item = Result<Bytes, ApiClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let item = std::task::ready!(self.inner.poll_next_unpin(cx));
+        if let Some(interaction) = self.interaction.as_mut() {
+            match &item {
+                Some(Ok(frame)) => interaction.received.push(hex::encode(frame)),
+                Some(Err(e)) => interaction.error = Some(e.to_string()),
+                None => interaction.closed = true,
+            }
+        }
+        if !matches!(item, Some(Ok(_))) {
+            self.finish();
+        }
+        Poll::Ready(item)
+    }
+}
+
+impl Drop for RecordingStream {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// Builds a [`RecordReplayClient`] around the output of another [`ApiBuilder`].
+#[derive(Clone)]
+pub struct RecordReplayBuilder<Builder> {
+    inner: Builder,
+    mode: RecordReplayMode,
+    path: Option<PathBuf>,
+}
+
+impl<Builder> RecordReplayBuilder<Builder> {
+    pub fn new(inner: Builder) -> Self {
+        Self {
+            inner,
+            mode: RecordReplayMode::Passthrough,
+            path: None,
+        }
+    }
+
+    pub fn mode(&mut self, mode: RecordReplayMode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+impl<Builder> ApiBuilder for RecordReplayBuilder<Builder>
+where
+    Builder: ApiBuilder,
+{
+    type Output = RecordReplayClient<Builder::Output>;
+    type Error = RecordReplayError;
+
+    fn build(self) -> Result<Self::Output, Self::Error> {
+        let inner = self
+            .inner
+            .build()
+            .map_err(|e| RecordReplayError::Inner(format!("{e:?}")))?;
+        RecordReplayClient::new(inner, self.mode, self.path.as_deref())
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::d14n::GetInboxIds;
+    use futures::FutureExt;
+    use std::panic::AssertUnwindSafe;
+    use xmtp_proto::api::{Query, mock::MockNetworkClient};
+
+    fn recording_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!(
+                "xmtp-record-replay-{}",
+                xmtp_common::rand_hexstring()
+            ))
+            .join(format!("{name}.jsonl"))
+    }
+
+    fn get_inbox_ids(address: &str) -> GetInboxIds {
+        GetInboxIds::builder()
+            .addresses(vec![address.to_string()])
+            .build()
+            .unwrap()
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_replays_recorded_requests() {
+        let path = recording_path("requests");
+        let mut inner = MockNetworkClient::default();
+        inner
+            .expect_request()
+            .times(1)
+            .returning(|_, _, _| Ok(http::Response::new(vec![].into())));
+        {
+            let recorder = RecordReplayClient::new(inner, RecordReplayMode::Record, Some(&path))?;
+            get_inbox_ids("0x01").query(&recorder).await?;
+        }
+
+        // The inner client has no expectations left, so any call to it panics
+        let replayer = RecordReplayClient::new(
+            MockNetworkClient::default(),
+            RecordReplayMode::Replay,
+            Some(&path),
+        )?;
+        // Replay falls back to the next request on the same path
+        get_inbox_ids("0x02").query(&replayer).await?;
+        assert!(get_inbox_ids("0x02").query(&replayer).await.is_err());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_strict_mode_rejects_unexpected_requests() {
+        let path = recording_path("strict");
+        let mut inner = MockNetworkClient::default();
+        inner
+            .expect_request()
+            .times(1)
+            .returning(|_, _, _| Ok(http::Response::new(vec![].into())));
+        {
+            let recorder = RecordReplayClient::new(inner, RecordReplayMode::Record, Some(&path))?;
+            get_inbox_ids("0x01").query(&recorder).await?;
+        }
+
+        let replayer = RecordReplayClient::new(
+            MockNetworkClient::default(),
+            RecordReplayMode::Strict,
+            Some(&path),
+        )?;
+        get_inbox_ids("0x01").query(&replayer).await?;
+        let unexpected = AssertUnwindSafe(get_inbox_ids("0x01").query(&replayer))
+            .catch_unwind()
+            .await;
+        assert!(unexpected.is_err());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_replays_recorded_streams() {
+        let path = recording_path("streams");
+        let stream_path = http::uri::PathAndQuery::from_static("/xmtp.mls.api.v1.MlsApi/Subscribe");
+        let mut inner = MockNetworkClient::default();
+        inner.expect_stream().times(1).returning(|_, _, _| {
+            let frames = vec![
+                Ok(Bytes::from_static(b"one")),
+                Ok(Bytes::from_static(b"two")),
+            ];
+            Ok(http::Response::new(BytesStream::new(stream::iter(frames))))
+        });
+        {
+            let recorder = RecordReplayClient::new(inner, RecordReplayMode::Record, Some(&path))?;
+            let response = recorder
+                .stream(
+                    http::Request::builder(),
+                    stream_path.clone(),
+                    Bytes::from_static(b"subscribe"),
+                )
+                .await?;
+            let frames: Vec<_> = response.into_body().collect().await;
+            assert_eq!(frames.len(), 2);
+        }
+
+        let replayer = RecordReplayClient::new(
+            MockNetworkClient::default(),
+            RecordReplayMode::Strict,
+            Some(&path),
+        )?;
+        let response = replayer
+            .stream(
+                http::Request::builder(),
+                stream_path,
+                Bytes::from_static(b"subscribe"),
+            )
+            .await?;
+        let frames = response
+            .into_body()
+            .map(|frame| frame.map(|f| f.to_vec()))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(frames, vec![b"one".to_vec(), b"two".to_vec()]);
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_replays_recorded_bidi_streams() {
+        let path = recording_path("bidi");
+        let bidi_path = http::uri::PathAndQuery::from_static(
+            "/xmtp.xmtpv4.message_api.ReplicationApi/Subscribe",
+        );
+        let mut inner = MockNetworkClient::default();
+        inner.expect_bidi_stream().times(1).returning(|_, _, body| {
+            // Answer every outbound frame with one inbound frame
+            let inbound =
+                body.map(|frame| Ok(Bytes::from([b"re:".as_slice(), &frame[..]].concat())));
+            Ok(http::Response::new(BytesStream::new(inbound)))
+        });
+        {
+            let recorder = RecordReplayClient::new(inner, RecordReplayMode::Record, Some(&path))?;
+            let outbound =
+                stream::iter(vec![Bytes::from_static(b"one"), Bytes::from_static(b"two")]);
+            let response = recorder
+                .bidi_stream(
+                    http::Request::builder(),
+                    bidi_path.clone(),
+                    Box::pin(outbound),
+                )
+                .await?;
+            let frames: Vec<_> = response.into_body().collect().await;
+            assert_eq!(frames.len(), 2);
+        }
+
+        let recorded: Vec<Interaction> = std::fs::read_to_string(&path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].kind, InteractionKind::BidiStream);
+        assert_eq!(
+            recorded[0].sent,
+            vec![hex::encode(b"one"), hex::encode(b"two")]
+        );
+
+        let replayer = RecordReplayClient::new(
+            MockNetworkClient::default(),
+            RecordReplayMode::Strict,
+            Some(&path),
+        )?;
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let response = replayer
+            .bidi_stream(http::Request::builder(), bidi_path, Box::pin(rx))
+            .await?;
+        let mut inbound = response.into_body();
+        // Replay serves the recorded frames while the outbound side stays open
+        tx.unbounded_send(Bytes::from_static(b"one"))?;
+        let first = inbound.next().await.unwrap()?;
+        let second = inbound.next().await.unwrap()?;
+        assert_eq!(first.to_vec(), b"re:one".to_vec());
+        assert_eq!(second.to_vec(), b"re:two".to_vec());
+        assert!(!tx.is_closed());
+    }
+}
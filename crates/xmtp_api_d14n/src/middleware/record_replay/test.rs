@@ -0,0 +1,55 @@
+use super::*;
+use xmtp_proto::api_client::{ToxicProxies, ToxicTestClient, XmtpTestClient};
+
+/// Directory recordings are written to and read from
+pub const RECORDINGS_DIR_ENV: &str = "XMTP_API_RECORDINGS";
+/// One of `record`, `replay` or `strict`
+pub const RECORD_MODE_ENV: &str = "XMTP_API_RECORD_MODE";
+
+impl<Builder> RecordReplayBuilder<Builder> {
+    /// Configure the builder from [`RECORD_MODE_ENV`] and [`RECORDINGS_DIR_ENV`].
+    /// Each test thread gets its own recording, named after the test.
+    /// Without a mode set, the client passes every call through.
+    pub fn from_env(inner: Builder) -> Self {
+        let mut builder = Self::new(inner);
+        let mode = match std::env::var(RECORD_MODE_ENV).as_deref() {
+            Ok("record") => RecordReplayMode::Record,
+            Ok("replay") => RecordReplayMode::Replay,
+            Ok("strict") => RecordReplayMode::Strict,
+            _ => return builder,
+        };
+        let dir = std::env::var(RECORDINGS_DIR_ENV)
+            .unwrap_or_else(|_| panic!("{RECORD_MODE_ENV} requires {RECORDINGS_DIR_ENV}"));
+        let thread = std::thread::current();
+        let name: String = thread
+            .name()
+            .unwrap_or("unnamed")
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        builder
+            .mode(mode)
+            .path(PathBuf::from(dir).join(format!("{name}.jsonl")));
+        builder
+    }
+}
+
+impl<C> XmtpTestClient for RecordReplayClient<C>
+where
+    C: XmtpTestClient,
+{
+    type Builder = RecordReplayBuilder<C::Builder>;
+    fn create() -> Self::Builder {
+        RecordReplayBuilder::from_env(<C as XmtpTestClient>::create())
+    }
+}
+
+#[xmtp_common::async_trait]
+impl<C> ToxicTestClient for RecordReplayClient<C>
+where
+    C: ToxicTestClient,
+{
+    async fn proxies() -> ToxicProxies {
+        <C as ToxicTestClient>::proxies().await
+    }
+}
@@ -128,6 +128,14 @@ where
                     .map(|r| r.identifier.clone())
                     .collect::<Vec<_>>(),
             )
+            .solana_addresses(
+                request
+                    .requests
+                    .iter()
+                    .filter(|r| r.identifier_kind == IdentifierKind::Solana as i32)
+                    .map(|r| r.identifier.clone())
+                    .collect::<Vec<_>>(),
+            )
             .build()?
             .query(&self.client)
             .await?;
@@ -138,7 +146,7 @@ where
                 .iter()
                 .map(|r| identity_v1::get_inbox_ids_response::Response {
                     identifier: r.identifier.clone(),
-                    identifier_kind: IdentifierKind::Ethereum as i32,
+                    identifier_kind: r.identifier_kind,
                     inbox_id: r.inbox_id.clone(),
                 })
                 .collect::<Vec<_>>(),
@@ -57,6 +57,14 @@ where
                     .map(|r| r.identifier.clone())
                     .collect::<Vec<_>>(),
             )
+            .solana_addresses(
+                request
+                    .requests
+                    .iter()
+                    .filter(|r| r.identifier_kind == IdentifierKind::Solana as i32)
+                    .map(|r| r.identifier.clone())
+                    .collect::<Vec<_>>(),
+            )
             .build()?
             .query(&self.client)
             .await
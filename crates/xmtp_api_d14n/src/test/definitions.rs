@@ -1,8 +1,10 @@
 //! type definitions for different backends (mock/v3/d14n)
 //! "creators" are test clients that can be used with [`XmtpTestClient`]
+//! v3 and d14n test clients record or replay their traffic when
+//! `XMTP_API_RECORD_MODE` is set, see [`RecordReplayClient`]
 
 use crate::{
-    D14nClient, MigrationClient, ReadWriteClient, TrackedStatsClient, V3Client,
+    D14nClient, MigrationClient, ReadWriteClient, RecordReplayClient, TrackedStatsClient, V3Client,
     protocol::{CursorStore, NoCursorStore},
 };
 use std::sync::Arc;
@@ -21,52 +23,76 @@ pub type ApiError = xmtp_api_grpc::error::GrpcError;
 
 /// test client that speaks only v3
 /// switches local/dev on feature flag
-pub type TestV3Client = TrackedStatsClient<V3Client<GrpcClient, Arc<dyn CursorStore>>>;
+pub type TestV3Client =
+    TrackedStatsClient<V3Client<RecordReplayClient<GrpcClient>, Arc<dyn CursorStore>>>;
 
 /// A built test client that speaks only d14n.
 /// switches local/dev on feature flag
-pub type TestD14nClient =
-    TrackedStatsClient<D14nClient<ReadWriteClient<GrpcClient, GrpcClient>, Arc<dyn CursorStore>>>;
+pub type TestD14nClient = TrackedStatsClient<
+    D14nClient<
+        ReadWriteClient<RecordReplayClient<GrpcClient>, RecordReplayClient<GrpcClient>>,
+        Arc<dyn CursorStore>,
+    >,
+>;
 
 /// Creator for a feature-flag switchable D14n client
 pub type TestD14nClientCreator = TrackedStatsClient<
-    D14nClient<ReadWriteClient<XmtpdClient, GatewayClient>, Arc<dyn CursorStore>>,
+    D14nClient<
+        ReadWriteClient<RecordReplayClient<XmtpdClient>, RecordReplayClient<GatewayClient>>,
+        Arc<dyn CursorStore>,
+    >,
 >;
 /// Creator for a feature-flag switchable V3 client
-pub type TestV3ClientCreator = TrackedStatsClient<V3Client<NodeGoClient, Arc<dyn CursorStore>>>;
+pub type TestV3ClientCreator =
+    TrackedStatsClient<V3Client<RecordReplayClient<NodeGoClient>, Arc<dyn CursorStore>>>;
 
 /// A that only communicates with dev docker
 /// _does not switch on feature flag_
 pub type DevOnlyD14nClientCreator = TrackedStatsClient<
-    D14nClient<ReadWriteClient<DevXmtpdClient, DevGatewayClient>, Arc<dyn CursorStore>>,
+    D14nClient<
+        ReadWriteClient<RecordReplayClient<DevXmtpdClient>, RecordReplayClient<DevGatewayClient>>,
+        Arc<dyn CursorStore>,
+    >,
 >;
 
 /// A client that only communicates with dev network
 /// _does not switch on feature flag_
 pub type DevOnlyV3ClientCreator =
-    TrackedStatsClient<V3Client<DevNodeGoClient, Arc<dyn CursorStore>>>;
+    TrackedStatsClient<V3Client<RecordReplayClient<DevNodeGoClient>, Arc<dyn CursorStore>>>;
 
 /// A client that only communicates with local docker
 /// _does not switch on feature flag_
 pub type LocalOnlyD14nClientCreator = TrackedStatsClient<
-    D14nClient<ReadWriteClient<LocalXmtpdClient, LocalGatewayClient>, Arc<dyn CursorStore>>,
+    D14nClient<
+        ReadWriteClient<
+            RecordReplayClient<LocalXmtpdClient>,
+            RecordReplayClient<LocalGatewayClient>,
+        >,
+        Arc<dyn CursorStore>,
+    >,
 >;
 
 /// client that only communicates with local docker
 /// _does not switch on feature flag_
 pub type LocalOnlyV3ClientCreator =
-    TrackedStatsClient<V3Client<LocalNodeGoClient, Arc<dyn CursorStore>>>;
+    TrackedStatsClient<V3Client<RecordReplayClient<LocalNodeGoClient>, Arc<dyn CursorStore>>>;
 
 /// A client that only communicates with local docker toxiproxy
 /// _does not switch on feature flag_
 pub type ToxicOnlyD14nClientCreator = TrackedStatsClient<
-    D14nClient<ReadWriteClient<ToxicXmtpdClient, ToxicGatewayClient>, Arc<dyn CursorStore>>,
+    D14nClient<
+        ReadWriteClient<
+            RecordReplayClient<ToxicXmtpdClient>,
+            RecordReplayClient<ToxicGatewayClient>,
+        >,
+        Arc<dyn CursorStore>,
+    >,
 >;
 
 /// client that only communicates with local docker toxiproxy
 /// _does not switch on feature flag_
 pub type ToxicOnlyV3ClientCreator =
-    TrackedStatsClient<V3Client<ToxicNodeGoClient, Arc<dyn CursorStore>>>;
+    TrackedStatsClient<V3Client<RecordReplayClient<ToxicNodeGoClient>, Arc<dyn CursorStore>>>;
 
 /// V3 client with mock network
 pub type MockV3Client = V3Client<MockNetworkClient, NoCursorStore>;
@@ -1,5 +1,7 @@
+use xmtp_db::consent_record::ConsentState;
 use xmtp_proto::xmtp::device_sync::{
     ArchiveOptions as ArchiveOptionsProto, BackupElementSelection as BackupElementSelectionProto,
+    consent_backup::ConsentStateSave,
 };
 
 /// Native representation of backup element selection with strongly-typed variants.
@@ -13,6 +15,8 @@ pub enum BackupElementSelection {
     Messages,
     Consent,
     Event,
+    /// Local message drafts. Only exported when explicitly selected.
+    Drafts,
 }
 
 impl From<BackupElementSelectionProto> for BackupElementSelection {
@@ -23,6 +27,7 @@ impl From<BackupElementSelectionProto> for BackupElementSelection {
             BackupElementSelectionProto::Messages => Self::Messages,
             BackupElementSelectionProto::Consent => Self::Consent,
             BackupElementSelectionProto::Event => Self::Event,
+            BackupElementSelectionProto::Drafts => Self::Drafts,
         }
     }
 }
@@ -35,6 +40,7 @@ impl From<BackupElementSelection> for BackupElementSelectionProto {
             BackupElementSelection::Messages => Self::Messages,
             BackupElementSelection::Consent => Self::Consent,
             BackupElementSelection::Event => Self::Event,
+            BackupElementSelection::Drafts => Self::Drafts,
         }
     }
 }
@@ -43,12 +49,22 @@ impl From<BackupElementSelection> for BackupElementSelectionProto {
 ///
 /// This wraps the proto `ArchiveOptions` to provide a more ergonomic API,
 /// using `BackupElementSelection` enum values directly instead of raw `i32`.
+///
+/// The group filters narrow down which conversations (and their messages and consent
+/// records) are exported. Consent records for inboxes are only filtered by state.
+/// An empty filter does not restrict anything.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct ArchiveOptions {
     pub elements: Vec<BackupElementSelection>,
     pub start_ns: Option<i64>,
     pub end_ns: Option<i64>,
     pub exclude_disappearing_messages: bool,
+    /// Only export these groups.
+    pub include_group_ids: Vec<Vec<u8>>,
+    /// Never export these groups. Takes precedence over `include_group_ids`.
+    pub exclude_group_ids: Vec<Vec<u8>>,
+    /// Only export conversations whose consent state is one of these.
+    pub consent_states: Vec<ConsentState>,
 }
 
 impl ArchiveOptions {
@@ -62,8 +78,36 @@ impl ArchiveOptions {
             start_ns: None,
             end_ns: None,
             exclude_disappearing_messages: false,
+            include_group_ids: vec![],
+            exclude_group_ids: vec![],
+            consent_states: vec![],
         }
     }
+
+    pub(crate) fn include_group_ids_filter(&self) -> Option<Vec<Vec<u8>>> {
+        (!self.include_group_ids.is_empty()).then(|| self.include_group_ids.clone())
+    }
+
+    pub(crate) fn exclude_group_ids_filter(&self) -> Option<Vec<Vec<u8>>> {
+        (!self.exclude_group_ids.is_empty()).then(|| self.exclude_group_ids.clone())
+    }
+
+    pub(crate) fn consent_states_filter(&self) -> Option<Vec<ConsentState>> {
+        (!self.consent_states.is_empty()).then(|| self.consent_states.clone())
+    }
+}
+
+pub(crate) fn consent_states_from_proto(
+    states: impl Iterator<Item = ConsentStateSave>,
+) -> Vec<ConsentState> {
+    states.filter_map(|state| state.try_into().ok()).collect()
+}
+
+pub(crate) fn consent_states_to_proto(states: &[ConsentState]) -> Vec<i32> {
+    states
+        .iter()
+        .map(|state| ConsentStateSave::from(*state) as i32)
+        .collect()
 }
 
 impl From<ArchiveOptionsProto> for ArchiveOptions {
@@ -73,6 +117,9 @@ impl From<ArchiveOptionsProto> for ArchiveOptions {
             start_ns: proto.start_ns,
             end_ns: proto.end_ns,
             exclude_disappearing_messages: proto.exclude_disappearing_messages,
+            consent_states: consent_states_from_proto(proto.consent_states()),
+            include_group_ids: proto.include_group_ids,
+            exclude_group_ids: proto.exclude_group_ids,
         }
     }
 }
@@ -88,6 +135,9 @@ impl From<ArchiveOptions> for ArchiveOptionsProto {
             start_ns: opts.start_ns,
             end_ns: opts.end_ns,
             exclude_disappearing_messages: opts.exclude_disappearing_messages,
+            consent_states: consent_states_to_proto(&opts.consent_states),
+            include_group_ids: opts.include_group_ids,
+            exclude_group_ids: opts.exclude_group_ids,
         }
     }
 }
@@ -103,6 +153,9 @@ impl From<&ArchiveOptions> for ArchiveOptionsProto {
             start_ns: opts.start_ns,
             end_ns: opts.end_ns,
             exclude_disappearing_messages: opts.exclude_disappearing_messages,
+            include_group_ids: opts.include_group_ids.clone(),
+            exclude_group_ids: opts.exclude_group_ids.clone(),
+            consent_states: consent_states_to_proto(&opts.consent_states),
         }
     }
 }
@@ -118,6 +171,7 @@ mod tests {
             BackupElementSelection::Messages,
             BackupElementSelection::Consent,
             BackupElementSelection::Event,
+            BackupElementSelection::Drafts,
         ];
 
         for variant in variants {
@@ -137,6 +191,9 @@ mod tests {
             start_ns: Some(1000),
             end_ns: Some(2000),
             exclude_disappearing_messages: true,
+            include_group_ids: vec![vec![1, 2, 3]],
+            exclude_group_ids: vec![vec![4, 5, 6]],
+            consent_states: vec![ConsentState::Allowed, ConsentState::Unknown],
         };
 
         let proto: ArchiveOptionsProto = opts.clone().into();
@@ -155,6 +212,8 @@ mod tests {
         assert_eq!(opts.start_ns, None);
         assert_eq!(opts.end_ns, None);
         assert!(!opts.exclude_disappearing_messages);
+        assert!(opts.include_group_ids.is_empty());
+        assert!(opts.consent_states.is_empty());
     }
 
     #[test]
@@ -164,6 +223,9 @@ mod tests {
             start_ns: Some(500),
             end_ns: None,
             exclude_disappearing_messages: false,
+            include_group_ids: vec![vec![9]],
+            exclude_group_ids: vec![],
+            consent_states: vec![ConsentState::Denied],
         };
 
         let proto: ArchiveOptionsProto = (&opts).into();
@@ -174,5 +236,8 @@ mod tests {
         assert_eq!(proto.start_ns, Some(500));
         assert_eq!(proto.end_ns, None);
         assert!(!proto.exclude_disappearing_messages);
+        assert_eq!(proto.include_group_ids, vec![vec![9]]);
+        assert!(proto.exclude_group_ids.is_empty());
+        assert_eq!(proto.consent_states, vec![ConsentStateSave::Denied as i32]);
     }
 }
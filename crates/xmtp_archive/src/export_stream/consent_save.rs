@@ -1,4 +1,5 @@
 use super::*;
+use xmtp_db::consent_record::ConsentRecordQueryArgs;
 use xmtp_proto::xmtp::device_sync::{backup_element::Element, consent_backup::ConsentSave};
 
 #[xmtp_common::async_trait]
@@ -11,16 +12,32 @@ impl BackupRecordProvider for ConsentSave {
         Self: Sized,
         D: DbQuery,
     {
-        let cursor = state.cursor.load(Ordering::SeqCst);
-        let batch = state.db.consent_records_paged(Self::BATCH_SIZE, cursor)?;
+        let args = ConsentRecordQueryArgs {
+            changed_after_ns: state.since_ns,
+            group_ids: state.opts.include_group_ids_filter(),
+            exclude_group_ids: state.opts.exclude_group_ids_filter(),
+            consent_states: state.opts.consent_states_filter(),
+        };
+        // The cursor holds the sequence id of the last change exported
+        let cursor = state.cursor.load(Ordering::SeqCst) as i32;
+        let batch = state
+            .db
+            .consent_records_paged(Self::BATCH_SIZE, cursor, &args)?;
+        if let Some((sequence_id, _)) = batch.last() {
+            state
+                .cursor
+                .store(i64::from(*sequence_id), Ordering::SeqCst);
+        }
 
         let records = batch
             .into_iter()
-            .map(|record| BackupElement {
+            .map(|(_, record)| BackupElement {
                 element: Some(Element::Consent(record.into())),
             })
             .collect();
 
         Ok(records)
     }
+
+    fn advance_cursor<D>(_state: &BackupProviderState<D>) {}
 }
@@ -0,0 +1,32 @@
+use super::*;
+use xmtp_proto::xmtp::device_sync::{backup_element::Element, draft_backup::DraftSave};
+
+#[xmtp_common::async_trait]
+impl BackupRecordProvider for DraftSave {
+    const BATCH_SIZE: i64 = 100;
+    async fn backup_records<D>(
+        state: Arc<BackupProviderState<D>>,
+    ) -> Result<Vec<BackupElement>, StorageError>
+    where
+        Self: Sized,
+        D: DbQuery,
+    {
+        let cursor = state.cursor.load(Ordering::SeqCst);
+        let batch = state.db.drafts_paged(
+            Self::BATCH_SIZE,
+            cursor,
+            state.since_ns,
+            state.opts.include_group_ids_filter(),
+            state.opts.exclude_group_ids_filter(),
+        )?;
+
+        let records = batch
+            .into_iter()
+            .map(|draft| BackupElement {
+                element: Some(Element::Draft(draft.into())),
+            })
+            .collect();
+
+        Ok(records)
+    }
+}
@@ -33,6 +33,10 @@ impl BackupRecordProvider for GroupSave {
         if let Some(end_ns) = state.opts.end_ns {
             args.created_before_ns = Some(end_ns);
         }
+        args.group_ids = state.opts.include_group_ids_filter();
+        args.exclude_group_ids = state.opts.exclude_group_ids_filter();
+        args.consent_states = state.opts.consent_states_filter();
+        args.changed_after_ns = state.since_ns;
 
         args.limit = Some(Self::BATCH_SIZE);
 
@@ -16,6 +16,10 @@ impl BackupRecordProvider for GroupMessageSave {
             .sent_after_ns(state.opts.start_ns)
             .sent_before_ns(state.opts.end_ns)
             .exclude_disappearing(state.opts.exclude_disappearing_messages)
+            .group_ids(state.opts.include_group_ids_filter())
+            .exclude_group_ids(state.opts.exclude_group_ids_filter())
+            .group_consent_states(state.opts.consent_states_filter())
+            .inserted_after_ns(state.since_ns)
             .limit(Self::BATCH_SIZE)
             .build()
             .expect("could not build");
@@ -12,13 +12,14 @@ use std::{
 use xmtp_common::{MaybeSend, MaybeSendFuture, if_native, if_wasm};
 use xmtp_db::{StorageError, prelude::*};
 use xmtp_proto::xmtp::device_sync::{
-    BackupElement, consent_backup::ConsentSave, group_backup::GroupSave,
+    BackupElement, consent_backup::ConsentSave, draft_backup::DraftSave, group_backup::GroupSave,
     message_backup::GroupMessageSave,
 };
 
 use crate::archive_options::{ArchiveOptions, BackupElementSelection};
 
 pub(crate) mod consent_save;
+pub(crate) mod draft_save;
 pub(crate) mod group_save;
 pub(crate) mod message_save;
 
@@ -41,7 +42,8 @@ pub(super) struct BatchExportStream {
 
 impl BatchExportStream {
     #[allow(deprecated)]
-    pub(super) fn new<D>(opts: &ArchiveOptions, db: Arc<D>) -> Self
+    /// `since_ns` limits the export to records changed after that time, for incremental archives.
+    pub(super) fn new<D>(opts: &ArchiveOptions, since_ns: Option<i64>, db: Arc<D>) -> Self
     where
         D: DbQuery + 'static,
     {
@@ -53,16 +55,29 @@ impl BatchExportStream {
                     vec![BackupRecordStreamer::<ConsentSave, D>::new_stream(
                         db.clone(),
                         opts.clone(),
+                        since_ns,
                     )]
                 }
                 BackupElementSelection::Messages => vec![
                     // Order matters here. Don't put messages before groups.
-                    BackupRecordStreamer::<GroupSave, D>::new_stream(db.clone(), opts.clone()),
+                    BackupRecordStreamer::<GroupSave, D>::new_stream(
+                        db.clone(),
+                        opts.clone(),
+                        since_ns,
+                    ),
                     BackupRecordStreamer::<GroupMessageSave, D>::new_stream(
                         db.clone(),
                         opts.clone(),
+                        since_ns,
                     ),
                 ],
+                BackupElementSelection::Drafts => {
+                    vec![BackupRecordStreamer::<DraftSave, D>::new_stream(
+                        db.clone(),
+                        opts.clone(),
+                        since_ns,
+                    )]
+                }
                 BackupElementSelection::Event => vec![],
                 BackupElementSelection::Unspecified => vec![],
             })
@@ -128,12 +143,19 @@ pub(crate) trait BackupRecordProvider: MaybeSend + Sized + 'static {
     ) -> Result<Vec<BackupElement>, StorageError>
     where
         D: MaybeSend + DbQuery + 'static;
+
+    /// Move the cursor past a batch that was just returned. Providers paging by offset skip
+    /// the batch size, providers paging by key move the cursor in `backup_records`.
+    fn advance_cursor<D>(state: &BackupProviderState<D>) {
+        state.cursor.fetch_add(Self::BATCH_SIZE, Ordering::SeqCst);
+    }
 }
 
 pub struct BackupProviderState<D> {
     db: Arc<D>,
     cursor: AtomicI64,
     opts: ArchiveOptions,
+    since_ns: Option<i64>,
 }
 
 #[pin_project]
@@ -149,12 +171,17 @@ where
     R: BackupRecordProvider + 'static,
     D: DbQuery + 'static,
 {
-    pub(super) fn new_stream(db: Arc<D>, opts: ArchiveOptions) -> BackupInputStream {
+    pub(super) fn new_stream(
+        db: Arc<D>,
+        opts: ArchiveOptions,
+        since_ns: Option<i64>,
+    ) -> BackupInputStream {
         Box::pin(Self {
             provider_state: Arc::new(BackupProviderState {
                 db,
                 cursor: AtomicI64::new(0),
                 opts,
+                since_ns,
             }),
             _phantom: PhantomData,
             current_future: None,
@@ -195,9 +222,7 @@ where
             }
             Ok(elements) => {
                 // Update cursor for next batch
-                this.provider_state
-                    .cursor
-                    .fetch_add(R::BATCH_SIZE, Ordering::SeqCst);
+                R::advance_cursor(this.provider_state);
                 Poll::Ready(Some(Ok(elements)))
             }
             Err(e) => {
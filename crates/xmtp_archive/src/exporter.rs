@@ -1,5 +1,10 @@
-use super::{BACKUP_VERSION, OptionsToSave, export_stream::BatchExportStream};
+use super::{
+    BACKUP_VERSION, BackupMetadata, OptionsToSave, RAW_KEY_BACKUP_VERSION,
+    export_stream::BatchExportStream,
+};
 use crate::archive_options::ArchiveOptions;
+use crate::manifest::ManifestBuilder;
+use crate::passphrase::{KdfParams, KeySource};
 use crate::{NONCE_SIZE, util::GenericArrayExt};
 use aes_gcm::{Aes256Gcm, AesGcm, KeyInit, aead::Aead, aes::Aes256};
 use async_compression::futures::write::ZstdEncoder;
@@ -23,13 +28,14 @@ pub struct ArchiveExporter {
     #[pin]
     stream: BatchExportStream,
     position: usize,
+    manifest: ManifestBuilder,
     zstd_encoder: ZstdEncoder<Vec<u8>>,
     encoder_finished: bool,
 
     cipher: AesGcm<Aes256, typenum::U12, typenum::U16>,
     nonce: GenericArray<u8, typenum::U12>,
 
-    // Used to write the nonce, contains the same data as nonce.
+    // Used to write the version, key source and nonce. Ends with the same data as nonce.
     nonce_buffer: Vec<u8>,
 }
 
@@ -39,6 +45,7 @@ pub(super) enum Stage {
     Nonce,
     Metadata,
     Elements,
+    Finished,
 }
 
 impl ArchiveExporter {
@@ -58,6 +65,57 @@ impl ArchiveExporter {
         Ok(exporter.metadata)
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn export_to_file_with_passphrase<D>(
+        options: ArchiveOptions,
+        db: D,
+        path: impl AsRef<std::path::Path>,
+        passphrase: &str,
+    ) -> Result<BackupMetadataSave, crate::ArchiveError>
+    where
+        D: DbQuery + 'static,
+    {
+        let mut exporter = Self::with_passphrase(options, db, passphrase).await?;
+        exporter.write_to_file(path).await?;
+
+        Ok(exporter.metadata)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn export_incremental_to_file<D>(
+        options: ArchiveOptions,
+        previous: &BackupMetadata,
+        db: D,
+        path: impl AsRef<std::path::Path>,
+        key: &[u8],
+    ) -> Result<BackupMetadataSave, crate::ArchiveError>
+    where
+        D: DbQuery + 'static,
+    {
+        let mut exporter = Self::incremental(options, previous, db, key)?;
+        exporter.write_to_file(path).await?;
+
+        Ok(exporter.metadata)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn export_incremental_to_file_with_passphrase<D>(
+        options: ArchiveOptions,
+        previous: &BackupMetadata,
+        db: D,
+        path: impl AsRef<std::path::Path>,
+        passphrase: &str,
+    ) -> Result<BackupMetadataSave, crate::ArchiveError>
+    where
+        D: DbQuery + 'static,
+    {
+        let mut exporter =
+            Self::incremental_with_passphrase(options, previous, db, passphrase).await?;
+        exporter.write_to_file(path).await?;
+
+        Ok(exporter.metadata)
+    }
+
     pub async fn post_to_url(self, url: &str) -> Result<String, crate::ArchiveError> {
         #[cfg(not(target_arch = "wasm32"))]
         let body = {
@@ -100,15 +158,112 @@ impl ArchiveExporter {
     where
         D: DbQuery + 'static,
     {
-        let mut nonce_buffer = BACKUP_VERSION.to_le_bytes().to_vec();
+        Self::build(options, None, db, key, KeySource::Raw)
+    }
+
+    /// Encrypt the archive with a key derived from `passphrase`.
+    /// The KDF salt and parameters are stored in the archive header.
+    pub async fn with_passphrase<D>(
+        options: ArchiveOptions,
+        db: D,
+        passphrase: &str,
+    ) -> Result<Self, crate::ArchiveError>
+    where
+        D: DbQuery + 'static,
+    {
+        let params = KdfParams::default();
+        let key = params.derive_key_async(passphrase).await?;
+
+        Ok(Self::build(
+            options,
+            None,
+            db,
+            &key,
+            KeySource::Passphrase(params),
+        ))
+    }
+
+    /// Export only what changed since the `previous` archive was made.
+    /// The resulting archive must be imported after `previous`.
+    pub fn incremental<D>(
+        options: ArchiveOptions,
+        previous: &BackupMetadata,
+        db: D,
+        key: &[u8],
+    ) -> Result<Self, crate::ArchiveError>
+    where
+        D: DbQuery + 'static,
+    {
+        if previous.archive_id.is_empty() {
+            return Err(crate::ArchiveError::NotChainable);
+        }
+
+        Ok(Self::build(
+            options,
+            Some(previous),
+            db,
+            key,
+            KeySource::Raw,
+        ))
+    }
+
+    /// [`Self::incremental`], encrypted with a key derived from `passphrase`.
+    pub async fn incremental_with_passphrase<D>(
+        options: ArchiveOptions,
+        previous: &BackupMetadata,
+        db: D,
+        passphrase: &str,
+    ) -> Result<Self, crate::ArchiveError>
+    where
+        D: DbQuery + 'static,
+    {
+        if previous.archive_id.is_empty() {
+            return Err(crate::ArchiveError::NotChainable);
+        }
+        let params = KdfParams::default();
+        let key = params.derive_key_async(passphrase).await?;
+
+        Ok(Self::build(
+            options,
+            Some(previous),
+            db,
+            &key,
+            KeySource::Passphrase(params),
+        ))
+    }
+
+    fn build<D>(
+        options: ArchiveOptions,
+        previous: Option<&BackupMetadata>,
+        db: D,
+        key: &[u8],
+        key_source: KeySource,
+    ) -> Self
+    where
+        D: DbQuery + 'static,
+    {
+        // Raw key archives leave out the key source, so version 0 importers can read them.
+        let mut nonce_buffer = match key_source {
+            KeySource::Raw => RAW_KEY_BACKUP_VERSION.to_le_bytes().to_vec(),
+            KeySource::Passphrase(_) => {
+                let mut header = BACKUP_VERSION.to_le_bytes().to_vec();
+                header.extend(key_source.to_bytes());
+                header
+            }
+        };
         let nonce = xmtp_common::rand_array::<NONCE_SIZE>();
         nonce_buffer.extend_from_slice(&nonce);
 
         Self {
             position: 0,
             stage: Stage::default(),
-            stream: BatchExportStream::new(&options, Arc::new(db)),
-            metadata: BackupMetadataSave::from_options(options),
+            stream: BatchExportStream::new(
+                &options,
+                previous.map(|p| p.exported_at_ns),
+                Arc::new(db),
+            ),
+            metadata: BackupMetadataSave::from_options(options, previous),
+            manifest: ManifestBuilder::new(key),
             zstd_encoder: ZstdEncoder::new(Vec::new()),
             encoder_finished: false,
 
@@ -178,25 +333,39 @@ impl AsyncRead for ArchiveExporter {
                     }
                     Stage::Metadata => {
                         *this.stage = Stage::Elements;
-                        BackupElement {
+                        let element = BackupElement {
                             element: Some(Element::Metadata(this.metadata.clone())),
-                        }
-                        .encode_to_vec()
+                        };
+                        let bytes = element.encode_to_vec();
+                        this.manifest.record(&element, &bytes);
+                        bytes
                     }
                     Stage::Elements => match ready!(this.stream.as_mut().poll_next(cx)) {
-                        Some(element) => element
-                            .map_err(|err| io::Error::other(err.to_string()))?
-                            .encode_to_vec(),
+                        Some(element) => {
+                            let element =
+                                element.map_err(|err| io::Error::other(err.to_string()))?;
+                            let bytes = element.encode_to_vec();
+                            this.manifest.record(&element, &bytes);
+                            bytes
+                        }
                         None => {
-                            if !*this.encoder_finished {
-                                *this.encoder_finished = true;
-                                let fut = this.zstd_encoder.close();
-                                pin_mut!(fut);
-                                let _ = fut.poll(cx)?;
+                            // Close out the archive with a manifest of everything written.
+                            *this.stage = Stage::Finished;
+                            BackupElement {
+                                element: Some(Element::Manifest(this.manifest.finish())),
                             }
-                            break;
+                            .encode_to_vec()
                         }
                     },
+                    Stage::Finished => {
+                        if !*this.encoder_finished {
+                            *this.encoder_finished = true;
+                            let fut = this.zstd_encoder.close();
+                            pin_mut!(fut);
+                            let _ = fut.poll(cx)?;
+                        }
+                        break;
+                    }
                 };
 
                 let mut element = this
@@ -229,3 +398,100 @@ impl AsyncRead for ArchiveExporter {
         }
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::archive_options::BackupElementSelection;
+    use async_compression::futures::bufread::ZstdDecoder;
+    use futures::io::{AsyncReadExt, BufReader, Cursor};
+    use xmtp_db::Store;
+    use xmtp_db::consent_record::{ConsentState, ConsentType, StoredConsentRecord};
+    use xmtp_db::test_utils::with_connection_async;
+    use xmtp_proto::xmtp::device_sync::consent_backup::ConsentSave;
+
+    /// `BackupElement` as importers before version 1 decode it, without the manifest.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct V0BackupElement {
+        #[prost(oneof = "V0Element", tags = "1, 4")]
+        element: Option<V0Element>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    enum V0Element {
+        #[prost(message, tag = "1")]
+        Metadata(BackupMetadataSave),
+        #[prost(message, tag = "4")]
+        Consent(ConsentSave),
+    }
+
+    /// Read an archive the way importers before version 1 did: the version is ignored and the
+    /// nonce follows it.
+    async fn read_as_v0(archive: &[u8], key: &[u8]) -> Vec<V0BackupElement> {
+        let (header, body) = archive.split_at(2 + NONCE_SIZE);
+        let mut decompressed = vec![];
+        ZstdDecoder::new(BufReader::new(Cursor::new(body)))
+            .read_to_end(&mut decompressed)
+            .await
+            .unwrap();
+
+        #[allow(deprecated)]
+        let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+        #[allow(deprecated)]
+        let mut nonce = GenericArray::clone_from_slice(&header[2..]);
+        let mut elements = vec![];
+        let mut rest = &decompressed[..];
+        while !rest.is_empty() {
+            let (len, tail) = rest.split_at(4);
+            let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+            let decrypted = cipher.decrypt(&nonce, &tail[..len]).unwrap();
+            nonce.increment();
+            elements.push(V0BackupElement::decode(&*decrypted).unwrap());
+            rest = &tail[len..];
+        }
+        elements
+    }
+
+    #[xmtp_common::test]
+    async fn test_v0_importers_skip_the_manifest() {
+        with_connection_async(|conn| async move {
+            StoredConsentRecord::new(
+                ConsentType::InboxId,
+                ConsentState::Allowed,
+                "inbox".to_string(),
+            )
+            .store(&conn)
+            .unwrap();
+
+            let key = [7; 32];
+            let opts = ArchiveOptions {
+                elements: vec![BackupElementSelection::Consent],
+                ..Default::default()
+            };
+            let mut archive = vec![];
+            ArchiveExporter::new(opts, conn, &key)
+                .read_to_end(&mut archive)
+                .await
+                .unwrap();
+            assert_eq!(
+                u16::from_le_bytes([archive[0], archive[1]]),
+                RAW_KEY_BACKUP_VERSION
+            );
+
+            let elements = read_as_v0(&archive, &key).await;
+            assert!(matches!(
+                elements.first().and_then(|e| e.element.as_ref()),
+                Some(V0Element::Metadata(_))
+            ));
+            assert!(matches!(
+                elements.get(1).and_then(|e| e.element.as_ref()),
+                Some(V0Element::Consent(consent)) if consent.entity == "inbox"
+            ));
+            // The manifest is an unknown field to them, so it decodes as an empty element,
+            // which they skip on import.
+            assert_eq!(elements.len(), 3);
+            assert_eq!(elements[2].element, None);
+        })
+        .await
+    }
+}
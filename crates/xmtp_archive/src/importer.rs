@@ -1,5 +1,8 @@
 use super::{ArchiveError, BackupMetadata};
-use crate::{NONCE_SIZE, util::GenericArrayExt};
+use crate::{
+    KEY_SOURCE_BACKUP_VERSION, MANIFEST_BACKUP_VERSION, NONCE_SIZE, manifest::ManifestBuilder,
+    passphrase::KeySource, util::GenericArrayExt,
+};
 use aes_gcm::{Aes256Gcm, AesGcm, KeyInit, aead::Aead, aes::Aes256};
 use async_compression::futures::bufread::ZstdDecoder;
 use futures::{FutureExt, Stream, StreamExt};
@@ -7,7 +10,7 @@ use futures_util::{AsyncBufRead, AsyncReadExt};
 use prost::Message;
 #[allow(deprecated)]
 use sha2::digest::{generic_array::GenericArray, typenum};
-use std::{pin::Pin, task::Poll};
+use std::{io, pin::Pin, task::Poll};
 use xmtp_common::{if_native, if_wasm};
 use xmtp_proto::xmtp::device_sync::{BackupElement, backup_element::Element};
 
@@ -27,6 +30,42 @@ pub struct ArchiveImporter {
     cipher: AesGcm<Aes256, typenum::U12, typenum::U16>,
     #[allow(deprecated)]
     nonce: GenericArray<u8, typenum::U12>,
+
+    manifest: ManifestBuilder,
+    manifest_required: bool,
+    manifest_state: ManifestState,
+}
+
+#[derive(Default, PartialEq)]
+enum ManifestState {
+    #[default]
+    Pending,
+    Verified,
+    Failed,
+}
+
+/// What an archive holds, as reported by [`ArchiveImporter::verify`].
+#[derive(Default)]
+pub struct ArchiveReport {
+    pub metadata: BackupMetadata,
+    pub group_count: u64,
+    pub group_message_count: u64,
+    pub consent_count: u64,
+    /// Sent time of the oldest message in the archive.
+    pub earliest_message_ns: Option<i64>,
+    /// Sent time of the newest message in the archive.
+    pub latest_message_ns: Option<i64>,
+    /// Whether the archive ended with a manifest whose MAC and contents check out.
+    /// Archives older than version 1 have no manifest.
+    pub manifest_verified: bool,
+    /// Decryption, decode and manifest failures, in the order they were found.
+    pub failures: Vec<String>,
+}
+
+impl ArchiveReport {
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
 }
 
 impl Stream for ArchiveImporter {
@@ -69,27 +108,84 @@ impl Stream for ArchiveImporter {
                     }
                 };
 
-                let element = BackupElement::decode(&*decrypted);
                 this.decoded.drain(..element_len);
                 this.nonce.increment();
-                return Poll::Ready(Some(element.map_err(ArchiveError::from)));
+                element_len = 0;
+
+                let element = BackupElement::decode(&*decrypted)?;
+                if let Some(Element::Manifest(manifest)) = &element.element {
+                    this.manifest_state = ManifestState::Failed;
+                    this.manifest.check(manifest)?;
+                    this.manifest_state = ManifestState::Verified;
+                    continue;
+                }
+                this.manifest.record(&element, &decrypted);
+                return Poll::Ready(Some(Ok(element)));
             }
 
-            if amount == 0 && this.decoded.is_empty() {
+            if amount == 0 {
+                if !this.decoded.is_empty() {
+                    this.decoded.clear();
+                    return Poll::Ready(Some(Err(ArchiveError::IO(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "archive is truncated",
+                    )))));
+                }
                 break;
             }
         }
 
+        if this.manifest_required && this.manifest_state == ManifestState::Pending {
+            this.manifest_state = ManifestState::Failed;
+            return Poll::Ready(Some(Err(ArchiveError::Manifest(
+                "archive has no manifest, it may be truncated".to_string(),
+            ))));
+        }
+
         Poll::Ready(None)
     }
 }
 
+enum ArchiveKey<'a> {
+    Raw(&'a [u8]),
+    Passphrase(&'a str),
+}
+
 impl ArchiveImporter {
-    pub async fn load(mut reader: AsyncReader, key: &[u8]) -> Result<Self, ArchiveError> {
+    pub async fn load(reader: AsyncReader, key: &[u8]) -> Result<Self, ArchiveError> {
+        Self::load_with(reader, ArchiveKey::Raw(key)).await
+    }
+
+    /// Load an archive that was exported with a passphrase.
+    pub async fn load_with_passphrase(
+        reader: AsyncReader,
+        passphrase: &str,
+    ) -> Result<Self, ArchiveError> {
+        Self::load_with(reader, ArchiveKey::Passphrase(passphrase)).await
+    }
+
+    async fn load_with(mut reader: AsyncReader, key: ArchiveKey<'_>) -> Result<Self, ArchiveError> {
         let mut version = [0; 2];
         reader.read_exact(&mut version).await?;
         let version = u16::from_le_bytes(version);
 
+        // Archives before version 2 were always encrypted with a raw key.
+        let key_source = if version < KEY_SOURCE_BACKUP_VERSION {
+            KeySource::Raw
+        } else {
+            KeySource::read(&mut reader).await?
+        };
+        let key = match (key_source, key) {
+            (KeySource::Raw, ArchiveKey::Raw(key)) => key.to_vec(),
+            (KeySource::Passphrase(params), ArchiveKey::Passphrase(passphrase)) => {
+                params.derive_key_async(passphrase).await?.to_vec()
+            }
+            (KeySource::Raw, ArchiveKey::Passphrase(_)) => return Err(ArchiveError::KeyRequired),
+            (KeySource::Passphrase(_), ArchiveKey::Raw(_)) => {
+                return Err(ArchiveError::PassphraseRequired);
+            }
+        };
+
         let mut nonce = [0; NONCE_SIZE];
         reader.read_exact(&mut nonce).await?;
 
@@ -99,9 +195,13 @@ impl ArchiveImporter {
             metadata: BackupMetadata::default(),
 
             #[allow(deprecated)]
-            cipher: Aes256Gcm::new(GenericArray::from_slice(key)),
+            cipher: Aes256Gcm::new(GenericArray::from_slice(&key)),
             #[allow(deprecated)]
             nonce: GenericArray::from(nonce),
+
+            manifest: ManifestBuilder::new(&key),
+            manifest_required: version >= MANIFEST_BACKUP_VERSION,
+            manifest_state: ManifestState::default(),
         };
 
         let Some(Ok(BackupElement {
@@ -118,4 +218,42 @@ impl ArchiveImporter {
     pub fn metadata(&self) -> &BackupMetadata {
         &self.metadata
     }
+
+    /// Read through the whole archive without importing anything,
+    /// reporting what it holds and any failures along the way.
+    pub async fn verify(mut self) -> ArchiveReport {
+        let mut report = ArchiveReport::default();
+
+        while let Some(element) = self.next().await {
+            match element.map(|e| e.element) {
+                Ok(Some(Element::Group(_))) => report.group_count += 1,
+                Ok(Some(Element::GroupMessage(message))) => {
+                    report.group_message_count += 1;
+                    let sent_at_ns = message.sent_at_ns;
+                    report.earliest_message_ns = Some(
+                        report
+                            .earliest_message_ns
+                            .map_or(sent_at_ns, |ns| ns.min(sent_at_ns)),
+                    );
+                    report.latest_message_ns = Some(
+                        report
+                            .latest_message_ns
+                            .map_or(sent_at_ns, |ns| ns.max(sent_at_ns)),
+                    );
+                }
+                Ok(Some(Element::Consent(_))) => report.consent_count += 1,
+                Ok(_) => {}
+                // The rest of the archive can't be read after a decryption or read failure.
+                Err(err @ (ArchiveError::AesGcm(_) | ArchiveError::IO(_))) => {
+                    report.failures.push(err.to_string());
+                    break;
+                }
+                Err(err) => report.failures.push(err.to_string()),
+            }
+        }
+
+        report.manifest_verified = self.manifest_state == ManifestState::Verified;
+        report.metadata = self.metadata;
+        report
+    }
 }
@@ -12,4 +12,15 @@ impl ArchiveImporter {
 
         Self::load(reader, key).await
     }
+
+    pub async fn from_file_with_passphrase(
+        path: impl AsRef<Path>,
+        passphrase: &str,
+    ) -> Result<Self, ArchiveError> {
+        let reader = tokio::fs::File::open(path.as_ref()).await?;
+        let reader = BufReader::new(reader.compat());
+        let reader = Box::pin(reader) as Pin<Box<_>>;
+
+        Self::load_with_passphrase(reader, passphrase).await
+    }
 }
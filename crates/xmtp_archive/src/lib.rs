@@ -1,7 +1,10 @@
-use crate::archive_options::{ArchiveOptions, BackupElementSelection};
-pub use importer::ArchiveImporter;
+use crate::archive_options::{
+    ArchiveOptions, BackupElementSelection, consent_states_from_proto, consent_states_to_proto,
+};
+pub use importer::{ArchiveImporter, ArchiveReport};
 use thiserror::Error;
 use xmtp_common::time::now_ns;
+use xmtp_db::consent_record::ConsentState;
 use xmtp_proto::xmtp::device_sync::{
     BackupElementSelection as BackupElementSelectionProto, BackupMetadataSave,
 };
@@ -9,13 +12,27 @@ use xmtp_proto::xmtp::device_sync::{
 pub const ENC_KEY_SIZE: usize = 32; // 256-bit key
 pub const NONCE_SIZE: usize = 12; // 96-bit nonce
 
-// Increment on breaking changes
-pub const BACKUP_VERSION: u16 = 0;
+// Increment on breaking changes. Importers before version 1 ignore the version and read the
+// version 0 header, so each version only adds to the one before it.
+// 0: The header is the version followed by the nonce. Encrypted with a raw key.
+// 1: Archives end with a manifest authenticated by an HMAC keyed from the archive key, which
+//    version 0 importers decode as an empty element and skip.
+// 2: The header holds a `KeySource` between the version and the nonce.
+pub const BACKUP_VERSION: u16 = 2;
+// Archives encrypted with a raw key, like the ones sent by device sync, are written as version 1
+// so that older installations can still import them.
+pub const RAW_KEY_BACKUP_VERSION: u16 = 1;
+// First version that ends with a manifest.
+pub(crate) const MANIFEST_BACKUP_VERSION: u16 = 1;
+// First version with a `KeySource` in the header.
+pub(crate) const KEY_SOURCE_BACKUP_VERSION: u16 = 2;
 
 pub mod archive_options;
 mod export_stream;
 pub mod exporter;
 pub mod importer;
+mod manifest;
+pub mod passphrase;
 mod util;
 
 #[derive(Debug, Error)]
@@ -30,6 +47,18 @@ pub enum ArchiveError {
     Decode(#[from] prost::DecodeError),
     #[error("reqwest error: {0}")]
     Reqwest(#[from] reqwest::Error),
+    #[error("Archive has no id and cannot be followed by an incremental archive")]
+    NotChainable,
+    #[error("Archive chain is broken: {0}")]
+    BrokenChain(String),
+    #[error("Key derivation error: {0}")]
+    Kdf(String),
+    #[error("Archive is protected by a passphrase")]
+    PassphraseRequired,
+    #[error("Archive is protected by a key, not a passphrase")]
+    KeyRequired,
+    #[error("Archive manifest check failed: {0}")]
+    Manifest(String),
 }
 
 #[derive(Default)]
@@ -39,6 +68,18 @@ pub struct BackupMetadata {
     pub exported_at_ns: i64,
     pub start_ns: Option<i64>,
     pub end_ns: Option<i64>,
+    /// Groups the export was limited to. Empty if it was not limited.
+    pub include_group_ids: Vec<Vec<u8>>,
+    /// Groups left out of the export.
+    pub exclude_group_ids: Vec<Vec<u8>>,
+    /// Consent states the exported conversations were limited to. Empty if not limited.
+    pub consent_states: Vec<ConsentState>,
+    /// Random id of this archive. Empty for archives exported before chaining was supported.
+    pub archive_id: Vec<u8>,
+    /// Id of the archive this incremental archive follows. None for full archives.
+    pub previous_archive_id: Option<Vec<u8>>,
+    /// Records changed after this time are included in an incremental archive.
+    pub since_ns: Option<i64>,
 }
 
 impl BackupMetadata {
@@ -48,20 +89,78 @@ impl BackupMetadata {
             end_ns: save.end_ns,
             start_ns: save.start_ns,
             exported_at_ns: save.exported_at_ns,
+            consent_states: consent_states_from_proto(save.consent_states()),
+            include_group_ids: save.include_group_ids,
+            exclude_group_ids: save.exclude_group_ids,
+            archive_id: save.archive_id,
+            previous_archive_id: save.previous_archive_id,
+            since_ns: save.since_ns,
             backup_version,
         }
     }
 
+    /// Whether the archive only holds changes made since a previous archive.
+    pub fn is_incremental(&self) -> bool {
+        self.previous_archive_id.is_some()
+    }
+
+    /// Whether the archive only holds a subset of the exporting installation's conversations.
+    pub fn is_partial(&self) -> bool {
+        !self.include_group_ids.is_empty()
+            || !self.exclude_group_ids.is_empty()
+            || !self.consent_states.is_empty()
+    }
+
     pub fn from_metadata_version_unknown(save: BackupMetadataSave) -> Self {
         Self::from_metadata_save(save, u16::MAX)
     }
 }
 
+/// Check that `chain` can be imported in order on top of the `applied` archive.
+/// Without an applied archive, the chain must start with a full archive.
+pub fn check_chain(
+    applied: Option<&BackupMetadata>,
+    chain: &[&BackupMetadata],
+) -> Result<(), ArchiveError> {
+    let mut previous = applied;
+    for metadata in chain {
+        match (&metadata.previous_archive_id, previous) {
+            (None, _) => {}
+            (Some(_), None) => {
+                return Err(ArchiveError::BrokenChain(
+                    "incremental archive has no base archive".to_string(),
+                ));
+            }
+            (Some(previous_id), Some(previous)) => {
+                if previous.archive_id.is_empty() {
+                    return Err(ArchiveError::NotChainable);
+                }
+                if *previous_id != previous.archive_id {
+                    return Err(ArchiveError::BrokenChain(format!(
+                        "archive {} does not follow archive {}",
+                        hex::encode(&metadata.archive_id),
+                        hex::encode(&previous.archive_id)
+                    )));
+                }
+            }
+        }
+        previous = Some(metadata);
+    }
+
+    Ok(())
+}
+
 pub(crate) trait OptionsToSave {
-    fn from_options(options: ArchiveOptions) -> BackupMetadataSave;
+    fn from_options(
+        options: ArchiveOptions,
+        previous: Option<&BackupMetadata>,
+    ) -> BackupMetadataSave;
 }
 impl OptionsToSave for BackupMetadataSave {
-    fn from_options(options: ArchiveOptions) -> BackupMetadataSave {
+    fn from_options(
+        options: ArchiveOptions,
+        previous: Option<&BackupMetadata>,
+    ) -> BackupMetadataSave {
         Self {
             end_ns: options.end_ns,
             start_ns: options.start_ns,
@@ -74,6 +173,48 @@ impl OptionsToSave for BackupMetadataSave {
                 })
                 .collect(),
             exported_at_ns: now_ns(),
+            consent_states: consent_states_to_proto(&options.consent_states),
+            include_group_ids: options.include_group_ids,
+            exclude_group_ids: options.exclude_group_ids,
+            archive_id: xmtp_common::rand_vec::<16>(),
+            previous_archive_id: previous.map(|p| p.archive_id.clone()),
+            since_ns: previous.map(|p| p.exported_at_ns),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive(id: u8, previous: Option<u8>) -> BackupMetadata {
+        BackupMetadata {
+            archive_id: vec![id],
+            previous_archive_id: previous.map(|p| vec![p]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_chain() {
+        let (base, delta, next) = (archive(1, None), archive(2, Some(1)), archive(3, Some(2)));
+
+        assert!(check_chain(None, &[&base, &delta, &next]).is_ok());
+        assert!(check_chain(Some(&delta), &[&next]).is_ok());
+        // A full archive can always start over.
+        assert!(check_chain(Some(&next), &[&base]).is_ok());
+
+        assert!(matches!(
+            check_chain(None, &[&delta]),
+            Err(ArchiveError::BrokenChain(_))
+        ));
+        assert!(matches!(
+            check_chain(None, &[&base, &next]),
+            Err(ArchiveError::BrokenChain(_))
+        ));
+        assert!(matches!(
+            check_chain(Some(&BackupMetadata::default()), &[&delta]),
+            Err(ArchiveError::NotChainable)
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::ArchiveError;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use prost::Message;
+use sha2::{Digest, Sha256};
+use xmtp_proto::xmtp::device_sync::{BackupElement, BackupManifestSave, backup_element::Element};
+
+const MANIFEST_KEY_INFO: &[u8] = b"xmtp archive manifest";
+
+/// Tracks what has gone into (or come out of) an archive so it can be summarized in a manifest.
+/// The manifest is authenticated with an HMAC keyed from the archive key, so it only proves
+/// that whoever wrote it held that key, not which installation did.
+pub(crate) struct ManifestBuilder {
+    hasher: Sha256,
+    group_count: u64,
+    group_message_count: u64,
+    consent_count: u64,
+    mac_key: [u8; 32],
+}
+
+impl ManifestBuilder {
+    pub(crate) fn new(archive_key: &[u8]) -> Self {
+        let mut mac_key = [0; 32];
+        Hkdf::<Sha256>::new(None, archive_key)
+            .expand(MANIFEST_KEY_INFO, &mut mac_key)
+            .expect("32 bytes is a valid HKDF output length");
+
+        Self {
+            hasher: Sha256::new(),
+            group_count: 0,
+            group_message_count: 0,
+            consent_count: 0,
+            mac_key,
+        }
+    }
+
+    /// Account for an element, given its encoded bytes.
+    pub(crate) fn record(&mut self, element: &BackupElement, encoded: &[u8]) {
+        self.hasher.update(encoded);
+        match element.element {
+            Some(Element::Group(_)) => self.group_count += 1,
+            Some(Element::GroupMessage(_)) => self.group_message_count += 1,
+            Some(Element::Consent(_)) => self.consent_count += 1,
+            _ => {}
+        }
+    }
+
+    /// Build a manifest, with its MAC, for everything recorded so far.
+    pub(crate) fn finish(&self) -> BackupManifestSave {
+        let mut manifest = BackupManifestSave {
+            group_count: self.group_count,
+            group_message_count: self.group_message_count,
+            consent_count: self.consent_count,
+            hash: self.hasher.clone().finalize().to_vec(),
+            mac: vec![],
+        };
+        manifest.mac = self.mac(&manifest).finalize().into_bytes().to_vec();
+
+        manifest
+    }
+
+    /// Check a manifest read from an archive against everything recorded so far.
+    pub(crate) fn check(&self, manifest: &BackupManifestSave) -> Result<(), ArchiveError> {
+        let unauthenticated = BackupManifestSave {
+            mac: vec![],
+            ..manifest.clone()
+        };
+        self.mac(&unauthenticated)
+            .verify_slice(&manifest.mac)
+            .map_err(|_| ArchiveError::Manifest("mac is invalid".to_string()))?;
+
+        let expected = self.finish();
+        let counts =
+            |m: &BackupManifestSave| (m.group_count, m.group_message_count, m.consent_count);
+        if counts(manifest) != counts(&expected) {
+            return Err(ArchiveError::Manifest(format!(
+                "manifest lists {:?} (groups, messages, consent records), archive holds {:?}",
+                counts(manifest),
+                counts(&expected)
+            )));
+        }
+        if manifest.hash != expected.hash {
+            return Err(ArchiveError::Manifest(
+                "hash of archive elements does not match".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn mac(&self, unauthenticated: &BackupManifestSave) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.mac_key).expect("HMAC accepts any key length");
+        mac.update(&unauthenticated.encode_to_vec());
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xmtp_proto::xmtp::device_sync::consent_backup::ConsentSave;
+
+    fn consent_element() -> (BackupElement, Vec<u8>) {
+        let element = BackupElement {
+            element: Some(Element::Consent(ConsentSave::default())),
+        };
+        let encoded = element.encode_to_vec();
+        (element, encoded)
+    }
+
+    #[test]
+    fn test_manifest_round_trip() {
+        let (element, encoded) = consent_element();
+
+        let mut writer = ManifestBuilder::new(&[1; 32]);
+        writer.record(&element, &encoded);
+        let manifest = writer.finish();
+        assert_eq!(manifest.consent_count, 1);
+
+        let mut reader = ManifestBuilder::new(&[1; 32]);
+        assert!(reader.check(&manifest).is_err());
+        reader.record(&element, &encoded);
+        assert!(reader.check(&manifest).is_ok());
+
+        // Authenticated with a different key
+        assert!(ManifestBuilder::new(&[2; 32]).check(&manifest).is_err());
+
+        // Tampered counts
+        let tampered = BackupManifestSave {
+            consent_count: 2,
+            ..manifest
+        };
+        assert!(reader.check(&tampered).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{ArchiveError, ENC_KEY_SIZE};
+use argon2::{Algorithm, Argon2, Params, Version};
+use futures_util::{AsyncRead, AsyncReadExt};
+
+pub const SALT_SIZE: usize = 16;
+
+// Upper bounds on the KDF cost read from an archive header,
+// so a crafted archive can't make the importer allocate unbounded memory.
+const MAX_MEMORY_KIB: u32 = 256 * 1024;
+const MAX_ITERATIONS: u32 = 16;
+const MAX_PARALLELISM: u32 = 16;
+
+const RAW_KEY_TAG: u8 = 0;
+const PASSPHRASE_TAG: u8 = 1;
+
+/// Where the archive encryption key came from. Written to the archive header right after the version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySource {
+    /// A caller-supplied key of `ENC_KEY_SIZE` bytes.
+    Raw,
+    /// Derived from a passphrase with argon2id.
+    Passphrase(KdfParams),
+}
+
+/// Argon2id parameters used to derive an archive key from a passphrase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KdfParams {
+    pub salt: [u8; SALT_SIZE],
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    /// Fresh random salt with the argon2 recommended costs.
+    fn default() -> Self {
+        Self {
+            salt: xmtp_common::rand_array::<SALT_SIZE>(),
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl KdfParams {
+    pub fn derive_key(&self, passphrase: &str) -> Result<[u8; ENC_KEY_SIZE], ArchiveError> {
+        if self.memory_kib > MAX_MEMORY_KIB
+            || self.iterations > MAX_ITERATIONS
+            || self.parallelism > MAX_PARALLELISM
+        {
+            return Err(ArchiveError::Kdf(
+                "KDF parameters are out of range".to_string(),
+            ));
+        }
+
+        let params = Params::new(
+            self.memory_kib,
+            self.iterations,
+            self.parallelism,
+            Some(ENC_KEY_SIZE),
+        )
+        .map_err(|err| ArchiveError::Kdf(err.to_string()))?;
+
+        let mut key = [0; ENC_KEY_SIZE];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), &self.salt, &mut key)
+            .map_err(|err| ArchiveError::Kdf(err.to_string()))?;
+
+        Ok(key)
+    }
+
+    /// [`Self::derive_key`] on a blocking thread, so the deliberately slow KDF doesn't stall
+    /// the async executor. Wasm has no blocking threads, so the key is derived in place there.
+    pub async fn derive_key_async(
+        &self,
+        passphrase: &str,
+    ) -> Result<[u8; ENC_KEY_SIZE], ArchiveError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let params = self.clone();
+            let passphrase = passphrase.to_string();
+            tokio::task::spawn_blocking(move || params.derive_key(&passphrase))
+                .await
+                .map_err(|err| ArchiveError::Kdf(err.to_string()))?
+        }
+        #[cfg(target_arch = "wasm32")]
+        self.derive_key(passphrase)
+    }
+}
+
+impl KeySource {
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Raw => vec![RAW_KEY_TAG],
+            Self::Passphrase(params) => {
+                let mut bytes = vec![PASSPHRASE_TAG];
+                bytes.extend_from_slice(&params.salt);
+                bytes.extend_from_slice(&params.memory_kib.to_le_bytes());
+                bytes.extend_from_slice(&params.iterations.to_le_bytes());
+                bytes.extend_from_slice(&params.parallelism.to_le_bytes());
+                bytes
+            }
+        }
+    }
+
+    pub(crate) async fn read(reader: &mut (impl AsyncRead + Unpin)) -> Result<Self, ArchiveError> {
+        let mut tag = [0; 1];
+        reader.read_exact(&mut tag).await?;
+
+        match tag[0] {
+            RAW_KEY_TAG => Ok(Self::Raw),
+            PASSPHRASE_TAG => {
+                let mut salt = [0; SALT_SIZE];
+                reader.read_exact(&mut salt).await?;
+                let mut costs = [[0; 4]; 3];
+                for cost in &mut costs {
+                    reader.read_exact(cost).await?;
+                }
+                let [memory_kib, iterations, parallelism] = costs.map(u32::from_le_bytes);
+
+                Ok(Self::Passphrase(KdfParams {
+                    salt,
+                    memory_kib,
+                    iterations,
+                    parallelism,
+                }))
+            }
+            tag => Err(ArchiveError::Kdf(format!("Unknown key source {tag}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::io::Cursor;
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_key_source_round_trip() {
+        for source in [KeySource::Raw, KeySource::Passphrase(KdfParams::default())] {
+            let mut reader = Cursor::new(source.to_bytes());
+            assert_eq!(KeySource::read(&mut reader).await?, source);
+        }
+    }
+
+    #[test]
+    fn test_derive_key() {
+        let params = KdfParams {
+            memory_kib: 64,
+            iterations: 1,
+            ..Default::default()
+        };
+        let key = params.derive_key("correct horse").unwrap();
+        assert_eq!(key, params.derive_key("correct horse").unwrap());
+        assert_ne!(key, params.derive_key("battery staple").unwrap());
+
+        let other_salt = KdfParams {
+            salt: [1; SALT_SIZE],
+            ..params.clone()
+        };
+        assert_ne!(key, other_salt.derive_key("correct horse").unwrap());
+
+        let greedy = KdfParams {
+            memory_kib: MAX_MEMORY_KIB + 1,
+            ..params
+        };
+        assert!(matches!(
+            greedy.derive_key("correct horse"),
+            Err(ArchiveError::Kdf(_))
+        ));
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_derive_key_async() {
+        let params = KdfParams {
+            memory_kib: 64,
+            iterations: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            params.derive_key_async("correct horse").await?,
+            params.derive_key("correct horse")?
+        );
+    }
+}
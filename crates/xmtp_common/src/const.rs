@@ -1,3 +1,5 @@
+pub const NS_IN_MS: i64 = 1_000_000;
+
 pub const NS_IN_SEC: i64 = 1_000_000_000;
 
 pub const NS_IN_MIN: i64 = NS_IN_SEC * 60;
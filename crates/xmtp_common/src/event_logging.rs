@@ -36,6 +36,9 @@ pub enum Event {
     /// Received new group from welcome.
     #[context(group_id, conversation_type, epoch, epoch_auth, icon = "🤝")]
     ReceivedWelcome,
+    /// Joined a group with an external invite.
+    #[context(group_id, epoch, icon = "🤝")]
+    JoinedWithInvite,
 
     // ===================== MLS Operations =====================
     /// Received staged commit. Merging and clearing any pending commits.
@@ -16,6 +16,16 @@ pub const GRPC_PAYLOAD_LIMIT: usize = 1024 * 1024 * 25;
 /// - connect to nodes and perform a health check
 pub const MULTI_NODE_TIMEOUT_MS: u64 = 30_000;
 
+/// Consecutive failed requests after which the multi-node client moves to another node
+pub const MULTI_NODE_MAX_CONSECUTIVE_ERRORS: u32 = 3;
+
+/// How often the multi-node client re-queries the gateway and re-probes nodes
+pub const MULTI_NODE_REPROBE_INTERVAL_MS: u64 = 5 * 60 * 1_000;
+
+/// The multi-node client re-probes nodes when average request latency grows to
+/// this multiple of the latency it saw after selecting the node
+pub const MULTI_NODE_LATENCY_REGRESSION_FACTOR: u32 = 3;
+
 pub struct DeviceSyncUrls;
 impl DeviceSyncUrls {
     pub const LOCAL_ADDRESS: &'static str = "http://0.0.0.0:5558";
@@ -26,6 +26,20 @@ pub const KEY_PACKAGE_ROTATION_INTERVAL_NS: i64 = NS_IN_30_DAYS; // 30 days
 
 pub const SEND_MESSAGE_UPDATE_INSTALLATIONS_INTERVAL_NS: i64 = 5 * NS_IN_SEC;
 
+/// How long an ephemeral signal (e.g. a typing indicator) stays relevant.
+/// Receivers drop signals that arrive after they expire.
+pub const EPHEMERAL_MESSAGE_TTL_NS: i64 = 30 * NS_IN_SEC;
+
+/// Attachments are uploaded and downloaded in chunks of this size, so an
+/// interrupted transfer resumes from the last completed chunk
+pub const ATTACHMENT_TRANSFER_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Number of attachments of a multi remote attachment transferred in parallel
+pub const ATTACHMENT_TRANSFER_CONCURRENCY: usize = 4;
+
+/// Default size limit of the local cache of downloaded attachments
+pub const ATTACHMENT_CACHE_MAX_BYTES: i64 = 256 * 1024 * 1024;
+
 pub const MAX_GROUP_SIZE: usize = 250;
 
 pub const MAX_INSTALLATIONS_PER_INBOX: usize = 10;
@@ -63,6 +77,38 @@ pub const MIN_RECOVERY_REQUEST_VERSION: &str = "1.6.0";
 /// of relying on this default.
 pub const PROPOSALS_MIN_PROTOCOL_VERSION: &str = "1.11.0-dev";
 
+/// Floor written into `MIN_SUPPORTED_PROTOCOL_VERSION` before a group first
+/// uses moderators. Older clients can't parse moderator policies, and drop
+/// the moderator list whenever they rebuild the group's mutable metadata.
+///
+/// Same invariant as [`PROPOSALS_MIN_PROTOCOL_VERSION`]: must be
+/// `<= CARGO_PKG_VERSION`.
+pub const MODERATORS_MIN_PROTOCOL_VERSION: &str = "1.12.0-dev";
+
+/// Floor written into `MIN_SUPPORTED_PROTOCOL_VERSION` before a migrated
+/// group's membership policies first use an allowlist or identifier gate.
+/// Older clients read those policies as deny and would reject every add.
+///
+/// Same invariant as [`PROPOSALS_MIN_PROTOCOL_VERSION`]: must be
+/// `<= CARGO_PKG_VERSION`.
+pub const MEMBERSHIP_GATES_MIN_PROTOCOL_VERSION: &str = "1.12.0-dev";
+
+/// Floor written into `MIN_SUPPORTED_PROTOCOL_VERSION` before a group first
+/// issues an external invite. Joiners publish their external commit as a
+/// public message, which older clients reject, forking the group.
+///
+/// Same invariant as [`PROPOSALS_MIN_PROTOCOL_VERSION`]: must be
+/// `<= CARGO_PKG_VERSION`.
+pub const EXTERNAL_INVITES_MIN_PROTOCOL_VERSION: &str = "1.12.0-dev";
+
+/// Floor written into `MIN_SUPPORTED_PROTOCOL_VERSION` before a group first
+/// carries a view-once or expire-after message. Older clients drop those
+/// envelope fields and would keep the message forever.
+///
+/// Same invariant as [`PROPOSALS_MIN_PROTOCOL_VERSION`]: must be
+/// `<= CARGO_PKG_VERSION`.
+pub const MESSAGE_EXPIRY_MIN_PROTOCOL_VERSION: &str = "1.12.0-dev";
+
 // Welcome pointers are mostly the hpke public key and less than 100 bytes for the welcome pointer
 // so as long as we have 2 installations that need a single welcome it will result in less data being
 // ingested by the nodes and stored. There is a slight penalty for egress data, but the amount needed
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use prost::Message;
+
+use super::{CodecError, ContentCodec};
+use xmtp_proto::xmtp::mls::message_contents::content_types::EditMessage;
+use xmtp_proto::xmtp::mls::message_contents::{ContentTypeId, EncodedContent};
+
+pub struct EditMessageCodec;
+
+impl EditMessageCodec {
+    const AUTHORITY_ID: &'static str = "xmtp.org";
+    pub const TYPE_ID: &'static str = "editMessage";
+    pub const MAJOR_VERSION: u32 = 1;
+    pub const MINOR_VERSION: u32 = 0;
+}
+
+impl ContentCodec<EditMessage> for EditMessageCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: EditMessageCodec::AUTHORITY_ID.to_string(),
+            type_id: EditMessageCodec::TYPE_ID.to_string(),
+            version_major: EditMessageCodec::MAJOR_VERSION,
+            version_minor: EditMessageCodec::MINOR_VERSION,
+        }
+    }
+
+    fn encode(data: EditMessage) -> Result<EncodedContent, CodecError> {
+        if data.edited_content.is_none() {
+            return Err(CodecError::Encode(
+                "EditMessage is missing edited content".to_string(),
+            ));
+        }
+
+        let mut buf = Vec::new();
+        data.encode(&mut buf)
+            .map_err(|e| CodecError::Encode(e.to_string()))?;
+
+        Ok(EncodedContent {
+            r#type: Some(EditMessageCodec::content_type()),
+            parameters: HashMap::new(),
+            fallback: None,
+            compression: None,
+            content: buf,
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<EditMessage, CodecError> {
+        let decoded = EditMessage::decode(content.content.as_slice())
+            .map_err(|e| CodecError::Decode(e.to_string()))?;
+
+        if decoded.edited_content.is_none() {
+            return Err(CodecError::Decode(
+                "EditMessage is missing edited content".to_string(),
+            ));
+        }
+
+        Ok(decoded)
+    }
+
+    fn should_push() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::text::TextCodec;
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_encode_decode() {
+        let data = EditMessage {
+            message_id: "test_message_id_123".to_string(),
+            edited_content: Some(TextCodec::encode("fixed typo".to_string()).unwrap()),
+        };
+
+        let encoded = EditMessageCodec::encode(data.clone()).unwrap();
+        assert_eq!(encoded.clone().r#type.unwrap().type_id, "editMessage");
+
+        let decoded = EditMessageCodec::decode(encoded).unwrap();
+        assert_eq!(decoded.message_id, data.message_id);
+        let text = TextCodec::decode(decoded.edited_content.unwrap()).unwrap();
+        assert_eq!(text, "fixed typo");
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_missing_edited_content() {
+        let data = EditMessage {
+            message_id: "test_message_id_123".to_string(),
+            edited_content: None,
+        };
+        assert!(EditMessageCodec::encode(data).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 pub mod actions;
 pub mod attachment;
 pub mod delete_message;
+pub mod edit_message;
 pub mod encryption;
 pub mod group_updated;
 pub mod intent;
@@ -8,6 +9,7 @@ pub mod leave_request;
 pub mod markdown;
 pub mod membership_change;
 pub mod multi_remote_attachment;
+pub mod poll;
 pub mod reaction;
 pub mod read_receipt;
 pub mod remote_attachment;
@@ -70,6 +72,9 @@ pub enum ContentType {
     DeviceSyncMessage,
     LeaveRequest,
     DeleteMessage,
+    EditMessage,
+    Poll,
+    PollVote,
 }
 
 impl TryFrom<&str> for ContentType {
@@ -99,6 +104,9 @@ impl TryFrom<&str> for ContentType {
             actions::ActionsCodec::TYPE_ID => Ok(Self::Actions),
             intent::IntentCodec::TYPE_ID => Ok(Self::Intent),
             delete_message::DeleteMessageCodec::TYPE_ID => Ok(Self::DeleteMessage),
+            edit_message::EditMessageCodec::TYPE_ID => Ok(Self::EditMessage),
+            poll::PollCodec::TYPE_ID => Ok(Self::Poll),
+            poll::PollVoteCodec::TYPE_ID => Ok(Self::PollVote),
             _ => Err(format!("Unknown content type ID: {type_id}")),
         }
     }
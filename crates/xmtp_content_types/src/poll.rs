@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use prost::Message;
+
+use super::{CodecError, ContentCodec};
+use xmtp_proto::xmtp::mls::message_contents::content_types::{Poll, PollVote};
+use xmtp_proto::xmtp::mls::message_contents::{ContentTypeId, EncodedContent};
+
+/// Maximum number of options a single poll may offer
+pub const MAX_POLL_OPTIONS: usize = 20;
+
+pub struct PollCodec;
+
+impl PollCodec {
+    const AUTHORITY_ID: &'static str = "xmtp.org";
+    pub const TYPE_ID: &'static str = "poll";
+    pub const MAJOR_VERSION: u32 = 1;
+    pub const MINOR_VERSION: u32 = 0;
+
+    fn fallback(poll: &Poll) -> String {
+        let options = poll
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| format!("{}. {option}", i + 1))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("Poll: {}\n{options}", poll.question)
+    }
+}
+
+fn validate_poll(poll: &Poll) -> Result<(), String> {
+    if poll.question.trim().is_empty() {
+        return Err("Poll question must not be empty".to_string());
+    }
+    if poll.options.len() < 2 || poll.options.len() > MAX_POLL_OPTIONS {
+        return Err(format!(
+            "Poll must have between 2 and {MAX_POLL_OPTIONS} options, found {}",
+            poll.options.len()
+        ));
+    }
+    if poll.options.iter().any(|option| option.trim().is_empty()) {
+        return Err("Poll options must not be empty".to_string());
+    }
+    Ok(())
+}
+
+impl ContentCodec<Poll> for PollCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: PollCodec::AUTHORITY_ID.to_string(),
+            type_id: PollCodec::TYPE_ID.to_string(),
+            version_major: PollCodec::MAJOR_VERSION,
+            version_minor: PollCodec::MINOR_VERSION,
+        }
+    }
+
+    fn encode(data: Poll) -> Result<EncodedContent, CodecError> {
+        validate_poll(&data).map_err(CodecError::Encode)?;
+
+        let mut buf = Vec::new();
+        data.encode(&mut buf)
+            .map_err(|e| CodecError::Encode(e.to_string()))?;
+
+        Ok(EncodedContent {
+            r#type: Some(PollCodec::content_type()),
+            parameters: HashMap::new(),
+            fallback: Some(PollCodec::fallback(&data)),
+            compression: None,
+            content: buf,
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<Poll, CodecError> {
+        let decoded = Poll::decode(content.content.as_slice())
+            .map_err(|e| CodecError::Decode(e.to_string()))?;
+        validate_poll(&decoded).map_err(CodecError::Decode)?;
+
+        Ok(decoded)
+    }
+
+    fn should_push() -> bool {
+        true
+    }
+}
+
+pub struct PollVoteCodec;
+
+impl PollVoteCodec {
+    const AUTHORITY_ID: &'static str = "xmtp.org";
+    pub const TYPE_ID: &'static str = "pollVote";
+    pub const MAJOR_VERSION: u32 = 1;
+    pub const MINOR_VERSION: u32 = 0;
+}
+
+impl ContentCodec<PollVote> for PollVoteCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: PollVoteCodec::AUTHORITY_ID.to_string(),
+            type_id: PollVoteCodec::TYPE_ID.to_string(),
+            version_major: PollVoteCodec::MAJOR_VERSION,
+            version_minor: PollVoteCodec::MINOR_VERSION,
+        }
+    }
+
+    fn encode(data: PollVote) -> Result<EncodedContent, CodecError> {
+        if data.poll_message_id.is_empty() {
+            return Err(CodecError::Encode(
+                "PollVote is missing the poll message id".to_string(),
+            ));
+        }
+
+        let mut buf = Vec::new();
+        data.encode(&mut buf)
+            .map_err(|e| CodecError::Encode(e.to_string()))?;
+
+        Ok(EncodedContent {
+            r#type: Some(PollVoteCodec::content_type()),
+            parameters: HashMap::new(),
+            fallback: None,
+            compression: None,
+            content: buf,
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<PollVote, CodecError> {
+        let decoded = PollVote::decode(content.content.as_slice())
+            .map_err(|e| CodecError::Decode(e.to_string()))?;
+
+        if decoded.poll_message_id.is_empty() {
+            return Err(CodecError::Decode(
+                "PollVote is missing the poll message id".to_string(),
+            ));
+        }
+
+        Ok(decoded)
+    }
+
+    fn should_push() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn poll() -> Poll {
+        Poll {
+            question: "Lunch?".to_string(),
+            options: vec!["Pizza".to_string(), "Tacos".to_string()],
+            allows_multiple_choices: false,
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_encode_decode_poll() {
+        let encoded = PollCodec::encode(poll()).unwrap();
+        assert_eq!(encoded.clone().r#type.unwrap().type_id, "poll");
+        assert_eq!(
+            encoded.fallback.as_deref(),
+            Some("Poll: Lunch?\n1. Pizza\n2. Tacos")
+        );
+
+        let decoded = PollCodec::decode(encoded).unwrap();
+        assert_eq!(decoded, poll());
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_invalid_poll() {
+        let mut no_question = poll();
+        no_question.question = " ".to_string();
+        assert!(PollCodec::encode(no_question).is_err());
+
+        let mut one_option = poll();
+        one_option.options.truncate(1);
+        assert!(PollCodec::encode(one_option).is_err());
+
+        let mut blank_option = poll();
+        blank_option.options.push(String::new());
+        assert!(PollCodec::encode(blank_option).is_err());
+
+        let mut too_many = poll();
+        too_many.options = (0..=MAX_POLL_OPTIONS).map(|i| i.to_string()).collect();
+        assert!(PollCodec::encode(too_many).is_err());
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_encode_decode_poll_vote() {
+        let data = PollVote {
+            poll_message_id: "test_message_id_123".to_string(),
+            option_indexes: vec![1],
+        };
+
+        let encoded = PollVoteCodec::encode(data.clone()).unwrap();
+        assert_eq!(encoded.clone().r#type.unwrap().type_id, "pollVote");
+
+        let decoded = PollVoteCodec::decode(encoded).unwrap();
+        assert_eq!(decoded, data);
+
+        let missing_poll = PollVote {
+            poll_message_id: String::new(),
+            option_indexes: vec![0],
+        };
+        assert!(PollVoteCodec::encode(missing_poll).is_err());
+    }
+}
@@ -2,9 +2,11 @@ use crate::{
     ContentCodec,
     attachment::{Attachment, AttachmentCodec},
     delete_message::DeleteMessageCodec,
+    edit_message::EditMessageCodec,
     group_updated::GroupUpdatedCodec,
     membership_change::GroupMembershipChangeCodec,
     multi_remote_attachment::MultiRemoteAttachmentCodec,
+    poll::{PollCodec, PollVoteCodec},
     reaction::ReactionCodec,
     read_receipt::{ReadReceipt, ReadReceiptCodec},
     remote_attachment::{RemoteAttachment, RemoteAttachmentCodec},
@@ -15,7 +17,8 @@ use crate::{
 use xmtp_proto::xmtp::mls::message_contents::{
     ContentTypeId, EncodedContent, GroupMembershipChanges, GroupUpdated,
     content_types::{
-        DeleteMessage, MultiRemoteAttachment, ReactionAction, ReactionSchema, ReactionV2,
+        DeleteMessage, EditMessage, MultiRemoteAttachment, Poll, PollVote, ReactionAction,
+        ReactionSchema, ReactionV2,
     },
 };
 
@@ -118,6 +121,35 @@ impl TestContentGenerator {
         DeleteMessageCodec::encode(delete_message).expect("Failed to encode delete message")
     }
 
+    pub fn edit_message_content(message_id: &str, text: &str) -> EncodedContent {
+        let edit_message = EditMessage {
+            message_id: message_id.to_string(),
+            edited_content: Some(Self::text_content(text)),
+        };
+        EditMessageCodec::encode(edit_message).expect("Failed to encode edit message")
+    }
+
+    pub fn poll_content(
+        question: &str,
+        options: &[&str],
+        allows_multiple_choices: bool,
+    ) -> EncodedContent {
+        let poll = Poll {
+            question: question.to_string(),
+            options: options.iter().map(|option| option.to_string()).collect(),
+            allows_multiple_choices,
+        };
+        PollCodec::encode(poll).expect("Failed to encode poll")
+    }
+
+    pub fn poll_vote_content(poll_message_id: &str, option_indexes: &[u32]) -> EncodedContent {
+        let poll_vote = PollVote {
+            poll_message_id: poll_message_id.to_string(),
+            option_indexes: option_indexes.to_vec(),
+        };
+        PollVoteCodec::encode(poll_vote).expect("Failed to encode poll vote")
+    }
+
     pub fn transaction_reference_content(
         reference: &str,
         network_id: i32,
@@ -5,7 +5,24 @@ use rand_chacha::ChaCha20Rng;
 
 use crate::Secret;
 
+/// Process-wide seed for [`rng`], set by [`seed`]
+#[cfg(feature = "test-utils")]
+static SEEDED: std::sync::Mutex<Option<ChaCha20Rng>> = std::sync::Mutex::new(None);
+
+/// Derive every later [`rng`] of this process from `seed`, so keys generated by a
+/// test are the same on every run. Meant for record/replay tests, which run one
+/// test per process.
+#[cfg(feature = "test-utils")]
+pub fn seed(seed: u64) {
+    let mut seeded = SEEDED.lock().unwrap_or_else(|e| e.into_inner());
+    *seeded = Some(ChaCha20Rng::seed_from_u64(seed));
+}
+
 pub fn rng() -> impl CryptoRng + RngExt {
+    #[cfg(feature = "test-utils")]
+    if let Some(seeded) = SEEDED.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        return ChaCha20Rng::from_rng(seeded);
+    }
     ChaCha20Rng::try_from_rng(&mut rand::rngs::SysRng).unwrap()
 }
 
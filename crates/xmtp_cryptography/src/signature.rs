@@ -127,9 +127,28 @@ pub fn sanitize_evm_addresses(
         .collect())
 }
 
+/// Check if a string is a valid solana address (base58 encoded 32 byte ed25519 public key).
+pub fn is_valid_solana_address<S: AsRef<str>>(address: S) -> bool {
+    let mut key = [0u8; 32];
+    matches!(bs58::decode(address.as_ref()).onto(&mut key), Ok(32))
+}
+
+/// Base58 is case-sensitive, so unlike evm addresses solana addresses are only trimmed.
+pub fn sanitize_solana_address(
+    account_address: impl AsRef<str>,
+) -> Result<String, IdentifierValidationError> {
+    let address = account_address.as_ref().trim();
+    if !is_valid_solana_address(address) {
+        return Err(IdentifierValidationError::InvalidAddresses(vec![
+            account_address.as_ref().to_string(),
+        ]));
+    }
+    Ok(address.to_string())
+}
+
 #[cfg(test)]
 pub mod tests {
-    use super::is_valid_ethereum_address;
+    use super::{is_valid_ethereum_address, is_valid_solana_address, sanitize_solana_address};
 
     use alloy::signers::SignerSync;
     use alloy::signers::local::PrivateKeySigner;
@@ -148,4 +167,24 @@ pub mod tests {
         ));
         assert!(!is_valid_ethereum_address("123"));
     }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_solana_address() {
+        let address = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+        assert!(is_valid_solana_address(address));
+        assert!(!is_valid_solana_address("123"));
+        // `0`, `O`, `I` and `l` are not in the base58 alphabet
+        assert!(!is_valid_solana_address(
+            "0WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+        ));
+        assert!(!is_valid_solana_address(
+            "0x7e57Aed10441c8879ce08E45805EC01Ee9689c9f"
+        ));
+        assert_eq!(
+            sanitize_solana_address(format!(" {address}\n")).unwrap(),
+            address
+        );
+        assert!(sanitize_solana_address(address.to_lowercase()).is_err());
+    }
 }
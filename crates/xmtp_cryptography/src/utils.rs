@@ -1,5 +1,19 @@
 use alloy::signers::local::PrivateKeySigner;
+use rand::Rng;
 
 pub fn generate_local_wallet() -> PrivateKeySigner {
     PrivateKeySigner::random()
 }
+
+/// Generate the same wallet for the same `seed`, with [`crate::rand::seeded_rng`]
+pub fn generate_seeded_wallet(seed: u64) -> PrivateKeySigner {
+    let mut rng = crate::rand::seeded_rng(seed);
+    loop {
+        let mut key = [0u8; 32];
+        rng.fill_bytes(&mut key);
+        // Almost every 32 bytes are a valid secp256k1 scalar
+        if let Ok(wallet) = PrivateKeySigner::from_slice(&key) {
+            return wallet;
+        }
+    }
+}
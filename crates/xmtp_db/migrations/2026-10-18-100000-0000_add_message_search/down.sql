@@ -0,0 +1,13 @@
+ALTER TABLE user_preferences
+DROP COLUMN message_search_backfilled;
+
+DROP TRIGGER IF EXISTS message_deletions_search_after_insert;
+DROP TRIGGER IF EXISTS group_messages_search_after_delete;
+DROP TRIGGER IF EXISTS message_search_content_after_update;
+DROP TRIGGER IF EXISTS message_search_content_after_delete;
+DROP TRIGGER IF EXISTS message_search_content_after_insert;
+
+DROP TABLE IF EXISTS message_search;
+
+DROP INDEX IF EXISTS idx_message_search_content_group_id;
+DROP TABLE IF EXISTS message_search_content;
@@ -0,0 +1,62 @@
+-- Full-text search index over decrypted message text.
+--
+-- Message bodies are protobuf-encoded `EncodedContent`, so the searchable text
+-- cannot be extracted in SQL. The application writes the extracted text into
+-- `message_search_content` when a message is stored, and the FTS5 table below
+-- indexes it as an external-content table. Triggers keep the FTS index in sync
+-- with the content table, and drop entries when the underlying message is
+-- deleted (including disappearing-message expiry) or soft-deleted through a
+-- DeleteMessage.
+CREATE TABLE message_search_content (
+  id INTEGER PRIMARY KEY NOT NULL,
+  -- The ID of the indexed message in group_messages
+  message_id BLOB NOT NULL UNIQUE,
+  -- The group the indexed message belongs to
+  group_id BLOB NOT NULL,
+  -- Plain text extracted from the message content
+  body TEXT NOT NULL
+);
+
+CREATE INDEX idx_message_search_content_group_id ON message_search_content(group_id);
+
+CREATE VIRTUAL TABLE message_search USING fts5(
+  body,
+  content = 'message_search_content',
+  content_rowid = 'id',
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER message_search_content_after_insert
+AFTER INSERT ON message_search_content
+BEGIN
+  INSERT INTO message_search(rowid, body) VALUES (new.id, new.body);
+END;
+
+CREATE TRIGGER message_search_content_after_delete
+AFTER DELETE ON message_search_content
+BEGIN
+  INSERT INTO message_search(message_search, rowid, body) VALUES ('delete', old.id, old.body);
+END;
+
+CREATE TRIGGER message_search_content_after_update
+AFTER UPDATE ON message_search_content
+BEGIN
+  INSERT INTO message_search(message_search, rowid, body) VALUES ('delete', old.id, old.body);
+  INSERT INTO message_search(rowid, body) VALUES (new.id, new.body);
+END;
+
+CREATE TRIGGER group_messages_search_after_delete
+AFTER DELETE ON group_messages
+BEGIN
+  DELETE FROM message_search_content WHERE message_id = old.id;
+END;
+
+CREATE TRIGGER message_deletions_search_after_insert
+AFTER INSERT ON message_deletions
+BEGIN
+  DELETE FROM message_search_content WHERE message_id = new.deleted_message_id;
+END;
+
+-- Existing messages are indexed by a one-time backfill on client startup.
+ALTER TABLE user_preferences
+ADD COLUMN message_search_backfilled BOOLEAN NOT NULL DEFAULT 0;
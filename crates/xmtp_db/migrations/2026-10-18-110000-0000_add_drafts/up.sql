@@ -0,0 +1,67 @@
+-- Local-only message drafts, one per conversation. Drafts are never sent to
+-- the network; `content` holds a serialized `EncodedContent`.
+CREATE TABLE drafts (
+  group_id BLOB PRIMARY KEY NOT NULL,
+  content BLOB NOT NULL,
+  -- The message this draft replies to, if any
+  reply_to_message_id BLOB,
+  updated_at_ns BIGINT NOT NULL
+);
+
+-- Recreate conversation_list so it can report whether a conversation has a draft
+DROP VIEW IF EXISTS conversation_list;
+
+CREATE VIEW conversation_list AS
+WITH ranked_messages AS (
+    SELECT
+        gm.group_id,
+        gm.id AS message_id,
+        gm.decrypted_message_bytes,
+        gm.sent_at_ns,
+        gm.kind AS message_kind,
+        gm.sender_installation_id,
+        gm.sender_inbox_id,
+        gm.delivery_status,
+        gm.content_type,
+        gm.version_major,
+        gm.version_minor,
+        gm.authority_id,
+        gm.sequence_id,
+        gm.originator_id,
+        ROW_NUMBER() OVER (PARTITION BY gm.group_id ORDER BY gm.sent_at_ns DESC) AS row_num
+    FROM
+        group_messages gm
+    WHERE
+        gm.kind = 1
+        AND gm.content_type IN (0, 1, 4, 6, 7, 8, 9, 10) -- 10 is added here
+)
+SELECT
+    g.id AS id,
+    g.created_at_ns,
+    g.membership_state,
+    g.installations_last_checked,
+    g.added_by_inbox_id,
+    g.sequence_id as welcome_sequence_id,
+    g.dm_id,
+    g.rotated_at_ns,
+    g.conversation_type,
+    g.is_commit_log_forked,
+    rm.message_id,
+    rm.decrypted_message_bytes,
+    rm.sent_at_ns,
+    rm.message_kind,
+    rm.sender_installation_id,
+    rm.sender_inbox_id,
+    rm.delivery_status,
+    rm.content_type,
+    rm.version_major,
+    rm.version_minor,
+    rm.authority_id,
+    rm.sequence_id,
+    rm.originator_id,
+    EXISTS (SELECT 1 FROM drafts d WHERE d.group_id = g.id) AS has_draft
+FROM
+    groups g
+    LEFT JOIN ranked_messages rm
+    ON g.id = rm.group_id AND rm.row_num = 1
+ORDER BY COALESCE(rm.sent_at_ns, g.created_at_ns) DESC;
@@ -0,0 +1,5 @@
+ALTER TABLE group_messages
+DROP COLUMN view_once;
+
+ALTER TABLE group_messages
+DROP COLUMN expire_after_ns;
@@ -0,0 +1,14 @@
+-- Per-message expiry and view-once, set by the sender on individual messages.
+--
+-- `expire_after_ns` is how long after sending the message disappears; it is
+-- folded into `expire_at_ns` once the message is published, alongside the
+-- group-wide disappearing settings, so the existing expiry index and the
+-- disappearing messages worker pick it up unchanged.
+--
+-- `view_once` messages are deleted on the receiver once the app reports that
+-- the message was viewed.
+ALTER TABLE group_messages
+ADD COLUMN expire_after_ns BIGINT;
+
+ALTER TABLE group_messages
+ADD COLUMN view_once BOOLEAN NOT NULL DEFAULT 0;
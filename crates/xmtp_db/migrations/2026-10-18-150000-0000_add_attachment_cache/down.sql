@@ -0,0 +1,3 @@
+DROP TRIGGER IF EXISTS attachment_cache_message_deleted;
+
+DROP TABLE IF EXISTS attachment_cache;
@@ -0,0 +1,24 @@
+-- Encrypted payloads of downloaded remote attachments, keyed by the message
+-- they were sent in. Evicted least recently accessed first once the cache
+-- grows past its size limit.
+CREATE TABLE attachment_cache (
+  message_id BLOB NOT NULL,
+  -- Position of the attachment within a multi remote attachment, 0 otherwise
+  attachment_index INTEGER NOT NULL,
+  -- Hex-encoded SHA-256 digest of the payload
+  content_digest TEXT NOT NULL,
+  payload BLOB NOT NULL,
+  size_bytes BIGINT NOT NULL,
+  last_accessed_ns BIGINT NOT NULL,
+  PRIMARY KEY (message_id, attachment_index)
+);
+
+CREATE INDEX idx_attachment_cache_last_accessed_ns ON attachment_cache (last_accessed_ns);
+
+-- Cached attachments go away with their message
+CREATE TRIGGER attachment_cache_message_deleted AFTER DELETE ON group_messages FOR EACH ROW BEGIN
+DELETE FROM attachment_cache
+WHERE
+    message_id = OLD.id;
+
+END;
@@ -0,0 +1,7 @@
+DROP TRIGGER IF EXISTS consent_record_deleted;
+
+DROP TRIGGER IF EXISTS consent_record_updated;
+
+DROP TRIGGER IF EXISTS consent_record_inserted;
+
+DROP TABLE IF EXISTS consent_record_changes;
@@ -0,0 +1,36 @@
+-- Tracks when each consent record last changed locally, so incremental archives
+-- pick up records synced after the previous archive even when their
+-- consented_at_ns is older.
+CREATE TABLE consent_record_changes (
+    sequence_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity_type INTEGER NOT NULL,
+    entity TEXT NOT NULL,
+    changed_at_ns BIGINT NOT NULL DEFAULT (
+        CAST(strftime('%s','now') AS INTEGER) * 1000000000 +
+        CAST(strftime('%f','now') * 1000000 AS INTEGER) * 1000
+    ),
+    UNIQUE (entity_type, entity)
+);
+
+-- Existing records are treated as having changed when they were consented to
+INSERT INTO consent_record_changes (entity_type, entity, changed_at_ns)
+SELECT entity_type, entity, consented_at_ns FROM consent_records ORDER BY consented_at_ns;
+
+-- Replacing the row gives the record a new, higher sequence_id
+CREATE TRIGGER consent_record_inserted AFTER INSERT ON consent_records FOR EACH ROW BEGIN
+INSERT OR REPLACE INTO consent_record_changes (entity_type, entity)
+VALUES (NEW.entity_type, NEW.entity);
+
+END;
+
+CREATE TRIGGER consent_record_updated AFTER UPDATE ON consent_records FOR EACH ROW BEGIN
+INSERT OR REPLACE INTO consent_record_changes (entity_type, entity)
+VALUES (NEW.entity_type, NEW.entity);
+
+END;
+
+CREATE TRIGGER consent_record_deleted AFTER DELETE ON consent_records FOR EACH ROW BEGIN
+DELETE FROM consent_record_changes
+WHERE entity_type = OLD.entity_type AND entity = OLD.entity;
+
+END;
@@ -0,0 +1,2 @@
+ALTER TABLE message_deletions
+DROP COLUMN is_moderator_deletion;
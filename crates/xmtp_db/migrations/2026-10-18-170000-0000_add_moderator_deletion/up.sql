@@ -0,0 +1,6 @@
+-- Whether the deleter removed someone else's message as a moderator, which is
+-- only allowed while the group's remove member policy is set to moderators or
+-- above. Kept apart from `is_super_admin_deletion` so that the two grants can
+-- be told apart.
+ALTER TABLE message_deletions
+ADD COLUMN is_moderator_deletion BOOLEAN NOT NULL DEFAULT 0;
@@ -0,0 +1,207 @@
+use super::ConnectionExt;
+use crate::DbConnection;
+use crate::schema::attachment_cache::{self, dsl};
+use diesel::dsl::sql;
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use serde::{Deserialize, Serialize};
+use xmtp_common::time::now_ns;
+
+#[derive(
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    Insertable,
+    Identifiable,
+    Queryable,
+    Selectable,
+    Eq,
+    PartialEq,
+    QueryableByName,
+)]
+#[diesel(table_name = attachment_cache)]
+#[diesel(primary_key(message_id, attachment_index))]
+/// The downloaded payload of a remote attachment. The payload is still
+/// encrypted with the attachment secret from the message it was sent in.
+pub struct StoredCachedAttachment {
+    pub message_id: Vec<u8>,
+    /// Position of the attachment within a multi remote attachment, 0 otherwise.
+    pub attachment_index: i32,
+    /// Hex-encoded SHA-256 digest of the payload.
+    pub content_digest: String,
+    pub payload: Vec<u8>,
+    pub size_bytes: i64,
+    /// Time in nanoseconds the attachment was last stored or read.
+    pub last_accessed_ns: i64,
+}
+
+impl StoredCachedAttachment {
+    pub fn new(
+        message_id: Vec<u8>,
+        attachment_index: i32,
+        content_digest: String,
+        payload: Vec<u8>,
+    ) -> Self {
+        Self {
+            message_id,
+            attachment_index,
+            content_digest,
+            size_bytes: payload.len() as i64,
+            payload,
+            last_accessed_ns: now_ns(),
+        }
+    }
+}
+
+pub trait QueryAttachmentCache {
+    /// Get a cached attachment, marking it as the most recently accessed.
+    fn get_cached_attachment(
+        &self,
+        message_id: &[u8],
+        attachment_index: i32,
+    ) -> Result<Option<StoredCachedAttachment>, crate::ConnectionError>;
+
+    /// Store an attachment, replacing any attachment cached at the same position.
+    fn insert_cached_attachment(
+        &self,
+        attachment: &StoredCachedAttachment,
+    ) -> Result<(), crate::ConnectionError>;
+
+    /// Delete the least recently accessed attachments until the cache holds
+    /// at most `max_bytes`. Returns the number of attachments deleted.
+    fn evict_cached_attachments(&self, max_bytes: i64) -> Result<usize, crate::ConnectionError>;
+
+    /// Total size in bytes of all cached attachments.
+    fn cached_attachments_size(&self) -> Result<i64, crate::ConnectionError>;
+}
+
+impl<T> QueryAttachmentCache for &T
+where
+    T: QueryAttachmentCache,
+{
+    fn get_cached_attachment(
+        &self,
+        message_id: &[u8],
+        attachment_index: i32,
+    ) -> Result<Option<StoredCachedAttachment>, crate::ConnectionError> {
+        (**self).get_cached_attachment(message_id, attachment_index)
+    }
+
+    fn insert_cached_attachment(
+        &self,
+        attachment: &StoredCachedAttachment,
+    ) -> Result<(), crate::ConnectionError> {
+        (**self).insert_cached_attachment(attachment)
+    }
+
+    fn evict_cached_attachments(&self, max_bytes: i64) -> Result<usize, crate::ConnectionError> {
+        (**self).evict_cached_attachments(max_bytes)
+    }
+
+    fn cached_attachments_size(&self) -> Result<i64, crate::ConnectionError> {
+        (**self).cached_attachments_size()
+    }
+}
+
+impl<C: ConnectionExt> QueryAttachmentCache for DbConnection<C> {
+    fn get_cached_attachment(
+        &self,
+        message_id: &[u8],
+        attachment_index: i32,
+    ) -> Result<Option<StoredCachedAttachment>, crate::ConnectionError> {
+        self.raw_query(|conn| {
+            diesel::update(
+                dsl::attachment_cache
+                    .filter(dsl::message_id.eq(message_id))
+                    .filter(dsl::attachment_index.eq(attachment_index)),
+            )
+            .set(dsl::last_accessed_ns.eq(now_ns()))
+            .returning(StoredCachedAttachment::as_returning())
+            .get_result(conn)
+            .optional()
+        })
+    }
+
+    fn insert_cached_attachment(
+        &self,
+        attachment: &StoredCachedAttachment,
+    ) -> Result<(), crate::ConnectionError> {
+        self.raw_query(|conn| {
+            diesel::replace_into(dsl::attachment_cache)
+                .values(attachment)
+                .execute(conn)
+        })?;
+        Ok(())
+    }
+
+    fn evict_cached_attachments(&self, max_bytes: i64) -> Result<usize, crate::ConnectionError> {
+        self.raw_query(|conn| {
+            let entries: Vec<(Vec<u8>, i32, i64)> = dsl::attachment_cache
+                .select((dsl::message_id, dsl::attachment_index, dsl::size_bytes))
+                .order(dsl::last_accessed_ns.desc())
+                .load(conn)?;
+
+            // Keep the most recently accessed attachments that fit in the budget
+            let mut retained_bytes = 0i64;
+            let mut evicted = 0;
+            for (message_id, attachment_index, size_bytes) in entries {
+                retained_bytes = retained_bytes.saturating_add(size_bytes);
+                if retained_bytes <= max_bytes {
+                    continue;
+                }
+                evicted += diesel::delete(
+                    dsl::attachment_cache
+                        .filter(dsl::message_id.eq(message_id))
+                        .filter(dsl::attachment_index.eq(attachment_index)),
+                )
+                .execute(conn)?;
+            }
+            Ok(evicted)
+        })
+    }
+
+    fn cached_attachments_size(&self) -> Result<i64, crate::ConnectionError> {
+        self.raw_query(|conn| {
+            dsl::attachment_cache
+                .select(sql::<BigInt>("COALESCE(SUM(size_bytes), 0)"))
+                .first(conn)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::with_connection;
+
+    fn cached(message_id: &[u8], size: usize) -> StoredCachedAttachment {
+        StoredCachedAttachment::new(message_id.to_vec(), 0, "digest".into(), vec![0; size])
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_evicts_least_recently_accessed() {
+        with_connection(|conn| {
+            for (i, id) in [b"a", b"b", b"c"].into_iter().enumerate() {
+                let mut attachment = cached(id, 10);
+                attachment.last_accessed_ns = i as i64;
+                conn.insert_cached_attachment(&attachment)?;
+            }
+            assert_eq!(conn.cached_attachments_size()?, 30);
+
+            // Reading "a" makes it the most recently accessed
+            assert_eq!(conn.get_cached_attachment(b"a", 0)??.size_bytes, 10);
+            assert!(conn.get_cached_attachment(b"a", 1)?.is_none());
+
+            assert_eq!(conn.evict_cached_attachments(20)?, 1);
+            assert!(conn.get_cached_attachment(b"b", 0)?.is_none());
+            assert!(conn.get_cached_attachment(b"a", 0)?.is_some());
+            assert!(conn.get_cached_attachment(b"c", 0)?.is_some());
+            assert_eq!(conn.cached_attachments_size()?, 20);
+
+            assert_eq!(conn.evict_cached_attachments(20)?, 0);
+            assert_eq!(conn.evict_cached_attachments(0)?, 2);
+            assert_eq!(conn.cached_attachments_size()?, 0);
+        })
+    }
+}
@@ -2,6 +2,7 @@ use super::{ConnectionExt, Sqlite, group::StoredGroup};
 use super::{
     db_connection::DbConnection,
     schema::{
+        consent_record_changes,
         consent_records::{self, dsl},
         groups::dsl as groups_dsl,
     },
@@ -10,14 +11,15 @@ use crate::{DbQuery, StorageError, impl_store};
 use diesel::{
     backend::Backend,
     deserialize::{self, FromSql, FromSqlRow},
-    expression::AsExpression,
+    dsl::sql,
+    expression::{AsExpression, SqlLiteral},
     prelude::*,
     serialize::{self, IsNull, Output, ToSql},
-    sql_types::Integer,
+    sql_types::{Bool, Integer},
     upsert::excluded,
 };
 use serde::{Deserialize, Serialize};
-use xmtp_common::time::now_ns;
+use xmtp_common::{NS_IN_MS, time::now_ns};
 use xmtp_proto::{
     ConversionError,
     xmtp::device_sync::consent_backup::{ConsentSave, ConsentStateSave, ConsentTypeSave},
@@ -80,6 +82,21 @@ impl StoredConsentRecord {
 
 impl_store!(StoredConsentRecord, consent_records);
 
+/// Narrows [`QueryConsentRecord::consent_records_paged`]. A `None` filter matches every record.
+#[derive(Debug, Default, Clone)]
+pub struct ConsentRecordQueryArgs {
+    /// Only return records changed locally after this time. Records synced from other
+    /// installations count as changed when they were stored, regardless of when they
+    /// were consented to.
+    pub changed_after_ns: Option<i64>,
+    /// Only return conversation records for these groups. Other records are kept.
+    pub group_ids: Option<Vec<Vec<u8>>>,
+    /// Never return conversation records for these groups
+    pub exclude_group_ids: Option<Vec<Vec<u8>>>,
+    /// Only return records in one of these states
+    pub consent_states: Option<Vec<ConsentState>>,
+}
+
 pub trait QueryConsentRecord {
     /// Returns the consent_records for the given entity up
     fn get_consent_record(
@@ -90,11 +107,16 @@ pub trait QueryConsentRecord {
 
     fn consent_records(&self) -> Result<Vec<StoredConsentRecord>, crate::ConnectionError>;
 
+    /// Page through consent records matching `args` in the order they last changed locally.
+    /// Each record comes with the sequence id of its latest change; pass the last one as
+    /// `after_sequence_id` to get the next page. A record that changes while paging moves to
+    /// the end, so it is returned again rather than skipped.
     fn consent_records_paged(
         &self,
         limit: i64,
-        offset: i64,
-    ) -> Result<Vec<StoredConsentRecord>, crate::ConnectionError>;
+        after_sequence_id: i32,
+        args: &ConsentRecordQueryArgs,
+    ) -> Result<Vec<(i32, StoredConsentRecord)>, crate::ConnectionError>;
 
     /// Returns true if newer
     fn insert_newer_consent_record(
@@ -142,14 +164,56 @@ impl<C: ConnectionExt> QueryConsentRecord for DbConnection<C> {
     fn consent_records_paged(
         &self,
         limit: i64,
-        offset: i64,
-    ) -> Result<Vec<StoredConsentRecord>, crate::ConnectionError> {
-        let query = consent_records::table
-            .order_by((consent_records::entity_type, consent_records::entity))
+        after_sequence_id: i32,
+        args: &ConsentRecordQueryArgs,
+    ) -> Result<Vec<(i32, StoredConsentRecord)>, crate::ConnectionError> {
+        let ConsentRecordQueryArgs {
+            changed_after_ns,
+            group_ids,
+            exclude_group_ids,
+            consent_states,
+        } = args;
+        let mut query = consent_records::table
+            .inner_join(
+                consent_record_changes::table.on(consent_record_changes::entity_type
+                    .eq(consent_records::entity_type)
+                    .and(consent_record_changes::entity.eq(consent_records::entity))),
+            )
+            .select((
+                consent_record_changes::sequence_id,
+                consent_records::all_columns,
+            ))
+            .filter(consent_record_changes::sequence_id.gt(after_sequence_id))
+            .order_by(consent_record_changes::sequence_id)
             .limit(limit)
-            .offset(offset);
+            .into_boxed();
+        if let Some(changed_after_ns) = changed_after_ns {
+            // Change times are recorded by the database with millisecond precision
+            query =
+                query.filter(consent_record_changes::changed_at_ns.gt(changed_after_ns - NS_IN_MS));
+        }
+        // Group filters only apply to conversation records, which are keyed by the hex group id
+        if let Some(group_ids) = group_ids {
+            let entities: Vec<String> = group_ids.iter().map(hex::encode).collect();
+            query = query.filter(
+                consent_records::entity_type
+                    .ne(ConsentType::ConversationId)
+                    .or(consent_records::entity.eq_any(entities)),
+            );
+        }
+        if let Some(exclude_group_ids) = exclude_group_ids {
+            let entities: Vec<String> = exclude_group_ids.iter().map(hex::encode).collect();
+            query = query.filter(
+                consent_records::entity_type
+                    .ne(ConsentType::ConversationId)
+                    .or(consent_records::entity.ne_all(entities)),
+            );
+        }
+        if let Some(consent_states) = consent_states {
+            query = query.filter(consent_records::state.eq_any(consent_states.clone()));
+        }
 
-        self.raw_query(|conn| query.load::<StoredConsentRecord>(conn))
+        self.raw_query(|conn| query.load::<(i32, StoredConsentRecord)>(conn))
     }
 
     // returns true if newer
@@ -301,9 +365,10 @@ impl<T: QueryConsentRecord + ?Sized> QueryConsentRecord for &T {
     fn consent_records_paged(
         &self,
         limit: i64,
-        offset: i64,
-    ) -> Result<Vec<StoredConsentRecord>, crate::ConnectionError> {
-        (**self).consent_records_paged(limit, offset)
+        after_sequence_id: i32,
+        args: &ConsentRecordQueryArgs,
+    ) -> Result<Vec<(i32, StoredConsentRecord)>, crate::ConnectionError> {
+        (**self).consent_records_paged(limit, after_sequence_id, args)
     }
 
     fn insert_newer_consent_record(
@@ -370,7 +435,9 @@ where
 }
 
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, AsExpression, FromSqlRow)]
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, AsExpression, FromSqlRow,
+)]
 #[diesel(sql_type = Integer)]
 /// The state of the consent
 pub enum ConsentState {
@@ -406,6 +473,46 @@ where
     }
 }
 
+/// Builds a SQL predicate matching conversations whose consent state is one of `states`.
+///
+/// `group_id_column` is the qualified blob column holding the conversation id. Conversations
+/// without a consent record are treated as [`ConsentState::Unknown`]. Returns `None` when
+/// `states` does not narrow anything down.
+pub(crate) fn conversation_consent_predicate(
+    group_id_column: &str,
+    states: &[ConsentState],
+) -> Option<SqlLiteral<Bool>> {
+    let includes_unknown = states.contains(&ConsentState::Unknown);
+    let recorded: Vec<String> = [ConsentState::Allowed, ConsentState::Denied]
+        .into_iter()
+        .filter(|state| states.contains(state))
+        .map(|state| (state as i32).to_string())
+        .collect();
+    if states.is_empty() || (includes_unknown && recorded.len() == 2) {
+        return None;
+    }
+
+    let entity = format!("lower(hex({group_id_column}))");
+    let entity_type = ConsentType::ConversationId as i32;
+    let mut clauses = vec![];
+    if !recorded.is_empty() {
+        clauses.push(format!(
+            "{entity} IN (SELECT entity FROM consent_records \
+             WHERE entity_type = {entity_type} AND state IN ({}))",
+            recorded.join(", ")
+        ));
+    }
+    if includes_unknown {
+        clauses.push(format!(
+            "{entity} NOT IN (SELECT entity FROM consent_records \
+             WHERE entity_type = {entity_type} AND state != {})",
+            ConsentState::Unknown as i32
+        ));
+    }
+
+    Some(sql::<Bool>(&format!("({})", clauses.join(" OR "))))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -445,6 +552,119 @@ mod tests {
         })
     }
 
+    #[xmtp_common::test(unwrap_try = true)]
+    fn paged_groups_filtered_by_consent() {
+        use crate::group::{GroupQueryArgs, QueryGroup};
+
+        with_connection(|conn| {
+            let allowed = generate_group(None);
+            let denied = generate_group(None);
+            let unrecorded = generate_group(None);
+            for group in [&allowed, &denied, &unrecorded] {
+                group.store(conn)?;
+            }
+            for (group, state) in [
+                (&allowed, ConsentState::Allowed),
+                (&denied, ConsentState::Denied),
+            ] {
+                generate_consent_record(ConsentType::ConversationId, state, hex::encode(group.id))
+                    .store(conn)?;
+            }
+
+            let ids = |states: Vec<ConsentState>| -> Vec<_> {
+                let args = GroupQueryArgs {
+                    consent_states: Some(states),
+                    ..Default::default()
+                };
+                conn.find_groups_by_id_paged(args, 0)
+                    .unwrap()
+                    .into_iter()
+                    .map(|g| g.id)
+                    .collect()
+            };
+
+            assert_eq!(ids(vec![ConsentState::Unknown]), vec![unrecorded.id]);
+            assert_eq!(ids(vec![ConsentState::Denied]), vec![denied.id]);
+            let mut expected = vec![allowed.id, unrecorded.id];
+            expected.sort();
+            assert_eq!(
+                ids(vec![ConsentState::Allowed, ConsentState::Unknown]),
+                expected
+            );
+            assert_eq!(ids(vec![]).len(), 3);
+        })
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn paged_consent_records_changed_after() {
+        with_connection(|conn| {
+            let record = |entity: &str, consented_at_ns| StoredConsentRecord {
+                consented_at_ns,
+                ..generate_consent_record(
+                    ConsentType::InboxId,
+                    ConsentState::Allowed,
+                    entity.to_string(),
+                )
+            };
+            let since_ns = now_ns();
+            record("exported", since_ns - 1).store(conn)?;
+            conn.raw_query(|conn| {
+                diesel::update(consent_record_changes::table)
+                    .set(consent_record_changes::changed_at_ns.eq(since_ns - NS_IN_MS * 10))
+                    .execute(conn)
+            })?;
+
+            // Synced from another installation after the export, but consented to before it
+            record("synced", since_ns - 1_000).store(conn)?;
+            let changed_since = ConsentRecordQueryArgs {
+                changed_after_ns: Some(since_ns),
+                ..Default::default()
+            };
+            let changed = conn.consent_records_paged(10, 0, &changed_since)?;
+            assert_eq!(changed.len(), 1);
+            assert_eq!(changed[0].1.entity, "synced");
+
+            // Changing a record moves it to the end of the pages
+            conn.insert_or_replace_consent_records(&[StoredConsentRecord {
+                state: ConsentState::Denied,
+                ..record("exported", now_ns())
+            }])?;
+            let entities: Vec<_> = conn
+                .consent_records_paged(10, 0, &Default::default())?
+                .into_iter()
+                .map(|(_, r)| r.entity)
+                .collect();
+            assert_eq!(entities, vec!["synced", "exported"]);
+            assert_eq!(conn.consent_records_paged(10, 0, &changed_since)?.len(), 2);
+        })
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn paged_consent_records_survive_changes() {
+        with_connection(|conn| {
+            let record = |entity: &str, state| {
+                generate_consent_record(ConsentType::InboxId, state, entity.to_string())
+            };
+            for entity in ["a", "b", "c"] {
+                record(entity, ConsentState::Allowed).store(conn)?;
+            }
+
+            let first = conn.consent_records_paged(1, 0, &Default::default())?;
+            assert_eq!(first.len(), 1);
+            let (last_seen, ref seen) = first[0];
+            assert_eq!(seen.entity, "a");
+
+            // A record changing between pages moves to the end, without shifting the others
+            conn.insert_or_replace_consent_records(&[record("a", ConsentState::Denied)])?;
+            let entities: Vec<_> = conn
+                .consent_records_paged(10, last_seen, &Default::default())?
+                .into_iter()
+                .map(|(_, r)| r.entity)
+                .collect();
+            assert_eq!(entities, vec!["b", "c", "a"]);
+        })
+    }
+
     #[xmtp_common::test]
     fn insert_and_read() {
         with_connection(|conn| {
@@ -1,6 +1,7 @@
 use super::ConnectionExt;
 use super::schema::conversation_list::dsl::conversation_list;
 use crate::consent_record::ConsentState;
+use crate::conversation_settings::conversation_settings_predicate;
 use crate::group::{ConversationType, GroupMembershipState, GroupQueryArgs, GroupQueryOrderBy};
 use crate::group_message::{ContentType, DeliveryStatus, GroupMessageKind};
 use crate::{DbConnection, StorageError};
@@ -61,6 +62,18 @@ pub struct ConversationListItem {
     pub sequence_id: Option<i64>,
     /// originator id of the message null if no messages for a group yet
     pub originator_id: Option<i64>,
+    /// Whether the conversation has an unsent local draft
+    pub has_draft: bool,
+    /// Number of messages from other inboxes sent after the read cursor
+    pub unread_count: i64,
+    /// The oldest unread message, if any
+    pub first_unread_message_id: Option<Vec<u8>>,
+    /// Notifications are muted until this time. `None` when not muted.
+    pub muted_until_ns: Option<i64>,
+    /// Whether the user archived the conversation
+    pub is_archived: bool,
+    /// Whether the user pinned the conversation to the top of their inbox
+    pub is_pinned: bool,
 }
 
 pub trait QueryConversationList {
@@ -104,6 +117,8 @@ impl<C: ConnectionExt> QueryConversationList for DbConnection<C> {
             last_activity_after_ns,
             last_activity_before_ns,
             order_by,
+            group_ids,
+            exclude_group_ids,
             ..
         } = args.as_ref();
 
@@ -149,6 +164,20 @@ impl<C: ConnectionExt> QueryConversationList for DbConnection<C> {
             query = query.filter(conversation_list_dsl::membership_state.eq_any(allowed_states));
         }
 
+        if let Some(group_ids) = group_ids {
+            query = query.filter(conversation_list_dsl::id.eq_any(group_ids));
+        }
+
+        if let Some(exclude_group_ids) = exclude_group_ids {
+            query = query.filter(conversation_list_dsl::id.ne_all(exclude_group_ids));
+        }
+
+        if let Some(predicate) =
+            conversation_settings_predicate("conversation_list.id", args.as_ref())
+        {
+            query = query.filter(predicate);
+        }
+
         // last_activity_after_ns takes precedence over created_after_ns
         if let Some(last_activity_after_ns) = last_activity_after_ns {
             // "Activity after" means groups that were either created,
@@ -296,6 +325,176 @@ pub(crate) mod tests {
         })
     }
 
+    #[xmtp_common::test]
+    fn test_conversation_list_reports_drafts() {
+        with_connection(|conn| {
+            let drafted = generate_group(None);
+            let plain = generate_group(None);
+            drafted.store(conn).unwrap();
+            plain.store(conn).unwrap();
+            conn.upsert_draft(&crate::draft::StoredDraft {
+                group_id: drafted.id,
+                content: vec![1, 2, 3],
+                reply_to_message_id: None,
+                updated_at_ns: 1,
+            })
+            .unwrap();
+
+            let conversation_list = conn
+                .fetch_conversation_list(GroupQueryArgs::default())
+                .unwrap();
+            let has_draft = |id| {
+                conversation_list
+                    .iter()
+                    .find(|c| c.id == id)
+                    .unwrap()
+                    .has_draft
+            };
+            assert!(has_draft(drafted.id));
+            assert!(!has_draft(plain.id));
+
+            conn.delete_draft(&drafted.id).unwrap();
+            let conversation_list = conn
+                .fetch_conversation_list(GroupQueryArgs::default())
+                .unwrap();
+            assert!(conversation_list.iter().all(|c| !c.has_draft));
+        })
+    }
+
+    #[xmtp_common::test]
+    fn test_conversation_list_reports_unread() {
+        with_connection(|conn| {
+            let group = generate_group(None);
+            group.store(conn).unwrap();
+            let message = |sent_at_ns, content_type| {
+                let message = generate_message(
+                    None,
+                    Some(&group.id),
+                    Some(sent_at_ns),
+                    Some(content_type),
+                    None,
+                    None,
+                );
+                message.store(conn).unwrap();
+                message
+            };
+            let first = message(1000, ContentType::Text);
+            let second = message(2000, ContentType::Text);
+            message(3000, ContentType::Reaction);
+
+            let unread = || {
+                let item = conn
+                    .fetch_conversation_list(GroupQueryArgs::default())
+                    .unwrap()
+                    .remove(0);
+                (item.unread_count, item.first_unread_message_id)
+            };
+            assert_eq!(unread(), (2, Some(first.id.clone())));
+
+            conn.insert_newer_read_cursor(&crate::read_cursor::StoredReadCursor::new(
+                group.id, first.id, 1000,
+            ))
+            .unwrap();
+            assert_eq!(unread(), (1, Some(second.id.clone())));
+
+            conn.insert_newer_read_cursor(&crate::read_cursor::StoredReadCursor::new(
+                group.id, second.id, 2000,
+            ))
+            .unwrap();
+            assert_eq!(unread(), (0, None));
+        })
+    }
+
+    #[xmtp_common::test]
+    fn test_conversation_list_filters_by_settings() {
+        use crate::conversation_settings::StoredConversationSettings;
+
+        with_connection(|conn| {
+            let plain = generate_group(None);
+            let archived = generate_group(None);
+            let pinned_and_muted = generate_group(None);
+            let mute_expired = generate_group(None);
+            for group in [&plain, &archived, &pinned_and_muted, &mute_expired] {
+                group.store(conn).unwrap();
+            }
+            let settings = |group_id, archived, pinned, muted_until_ns| {
+                let settings = StoredConversationSettings {
+                    archived,
+                    pinned,
+                    muted_until_ns,
+                    ..StoredConversationSettings::new(group_id)
+                };
+                conn.insert_newer_conversation_settings(&settings).unwrap();
+            };
+            settings(archived.id, true, false, None);
+            settings(pinned_and_muted.id, false, true, Some(i64::MAX));
+            settings(mute_expired.id, false, false, Some(1));
+
+            let ids = |args: GroupQueryArgs| {
+                let mut ids: Vec<_> = conn
+                    .fetch_conversation_list(args)
+                    .unwrap()
+                    .into_iter()
+                    .map(|c| c.id)
+                    .collect();
+                ids.sort();
+                ids
+            };
+            let sorted = |mut ids: Vec<_>| {
+                ids.sort();
+                ids
+            };
+
+            assert_eq!(
+                ids(GroupQueryArgs {
+                    archived: Some(true),
+                    ..Default::default()
+                }),
+                vec![archived.id]
+            );
+            assert_eq!(
+                ids(GroupQueryArgs {
+                    archived: Some(false),
+                    ..Default::default()
+                }),
+                sorted(vec![plain.id, pinned_and_muted.id, mute_expired.id])
+            );
+            assert_eq!(
+                ids(GroupQueryArgs {
+                    pinned: Some(true),
+                    ..Default::default()
+                }),
+                vec![pinned_and_muted.id]
+            );
+            assert_eq!(
+                ids(GroupQueryArgs {
+                    muted: Some(true),
+                    ..Default::default()
+                }),
+                vec![pinned_and_muted.id]
+            );
+            assert_eq!(
+                ids(GroupQueryArgs {
+                    muted: Some(false),
+                    archived: Some(false),
+                    ..Default::default()
+                }),
+                sorted(vec![plain.id, mute_expired.id])
+            );
+
+            let item = conn
+                .fetch_conversation_list(GroupQueryArgs {
+                    group_ids: Some(vec![pinned_and_muted.id.to_vec()]),
+                    ..Default::default()
+                })
+                .unwrap()
+                .remove(0);
+            assert!(item.is_pinned);
+            assert!(!item.is_archived);
+            assert_eq!(item.muted_until_ns, Some(i64::MAX));
+        })
+    }
+
     #[xmtp_common::test]
     fn test_three_groups_specific_ordering() {
         with_connection(|conn| {
@@ -0,0 +1,219 @@
+use super::ConnectionExt;
+use crate::DbConnection;
+use crate::group::GroupQueryArgs;
+use crate::schema::conversation_settings::{self, dsl};
+use diesel::dsl::sql;
+use diesel::expression::SqlLiteral;
+use diesel::prelude::*;
+use diesel::sql_types::Bool;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+use xmtp_common::time::now_ns;
+use xmtp_proto::ConversionError;
+use xmtp_proto::types::GroupId;
+use xmtp_proto::xmtp::device_sync::content::ConversationSettingsUpdate;
+
+#[derive(
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    Insertable,
+    Identifiable,
+    Queryable,
+    Eq,
+    PartialEq,
+    QueryableByName,
+)]
+#[diesel(table_name = conversation_settings)]
+#[diesel(primary_key(group_id))]
+/// How the user has organized a conversation in their inbox. Settings are
+/// synced across installations, the most recently updated settings win.
+pub struct StoredConversationSettings {
+    pub group_id: GroupId,
+    /// Notifications are muted until this time. `None` when not muted.
+    pub muted_until_ns: Option<i64>,
+    pub archived: bool,
+    pub pinned: bool,
+    /// Time in nanoseconds the settings were last changed, on any installation.
+    pub updated_at_ns: i64,
+}
+
+impl StoredConversationSettings {
+    /// Settings for a conversation that is not muted, archived or pinned.
+    pub fn new(group_id: GroupId) -> Self {
+        Self {
+            group_id,
+            muted_until_ns: None,
+            archived: false,
+            pinned: false,
+            updated_at_ns: now_ns(),
+        }
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted_until_ns.is_some_and(|until| until > now_ns())
+    }
+}
+
+pub trait QueryConversationSettings {
+    fn get_conversation_settings(
+        &self,
+        group_id: &GroupId,
+    ) -> Result<Option<StoredConversationSettings>, crate::ConnectionError>;
+
+    /// Store the settings unless the conversation already has settings updated
+    /// at or after `settings.updated_at_ns`. Returns true if the settings were stored.
+    fn insert_newer_conversation_settings(
+        &self,
+        settings: &StoredConversationSettings,
+    ) -> Result<bool, crate::ConnectionError>;
+}
+
+impl<T> QueryConversationSettings for &T
+where
+    T: QueryConversationSettings,
+{
+    fn get_conversation_settings(
+        &self,
+        group_id: &GroupId,
+    ) -> Result<Option<StoredConversationSettings>, crate::ConnectionError> {
+        (**self).get_conversation_settings(group_id)
+    }
+
+    fn insert_newer_conversation_settings(
+        &self,
+        settings: &StoredConversationSettings,
+    ) -> Result<bool, crate::ConnectionError> {
+        (**self).insert_newer_conversation_settings(settings)
+    }
+}
+
+impl<C: ConnectionExt> QueryConversationSettings for DbConnection<C> {
+    fn get_conversation_settings(
+        &self,
+        group_id: &GroupId,
+    ) -> Result<Option<StoredConversationSettings>, crate::ConnectionError> {
+        self.raw_query(|conn| {
+            dsl::conversation_settings
+                .filter(dsl::group_id.eq(group_id))
+                .first(conn)
+                .optional()
+        })
+    }
+
+    fn insert_newer_conversation_settings(
+        &self,
+        settings: &StoredConversationSettings,
+    ) -> Result<bool, crate::ConnectionError> {
+        let updated = self.raw_query(|conn| {
+            diesel::insert_into(dsl::conversation_settings)
+                .values(settings)
+                .on_conflict(dsl::group_id)
+                .do_update()
+                .set((
+                    dsl::muted_until_ns.eq(excluded(dsl::muted_until_ns)),
+                    dsl::archived.eq(excluded(dsl::archived)),
+                    dsl::pinned.eq(excluded(dsl::pinned)),
+                    dsl::updated_at_ns.eq(excluded(dsl::updated_at_ns)),
+                ))
+                .filter(dsl::updated_at_ns.lt(excluded(dsl::updated_at_ns)))
+                .execute(conn)
+        })?;
+        Ok(updated > 0)
+    }
+}
+
+/// SQL predicate on `group_id_column` for the `archived`, `pinned` and `muted`
+/// filters of [`GroupQueryArgs`], or `None` if none of them are set.
+pub(crate) fn conversation_settings_predicate(
+    group_id_column: &str,
+    args: &GroupQueryArgs,
+) -> Option<SqlLiteral<Bool>> {
+    let now = now_ns();
+    let clauses: Vec<String> = [
+        (args.archived, "archived".to_string()),
+        (args.pinned, "pinned".to_string()),
+        (
+            args.muted,
+            format!("muted_until_ns IS NOT NULL AND muted_until_ns > {now}"),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(wanted, condition)| {
+        let wanted = wanted?;
+        let not = if wanted { "" } else { "NOT " };
+        Some(format!(
+            "{group_id_column} {not}IN (SELECT group_id FROM conversation_settings WHERE {condition})"
+        ))
+    })
+    .collect();
+
+    if clauses.is_empty() {
+        return None;
+    }
+    Some(sql::<Bool>(&format!("({})", clauses.join(" AND "))))
+}
+
+impl TryFrom<ConversationSettingsUpdate> for StoredConversationSettings {
+    type Error = ConversionError;
+    fn try_from(value: ConversationSettingsUpdate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            group_id: value.group_id.try_into()?,
+            muted_until_ns: value.muted_until_ns,
+            archived: value.archived,
+            pinned: value.pinned,
+            updated_at_ns: value.updated_at_ns,
+        })
+    }
+}
+
+impl From<StoredConversationSettings> for ConversationSettingsUpdate {
+    fn from(value: StoredConversationSettings) -> Self {
+        Self {
+            group_id: value.group_id.to_vec(),
+            muted_until_ns: value.muted_until_ns,
+            archived: value.archived,
+            pinned: value.pinned,
+            updated_at_ns: value.updated_at_ns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::with_connection;
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_newest_conversation_settings_win() {
+        with_connection(|conn| {
+            assert!(conn.get_conversation_settings(&GroupId::ONE)?.is_none());
+
+            let mut settings = StoredConversationSettings::new(GroupId::ONE);
+            settings.pinned = true;
+            assert!(conn.insert_newer_conversation_settings(&settings)?);
+            assert!(!conn.insert_newer_conversation_settings(&settings)?);
+
+            let stale = StoredConversationSettings {
+                archived: true,
+                updated_at_ns: settings.updated_at_ns - 1,
+                ..settings.clone()
+            };
+            assert!(!conn.insert_newer_conversation_settings(&stale)?);
+            assert_eq!(conn.get_conversation_settings(&GroupId::ONE)??, settings);
+
+            let newer = StoredConversationSettings {
+                pinned: false,
+                muted_until_ns: Some(i64::MAX),
+                updated_at_ns: settings.updated_at_ns + 1,
+                ..settings.clone()
+            };
+            assert!(conn.insert_newer_conversation_settings(&newer)?);
+            let stored = conn.get_conversation_settings(&GroupId::ONE)??;
+            assert!(stored.is_muted());
+            assert!(!stored.pinned);
+            assert!(conn.get_conversation_settings(&GroupId::TWO)?.is_none());
+        })
+    }
+}
@@ -5,7 +5,7 @@ use crate::StorageError;
 use crate::database::instrumentation::TestInstrumentation;
 /// Native SQLite connection using SqlCipher
 use crate::{ConnectionError, ConnectionExt, DbConnection, NotFound};
-use arc_swap::ArcSwapOption;
+use arc_swap::{ArcSwap, ArcSwapOption};
 use diesel::sqlite::SqliteConnection;
 use diesel::{
     Connection,
@@ -14,6 +14,7 @@ use diesel::{
 };
 use parking_lot::Mutex;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use xmtp_common::{BoxDynError, ErrorCode, RetryableError, retryable};
 use xmtp_configuration::{BUSY_TIMEOUT, MAX_DB_POOL_SIZE, MIN_DB_POOL_SIZE};
@@ -40,6 +41,9 @@ trait ConnectionOptions {
     fn is_persistent(&self) -> bool {
         matches!(self.options(), StorageOption::Persistent(_))
     }
+    fn is_encrypted(&self) -> bool {
+        false
+    }
 }
 
 impl<T> XmtpConnection for T where
@@ -52,6 +56,10 @@ impl<T> XmtpConnection for T where
 
 dyn_clone::clone_trait_object!(XmtpConnection);
 
+/// The customizer shared between a [`NativeDb`], its clones and its connections, so that
+/// swapping it after a re-key is seen by every new connection.
+type SharedCustomizer = Arc<ArcSwap<Box<dyn XmtpConnection>>>;
+
 pub(crate) trait ValidatedConnection {
     fn validate(&self, _conn: &mut SqliteConnection) -> Result<(), PlatformStorageError> {
         Ok(())
@@ -183,6 +191,42 @@ pub enum PlatformStorageError {
     /// PRAGMA key or salt has wrong value. Not retryable.
     #[error("PRAGMA key or salt has incorrect value")]
     SqlCipherKeyIncorrect,
+    /// Not encrypted.
+    ///
+    /// Only an encrypted database can be re-keyed. Not retryable.
+    #[error("The database is not encrypted")]
+    NotEncrypted,
+    /// Already encrypted.
+    ///
+    /// Database is already encrypted and should be re-keyed instead. Not retryable.
+    #[error("The database is already encrypted")]
+    AlreadyEncrypted,
+    /// Re-key requires path.
+    ///
+    /// Only a persistent database can be re-keyed or encrypted. Not retryable.
+    #[error("Re-keying a database requires a persistent path")]
+    RekeyRequiresPath,
+    /// Connections in use.
+    ///
+    /// Connections checked out of the pool were not returned in time to re-key the database.
+    /// Retryable.
+    #[error("Database connections are still in use")]
+    ConnectionsInUse,
+    /// Checkpoint incomplete.
+    ///
+    /// The write-ahead log could not be fully checkpointed, usually because another process has
+    /// the database open. Retryable.
+    #[error("Checkpointed {checkpointed} of {log} write-ahead log frames")]
+    CheckpointIncomplete { log: i32, checkpointed: i32 },
+    /// Reconnect failed.
+    ///
+    /// Re-keying failed and the database could not be reopened afterwards. Not retryable.
+    #[error("{error}; reconnecting to the database afterwards failed: {reconnect}")]
+    ReconnectFailed {
+        #[source]
+        error: Box<PlatformStorageError>,
+        reconnect: Box<ConnectionError>,
+    },
     /// Database locked.
     ///
     /// Database file is locked by another process. Retryable.
@@ -232,6 +276,8 @@ impl RetryableError for PlatformStorageError {
             Self::PoolNeedsConnection => true,
             Self::SqlCipherKeyIncorrect => false,
             Self::DatabaseLocked => true,
+            Self::ConnectionsInUse => true,
+            Self::CheckpointIncomplete { .. } => true,
             Self::DieselResult(result) => retryable!(result),
             Self::Io(_) => true,
             Self::DieselConnect(_) => true,
@@ -244,12 +290,15 @@ impl RetryableError for PlatformStorageError {
 /// Database used in `native` (everywhere but web)
 #[derive(Clone, Debug)]
 pub struct NativeDb {
-    customizer: Box<dyn XmtpConnection>,
+    customizer: SharedCustomizer,
     conn: Arc<PersistentOrMem<NativeDbConnection, SingleDbConnection, EphemeralDbConnection>>,
     opts: StorageOption,
+    opened_with_fallback_key: bool,
 }
 
-use native_db_builder::{Empty, IsComplete, IsSet, IsUnset, SetKey, SetOpts, SetSingleConnection};
+use native_db_builder::{
+    Empty, IsComplete, IsSet, IsUnset, SetFallbackKey, SetKey, SetOpts, SetSingleConnection,
+};
 
 impl NativeDb {
     pub fn builder() -> NativeDbBuilder<Empty> {
@@ -261,6 +310,7 @@ impl NativeDb {
 pub fn native_db(
     #[builder(setters(vis = "", name = opts_internal))] opts: StorageOption,
     #[builder(required, setters(vis = "", name = key_internal))] key: Option<EncryptionKey>,
+    #[builder(setters(vis = "", name = fallback_key_internal))] fallback_key: Option<EncryptionKey>,
     #[builder(default = MAX_DB_POOL_SIZE)] max_pool_size: u32,
     /// minimum amount of connections maintained at any time
     #[builder(default = MIN_DB_POOL_SIZE)]
@@ -271,8 +321,15 @@ pub fn native_db(
     #[builder(default = false, setters(vis = "", name = single_connection_internal))]
     single_connection: bool,
 ) -> Result<NativeDb, StorageError> {
-    NativeDb::new_inner(&opts, key, max_pool_size, min_pool_size, single_connection)
-        .map_err(Into::into)
+    NativeDb::new_inner(
+        &opts,
+        key,
+        fallback_key,
+        max_pool_size,
+        min_pool_size,
+        single_connection,
+    )
+    .map_err(Into::into)
 }
 
 impl<S: native_db_builder::State> NativeDbBuilder<S> {
@@ -297,6 +354,27 @@ impl<S: native_db_builder::State> NativeDbBuilder<S> {
         self.key_internal(Some(key.into()))
     }
 
+    /// A second key to open the database with if [`Self::key`] is incorrect, such as the
+    /// other key of an interrupted [`NativeDb::rekey`].
+    /// [`NativeDb::opened_with_fallback_key`] reports whether it was used.
+    pub fn fallback_key(self, key: impl Into<EncryptionKey>) -> NativeDbBuilder<SetFallbackKey<S>>
+    where
+        S::FallbackKey: IsUnset,
+    {
+        self.fallback_key_internal(key.into())
+    }
+
+    /// Like [`Self::fallback_key`], leaving it unset when `key` is `None`.
+    pub fn maybe_fallback_key(
+        self,
+        key: Option<EncryptionKey>,
+    ) -> NativeDbBuilder<SetFallbackKey<S>>
+    where
+        S::FallbackKey: IsUnset,
+    {
+        self.maybe_fallback_key_internal(key)
+    }
+
     /// Use a single `Mutex<SqliteConnection>` instead of a connection pool.
     /// Costs exactly one file descriptor. Only meaningful for persistent
     /// databases; ignored for ephemeral ones.
@@ -331,23 +409,29 @@ impl NativeDb {
     fn new_inner(
         opts: &StorageOption,
         enc_key: Option<EncryptionKey>,
+        fallback_key: Option<EncryptionKey>,
         max_pool_size: u32,
         min_pool_size: u32,
         single_connection: bool,
     ) -> Result<Self, PlatformStorageError> {
+        let mut opened_with_fallback_key = false;
         let customizer = if let Some(key) = enc_key {
-            let enc_connection = EncryptedConnection::new(key, opts)?;
-            if let Some(path) = enc_connection.options().path() {
-                let mut conn = SqliteConnection::establish(path)?;
-                enc_connection.validate(&mut conn)?;
-            }
+            let enc_connection = match (Self::open_encrypted(key, opts), fallback_key) {
+                (Err(PlatformStorageError::SqlCipherKeyIncorrect), Some(fallback_key)) => {
+                    tracing::warn!("database key is incorrect, opening with the fallback key");
+                    opened_with_fallback_key = true;
+                    Self::open_encrypted(fallback_key, opts)?
+                }
+                (opened, _) => opened?,
+            };
             Box::new(enc_connection) as Box<dyn XmtpConnection>
         } else if matches!(opts, StorageOption::Persistent(_)) {
             Box::new(UnencryptedConnection::new(opts.clone())) as Box<dyn XmtpConnection>
         } else {
             Box::new(NopConnection::default()) as Box<dyn XmtpConnection>
         };
-        let conn = if customizer.is_persistent() {
+        let customizer: SharedCustomizer = Arc::new(ArcSwap::from_pointee(customizer));
+        let conn = if customizer.load().is_persistent() {
             if single_connection {
                 PersistentOrMem::Single(SingleDbConnection::new(customizer.clone())?)
             } else {
@@ -370,8 +454,87 @@ impl NativeDb {
             opts: opts.clone(),
             conn: conn.into(),
             customizer,
+            opened_with_fallback_key,
         })
     }
+
+    fn open_encrypted(
+        key: EncryptionKey,
+        opts: &StorageOption,
+    ) -> Result<EncryptedConnection, PlatformStorageError> {
+        let enc_connection = EncryptedConnection::new(key, opts)?;
+        if let Some(path) = enc_connection.options().path() {
+            let mut conn = SqliteConnection::establish(path)?;
+            enc_connection.validate(&mut conn)?;
+        }
+        Ok(enc_connection)
+    }
+}
+
+impl NativeDb {
+    /// Re-encrypt the database with `new_key`.
+    ///
+    /// The database is copied into a new file encrypted with `new_key` which then replaces the
+    /// original, so the original is left untouched if the copy fails. Connections are released
+    /// for the duration of the copy, after waiting for queries still using them to finish, and
+    /// the database must not be open in any other process. This blocks until the copy is done,
+    /// so async callers should run it on a blocking thread.
+    ///
+    /// If the process is killed during the swap, the database is left encrypted with either key.
+    /// Keep the old key until this returns, and open the database with one of the keys as
+    /// [`NativeDbBuilder::key`] and the other as [`NativeDbBuilder::fallback_key`];
+    /// [`Self::opened_with_fallback_key`] then tells which one it is encrypted with.
+    pub fn rekey(&self, new_key: impl Into<EncryptionKey>) -> Result<(), StorageError> {
+        if !self.customizer.load().is_encrypted() {
+            return Err(PlatformStorageError::NotEncrypted.into());
+        }
+        self.swap_encryption(new_key.into())
+    }
+
+    /// Whether the database was opened with [`NativeDbBuilder::fallback_key`] because the
+    /// primary key was incorrect.
+    pub fn opened_with_fallback_key(&self) -> bool {
+        self.opened_with_fallback_key
+    }
+
+    /// Encrypt a database built with [`NativeDbBuilder::build_unencrypted`] with `key`.
+    ///
+    /// Uses the same copy-then-swap strategy as [`NativeDb::rekey`].
+    pub fn encrypt_in_place(&self, key: impl Into<EncryptionKey>) -> Result<(), StorageError> {
+        if self.customizer.load().is_encrypted() {
+            return Err(PlatformStorageError::AlreadyEncrypted.into());
+        }
+        self.swap_encryption(key.into())
+    }
+
+    fn swap_encryption(&self, key: EncryptionKey) -> Result<(), StorageError> {
+        let current = self.customizer.load_full();
+        if !current.is_persistent() {
+            return Err(PlatformStorageError::RekeyRequiresPath.into());
+        }
+        match &*self.conn {
+            // wait for connections checked out of the pool, which could still write
+            PersistentOrMem::Persistent(pool) => {
+                pool.db_drain(Duration::from_millis(BUSY_TIMEOUT as u64))?
+            }
+            conn => conn.disconnect()?,
+        }
+        let swapped = EncryptedConnection::export_and_swap(&**current, key).map(|encrypted| {
+            self.customizer
+                .store(Arc::new(Box::new(encrypted) as Box<dyn XmtpConnection>));
+        });
+        // reconnect with whichever key the database on disk is now encrypted with
+        let reconnected = self.conn.reconnect();
+        match (swapped, reconnected) {
+            (Ok(()), reconnected) => Ok(reconnected?),
+            (Err(error), Ok(())) => Err(error.into()),
+            (Err(error), Err(reconnect)) => Err(PlatformStorageError::ReconnectFailed {
+                error: Box::new(error),
+                reconnect: Box::new(reconnect),
+            }
+            .into()),
+        }
+    }
 }
 
 impl XmtpDb for NativeDb {
@@ -392,7 +555,7 @@ impl XmtpDb for NativeDb {
     }
 
     fn validate(&self, conn: &mut SqliteConnection) -> Result<(), ConnectionError> {
-        self.customizer.validate(conn)?;
+        self.customizer.load().validate(conn)?;
         Ok(())
     }
 
@@ -479,7 +642,7 @@ impl ConnectionExt for EphemeralDbConnection {
 /// [`reconnect`]: ConnectionExt::reconnect
 pub struct SingleDbConnection {
     conn: Arc<Mutex<Option<SqliteConnection>>>,
-    customizer: Box<dyn XmtpConnection>,
+    customizer: SharedCustomizer,
 }
 
 impl std::fmt::Debug for SingleDbConnection {
@@ -495,18 +658,19 @@ impl std::fmt::Debug for SingleDbConnection {
         write!(
             f,
             "SingleDbConnection {{ path: {}, connected={} }}",
-            self.customizer.options(),
+            self.customizer.load().options(),
             connected
         )
     }
 }
 
 impl SingleDbConnection {
-    fn new(customizer: Box<dyn XmtpConnection>) -> Result<Self, PlatformStorageError> {
-        let StorageOption::Persistent(path) = customizer.options() else {
+    fn new(customizer: SharedCustomizer) -> Result<Self, PlatformStorageError> {
+        let current = customizer.load_full();
+        let StorageOption::Persistent(path) = current.options() else {
             return Err(PlatformStorageError::PoolRequiresPath);
         };
-        let conn = Self::establish(path, &*customizer)?;
+        let conn = Self::establish(path, &**current)?;
         Ok(Self {
             conn: Arc::new(Mutex::new(Some(conn))),
             customizer,
@@ -549,7 +713,8 @@ impl SingleDbConnection {
 
     fn db_reconnect(&self) -> Result<(), PlatformStorageError> {
         tracing::info!("single-connection: reconnecting sqlite database connection");
-        let StorageOption::Persistent(path) = self.customizer.options() else {
+        let customizer = self.customizer.load_full();
+        let StorageOption::Persistent(path) = customizer.options() else {
             return Err(PlatformStorageError::PoolRequiresPath);
         };
         // Drop the existing connection (releasing its fd) BEFORE establishing the
@@ -560,7 +725,7 @@ impl SingleDbConnection {
         // a concurrent `raw_query` can't observe a half-open state.
         let mut guard = self.conn.lock();
         *guard = None;
-        *guard = Some(Self::establish(path, &*self.customizer)?);
+        *guard = Some(Self::establish(path, &**customizer)?);
         Ok(())
     }
 }
@@ -593,7 +758,7 @@ impl ConnectionExt for SingleDbConnection {
 
 pub struct NativeDbConnection {
     pub(super) pool: ArcSwapOption<DbPool>,
-    customizer: Box<dyn XmtpConnection>,
+    customizer: SharedCustomizer,
     max_pool_size: u32,
     min_pool_size: u32,
 }
@@ -603,7 +768,7 @@ impl std::fmt::Debug for NativeDbConnection {
         write!(
             f,
             "NativeDbConnection {{ path: {}, state={:?} }}",
-            self.customizer.options(),
+            self.customizer.load().options(),
             self.pool.load().as_ref().map(|s| s.state()),
         )
     }
@@ -611,12 +776,12 @@ impl std::fmt::Debug for NativeDbConnection {
 
 impl NativeDbConnection {
     fn new(
-        customizer: Box<dyn XmtpConnection>,
+        customizer: SharedCustomizer,
         max_pool_size: u32,
         min_pool_size: u32,
     ) -> Result<Self, PlatformStorageError> {
         let pool = DbPool::builder()
-            .customizer(customizer.clone())
+            .customizer((**customizer.load()).clone())
             .max_size(max_pool_size)
             .min_size(min_pool_size)
             .build()?;
@@ -635,12 +800,33 @@ impl NativeDbConnection {
         Ok(())
     }
 
+    /// Release the pool like [`Self::db_disconnect`], then wait up to `timeout` for queries
+    /// still using it to finish, so nothing writes to the database once this returns.
+    /// The pool is restored if the queries do not finish in time. This blocks the calling
+    /// thread.
+    fn db_drain(&self, timeout: Duration) -> Result<(), PlatformStorageError> {
+        let Some(pool) = self.pool.swap(None) else {
+            return Ok(());
+        };
+        tracing::warn!("draining sqlite database connections");
+        let deadline = Instant::now() + timeout;
+        // every query holds a reference to the pool for as long as it uses a connection
+        while Arc::strong_count(&pool) > 1 {
+            if Instant::now() >= deadline {
+                self.pool.store(Some(pool));
+                return Err(PlatformStorageError::ConnectionsInUse);
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        Ok(())
+    }
+
     fn db_reconnect(&self) -> Result<(), PlatformStorageError> {
         tracing::info!("reconnecting sqlite database connection");
         let pool = DbPool::builder()
             .max_size(self.max_pool_size)
             .min_size(self.min_pool_size)
-            .customizer(self.customizer.clone())
+            .customizer((**self.customizer.load()).clone())
             .build()?;
         self.pool.store(Some(Arc::new(pool)));
         Ok(())
@@ -751,6 +937,223 @@ mod tests {
         EncryptedMessageStore::<()>::remove_db_files(db_path)
     }
 
+    #[tokio::test]
+    async fn rekey_database() {
+        use crate::database::PlatformStorageError;
+        let old_key = [1u8; 32];
+        let new_key = [2u8; 32];
+
+        let db_path = tmp_path();
+        {
+            let db = NativeDb::builder()
+                .persistent(db_path.clone())
+                .key(old_key)
+                .build()
+                .unwrap();
+            let store = EncryptedMessageStore::new(db).unwrap();
+            StoredIdentity::new("inbox_id".to_string(), rand_vec::<24>(), rand_vec::<24>())
+                .store(&store.conn())
+                .unwrap();
+
+            store.rekey(new_key).unwrap();
+            // the same store keeps working with the new key
+            let identity: StoredIdentity = store.conn().fetch(&()).unwrap().unwrap();
+            assert_eq!(identity.inbox_id, "inbox_id");
+            assert!(matches!(
+                store.encrypt_in_place(new_key),
+                Err(crate::StorageError::Platform(
+                    PlatformStorageError::AlreadyEncrypted
+                ))
+            ));
+        }
+
+        let err = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key(old_key)
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::StorageError::Platform(PlatformStorageError::SqlCipherKeyIncorrect)
+        ));
+        let db = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key(new_key)
+            .build()
+            .unwrap();
+        let identity: StoredIdentity = db.conn().fetch(&()).unwrap().unwrap();
+        assert_eq!(identity.inbox_id, "inbox_id");
+        drop(db);
+        EncryptedMessageStore::<()>::remove_db_files(db_path)
+    }
+
+    #[tokio::test]
+    async fn opens_with_fallback_key() {
+        use crate::database::PlatformStorageError;
+        let old_key = [8u8; 32];
+        let new_key = [9u8; 32];
+
+        let db_path = tmp_path();
+        {
+            let db = NativeDb::builder()
+                .persistent(db_path.clone())
+                .key(old_key)
+                .build()
+                .unwrap();
+            db.init().unwrap();
+            assert!(!db.opened_with_fallback_key());
+        }
+
+        // killed before the re-encrypted copy was swapped in
+        let db = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key(new_key)
+            .fallback_key(old_key)
+            .build()
+            .unwrap();
+        assert!(db.opened_with_fallback_key());
+        db.rekey(new_key).unwrap();
+        drop(db);
+
+        let db = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key(new_key)
+            .fallback_key(old_key)
+            .build()
+            .unwrap();
+        assert!(!db.opened_with_fallback_key());
+        drop(db);
+
+        let err = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key([10u8; 32])
+            .fallback_key(old_key)
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::StorageError::Platform(PlatformStorageError::SqlCipherKeyIncorrect)
+        ));
+        EncryptedMessageStore::<()>::remove_db_files(db_path)
+    }
+
+    #[tokio::test]
+    async fn encrypt_unencrypted_database_in_place() {
+        use crate::database::PlatformStorageError;
+        let key = [3u8; 32];
+
+        let db_path = tmp_path();
+        {
+            let db = NativeDb::builder()
+                .persistent(db_path.clone())
+                .single_connection()
+                .build_unencrypted()
+                .unwrap();
+            let store = EncryptedMessageStore::new(db).unwrap();
+            StoredIdentity::new("inbox_id".to_string(), rand_vec::<24>(), rand_vec::<24>())
+                .store(&store.conn())
+                .unwrap();
+            assert!(matches!(
+                store.rekey(key),
+                Err(crate::StorageError::Platform(
+                    PlatformStorageError::NotEncrypted
+                ))
+            ));
+
+            store.encrypt_in_place(key).unwrap();
+            let identity: StoredIdentity = store.conn().fetch(&()).unwrap().unwrap();
+            assert_eq!(identity.inbox_id, "inbox_id");
+        }
+
+        assert!(EncryptedConnection::salt_file(&db_path).unwrap().exists());
+        let db = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key(key)
+            .build()
+            .unwrap();
+        let identity: StoredIdentity = db.conn().fetch(&()).unwrap().unwrap();
+        assert_eq!(identity.inbox_id, "inbox_id");
+        drop(db);
+        EncryptedMessageStore::<()>::remove_db_files(db_path)
+    }
+
+    #[tokio::test]
+    async fn rekey_waits_for_checked_out_connections() {
+        let db_path = tmp_path();
+        {
+            let db = NativeDb::builder()
+                .persistent(db_path.clone())
+                .key([5u8; 32])
+                .build()
+                .unwrap();
+            db.init().unwrap();
+
+            let (checked_out, wait_for_checkout) = std::sync::mpsc::channel();
+            let conn = db.conn();
+            let writer = std::thread::spawn(move || {
+                conn.raw_query(|c| {
+                    checked_out.send(()).unwrap();
+                    std::thread::sleep(Duration::from_millis(200));
+                    c.batch_execute("CREATE TABLE written_during_rekey (id INTEGER);")
+                })
+            });
+            wait_for_checkout.recv().unwrap();
+            db.rekey([6u8; 32]).unwrap();
+            writer.join().unwrap().unwrap();
+        }
+
+        let db = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key([6u8; 32])
+            .build()
+            .unwrap();
+        db.conn()
+            .raw_query(|c| c.batch_execute("SELECT id FROM written_during_rekey;"))
+            .unwrap();
+        drop(db);
+        EncryptedMessageStore::<()>::remove_db_files(db_path)
+    }
+
+    #[tokio::test]
+    async fn recovers_interrupted_rekey() {
+        let key = [4u8; 32];
+        let db_path = tmp_path();
+        let swap_path = format!("{db_path}.swap");
+        {
+            let db = NativeDb::builder()
+                .persistent(db_path.clone())
+                .key(key)
+                .build()
+                .unwrap();
+            db.init().unwrap();
+            db.rekey(key).unwrap();
+        }
+
+        // interrupted before the copy was swapped in
+        std::fs::write(&swap_path, b"incomplete").unwrap();
+        let db = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key(key)
+            .build()
+            .unwrap();
+        db.init().unwrap();
+        assert!(!std::path::Path::new(&swap_path).exists());
+        drop(db);
+
+        // interrupted after the copy was swapped in, but before its salt was
+        let salt = EncryptedConnection::salt_file(&db_path).unwrap();
+        std::fs::rename(&salt, EncryptedConnection::salt_file(&swap_path).unwrap()).unwrap();
+        let db = NativeDb::builder()
+            .persistent(db_path.clone())
+            .key(key)
+            .build()
+            .unwrap();
+        db.init().unwrap();
+        assert!(salt.exists());
+        drop(db);
+        EncryptedMessageStore::<()>::remove_db_files(db_path)
+    }
+
     #[tokio::test]
     async fn single_connection_roundtrip_and_reconnect() {
         use crate::{Fetch, Store, identity::StoredIdentity};
@@ -13,7 +13,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use super::PlatformStorageError;
+use super::{PlatformStorageError, ValidatedConnection, XmtpConnection};
 use crate::{
     NotFound,
     database::instrumentation::TestInstrumentation,
@@ -25,6 +25,7 @@ use crate::{EncryptionKey, StorageOption};
 pub type Salt = [u8; 16];
 const PLAINTEXT_HEADER_SIZE: usize = 32;
 const SALT_FILE_NAME: &str = "sqlcipher_salt";
+const SWAP_FILE_NAME: &str = "swap";
 
 // For PRAGMA query log statements
 #[derive(QueryableByName, Debug)]
@@ -40,6 +41,17 @@ struct CipherProviderVersion {
     cipher_provider_version: String,
 }
 
+// Result of PRAGMA wal_checkpoint
+#[derive(QueryableByName, Debug)]
+struct Checkpoint {
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    busy: i32,
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    log: i32,
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    checkpointed: i32,
+}
+
 /// Specialized Connection for r2d2 connection pool.
 #[derive(Clone, Debug, Builder, zeroize::ZeroizeOnDrop)]
 pub struct EncryptedConnection {
@@ -57,6 +69,7 @@ impl EncryptedConnection {
         let salt = match opts {
             Ephemeral => None,
             Persistent(db_path) => {
+                Self::recover_swap(db_path)?;
                 {
                     let mut conn = SqliteConnection::establish(db_path)?;
                     Self::check_for_sqlcipher(opts, &mut conn)?;
@@ -140,7 +153,7 @@ impl EncryptedConnection {
             pragma_plaintext_header()
         ))?;
 
-        Self::write_salt(path, conn, salt)?;
+        Self::write_salt(path, conn, "main", salt)?;
         Ok(())
     }
 
@@ -165,7 +178,7 @@ impl EncryptedConnection {
         ))?;
 
         // get the salt and save it for later use
-        Self::write_salt(path, conn, salt)?;
+        Self::write_salt(path, conn, "main", salt)?;
 
         conn.batch_execute(&format!(
             r#"
@@ -178,14 +191,111 @@ impl EncryptedConnection {
         Ok(())
     }
 
-    /// Get the salt from the opened database, write it to `Self::salt_file(db_path)` as hex-encoded
+    /// Copy the database of `source` into a new file encrypted with `key` and swap it in place of
+    /// the original database, along with its salt file.
+    ///
+    /// The original is only replaced by a rename once the copy is complete, so a failure or crash
+    /// during the copy leaves it untouched. A crash between renaming the database and its salt is
+    /// finished by [`Self::recover_swap`] the next time the database is opened. Until the rename
+    /// the database is encrypted with its old key and afterwards with `key`, so after a crash it
+    /// has to be opened with a fallback key, see [`NativeDb::rekey`](super::NativeDb::rekey).
+    pub(super) fn export_and_swap(
+        source: &dyn XmtpConnection,
+        key: EncryptionKey,
+    ) -> Result<Self, PlatformStorageError> {
+        let StorageOption::Persistent(path) = source.options() else {
+            return Err(PlatformStorageError::RekeyRequiresPath);
+        };
+        Self::recover_swap(path)?;
+        let swap_path = Self::swap_file(path);
+
+        // Hold the write lock while the database is copied, so nothing is written to the
+        // original that the copy would miss.
+        let mut lock = SqliteConnection::establish(path)?;
+        source.validate(&mut lock)?;
+        lock.batch_execute("BEGIN IMMEDIATE;")?;
+
+        let mut salt = [0u8; 16];
+        {
+            let conn = &mut SqliteConnection::establish(path)?;
+            source.validate(conn)?;
+            // the write-ahead log is deleted below, so all of it must be in the database file
+            let Checkpoint {
+                busy,
+                log,
+                checkpointed,
+            } = sql_query("PRAGMA wal_checkpoint(PASSIVE)").get_result::<Checkpoint>(conn)?;
+            if busy != 0 || log != checkpointed {
+                return Err(PlatformStorageError::CheckpointIncomplete { log, checkpointed });
+            }
+            conn.batch_execute(&format!(
+                r#"
+                ATTACH DATABASE '{}' AS swap KEY "x'{}'";
+                PRAGMA swap.cipher_plaintext_header_size={PLAINTEXT_HEADER_SIZE};
+                SELECT sqlcipher_export('swap');
+                "#,
+                swap_path.replace('\'', "''"),
+                hex::encode(&key)
+            ))?;
+            Self::write_salt(&swap_path, conn, "swap", &mut salt)?;
+            conn.batch_execute("DETACH DATABASE swap;")?;
+        }
+
+        // the files can't be replaced while a connection has them open on every platform
+        lock.batch_execute("ROLLBACK;")?;
+        drop(lock);
+
+        // the write-ahead log of the original must not be replayed onto the copy
+        for suffix in ["-wal", "-shm"] {
+            remove_if_exists(format!("{path}{suffix}"))?;
+        }
+        tracing::info!("swapping re-encrypted database [{swap_path}] into [{path}]");
+        std::fs::rename(&swap_path, path)?;
+        remove_if_exists(Self::salt_file(path)?)?;
+        std::fs::rename(Self::salt_file(&swap_path)?, Self::salt_file(path)?)?;
+
+        Ok(Self {
+            key,
+            salt: Some(salt),
+            options: source.options().clone(),
+        })
+    }
+
+    /// Clean up after a crash in [`Self::export_and_swap`].
+    ///
+    /// If the copy still exists it was never swapped in and the original is intact, so the copy is
+    /// discarded. If only the salt of the copy exists, the copy was swapped in and its salt is
+    /// moved next to it.
+    fn recover_swap(path: &str) -> Result<(), PlatformStorageError> {
+        let swap_path = Self::swap_file(path);
+        let swap_salt = Self::salt_file(&swap_path)?;
+        if Path::new(&swap_path).try_exists()? {
+            tracing::warn!("discarding incomplete re-encrypted database [{swap_path}]");
+            remove_if_exists(&swap_path)?;
+            remove_if_exists(format!("{swap_path}-journal"))?;
+            remove_if_exists(swap_salt)?;
+        } else if swap_salt.try_exists()? {
+            tracing::warn!("finishing interrupted swap of re-encrypted database [{path}]");
+            remove_if_exists(Self::salt_file(path)?)?;
+            std::fs::rename(swap_salt, Self::salt_file(path)?)?;
+        }
+        Ok(())
+    }
+
+    /// The re-encrypted copy of a database is written next to it as `{db_file_name}.SWAP_FILE_NAME`
+    fn swap_file(db_path: &str) -> String {
+        format!("{db_path}.{SWAP_FILE_NAME}")
+    }
+
+    /// Get the salt of the `schema` database, write it to `Self::salt_file(db_path)` as hex-encoded
     /// bytes, and then copy it to `buf` after decoding hex bytes.
     fn write_salt(
         path: &String,
         conn: &mut SqliteConnection,
+        schema: &str,
         buf: &mut [u8],
     ) -> Result<(), PlatformStorageError> {
-        let mut row_iter = conn.load(sql_query("PRAGMA cipher_salt"))?;
+        let mut row_iter = conn.load(sql_query(format!("PRAGMA {schema}.cipher_salt")))?;
         // cipher salt should always exist. if it doesn't SQLCipher is misconfigured.
         let row = row_iter
             .next()
@@ -268,9 +378,13 @@ impl ConnectionOptions for EncryptedConnection {
     fn options(&self) -> &StorageOption {
         &self.options
     }
+
+    fn is_encrypted(&self) -> bool {
+        true
+    }
 }
 
-impl super::ValidatedConnection for EncryptedConnection {
+impl ValidatedConnection for EncryptedConnection {
     fn validate(&self, conn: &mut SqliteConnection) -> Result<(), PlatformStorageError> {
         let sqlcipher_version = EncryptedConnection::check_for_sqlcipher(&self.options, conn)?;
 
@@ -321,6 +435,13 @@ impl diesel::r2d2::CustomizeConnection<SqliteConnection, diesel::r2d2::Error>
     }
 }
 
+fn remove_if_exists(path: impl AsRef<Path>) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
 fn pragma_key(key: impl Display) -> impl Display {
     format!(r#"PRAGMA key = "x'{key}'";"#)
 }
@@ -0,0 +1,261 @@
+use super::ConnectionExt;
+use crate::DbConnection;
+use crate::schema::drafts::{self, dsl};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+use xmtp_proto::ConversionError;
+use xmtp_proto::types::GroupId;
+use xmtp_proto::xmtp::device_sync::draft_backup::DraftSave;
+
+#[derive(
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    Insertable,
+    Identifiable,
+    Queryable,
+    Eq,
+    PartialEq,
+    QueryableByName,
+)]
+#[diesel(table_name = drafts)]
+#[diesel(primary_key(group_id))]
+/// An unsent message draft. Drafts are local to this installation and are
+/// never published to the network.
+pub struct StoredDraft {
+    /// The conversation the draft belongs to. Each conversation has at most one draft.
+    pub group_id: GroupId,
+    /// Serialized `EncodedContent` of the draft.
+    pub content: Vec<u8>,
+    /// The message the draft replies to, if any.
+    pub reply_to_message_id: Option<Vec<u8>>,
+    /// Time in nanoseconds the draft was last changed.
+    pub updated_at_ns: i64,
+}
+
+pub trait QueryDrafts {
+    /// Insert the draft, replacing any existing draft for the conversation.
+    fn upsert_draft(&self, draft: &StoredDraft) -> Result<(), crate::ConnectionError>;
+
+    fn get_draft(&self, group_id: &GroupId) -> Result<Option<StoredDraft>, crate::ConnectionError>;
+
+    /// Returns true if a draft was deleted.
+    fn delete_draft(&self, group_id: &GroupId) -> Result<bool, crate::ConnectionError>;
+
+    /// Store the draft unless the conversation already has one that is at least
+    /// as recent. Returns true if the draft was stored.
+    fn insert_newer_draft(&self, draft: &StoredDraft) -> Result<bool, crate::ConnectionError>;
+
+    /// Page through drafts for archiving. The group filters behave like
+    /// `GroupQueryArgs::group_ids` and `GroupQueryArgs::exclude_group_ids`.
+    fn drafts_paged(
+        &self,
+        limit: i64,
+        offset: i64,
+        changed_after_ns: Option<i64>,
+        group_ids: Option<Vec<Vec<u8>>>,
+        exclude_group_ids: Option<Vec<Vec<u8>>>,
+    ) -> Result<Vec<StoredDraft>, crate::ConnectionError>;
+}
+
+impl<T> QueryDrafts for &T
+where
+    T: QueryDrafts,
+{
+    fn upsert_draft(&self, draft: &StoredDraft) -> Result<(), crate::ConnectionError> {
+        (**self).upsert_draft(draft)
+    }
+
+    fn get_draft(&self, group_id: &GroupId) -> Result<Option<StoredDraft>, crate::ConnectionError> {
+        (**self).get_draft(group_id)
+    }
+
+    fn delete_draft(&self, group_id: &GroupId) -> Result<bool, crate::ConnectionError> {
+        (**self).delete_draft(group_id)
+    }
+
+    fn insert_newer_draft(&self, draft: &StoredDraft) -> Result<bool, crate::ConnectionError> {
+        (**self).insert_newer_draft(draft)
+    }
+
+    fn drafts_paged(
+        &self,
+        limit: i64,
+        offset: i64,
+        changed_after_ns: Option<i64>,
+        group_ids: Option<Vec<Vec<u8>>>,
+        exclude_group_ids: Option<Vec<Vec<u8>>>,
+    ) -> Result<Vec<StoredDraft>, crate::ConnectionError> {
+        (**self).drafts_paged(
+            limit,
+            offset,
+            changed_after_ns,
+            group_ids,
+            exclude_group_ids,
+        )
+    }
+}
+
+impl<C: ConnectionExt> QueryDrafts for DbConnection<C> {
+    fn upsert_draft(&self, draft: &StoredDraft) -> Result<(), crate::ConnectionError> {
+        self.raw_query(|conn| {
+            diesel::replace_into(dsl::drafts)
+                .values(draft)
+                .execute(conn)
+        })?;
+        Ok(())
+    }
+
+    fn get_draft(&self, group_id: &GroupId) -> Result<Option<StoredDraft>, crate::ConnectionError> {
+        self.raw_query(|conn| {
+            dsl::drafts
+                .filter(dsl::group_id.eq(group_id))
+                .first(conn)
+                .optional()
+        })
+    }
+
+    fn delete_draft(&self, group_id: &GroupId) -> Result<bool, crate::ConnectionError> {
+        let deleted = self.raw_query(|conn| {
+            diesel::delete(dsl::drafts.filter(dsl::group_id.eq(group_id))).execute(conn)
+        })?;
+        Ok(deleted > 0)
+    }
+
+    fn insert_newer_draft(&self, draft: &StoredDraft) -> Result<bool, crate::ConnectionError> {
+        let updated = self.raw_query(|conn| {
+            diesel::insert_into(dsl::drafts)
+                .values(draft)
+                .on_conflict(dsl::group_id)
+                .do_update()
+                .set((
+                    dsl::content.eq(excluded(dsl::content)),
+                    dsl::reply_to_message_id.eq(excluded(dsl::reply_to_message_id)),
+                    dsl::updated_at_ns.eq(excluded(dsl::updated_at_ns)),
+                ))
+                .filter(dsl::updated_at_ns.lt(excluded(dsl::updated_at_ns)))
+                .execute(conn)
+        })?;
+        Ok(updated > 0)
+    }
+
+    fn drafts_paged(
+        &self,
+        limit: i64,
+        offset: i64,
+        changed_after_ns: Option<i64>,
+        group_ids: Option<Vec<Vec<u8>>>,
+        exclude_group_ids: Option<Vec<Vec<u8>>>,
+    ) -> Result<Vec<StoredDraft>, crate::ConnectionError> {
+        let mut query = drafts::table
+            .order_by(drafts::group_id)
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+        if let Some(changed_after_ns) = changed_after_ns {
+            query = query.filter(drafts::updated_at_ns.gt(changed_after_ns));
+        }
+        if let Some(group_ids) = group_ids {
+            query = query.filter(drafts::group_id.eq_any(group_ids));
+        }
+        if let Some(exclude_group_ids) = exclude_group_ids {
+            query = query.filter(drafts::group_id.ne_all(exclude_group_ids));
+        }
+
+        self.raw_query(|conn| query.load::<StoredDraft>(conn))
+    }
+}
+
+impl TryFrom<DraftSave> for StoredDraft {
+    type Error = ConversionError;
+    fn try_from(value: DraftSave) -> Result<Self, Self::Error> {
+        Ok(Self {
+            group_id: value.group_id.try_into()?,
+            content: value.content,
+            reply_to_message_id: value.reply_to_message_id,
+            updated_at_ns: value.updated_at_ns,
+        })
+    }
+}
+
+impl From<StoredDraft> for DraftSave {
+    fn from(value: StoredDraft) -> Self {
+        Self {
+            group_id: value.group_id.to_vec(),
+            content: value.content,
+            reply_to_message_id: value.reply_to_message_id,
+            updated_at_ns: value.updated_at_ns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::with_connection;
+
+    fn draft(group_id: GroupId, content: &[u8], updated_at_ns: i64) -> StoredDraft {
+        StoredDraft {
+            group_id,
+            content: content.to_vec(),
+            reply_to_message_id: None,
+            updated_at_ns,
+        }
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_upsert_get_delete_draft() {
+        with_connection(|conn| {
+            assert!(conn.get_draft(&GroupId::ONE)?.is_none());
+
+            conn.upsert_draft(&draft(GroupId::ONE, b"hello", 1))?;
+            let mut replacement = draft(GroupId::ONE, b"hello again", 2);
+            replacement.reply_to_message_id = Some(vec![9]);
+            conn.upsert_draft(&replacement)?;
+
+            assert_eq!(conn.get_draft(&GroupId::ONE)?, Some(replacement));
+            assert!(conn.get_draft(&GroupId::TWO)?.is_none());
+
+            assert!(conn.delete_draft(&GroupId::ONE)?);
+            assert!(!conn.delete_draft(&GroupId::ONE)?);
+            assert!(conn.get_draft(&GroupId::ONE)?.is_none());
+        })
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_insert_newer_draft_keeps_latest() {
+        with_connection(|conn| {
+            assert!(conn.insert_newer_draft(&draft(GroupId::ONE, b"local", 10))?);
+            assert!(!conn.insert_newer_draft(&draft(GroupId::ONE, b"older", 5))?);
+            assert_eq!(conn.get_draft(&GroupId::ONE)??.content, b"local");
+
+            assert!(conn.insert_newer_draft(&draft(GroupId::ONE, b"newer", 20))?);
+            assert_eq!(conn.get_draft(&GroupId::ONE)??.content, b"newer");
+        })
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_drafts_paged_since() {
+        with_connection(|conn| {
+            conn.upsert_draft(&draft(GroupId::ONE, b"one", 10))?;
+            conn.upsert_draft(&draft(GroupId::TWO, b"two", 20))?;
+
+            assert_eq!(conn.drafts_paged(10, 0, None, None, None)?.len(), 2);
+            assert_eq!(conn.drafts_paged(1, 1, None, None, None)?.len(), 1);
+            let changed = conn.drafts_paged(10, 0, Some(15), None, None)?;
+            assert_eq!(changed.len(), 1);
+            assert_eq!(changed[0].group_id, GroupId::TWO);
+
+            let included =
+                conn.drafts_paged(10, 0, None, Some(vec![GroupId::ONE.to_vec()]), None)?;
+            assert_eq!(included.len(), 1);
+            assert_eq!(included[0].group_id, GroupId::ONE);
+            let excluded =
+                conn.drafts_paged(10, 0, None, None, Some(vec![GroupId::ONE.to_vec()]))?;
+            assert_eq!(excluded.len(), 1);
+            assert_eq!(excluded[0].group_id, GroupId::TWO);
+        })
+    }
+}
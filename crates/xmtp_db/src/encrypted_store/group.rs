@@ -1,8 +1,10 @@
 //! The Group database table. Stored information surrounding group membership and ID's.
 use super::{
     ConnectionExt, Sqlite,
-    consent_record::ConsentState,
+    consent_record::{ConsentState, conversation_consent_predicate},
+    conversation_settings::conversation_settings_predicate,
     db_connection::DbConnection,
+    schema::group_messages,
     schema::groups::{self, dsl},
 };
 use crate::NotFound;
@@ -214,6 +216,18 @@ pub struct GroupQueryArgs {
     pub include_duplicate_dms: bool,
     pub should_publish_commit_log: Option<bool>,
     pub order_by: Option<GroupQueryOrderBy>,
+    /// Only return groups with one of these ids
+    pub group_ids: Option<Vec<Vec<u8>>>,
+    /// Never return groups with one of these ids
+    pub exclude_group_ids: Option<Vec<Vec<u8>>>,
+    /// Only return groups created, or with messages inserted, after this time
+    pub changed_after_ns: Option<i64>,
+    /// Only return archived (`true`) or unarchived (`false`) conversations
+    pub archived: Option<bool>,
+    /// Only return pinned (`true`) or unpinned (`false`) conversations
+    pub pinned: Option<bool>,
+    /// Only return conversations that are (`true`) or are not (`false`) muted right now
+    pub muted: Option<bool>,
 }
 
 impl AsRef<GroupQueryArgs> for GroupQueryArgs {
@@ -582,6 +596,10 @@ impl<C: ConnectionExt> QueryGroup for DbConnection<C> {
             last_activity_before_ns,
             should_publish_commit_log,
             order_by,
+            group_ids,
+            exclude_group_ids,
+            changed_after_ns,
+            ..
         } = args.as_ref();
 
         let order_expression = match order_by.clone().unwrap_or_default() {
@@ -651,6 +669,22 @@ impl<C: ConnectionExt> QueryGroup for DbConnection<C> {
             query = query.filter(dsl::conversation_type.eq(conversation_type));
         }
 
+        if let Some(group_ids) = group_ids {
+            query = query.filter(dsl::id.eq_any(group_ids));
+        }
+
+        if let Some(exclude_group_ids) = exclude_group_ids {
+            query = query.filter(dsl::id.ne_all(exclude_group_ids));
+        }
+
+        if let Some(changed_after_ns) = changed_after_ns {
+            query = query.filter(changed_after(*changed_after_ns));
+        }
+
+        if let Some(predicate) = conversation_settings_predicate("groups.id", args.as_ref()) {
+            query = query.filter(predicate);
+        }
+
         let effective_consent_states = match &consent_states {
             Some(states) if !states.is_empty() => states.clone(),
             _ => vec![ConsentState::Allowed, ConsentState::Unknown],
@@ -720,6 +754,10 @@ impl<C: ConnectionExt> QueryGroup for DbConnection<C> {
             created_after_ns,
             created_before_ns,
             limit,
+            consent_states,
+            group_ids,
+            exclude_group_ids,
+            changed_after_ns,
             ..
         } = args.as_ref();
 
@@ -734,6 +772,21 @@ impl<C: ConnectionExt> QueryGroup for DbConnection<C> {
         if let Some(end_ns) = created_before_ns {
             query = query.filter(groups::created_at_ns.le(end_ns));
         }
+        if let Some(group_ids) = group_ids {
+            query = query.filter(groups::id.eq_any(group_ids));
+        }
+        if let Some(exclude_group_ids) = exclude_group_ids {
+            query = query.filter(groups::id.ne_all(exclude_group_ids));
+        }
+        if let Some(changed_after_ns) = changed_after_ns {
+            query = query.filter(changed_after(*changed_after_ns));
+        }
+        if let Some(predicate) = consent_states
+            .as_deref()
+            .and_then(|states| conversation_consent_predicate("groups.id", states))
+        {
+            query = query.filter(predicate);
+        }
 
         query = query.limit(limit.unwrap_or(100)).offset(offset);
 
@@ -1245,6 +1298,18 @@ impl<C: ConnectionExt> QueryGroup for DbConnection<C> {
     }
 }
 
+/// Groups created, or with any message inserted, after `changed_after_ns`
+fn changed_after(
+    changed_after_ns: i64,
+) -> impl BoxableExpression<groups::table, Sqlite, SqlType = diesel::sql_types::Bool>
++ diesel::expression::NonAggregate {
+    dsl::created_at_ns.gt(changed_after_ns).or(dsl::id.eq_any(
+        group_messages::table
+            .filter(group_messages::inserted_at_ns.gt(changed_after_ns))
+            .select(group_messages::group_id),
+    ))
+}
+
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, AsExpression, FromSqlRow)]
 #[diesel(sql_type = Integer)]
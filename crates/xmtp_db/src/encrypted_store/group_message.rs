@@ -1,4 +1,5 @@
 use super::ConnectionExt;
+use super::consent_record::{ConsentState, conversation_consent_predicate};
 use super::group::ConversationType;
 use super::schema::groups;
 use super::{
@@ -24,9 +25,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use xmtp_common::{NS_IN_DAY, time::now_ns};
 use xmtp_content_types::{
-    actions, attachment, delete_message, group_updated, intent, leave_request, markdown,
-    membership_change, multi_remote_attachment, reaction, read_receipt, remote_attachment, reply,
-    text, transaction_reference, wallet_send_calls,
+    actions, attachment, delete_message, edit_message, group_updated, intent, leave_request,
+    markdown, membership_change, multi_remote_attachment, poll, reaction, read_receipt,
+    remote_attachment, reply, text, transaction_reference, wallet_send_calls,
 };
 use xmtp_proto::types::{Cursor, GroupId};
 
@@ -84,12 +85,29 @@ pub struct StoredGroupMessage {
     /// The idempotency key the message id is derived from. Defaults to the send
     /// timestamp, but callers may supply their own to make retries idempotent.
     pub idempotency_key: String,
+    /// Sender-requested lifetime of this message, counted from when it was sent.
+    /// Folded into `expire_at_ns` once the message is published.
+    pub expire_after_ns: Option<i64>,
+    /// Whether the message is deleted on the receiver once it has been viewed.
+    pub view_once: bool,
 }
 
 impl StoredGroupMessage {
     pub fn cursor(&self) -> Cursor {
         Cursor::new(self.sequence_id as u64, self.originator_id as u32)
     }
+
+    /// Whether this message is an EditMessage that may replace the content of `original`.
+    ///
+    /// Only the original sender can edit a message, and only within the same group.
+    pub fn is_valid_edit_of(&self, original: &StoredGroupMessage) -> bool {
+        self.content_type == ContentType::EditMessage
+            && self.reference_id.as_deref() == Some(original.id.as_slice())
+            && self.group_id == original.group_id
+            && self.sender_inbox_id == original.sender_inbox_id
+            && original.kind.is_editable()
+            && original.content_type.is_editable()
+    }
 }
 
 // Separate Insertable struct that excludes inserted_at_ns to let the database set it
@@ -115,6 +133,8 @@ struct NewStoredGroupMessage {
     pub expire_at_ns: Option<i64>,
     pub should_push: bool,
     pub idempotency_key: String,
+    pub expire_after_ns: Option<i64>,
+    pub view_once: bool,
 }
 
 impl From<&StoredGroupMessage> for NewStoredGroupMessage {
@@ -138,6 +158,8 @@ impl From<&StoredGroupMessage> for NewStoredGroupMessage {
             expire_at_ns: msg.expire_at_ns,
             should_push: msg.should_push,
             idempotency_key: msg.idempotency_key.clone(),
+            expire_after_ns: msg.expire_after_ns,
+            view_once: msg.view_once,
         }
     }
 }
@@ -210,6 +232,21 @@ impl Deletable for GroupMessageKind {
     }
 }
 
+/// Trait for determining if a message can be edited by its sender.
+pub trait Editable {
+    /// Returns whether this message can be edited by its sender.
+    fn is_editable(&self) -> bool;
+}
+
+impl Editable for GroupMessageKind {
+    fn is_editable(&self) -> bool {
+        match self {
+            GroupMessageKind::Application => true,
+            GroupMessageKind::MembershipChange => false,
+        }
+    }
+}
+
 //Legacy content types found at https://github.com/xmtp/xmtp-js/tree/main/content-types
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, FromSqlRow, AsExpression)]
@@ -232,6 +269,9 @@ pub enum ContentType {
     Intent = 14,
     MultiRemoteAttachment = 15,
     DeleteMessage = 16,
+    EditMessage = 17,
+    Poll = 18,
+    PollVote = 19,
 }
 
 impl ContentType {
@@ -254,6 +294,9 @@ impl ContentType {
             ContentType::Intent,
             ContentType::MultiRemoteAttachment,
             ContentType::DeleteMessage,
+            ContentType::EditMessage,
+            ContentType::Poll,
+            ContentType::PollVote,
         ]
     }
 }
@@ -269,6 +312,8 @@ impl Deletable for ContentType {
             | ContentType::Actions
             | ContentType::Intent
             | ContentType::DeleteMessage
+            | ContentType::EditMessage
+            | ContentType::PollVote
             // Unknown content types default to non-deletable for safety
             |ContentType::Unknown => false,
 
@@ -279,11 +324,19 @@ impl Deletable for ContentType {
             | ContentType::RemoteAttachment
             | ContentType::TransactionReference
             | ContentType::MultiRemoteAttachment
-            | ContentType::WalletSendCalls => true,
+            | ContentType::WalletSendCalls
+            | ContentType::Poll => true,
         }
     }
 }
 
+impl Editable for ContentType {
+    fn is_editable(&self) -> bool {
+        // Only plain text content can be edited; the edited content must be editable as well
+        matches!(self, ContentType::Text | ContentType::Markdown)
+    }
+}
+
 impl std::fmt::Display for ContentType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let as_string = match self {
@@ -306,6 +359,9 @@ impl std::fmt::Display for ContentType {
                 multi_remote_attachment::MultiRemoteAttachmentCodec::TYPE_ID
             }
             Self::DeleteMessage => delete_message::DeleteMessageCodec::TYPE_ID,
+            Self::EditMessage => edit_message::EditMessageCodec::TYPE_ID,
+            Self::Poll => poll::PollCodec::TYPE_ID,
+            Self::PollVote => poll::PollVoteCodec::TYPE_ID,
         };
 
         write!(f, "{}", as_string)
@@ -333,6 +389,9 @@ impl From<String> for ContentType {
                 Self::MultiRemoteAttachment
             }
             delete_message::DeleteMessageCodec::TYPE_ID => Self::DeleteMessage,
+            edit_message::EditMessageCodec::TYPE_ID => Self::EditMessage,
+            poll::PollCodec::TYPE_ID => Self::Poll,
+            poll::PollVoteCodec::TYPE_ID => Self::PollVote,
             _ => Self::Unknown,
         }
     }
@@ -371,6 +430,9 @@ where
             14 => Ok(ContentType::Intent),
             15 => Ok(ContentType::MultiRemoteAttachment),
             16 => Ok(ContentType::DeleteMessage),
+            17 => Ok(ContentType::EditMessage),
+            18 => Ok(ContentType::Poll),
+            19 => Ok(ContentType::PollVote),
             x => Err(format!("Unrecognized variant {}", x).into()),
         }
     }
@@ -422,8 +484,8 @@ where
         into.raw_query::<_, _>(|conn| {
             diesel::insert_into(group_messages::table)
                 .values(&new_msg)
-                .execute(conn)
-                .map(|_| ())
+                .execute(conn)?;
+            super::message_search::index_message(conn, self)
         })
         .map_err(Into::into)
     }
@@ -439,10 +501,13 @@ where
     fn store_or_ignore(&self, into: &C) -> Result<(), crate::StorageError> {
         let new_msg = NewStoredGroupMessage::from(self);
         into.raw_query(|conn| {
-            diesel::insert_or_ignore_into(group_messages::table)
+            let inserted = diesel::insert_or_ignore_into(group_messages::table)
                 .values(&new_msg)
-                .execute(conn)
-                .map(|_| ())
+                .execute(conn)?;
+            if inserted > 0 {
+                super::message_search::index_message(conn, self)?;
+            }
+            Ok(())
         })
         .map_err(Into::into)
     }
@@ -477,6 +542,16 @@ pub struct MsgQueryArgs {
     pub inserted_before_ns: Option<i64>,
     #[builder(default = false)]
     pub exclude_disappearing: bool,
+    /// Only match messages in these groups. Honored by queries spanning groups.
+    #[builder(default = None)]
+    pub group_ids: Option<Vec<Vec<u8>>>,
+    /// Skip messages in these groups. Honored by queries spanning groups.
+    #[builder(default = None)]
+    pub exclude_group_ids: Option<Vec<Vec<u8>>>,
+    /// Only match messages in conversations with one of these consent states.
+    /// Honored by queries spanning groups.
+    #[builder(default = None)]
+    pub group_consent_states: Option<Vec<ConsentState>>,
 }
 
 impl MsgQueryArgs {
@@ -867,6 +942,7 @@ macro_rules! apply_message_filters {
         query
     }};
 }
+pub(crate) use apply_message_filters;
 
 impl<C: ConnectionExt> QueryGroupMessage for DbConnection<C> {
     /// Query for group messages
@@ -993,6 +1069,10 @@ impl<C: ConnectionExt> QueryGroupMessage for DbConnection<C> {
             sent_before_ns,
             limit,
             exclude_disappearing,
+            group_ids,
+            exclude_group_ids,
+            group_consent_states,
+            inserted_after_ns,
             ..
         } = args;
 
@@ -1003,14 +1083,33 @@ impl<C: ConnectionExt> QueryGroupMessage for DbConnection<C> {
             .order_by(group_messages::id)
             .into_boxed();
 
+        if let Some(group_ids) = group_ids {
+            query = query.filter(group_messages::group_id.eq_any(group_ids));
+        }
+        if let Some(exclude_group_ids) = exclude_group_ids {
+            query = query.filter(group_messages::group_id.ne_all(exclude_group_ids));
+        }
+        if let Some(predicate) = group_consent_states
+            .as_deref()
+            .and_then(|states| conversation_consent_predicate("group_messages.group_id", states))
+        {
+            query = query.filter(predicate);
+        }
+
         if let Some(start_ns) = sent_after_ns {
             query = query.filter(group_messages::sent_at_ns.gt(start_ns));
         }
         if let Some(end_ns) = sent_before_ns {
             query = query.filter(group_messages::sent_at_ns.le(end_ns));
         }
+        if let Some(inserted_after_ns) = inserted_after_ns {
+            query = query.filter(group_messages::inserted_at_ns.gt(inserted_after_ns));
+        }
         if *exclude_disappearing {
-            query = query.filter(group_messages::expire_at_ns.is_null());
+            // View-once messages disappear too, once they have been viewed
+            query = query
+                .filter(group_messages::expire_at_ns.is_null())
+                .filter(group_messages::view_once.eq(false));
         } else {
             // Always exclude expired messages (expire_at_ns < now)
             let current_time = now_ns();
@@ -41,6 +41,8 @@ impl TryFrom<GroupMessageSave> for StoredGroupMessage {
             // the historical default (the send timestamp). Restored messages are
             // already published, so this value is only informational.
             idempotency_key: value.sent_at_ns.to_string(),
+            expire_after_ns: None,
+            view_once: false,
         })
     }
 }
@@ -30,6 +30,8 @@ fn generate_message_with_cursor(
         expire_at_ns: None,
         should_push: true,
         idempotency_key: sent_at_ns.to_string(),
+        expire_after_ns: None,
+        view_once: false,
     }
 }
 
@@ -30,6 +30,8 @@ pub(crate) fn generate_message(
         inserted_at_ns: 0, // Will be set by database
         should_push: true,
         idempotency_key: String::new(),
+        expire_after_ns: None,
+        view_once: false,
     }
 }
 
@@ -599,6 +601,8 @@ pub(crate) fn generate_message_with_reference<C: ConnectionExt>(
         inserted_at_ns: 0, // Will be set by database
         should_push: true,
         idempotency_key: sent_at_ns.to_string(),
+        expire_after_ns: None,
+        view_once: false,
     };
     message.store(conn).unwrap();
     message
@@ -2701,6 +2705,7 @@ fn test_content_type_is_deletable() {
     assert!(ContentType::RemoteAttachment.is_deletable());
     assert!(ContentType::TransactionReference.is_deletable());
     assert!(ContentType::WalletSendCalls.is_deletable());
+    assert!(ContentType::Poll.is_deletable());
 
     // System messages should NOT be deletable
     assert!(!ContentType::GroupMembershipChange.is_deletable());
@@ -2714,6 +2719,12 @@ fn test_content_type_is_deletable() {
     // Delete messages should NOT be deletable (prevents recursive deletion)
     assert!(!ContentType::DeleteMessage.is_deletable());
 
+    // Edits are not deletable on their own; deleting the original hides every edit
+    assert!(!ContentType::EditMessage.is_deletable());
+
+    // Votes are removed by deleting the poll they reference
+    assert!(!ContentType::PollVote.is_deletable());
+
     // Unknown content types should NOT be deletable for safety
     // (we don't know if they're system messages that shouldn't be deleted)
     assert!(!ContentType::Unknown.is_deletable());
@@ -31,6 +31,7 @@ pub struct IdentityCache {
 pub enum StoredIdentityKind {
     Ethereum = 1,
     Passkey = 2,
+    Solana = 3,
 }
 
 impl TryFrom<IdentifierKind> for StoredIdentityKind {
@@ -39,6 +40,7 @@ impl TryFrom<IdentifierKind> for StoredIdentityKind {
         match kind {
             IdentifierKind::Ethereum => Ok(StoredIdentityKind::Ethereum),
             IdentifierKind::Passkey => Ok(StoredIdentityKind::Passkey),
+            IdentifierKind::Solana => Ok(StoredIdentityKind::Solana),
             IdentifierKind::Unspecified => {
                 Err(ConversionError::Unspecified("IdentifierKind::Unspecified"))
             }
@@ -53,9 +55,10 @@ impl TryFrom<i32> for StoredIdentityKind {
         match value {
             1 => Ok(StoredIdentityKind::Ethereum),
             2 => Ok(StoredIdentityKind::Passkey),
+            3 => Ok(StoredIdentityKind::Solana),
             v => Err(ConversionError::InvalidValue {
                 item: type_name::<StoredIdentityKind>(),
-                expected: "a integer value of `1` or `2`",
+                expected: "a integer value of `1`, `2` or `3`",
                 got: v.to_string(),
             }),
         }
@@ -68,6 +71,7 @@ impl From<&StoredIdentityKind> for i32 {
         match value {
             Ethereum => 1,
             Passkey => 2,
+            Solana => 3,
         }
     }
 }
@@ -78,6 +82,7 @@ impl From<StoredIdentityKind> for IdentifierKind {
         match value {
             Ethereum => IdentifierKind::Ethereum,
             Passkey => IdentifierKind::Passkey,
+            Solana => IdentifierKind::Solana,
         }
     }
 }
@@ -180,6 +185,7 @@ where
         match i32::from_sql(bytes)? {
             1 => Ok(Self::Ethereum),
             2 => Ok(Self::Passkey),
+            3 => Ok(Self::Solana),
             x => Err(format!("Unrecognized variant {}", x).into()),
         }
     }
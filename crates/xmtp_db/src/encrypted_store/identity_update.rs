@@ -69,6 +69,16 @@ pub trait QueryIdentityUpdates {
         inbox_id: &str,
     ) -> Result<i64, crate::ConnectionError>;
 
+    /// Returns the sequence ID of the last identity update for `inbox_id` at or before the given
+    /// point, where the point is bounded by `max_sequence_id` and/or `max_timestamp_ns` (inclusive,
+    /// compared against the server timestamp). Returns `None` if no update matches.
+    fn get_sequence_id_at(
+        &self,
+        inbox_id: &str,
+        max_sequence_id: Option<i64>,
+        max_timestamp_ns: Option<i64>,
+    ) -> Result<Option<i64>, crate::ConnectionError>;
+
     /// Given a list of inbox_ids return a HashMap of each inbox ID -> highest known sequence ID
     fn get_latest_sequence_id(
         &self,
@@ -109,6 +119,15 @@ where
         (**self).get_latest_sequence_id_for_inbox(inbox_id)
     }
 
+    fn get_sequence_id_at(
+        &self,
+        inbox_id: &str,
+        max_sequence_id: Option<i64>,
+        max_timestamp_ns: Option<i64>,
+    ) -> Result<Option<i64>, crate::ConnectionError> {
+        (**self).get_sequence_id_at(inbox_id, max_sequence_id, max_timestamp_ns)
+    }
+
     fn get_latest_sequence_id(
         &self,
         inbox_ids: &[&str],
@@ -177,6 +196,28 @@ impl<C: ConnectionExt> QueryIdentityUpdates for DbConnection<C> {
         self.raw_query(|conn| query.first::<i64>(conn))
     }
 
+    fn get_sequence_id_at(
+        &self,
+        inbox_id: &str,
+        max_sequence_id: Option<i64>,
+        max_timestamp_ns: Option<i64>,
+    ) -> Result<Option<i64>, crate::ConnectionError> {
+        let mut query = dsl::identity_updates
+            .select(max(dsl::sequence_id))
+            .filter(dsl::inbox_id.eq(inbox_id))
+            .into_boxed();
+
+        if let Some(sequence_id) = max_sequence_id {
+            query = query.filter(dsl::sequence_id.le(sequence_id));
+        }
+
+        if let Some(timestamp_ns) = max_timestamp_ns {
+            query = query.filter(dsl::server_timestamp_ns.le(timestamp_ns));
+        }
+
+        self.raw_query(|conn| query.first::<Option<i64>>(conn))
+    }
+
     /// Given a list of inbox_ids return a HashMap of each inbox ID -> highest known sequence ID
     #[tracing::instrument(level = "trace", skip_all)]
     fn get_latest_sequence_id(
@@ -345,6 +386,35 @@ pub(crate) mod tests {
         })
     }
 
+    #[xmtp_common::test]
+    fn test_get_sequence_id_at() {
+        with_connection(|conn| {
+            let inbox_id = "inbox_1";
+            let mut updates = vec![
+                build_update(inbox_id, 2),
+                build_update(inbox_id, 5),
+                build_update(inbox_id, 9),
+            ];
+            for (i, update) in updates.iter_mut().enumerate() {
+                update.server_timestamp_ns = (i as i64 + 1) * 1_000;
+            }
+            conn.insert_or_ignore_identity_updates(&updates).unwrap();
+
+            let at = |seq, ts| conn.get_sequence_id_at(inbox_id, seq, ts).unwrap();
+            assert_eq!(at(None, None), Some(9));
+            assert_eq!(at(Some(5), None), Some(5));
+            assert_eq!(at(Some(8), None), Some(5));
+            assert_eq!(at(Some(1), None), None);
+            assert_eq!(at(None, Some(2_500)), Some(5));
+            assert_eq!(at(None, Some(999)), None);
+            assert_eq!(at(Some(9), Some(1_000)), Some(2));
+            assert_eq!(
+                conn.get_sequence_id_at("missing", None, None).unwrap(),
+                None
+            );
+        })
+    }
+
     #[xmtp_common::test]
     fn test_count_inbox_updates() {
         with_connection(|conn| {
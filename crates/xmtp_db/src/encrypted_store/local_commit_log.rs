@@ -19,6 +19,8 @@ pub enum CommitType {
     /// commit and cannot derive the new epoch's secrets, so the logged entry
     /// records the pre-commit epoch and authenticator.
     RemovedFromGroup,
+    /// This installation joined the group by publishing an external commit.
+    ExternalJoin,
 }
 
 impl std::fmt::Display for CommitType {
@@ -33,6 +35,7 @@ impl std::fmt::Display for CommitType {
             CommitType::UpdateAdminList => "UpdateAdminList",
             CommitType::UpdatePermission => "UpdatePermission",
             CommitType::RemovedFromGroup => "RemovedFromGroup",
+            CommitType::ExternalJoin => "ExternalJoin",
         };
         write!(f, "{}", description)
     }
@@ -33,6 +33,8 @@ pub struct StoredMessageDeletion {
     pub is_super_admin_deletion: bool,
     /// Timestamp when the deletion was processed
     pub deleted_at_ns: i64,
+    /// Whether the deleter was allowed to delete as a moderator at deletion time
+    pub is_moderator_deletion: bool,
 }
 
 impl_store!(StoredMessageDeletion, message_deletions);
@@ -227,6 +229,8 @@ mod tests {
             inserted_at_ns: 0,
             should_push: false,
             idempotency_key: 1000.to_string(),
+            expire_after_ns: None,
+            view_once: false,
         }
         .store(conn)
         .unwrap();
@@ -250,6 +254,7 @@ mod tests {
                 deleted_by_inbox_id: "sender".to_string(),
                 is_super_admin_deletion: false,
                 deleted_at_ns: 2000,
+                is_moderator_deletion: false,
             };
 
             deletion.store(conn)?;
@@ -288,6 +293,7 @@ mod tests {
                 deleted_by_inbox_id: "sender".to_string(),
                 is_super_admin_deletion: false,
                 deleted_at_ns: 2000,
+                is_moderator_deletion: false,
             }
             .store(conn)?;
 
@@ -321,6 +327,7 @@ mod tests {
                 deleted_by_inbox_id: "sender".to_string(),
                 is_super_admin_deletion: false,
                 deleted_at_ns: 2000,
+                is_moderator_deletion: false,
             }
             .store(conn)?;
 
@@ -331,6 +338,7 @@ mod tests {
                 deleted_by_inbox_id: "admin".to_string(),
                 is_super_admin_deletion: true,
                 deleted_at_ns: 3000,
+                is_moderator_deletion: false,
             }
             .store(conn)?;
 
@@ -368,6 +376,7 @@ mod tests {
                 deleted_by_inbox_id: "sender".to_string(),
                 is_super_admin_deletion: false,
                 deleted_at_ns: 2000,
+                is_moderator_deletion: false,
             }
             .store(conn)?;
 
@@ -378,6 +387,7 @@ mod tests {
                 deleted_by_inbox_id: "sender".to_string(),
                 is_super_admin_deletion: false,
                 deleted_at_ns: 3000,
+                is_moderator_deletion: false,
             }
             .store(conn)?;
 
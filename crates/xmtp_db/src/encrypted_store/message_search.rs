@@ -0,0 +1,579 @@
+//! Local full-text search over decrypted message text.
+//!
+//! Searchable text is extracted from a message's `EncodedContent` when it is stored and written to
+//! `message_search_content`, which backs the `message_search` FTS5 index. Entries are removed by
+//! triggers when the message is deleted, expires, or is soft-deleted through a DeleteMessage.
+//! Messages that were edited by their sender are indexed by the text of their latest edit.
+
+use super::ConnectionExt;
+use super::group::ConversationType;
+use super::group_message::{
+    ContentType, Editable, GroupMessageKind, MsgQueryArgs, StoredGroupMessage,
+    apply_message_filters,
+};
+use super::schema::{
+    group_messages::{self, dsl},
+    groups::dsl as groups_dsl,
+    message_deletions, message_search, message_search_content,
+};
+use crate::DbConnection;
+use diesel::{
+    dsl::sql as diesel_sql,
+    expression::IntoSql,
+    prelude::*,
+    sql_types::{Double, Text},
+    sqlite::{Sqlite, SqliteConnection},
+    upsert::excluded,
+};
+use prost::Message;
+use xmtp_content_types::{
+    ContentCodec, edit_message::EditMessageCodec, markdown::MarkdownCodec, reply::ReplyCodec,
+    text::TextCodec,
+};
+use xmtp_proto::types::GroupId;
+use xmtp_proto::xmtp::mls::message_contents::EncodedContent;
+
+/// Marker inserted before each matched term in [`MessageSearchHit::snippet`]
+pub const SNIPPET_MATCH_START: &str = "<mark>";
+/// Marker inserted after each matched term in [`MessageSearchHit::snippet`]
+pub const SNIPPET_MATCH_END: &str = "</mark>";
+/// Maximum number of tokens in a snippet
+const SNIPPET_TOKENS: i32 = 16;
+/// Number of results returned when [`MsgQueryArgs::limit`] is not set
+const DEFAULT_SEARCH_LIMIT: i64 = 50;
+
+diesel::infix_operator!(Matches, " MATCH ", backend: Sqlite);
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = message_search_content)]
+struct NewMessageSearchEntry<'a> {
+    message_id: &'a [u8],
+    group_id: GroupId,
+    body: String,
+}
+
+/// A message matching a full-text search query
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageSearchHit {
+    pub message: StoredGroupMessage,
+    /// BM25 relevance of the hit. Lower is more relevant.
+    pub rank: f64,
+    /// Excerpt of the message text with matched terms wrapped in
+    /// [`SNIPPET_MATCH_START`] and [`SNIPPET_MATCH_END`]
+    pub snippet: String,
+}
+
+/// Extract the text that should be indexed for a message, if any.
+///
+/// Only application messages with a text-like content type are indexed. Replies are indexed by
+/// the text of their inner content.
+pub fn searchable_text(message: &StoredGroupMessage) -> Option<String> {
+    if message.kind != GroupMessageKind::Application {
+        return None;
+    }
+    if !matches!(
+        message.content_type,
+        ContentType::Text | ContentType::Markdown | ContentType::Reply
+    ) {
+        return None;
+    }
+    let encoded = EncodedContent::decode(message.decrypted_message_bytes.as_slice()).ok()?;
+    encoded_text(encoded).filter(|text| !text.trim().is_empty())
+}
+
+fn encoded_text(encoded: EncodedContent) -> Option<String> {
+    let type_id = encoded.r#type.as_ref()?.type_id.as_str();
+    match type_id {
+        TextCodec::TYPE_ID => TextCodec::decode(encoded).ok(),
+        MarkdownCodec::TYPE_ID => MarkdownCodec::decode(encoded).ok(),
+        ReplyCodec::TYPE_ID => encoded_text(ReplyCodec::decode(encoded).ok()?.content),
+        _ => None,
+    }
+}
+
+/// Text of the content an EditMessage replaces its original with
+fn edited_text(edit: &StoredGroupMessage) -> Option<String> {
+    let encoded = EncodedContent::decode(edit.decrypted_message_bytes.as_slice()).ok()?;
+    let edited_content = EditMessageCodec::decode(encoded).ok()?.edited_content?;
+    let edited_type = ContentType::from(edited_content.r#type.as_ref()?.type_id.clone());
+    if !edited_type.is_editable() {
+        return None;
+    }
+    encoded_text(edited_content).filter(|text| !text.trim().is_empty())
+}
+
+/// Text of the most recent valid edit of `message`, if it was edited
+fn latest_edited_text(
+    conn: &mut SqliteConnection,
+    message: &StoredGroupMessage,
+) -> QueryResult<Option<String>> {
+    let edits: Vec<StoredGroupMessage> = dsl::group_messages
+        .filter(dsl::reference_id.eq(&message.id))
+        .filter(dsl::content_type.eq(ContentType::EditMessage))
+        .filter(dsl::group_id.eq(&message.group_id))
+        .order((dsl::sent_at_ns.desc(), dsl::id.desc()))
+        .load(conn)?;
+    Ok(edits
+        .iter()
+        .find(|edit| edit.is_valid_edit_of(message))
+        .and_then(edited_text))
+}
+
+/// Re-index the message an EditMessage refers to, once both have been stored.
+fn index_edit(conn: &mut SqliteConnection, edit: &StoredGroupMessage) -> QueryResult<()> {
+    let Some(original_id) = edit.reference_id.as_deref() else {
+        return Ok(());
+    };
+    // An edit can arrive before the message it edits; the original picks it up when stored
+    let Some(original) = dsl::group_messages
+        .find(original_id)
+        .first::<StoredGroupMessage>(conn)
+        .optional()?
+    else {
+        return Ok(());
+    };
+    if !edit.is_valid_edit_of(&original) {
+        return Ok(());
+    }
+    index_message(conn, &original)
+}
+
+/// Add a stored message to the search index. No-op for messages without searchable text or
+/// messages that were already deleted through a DeleteMessage.
+pub(crate) fn index_message(
+    conn: &mut SqliteConnection,
+    message: &StoredGroupMessage,
+) -> QueryResult<()> {
+    if message.content_type == ContentType::EditMessage {
+        return index_edit(conn, message);
+    }
+    let Some(original_body) = searchable_text(message) else {
+        return Ok(());
+    };
+
+    // A DeleteMessage can arrive before the message it deletes
+    let deleted = diesel::select(diesel::dsl::exists(
+        message_deletions::table.filter(message_deletions::deleted_message_id.eq(&message.id)),
+    ))
+    .get_result::<bool>(conn)?;
+    if deleted {
+        return Ok(());
+    }
+    let body = latest_edited_text(conn, message)?.unwrap_or(original_body);
+
+    diesel::insert_into(message_search_content::table)
+        .values(NewMessageSearchEntry {
+            message_id: &message.id,
+            group_id: message.group_id,
+            body,
+        })
+        .on_conflict(message_search_content::message_id)
+        .do_update()
+        .set(message_search_content::body.eq(excluded(message_search_content::body)))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Turn free-form user input into an FTS5 query.
+///
+/// Every whitespace separated term is quoted so that FTS5 operators and punctuation in user input
+/// are matched literally, and the final term is treated as a prefix so results update as the user
+/// types. Returns `None` if the input contains no terms.
+pub fn fts_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect();
+    let (last, rest) = terms.split_last()?;
+    let mut query = rest.join(" ");
+    if !query.is_empty() {
+        query.push(' ');
+    }
+    query.push_str(last);
+    query.push('*');
+    Some(query)
+}
+
+pub trait QueryMessageSearch {
+    /// Search the text of stored messages, across all conversations or within `group_id`.
+    ///
+    /// Results are ordered by relevance and honor the filters in `args`, except for sorting.
+    fn search_messages(
+        &self,
+        query: &str,
+        group_id: Option<&GroupId>,
+        args: &MsgQueryArgs,
+    ) -> Result<Vec<MessageSearchHit>, crate::ConnectionError>;
+
+    /// Index up to `limit` stored messages with an id greater than `after_id`, in id order.
+    ///
+    /// Returns the id of the last message visited, or `None` once every message has been visited.
+    /// Used to backfill the index for messages stored before search was introduced.
+    fn index_messages_for_search(
+        &self,
+        after_id: Option<&[u8]>,
+        limit: i64,
+    ) -> Result<Option<Vec<u8>>, crate::ConnectionError>;
+}
+
+impl<T> QueryMessageSearch for &T
+where
+    T: QueryMessageSearch,
+{
+    fn search_messages(
+        &self,
+        query: &str,
+        group_id: Option<&GroupId>,
+        args: &MsgQueryArgs,
+    ) -> Result<Vec<MessageSearchHit>, crate::ConnectionError> {
+        (**self).search_messages(query, group_id, args)
+    }
+
+    fn index_messages_for_search(
+        &self,
+        after_id: Option<&[u8]>,
+        limit: i64,
+    ) -> Result<Option<Vec<u8>>, crate::ConnectionError> {
+        (**self).index_messages_for_search(after_id, limit)
+    }
+}
+
+impl<C: ConnectionExt> QueryMessageSearch for DbConnection<C> {
+    #[xmtp_common::db_span]
+    fn search_messages(
+        &self,
+        query: &str,
+        group_id: Option<&GroupId>,
+        args: &MsgQueryArgs,
+    ) -> Result<Vec<MessageSearchHit>, crate::ConnectionError> {
+        let Some(fts_query) = fts_query(query) else {
+            return Ok(vec![]);
+        };
+
+        let mut query = message_search::table
+            .inner_join(
+                message_search_content::table
+                    .on(message_search_content::id.eq(message_search::rowid)),
+            )
+            .inner_join(group_messages::table.on(dsl::id.eq(message_search_content::message_id)))
+            .filter(Matches::new(
+                message_search::body,
+                fts_query.into_sql::<Text>(),
+            ))
+            .filter(
+                dsl::group_id.ne_all(
+                    groups_dsl::groups
+                        .filter(
+                            groups_dsl::conversation_type.eq_any(ConversationType::virtual_types()),
+                        )
+                        .select(groups_dsl::id),
+                ),
+            )
+            .into_boxed();
+
+        // Include the other half of a stitched DM, matching `get_group_messages`
+        if let Some(group_id) = group_id {
+            query = query.filter(
+                dsl::group_id.eq_any(
+                    groups_dsl::groups
+                        .filter(
+                            groups_dsl::id.eq(*group_id).or(groups_dsl::dm_id.eq_any(
+                                groups_dsl::groups
+                                    .select(groups_dsl::dm_id)
+                                    .filter(groups_dsl::id.eq(*group_id))
+                                    .into_boxed(),
+                            )),
+                        )
+                        .select(groups_dsl::id),
+                ),
+            );
+        }
+
+        query = apply_message_filters!(query, args);
+
+        let snippet = format!(
+            "snippet(message_search, 0, '{SNIPPET_MATCH_START}', '{SNIPPET_MATCH_END}', '…', {SNIPPET_TOKENS})"
+        );
+        let hits = self.raw_query(|conn| {
+            query
+                .select((
+                    StoredGroupMessage::as_select(),
+                    diesel_sql::<Double>("bm25(message_search)"),
+                    diesel_sql::<Text>(&snippet),
+                ))
+                .order((
+                    diesel_sql::<Double>("bm25(message_search)").asc(),
+                    dsl::sent_at_ns.desc(),
+                ))
+                .limit(args.limit.unwrap_or(DEFAULT_SEARCH_LIMIT))
+                .load::<(StoredGroupMessage, f64, String)>(conn)
+        })?;
+
+        Ok(hits
+            .into_iter()
+            .map(|(message, rank, snippet)| MessageSearchHit {
+                message,
+                rank,
+                snippet,
+            })
+            .collect())
+    }
+
+    #[xmtp_common::db_span]
+    fn index_messages_for_search(
+        &self,
+        after_id: Option<&[u8]>,
+        limit: i64,
+    ) -> Result<Option<Vec<u8>>, crate::ConnectionError> {
+        self.raw_query(|conn| {
+            let mut query = dsl::group_messages
+                .filter(dsl::kind.eq(GroupMessageKind::Application))
+                .order(dsl::id.asc())
+                .limit(limit)
+                .into_boxed();
+            if let Some(after_id) = after_id {
+                query = query.filter(dsl::id.gt(after_id));
+            }
+            let messages = query.load::<StoredGroupMessage>(conn)?;
+
+            for message in &messages {
+                index_message(conn, message)?;
+            }
+            Ok(messages.last().map(|m| m.id.clone()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Store, XmtpTestDb, group::tests::generate_group, group_message::tests::generate_message,
+        message_deletion::StoredMessageDeletion, test_utils::with_connection,
+    };
+    use xmtp_proto::xmtp::mls::message_contents::content_types::EditMessage;
+
+    fn text_message(group_id: &GroupId, text: &str, sent_at_ns: i64) -> StoredGroupMessage {
+        let mut msg = generate_message(
+            None,
+            Some(group_id),
+            Some(sent_at_ns),
+            Some(ContentType::Text),
+            None,
+            None,
+        );
+        msg.decrypted_message_bytes = TextCodec::encode(text.to_string())
+            .expect("text encodes")
+            .encode_to_vec();
+        msg
+    }
+
+    #[xmtp_common::test]
+    fn test_fts_query_quotes_terms() {
+        assert_eq!(fts_query("   "), None);
+        assert_eq!(fts_query("hello"), Some("\"hello\"*".to_string()));
+        assert_eq!(
+            fts_query("say \"hi\" -now"),
+            Some("\"say\" \"\"\"hi\"\"\" \"-now\"*".to_string())
+        );
+    }
+
+    /// The search migration needs FTS5 and the `unicode61` tokenizer. On wasm a
+    /// persistent store is the OPFS-backed `sqlite-wasm-rs` build that ships, so
+    /// this catches that build losing either.
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_search_on_persistent_store() {
+        let store = crate::TestDb::create_persistent_store(None).await;
+        let conn = store.db();
+        let fts5_enabled = conn.raw_query(|c| {
+            diesel::select(diesel_sql::<diesel::sql_types::Bool>(
+                "sqlite_compileoption_used('ENABLE_FTS5')",
+            ))
+            .get_result::<bool>(c)
+        })?;
+        assert!(fts5_enabled, "sqlite was built without FTS5");
+
+        let group = generate_group(None);
+        group.store(&conn)?;
+        let msg = text_message(&group.id, "Crème brûlée at noon", 1_000);
+        msg.store(&conn)?;
+
+        let hits = conn.search_messages("creme brul", None, &MsgQueryArgs::default())?;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].message.id, msg.id);
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_search_messages_ranks_and_scopes() {
+        with_connection(|conn| {
+            let group_a = generate_group(None);
+            group_a.store(conn)?;
+            let group_b = generate_group(None);
+            group_b.store(conn)?;
+
+            text_message(&group_a.id, "lunch at the café tomorrow?", 1_000).store(conn)?;
+            text_message(&group_a.id, "lunch lunch lunch", 2_000).store(conn)?;
+            text_message(&group_b.id, "no lunch for me", 3_000).store(conn)?;
+            text_message(&group_b.id, "unrelated", 4_000).store(conn)?;
+
+            let hits = conn.search_messages("lunch", None, &MsgQueryArgs::default())?;
+            assert_eq!(hits.len(), 3);
+            assert!(hits.windows(2).all(|w| w[0].rank <= w[1].rank));
+            assert!(hits[0].snippet.contains("<mark>lunch</mark>"));
+
+            let hits =
+                conn.search_messages("lunch", Some(&group_b.id), &MsgQueryArgs::default())?;
+            assert_eq!(hits.len(), 1);
+            assert_eq!(hits[0].message.group_id, group_b.id);
+
+            // diacritics are folded and the last term is a prefix
+            let hits = conn.search_messages("cafe tomor", None, &MsgQueryArgs::default())?;
+            assert_eq!(hits.len(), 1);
+
+            let hits = conn.search_messages(
+                "lunch",
+                None,
+                &MsgQueryArgs {
+                    sent_after_ns: Some(1_500),
+                    ..Default::default()
+                },
+            )?;
+            assert_eq!(hits.len(), 2);
+        })
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_search_index_follows_deletes() {
+        with_connection(|conn| {
+            let group = generate_group(None);
+            group.store(conn)?;
+
+            let deleted = text_message(&group.id, "delete me", 1_000);
+            deleted.store(conn)?;
+            let soft_deleted = text_message(&group.id, "delete me softly", 2_000);
+            soft_deleted.store(conn)?;
+            let delete_msg = generate_message(None, Some(&group.id), Some(3_000), None, None, None);
+            delete_msg.store(conn)?;
+
+            assert_eq!(
+                conn.search_messages("delete", None, &MsgQueryArgs::default())?
+                    .len(),
+                2
+            );
+
+            conn.delete_message_by_id(&deleted.id)?;
+            StoredMessageDeletion {
+                id: delete_msg.id.clone(),
+                group_id: group.id,
+                deleted_message_id: soft_deleted.id.clone(),
+                deleted_by_inbox_id: "0x0".to_string(),
+                is_super_admin_deletion: false,
+                deleted_at_ns: 3_000,
+                is_moderator_deletion: false,
+            }
+            .store(conn)?;
+
+            assert!(
+                conn.search_messages("delete", None, &MsgQueryArgs::default())?
+                    .is_empty()
+            );
+        })
+    }
+
+    fn edit_message(
+        original: &StoredGroupMessage,
+        text: &str,
+        sent_at_ns: i64,
+    ) -> StoredGroupMessage {
+        let mut msg = generate_message(
+            None,
+            Some(&original.group_id),
+            Some(sent_at_ns),
+            Some(ContentType::EditMessage),
+            None,
+            Some(original.sender_inbox_id.clone()),
+        );
+        msg.reference_id = Some(original.id.clone());
+        msg.decrypted_message_bytes = EditMessageCodec::encode(EditMessage {
+            message_id: hex::encode(&original.id),
+            edited_content: Some(TextCodec::encode(text.to_string()).expect("text encodes")),
+        })
+        .expect("edit encodes")
+        .encode_to_vec();
+        msg
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_search_index_follows_edits() {
+        with_connection(|conn| {
+            let group = generate_group(None);
+            group.store(conn)?;
+            let original = text_message(&group.id, "meet at noon", 1_000);
+            original.store(conn)?;
+
+            edit_message(&original, "meet at midnight", 2_000).store(conn)?;
+            assert!(
+                conn.search_messages("noon", None, &MsgQueryArgs::default())?
+                    .is_empty()
+            );
+            let hits = conn.search_messages("midnight", None, &MsgQueryArgs::default())?;
+            assert_eq!(hits.len(), 1);
+            assert_eq!(hits[0].message.id, original.id);
+
+            // An older edit arriving late does not replace the latest text
+            edit_message(&original, "meet at dawn", 1_500).store(conn)?;
+            assert!(
+                conn.search_messages("dawn", None, &MsgQueryArgs::default())?
+                    .is_empty()
+            );
+
+            // Edits from anyone but the sender are ignored
+            let mut forged = edit_message(&original, "meet at the docks", 3_000);
+            forged.sender_inbox_id = "0x1".to_string();
+            forged.store(conn)?;
+            assert!(
+                conn.search_messages("docks", None, &MsgQueryArgs::default())?
+                    .is_empty()
+            );
+
+            // An edit stored before its original is applied once the original arrives
+            let late = text_message(&group.id, "first draft", 4_000);
+            edit_message(&late, "final version", 5_000).store(conn)?;
+            late.store(conn)?;
+            assert!(
+                conn.search_messages("draft", None, &MsgQueryArgs::default())?
+                    .is_empty()
+            );
+            assert_eq!(
+                conn.search_messages("final", None, &MsgQueryArgs::default())?
+                    .len(),
+                1
+            );
+        })
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_backfill_indexes_unindexed_messages() {
+        with_connection(|conn| {
+            let group = generate_group(None);
+            group.store(conn)?;
+            let msg = text_message(&group.id, "backfilled text", 1_000);
+            msg.store(conn)?;
+            // Simulate a message stored before the index existed
+            conn.raw_query(|c| diesel::delete(message_search_content::table).execute(c))?;
+            assert!(
+                conn.search_messages("backfilled", None, &MsgQueryArgs::default())?
+                    .is_empty()
+            );
+
+            let mut cursor = None;
+            while let Some(last) = conn.index_messages_for_search(cursor.as_deref(), 1)? {
+                cursor = Some(last);
+            }
+
+            let hits = conn.search_messages("backfilled", None, &MsgQueryArgs::default())?;
+            assert_eq!(hits.len(), 1);
+            assert_eq!(hits[0].message.id, msg.id);
+        })
+    }
+}
@@ -11,11 +11,14 @@
 //! `diesel print-schema` or use `cargo run update-schema` which will update the files for you.
 
 pub mod association_state;
+pub mod attachment_cache;
 pub mod consent_record;
 pub mod conversation_list;
+pub mod conversation_settings;
 pub mod d14n_migration_cutover;
 pub mod database;
 pub mod db_connection;
+pub mod draft;
 pub mod group;
 pub mod group_intent;
 pub mod group_message;
@@ -27,15 +30,18 @@ pub mod key_package_history;
 pub mod key_store_entry;
 pub mod local_commit_log;
 pub mod message_deletion;
+pub mod message_search;
 pub mod migrations;
 pub mod pending_remove;
 pub mod pragmas;
 pub mod processed_device_sync_messages;
+pub mod read_cursor;
 pub mod readd_status;
 pub mod refresh_state;
 pub mod remote_commit_log;
 pub mod schema;
 mod schema_gen;
+pub mod storage;
 pub mod store;
 pub mod tasks;
 pub mod user_preferences;
@@ -0,0 +1,168 @@
+use super::ConnectionExt;
+use crate::DbConnection;
+use crate::schema::read_cursors::{self, dsl};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+use xmtp_common::time::now_ns;
+use xmtp_proto::ConversionError;
+use xmtp_proto::types::GroupId;
+use xmtp_proto::xmtp::device_sync::content::ReadCursorUpdate;
+
+#[derive(
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    Insertable,
+    Identifiable,
+    Queryable,
+    Eq,
+    PartialEq,
+    QueryableByName,
+)]
+#[diesel(table_name = read_cursors)]
+#[diesel(primary_key(group_id))]
+/// The newest message the user has read in a conversation. Messages from other
+/// inboxes sent after `sent_at_ns` are unread.
+pub struct StoredReadCursor {
+    pub group_id: GroupId,
+    /// The last message that was read.
+    pub message_id: Vec<u8>,
+    /// `sent_at_ns` of the last message that was read.
+    pub sent_at_ns: i64,
+    /// Time in nanoseconds the cursor was last moved on this installation.
+    pub updated_at_ns: i64,
+}
+
+impl StoredReadCursor {
+    pub fn new(group_id: GroupId, message_id: Vec<u8>, sent_at_ns: i64) -> Self {
+        Self {
+            group_id,
+            message_id,
+            sent_at_ns,
+            updated_at_ns: now_ns(),
+        }
+    }
+}
+
+pub trait QueryReadCursors {
+    fn get_read_cursor(
+        &self,
+        group_id: &GroupId,
+    ) -> Result<Option<StoredReadCursor>, crate::ConnectionError>;
+
+    /// Store the cursor unless the conversation already has one at or past the
+    /// same message. Returns true if the cursor moved.
+    fn insert_newer_read_cursor(
+        &self,
+        cursor: &StoredReadCursor,
+    ) -> Result<bool, crate::ConnectionError>;
+}
+
+impl<T> QueryReadCursors for &T
+where
+    T: QueryReadCursors,
+{
+    fn get_read_cursor(
+        &self,
+        group_id: &GroupId,
+    ) -> Result<Option<StoredReadCursor>, crate::ConnectionError> {
+        (**self).get_read_cursor(group_id)
+    }
+
+    fn insert_newer_read_cursor(
+        &self,
+        cursor: &StoredReadCursor,
+    ) -> Result<bool, crate::ConnectionError> {
+        (**self).insert_newer_read_cursor(cursor)
+    }
+}
+
+impl<C: ConnectionExt> QueryReadCursors for DbConnection<C> {
+    fn get_read_cursor(
+        &self,
+        group_id: &GroupId,
+    ) -> Result<Option<StoredReadCursor>, crate::ConnectionError> {
+        self.raw_query(|conn| {
+            dsl::read_cursors
+                .filter(dsl::group_id.eq(group_id))
+                .first(conn)
+                .optional()
+        })
+    }
+
+    fn insert_newer_read_cursor(
+        &self,
+        cursor: &StoredReadCursor,
+    ) -> Result<bool, crate::ConnectionError> {
+        let updated = self.raw_query(|conn| {
+            diesel::insert_into(dsl::read_cursors)
+                .values(cursor)
+                .on_conflict(dsl::group_id)
+                .do_update()
+                .set((
+                    dsl::message_id.eq(excluded(dsl::message_id)),
+                    dsl::sent_at_ns.eq(excluded(dsl::sent_at_ns)),
+                    dsl::updated_at_ns.eq(excluded(dsl::updated_at_ns)),
+                ))
+                .filter(dsl::sent_at_ns.lt(excluded(dsl::sent_at_ns)))
+                .execute(conn)
+        })?;
+        Ok(updated > 0)
+    }
+}
+
+impl TryFrom<ReadCursorUpdate> for StoredReadCursor {
+    type Error = ConversionError;
+    fn try_from(value: ReadCursorUpdate) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            value.group_id.try_into()?,
+            value.message_id,
+            value.sent_at_ns,
+        ))
+    }
+}
+
+impl From<StoredReadCursor> for ReadCursorUpdate {
+    fn from(value: StoredReadCursor) -> Self {
+        Self {
+            group_id: value.group_id.to_vec(),
+            message_id: value.message_id,
+            sent_at_ns: value.sent_at_ns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::with_connection;
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn test_read_cursor_only_moves_forward() {
+        with_connection(|conn| {
+            assert!(conn.get_read_cursor(&GroupId::ONE)?.is_none());
+
+            let cursor = StoredReadCursor::new(GroupId::ONE, vec![1], 10);
+            assert!(conn.insert_newer_read_cursor(&cursor)?);
+            assert!(!conn.insert_newer_read_cursor(&StoredReadCursor::new(
+                GroupId::ONE,
+                vec![0],
+                5
+            ))?);
+            assert!(!conn.insert_newer_read_cursor(&cursor)?);
+            assert_eq!(conn.get_read_cursor(&GroupId::ONE)??.message_id, vec![1]);
+
+            assert!(conn.insert_newer_read_cursor(&StoredReadCursor::new(
+                GroupId::ONE,
+                vec![2],
+                20
+            ))?);
+            let stored = conn.get_read_cursor(&GroupId::ONE)??;
+            assert_eq!(stored.message_id, vec![2]);
+            assert_eq!(stored.sent_at_ns, 20);
+            assert!(conn.get_read_cursor(&GroupId::TWO)?.is_none());
+        })
+    }
+}
@@ -25,7 +25,25 @@ diesel::table! {
     authority_id -> Nullable<Text>,
     sequence_id -> Nullable<BigInt>, // null when a group has no messages
     originator_id -> Nullable<BigInt>,
+    has_draft -> Bool,
+    unread_count -> BigInt,
+    first_unread_message_id -> Nullable<Binary>,
+    muted_until_ns -> Nullable<BigInt>,
+    is_archived -> Bool,
+    is_pinned -> Bool,
+  }
+}
+
+// FTS5 external-content table over `message_search_content`. Virtual tables are
+// excluded from `print-schema`, so it is declared by hand.
+diesel::table! {
+  message_search (rowid) {
+    rowid -> Integer,
+    body -> Text,
   }
 }
 
 diesel::allow_tables_to_appear_in_same_query!(consent_records, conversation_list);
+diesel::allow_tables_to_appear_in_same_query!(message_search, message_search_content);
+diesel::allow_tables_to_appear_in_same_query!(message_search, group_messages);
+diesel::allow_tables_to_appear_in_same_query!(message_search, groups);
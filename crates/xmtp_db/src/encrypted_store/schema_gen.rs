@@ -8,6 +8,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    attachment_cache (message_id, attachment_index) {
+        message_id -> Binary,
+        attachment_index -> Integer,
+        content_digest -> Text,
+        payload -> Binary,
+        size_bytes -> BigInt,
+        last_accessed_ns -> BigInt,
+    }
+}
+
+diesel::table! {
+    consent_record_changes (sequence_id) {
+        sequence_id -> Integer,
+        entity_type -> Integer,
+        entity -> Text,
+        changed_at_ns -> BigInt,
+    }
+}
+
 diesel::table! {
     consent_records (entity_type, entity) {
         entity_type -> Integer,
@@ -17,6 +37,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    conversation_settings (group_id) {
+        group_id -> Binary,
+        muted_until_ns -> Nullable<BigInt>,
+        archived -> Bool,
+        pinned -> Bool,
+        updated_at_ns -> BigInt,
+    }
+}
+
 diesel::table! {
     d14n_migration_cutover (id) {
         id -> Integer,
@@ -26,6 +56,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    drafts (group_id) {
+        group_id -> Binary,
+        content -> Binary,
+        reply_to_message_id -> Nullable<Binary>,
+        updated_at_ns -> BigInt,
+    }
+}
+
 diesel::table! {
     group_intents (id) {
         id -> Integer,
@@ -65,6 +104,8 @@ diesel::table! {
         expire_at_ns -> Nullable<BigInt>,
         should_push -> Bool,
         idempotency_key -> Text,
+        expire_after_ns -> Nullable<BigInt>,
+        view_once -> Bool,
     }
 }
 
@@ -175,6 +216,16 @@ diesel::table! {
         deleted_by_inbox_id -> Text,
         is_super_admin_deletion -> Bool,
         deleted_at_ns -> BigInt,
+        is_moderator_deletion -> Bool,
+    }
+}
+
+diesel::table! {
+    message_search_content (id) {
+        id -> Integer,
+        message_id -> Binary,
+        group_id -> Binary,
+        body -> Text,
     }
 }
 
@@ -209,6 +260,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    read_cursors (group_id) {
+        group_id -> Binary,
+        message_id -> Binary,
+        sent_at_ns -> BigInt,
+        updated_at_ns -> BigInt,
+    }
+}
+
 diesel::table! {
     readd_status (group_id, installation_id) {
         group_id -> Binary,
@@ -264,6 +324,7 @@ diesel::table! {
         hmac_key -> Nullable<Binary>,
         hmac_key_cycled_at_ns -> Nullable<BigInt>,
         dm_group_updates_migrated -> Bool,
+        message_search_backfilled -> Bool,
     }
 }
 
@@ -274,8 +335,12 @@ diesel::joinable!(message_deletions -> group_messages (id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     association_state,
+    attachment_cache,
+    consent_record_changes,
     consent_records,
+    conversation_settings,
     d14n_migration_cutover,
+    drafts,
     group_intents,
     group_messages,
     groups,
@@ -287,10 +352,12 @@ diesel::allow_tables_to_appear_in_same_query!(
     key_package_history,
     local_commit_log,
     message_deletions,
+    message_search_content,
     openmls_key_store,
     openmls_key_value,
     pending_remove,
     processed_device_sync_messages,
+    read_cursors,
     readd_status,
     refresh_state,
     remote_commit_log,
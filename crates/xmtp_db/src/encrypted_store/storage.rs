@@ -0,0 +1,446 @@
+//! Reporting on and reclaiming the space used by the database.
+use std::collections::BTreeMap;
+
+use diesel::prelude::*;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Binary, Integer, Text};
+use itertools::Itertools;
+use xmtp_common::{NS_IN_DAY, time::now_ns};
+use xmtp_configuration::Originators;
+
+use super::{ConnectionExt, db_connection::DbConnection, refresh_state::EntityKind};
+use crate::ConnectionError;
+
+/// Key packages are retained for this long by default before they are considered stale
+pub const DEFAULT_KEY_PACKAGE_RETENTION_NS: i64 = 90 * NS_IN_DAY;
+
+/// Rows and bytes used by a table, or by the rows of a table belonging to one group.
+///
+/// Bytes are the size of the stored column values: integers and reals count as 8 bytes, text and
+/// blobs as their length. Page overhead and indexes are not included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableStorage {
+    pub table: String,
+    pub rows: i64,
+    pub bytes: i64,
+}
+
+/// Rows and bytes used by a group across every table with a `group_id` column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupStorage {
+    pub group_id: Vec<u8>,
+    pub rows: i64,
+    pub bytes: i64,
+    /// Usage of each table holding rows for the group, largest first
+    pub tables: Vec<TableStorage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageReport {
+    /// Size of the database file in bytes
+    pub database_bytes: i64,
+    /// Bytes in free pages, which can be reclaimed with `VACUUM`
+    pub free_bytes: i64,
+    /// Usage of each table, largest first
+    pub tables: Vec<TableStorage>,
+    /// Usage of each group, largest first
+    pub groups: Vec<GroupStorage>,
+}
+
+/// What [`QueryStorage::prune`] keeps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Key packages created longer ago than this, other than the newest, are marked for deletion
+    pub key_package_retention_ns: i64,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            key_package_retention_ns: DEFAULT_KEY_PACKAGE_RETENTION_NS,
+        }
+    }
+}
+
+/// Number of rows removed or marked by [`QueryStorage::prune`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    pub local_commit_log: usize,
+    pub remote_commit_log: usize,
+    pub icebox: usize,
+    /// Key packages marked for deletion, they are deleted along with their key material by the
+    /// client's key package maintenance
+    pub key_packages: usize,
+}
+
+pub trait QueryStorage {
+    /// Report the rows and bytes used by each table and each group.
+    fn storage_report(&self) -> Result<StorageReport, ConnectionError>;
+
+    /// Remove state that has been fully processed and is no longer needed:
+    /// - local commit log rows that were both published and checked for forks
+    /// - remote commit log rows that were checked for forks
+    /// - icebox entries whose envelope has been processed
+    /// - key packages older than the retention policy, which are marked for deletion
+    ///
+    /// The latest commit log row of every group, and the latest row starting a commit chain,
+    /// are always kept. Run `VACUUM` afterwards to return the freed pages to the file system.
+    fn prune(&self, policy: RetentionPolicy) -> Result<PruneReport, ConnectionError>;
+}
+
+#[derive(QueryableByName)]
+struct Name {
+    #[diesel(sql_type = Text)]
+    name: String,
+}
+
+#[derive(QueryableByName)]
+struct Usage {
+    #[diesel(sql_type = BigInt)]
+    rows: i64,
+    #[diesel(sql_type = BigInt)]
+    bytes: i64,
+}
+
+#[derive(QueryableByName)]
+struct GroupUsage {
+    #[diesel(sql_type = Binary)]
+    group_id: Vec<u8>,
+    #[diesel(sql_type = BigInt)]
+    rows: i64,
+    #[diesel(sql_type = BigInt)]
+    bytes: i64,
+}
+
+#[derive(QueryableByName)]
+struct Pages {
+    #[diesel(sql_type = BigInt)]
+    page_count: i64,
+    #[diesel(sql_type = BigInt)]
+    freelist_count: i64,
+    #[diesel(sql_type = BigInt)]
+    page_size: i64,
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// SQL summing the size of every column of a row
+fn row_bytes(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|c| {
+            let c = quote_identifier(c);
+            format!(
+                "CASE typeof({c}) WHEN 'null' THEN 0 WHEN 'integer' THEN 8 WHEN 'real' THEN 8 \
+                 ELSE length(CAST({c} AS BLOB)) END"
+            )
+        })
+        .join(" + ")
+}
+
+impl<C: ConnectionExt> QueryStorage for DbConnection<C> {
+    fn storage_report(&self) -> Result<StorageReport, ConnectionError> {
+        let pages = self.raw_query(|conn| {
+            sql_query(
+                "SELECT page_count, freelist_count, page_size \
+                 FROM pragma_page_count(), pragma_freelist_count(), pragma_page_size()",
+            )
+            .get_result::<Pages>(conn)
+        })?;
+        let table_names = self.raw_query(|conn| {
+            sql_query(
+                "SELECT name FROM sqlite_master WHERE type = 'table' \
+                 AND name NOT LIKE 'sqlite_%' AND sql NOT LIKE 'CREATE VIRTUAL TABLE%' \
+                 ORDER BY name",
+            )
+            .load::<Name>(conn)
+        })?;
+
+        let mut tables = Vec::with_capacity(table_names.len());
+        let mut groups: BTreeMap<Vec<u8>, GroupStorage> = BTreeMap::new();
+        for Name { name: table } in table_names {
+            let columns: Vec<String> = self
+                .raw_query(|conn| {
+                    sql_query("SELECT name FROM pragma_table_info(?)")
+                        .bind::<Text, _>(&table)
+                        .load::<Name>(conn)
+                })?
+                .into_iter()
+                .map(|c| c.name)
+                .collect();
+            let bytes = row_bytes(&columns);
+            let quoted = quote_identifier(&table);
+
+            let usage = self.raw_query(|conn| {
+                sql_query(format!(
+                    "SELECT COUNT(*) AS \"rows\", COALESCE(SUM({bytes}), 0) AS \"bytes\" FROM {quoted}"
+                ))
+                .get_result::<Usage>(conn)
+            })?;
+            tables.push(TableStorage {
+                table: table.clone(),
+                rows: usage.rows,
+                bytes: usage.bytes,
+            });
+
+            if !columns.iter().any(|c| c == "group_id") {
+                continue;
+            }
+            let group_usage = self.raw_query(|conn| {
+                sql_query(format!(
+                    "SELECT group_id, COUNT(*) AS \"rows\", COALESCE(SUM({bytes}), 0) AS \"bytes\" \
+                     FROM {quoted} WHERE group_id IS NOT NULL GROUP BY group_id"
+                ))
+                .load::<GroupUsage>(conn)
+            })?;
+            for usage in group_usage {
+                let group = groups
+                    .entry(usage.group_id.clone())
+                    .or_insert_with(|| GroupStorage {
+                        group_id: usage.group_id,
+                        rows: 0,
+                        bytes: 0,
+                        tables: vec![],
+                    });
+                group.rows += usage.rows;
+                group.bytes += usage.bytes;
+                group.tables.push(TableStorage {
+                    table: table.clone(),
+                    rows: usage.rows,
+                    bytes: usage.bytes,
+                });
+            }
+        }
+
+        tables.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+        let mut groups: Vec<_> = groups.into_values().collect();
+        for group in &mut groups {
+            group.tables.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+        }
+        groups.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+
+        Ok(StorageReport {
+            database_bytes: pages.page_count * pages.page_size,
+            free_bytes: pages.freelist_count * pages.page_size,
+            tables,
+            groups,
+        })
+    }
+
+    fn prune(&self, policy: RetentionPolicy) -> Result<PruneReport, ConnectionError> {
+        use crate::icebox::QueryIcebox;
+
+        let originator = Originators::REMOTE_COMMIT_LOG as i32;
+        let local_commit_log = self.raw_query(|conn| {
+            sql_query(
+                r#"
+                DELETE FROM local_commit_log
+                WHERE rowid <= (
+                    SELECT sequence_id FROM refresh_state
+                    WHERE entity_id = local_commit_log.group_id
+                    AND entity_kind = ? AND originator_id = ?
+                )
+                AND rowid <= (
+                    SELECT sequence_id FROM refresh_state
+                    WHERE entity_id = local_commit_log.group_id
+                    AND entity_kind = ? AND originator_id = ?
+                )
+                AND rowid < (
+                    SELECT MAX(rowid) FROM local_commit_log latest
+                    WHERE latest.group_id = local_commit_log.group_id
+                )
+                AND rowid NOT IN (
+                    SELECT MAX(rowid) FROM local_commit_log
+                    WHERE commit_sequence_id = 0
+                    GROUP BY group_id
+                )
+                "#,
+            )
+            .bind::<Integer, _>(EntityKind::CommitLogUpload as i32)
+            .bind::<Integer, _>(originator)
+            .bind::<Integer, _>(EntityKind::CommitLogForkCheckLocal as i32)
+            .bind::<Integer, _>(originator)
+            .execute(conn)
+        })?;
+
+        let remote_commit_log = self.raw_query(|conn| {
+            sql_query(
+                r#"
+                DELETE FROM remote_commit_log
+                WHERE rowid <= (
+                    SELECT sequence_id FROM refresh_state
+                    WHERE entity_id = remote_commit_log.group_id
+                    AND entity_kind = ? AND originator_id = ?
+                )
+                AND log_sequence_id < (
+                    SELECT MAX(log_sequence_id) FROM remote_commit_log latest
+                    WHERE latest.group_id = remote_commit_log.group_id
+                )
+                "#,
+            )
+            .bind::<Integer, _>(EntityKind::CommitLogForkCheckRemote as i32)
+            .bind::<Integer, _>(originator)
+            .execute(conn)
+        })?;
+
+        let icebox = self.prune_icebox()?;
+
+        let now = now_ns();
+        let key_packages = self.raw_query(|conn| {
+            sql_query(
+                r#"
+                UPDATE key_package_history SET delete_at_ns = ?
+                WHERE delete_at_ns IS NULL
+                AND created_at_ns < ?
+                AND id < (SELECT MAX(id) FROM key_package_history)
+                "#,
+            )
+            .bind::<BigInt, _>(now)
+            .bind::<BigInt, _>(now - policy.key_package_retention_ns)
+            .execute(conn)
+        })?;
+
+        let report = PruneReport {
+            local_commit_log,
+            remote_commit_log,
+            icebox,
+            key_packages,
+        };
+        tracing::info!(?report, "pruned database");
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Store, StoreOrIgnore,
+        key_package_history::NewKeyPackageHistoryEntry,
+        local_commit_log::{CommitResult, NewLocalCommitLog},
+        prelude::*,
+        remote_commit_log::NewRemoteCommitLog,
+        test_utils::with_connection,
+    };
+    use xmtp_common::{Generate, rand_vec};
+    use xmtp_proto::types::{Cursor, GroupId};
+
+    fn local_log(group_id: GroupId, commit_sequence_id: i64) -> NewLocalCommitLog {
+        NewLocalCommitLog {
+            group_id,
+            commit_sequence_id,
+            last_epoch_authenticator: vec![],
+            commit_result: CommitResult::Success,
+            applied_epoch_number: commit_sequence_id,
+            applied_epoch_authenticator: vec![1; 32],
+            error_message: None,
+            sender_inbox_id: None,
+            sender_installation_id: None,
+            commit_type: None,
+        }
+    }
+
+    fn remote_log(group_id: GroupId, log_sequence_id: i64) -> NewRemoteCommitLog {
+        NewRemoteCommitLog {
+            log_sequence_id,
+            group_id,
+            commit_sequence_id: log_sequence_id,
+            commit_result: CommitResult::Success,
+            applied_epoch_number: log_sequence_id,
+            applied_epoch_authenticator: vec![1; 32],
+        }
+    }
+
+    #[xmtp_common::test]
+    fn test_storage_report() {
+        with_connection(|conn| {
+            let group_id = GroupId::generate();
+            for i in 1..=3 {
+                local_log(group_id, i).store(conn).unwrap();
+            }
+
+            let report = conn.storage_report().unwrap();
+            assert!(report.database_bytes > 0);
+            let table = report
+                .tables
+                .iter()
+                .find(|t| t.table == "local_commit_log")
+                .unwrap();
+            assert_eq!(table.rows, 3);
+            assert!(table.bytes >= 3 * 32);
+
+            assert_eq!(report.groups.len(), 1);
+            let group = &report.groups[0];
+            assert_eq!(group.group_id, group_id.to_vec());
+            assert_eq!(group.rows, 3);
+            assert_eq!(group.tables[0].table, "local_commit_log");
+        })
+    }
+
+    #[xmtp_common::test]
+    fn test_prune() {
+        with_connection(|conn| {
+            let group_id = GroupId::generate();
+            // a chain start followed by three commits
+            for i in 0..=3 {
+                local_log(group_id, i).store(conn).unwrap();
+            }
+            for i in 1..=3 {
+                remote_log(group_id, i).store(conn).unwrap();
+            }
+
+            // nothing is pruned before the logs are processed
+            assert_eq!(
+                conn.prune(RetentionPolicy::default()).unwrap(),
+                PruneReport::default()
+            );
+
+            for kind in [
+                EntityKind::CommitLogUpload,
+                EntityKind::CommitLogForkCheckLocal,
+                EntityKind::CommitLogForkCheckRemote,
+            ] {
+                conn.update_cursor(group_id, kind, Cursor::commit_log(4))
+                    .unwrap();
+            }
+            let old = now_ns() - DEFAULT_KEY_PACKAGE_RETENTION_NS - 1;
+            for created_at_ns in [old, old, now_ns()] {
+                NewKeyPackageHistoryEntry {
+                    key_package_hash_ref: rand_vec::<32>(),
+                    post_quantum_public_key: None,
+                    created_at_ns,
+                }
+                .store_or_ignore(conn)
+                .unwrap();
+            }
+
+            let report = conn.prune(RetentionPolicy::default()).unwrap();
+            // the chain start and the latest commit are kept
+            assert_eq!(report.local_commit_log, 2);
+            assert_eq!(report.remote_commit_log, 2);
+            assert_eq!(report.key_packages, 2);
+            let remaining: Vec<_> = conn
+                .get_group_logs(&group_id)
+                .unwrap()
+                .into_iter()
+                .map(|l| l.commit_sequence_id)
+                .collect();
+            assert_eq!(remaining, vec![0, 3]);
+            assert_eq!(
+                conn.get_latest_remote_log_for_group(&group_id)
+                    .unwrap()
+                    .unwrap()
+                    .log_sequence_id,
+                3
+            );
+            assert_eq!(conn.get_expired_key_packages().unwrap().len(), 2);
+
+            assert_eq!(
+                conn.prune(RetentionPolicy::default()).unwrap(),
+                PruneReport::default()
+            );
+        })
+    }
+}
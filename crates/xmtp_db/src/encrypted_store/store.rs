@@ -47,6 +47,25 @@ where
     }
 }
 
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+impl EncryptedMessageStore<NativeDb> {
+    /// Re-encrypt the database with `new_key`. See [`NativeDb::rekey`].
+    pub fn rekey(&self, new_key: impl Into<EncryptionKey>) -> Result<(), StorageError> {
+        self.db.rekey(new_key)
+    }
+
+    /// Whether the database was opened with its fallback key. See
+    /// [`NativeDb::opened_with_fallback_key`].
+    pub fn opened_with_fallback_key(&self) -> bool {
+        self.db.opened_with_fallback_key()
+    }
+
+    /// Encrypt an unencrypted database with `key`. See [`NativeDb::encrypt_in_place`].
+    pub fn encrypt_in_place(&self, key: impl Into<EncryptionKey>) -> Result<(), StorageError> {
+        self.db.encrypt_in_place(key)
+    }
+}
+
 impl<Db> XmtpDb for EncryptedMessageStore<Db>
 where
     Db: XmtpDb,
@@ -18,6 +18,8 @@ pub struct StoredUserPreferences {
     pub hmac_key_cycled_at_ns: Option<i64>,
     /// Whether DM group updates have been migrated.
     pub dm_group_updates_migrated: bool,
+    /// Whether messages stored before full-text search existed have been indexed.
+    pub message_search_backfilled: bool,
 }
 
 impl<C> Store<C> for StoredUserPreferences
@@ -41,9 +41,12 @@ pub type DefaultMlsStore = SqlKeyStore<<DefaultStore as XmtpDb>::DbQuery>;
 pub mod prelude {
     pub use super::ReadOnly;
     pub use super::association_state::QueryAssociationStateCache;
+    pub use super::attachment_cache::QueryAttachmentCache;
     pub use super::consent_record::QueryConsentRecord;
     pub use super::conversation_list::QueryConversationList;
+    pub use super::conversation_settings::QueryConversationSettings;
     pub use super::d14n_migration_cutover::QueryMigrationCutover;
+    pub use super::draft::QueryDrafts;
     pub use super::group::QueryDms;
     pub use super::group::QueryGroup;
     pub use super::group::QueryGroupVersion;
@@ -56,12 +59,15 @@ pub mod prelude {
     pub use super::key_package_history::QueryKeyPackageHistory;
     pub use super::key_store_entry::QueryKeyStoreEntry;
     pub use super::local_commit_log::QueryLocalCommitLog;
+    pub use super::message_search::QueryMessageSearch;
     pub use super::migrations::QueryMigrations;
     pub use super::pragmas::Pragmas;
     pub use super::processed_device_sync_messages::QueryDeviceSyncMessages;
+    pub use super::read_cursor::QueryReadCursors;
     pub use super::readd_status::QueryReaddStatus;
     pub use super::refresh_state::QueryRefreshState;
     pub use super::remote_commit_log::QueryRemoteCommitLog;
+    pub use super::storage::QueryStorage;
     pub use super::tasks::QueryTasks;
     pub use super::traits::*;
 }
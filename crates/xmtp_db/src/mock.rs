@@ -81,8 +81,9 @@ mock! {
         fn consent_records_paged(
             &self,
             limit: i64,
-            offset: i64,
-        ) -> Result<Vec<crate::consent_record::StoredConsentRecord>, crate::ConnectionError>;
+            after_sequence_id: i32,
+            args: &crate::consent_record::ConsentRecordQueryArgs,
+        ) -> Result<Vec<(i32, crate::consent_record::StoredConsentRecord)>, crate::ConnectionError>;
 
         fn insert_newer_consent_record(
             &self,
@@ -658,6 +659,13 @@ mock! {
             inbox_id: &str,
         ) -> Result<i64, crate::ConnectionError>;
 
+        fn get_sequence_id_at(
+            &self,
+            inbox_id: &str,
+            max_sequence_id: Option<i64>,
+            max_timestamp_ns: Option<i64>,
+        ) -> Result<Option<i64>, crate::ConnectionError>;
+
         fn get_latest_sequence_id<'a>(
             &'a self,
             inbox_ids: &'a [&'a str],
@@ -866,6 +874,90 @@ mock! {
         ) -> Result<bool, crate::ConnectionError>;
     }
 
+    impl crate::message_search::QueryMessageSearch for DbQuery {
+        fn search_messages<'a>(
+            &self,
+            query: &str,
+            group_id: Option<&'a GroupId>,
+            args: &crate::group_message::MsgQueryArgs,
+        ) -> Result<Vec<crate::message_search::MessageSearchHit>, crate::ConnectionError>;
+
+        fn index_messages_for_search<'a>(
+            &self,
+            after_id: Option<&'a [u8]>,
+            limit: i64,
+        ) -> Result<Option<Vec<u8>>, crate::ConnectionError>;
+    }
+
+    impl crate::draft::QueryDrafts for DbQuery {
+        fn upsert_draft(
+            &self,
+            draft: &crate::draft::StoredDraft,
+        ) -> Result<(), crate::ConnectionError>;
+
+        fn get_draft(
+            &self,
+            group_id: &GroupId,
+        ) -> Result<Option<crate::draft::StoredDraft>, crate::ConnectionError>;
+
+        fn delete_draft(&self, group_id: &GroupId) -> Result<bool, crate::ConnectionError>;
+
+        fn insert_newer_draft(
+            &self,
+            draft: &crate::draft::StoredDraft,
+        ) -> Result<bool, crate::ConnectionError>;
+
+        fn drafts_paged(
+            &self,
+            limit: i64,
+            offset: i64,
+            changed_after_ns: Option<i64>,
+            group_ids: Option<Vec<Vec<u8>>>,
+            exclude_group_ids: Option<Vec<Vec<u8>>>,
+        ) -> Result<Vec<crate::draft::StoredDraft>, crate::ConnectionError>;
+    }
+
+    impl crate::read_cursor::QueryReadCursors for DbQuery {
+        fn get_read_cursor(
+            &self,
+            group_id: &GroupId,
+        ) -> Result<Option<crate::read_cursor::StoredReadCursor>, crate::ConnectionError>;
+
+        fn insert_newer_read_cursor(
+            &self,
+            cursor: &crate::read_cursor::StoredReadCursor,
+        ) -> Result<bool, crate::ConnectionError>;
+    }
+
+    impl crate::conversation_settings::QueryConversationSettings for DbQuery {
+        fn get_conversation_settings(
+            &self,
+            group_id: &GroupId,
+        ) -> Result<Option<crate::conversation_settings::StoredConversationSettings>, crate::ConnectionError>;
+
+        fn insert_newer_conversation_settings(
+            &self,
+            settings: &crate::conversation_settings::StoredConversationSettings,
+        ) -> Result<bool, crate::ConnectionError>;
+    }
+
+    impl crate::attachment_cache::QueryAttachmentCache for DbQuery {
+        fn get_cached_attachment(
+            &self,
+            message_id: &[u8],
+            attachment_index: i32,
+        ) -> Result<Option<crate::attachment_cache::StoredCachedAttachment>, crate::ConnectionError>;
+
+        fn insert_cached_attachment(
+            &self,
+            attachment: &crate::attachment_cache::StoredCachedAttachment,
+        ) -> Result<(), crate::ConnectionError>;
+
+        fn evict_cached_attachments(&self, max_bytes: i64) -> Result<usize, crate::ConnectionError>;
+
+        fn cached_attachments_size(&self) -> Result<i64, crate::ConnectionError>;
+    }
+
 }
 
 impl ConnectionExt for MockDbQuery {
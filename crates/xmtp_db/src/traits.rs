@@ -4,6 +4,7 @@ use crate::association_state::QueryAssociationStateCache;
 use crate::d14n_migration_cutover::QueryMigrationCutover;
 use crate::icebox::QueryIcebox;
 use crate::message_deletion::QueryMessageDeletion;
+use crate::message_search::QueryMessageSearch;
 use crate::pending_remove::QueryPendingRemove;
 use crate::prelude::*;
 use crate::readd_status::QueryReaddStatus;
@@ -91,6 +92,11 @@ pub trait DbQuery:
     + QueryPendingRemove
     + QueryIcebox
     + QueryMessageDeletion
+    + QueryMessageSearch
+    + QueryDrafts
+    + QueryReadCursors
+    + QueryConversationSettings
+    + QueryAttachmentCache
     + QueryMigrationCutover
     + Pragmas
     + crate::ConnectionExt
@@ -123,6 +129,11 @@ impl<T: ?Sized> DbQuery for T where
         + QueryPendingRemove
         + QueryIcebox
         + QueryMessageDeletion
+        + QueryMessageSearch
+        + QueryDrafts
+        + QueryReadCursors
+        + QueryConversationSettings
+        + QueryAttachmentCache
         + QueryMigrationCutover
         + Pragmas
         + crate::ConnectionExt
@@ -499,6 +499,7 @@ fn allowed_signature_for_kind(
         ),
         MemberKind::Installation => matches!(signature_kind, SignatureKind::InstallationKey),
         MemberKind::Passkey => matches!(signature_kind, SignatureKind::P256),
+        MemberKind::Solana => matches!(signature_kind, SignatureKind::Ed25519Wallet),
     };
 
     if !is_ok {
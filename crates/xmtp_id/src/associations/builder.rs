@@ -218,7 +218,12 @@ impl SignatureRequest {
     pub fn missing_address_signatures(&self) -> Vec<&MemberIdentifier> {
         self.missing_signatures()
             .into_iter()
-            .filter(|member| matches!(member.kind(), MemberKind::Ethereum | MemberKind::Passkey))
+            .filter(|member| {
+                matches!(
+                    member.kind(),
+                    MemberKind::Ethereum | MemberKind::Passkey | MemberKind::Solana
+                )
+            })
             .collect()
     }
 
@@ -434,6 +439,7 @@ pub(crate) mod tests {
             unverified::UnverifiedRecoverableEcdsaSignature,
         },
     };
+    use xmtp_proto::xmtp::identity::associations::IdentityUpdate as IdentityUpdateProto;
 
     use super::*;
 
@@ -494,6 +500,46 @@ pub(crate) mod tests {
         assert_eq!(state.members().len(), 2);
     }
 
+    #[xmtp_common::test]
+    async fn create_inbox_with_solana_wallet() {
+        let wallet = ed25519_dalek::SigningKey::from_bytes(&xmtp_common::rand_array::<32>());
+        let installation_key = XmtpInstallationCredential::new();
+        let ident = wallet.get_identifier().unwrap();
+        let nonce = 0;
+        let inbox_id = ident.inbox_id(nonce).unwrap();
+
+        let mut signature_request = SignatureRequestBuilder::new(inbox_id)
+            .create_inbox(ident.clone(), nonce)
+            .add_association(
+                MemberIdentifier::installation(installation_key.public_bytes().to_vec()),
+                ident.clone().into(),
+            )
+            .build();
+        assert!(
+            signature_request
+                .missing_address_signatures()
+                .contains(&&MemberIdentifier::from(ident.clone()))
+        );
+
+        let signature = wallet.sign(&signature_request.signature_text()).unwrap();
+        signature_request
+            .add_signature(signature, &MockSmartContractSignatureVerifier::new(false))
+            .await
+            .expect("should succeed");
+        add_installation_key_signature(&mut signature_request, &installation_key).await;
+
+        // Round trip through the proto, as it would be published
+        let identity_update: IdentityUpdateProto =
+            signature_request.build_identity_update().unwrap().into();
+        let identity_update = UnverifiedIdentityUpdate::try_from(identity_update).unwrap();
+
+        let state =
+            get_state(vec![convert_to_verified(&identity_update).await]).expect("should be valid");
+        assert_eq!(state.members().len(), 2);
+        assert_eq!(state.recovery_identifier(), &ident);
+        assert_eq!(state.identifiers(), vec![ident]);
+    }
+
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
     async fn create_and_revoke() {
@@ -3,7 +3,9 @@
 mod ethereum;
 mod installation;
 mod passkey;
+mod solana;
 
 pub use ethereum::*;
 pub use installation::*;
 pub use passkey::*;
+pub use solana::*;
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use xmtp_cryptography::signature::{IdentifierValidationError, sanitize_solana_address};
+
+/// A base58 encoded ed25519 wallet public key
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Solana(pub String);
+
+impl Solana {
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn rand() -> Self {
+        Self(bs58::encode(xmtp_common::rand_vec::<32>()).into_string())
+    }
+
+    pub fn sanitize(self) -> Result<Self, IdentifierValidationError> {
+        Ok(Self(sanitize_solana_address(self.0)?))
+    }
+
+    pub fn from_public_key(key: &[u8]) -> Self {
+        Self(bs58::encode(key).into_string())
+    }
+
+    /// The ed25519 public key this address encodes.
+    pub fn public_key(&self) -> Result<[u8; 32], IdentifierValidationError> {
+        let mut key = [0u8; 32];
+        match bs58::decode(&self.0).onto(&mut key) {
+            Ok(32) => Ok(key),
+            _ => Err(IdentifierValidationError::InvalidAddresses(vec![
+                self.0.clone(),
+            ])),
+        }
+    }
+}
+
+impl Display for Solana {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
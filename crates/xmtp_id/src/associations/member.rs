@@ -6,7 +6,10 @@ use std::{
     fmt::{Debug, Display},
     hash::Hash,
 };
-use xmtp_cryptography::{XmtpInstallationCredential, signature::IdentifierValidationError};
+use xmtp_cryptography::{
+    XmtpInstallationCredential,
+    signature::{IdentifierValidationError, is_valid_solana_address},
+};
 use xmtp_proto::types::ApiIdentifier;
 use xmtp_proto::{
     ConversionError,
@@ -22,6 +25,7 @@ pub enum MemberIdentifier {
     Installation(ident::Installation),
     Ethereum(ident::Ethereum),
     Passkey(ident::Passkey),
+    Solana(ident::Solana),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -32,6 +36,7 @@ pub enum MemberIdentifier {
 pub enum Identifier {
     Ethereum(ident::Ethereum),
     Passkey(ident::Passkey),
+    Solana(ident::Solana),
 }
 
 impl From<Identifier> for i32 {
@@ -49,6 +54,7 @@ impl MemberIdentifier {
     pub fn sanitize(self) -> Result<Self, IdentifierValidationError> {
         let ident = match self {
             Self::Ethereum(addr) => Self::Ethereum(addr.sanitize()?),
+            Self::Solana(addr) => Self::Solana(addr.sanitize()?),
             ident => ident,
         };
         Ok(ident)
@@ -68,6 +74,10 @@ impl MemberIdentifier {
         Ok(Identifier::eth(addr)?.into())
     }
 
+    pub fn solana(addr: impl ToString) -> Result<Self, IdentifierValidationError> {
+        Ok(Identifier::solana(addr)?.into())
+    }
+
     pub fn installation(key: Vec<u8>) -> Self {
         Self::Installation(ident::Installation(key))
     }
@@ -102,6 +112,16 @@ impl MemberIdentifier {
         }
     }
 
+    /// Get the value for [`MemberIdentifier::Solana`] variant.
+    /// Returns `None` if the type is not the correct variant.
+    pub fn solana_address(&self) -> Option<&str> {
+        if let Self::Solana(address) = self {
+            Some(&address.0)
+        } else {
+            None
+        }
+    }
+
     /// Get the value for [`MemberIdentifier::Installation`] variant.
     /// Returns `None` if the type is not the correct variant.
     pub fn to_installation(&self) -> Option<&[u8]> {
@@ -119,9 +139,15 @@ impl Identifier {
         Self::Ethereum(ident::Ethereum::rand())
     }
 
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn rand_solana() -> Self {
+        Self::Solana(ident::Solana::rand())
+    }
+
     pub fn sanitize(self) -> Result<Self, IdentifierValidationError> {
         let ident = match self {
             Self::Ethereum(addr) => Self::Ethereum(addr.sanitize()?),
+            Self::Solana(addr) => Self::Solana(addr.sanitize()?),
             ident => ident,
         };
         Ok(ident)
@@ -131,6 +157,10 @@ impl Identifier {
         Self::Ethereum(ident::Ethereum(addr.to_string())).sanitize()
     }
 
+    pub fn solana(addr: impl ToString) -> Result<Self, IdentifierValidationError> {
+        Self::Solana(ident::Solana(addr.to_string())).sanitize()
+    }
+
     pub fn passkey(key: Vec<u8>, relying_party: Option<String>) -> Self {
         Self::Passkey(ident::Passkey { key, relying_party })
     }
@@ -162,6 +192,7 @@ impl Identifier {
                 })?,
                 relying_party,
             }),
+            IdentifierKind::Solana => Self::Solana(ident::Solana(ident.to_string())),
         };
         Ok(ident)
     }
@@ -176,8 +207,9 @@ impl Identifier {
         Ok(sha256_string(format!("{ident}{nonce}")))
     }
 
-    /// Validates that the account address is exactly 42 characters, starts with "0x",
-    /// and contains only valid hex digits.
+    /// Validates that an ethereum address is exactly 42 characters, starts with "0x",
+    /// and contains only valid hex digits, and that a solana address is a base58
+    /// encoded 32 byte key.
     fn is_valid_address(&self) -> bool {
         match self {
             Self::Ethereum(ident::Ethereum(addr)) => {
@@ -185,6 +217,7 @@ impl Identifier {
                     && addr.starts_with("0x")
                     && addr[2..].chars().all(|c| c.is_ascii_hexdigit())
             }
+            Self::Solana(ident::Solana(addr)) => is_valid_solana_address(addr),
             _ => true,
         }
     }
@@ -195,6 +228,7 @@ pub enum MemberKind {
     Installation,
     Ethereum,
     Passkey,
+    Solana,
 }
 
 impl Display for MemberKind {
@@ -203,6 +237,7 @@ impl Display for MemberKind {
             MemberKind::Installation => write!(f, "installation"),
             MemberKind::Ethereum => write!(f, "ethereum"),
             MemberKind::Passkey => write!(f, "passkey"),
+            MemberKind::Solana => write!(f, "solana"),
         }
     }
 }
@@ -217,6 +252,7 @@ impl HasMemberKind for MemberIdentifier {
             Self::Installation(_) => MemberKind::Installation,
             Self::Ethereum(_) => MemberKind::Ethereum,
             Self::Passkey(_) => MemberKind::Passkey,
+            Self::Solana(_) => MemberKind::Solana,
         }
     }
 }
@@ -225,6 +261,7 @@ impl HasMemberKind for Identifier {
         match self {
             Self::Ethereum(_) => MemberKind::Ethereum,
             Self::Passkey(_) => MemberKind::Passkey,
+            Self::Solana(_) => MemberKind::Solana,
         }
     }
 }
@@ -235,6 +272,7 @@ impl Display for MemberIdentifier {
             Self::Ethereum(eth) => write!(f, "{eth}"),
             Self::Installation(ident) => write!(f, "{ident}"),
             Self::Passkey(passkey) => write!(f, "{passkey}"),
+            Self::Solana(sol) => write!(f, "{sol}"),
         }
     }
 }
@@ -250,6 +288,7 @@ impl Debug for MemberIdentifier {
             Self::Passkey(ident::Passkey { key, .. }) => {
                 f.debug_tuple("Passkey").field(&hex::encode(key)).finish()
             }
+            Self::Solana(ident::Solana(addr)) => f.debug_tuple("Solana").field(addr).finish(),
         }
     }
 }
@@ -259,6 +298,7 @@ impl Display for Identifier {
         match self {
             Self::Ethereum(eth) => write!(f, "{eth}"),
             Self::Passkey(passkey) => write!(f, "{passkey}"),
+            Self::Solana(sol) => write!(f, "{sol}"),
         }
     }
 }
@@ -286,6 +326,7 @@ impl From<Identifier> for MemberIdentifier {
         match ident {
             Identifier::Ethereum(addr) => Self::Ethereum(addr),
             Identifier::Passkey(passkey) => Self::Passkey(passkey),
+            Identifier::Solana(sol) => Self::Solana(sol),
         }
     }
 }
@@ -294,6 +335,7 @@ impl From<MemberIdentifier> for Option<Identifier> {
         let ident = match ident {
             MemberIdentifier::Passkey(passkey) => Identifier::Passkey(passkey),
             MemberIdentifier::Ethereum(eth) => Identifier::Ethereum(eth),
+            MemberIdentifier::Solana(sol) => Identifier::Solana(sol),
             _ => {
                 return None;
             }
@@ -338,6 +380,7 @@ impl TryFrom<ApiIdentifier> for Identifier {
                     .map_err(|_| DeserializationError::InvalidPasskey)?,
                 relying_party: None,
             }),
+            IdentifierKind::Solana => Identifier::solana(ident.identifier)?,
         };
         Ok(ident)
     }
@@ -394,6 +437,7 @@ impl PartialEq<MemberIdentifier> for Identifier {
                 ident == other_ident
             }
             (Self::Passkey(ident), MemberIdentifier::Passkey(other_ident)) => ident == other_ident,
+            (Self::Solana(ident), MemberIdentifier::Solana(other_ident)) => ident == other_ident,
             _ => false,
         }
     }
@@ -446,4 +490,29 @@ pub(crate) mod tests {
         assert!(installation_1.ne(&installation_2));
         assert!(installation_1 == installation_1_copy);
     }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_solana_identifier() {
+        let address = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+        let ident = Identifier::solana(format!(" {address} ")).unwrap();
+        assert_eq!(ident.to_string(), address);
+        assert_eq!(IdentifierKind::from(&ident), IdentifierKind::Solana);
+        assert!(ident.inbox_id(0).is_ok());
+        assert_ne!(
+            ident.inbox_id(0).unwrap(),
+            Identifier::rand_solana().inbox_id(0).unwrap()
+        );
+
+        assert!(Identifier::solana("0x7e57Aed10441c8879ce08E45805EC01Ee9689c9f").is_err());
+        assert!(matches!(
+            Identifier::from_proto("not base58!", IdentifierKind::Solana, None)
+                .unwrap()
+                .inbox_id(0),
+            Err(AssociationError::InvalidAccountAddress)
+        ));
+
+        let api: ApiIdentifier = ident.clone().into();
+        assert_eq!(Identifier::try_from(api).unwrap(), ident);
+    }
 }
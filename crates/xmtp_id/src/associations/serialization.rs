@@ -284,6 +284,10 @@ impl TryFrom<SignatureWrapperProto> for UnverifiedSignature {
                 sig.authenticator_data,
                 sig.client_data_json,
             ),
+            SignatureKindProto::Ed25519Wallet(sig) => UnverifiedSignature::new_ed25519_wallet(
+                sig.bytes,
+                sig.public_key.as_slice().try_into()?,
+            ),
         };
 
         Ok(unverified_sig)
@@ -394,6 +398,7 @@ impl From<&Identifier> for IdentifierKind {
         match ident {
             Identifier::Ethereum(_) => IdentifierKind::Ethereum,
             Identifier::Passkey(_) => IdentifierKind::Passkey,
+            Identifier::Solana(_) => IdentifierKind::Solana,
         }
     }
 }
@@ -441,6 +446,12 @@ impl From<UnverifiedSignature> for SignatureWrapperProto {
                     client_data_json: sig.client_data_json,
                 })
             }
+            UnverifiedSignature::Ed25519Wallet(sig) => {
+                SignatureKindProto::Ed25519Wallet(RecoverableEd25519SignatureProto {
+                    bytes: sig.signature_bytes,
+                    public_key: sig.verifying_key.as_bytes().to_vec(),
+                })
+            }
         };
 
         Self {
@@ -487,6 +498,9 @@ impl From<MemberIdentifierKindProto> for MemberIdentifier {
             MemberIdentifierKindProto::Passkey(PasskeyProto { key, relying_party }) => {
                 Self::Passkey(ident::Passkey { key, relying_party })
             }
+            MemberIdentifierKindProto::SolanaAddress(address) => {
+                Self::Solana(ident::Solana(address))
+            }
         }
     }
 }
@@ -540,6 +554,9 @@ impl From<MemberIdentifier> for MemberIdentifierProto {
                     })),
                 }
             }
+            MemberIdentifier::Solana(ident::Solana(address)) => MemberIdentifierProto {
+                kind: Some(MemberIdentifierKindProto::SolanaAddress(address)),
+            },
         }
     }
 }
@@ -561,6 +578,9 @@ impl TryFrom<MemberIdentifierProto> for MemberIdentifier {
                     relying_party,
                 }))
             }
+            Some(MemberIdentifierKindProto::SolanaAddress(address)) => {
+                Ok(MemberIdentifier::Solana(ident::Solana(address)))
+            }
             None => Err(ConversionError::Missing {
                 item: "member_identifier",
                 r#type: std::any::type_name::<MemberIdentifierKindProto>(),
@@ -172,6 +172,8 @@ pub enum SignatureKind {
     InstallationKey,
     LegacyDelegated,
     P256,
+    /// An ed25519 wallet, such as solana, signing the raw signature text
+    Ed25519Wallet,
 }
 
 impl std::fmt::Display for SignatureKind {
@@ -182,6 +184,7 @@ impl std::fmt::Display for SignatureKind {
             SignatureKind::InstallationKey => write!(f, "installation-key"),
             SignatureKind::LegacyDelegated => write!(f, "legacy-delegated"),
             SignatureKind::P256 => write!(f, "p256"),
+            SignatureKind::Ed25519Wallet => write!(f, "ed25519-wallet"),
         }
     }
 }
@@ -87,6 +87,7 @@ impl TryFrom<MemberIdentifier> for Identifier {
         let ident = match ident {
             MemberIdentifier::Ethereum(eth) => Self::Ethereum(eth),
             MemberIdentifier::Passkey(passkey) => Self::Passkey(passkey),
+            MemberIdentifier::Solana(sol) => Self::Solana(sol),
             MemberIdentifier::Installation(_) => {
                 return Err(AssociationError::NotIdentifier(
                     "Installation Keys".to_string(),
@@ -174,6 +175,7 @@ impl AssociationState {
             .filter_map(|member| match member.identifier {
                 MemberIdentifier::Ethereum(eth) => Some(Identifier::Ethereum(eth)),
                 MemberIdentifier::Passkey(pk) => Some(Identifier::Passkey(pk)),
+                MemberIdentifier::Solana(sol) => Some(Identifier::Solana(sol)),
                 _ => None,
             })
             .collect()
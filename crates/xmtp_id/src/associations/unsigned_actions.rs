@@ -34,6 +34,7 @@ impl SignatureTextCreator for UnsignedAddAssociation {
             MemberKind::Installation => "Grant messaging access to app",
             MemberKind::Ethereum => "Link address to inbox",
             MemberKind::Passkey => "Link passkey to inbox",
+            MemberKind::Solana => "Link address to inbox",
         };
         format!("- {prefix}\n  ({id_kind}: {})", self.new_member_identifier)
     }
@@ -52,6 +53,7 @@ impl SignatureTextCreator for UnsignedRevokeAssociation {
             MemberKind::Installation => "Revoke messaging access from app",
             MemberKind::Ethereum => "Unlink address from inbox",
             MemberKind::Passkey => "Unlink passkey from inbox",
+            MemberKind::Solana => "Unlink address from inbox",
         };
         format!("- {prefix}\n  ({id_kind}: {})", self.revoked_member)
     }
@@ -126,7 +128,7 @@ impl SignatureTextCreator for UnsignedIdentityUpdate {
 
 fn get_identifier_text(kind: &MemberKind) -> String {
     match kind {
-        MemberKind::Ethereum => "Address".to_string(),
+        MemberKind::Ethereum | MemberKind::Solana => "Address".to_string(),
         MemberKind::Installation => "ID".to_string(),
         MemberKind::Passkey => "Passkey".to_string(),
     }
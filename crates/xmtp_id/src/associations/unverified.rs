@@ -247,6 +247,7 @@ pub enum UnverifiedSignature {
     SmartContractWallet(UnverifiedSmartContractWalletSignature),
     LegacyDelegated(UnverifiedLegacyDelegatedSignature),
     Passkey(UnverifiedPasskeySignature),
+    Ed25519Wallet(UnverifiedEd25519WalletSignature),
 }
 
 impl UnverifiedSignature {
@@ -286,6 +287,11 @@ impl UnverifiedSignature {
                 &sig.authenticator_data,
                 &sig.client_data_json,
             ),
+            UnverifiedSignature::Ed25519Wallet(sig) => VerifiedSignature::from_ed25519_wallet(
+                signature_text,
+                &sig.signature_bytes,
+                *sig.verifying_key(),
+            ),
         }
     }
 
@@ -303,6 +309,25 @@ impl UnverifiedSignature {
         ))
     }
 
+    pub fn new_ed25519_wallet(
+        signature: Vec<u8>,
+        verifying_key: ed25519_dalek::VerifyingKey,
+    ) -> Self {
+        Self::Ed25519Wallet(UnverifiedEd25519WalletSignature::new(
+            signature,
+            verifying_key,
+        ))
+    }
+
+    /// Builds an ed25519 wallet signature, deriving the verifying key from a base58 Solana address
+    pub fn new_solana(
+        signature: Vec<u8>,
+        address: &super::ident::Solana,
+    ) -> Result<Self, SignatureError> {
+        let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&address.public_key()?)?;
+        Ok(Self::new_ed25519_wallet(signature, verifying_key))
+    }
+
     pub fn new_passkey(
         public_key: Vec<u8>,
         signature: Vec<u8>,
@@ -365,6 +390,26 @@ impl UnverifiedInstallationKeySignature {
     }
 }
 
+/// A signature by an ed25519 wallet, such as solana, over the raw signature text
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnverifiedEd25519WalletSignature {
+    pub(crate) signature_bytes: Vec<u8>,
+    pub(crate) verifying_key: Box<ed25519_dalek::VerifyingKey>,
+}
+
+impl UnverifiedEd25519WalletSignature {
+    pub fn new(signature_bytes: Vec<u8>, verifying_key: ed25519_dalek::VerifyingKey) -> Self {
+        Self {
+            signature_bytes,
+            verifying_key: Box::new(verifying_key),
+        }
+    }
+
+    pub fn verifying_key(&self) -> &ed25519_dalek::VerifyingKey {
+        self.verifying_key.as_ref()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnverifiedPasskeySignature {
     // This json string contains the challenge we sent out
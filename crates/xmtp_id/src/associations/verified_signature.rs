@@ -155,6 +155,23 @@ impl VerifiedSignature {
         ))
     }
 
+    /// Verifies an ed25519 wallet signature, such as a solana `signMessage`, over the raw
+    /// signature text. Unlike installation keys, wallets sign without a context.
+    pub fn from_ed25519_wallet<Text: AsRef<str>>(
+        signature_text: Text,
+        signature_bytes: &[u8],
+        verifying_key: ed25519_dalek::VerifyingKey,
+    ) -> Result<Self, SignatureError> {
+        let signature = ed25519_dalek::Signature::from_slice(signature_bytes)?;
+        verifying_key.verify_strict(signature_text.as_ref().as_bytes(), &signature)?;
+        Ok(Self::new(
+            MemberIdentifier::Solana(ident::Solana::from_public_key(verifying_key.as_bytes())),
+            SignatureKind::Ed25519Wallet,
+            signature_bytes.to_vec(),
+            None,
+        ))
+    }
+
     /// Verifies a legacy delegated signature and recovers the wallet address responsible
     /// associated with the signer.
     pub fn from_legacy_delegated<Text: AsRef<str>>(
@@ -305,6 +322,39 @@ mod tests {
         .expect_err("should fail with incorrect verifying key");
     }
 
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_ed25519_wallet() {
+        let wallet = ed25519_dalek::SigningKey::from_bytes(&xmtp_common::rand_array::<32>());
+        let signature_text = "test signature text";
+        let sig = ed25519_dalek::Signer::sign(&wallet, signature_text.as_bytes()).to_bytes();
+
+        let verified_sig =
+            VerifiedSignature::from_ed25519_wallet(signature_text, &sig, wallet.verifying_key())
+                .expect("should succeed");
+        let expected =
+            MemberIdentifier::solana(bs58::encode(wallet.verifying_key().as_bytes()).into_string())
+                .unwrap();
+        assert_eq!(expected, verified_sig.signer);
+        assert_eq!(SignatureKind::Ed25519Wallet, verified_sig.kind);
+        assert_eq!(verified_sig.raw_bytes, sig);
+
+        VerifiedSignature::from_ed25519_wallet(
+            "wrong signature text",
+            &sig,
+            wallet.verifying_key(),
+        )
+        .expect_err("should fail with incorrect signature text");
+
+        // An installation key signature is made with a context, so it is not a wallet signature
+        let key = XmtpInstallationCredential::new();
+        let sig = key
+            .credential_sign::<InstallationKeyContext>(signature_text)
+            .unwrap();
+        VerifiedSignature::from_ed25519_wallet(signature_text, &sig, key.verifying_key())
+            .expect_err("should fail with a context signature");
+    }
+
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     fn validate_good_key_round_trip() {
@@ -110,6 +110,23 @@ impl InboxOwner for PrivateKeySigner {
     }
 }
 
+/// A solana, or other ed25519, wallet keypair
+impl InboxOwner for ed25519_dalek::SigningKey {
+    fn get_identifier(&self) -> Result<Identifier, IdentifierValidationError> {
+        Ok(Identifier::Solana(
+            associations::ident::Solana::from_public_key(self.verifying_key().as_bytes()),
+        ))
+    }
+
+    fn sign(&self, text: &str) -> Result<UnverifiedSignature, SignatureError> {
+        let signature = ed25519_dalek::Signer::sign(self, text.as_bytes());
+        Ok(UnverifiedSignature::new_ed25519_wallet(
+            signature.to_bytes().to_vec(),
+            self.verifying_key(),
+        ))
+    }
+}
+
 impl<T> InboxOwner for &T
 where
     T: InboxOwner,
@@ -1,4 +1,5 @@
 mod chain_rpc_verifier;
+mod quorum_verifier;
 mod remote_signature_verifier;
 use crate::associations::AccountId;
 use alloy::{
@@ -6,7 +7,9 @@ use alloy::{
     providers::DynProvider,
 };
 pub use chain_rpc_verifier::*;
+pub use quorum_verifier::*;
 pub use remote_signature_verifier::*;
+use serde::Deserialize;
 use std::{collections::HashMap, fs, path::Path, sync::Arc};
 use thiserror::Error;
 use tracing::info;
@@ -60,6 +63,21 @@ pub enum VerifierError {
     /// Hash has invalid length or format. Not retryable.
     #[error("hash was invalid length or otherwise malformed")]
     InvalidHash(Vec<u8>),
+    /// Invalid quorum.
+    ///
+    /// Quorum must be a majority of the providers. Not retryable.
+    #[error("quorum of {quorum} is invalid for {providers} providers")]
+    InvalidQuorum { quorum: usize, providers: usize },
+    /// Quorum not reached.
+    ///
+    /// Chain RPC providers did not agree on a result. Retryable.
+    #[error("quorum of {quorum} not reached: {valid} valid, {invalid} invalid, {errors} errors")]
+    QuorumNotReached {
+        quorum: usize,
+        valid: usize,
+        invalid: usize,
+        errors: usize,
+    },
     /// Other error.
     ///
     /// Unclassified verifier error. May be retryable.
@@ -74,6 +92,7 @@ impl RetryableError for VerifierError {
             Io(_) => true,
             NoVerifier(_) => true,
             Provider(_) => true,
+            QuorumNotReached { .. } => true,
             Other(o) => o.is_retryable(),
             _ => false,
         }
@@ -159,14 +178,26 @@ pub struct ValidationResponse {
     pub error: Option<String>,
 }
 
+/// The RPC configuration for one chain in a `chain_urls` file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ChainRpcConfig {
+    /// A single RPC url whose answer is trusted on its own
+    Single(Url),
+    /// Several RPC urls, of which at least `quorum` must return the same answer
+    Quorum { urls: Vec<Url>, quorum: usize },
+}
+
 pub struct MultiSmartContractSignatureVerifier {
     verifiers: HashMap<String, Box<dyn SmartContractSignatureVerifier>>,
+    quorum_metrics: HashMap<String, Arc<QuorumMetrics>>,
 }
 
 impl std::fmt::Debug for MultiSmartContractSignatureVerifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MultiSmartContractSignatureVerifier")
             .field("verifiers", &self.verifiers.keys().collect::<Vec<_>>())
+            .field("quorums", &self.quorum_metrics.keys().collect::<Vec<_>>())
             .finish()
     }
 }
@@ -183,7 +214,31 @@ impl MultiSmartContractSignatureVerifier {
             })
             .collect::<Result<HashMap<_, _>, _>>()?;
 
-        Ok(Self { verifiers })
+        Ok(Self {
+            verifiers,
+            quorum_metrics: HashMap::new(),
+        })
+    }
+
+    pub fn new_from_config(config: HashMap<String, ChainRpcConfig>) -> Result<Self, VerifierError> {
+        let mut verifier = Self::new(HashMap::new())?;
+        for (chain_id, chain_config) in config {
+            match chain_config {
+                ChainRpcConfig::Single(url) => verifier.add_verifier(chain_id, url.to_string())?,
+                ChainRpcConfig::Quorum { urls, quorum } => {
+                    let quorum_verifier =
+                        QuorumSmartContractSignatureVerifier::new_from_urls(urls, quorum)?;
+                    verifier
+                        .quorum_metrics
+                        .insert(chain_id.clone(), quorum_verifier.metrics());
+                    verifier
+                        .verifiers
+                        .insert(chain_id, Box::new(quorum_verifier));
+                }
+            }
+        }
+
+        Ok(verifier)
     }
 
     pub fn new_providers(providers: HashMap<String, DynProvider>) -> Result<Self, VerifierError> {
@@ -196,7 +251,10 @@ impl MultiSmartContractSignatureVerifier {
                 )
             })
             .collect();
-        Ok(Self { verifiers })
+        Ok(Self {
+            verifiers,
+            quorum_metrics: HashMap::new(),
+        })
     }
 
     pub fn new_from_env() -> Result<Self, VerifierError> {
@@ -206,9 +264,14 @@ impl MultiSmartContractSignatureVerifier {
 
     pub fn new_from_file(path: impl AsRef<Path>) -> Result<Self, VerifierError> {
         let json = fs::read_to_string(path.as_ref())?;
-        let urls: HashMap<String, Url> = serde_json::from_str(&json)?;
+        let config: HashMap<String, ChainRpcConfig> = serde_json::from_str(&json)?;
 
-        Self::new(urls)
+        Self::new_from_config(config)
+    }
+
+    /// Metrics for each chain that is verified by a quorum of providers
+    pub fn quorum_metrics(&self) -> &HashMap<String, Arc<QuorumMetrics>> {
+        &self.quorum_metrics
     }
 
     /// Upgrade the default urls to paid/private/alternative urls if the env vars are present.
@@ -218,6 +281,7 @@ impl MultiSmartContractSignatureVerifier {
             let eip_id = id.split(":").nth(1).ok_or(VerifierError::MalformedEipUrl)?;
             if let Ok(url) = std::env::var(format!("CHAIN_RPC_{eip_id}")) {
                 *verifier = Box::new(RpcSmartContractWalletVerifier::new(url)?);
+                self.quorum_metrics.remove(id);
             } else {
                 info!("No upgraded chain url for chain {id}, using default.");
             };
@@ -235,17 +299,14 @@ impl MultiSmartContractSignatureVerifier {
     }
 
     pub fn add_verifier(&mut self, id: String, url: String) -> Result<(), VerifierError> {
+        self.quorum_metrics.remove(&id);
         self.verifiers
             .insert(id, Box::new(RpcSmartContractWalletVerifier::new(url)?));
         Ok(())
     }
 
     pub fn add_anvil(&mut self, url: String) -> Result<(), VerifierError> {
-        self.verifiers.insert(
-            "eip155:31337".to_string(),
-            Box::new(RpcSmartContractWalletVerifier::new(url)?),
-        );
-        Ok(())
+        self.add_verifier("eip155:31337".to_string(), url)
     }
 }
 
@@ -0,0 +1,350 @@
+//! Verification of smart contract wallet signatures against several RPC providers for the same
+//! chain, so that no single faulty or malicious provider decides the result.
+use super::{
+    RpcSmartContractWalletVerifier, SmartContractSignatureVerifier, ValidationResponse,
+    VerifierError,
+};
+use crate::associations::AccountId;
+use alloy::primitives::{BlockNumber, Bytes};
+use futures::future::join_all;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use url::Url;
+
+/// Counters for the checks made by a [`QuorumSmartContractSignatureVerifier`]
+#[derive(Default, Debug)]
+pub struct QuorumMetrics {
+    checks: AtomicUsize,
+    disagreements: AtomicUsize,
+    provider_errors: AtomicUsize,
+    quorum_failures: AtomicUsize,
+}
+
+impl QuorumMetrics {
+    /// Total signatures checked
+    pub fn checks(&self) -> usize {
+        self.checks.load(Ordering::Relaxed)
+    }
+
+    /// Checks where some providers found the signature valid and others found it invalid
+    pub fn disagreements(&self) -> usize {
+        self.disagreements.load(Ordering::Relaxed)
+    }
+
+    /// Individual provider calls that returned an error
+    pub fn provider_errors(&self) -> usize {
+        self.provider_errors.load(Ordering::Relaxed)
+    }
+
+    /// Checks where no answer reached the quorum
+    pub fn quorum_failures(&self) -> usize {
+        self.quorum_failures.load(Ordering::Relaxed)
+    }
+}
+
+/// Sends each check to every provider and only accepts an answer that at least `quorum`
+/// providers agree on. The quorum must be a majority, so no minority of providers can decide the
+/// result.
+pub struct QuorumSmartContractSignatureVerifier {
+    verifiers: Vec<Box<dyn SmartContractSignatureVerifier>>,
+    quorum: usize,
+    metrics: Arc<QuorumMetrics>,
+}
+
+impl std::fmt::Debug for QuorumSmartContractSignatureVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QuorumSmartContractSignatureVerifier")
+            .field("providers", &self.verifiers.len())
+            .field("quorum", &self.quorum)
+            .finish()
+    }
+}
+
+impl QuorumSmartContractSignatureVerifier {
+    pub fn new(
+        verifiers: Vec<Box<dyn SmartContractSignatureVerifier>>,
+        quorum: usize,
+    ) -> Result<Self, VerifierError> {
+        if quorum <= verifiers.len() / 2 || quorum > verifiers.len() {
+            return Err(VerifierError::InvalidQuorum {
+                quorum,
+                providers: verifiers.len(),
+            });
+        }
+
+        Ok(Self {
+            verifiers,
+            quorum,
+            metrics: Arc::default(),
+        })
+    }
+
+    pub fn new_from_urls(urls: Vec<Url>, quorum: usize) -> Result<Self, VerifierError> {
+        let verifiers = urls
+            .into_iter()
+            .map(|url| {
+                Ok::<_, VerifierError>(Box::new(RpcSmartContractWalletVerifier::new(
+                    url.to_string(),
+                )?) as Box<_>)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(verifiers, quorum)
+    }
+
+    pub fn metrics(&self) -> Arc<QuorumMetrics> {
+        self.metrics.clone()
+    }
+}
+
+#[xmtp_common::async_trait]
+impl SmartContractSignatureVerifier for QuorumSmartContractSignatureVerifier {
+    async fn is_valid_signature(
+        &self,
+        account_id: AccountId,
+        hash: [u8; 32],
+        signature: Bytes,
+        block_number: Option<BlockNumber>,
+    ) -> Result<ValidationResponse, VerifierError> {
+        let responses = join_all(self.verifiers.iter().map(|verifier| {
+            verifier.is_valid_signature(account_id.clone(), hash, signature.clone(), block_number)
+        }))
+        .await;
+        self.metrics.checks.fetch_add(1, Ordering::Relaxed);
+
+        let (mut valid, mut invalid, mut errors) = (vec![], vec![], 0);
+        for (provider, response) in responses.into_iter().enumerate() {
+            match response {
+                Ok(response) if response.is_valid => valid.push(response),
+                Ok(response) => invalid.push(response),
+                Err(e) => {
+                    errors += 1;
+                    tracing::warn!(
+                        chain_id = %account_id.chain_id,
+                        provider,
+                        "smart contract wallet verifier provider failed: {e}"
+                    );
+                }
+            }
+        }
+        self.metrics
+            .provider_errors
+            .fetch_add(errors, Ordering::Relaxed);
+
+        if !valid.is_empty() && !invalid.is_empty() {
+            self.metrics.disagreements.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                chain_id = %account_id.chain_id,
+                account = %account_id.account_address,
+                valid = valid.len(),
+                invalid = invalid.len(),
+                errors,
+                "smart contract wallet verifier providers disagree"
+            );
+        }
+
+        let agreed = match (valid.len() >= self.quorum, invalid.len() >= self.quorum) {
+            (true, false) => valid,
+            (false, true) => invalid,
+            _ => {
+                self.metrics.quorum_failures.fetch_add(1, Ordering::Relaxed);
+                return Err(VerifierError::QuorumNotReached {
+                    quorum: self.quorum,
+                    valid: valid.len(),
+                    invalid: invalid.len(),
+                    errors,
+                });
+            }
+        };
+
+        Ok(ValidationResponse {
+            is_valid: agreed[0].is_valid,
+            block_number: agreed.iter().filter_map(|r| r.block_number).min(),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::associations::test_utils::MockSmartContractSignatureVerifier;
+
+    fn quorum_of(answers: &[bool], quorum: usize) -> QuorumSmartContractSignatureVerifier {
+        let verifiers = answers
+            .iter()
+            .map(|answer| Box::new(MockSmartContractSignatureVerifier::new(*answer)) as Box<_>)
+            .collect();
+        QuorumSmartContractSignatureVerifier::new(verifiers, quorum).unwrap()
+    }
+
+    async fn check(
+        verifier: &QuorumSmartContractSignatureVerifier,
+    ) -> Result<ValidationResponse, VerifierError> {
+        let account_id = AccountId::new_evm(1, "0xaaa".to_string());
+        verifier
+            .is_valid_signature(account_id, [0; 32], Bytes::new(), None)
+            .await
+    }
+
+    #[xmtp_common::test]
+    async fn test_quorum_reached() {
+        let verifier = quorum_of(&[true, true, false], 2);
+        assert!(check(&verifier).await.unwrap().is_valid);
+        assert_eq!(verifier.metrics().checks(), 1);
+        assert_eq!(verifier.metrics().disagreements(), 1);
+
+        let verifier = quorum_of(&[false, false, true], 2);
+        assert!(!check(&verifier).await.unwrap().is_valid);
+    }
+
+    #[xmtp_common::test]
+    async fn test_quorum_not_reached() {
+        let verifier = quorum_of(&[true, false, false], 3);
+        assert!(matches!(
+            check(&verifier).await,
+            Err(VerifierError::QuorumNotReached {
+                valid: 1,
+                invalid: 2,
+                ..
+            })
+        ));
+        assert_eq!(verifier.metrics().quorum_failures(), 1);
+
+        // Neither answer reaches the quorum when a provider fails
+        let verifiers = vec![
+            Box::new(MockSmartContractSignatureVerifier::new(true)) as Box<_>,
+            Box::new(MockSmartContractSignatureVerifier::new(false)) as Box<_>,
+        ];
+        let verifier = QuorumSmartContractSignatureVerifier::new(verifiers, 2).unwrap();
+        assert!(matches!(
+            check(&verifier).await,
+            Err(VerifierError::QuorumNotReached { .. })
+        ));
+    }
+
+    #[xmtp_common::test]
+    fn test_quorum_from_chain_urls_config() {
+        use crate::scw_verifier::{ChainRpcConfig, MultiSmartContractSignatureVerifier};
+        use std::collections::HashMap;
+
+        let config: HashMap<String, ChainRpcConfig> = serde_json::from_str(
+            r#"{
+                "eip155:1": "https://one.example",
+                "eip155:10": {
+                    "urls": ["https://a.example", "https://b.example", "https://c.example"],
+                    "quorum": 2
+                }
+            }"#,
+        )
+        .unwrap();
+        let verifier = MultiSmartContractSignatureVerifier::new_from_config(config).unwrap();
+        assert_eq!(
+            verifier.quorum_metrics().keys().collect::<Vec<_>>(),
+            vec!["eip155:10"]
+        );
+
+        let config: HashMap<String, ChainRpcConfig> = serde_json::from_str(
+            r#"{ "eip155:10": { "urls": ["https://a.example"], "quorum": 2 } }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            MultiSmartContractSignatureVerifier::new_from_config(config),
+            Err(VerifierError::InvalidQuorum { .. })
+        ));
+    }
+
+    #[xmtp_common::test]
+    fn test_invalid_quorum() {
+        let mock = || Box::new(MockSmartContractSignatureVerifier::new(true)) as Box<_>;
+        assert!(matches!(
+            QuorumSmartContractSignatureVerifier::new(vec![mock(), mock()], 0),
+            Err(VerifierError::InvalidQuorum { .. })
+        ));
+        assert!(matches!(
+            QuorumSmartContractSignatureVerifier::new(vec![mock(), mock()], 3),
+            Err(VerifierError::InvalidQuorum { .. })
+        ));
+        // A single provider out of three must not decide the result
+        assert!(matches!(
+            QuorumSmartContractSignatureVerifier::new(vec![mock(), mock(), mock()], 1),
+            Err(VerifierError::InvalidQuorum { .. })
+        ));
+        // Half of the providers is not a majority
+        assert!(matches!(
+            QuorumSmartContractSignatureVerifier::new(vec![mock(), mock(), mock(), mock()], 2),
+            Err(VerifierError::InvalidQuorum { .. })
+        ));
+    }
+}
+
+// Anvil does not work with WASM
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod anvil_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::associations::test_utils::MockSmartContractSignatureVerifier;
+    use crate::utils::test::{SignatureWithNonce, SmartWalletContext, docker_smart_wallet};
+    use alloy::dyn_abi::SolType;
+    use alloy::primitives::{B256, U256};
+    use alloy::providers::Provider;
+    use alloy::signers::Signer;
+    use std::time::Duration;
+
+    #[rstest::rstest]
+    #[xmtp_common::timeout(Duration::from_secs(60))]
+    #[tokio::test]
+    async fn test_quorum_outvotes_lying_provider(
+        #[future] docker_smart_wallet: SmartWalletContext,
+    ) {
+        let SmartWalletContext {
+            factory,
+            sw,
+            owner0,
+            sw_address,
+            ..
+        } = docker_smart_wallet.await;
+        let provider = factory.provider();
+        let chain_id = provider.get_chain_id().await.unwrap();
+        let account_id = AccountId::new_evm(chain_id, format!("{sw_address}"));
+        let hash = B256::random();
+        let replay_safe_hash = sw.replaySafeHash(hash).call().await.unwrap();
+        let signature = owner0.sign_hash(&replay_safe_hash).await.unwrap();
+        let signature: Bytes =
+            SignatureWithNonce::abi_encode(&(U256::from(0), signature.as_bytes())).into();
+
+        let rpc = || {
+            Box::new(RpcSmartContractWalletVerifier::new_from_provider(
+                provider.clone(),
+            )) as Box<dyn SmartContractSignatureVerifier>
+        };
+        // The lying provider claims every signature is valid
+        let verifier = QuorumSmartContractSignatureVerifier::new(
+            vec![
+                rpc(),
+                rpc(),
+                Box::new(MockSmartContractSignatureVerifier::new(true)),
+            ],
+            2,
+        )
+        .unwrap();
+
+        let res = verifier
+            .is_valid_signature(account_id.clone(), *hash, signature.clone(), None)
+            .await
+            .unwrap();
+        assert!(res.is_valid);
+        assert_eq!(verifier.metrics().disagreements(), 0);
+
+        let res = verifier
+            .is_valid_signature(account_id, *B256::random(), signature, None)
+            .await
+            .unwrap();
+        assert!(!res.is_valid);
+        assert_eq!(verifier.metrics().checks(), 2);
+        assert_eq!(verifier.metrics().disagreements(), 1);
+    }
+}
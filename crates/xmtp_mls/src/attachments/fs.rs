@@ -0,0 +1,108 @@
+use super::storage::{StorageProvider, StorageProviderError};
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+const FILE_SCHEME: &str = "file://";
+
+/// A [`StorageProvider`] that keeps attachments as files in a local directory.
+/// Uploads in progress are kept next to the finished files with a `.part` extension.
+#[derive(Debug, Clone)]
+pub struct FileSystemStorageProvider {
+    root: PathBuf,
+}
+
+impl FileSystemStorageProvider {
+    pub fn new(root: impl Into<PathBuf>) -> Result<Self, StorageProviderError> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        Ok(Self { root })
+    }
+
+    fn object_path(&self, key: &str) -> Result<PathBuf, StorageProviderError> {
+        // Keys are hex digests, anything else could escape the root directory
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(StorageProviderError::Rejected(format!(
+                "invalid object key {key}"
+            )));
+        }
+        Ok(self.root.join(key))
+    }
+
+    fn part_path(&self, key: &str) -> Result<PathBuf, StorageProviderError> {
+        Ok(self.object_path(key)?.with_extension("part"))
+    }
+
+    fn path_from_url<'a>(&self, url: &'a str) -> Result<&'a Path, StorageProviderError> {
+        let path = url
+            .strip_prefix(FILE_SCHEME)
+            .map(Path::new)
+            .ok_or_else(|| StorageProviderError::Rejected(format!("not a file url: {url}")))?;
+        if path.parent() != Some(self.root.as_path()) {
+            return Err(StorageProviderError::Rejected(format!(
+                "{url} is not stored by this provider"
+            )));
+        }
+        Ok(path)
+    }
+}
+
+#[xmtp_common::async_trait]
+impl StorageProvider for FileSystemStorageProvider {
+    async fn uploaded_len(&self, key: &str) -> Result<u64, StorageProviderError> {
+        match fs::metadata(self.part_path(key)?) {
+            Ok(metadata) => Ok(metadata.len()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn upload_chunk(
+        &self,
+        key: &str,
+        offset: u64,
+        chunk: &[u8],
+    ) -> Result<(), StorageProviderError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(self.part_path(key)?)?;
+        // Drop anything written past `offset` by an earlier attempt
+        file.set_len(offset)?;
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(chunk)?;
+        file.sync_data()?;
+        Ok(())
+    }
+
+    async fn complete_upload(&self, key: &str) -> Result<String, StorageProviderError> {
+        let path = self.object_path(key)?;
+        let part = self.part_path(key)?;
+        if part.exists() {
+            fs::rename(&part, &path)?;
+        } else if !path.exists() {
+            return Err(StorageProviderError::NotFound(key.to_string()));
+        }
+        Ok(format!("{FILE_SCHEME}{}", path.display()))
+    }
+
+    async fn download_range(
+        &self,
+        url: &str,
+        offset: u64,
+        len: u64,
+    ) -> Result<Vec<u8>, StorageProviderError> {
+        let mut file = match fs::File::open(self.path_from_url(url)?) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(StorageProviderError::NotFound(url.to_string()));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        file.seek(SeekFrom::Start(offset))?;
+        let mut buf = Vec::new();
+        file.take(len).read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
@@ -0,0 +1,269 @@
+//! Upload and download of remote attachments.
+//!
+//! [`AttachmentService`] encrypts attachments, transfers them through a
+//! pluggable [`StorageProvider`] and keeps downloaded payloads in a bounded
+//! cache in the encrypted database, keyed by the id of the message the
+//! attachment was sent in.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod fs;
+mod storage;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use fs::FileSystemStorageProvider;
+pub use storage::{StorageProvider, StorageProviderError};
+
+use crate::context::XmtpSharedContext;
+use futures::{StreamExt, TryStreamExt, stream};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use thiserror::Error;
+use xmtp_common::{Retry, RetryableError, retry_async};
+use xmtp_configuration::{
+    ATTACHMENT_CACHE_MAX_BYTES, ATTACHMENT_TRANSFER_CHUNK_SIZE, ATTACHMENT_TRANSFER_CONCURRENCY,
+};
+use xmtp_content_types::{
+    CodecError,
+    attachment::Attachment,
+    encryption::sha256,
+    remote_attachment::{
+        EncryptedAttachment, RemoteAttachment, decrypt_attachment, encrypt_attachment,
+    },
+};
+use xmtp_db::attachment_cache::StoredCachedAttachment;
+use xmtp_db::prelude::*;
+use xmtp_proto::xmtp::mls::message_contents::content_types::MultiRemoteAttachment;
+
+#[derive(Debug, Error)]
+pub enum AttachmentError {
+    #[error(transparent)]
+    Storage(#[from] StorageProviderError),
+    #[error(transparent)]
+    Codec(#[from] CodecError),
+    #[error(transparent)]
+    Connection(#[from] xmtp_db::ConnectionError),
+    #[error("content digest mismatch: expected {expected}, got {actual}")]
+    DigestMismatch { expected: String, actual: String },
+    #[error("attachment is larger than its declared length of {0} bytes")]
+    LengthExceeded(u32),
+}
+
+impl RetryableError for AttachmentError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::Storage(e) => e.is_retryable(),
+            Self::Connection(e) => e.is_retryable(),
+            Self::Codec(_) | Self::DigestMismatch { .. } | Self::LengthExceeded(_) => false,
+        }
+    }
+}
+
+/// Uploads and downloads remote attachments through a [`StorageProvider`].
+pub struct AttachmentService<Context, Storage> {
+    context: Context,
+    storage: Storage,
+    max_cache_bytes: i64,
+    /// Payloads of interrupted downloads by content digest, so the next
+    /// attempt resumes where the last one stopped.
+    partial_downloads: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl<Context, Storage> AttachmentService<Context, Storage>
+where
+    Context: XmtpSharedContext,
+    Storage: StorageProvider,
+{
+    pub fn new(context: Context, storage: Storage) -> Self {
+        Self {
+            context,
+            storage,
+            max_cache_bytes: ATTACHMENT_CACHE_MAX_BYTES,
+            partial_downloads: Mutex::default(),
+        }
+    }
+
+    /// Limit the local attachment cache to `max_cache_bytes`. The least
+    /// recently accessed attachments are evicted first.
+    pub fn with_max_cache_bytes(mut self, max_cache_bytes: i64) -> Self {
+        self.max_cache_bytes = max_cache_bytes;
+        self
+    }
+
+    /// Encrypt and upload an attachment. The returned [`RemoteAttachment`] is
+    /// the content to send.
+    pub async fn upload(
+        &self,
+        attachment: Attachment,
+    ) -> Result<RemoteAttachment, AttachmentError> {
+        let encrypted = encrypt_attachment(attachment)?;
+        let url = self
+            .upload_payload(&encrypted.content_digest, &encrypted.payload)
+            .await?;
+        Ok(remote_attachment(encrypted, url))
+    }
+
+    /// Encrypt and upload several attachments in parallel.
+    pub async fn upload_multi(
+        &self,
+        attachments: Vec<Attachment>,
+    ) -> Result<MultiRemoteAttachment, AttachmentError> {
+        let attachments = stream::iter(attachments)
+            .map(|attachment| self.upload(attachment))
+            .buffered(ATTACHMENT_TRANSFER_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(MultiRemoteAttachment { attachments })
+    }
+
+    /// Download and decrypt the attachment sent in message `message_id`,
+    /// serving it from the local cache when possible.
+    pub async fn download(
+        &self,
+        message_id: &[u8],
+        remote: &RemoteAttachment,
+    ) -> Result<Attachment, AttachmentError> {
+        self.download_at(message_id, 0, remote).await
+    }
+
+    /// Download and decrypt all attachments of a multi remote attachment in parallel.
+    pub async fn download_multi(
+        &self,
+        message_id: &[u8],
+        multi: &MultiRemoteAttachment,
+    ) -> Result<Vec<Attachment>, AttachmentError> {
+        stream::iter(multi.attachments.iter().enumerate())
+            .map(|(index, remote)| self.download_at(message_id, index as i32, remote))
+            .buffered(ATTACHMENT_TRANSFER_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    async fn download_at(
+        &self,
+        message_id: &[u8],
+        attachment_index: i32,
+        remote: &RemoteAttachment,
+    ) -> Result<Attachment, AttachmentError> {
+        let db = self.context.db();
+        // A cached payload for a different digest is stale, download it again
+        if let Some(cached) = db.get_cached_attachment(message_id, attachment_index)?
+            && cached.content_digest == remote.content_digest
+        {
+            return Ok(decrypt_attachment(&cached.payload, remote)?);
+        }
+
+        let payload = self.download_payload(remote).await?;
+        let attachment = decrypt_attachment(&payload, remote)?;
+
+        db.insert_cached_attachment(&StoredCachedAttachment::new(
+            message_id.to_vec(),
+            attachment_index,
+            remote.content_digest.clone(),
+            payload,
+        ))?;
+        db.evict_cached_attachments(self.max_cache_bytes)?;
+
+        Ok(attachment)
+    }
+
+    /// Upload `payload` in chunks, resuming an earlier upload of the same
+    /// payload if the storage provider already has part of it.
+    async fn upload_payload(&self, key: &str, payload: &[u8]) -> Result<String, AttachmentError> {
+        let retry = Retry::default();
+        let uploaded = retry_async!(retry, (async { self.storage.uploaded_len(key).await }))?;
+        // A partial upload longer than the payload can't belong to it, start over
+        let mut offset = usize::try_from(uploaded)
+            .ok()
+            .filter(|uploaded| *uploaded <= payload.len())
+            .unwrap_or_default();
+
+        while offset < payload.len() {
+            let end = (offset + ATTACHMENT_TRANSFER_CHUNK_SIZE).min(payload.len());
+            let chunk = &payload[offset..end];
+            retry_async!(
+                retry,
+                (async { self.storage.upload_chunk(key, offset as u64, chunk).await })
+            )?;
+            offset = end;
+        }
+
+        Ok(retry_async!(
+            retry,
+            (async { self.storage.complete_upload(key).await })
+        )?)
+    }
+
+    /// Download the payload of `remote` in chunks and verify its digest.
+    async fn download_payload(
+        &self,
+        remote: &RemoteAttachment,
+    ) -> Result<Vec<u8>, AttachmentError> {
+        let retry = Retry::default();
+        let mut payload = self
+            .partial_downloads
+            .lock()
+            .remove(&remote.content_digest)
+            .unwrap_or_default();
+
+        loop {
+            let offset = payload.len() as u64;
+            let chunk = retry_async!(
+                retry,
+                (async {
+                    self.storage
+                        .download_range(&remote.url, offset, ATTACHMENT_TRANSFER_CHUNK_SIZE as u64)
+                        .await
+                })
+            );
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    // Keep what was downloaded so far for the next attempt
+                    self.partial_downloads
+                        .lock()
+                        .insert(remote.content_digest.clone(), payload);
+                    return Err(e.into());
+                }
+            };
+            let finished = chunk.len() < ATTACHMENT_TRANSFER_CHUNK_SIZE;
+            payload.extend_from_slice(&chunk);
+
+            if let Some(content_length) = remote.content_length
+                && payload.len() > content_length as usize
+            {
+                return Err(AttachmentError::LengthExceeded(content_length));
+            }
+            if finished {
+                break;
+            }
+        }
+
+        let actual = hex::encode(sha256(&payload));
+        if actual != remote.content_digest {
+            return Err(AttachmentError::DigestMismatch {
+                expected: remote.content_digest.clone(),
+                actual,
+            });
+        }
+        Ok(payload)
+    }
+}
+
+fn remote_attachment(encrypted: EncryptedAttachment, url: String) -> RemoteAttachment {
+    let scheme = url
+        .split_once("://")
+        .map(|(scheme, _)| scheme.to_string())
+        .unwrap_or_default();
+    RemoteAttachment {
+        content_digest: encrypted.content_digest,
+        secret: encrypted.secret,
+        nonce: encrypted.nonce,
+        salt: encrypted.salt,
+        scheme,
+        url,
+        content_length: Some(encrypted.content_length),
+        filename: encrypted.filename,
+    }
+}
@@ -0,0 +1,56 @@
+use thiserror::Error;
+use xmtp_common::{MaybeSend, MaybeSync, RetryableError};
+
+#[derive(Debug, Error)]
+pub enum StorageProviderError {
+    #[error("object not found: {0}")]
+    NotFound(String),
+    /// The storage backend could not be reached or failed temporarily.
+    #[error("storage unavailable: {0}")]
+    Unavailable(String),
+    /// The storage backend refused the request, retrying will not help.
+    #[error("storage request rejected: {0}")]
+    Rejected(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl RetryableError for StorageProviderError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Unavailable(_) | Self::Io(_))
+    }
+}
+
+/// A place encrypted attachment payloads are uploaded to and downloaded from,
+/// such as an object store or a CDN.
+///
+/// Objects are content addressed: the key of an upload is the hex-encoded
+/// SHA-256 digest of the payload. Transfers happen in chunks so an interrupted
+/// transfer can resume where it stopped instead of starting over.
+#[xmtp_common::async_trait]
+pub trait StorageProvider: MaybeSend + MaybeSync {
+    /// Number of bytes of the object at `key` already stored by an earlier,
+    /// interrupted upload. 0 if the upload has not started.
+    async fn uploaded_len(&self, key: &str) -> Result<u64, StorageProviderError>;
+
+    /// Write `chunk` to the object at `key`, starting at `offset`.
+    async fn upload_chunk(
+        &self,
+        key: &str,
+        offset: u64,
+        chunk: &[u8],
+    ) -> Result<(), StorageProviderError>;
+
+    /// Finish the upload of the object at `key`, returning the URL it can be
+    /// downloaded from.
+    async fn complete_upload(&self, key: &str) -> Result<String, StorageProviderError>;
+
+    /// Download at most `len` bytes of the object at `url`, starting at
+    /// `offset`. Returns fewer than `len` bytes once the end of the object is reached.
+    async fn download_range(
+        &self,
+        url: &str,
+        offset: u64,
+        len: u64,
+    ) -> Result<Vec<u8>, StorageProviderError>;
+}
@@ -0,0 +1,200 @@
+use super::*;
+use crate::tester;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+fn attachment(name: &str, len: usize) -> Attachment {
+    Attachment {
+        filename: Some(name.to_string()),
+        mime_type: "application/octet-stream".to_string(),
+        content: (0..len).map(|i| i as u8).collect(),
+    }
+}
+
+/// Fails the first transfer of a chunk past the first one, recording the
+/// offset of every chunk transferred.
+struct InterruptedStorage {
+    inner: FileSystemStorageProvider,
+    interrupted: AtomicBool,
+    offsets: Mutex<Vec<u64>>,
+}
+
+impl InterruptedStorage {
+    fn new(inner: FileSystemStorageProvider) -> Self {
+        Self {
+            inner,
+            interrupted: AtomicBool::new(false),
+            offsets: Mutex::default(),
+        }
+    }
+
+    fn transfer(&self, offset: u64) -> Result<(), StorageProviderError> {
+        if offset > 0 && !self.interrupted.swap(true, Ordering::SeqCst) {
+            return Err(StorageProviderError::Rejected("connection lost".into()));
+        }
+        self.offsets.lock().push(offset);
+        Ok(())
+    }
+}
+
+#[xmtp_common::async_trait]
+impl StorageProvider for InterruptedStorage {
+    async fn uploaded_len(&self, key: &str) -> Result<u64, StorageProviderError> {
+        self.inner.uploaded_len(key).await
+    }
+
+    async fn upload_chunk(
+        &self,
+        key: &str,
+        offset: u64,
+        chunk: &[u8],
+    ) -> Result<(), StorageProviderError> {
+        self.transfer(offset)?;
+        self.inner.upload_chunk(key, offset, chunk).await
+    }
+
+    async fn complete_upload(&self, key: &str) -> Result<String, StorageProviderError> {
+        self.inner.complete_upload(key).await
+    }
+
+    async fn download_range(
+        &self,
+        url: &str,
+        offset: u64,
+        len: u64,
+    ) -> Result<Vec<u8>, StorageProviderError> {
+        self.transfer(offset)?;
+        self.inner.download_range(url, offset, len).await
+    }
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_upload_and_download_attachment() {
+    tester!(alix);
+    let dir = tempfile::tempdir()?;
+    let service = AttachmentService::new(
+        alix.context.clone(),
+        FileSystemStorageProvider::new(dir.path())?,
+    );
+
+    let original = attachment("photo.jpg", 1024);
+    let remote = service.upload(original.clone()).await?;
+    assert_eq!(remote.scheme, "file");
+    assert_eq!(remote.filename.as_deref(), Some("photo.jpg"));
+
+    let downloaded = service.download(b"message", &remote).await?;
+    assert_eq!(downloaded.content, original.content);
+
+    // Served from the cache once the remote copy is gone
+    std::fs::remove_file(remote.url.strip_prefix("file://")?)?;
+    let cached = service.download(b"message", &remote).await?;
+    assert_eq!(cached.content, original.content);
+    assert!(service.download(b"other message", &remote).await.is_err());
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_upload_and_download_multi_attachment() {
+    tester!(alix);
+    let dir = tempfile::tempdir()?;
+    let service = AttachmentService::new(
+        alix.context.clone(),
+        FileSystemStorageProvider::new(dir.path())?,
+    );
+
+    let originals: Vec<_> = (0..6)
+        .map(|i| attachment(&format!("file-{i}"), 100 + i))
+        .collect();
+    let multi = service.upload_multi(originals.clone()).await?;
+    assert_eq!(multi.attachments.len(), originals.len());
+
+    let downloaded = service.download_multi(b"message", &multi).await?;
+    for (downloaded, original) in downloaded.iter().zip(&originals) {
+        assert_eq!(downloaded.filename, original.filename);
+        assert_eq!(downloaded.content, original.content);
+    }
+    assert!(
+        alix.context
+            .db()
+            .get_cached_attachment(b"message", 5)?
+            .is_some()
+    );
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_interrupted_transfers_resume() {
+    tester!(alix);
+    let dir = tempfile::tempdir()?;
+    let storage = InterruptedStorage::new(FileSystemStorageProvider::new(dir.path())?);
+    let service = AttachmentService::new(alix.context.clone(), storage);
+    let chunk = ATTACHMENT_TRANSFER_CHUNK_SIZE as u64;
+
+    // Encrypt once, so both upload attempts send the same payload
+    let encrypted =
+        encrypt_attachment(attachment("video.mp4", 2 * ATTACHMENT_TRANSFER_CHUNK_SIZE))?;
+    assert!(
+        service
+            .upload_payload(&encrypted.content_digest, &encrypted.payload)
+            .await
+            .is_err()
+    );
+    let url = service
+        .upload_payload(&encrypted.content_digest, &encrypted.payload)
+        .await?;
+    assert_eq!(*service.storage.offsets.lock(), vec![0, chunk, 2 * chunk]);
+
+    service.storage.interrupted.store(false, Ordering::SeqCst);
+    service.storage.offsets.lock().clear();
+    let remote = remote_attachment(encrypted, url);
+    assert!(service.download(b"message", &remote).await.is_err());
+    let downloaded = service.download(b"message", &remote).await?;
+    assert_eq!(downloaded.content.len(), 2 * ATTACHMENT_TRANSFER_CHUNK_SIZE);
+    assert_eq!(*service.storage.offsets.lock(), vec![0, chunk, 2 * chunk]);
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_tampered_attachment_is_rejected() {
+    tester!(alix);
+    let dir = tempfile::tempdir()?;
+    let service = AttachmentService::new(
+        alix.context.clone(),
+        FileSystemStorageProvider::new(dir.path())?,
+    );
+
+    let remote = service.upload(attachment("doc.pdf", 64)).await?;
+    let path = remote.url.strip_prefix("file://")?.to_string();
+    let mut payload = std::fs::read(&path)?;
+    payload[0] ^= 1;
+    std::fs::write(&path, payload)?;
+
+    let result = service.download(b"message", &remote).await;
+    assert!(matches!(
+        result,
+        Err(AttachmentError::DigestMismatch { .. })
+    ));
+    assert!(
+        alix.context
+            .db()
+            .get_cached_attachment(b"message", 0)?
+            .is_none()
+    );
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_attachment_cache_is_bounded() {
+    tester!(alix);
+    let dir = tempfile::tempdir()?;
+    let service = AttachmentService::new(
+        alix.context.clone(),
+        FileSystemStorageProvider::new(dir.path())?,
+    )
+    .with_max_cache_bytes(1500);
+
+    let first = service.upload(attachment("first", 1000)).await?;
+    let second = service.upload(attachment("second", 1000)).await?;
+    service.download(b"first", &first).await?;
+    service.download(b"second", &second).await?;
+
+    let db = alix.context.db();
+    assert!(db.cached_attachments_size()? <= 1500);
+    assert!(db.get_cached_attachment(b"first", 0)?.is_none());
+    assert!(db.get_cached_attachment(b"second", 0)?.is_some());
+}
@@ -6,7 +6,7 @@ use crate::{
     identity::{Identity, IdentityStrategy},
     identity_updates::load_identity_updates,
     mutex_registry::MutexRegistry,
-    utils::{VersionInfo, cleanup_duplicate_updates},
+    utils::{VersionInfo, backfill_message_search, cleanup_duplicate_updates},
     worker::{WorkerRunner, tasks::TaskWorker},
     worker::{device_sync::worker::SyncWorker, disappearing_messages::DisappearingMessagesWorker},
 };
@@ -484,6 +484,16 @@ impl<ApiClient, S, Db> ClientBuilder<ApiClient, S, Db> {
             }
         });
 
+        // Index messages stored before full-text search existed.
+        let conn = DbConnection::new(client.db());
+        let cancel = client.context.cancellation_token().clone();
+        xmtp_common::spawn(None, async move {
+            tokio::select! {
+                _ = cancel.cancelled() => {}
+                _ = backfill_message_search::perform(conn) => {}
+            }
+        });
+
         Ok(client)
     }
 
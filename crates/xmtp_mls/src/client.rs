@@ -6,7 +6,10 @@ use crate::{
         welcome_sync::WelcomeService,
     },
     identity::{Identity, IdentityError, parse_credential},
-    identity_updates::{IdentityUpdateError, IdentityUpdates, load_identity_updates},
+    identity_updates::{
+        AssociationStateChange, IdentityUpdateError, IdentityUpdates, InboxStatePoint,
+        load_identity_updates,
+    },
     mls_store::{MlsStore, MlsStoreError},
     subscriptions::{LocalEventError, LocalEvents, SyncWorkerEvent},
     utils::VersionInfo,
@@ -39,9 +42,10 @@ use xmtp_db::{
     db_connection::DbConnection,
     encrypted_store::conversation_list::ConversationListItem as DbConversationListItem,
     group::{ConversationType, GroupMembershipState, GroupQueryArgs},
-    group_message::StoredGroupMessage,
+    group_message::{MsgQueryArgs, StoredGroupMessage},
     identity::StoredIdentity,
     identity_cache::StoredIdentityKind,
+    message_search::MessageSearchHit,
 };
 use xmtp_db::{group::GroupQueryOrderBy, prelude::*};
 use xmtp_id::key_package::{KeyPackageVerificationError, VerifiedKeyPackageV2};
@@ -59,6 +63,7 @@ use xmtp_mls_common::{
     group_metadata::DmMembers,
     group_mutable_metadata::MessageDisappearingSettings,
 };
+use xmtp_proto::xmtp::mls::message_contents::ExternalInvitePayload;
 use xmtp_proto::{
     ConversionError,
     api::HasStats,
@@ -532,6 +537,38 @@ where
         Ok(state)
     }
 
+    /// Get the [`AssociationState`] for `inbox_id` as it was at a point in its history, e.g. to
+    /// check which installations an inbox controlled when an old message was sent
+    pub async fn inbox_state_at(
+        &self,
+        refresh_from_network: bool,
+        inbox_id: InboxIdRef<'_>,
+        at: InboxStatePoint,
+    ) -> Result<AssociationState, ClientError> {
+        let conn = self.context.db();
+        if refresh_from_network {
+            load_identity_updates(self.context.api(), &conn, &[inbox_id]).await?;
+        }
+        IdentityUpdates::new(&self.context)
+            .get_association_state_at(&conn, inbox_id, at)
+            .await
+    }
+
+    /// Get the change each identity update made to the [`AssociationState`] of `inbox_id`, oldest first
+    pub async fn inbox_state_timeline(
+        &self,
+        refresh_from_network: bool,
+        inbox_id: InboxIdRef<'_>,
+    ) -> Result<Vec<AssociationStateChange>, ClientError> {
+        let conn = self.context.db();
+        if refresh_from_network {
+            load_identity_updates(self.context.api(), &conn, &[inbox_id]).await?;
+        }
+        IdentityUpdates::new(&self.context)
+            .get_association_state_timeline(&conn, inbox_id)
+            .await
+    }
+
     /// Get the total number of inbox updates for `inbox_ids`. `refresh_from_network` will force
     /// a network refresh. May still access network if an inbox_id does not yet exist in the local
     /// cache.
@@ -694,6 +731,35 @@ where
         Ok(group)
     }
 
+    /// Join a group with an invite created by [`MlsGroup::create_invite`].
+    ///
+    /// Publishes a single external commit that adds this installation to the group
+    /// and records this inbox in the group membership. The invite's expiry and the
+    /// group's add member policy are enforced by every member when they validate the
+    /// commit.
+    pub async fn join_with_invite(
+        &self,
+        invite: &ExternalInvitePayload,
+    ) -> Result<MlsGroup<Context>, ClientError> {
+        self.ensure_identity_ready()?;
+
+        let group = MlsGroup::join_with_invite(self.context.clone(), invite).await?;
+
+        log_event!(
+            Event::JoinedWithInvite,
+            self.context.installation_id(),
+            group_id = group.group_id,
+            epoch = group.epoch().await?
+        );
+
+        // notify streams of our new group
+        let _ = self
+            .local_events
+            .send(LocalEvents::NewGroup(group.group_id));
+
+        Ok(group)
+    }
+
     /// Create a group with an initial set of members added
     pub async fn create_group_with_identifiers(
         &self,
@@ -899,7 +965,7 @@ where
 
         let group_id = message.group_id;
 
-        let enriched = enrich_messages(conn, &group_id, vec![message])?;
+        let enriched = enrich_messages(conn, &group_id, vec![message], Some(self.inbox_id()))?;
 
         // Since enrich_messages returns a Vec<DecodedMessage>, we can use .into_iter().next().ok_or(...) to take ownership without cloning.
         enriched
@@ -909,6 +975,19 @@ where
             .ok_or_else(|| ClientError::Generic("Failed to decode message".to_string()))
     }
 
+    /// Full-text search over the messages of every conversation, ordered by relevance.
+    ///
+    /// `args` narrows the search with the same filters as [`MlsGroup::find_messages`]; results
+    /// are always ranked, so its sort options are ignored.
+    pub fn search_messages(
+        &self,
+        query: &str,
+        args: &MsgQueryArgs,
+    ) -> Result<Vec<MessageSearchHit>, ClientError> {
+        let conn = self.context.db();
+        Ok(conn.search_messages(query, None, args)?)
+    }
+
     /// Delete a message by its ID
     /// This method is idempotent and will not error if the message is not found
     /// Returns the number of messages deleted (0 or 1)
@@ -986,6 +1065,8 @@ where
                         // The conversation_list view does not carry the key; use
                         // the timestamp proxy (display-only, never republished).
                         idempotency_key: conversation_item.sent_at_ns.unwrap_or_default().to_string(),
+                        expire_after_ns: None,
+                        view_once: false,
                     });
                     if msg.is_none() {
                         tracing::warn!("tried listing message, but message had missing fields so it was skipped");
@@ -1003,6 +1084,12 @@ where
                     ),
                     last_message: message,
                     is_commit_log_forked: conversation_item.is_commit_log_forked,
+                    has_draft: conversation_item.has_draft,
+                    unread_count: conversation_item.unread_count,
+                    first_unread_message_id: conversation_item.first_unread_message_id,
+                    muted_until_ns: conversation_item.muted_until_ns,
+                    is_archived: conversation_item.is_archived,
+                    is_pinned: conversation_item.is_pinned,
                 }
             })
             .collect())
@@ -1292,6 +1379,7 @@ pub(crate) mod tests {
         group_message::MsgQueryArgs, schema::identity_updates,
     };
     use xmtp_id::associations::test_utils::WalletTestExt;
+    use xmtp_id::{InboxOwner, associations::Identifier};
 
     #[xmtp_common::test]
     async fn test_group_member_recovery() {
@@ -1428,6 +1516,24 @@ pub(crate) mod tests {
         );
     }
 
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_solana_wallet_client() {
+        let wallet = ed25519_dalek::SigningKey::from_bytes(&xmtp_common::rand_array::<32>());
+        let solana = ClientBuilder::new_test_client(&wallet).await;
+        tester!(alix);
+
+        let ident = wallet.get_identifier()?;
+        assert!(matches!(ident, Identifier::Solana(_)));
+        assert_eq!(
+            alix.find_inbox_id_from_identifier(&alix.context.db(), ident.clone())
+                .await?,
+            Some(solana.inbox_id().to_string())
+        );
+
+        let can_message = alix.can_message(&[ident.clone()]).await?;
+        assert_eq!(can_message.get(&ident), Some(&true));
+    }
+
     #[xmtp_common::test(unwrap_try = true)]
     async fn test_double_dms() {
         tester!(alice);
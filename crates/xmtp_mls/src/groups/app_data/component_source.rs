@@ -276,7 +276,9 @@ pub(crate) fn component_type(id: ComponentId) -> Option<ComponentType> {
         | ComponentId::GROUP_DESCRIPTION
         | ComponentId::GROUP_IMAGE_URL
         | ComponentId::APP_DATA
-        | ComponentId::MIN_SUPPORTED_PROTOCOL_VERSION => Some(ComponentType::String),
+        | ComponentId::MIN_SUPPORTED_PROTOCOL_VERSION
+        | ComponentId::PINNED_MESSAGES
+        | ComponentId::EXTERNAL_INVITES => Some(ComponentType::String),
 
         // GroupMutableMetadata-backed bytes components.
         ComponentId::MESSAGE_DISAPPEAR_FROM_NS
@@ -875,6 +877,28 @@ pub(crate) fn read_super_admin_list_from_extensions(
     Ok(Some(set.iter().map(|id| id.to_hex()).collect()))
 }
 
+/// Read the inbox ids listed in the `inbox_id_set` component `id`, used as
+/// an allowlist by membership policies. Unmigrated groups and missing
+/// components yield an empty list, so nobody is allowlisted.
+pub(crate) fn read_inbox_allowlist_from_extensions(
+    id: ComponentId,
+    extensions: &Extensions<GroupContext>,
+) -> Result<Vec<String>, ComponentSourceError> {
+    if !super::is_migrated_extensions(extensions) {
+        return Ok(vec![]);
+    }
+    let Some(bytes) = read_from_app_data_dict_from_extensions(id, extensions) else {
+        return Ok(vec![]);
+    };
+    let set = TlsSet::<InboxId>::tls_deserialize_exact(&bytes).map_err(|e| {
+        ComponentSourceError::MalformedComponentValue {
+            component_id: id,
+            reason: format!("invalid TlsSet<InboxId>: {e}"),
+        }
+    })?;
+    Ok(set.iter().map(|id| id.to_hex()).collect())
+}
+
 /// Synthesize a [`GroupMetadata`] from the AppData dictionary on a
 /// migrated group. Returns `Ok(None)` if the critical immutable seeds
 /// aren't present (unmigrated group).
@@ -1206,6 +1230,8 @@ mod tests {
             ComponentId::GROUP_IMAGE_URL,
             ComponentId::APP_DATA,
             ComponentId::MIN_SUPPORTED_PROTOCOL_VERSION,
+            ComponentId::PINNED_MESSAGES,
+            ComponentId::EXTERNAL_INVITES,
         ] {
             assert_eq!(component_type(id), Some(ComponentType::String));
         }
@@ -1262,6 +1288,8 @@ mod tests {
             MetadataField::MinimumSupportedProtocolVersion,
             MetadataField::CommitLogSigner,
             MetadataField::AppData,
+            MetadataField::PinnedMessageIds,
+            MetadataField::ExternalInvites,
         ] {
             let id = metadata_field_to_component_id(field.as_str())
                 .expect("every MetadataField has a ComponentId");
@@ -2189,6 +2217,31 @@ mod tests {
         ));
     }
 
+    // --- read_inbox_allowlist_from_extensions ------------------------------
+
+    #[xmtp_common::test]
+    fn read_inbox_allowlist_reads_app_range_set() {
+        let id = ComponentId::new(0xC001);
+        let bytes = encode_inbox_id_set(&[fake_inbox_id(0xAA)]).unwrap();
+        let exts = extensions_with_entries(true, &[(id.as_u16(), bytes.clone())]);
+        assert_eq!(
+            read_inbox_allowlist_from_extensions(id, &exts).unwrap(),
+            vec![fake_inbox_id(0xAA)]
+        );
+        // Missing components and unmigrated groups allowlist nobody
+        assert!(
+            read_inbox_allowlist_from_extensions(ComponentId::new(0xC002), &exts)
+                .unwrap()
+                .is_empty()
+        );
+        let unmigrated = extensions_with_entries(false, &[(id.as_u16(), bytes)]);
+        assert!(
+            read_inbox_allowlist_from_extensions(id, &unmigrated)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     // --- read_group_metadata_from_extensions --------------------------------
 
     fn encode_conv_type_bytes(value: i32) -> Vec<u8> {
@@ -233,12 +233,17 @@ where
 /// `own` is the client's parsed pkg_version (threaded from the caller's
 /// context rather than read from a constant so cross-version tests can
 /// override it).
+///
+/// Groups whose floor admits external invites are switched to accept
+/// public messages first, so invitees' external commits can be processed.
 pub(crate) fn process_message_with_app_data<Provider: OpenMlsProvider>(
     mls_group: &mut OpenMlsGroup,
     provider: &Provider,
     message: impl Into<ProtocolMessage>,
     own: &LibXMTPVersion,
 ) -> Result<ProcessedMessage, ProcessMessageWithAppDataError<Provider::StorageError>> {
+    super::external_invites::accept_external_commits(mls_group, provider)
+        .map_err(ProcessMessageError::StorageError)?;
     let processed = mls_group.process_message(provider, message)?;
 
     // PAUSE BEFORE PARSE: every commit on a below-floor group must pause
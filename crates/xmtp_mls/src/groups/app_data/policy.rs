@@ -17,21 +17,28 @@
 //! legacy GCE-shaped callers expect a populated `PolicySet` for
 //! sanity checks unrelated to the per-component policy lookup.
 
-use openmls::group::MlsGroup as OpenMlsGroup;
-use xmtp_mls_common::app_data::component_id::ComponentId;
+use openmls::group::{MlsGroup as OpenMlsGroup, StagedCommit};
+use prost::Message;
+use tls_codec::VLBytes;
+use xmtp_mls_common::app_data::{
+    component_id::ComponentId, component_registry::ComponentRegistry,
+    components::tls_map_components::ComponentRegistryComponent, typed::Component,
+};
+use xmtp_mls_common::tls_map::TlsMapDelta;
+use xmtp_proto::xmtp::identity::associations::IdentifierKind;
 use xmtp_proto::xmtp::mls::message_contents::{
     MetadataPolicy as MetadataPolicyProto, metadata_policy::Kind as MetadataPolicyKindProto,
     metadata_policy::MetadataBasePolicy as MetadataBasePolicyProto,
 };
 
-use super::component_source::ComponentSourceError;
+use super::component_source::{ComponentSourceError, read_post_commit_component_bytes};
 use crate::groups::group_permissions::{
     GroupMutablePermissions, MembershipPolicies, PermissionsPolicies, PolicySet,
 };
 
 /// Translate a wire-form `MetadataPolicy` into a `MembershipPolicies`.
 ///
-/// Unknown / non-base variants conservatively map to `Deny` — Add /
+/// Unknown variants conservatively map to `Deny` — Add /
 /// Remove proposals from peers using a policy shape we don't recognize
 /// must not slip through silently.
 fn metadata_policy_to_membership(p: &MetadataPolicyProto) -> MembershipPolicies {
@@ -46,14 +53,46 @@ fn metadata_policy_to_membership(p: &MetadataPolicyProto) -> MembershipPolicies
                 Ok(MetadataBasePolicyProto::AllowIfSuperAdmin) => {
                     MembershipPolicies::allow_if_actor_super_admin()
                 }
+                Ok(MetadataBasePolicyProto::AllowIfModerator) => {
+                    MembershipPolicies::allow_if_actor_moderator()
+                }
                 Ok(MetadataBasePolicyProto::Unspecified) | Err(_) => MembershipPolicies::deny(),
             }
         }
-        // AndCondition / AnyCondition variants don't have a clean
-        // `MembershipPolicies` analogue today — every well-known
-        // component the bootstrap synthesizer emits uses `Base`.
-        // Conservative deny here keeps the door closed if we ever hit
-        // a richer policy shape we haven't translated.
+        Some(MetadataPolicyKindProto::InboxAllowlist(allowlist)) => {
+            match u16::try_from(allowlist.component_id) {
+                Ok(id) => MembershipPolicies::allow_if_inbox_in_allowlist(ComponentId::new(id)),
+                Err(_) => MembershipPolicies::deny(),
+            }
+        }
+        Some(MetadataPolicyKindProto::IdentifierRequirement(requirement)) => {
+            match IdentifierKind::try_from(requirement.kind) {
+                Ok(IdentifierKind::Unspecified) | Err(_) => MembershipPolicies::deny(),
+                Ok(kind) => MembershipPolicies::allow_if_inbox_has_identifier(
+                    kind,
+                    requirement.domain.clone(),
+                ),
+            }
+        }
+        // Combinators are needed to gate on both the actor and the added
+        // inbox, e.g. "admins may add allowlisted inboxes". Empty ones are
+        // malformed and deny.
+        Some(MetadataPolicyKindProto::AndCondition(and)) if !and.policies.is_empty() => {
+            MembershipPolicies::and(
+                and.policies
+                    .iter()
+                    .map(metadata_policy_to_membership)
+                    .collect(),
+            )
+        }
+        Some(MetadataPolicyKindProto::AnyCondition(any)) if !any.policies.is_empty() => {
+            MembershipPolicies::any(
+                any.policies
+                    .iter()
+                    .map(metadata_policy_to_membership)
+                    .collect(),
+            )
+        }
         _ => MembershipPolicies::deny(),
     }
 }
@@ -67,7 +106,13 @@ pub(crate) fn membership_policy_set_from_registry(
     mls_group: &OpenMlsGroup,
 ) -> Result<GroupMutablePermissions, ComponentSourceError> {
     let registry = super::load_component_registry(mls_group)?;
+    Ok(membership_policy_set_from_component_registry(&registry))
+}
 
+/// [`membership_policy_set_from_registry`] for an already loaded registry.
+fn membership_policy_set_from_component_registry(
+    registry: &ComponentRegistry,
+) -> GroupMutablePermissions {
     let (add_policy, remove_policy) = match registry.get(&ComponentId::GROUP_MEMBERSHIP) {
         Ok(Some(meta)) => match meta.permissions {
             Some(perms) => {
@@ -129,12 +174,85 @@ pub(crate) fn membership_policy_set_from_registry(
     // change comes through; legacy callers only consult these slots
     // for sanity checks unrelated to the per-component permission
     // policy.
-    Ok(GroupMutablePermissions::new(PolicySet::new(
+    GroupMutablePermissions::new(PolicySet::new(
         add_policy,
         remove_policy,
         std::collections::HashMap::new(),
         PermissionsPolicies::allow_if_actor_super_admin(),
         PermissionsPolicies::allow_if_actor_super_admin(),
         PermissionsPolicies::allow_if_actor_super_admin(),
-    )))
+    ))
+}
+
+/// Returns true if `staged_commit` writes the `COMPONENT_REGISTRY` and
+/// leaves inbox gates in its `GROUP_MEMBERSHIP` policies. Such commits
+/// need the group's floor at
+/// [`xmtp_configuration::MEMBERSHIP_GATES_MIN_PROTOCOL_VERSION`].
+///
+/// `registry` is the pre-commit registry, as for
+/// [`read_post_commit_component_bytes`].
+pub(crate) fn commit_sets_membership_gates(
+    mls_group: &OpenMlsGroup,
+    staged_commit: &StagedCommit,
+    registry: &ComponentRegistry,
+) -> Result<bool, ComponentSourceError> {
+    let writes_registry = staged_commit.app_data_update_proposals().any(|queued| {
+        queued.app_data_update_proposal().component_id() == ComponentId::COMPONENT_REGISTRY.as_u16()
+    });
+    if !writes_registry {
+        return Ok(false);
+    }
+    let Some(bytes) = read_post_commit_component_bytes(
+        ComponentId::COMPONENT_REGISTRY,
+        mls_group,
+        staged_commit,
+        registry,
+    )?
+    else {
+        return Ok(false);
+    };
+    let post_commit = ComponentRegistry::from_bytes(&bytes).map_err(|e| {
+        ComponentSourceError::MalformedComponentValue {
+            component_id: ComponentId::COMPONENT_REGISTRY,
+            reason: format!("registry decode: {e}"),
+        }
+    })?;
+    Ok(membership_policy_set_from_component_registry(&post_commit)
+        .policies
+        .has_inbox_gates())
+}
+
+/// Encode the `COMPONENT_REGISTRY` mutation that makes `policy` the
+/// `GROUP_MEMBERSHIP` insert policy, i.e. the group's add member policy.
+/// The rest of the entry is carried over from `mls_group`'s registry.
+pub(crate) fn add_member_policy_registry_update(
+    mls_group: &OpenMlsGroup,
+    policy: &MembershipPolicies,
+) -> Result<Vec<u8>, ComponentSourceError> {
+    let malformed = |reason: &str| ComponentSourceError::MalformedComponentValue {
+        component_id: ComponentId::GROUP_MEMBERSHIP,
+        reason: reason.to_string(),
+    };
+    let insert_policy = policy
+        .to_metadata_policy_proto()
+        .ok_or_else(|| malformed("add member policy has no registry form"))?;
+    let registry = super::load_component_registry(mls_group)?;
+    let mut metadata = registry
+        .get(&ComponentId::GROUP_MEMBERSHIP)
+        .map_err(|e| malformed(&format!("registry get failed: {e}")))?
+        .ok_or_else(|| malformed("registry has no entry for target component"))?;
+    let mut permissions = metadata
+        .permissions
+        .clone()
+        .ok_or_else(|| malformed("registry entry missing permissions"))?;
+    permissions.insert_policy = Some(insert_policy);
+    metadata.permissions = Some(permissions);
+
+    let delta = TlsMapDelta::<ComponentId, VLBytes>::new().update(
+        ComponentId::GROUP_MEMBERSHIP,
+        VLBytes::new(metadata.encode_to_vec()),
+    );
+    Ok(<ComponentRegistryComponent as Component>::encode_mutation(
+        &delta,
+    )?)
 }
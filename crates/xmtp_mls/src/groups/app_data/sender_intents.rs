@@ -35,7 +35,7 @@ use super::{load_component_registry, stage_app_data_propose_and_commit};
 use crate::{
     context::XmtpSharedContext,
     groups::{
-        AdminListActionType, GroupError,
+        AdminListActionType, GroupError, MetadataPermissionsError,
         intents::{
             AppDataUpdateIntentData, PermissionPolicyOption, PermissionUpdateType,
             UpdateAdminListIntentData, UpdatePermissionIntentData,
@@ -72,6 +72,10 @@ pub(crate) fn apply_update_admin_list_app_data_intent(
             ComponentId::SUPER_ADMIN_LIST,
             TlsSetMutation::Remove(inbox_id),
         ),
+        // The AppData dictionary has no moderator list component
+        AdminListActionType::AddModerator | AdminListActionType::RemoveModerator => {
+            return Err(MetadataPermissionsError::ModeratorsNotSupported.into());
+        }
     };
 
     let delta = TlsSetDelta::<InboxId> {
@@ -138,6 +142,7 @@ pub(crate) fn apply_update_permission_app_data_intent(
         PermissionPolicyOption::Deny => MetadataBasePolicy::Deny,
         PermissionPolicyOption::AdminOnly => MetadataBasePolicy::AllowIfAdmin,
         PermissionPolicyOption::SuperAdminOnly => MetadataBasePolicy::AllowIfSuperAdmin,
+        PermissionPolicyOption::ModeratorOrAbove => MetadataBasePolicy::AllowIfModerator,
     };
     let new_policy = MetadataPolicyProto {
         kind: Some(MetadataPolicyKind::Base(base as i32)),
@@ -0,0 +1,66 @@
+use super::{GroupError, MlsGroup};
+use crate::context::XmtpSharedContext;
+use crate::subscriptions::{LocalEvents, SyncWorkerEvent};
+use crate::worker::device_sync::preference_sync::PreferenceUpdate;
+use xmtp_common::time::now_ns;
+use xmtp_db::conversation_settings::StoredConversationSettings;
+use xmtp_db::prelude::*;
+
+impl<Context> MlsGroup<Context>
+where
+    Context: XmtpSharedContext,
+{
+    /// How the user has organized this conversation. Conversations that were
+    /// never muted, archived or pinned return the default settings.
+    pub fn conversation_settings(&self) -> Result<StoredConversationSettings, GroupError> {
+        Ok(self
+            .context
+            .db()
+            .get_conversation_settings(&self.group_id)?
+            .unwrap_or_else(|| StoredConversationSettings::new(self.group_id)))
+    }
+
+    /// Mute notifications until `muted_until_ns`, or unmute with `None`.
+    pub fn set_muted_until(&self, muted_until_ns: Option<i64>) -> Result<(), GroupError> {
+        self.update_conversation_settings(|settings| settings.muted_until_ns = muted_until_ns)
+    }
+
+    pub fn set_archived(&self, archived: bool) -> Result<(), GroupError> {
+        self.update_conversation_settings(|settings| settings.archived = archived)
+    }
+
+    pub fn set_pinned(&self, pinned: bool) -> Result<(), GroupError> {
+        self.update_conversation_settings(|settings| settings.pinned = pinned)
+    }
+
+    /// Apply `update` to the stored settings and sync the result to the
+    /// user's other installations through device sync.
+    fn update_conversation_settings(
+        &self,
+        update: impl FnOnce(&mut StoredConversationSettings),
+    ) -> Result<(), GroupError> {
+        let mut settings = self.conversation_settings()?;
+        update(&mut settings);
+        // Always move forward, even if the clock went backwards since the last update
+        settings.updated_at_ns = now_ns().max(settings.updated_at_ns + 1);
+
+        if self
+            .context
+            .db()
+            .insert_newer_conversation_settings(&settings)?
+        {
+            let updates = vec![PreferenceUpdate::ConversationSettings(settings)];
+            // Dispatch an update event so it can be synced across devices
+            let _ = self
+                .context
+                .worker_events()
+                .send(SyncWorkerEvent::SyncPreferences(updates.clone()));
+            let _ = self
+                .context
+                .local_events()
+                .send(LocalEvents::PreferencesChanged(updates));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use super::{GroupError, MlsGroup};
+use crate::context::XmtpSharedContext;
+use prost::Message;
+use xmtp_common::time::now_ns;
+use xmtp_db::draft::StoredDraft;
+use xmtp_db::prelude::QueryDrafts;
+use xmtp_proto::xmtp::mls::message_contents::EncodedContent;
+
+/// An unsent message draft for a conversation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Draft {
+    pub content: EncodedContent,
+    /// The message this draft replies to, if any.
+    pub reply_to_message_id: Option<Vec<u8>>,
+    pub updated_at_ns: i64,
+}
+
+impl TryFrom<StoredDraft> for Draft {
+    type Error = prost::DecodeError;
+    fn try_from(draft: StoredDraft) -> Result<Self, Self::Error> {
+        Ok(Self {
+            content: EncodedContent::decode(draft.content.as_slice())?,
+            reply_to_message_id: draft.reply_to_message_id,
+            updated_at_ns: draft.updated_at_ns,
+        })
+    }
+}
+
+impl<Context> MlsGroup<Context>
+where
+    Context: XmtpSharedContext,
+{
+    /// Save the unsent draft for this conversation, replacing any previous one.
+    /// Drafts are stored locally and never sent to the network.
+    pub fn set_draft(
+        &self,
+        content: EncodedContent,
+        reply_to_message_id: Option<Vec<u8>>,
+    ) -> Result<(), GroupError> {
+        self.context.db().upsert_draft(&StoredDraft {
+            group_id: self.group_id,
+            content: content.encode_to_vec(),
+            reply_to_message_id,
+            updated_at_ns: now_ns(),
+        })?;
+        Ok(())
+    }
+
+    pub fn get_draft(&self) -> Result<Option<Draft>, GroupError> {
+        let Some(draft) = self.context.db().get_draft(&self.group_id)? else {
+            return Ok(None);
+        };
+        Ok(Some(draft.try_into()?))
+    }
+
+    /// Discard the draft for this conversation. A no-op if there is none.
+    pub fn clear_draft(&self) -> Result<(), GroupError> {
+        self.context.db().delete_draft(&self.group_id)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use super::{GroupError, MlsGroup, intents::QueueIntent, intents::SendMessageIntentData};
+use crate::context::XmtpSharedContext;
+use prost::Message;
+use xmtp_common::time::now_ns;
+use xmtp_configuration::EPHEMERAL_MESSAGE_TTL_NS;
+use xmtp_proto::types::GroupId;
+use xmtp_proto::xmtp::mls::message_contents::{
+    EphemeralContent, PlaintextEnvelope,
+    plaintext_envelope::{Content, V2, v2::MessageType},
+};
+
+/// A short-lived signal received from another member, such as a typing indicator.
+/// Ephemeral messages are only delivered to live streams and are never stored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EphemeralMessage {
+    pub group_id: GroupId,
+    pub sender_inbox_id: String,
+    pub sender_installation_id: Vec<u8>,
+    /// Expected to be EncodedContent
+    pub content: Vec<u8>,
+    pub sent_at_ns: i64,
+    pub expires_at_ns: i64,
+}
+
+impl EphemeralMessage {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at_ns <= now_ns()
+    }
+}
+
+impl<Context> MlsGroup<Context>
+where
+    Context: XmtpSharedContext,
+{
+    /// Send a short-lived signal, such as a typing indicator, to the members of this group.
+    ///
+    /// Unlike [`Self::send_message`] the content is not stored by the sender or the
+    /// receivers, does not trigger push notifications and is dropped by receivers
+    /// that process it after [`EPHEMERAL_MESSAGE_TTL_NS`] has passed.
+    #[xmtp_common::mls_span]
+    pub async fn send_ephemeral(&self, content: &[u8]) -> Result<(), GroupError> {
+        if !self.is_active()? {
+            tracing::warn!("Unable to send an ephemeral message on an inactive group.");
+            return Err(GroupError::GroupInactive);
+        }
+
+        self.ensure_not_paused().await?;
+        // OpenMLS blocks message creation when there are pending proposals
+        self.commit_pending_proposals_if_any().await?;
+
+        let now = now_ns();
+        let envelope = PlaintextEnvelope {
+            content: Some(Content::V2(V2 {
+                idempotency_key: now.to_string(),
+                message_type: Some(MessageType::Ephemeral(EphemeralContent {
+                    content: content.to_vec(),
+                    expires_at_ns: now + EPHEMERAL_MESSAGE_TTL_NS,
+                })),
+            })),
+        };
+        let intent_data: Vec<u8> = SendMessageIntentData::new(envelope.encode_to_vec()).into();
+        QueueIntent::send_message()
+            .data(intent_data)
+            .should_push(false)
+            .queue(self)?;
+
+        self.sync_until_last_intent_resolved().await?;
+
+        Ok(())
+    }
+}
@@ -27,6 +27,9 @@ use xmtp_db::NotFound;
 use xmtp_db::sql_key_store;
 use xmtp_mls_common::group_metadata::GroupMetadataError;
 use xmtp_mls_common::group_mutable_metadata::GroupMutableMetadataError;
+use xmtp_mls_common::invite::{
+    encrypted_group_info::EncryptedGroupInfoError, payload::InvitePayloadError,
+};
 use xmtp_mls_common::mls_ext::payload_encryption::{UnwrapPayloadError, WrapPayloadError};
 
 /// Installation IDs that failed key package verification during a membership update.
@@ -287,6 +290,11 @@ pub enum GroupError {
     /// string-flattened wrappers. Not retryable.
     #[error("invalid min_version {value:?}: {reason}")]
     InvalidMinVersion { value: String, reason: String },
+    /// Caller asked to send a view-once or expire-after message on a group
+    /// whose `MIN_SUPPORTED_PROTOCOL_VERSION` is below `required`, so some
+    /// members may keep the message forever. Not retryable.
+    #[error("message expiry needs the group min_version to be at least {required}")]
+    MessageExpiryUnsupported { required: String },
     /// Component source error.
     ///
     /// Failed to encode, decode, or look up a well-known component during the
@@ -460,6 +468,21 @@ pub enum GroupError {
     /// Failed to delete message. Not retryable.
     #[error(transparent)]
     DeleteMessage(#[from] DeleteMessageError),
+    /// Edit message error.
+    ///
+    /// Failed to edit message. Not retryable.
+    #[error(transparent)]
+    EditMessage(#[from] EditMessageError),
+    /// Pin message error.
+    ///
+    /// Failed to pin or unpin message. Not retryable.
+    #[error(transparent)]
+    PinMessage(#[from] PinMessageError),
+    /// External invite error.
+    ///
+    /// Failed to create, revoke or join with an external invite. May be retryable.
+    #[error(transparent)]
+    Invite(#[from] InviteError),
     /// Device sync error.
     ///
     /// Device sync operation failed. May be retryable.
@@ -485,6 +508,72 @@ impl RetryableError for DeleteMessageError {
     }
 }
 
+#[derive(Error, Debug)]
+pub enum EditMessageError {
+    #[error("Message not found: {0}")]
+    MessageNotFound(String),
+    #[error("Not authorized to edit this message")]
+    NotAuthorized,
+    #[error("Cannot edit this message type")]
+    NonEditableMessage,
+    #[error("Cannot replace a message with content of type {0}")]
+    NonEditableContent(String),
+    #[error("Cannot edit a deleted message")]
+    MessageDeleted,
+}
+
+impl RetryableError for EditMessageError {
+    fn is_retryable(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum PinMessageError {
+    #[error("Message not found: {0}")]
+    MessageNotFound(String),
+    #[error("Cannot pin this message type")]
+    NonPinnableMessage,
+    #[error("A group can have at most {0} pinned messages")]
+    TooManyPinnedMessages(usize),
+}
+
+impl RetryableError for PinMessageError {
+    fn is_retryable(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum InviteError {
+    #[error(transparent)]
+    Payload(#[from] InvitePayloadError),
+    #[error(transparent)]
+    EncryptedGroupInfo(#[from] EncryptedGroupInfoError),
+    #[error("Invite service pointer is not a group info topic")]
+    InvalidServicePointer,
+    #[error("No group info has been published for this invite")]
+    GroupInfoNotFound,
+    #[error("Invite does not contain a valid group info")]
+    MalformedGroupInfo,
+    #[error("External commit failed: {0}")]
+    ExternalCommit(String),
+    #[error("No active invite with this id")]
+    InviteNotFound,
+    #[error("External invites are not supported for this conversation")]
+    Unsupported,
+    #[error("A group can have at most {0} outstanding invites")]
+    TooManyInvites(usize),
+    #[error("Invite expiry must be in the future")]
+    InvalidExpiry,
+}
+
+impl RetryableError for InviteError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::GroupInfoNotFound)
+    }
+}
+
 impl From<prost::EncodeError> for GroupError {
     fn from(value: prost::EncodeError) -> Self {
         GroupError::ConversionError(value.into())
@@ -515,6 +604,8 @@ pub enum MetadataPermissionsError {
     InvalidPermissionUpdate,
     #[error("cannot change metadata of DM")]
     DmGroupMetadataForbidden,
+    #[error("moderators are not supported on groups migrated to the app data dictionary")]
+    ModeratorsNotSupported,
     #[error(transparent)]
     DmValidation(#[from] DmValidationError),
     #[error("Invalid extension: {0}")]
@@ -608,6 +699,7 @@ impl RetryableError for GroupError {
             Self::MinVersionExceedsOwnVersion { .. } => false,
             Self::MinVersionDowngrade { .. } => false,
             Self::InvalidMinVersion { .. } => false,
+            Self::MessageExpiryUnsupported { .. } => false,
             Self::ComponentSource(_) => false,
             Self::AppDataCommit(e) => e.is_retryable(),
             // Bootstrap synthesis can fail on a transient identity-update
@@ -632,6 +724,9 @@ impl RetryableError for GroupError {
             Self::Diesel(e) => e.is_retryable(),
             Self::LeaveCantProcessed(e) => e.is_retryable(),
             Self::DeleteMessage(e) => e.is_retryable(),
+            Self::EditMessage(e) => e.is_retryable(),
+            Self::PinMessage(e) => e.is_retryable(),
+            Self::Invite(e) => e.is_retryable(),
             Self::DeviceSync(e) => e.is_retryable(),
             Self::MergePendingCommit(e) => e.is_retryable(),
             // Only emitted when a fresh `load_identity_updates` network
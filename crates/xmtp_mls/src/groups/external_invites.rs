@@ -0,0 +1,533 @@
+//! Shareable invites that let anyone holding them join a group through an
+//! MLS external commit.
+//!
+//! An admin calls [`MlsGroup::create_invite`], which records the invite in the
+//! group's `external_invites` metadata attribute and returns an
+//! [`ExternalInvitePayload`] to share as a link or QR code. The invitee calls
+//! [`Client::join_with_invite`](crate::Client::join_with_invite), which
+//! fetches the group's GroupInfo and publishes a single external commit that
+//! carries the invite's `external_group_id` as AAD and records the joiner's
+//! inbox in the group membership. Members validate the external commit
+//! against the invite and the group's add member policy in
+//! [`ValidatedCommit::from_external_commit`](super::validated_commit::ValidatedCommit::from_external_commit).
+//!
+//! A GroupInfo can only be used to join the epoch it was exported at, so
+//! every member that commits publishes the new epoch's GroupInfo for each
+//! live invite, encrypted under the invite key to the welcome topic the
+//! invite points at. Invites keep working until they expire or are revoked.
+//!
+//! External commits are public messages, which older clients reject, so
+//! creating an invite first raises the group's minimum supported protocol
+//! version to [`EXTERNAL_INVITES_MIN_PROTOCOL_VERSION`]. Members only start
+//! accepting public messages once the group's floor admits invites.
+
+use super::{
+    GroupError, InviteError, MAX_EXTERNAL_INVITES, MetadataPermissionsError, MlsGroup,
+    build_group_membership_extension,
+    intents::{QueueIntent, UpdateMetadataIntentData},
+    mls_ext::{CommitLogStorer, build_invite_group_join_config},
+    validated_commit::{LibXMTPVersion, extract_group_membership},
+};
+use crate::{context::XmtpSharedContext, identity::installation_capabilities};
+use openmls::{
+    extensions::{ApplicationIdExtension, Extension, Extensions},
+    group::{GroupContext, MIXED_CIPHERTEXT_WIRE_FORMAT_POLICY, MlsGroup as OpenMlsGroup},
+    prelude::{CredentialWithKey, LeafNodeParameters, MlsMessageBodyIn, MlsMessageIn},
+};
+use openmls_traits::OpenMlsProvider;
+use prost::Message;
+use tls_codec::{Deserialize, Serialize};
+use xmtp_common::time::now_ns;
+use xmtp_configuration::EXTERNAL_INVITES_MIN_PROTOCOL_VERSION;
+use xmtp_db::{
+    consent_record::ConsentState,
+    group::{ConversationType, GroupMembershipState, StoredGroup},
+    prelude::*,
+};
+use xmtp_mls_common::{
+    app_data::components::metadata_attributes::ExternalInvitesComponent,
+    group_metadata::GroupMetadata,
+    group_mutable_metadata::{GroupMutableMetadata, MetadataField},
+    invite::{
+        encrypted_group_info,
+        group_invites::{
+            GroupInvite, decode_group_invites, encode_group_invites, find_active_invite,
+        },
+        payload::{
+            self, InvitePayloadError, SYMMETRIC_KEY_LEN, build_payload, generate_external_group_id,
+            generate_symmetric_key,
+        },
+    },
+};
+use xmtp_proto::prelude::XmtpMlsClient;
+use xmtp_proto::types::{GroupId, WelcomeMessageType};
+use xmtp_proto::xmtp::mls::{
+    api::v1::{
+        WelcomeMessageInput,
+        welcome_message_input::{
+            V1 as WelcomeMessageInputV1, Version as WelcomeMessageInputVersion,
+        },
+    },
+    message_contents::{
+        EncryptedGroupInfoBlob, ExternalCommitPolicyV1, ExternalInvitePayload,
+        ExternalInvitePayloadV1, WelcomeWrapperAlgorithm,
+    },
+};
+
+impl<Context> MlsGroup<Context>
+where
+    Context: XmtpSharedContext,
+{
+    /// Creates an invite that lets anyone holding the returned payload join the group.
+    ///
+    /// The invite admits joiners until `expires_at_ns`, if given, or until it is
+    /// revoked. Who may create invites is controlled by the `external_invites` metadata
+    /// policy, admins only by default.
+    ///
+    /// The first invite raises the group's minimum supported protocol version to
+    /// [`EXTERNAL_INVITES_MIN_PROTOCOL_VERSION`], which pauses the group for clients
+    /// that would reject a joiner's external commit.
+    #[cfg_attr(any(test, feature = "test-utils"), tracing::instrument(level = "info", fields(who = %self.context.inbox_id()), skip(self)))]
+    #[cfg_attr(
+        not(any(test, feature = "test-utils")),
+        tracing::instrument(level = "trace", skip(self))
+    )]
+    pub async fn create_invite(
+        &self,
+        expires_at_ns: Option<i64>,
+    ) -> Result<ExternalInvitePayload, GroupError> {
+        self.ensure_not_paused().await?;
+        self.ensure_supports_invites().await?;
+        self.ensure_group_min_version(EXTERNAL_INVITES_MIN_PROTOCOL_VERSION)
+            .await?;
+
+        let now = now_ns();
+        let expires_at_ns = match expires_at_ns {
+            Some(expires_at_ns) if expires_at_ns <= now => {
+                return Err(InviteError::InvalidExpiry.into());
+            }
+            Some(expires_at_ns) => expires_at_ns as u64,
+            None => 0,
+        };
+
+        let mut invites = self.invites()?;
+        if invites.len() >= MAX_EXTERNAL_INVITES {
+            return Err(InviteError::TooManyInvites(MAX_EXTERNAL_INVITES).into());
+        }
+        let symmetric_key = generate_symmetric_key();
+        let external_group_id = generate_external_group_id().to_vec();
+        // Members only accept new invites naming the epoch of the commit that
+        // records them. The intent restamps this if another commit lands first.
+        let group_info_epoch = self.epoch().await? + 1;
+        invites.push(GroupInvite {
+            policy: ExternalCommitPolicyV1 {
+                allow_external_commit: true,
+                expires_at_ns,
+                expire_in_ns: 0,
+                symmetric_key: symmetric_key.to_vec(),
+                external_group_id: external_group_id.clone(),
+            },
+            created_by_inbox_id: self.context.inbox_id().to_string(),
+            group_info_epoch,
+        });
+        // The GroupInfo for the epoch this commit creates is published for every live
+        // invite, including this one, once the commit is merged
+        self.update_external_invites(&invites).await?;
+
+        let service_pointer = encrypted_group_info::destination(&symmetric_key).to_vec();
+
+        Ok(
+            build_payload(service_pointer, external_group_id, symmetric_key)
+                .map_err(InviteError::from)?,
+        )
+    }
+
+    /// The group's invites that still admit joiners. Expired invites are dropped on
+    /// the next write.
+    pub fn invites(&self) -> Result<Vec<GroupInvite>, GroupError> {
+        let now = now_ns() as u64;
+        Ok(self
+            .read_single_component::<ExternalInvitesComponent>()?
+            .map(|value| decode_group_invites(&value))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|invite| invite.is_active(now))
+            .collect())
+    }
+
+    /// Revokes an invite so that it no longer admits joiners. Members who already
+    /// joined with it are unaffected.
+    #[cfg_attr(any(test, feature = "test-utils"), tracing::instrument(level = "info", fields(who = %self.context.inbox_id()), skip(self)))]
+    #[cfg_attr(
+        not(any(test, feature = "test-utils")),
+        tracing::instrument(level = "trace", skip(self))
+    )]
+    pub async fn revoke_invite(&self, external_group_id: &[u8]) -> Result<(), GroupError> {
+        let (revoked, invites): (Vec<_>, Vec<_>) = self
+            .invites()?
+            .into_iter()
+            .partition(|invite| invite.external_group_id() == external_group_id);
+        if revoked.is_empty() {
+            return Err(InviteError::InviteNotFound.into());
+        }
+
+        self.update_external_invites(&invites).await?;
+        // Joiners use the latest GroupInfo published for an invite, so publish one
+        // without the invite rather than leave them one that still contains it
+        self.publish_group_info(&revoked).await
+    }
+
+    /// Publishes the GroupInfo for the group's current epoch to each of `invites`,
+    /// encrypted under the invite's key. Blobs expire with their invite.
+    pub(crate) async fn publish_group_info(
+        &self,
+        invites: &[GroupInvite],
+    ) -> Result<(), GroupError> {
+        if invites.is_empty() {
+            return Ok(());
+        }
+        let (group_info, epoch, tree_hash) = self.export_group_info()?;
+        let messages = invites
+            .iter()
+            .map(|invite| {
+                let symmetric_key: &[u8; SYMMETRIC_KEY_LEN] = invite
+                    .policy
+                    .symmetric_key
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| {
+                        InvitePayloadError::InvalidSymmetricKeyLength(
+                            invite.policy.symmetric_key.len(),
+                        )
+                    })
+                    .map_err(InviteError::from)?;
+                let blob = encrypted_group_info::encrypt(
+                    &group_info,
+                    symmetric_key,
+                    epoch,
+                    tree_hash.clone(),
+                    invite.policy.expires_at_ns,
+                )
+                .map_err(InviteError::from)?;
+                Ok(WelcomeMessageInput {
+                    version: Some(WelcomeMessageInputVersion::V1(WelcomeMessageInputV1 {
+                        installation_key: encrypted_group_info::destination(symmetric_key).to_vec(),
+                        data: blob.encode_to_vec(),
+                        hpke_public_key: vec![],
+                        wrapper_algorithm: WelcomeWrapperAlgorithm::SymmetricKey.into(),
+                        welcome_metadata: vec![],
+                    })),
+                })
+            })
+            .collect::<Result<Vec<_>, GroupError>>()?;
+        self.context.api().send_welcome_messages(&messages).await?;
+        Ok(())
+    }
+
+    async fn ensure_supports_invites(&self) -> Result<(), GroupError> {
+        if self.metadata().await?.conversation_type != ConversationType::Group {
+            return Err(InviteError::Unsupported.into());
+        }
+        let group_context = self.load_group_context()?;
+        if super::app_data::is_migrated_extensions(group_context.extensions()) {
+            return Err(InviteError::Unsupported.into());
+        }
+        Ok(())
+    }
+
+    /// Exports the GroupInfo an invitee needs to join the group's current epoch,
+    /// along with that epoch and the group's tree hash.
+    pub(crate) fn export_group_info(&self) -> Result<(Vec<u8>, u64, Vec<u8>), GroupError> {
+        self.load_mls_group_with_lock(self.context.mls_storage(), |mls_group| {
+            let provider = self.context.mls_provider();
+            let group_info = mls_group
+                .export_group_info(
+                    provider.crypto(),
+                    &self.context.identity().installation_keys,
+                    true,
+                )
+                .map_err(|e| InviteError::ExternalCommit(e.to_string()))?;
+            Ok((
+                group_info.tls_serialize_detached()?,
+                mls_group.epoch().as_u64(),
+                mls_group.tree_hash().to_vec(),
+            ))
+        })
+    }
+
+    async fn update_external_invites(&self, invites: &[GroupInvite]) -> Result<(), GroupError> {
+        self.ensure_not_paused().await?;
+        self.ensure_supports_invites().await?;
+
+        let intent_data: Vec<u8> =
+            UpdateMetadataIntentData::new_update_external_invites(encode_group_invites(invites))
+                .into();
+        let intent = QueueIntent::metadata_update()
+            .data(intent_data)
+            .queue(self)?;
+
+        let _ = self.sync_until_intent_resolved(intent.id).await?;
+        Ok(())
+    }
+
+    /// Joins a group with an invite created by [`Self::create_invite`].
+    ///
+    /// The external commit is published before anything is stored locally. If it is
+    /// rejected by the network, or the joined state does not contain the invite, the
+    /// local group state is discarded.
+    pub(crate) async fn join_with_invite(
+        context: Context,
+        invite: &ExternalInvitePayload,
+    ) -> Result<Self, GroupError> {
+        let invite = payload::validate(invite).map_err(InviteError::from)?;
+        let now = now_ns() as u64;
+        let group_info = Self::fetch_group_info(&context, invite, now).await?;
+
+        let provider = context.mls_provider();
+        let identity = context.identity();
+        let (mut mls_group, commit) =
+            Self::build_external_join(&context, &group_info, &invite.external_group_id)?;
+
+        let group_id = GroupId::try_from(mls_group.group_id())?;
+        let group = Self::new(
+            context.clone(),
+            group_id,
+            None,
+            ConversationType::Group,
+            now_ns(),
+        );
+
+        let published = async {
+            // Re-check the invite against the state we are about to join, since the
+            // GroupInfo only proves which group it came from, not that it is still open.
+            let extensions = mls_group.extensions();
+            let metadata =
+                GroupMetadata::try_from(extensions).map_err(MetadataPermissionsError::from)?;
+            if metadata.conversation_type != ConversationType::Group
+                || !admits_external_invites(extensions)
+            {
+                return Err(GroupError::from(InviteError::Unsupported));
+            }
+            let mutable_metadata = GroupMutableMetadata::try_from(extensions)
+                .map_err(MetadataPermissionsError::from)?;
+            let invites = mutable_metadata
+                .attributes
+                .get(MetadataField::ExternalInvites.as_str())
+                .map(|value| decode_group_invites(value))
+                .unwrap_or_default();
+            let inviter = find_active_invite(&invites, &invite.external_group_id, now)
+                .ok_or(InviteError::InviteNotFound)?
+                .created_by_inbox_id
+                .clone();
+
+            let messages = group.prepare_group_messages(vec![(commit.as_slice(), false)])?;
+            context.api().send_group_messages(messages).await?;
+            Ok(inviter)
+        }
+        .await;
+        let inviter = match published {
+            Ok(inviter) => inviter,
+            Err(e) => {
+                if let Err(delete_error) = mls_group.delete(provider.storage()) {
+                    tracing::warn!("failed to discard external commit state: {delete_error}");
+                }
+                return Err(e);
+            }
+        };
+        mls_group.merge_external_join_logged(&provider, identity)?;
+
+        let db = context.db();
+        StoredGroup::builder()
+            .id(group_id)
+            .created_at_ns(now_ns())
+            .membership_state(GroupMembershipState::Allowed)
+            .conversation_type(ConversationType::Group)
+            .added_by_inbox_id(inviter)
+            .build()?
+            .store_or_ignore(&db)?;
+        group.update_consent_state(ConsentState::Allowed)?;
+
+        // Our commit moved the group to a new epoch, so publish its GroupInfo for the
+        // group's invites as any other committer would. The join already happened.
+        let published = async { group.publish_group_info(&group.invites()?).await }.await;
+        if let Err(e) = published {
+            tracing::warn!("failed to publish group info for invites: {e}");
+        }
+
+        Ok(group)
+    }
+
+    /// Fetches the GroupInfo of the latest epoch published for `invite`, skipping
+    /// blobs that don't open with the invite key or have expired at `now_ns`.
+    async fn fetch_group_info(
+        context: &Context,
+        invite: &ExternalInvitePayloadV1,
+        now_ns: u64,
+    ) -> Result<Vec<u8>, GroupError> {
+        let destination: [u8; 32] = invite
+            .service_pointer
+            .as_slice()
+            .try_into()
+            .map_err(|_| InviteError::InvalidServicePointer)?;
+        let welcomes = context
+            .api()
+            .api_client
+            .query_welcome_messages(destination.as_slice().try_into()?)
+            .await
+            .map_err(|e| xmtp_api::ApiError::Api(Box::new(e)))?;
+
+        welcomes
+            .into_iter()
+            .filter_map(|welcome| match welcome.variant {
+                WelcomeMessageType::V1(v1) => {
+                    EncryptedGroupInfoBlob::decode(v1.data.as_slice()).ok()
+                }
+                _ => None,
+            })
+            .filter_map(|blob| {
+                let blob = encrypted_group_info::validate(&blob).ok()?;
+                let group_info =
+                    encrypted_group_info::decrypt(blob, &invite.symmetric_key, now_ns).ok()?;
+                // The blob's epoch is plaintext and not covered by the AEAD
+                let epoch = group_info_context(&group_info).ok()?.epoch().as_u64();
+                (epoch == blob.epoch).then_some((epoch, group_info))
+            })
+            .max_by_key(|(epoch, _)| *epoch)
+            .map(|(_, group_info)| group_info)
+            .ok_or_else(|| InviteError::GroupInfoNotFound.into())
+    }
+
+    /// Builds an external commit that joins the group described by the serialized
+    /// `group_info` and records our inbox in the group membership, so that the join
+    /// is a single commit. Returns the pending group state and the serialized commit.
+    pub(crate) fn build_external_join(
+        context: &Context,
+        group_info: &[u8],
+        external_group_id: &[u8],
+    ) -> Result<(OpenMlsGroup, Vec<u8>), GroupError> {
+        let group_context = group_info_context(group_info)?;
+        let group_info = match MlsMessageIn::tls_deserialize(&mut &group_info[..])
+            .map_err(|_| InviteError::MalformedGroupInfo)?
+            .extract()
+        {
+            MlsMessageBodyIn::GroupInfo(group_info) => group_info,
+            _ => return Err(InviteError::MalformedGroupInfo.into()),
+        };
+
+        let provider = context.mls_provider();
+        let identity = context.identity();
+        let mut membership = extract_group_membership(group_context.extensions())?;
+        membership.add(
+            identity.inbox_id().to_string(),
+            identity.sequence_id(&context.db())? as u64,
+        );
+        let mut extensions = group_context.extensions().clone();
+        extensions.add_or_replace(build_group_membership_extension(&membership))?;
+
+        let application_id =
+            Extension::ApplicationId(ApplicationIdExtension::new(identity.inbox_id().as_bytes()));
+        let leaf_node_parameters = LeafNodeParameters::builder()
+            .with_capabilities(installation_capabilities())
+            .with_extensions(
+                Extensions::single(application_id)
+                    .map_err(|e| InviteError::ExternalCommit(e.to_string()))?,
+            )
+            .build();
+        let (mls_group, commit_bundle) = OpenMlsGroup::external_commit_builder()
+            .with_aad(external_group_id.to_vec())
+            .with_config(build_invite_group_join_config())
+            .build_group(
+                &provider,
+                group_info,
+                CredentialWithKey {
+                    credential: identity.credential(),
+                    signature_key: identity.installation_keys.public_slice().into(),
+                },
+            )
+            .map_err(|e| InviteError::ExternalCommit(e.to_string()))?
+            .leaf_node_parameters(leaf_node_parameters)
+            .propose_group_context_extensions(extensions)
+            .map_err(|e| InviteError::ExternalCommit(e.to_string()))?
+            .load_psks(provider.storage())
+            .map_err(|e| InviteError::ExternalCommit(e.to_string()))?
+            .build(
+                provider.rand(),
+                provider.crypto(),
+                &identity.installation_keys,
+                |_| true,
+            )
+            .map_err(|e| InviteError::ExternalCommit(e.to_string()))?
+            .finalize(&provider)
+            .map_err(|e| InviteError::ExternalCommit(e.to_string()))?;
+
+        Ok((mls_group, commit_bundle.commit().tls_serialize_detached()?))
+    }
+}
+
+/// Whether the group's minimum supported protocol version keeps out the clients
+/// that would reject an invitee's public external commit.
+pub(crate) fn admits_external_invites(extensions: &Extensions<GroupContext>) -> bool {
+    if super::app_data::is_migrated_extensions(extensions) {
+        return false;
+    }
+    let Ok(mutable_metadata) = GroupMutableMetadata::try_from(extensions) else {
+        return false;
+    };
+    let floor = mutable_metadata
+        .attributes
+        .get(MetadataField::MinimumSupportedProtocolVersion.as_str())
+        .and_then(|floor| LibXMTPVersion::parse(floor).ok());
+    match (
+        floor,
+        LibXMTPVersion::parse(EXTERNAL_INVITES_MIN_PROTOCOL_VERSION),
+    ) {
+        (Some(floor), Ok(required)) => floor >= required,
+        _ => false,
+    }
+}
+
+/// Stamps the invites a pending write adds with the epoch its commit creates.
+/// Members reject new invites naming any other epoch, and the write may be
+/// published after other commits have moved the group on.
+pub(crate) fn stamp_new_invites(committed: Option<&str>, pending: &str, epoch: u64) -> String {
+    let committed = committed.map(decode_group_invites).unwrap_or_default();
+    let invites = decode_group_invites(pending)
+        .into_iter()
+        .map(|mut invite| {
+            if !committed.contains(&invite) {
+                invite.group_info_epoch = epoch;
+            }
+            invite
+        })
+        .collect::<Vec<_>>();
+    encode_group_invites(&invites)
+}
+
+/// Starts accepting public messages once the group admits external invites, so
+/// that invitees' external commits can be processed. Groups that can't use
+/// invites keep the default policy and reject them.
+pub(crate) fn accept_external_commits<Provider: OpenMlsProvider>(
+    mls_group: &mut OpenMlsGroup,
+    provider: &Provider,
+) -> Result<(), Provider::StorageError> {
+    if mls_group.configuration().wire_format_policy() == MIXED_CIPHERTEXT_WIRE_FORMAT_POLICY
+        || !admits_external_invites(mls_group.extensions())
+    {
+        return Ok(());
+    }
+    mls_group.set_configuration(provider.storage(), &build_invite_group_join_config())
+}
+
+/// Reads the group context off a serialized GroupInfo message. The joiner needs
+/// it to build its membership update before OpenMLS verifies the GroupInfo, and a
+/// tampered context only yields a commit the group rejects.
+fn group_info_context(group_info: &[u8]) -> Result<GroupContext, GroupError> {
+    let mut bytes = group_info;
+    // An MLS message starts with its protocol version and wire format, and a
+    // GroupInfo starts with its group context
+    let context = u16::tls_deserialize(&mut bytes)
+        .and_then(|_| u16::tls_deserialize(&mut bytes))
+        .and_then(|_| GroupContext::tls_deserialize(&mut bytes))
+        .map_err(|_| InviteError::MalformedGroupInfo)?;
+    Ok(context)
+}
@@ -6,10 +6,14 @@ use prost::Message;
 use std::collections::HashMap;
 use thiserror::Error;
 use xmtp_common::ErrorCode;
+use xmtp_id::associations::Identifier;
+use xmtp_proto::xmtp::identity::associations::IdentifierKind;
 use xmtp_proto::xmtp::mls::message_contents::{
     GroupMutablePermissionsV1 as GroupMutablePermissionsProto,
-    MembershipPolicy as MembershipPolicyProto, MetadataPolicy as MetadataPolicyProto,
-    PermissionsUpdatePolicy as PermissionsPolicyProto, PolicySet as PolicySetProto,
+    IdentifierRequirementPolicy as IdentifierRequirementProto,
+    InboxAllowlistPolicy as InboxAllowlistProto, MembershipPolicy as MembershipPolicyProto,
+    MetadataPolicy as MetadataPolicyProto, PermissionsUpdatePolicy as PermissionsPolicyProto,
+    PolicySet as PolicySetProto,
     membership_policy::{
         AndCondition as AndConditionProto, AnyCondition as AnyConditionProto,
         BasePolicy as BasePolicyProto, Kind as PolicyKindProto,
@@ -25,7 +29,10 @@ use xmtp_proto::xmtp::mls::message_contents::{
 };
 
 use super::validated_commit::{CommitParticipant, Inbox, MetadataFieldChange, ValidatedCommit};
-use xmtp_configuration::{GROUP_PERMISSIONS_EXTENSION_ID, SUPER_ADMIN_METADATA_PREFIX};
+use xmtp_configuration::{
+    GROUP_PERMISSIONS_EXTENSION_ID, MODERATORS_MIN_PROTOCOL_VERSION, SUPER_ADMIN_METADATA_PREFIX,
+};
+use xmtp_mls_common::app_data::component_id::ComponentId;
 use xmtp_mls_common::group_mutable_metadata::{GroupMutableMetadata, MetadataField};
 
 /// Errors that can occur when working with GroupMutablePermissions.
@@ -66,6 +73,12 @@ pub enum GroupMutablePermissionsError {
     /// Invalid permission policy configuration. Not retryable.
     #[error("invalid permission policy option")]
     InvalidPermissionPolicyOption,
+    /// Inbox gates on an unmigrated group.
+    ///
+    /// Allowlist and identifier gates are only supported on groups migrated
+    /// to the app data dictionary. Not retryable.
+    #[error("membership gates require a group migrated to the app data dictionary")]
+    InboxGatesRequireMigration,
 }
 
 /// Represents the mutable permissions for a group.
@@ -97,15 +110,22 @@ impl GroupMutablePermissions {
         if proto.policies.is_none() {
             return Err(GroupMutablePermissionsError::MissingPolicies);
         }
-        let policies = proto.policies.expect("checked for none");
+        let policies = PolicySet::from_proto(proto.policies.expect("checked for none"))?;
+        if policies.has_inbox_gates() {
+            return Err(GroupMutablePermissionsError::InboxGatesRequireMigration);
+        }
 
-        Ok(Self::new(PolicySet::from_proto(policies)?))
+        Ok(Self::new(policies))
     }
 
     /// Converts the GroupMutablePermissions to its proto representation.
     pub(crate) fn to_proto(
         &self,
     ) -> Result<GroupMutablePermissionsProto, GroupMutablePermissionsError> {
+        // Clients before the gates can't parse them in this extension
+        if self.policies.has_inbox_gates() {
+            return Err(GroupMutablePermissionsError::InboxGatesRequireMigration);
+        }
         Ok(GroupMutablePermissionsProto {
             policies: Some(self.policies.to_proto()?),
         })
@@ -197,6 +217,7 @@ pub enum MetadataBasePolicies {
     Deny,
     AllowIfActorAdminOrSuperAdmin,
     AllowIfActorSuperAdmin,
+    AllowIfActorModeratorOrAbove,
 }
 
 /// Implements the MetadataPolicy trait for MetadataBasePolicies.
@@ -209,6 +230,7 @@ impl MetadataPolicy for &MetadataBasePolicies {
                 actor.is_admin || actor.is_super_admin
             }
             MetadataBasePolicies::AllowIfActorSuperAdmin => actor.is_super_admin,
+            MetadataBasePolicies::AllowIfActorModeratorOrAbove => actor.is_moderator_or_above(),
         }
     }
 
@@ -222,6 +244,9 @@ impl MetadataPolicy for &MetadataBasePolicies {
             MetadataBasePolicies::AllowIfActorSuperAdmin => {
                 MetadataBasePolicyProto::AllowIfSuperAdmin as i32
             }
+            MetadataBasePolicies::AllowIfActorModeratorOrAbove => {
+                MetadataBasePolicyProto::AllowIfModerator as i32
+            }
         };
 
         Ok(MetadataPolicyProto {
@@ -257,6 +282,9 @@ impl MetadataPolicies {
                         MetadataPolicies::allow_if_actor_super_admin(),
                     );
                 }
+                MetadataField::ExternalInvites => {
+                    map.insert(field.to_string(), MetadataPolicies::allow_if_actor_admin());
+                }
                 _ => {
                     map.insert(field.to_string(), policies.clone());
                 }
@@ -294,6 +322,11 @@ impl MetadataPolicies {
         MetadataPolicies::Standard(MetadataBasePolicies::AllowIfActorSuperAdmin)
     }
 
+    /// Creates an "Allow if actor is moderator, admin or super admin" metadata policy.
+    pub fn allow_if_actor_moderator() -> Self {
+        MetadataPolicies::Standard(MetadataBasePolicies::AllowIfActorModeratorOrAbove)
+    }
+
     /// Creates an "And" condition metadata policy.
     pub fn and(policies: Vec<MetadataPolicies>) -> Self {
         MetadataPolicies::AndCondition(MetadataAndCondition::new(policies))
@@ -303,6 +336,19 @@ impl MetadataPolicies {
     pub fn any(policies: Vec<MetadataPolicies>) -> Self {
         MetadataPolicies::AnyCondition(MetadataAnyCondition::new(policies))
     }
+
+    /// Returns true if the policy refers to moderators anywhere in its condition tree.
+    pub fn uses_moderators(&self) -> bool {
+        match self {
+            MetadataPolicies::Standard(policy) => {
+                *policy == MetadataBasePolicies::AllowIfActorModeratorOrAbove
+            }
+            MetadataPolicies::AndCondition(MetadataAndCondition { policies })
+            | MetadataPolicies::AnyCondition(MetadataAnyCondition { policies }) => {
+                policies.iter().any(MetadataPolicies::uses_moderators)
+            }
+        }
+    }
 }
 
 /// Implements conversion from MetadataPolicyProto to MetadataPolicies.
@@ -316,6 +362,7 @@ impl TryFrom<MetadataPolicyProto> for MetadataPolicies {
                 2 => Ok(MetadataPolicies::deny()),
                 3 => Ok(MetadataPolicies::allow_if_actor_admin()),
                 4 => Ok(MetadataPolicies::allow_if_actor_super_admin()),
+                5 => Ok(MetadataPolicies::allow_if_actor_moderator()),
                 _ => Err(PolicyError::InvalidMetadataPolicy),
             },
             Some(MetadataPolicyKindProto::AndCondition(inner)) => {
@@ -343,6 +390,11 @@ impl TryFrom<MetadataPolicyProto> for MetadataPolicies {
 
                 Ok(MetadataPolicies::any(policies))
             }
+            // Membership gates look at added inboxes, not metadata fields
+            Some(MetadataPolicyKindProto::InboxAllowlist(_))
+            | Some(MetadataPolicyKindProto::IdentifierRequirement(_)) => {
+                Err(PolicyError::InvalidMetadataPolicy)
+            }
             None => Err(PolicyError::InvalidMetadataPolicy),
         }
     }
@@ -699,6 +751,7 @@ pub enum BasePolicies {
     AllowSameMember,
     AllowIfAdminOrSuperAdmin,
     AllowIfSuperAdmin,
+    AllowIfModeratorOrAbove,
 }
 
 /// Implements the MembershipPolicy trait for BasePolicies.
@@ -710,6 +763,12 @@ impl MembershipPolicy for BasePolicies {
             BasePolicies::AllowSameMember => inbox.inbox_id == actor.inbox_id,
             BasePolicies::AllowIfAdminOrSuperAdmin => actor.is_admin || actor.is_super_admin,
             BasePolicies::AllowIfSuperAdmin => actor.is_super_admin,
+            // Moderators can act on members, but not on admins
+            BasePolicies::AllowIfModeratorOrAbove => {
+                actor.is_admin
+                    || actor.is_super_admin
+                    || (actor.is_moderator && !inbox.is_admin && !inbox.is_super_admin)
+            }
         }
     }
 
@@ -722,6 +781,9 @@ impl MembershipPolicy for BasePolicies {
                 BasePolicyProto::AllowIfAdminOrSuperAdmin as i32
             }
             BasePolicies::AllowIfSuperAdmin => BasePolicyProto::AllowIfSuperAdmin as i32,
+            BasePolicies::AllowIfModeratorOrAbove => {
+                BasePolicyProto::AllowIfModeratorOrAbove as i32
+            }
         };
 
         Ok(MembershipPolicyProto {
@@ -737,6 +799,8 @@ pub enum MembershipPolicies {
     Standard(BasePolicies),
     AndCondition(AndCondition),
     AnyCondition(AnyCondition),
+    InboxAllowlist(InboxAllowlist),
+    IdentifierRequirement(IdentifierRequirement),
 }
 
 impl MembershipPolicies {
@@ -762,6 +826,11 @@ impl MembershipPolicies {
         MembershipPolicies::Standard(BasePolicies::AllowIfSuperAdmin)
     }
 
+    /// Creates an "Allow if actor is moderator, admin or super admin" membership policy.
+    pub fn allow_if_actor_moderator() -> Self {
+        MembershipPolicies::Standard(BasePolicies::AllowIfModeratorOrAbove)
+    }
+
     /// Creates an "And" condition membership policy.
     pub fn and(policies: Vec<MembershipPolicies>) -> Self {
         MembershipPolicies::AndCondition(AndCondition::new(policies))
@@ -771,6 +840,138 @@ impl MembershipPolicies {
     pub fn any(policies: Vec<MembershipPolicies>) -> Self {
         MembershipPolicies::AnyCondition(AnyCondition::new(policies))
     }
+
+    /// Creates an "Allow if the inbox is in the allowlist" membership policy.
+    /// The allowlist is the `inbox_id_set` component `component_id` of the
+    /// group's AppData dictionary.
+    pub fn allow_if_inbox_in_allowlist(component_id: ComponentId) -> Self {
+        MembershipPolicies::InboxAllowlist(InboxAllowlist::new(component_id))
+    }
+
+    /// Creates an "Allow if the inbox has an identifier of `kind`" membership
+    /// policy. If `domain` is set, the identifier must also belong to it.
+    pub fn allow_if_inbox_has_identifier(kind: IdentifierKind, domain: Option<String>) -> Self {
+        MembershipPolicies::IdentifierRequirement(IdentifierRequirement::new(kind, domain))
+    }
+
+    /// Returns true if the policy looks at the added inbox's allowlist
+    /// membership or identifiers, anywhere in its condition tree.
+    pub fn has_inbox_gates(&self) -> bool {
+        !self.allowlist_components().is_empty() || self.requires_identifiers()
+    }
+
+    /// The allowlist components referenced anywhere in the policy.
+    pub fn allowlist_components(&self) -> Vec<ComponentId> {
+        match self {
+            MembershipPolicies::InboxAllowlist(policy) => vec![policy.component_id()],
+            MembershipPolicies::AndCondition(AndCondition { policies })
+            | MembershipPolicies::AnyCondition(AnyCondition { policies }) => policies
+                .iter()
+                .flat_map(MembershipPolicies::allowlist_components)
+                .collect(),
+            MembershipPolicies::Standard(_) | MembershipPolicies::IdentifierRequirement(_) => {
+                vec![]
+            }
+        }
+    }
+
+    /// The policy with every inbox gate treated as satisfied. Used to tell a
+    /// rejection caused by the added inbox apart from one caused by the actor.
+    pub fn with_inbox_gates_satisfied(&self) -> MembershipPolicies {
+        match self {
+            MembershipPolicies::InboxAllowlist(_)
+            | MembershipPolicies::IdentifierRequirement(_) => MembershipPolicies::allow(),
+            MembershipPolicies::AndCondition(AndCondition { policies }) => MembershipPolicies::and(
+                policies
+                    .iter()
+                    .map(MembershipPolicies::with_inbox_gates_satisfied)
+                    .collect(),
+            ),
+            MembershipPolicies::AnyCondition(AnyCondition { policies }) => MembershipPolicies::any(
+                policies
+                    .iter()
+                    .map(MembershipPolicies::with_inbox_gates_satisfied)
+                    .collect(),
+            ),
+            MembershipPolicies::Standard(_) => self.clone(),
+        }
+    }
+
+    /// Returns true if the policy refers to moderators anywhere in its condition tree.
+    pub fn uses_moderators(&self) -> bool {
+        match self {
+            MembershipPolicies::Standard(policy) => {
+                *policy == BasePolicies::AllowIfModeratorOrAbove
+            }
+            MembershipPolicies::AndCondition(AndCondition { policies })
+            | MembershipPolicies::AnyCondition(AnyCondition { policies }) => {
+                policies.iter().any(MembershipPolicies::uses_moderators)
+            }
+            MembershipPolicies::InboxAllowlist(_)
+            | MembershipPolicies::IdentifierRequirement(_) => false,
+        }
+    }
+
+    /// Returns true if the policy checks the identifiers of the added inbox.
+    pub fn requires_identifiers(&self) -> bool {
+        match self {
+            MembershipPolicies::IdentifierRequirement(_) => true,
+            MembershipPolicies::AndCondition(AndCondition { policies })
+            | MembershipPolicies::AnyCondition(AnyCondition { policies }) => policies
+                .iter()
+                .any(MembershipPolicies::requires_identifiers),
+            MembershipPolicies::Standard(_) | MembershipPolicies::InboxAllowlist(_) => false,
+        }
+    }
+
+    /// The registry form of the policy, stored as the `GROUP_MEMBERSHIP`
+    /// policy of migrated groups. Returns `None` if the policy uses
+    /// `AllowSameMember`, which the registry can't express.
+    pub(crate) fn to_metadata_policy_proto(&self) -> Option<MetadataPolicyProto> {
+        let kind = match self {
+            MembershipPolicies::Standard(base) => {
+                let base = match base {
+                    BasePolicies::Allow => MetadataBasePolicyProto::Allow,
+                    BasePolicies::Deny => MetadataBasePolicyProto::Deny,
+                    BasePolicies::AllowIfAdminOrSuperAdmin => MetadataBasePolicyProto::AllowIfAdmin,
+                    BasePolicies::AllowIfSuperAdmin => MetadataBasePolicyProto::AllowIfSuperAdmin,
+                    BasePolicies::AllowIfModeratorOrAbove => {
+                        MetadataBasePolicyProto::AllowIfModerator
+                    }
+                    BasePolicies::AllowSameMember => return None,
+                };
+                MetadataPolicyKindProto::Base(base as i32)
+            }
+            MembershipPolicies::AndCondition(AndCondition { policies }) => {
+                MetadataPolicyKindProto::AndCondition(MetadataAndConditionProto {
+                    policies: policies
+                        .iter()
+                        .map(MembershipPolicies::to_metadata_policy_proto)
+                        .collect::<Option<_>>()?,
+                })
+            }
+            MembershipPolicies::AnyCondition(AnyCondition { policies }) => {
+                MetadataPolicyKindProto::AnyCondition(MetadataAnyConditionProto {
+                    policies: policies
+                        .iter()
+                        .map(MembershipPolicies::to_metadata_policy_proto)
+                        .collect::<Option<_>>()?,
+                })
+            }
+            MembershipPolicies::InboxAllowlist(policy) => {
+                MetadataPolicyKindProto::InboxAllowlist(InboxAllowlistProto {
+                    component_id: policy.component_id().as_u16().into(),
+                })
+            }
+            MembershipPolicies::IdentifierRequirement(policy) => {
+                MetadataPolicyKindProto::IdentifierRequirement(IdentifierRequirementProto {
+                    kind: policy.kind() as i32,
+                    domain: policy.domain().map(str::to_string),
+                })
+            }
+        };
+        Some(MetadataPolicyProto { kind: Some(kind) })
+    }
 }
 
 /// Implements conversion from MembershipPolicyProto to MembershipPolicies.
@@ -784,6 +985,7 @@ impl TryFrom<MembershipPolicyProto> for MembershipPolicies {
                 2 => Ok(MembershipPolicies::deny()),
                 3 => Ok(MembershipPolicies::allow_if_actor_admin()),
                 4 => Ok(MembershipPolicies::allow_if_actor_super_admin()),
+                5 => Ok(MembershipPolicies::allow_if_actor_moderator()),
                 _ => Err(PolicyError::InvalidMembershipPolicy),
             },
             Some(PolicyKindProto::AndCondition(inner)) => {
@@ -811,6 +1013,12 @@ impl TryFrom<MembershipPolicyProto> for MembershipPolicies {
 
                 Ok(MembershipPolicies::any(policies))
             }
+            Some(PolicyKindProto::InboxAllowlist(inner)) => {
+                Ok(MembershipPolicies::InboxAllowlist(inner.try_into()?))
+            }
+            Some(PolicyKindProto::IdentifierRequirement(inner)) => {
+                Ok(MembershipPolicies::IdentifierRequirement(inner.try_into()?))
+            }
             None => Err(PolicyError::InvalidMembershipPolicy),
         }
     }
@@ -823,6 +1031,8 @@ impl MembershipPolicy for MembershipPolicies {
             MembershipPolicies::Standard(policy) => policy.evaluate(actor, inbox),
             MembershipPolicies::AndCondition(policy) => policy.evaluate(actor, inbox),
             MembershipPolicies::AnyCondition(policy) => policy.evaluate(actor, inbox),
+            MembershipPolicies::InboxAllowlist(policy) => policy.evaluate(actor, inbox),
+            MembershipPolicies::IdentifierRequirement(policy) => policy.evaluate(actor, inbox),
         }
     }
 
@@ -831,6 +1041,8 @@ impl MembershipPolicy for MembershipPolicies {
             MembershipPolicies::Standard(policy) => policy.to_proto()?,
             MembershipPolicies::AndCondition(policy) => policy.to_proto()?,
             MembershipPolicies::AnyCondition(policy) => policy.to_proto()?,
+            MembershipPolicies::InboxAllowlist(policy) => policy.to_proto()?,
+            MembershipPolicies::IdentifierRequirement(policy) => policy.to_proto()?,
         })
     }
 }
@@ -902,6 +1114,118 @@ impl MembershipPolicy for AnyCondition {
     }
 }
 
+/// An InboxAllowlist evaluates to true if the added inbox is listed in the
+/// `inbox_id_set` component `component_id` of the group's AppData dictionary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InboxAllowlist {
+    component_id: ComponentId,
+}
+
+impl InboxAllowlist {
+    pub(super) fn new(component_id: ComponentId) -> Self {
+        Self { component_id }
+    }
+
+    pub fn component_id(&self) -> ComponentId {
+        self.component_id
+    }
+}
+
+impl TryFrom<InboxAllowlistProto> for InboxAllowlist {
+    type Error = PolicyError;
+
+    fn try_from(proto: InboxAllowlistProto) -> Result<Self, Self::Error> {
+        let component_id =
+            u16::try_from(proto.component_id).map_err(|_| PolicyError::InvalidMembershipPolicy)?;
+        Ok(Self::new(ComponentId::new(component_id)))
+    }
+}
+
+/// Implements the MembershipPolicy trait for InboxAllowlist.
+impl MembershipPolicy for InboxAllowlist {
+    fn evaluate(&self, _actor: &CommitParticipant, inbox: &Inbox) -> bool {
+        inbox.attributes.allowlists.contains(&self.component_id)
+    }
+
+    fn to_proto(&self) -> Result<MembershipPolicyProto, PolicyError> {
+        Ok(MembershipPolicyProto {
+            kind: Some(PolicyKindProto::InboxAllowlist(InboxAllowlistProto {
+                component_id: self.component_id.as_u16().into(),
+            })),
+        })
+    }
+}
+
+/// An IdentifierRequirement evaluates to true if the added inbox has an
+/// associated identifier of `kind`. If `domain` is set, the identifier must
+/// belong to that domain, which for passkeys is the relying party.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdentifierRequirement {
+    kind: IdentifierKind,
+    domain: Option<String>,
+}
+
+impl IdentifierRequirement {
+    pub(super) fn new(kind: IdentifierKind, domain: Option<String>) -> Self {
+        Self { kind, domain }
+    }
+
+    pub fn kind(&self) -> IdentifierKind {
+        self.kind
+    }
+
+    pub fn domain(&self) -> Option<&str> {
+        self.domain.as_deref()
+    }
+
+    fn matches(&self, identifier: &Identifier) -> bool {
+        if IdentifierKind::from(identifier) != self.kind {
+            return false;
+        }
+        let Some(domain) = &self.domain else {
+            return true;
+        };
+        match identifier {
+            Identifier::Passkey(passkey) => passkey.relying_party.as_ref() == Some(domain),
+            // Ethereum and Solana identifiers carry no domain
+            Identifier::Ethereum(_) | Identifier::Solana(_) => false,
+        }
+    }
+}
+
+impl TryFrom<IdentifierRequirementProto> for IdentifierRequirement {
+    type Error = PolicyError;
+
+    fn try_from(proto: IdentifierRequirementProto) -> Result<Self, Self::Error> {
+        match IdentifierKind::try_from(proto.kind) {
+            Ok(IdentifierKind::Unspecified) | Err(_) => Err(PolicyError::InvalidMembershipPolicy),
+            Ok(kind) => Ok(Self::new(kind, proto.domain)),
+        }
+    }
+}
+
+/// Implements the MembershipPolicy trait for IdentifierRequirement.
+impl MembershipPolicy for IdentifierRequirement {
+    fn evaluate(&self, _actor: &CommitParticipant, inbox: &Inbox) -> bool {
+        inbox
+            .attributes
+            .identifiers
+            .iter()
+            .any(|identifier| self.matches(identifier))
+    }
+
+    fn to_proto(&self) -> Result<MembershipPolicyProto, PolicyError> {
+        Ok(MembershipPolicyProto {
+            kind: Some(PolicyKindProto::IdentifierRequirement(
+                IdentifierRequirementProto {
+                    kind: self.kind as i32,
+                    domain: self.domain.clone(),
+                },
+            )),
+        })
+    }
+}
+
 /// Represents a set of policies for a group.
 #[derive(Debug, Clone, PartialEq)]
 #[allow(dead_code)]
@@ -951,6 +1275,30 @@ impl PolicySet {
         }
     }
 
+    /// The `MinimumSupportedProtocolVersion` floor a group needs before it can use
+    /// these policies, if clients below it can't parse them.
+    pub fn required_min_version(&self) -> Option<&'static str> {
+        let uses_moderators = self.add_member_policy.uses_moderators()
+            || self.remove_member_policy.uses_moderators()
+            || self
+                .update_metadata_policy
+                .values()
+                .any(MetadataPolicies::uses_moderators);
+        uses_moderators.then_some(MODERATORS_MIN_PROTOCOL_VERSION)
+    }
+
+    /// Returns true if the add or remove member policy has inbox gates.
+    pub fn has_inbox_gates(&self) -> bool {
+        self.add_member_policy.has_inbox_gates() || self.remove_member_policy.has_inbox_gates()
+    }
+
+    /// Moderator deletion is opt-in: moderators, and the admins above them, can
+    /// delete other members' messages only while the remove member policy is
+    /// explicitly set to moderators or above.
+    pub fn allows_moderator_deletion(&self) -> bool {
+        self.remove_member_policy == MembershipPolicies::allow_if_actor_moderator()
+    }
+
     /// The [`evaluate_commit`](Self::evaluate_commit) function is the core function for client side verification
     /// that [ValidatedCommit]
     /// adheres to the XMTP permission policies set in the PolicySet.
@@ -1030,6 +1378,15 @@ impl PolicySet {
             .is_empty()
             || (admin_actor.is_super_admin && commit.metadata_validation_info.num_super_admins > 0);
 
+        // Moderators can only be added or removed by admins and super admins
+        let moderator_changes_valid = (commit.metadata_validation_info.moderators_added.is_empty()
+            && commit
+                .metadata_validation_info
+                .moderators_removed
+                .is_empty())
+            || admin_actor.is_admin
+            || admin_actor.is_super_admin;
+
         // Permissions can only be changed by the super admin
         // Use first proposer for permission changes if available
         let permissions_changes_valid = !commit.permissions_changed || admin_actor.is_super_admin;
@@ -1041,6 +1398,7 @@ impl PolicySet {
             && removed_admins_valid
             && super_admin_add_valid
             && super_admin_remove_valid
+            && moderator_changes_valid
             && permissions_changes_valid
     }
 
@@ -1283,6 +1641,10 @@ pub(crate) fn default_policy() -> PolicySet {
                     MetadataPolicies::allow_if_actor_super_admin(),
                 );
             }
+            MetadataField::ExternalInvites => {
+                metadata_policies_map
+                    .insert(field.to_string(), MetadataPolicies::allow_if_actor_admin());
+            }
             _ => {
                 metadata_policies_map.insert(field.to_string(), MetadataPolicies::allow());
             }
@@ -1320,6 +1682,10 @@ pub(crate) fn policy_admin_only() -> PolicySet {
                     MetadataPolicies::allow_if_actor_super_admin(),
                 );
             }
+            MetadataField::ExternalInvites => {
+                metadata_policies_map
+                    .insert(field.to_string(), MetadataPolicies::allow_if_actor_admin());
+            }
             _ => {
                 metadata_policies_map
                     .insert(field.to_string(), MetadataPolicies::allow_if_actor_admin());
@@ -1399,6 +1765,7 @@ pub(crate) mod tests {
             is_super_admin,
             is_admin,
             proposer: None,
+            attributes: Default::default(),
         }
     }
 
@@ -1415,6 +1782,7 @@ pub(crate) mod tests {
             is_creator: is_super_admin,
             is_admin,
             is_super_admin,
+            is_moderator: false,
         }
     }
 
@@ -1651,6 +2019,73 @@ pub(crate) mod tests {
         assert!(permissions.evaluate_commit(&member_added_commit));
     }
 
+    /// Tests that inbox gates look at the added inbox, not the actor.
+    #[xmtp_common::test]
+    fn test_inbox_gates() {
+        let allowlist_id = ComponentId::new(0xC001);
+        let allowlist = MembershipPolicies::and(vec![
+            MembershipPolicies::allow_if_actor_admin(),
+            MembershipPolicies::allow_if_inbox_in_allowlist(allowlist_id),
+        ]);
+        let passkey = MembershipPolicies::allow_if_inbox_has_identifier(
+            IdentifierKind::Passkey,
+            Some("xmtp.org".to_string()),
+        );
+        assert_eq!(allowlist.allowlist_components(), vec![allowlist_id]);
+        assert!(!allowlist.requires_identifiers());
+        assert!(passkey.requires_identifiers());
+
+        let admin = build_actor(None, None, true, false);
+        let mut inbox = build_change(None, false, false);
+        assert!(!allowlist.evaluate(&admin, &inbox));
+        assert!(
+            allowlist
+                .with_inbox_gates_satisfied()
+                .evaluate(&admin, &inbox)
+        );
+        inbox.attributes.allowlists.insert(allowlist_id);
+        assert!(allowlist.evaluate(&admin, &inbox));
+        assert!(!allowlist.evaluate(&build_actor(None, None, false, false), &inbox));
+
+        let passkey_for = |relying_party: &str| {
+            Identifier::Passkey(xmtp_id::associations::ident::Passkey {
+                key: rand_vec::<32>(),
+                relying_party: Some(relying_party.to_string()),
+            })
+        };
+        inbox.attributes.identifiers =
+            vec![Identifier::rand_ethereum(), passkey_for("example.com")];
+        assert!(!passkey.evaluate(&admin, &inbox));
+        inbox.attributes.identifiers.push(passkey_for("xmtp.org"));
+        assert!(passkey.evaluate(&admin, &inbox));
+
+        let permissions = PolicySet::new(
+            MembershipPolicies::any(vec![allowlist, passkey]),
+            MembershipPolicies::allow_if_actor_admin(),
+            MetadataPolicies::default_map(MetadataPolicies::deny()),
+            PermissionsPolicies::allow_if_actor_super_admin(),
+            PermissionsPolicies::allow_if_actor_super_admin(),
+            PermissionsPolicies::allow_if_actor_super_admin(),
+        );
+        let as_bytes = permissions.to_bytes().expect("serialization failed");
+        let restored = PolicySet::from_bytes(as_bytes.as_slice()).expect("proto conversion failed");
+        assert_eq!(permissions, restored);
+
+        // The legacy extension can't carry gates
+        let extension: Result<Vec<u8>, _> = GroupMutablePermissions::new(permissions).try_into();
+        assert!(matches!(
+            extension,
+            Err(GroupMutablePermissionsError::InboxGatesRequireMigration)
+        ));
+        let proto = GroupMutablePermissionsProto {
+            policies: Some(restored.to_proto().unwrap()),
+        };
+        assert!(matches!(
+            GroupMutablePermissions::from_proto(proto),
+            Err(GroupMutablePermissionsError::InboxGatesRequireMigration)
+        ));
+    }
+
     /// Tests that the PolicySet can be serialized and deserialized.
     #[xmtp_common::test]
     fn test_serialize() {
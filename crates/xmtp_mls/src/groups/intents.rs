@@ -240,6 +240,17 @@ impl UpdateMetadataIntentData {
         }
     }
 
+    pub fn new_update_pinned_message_ids(pinned_message_ids: String) -> Self {
+        Self::new(
+            MetadataField::PinnedMessageIds.to_string(),
+            pinned_message_ids,
+        )
+    }
+
+    pub fn new_update_external_invites(external_invites: String) -> Self {
+        Self::new(MetadataField::ExternalInvites.to_string(), external_invites)
+    }
+
     pub fn new_update_conversation_message_disappear_from_ns(from_ns: i64) -> Self {
         Self::new(
             MetadataField::MessageDisappearFromNS.to_string(),
@@ -453,10 +464,12 @@ impl<'a> TryFrom<&'a [u8]> for UpdateGroupMembershipIntentData {
 #[repr(i32)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum AdminListActionType {
-    Add = 1,         // Matches ADD_ADMIN in Protobuf
-    Remove = 2,      // Matches REMOVE_ADMIN in Protobuf
-    AddSuper = 3,    // Matches ADD_SUPER_ADMIN in Protobuf
-    RemoveSuper = 4, // Matches REMOVE_SUPER_ADMIN in Protobuf
+    Add = 1,             // Matches ADD_ADMIN in Protobuf
+    Remove = 2,          // Matches REMOVE_ADMIN in Protobuf
+    AddSuper = 3,        // Matches ADD_SUPER_ADMIN in Protobuf
+    RemoveSuper = 4,     // Matches REMOVE_SUPER_ADMIN in Protobuf
+    AddModerator = 5,    // Matches ADD_MODERATOR in Protobuf
+    RemoveModerator = 6, // Matches REMOVE_MODERATOR in Protobuf
 }
 
 impl TryFrom<i32> for AdminListActionType {
@@ -468,6 +481,8 @@ impl TryFrom<i32> for AdminListActionType {
             2 => Ok(AdminListActionType::Remove),
             3 => Ok(AdminListActionType::AddSuper),
             4 => Ok(AdminListActionType::RemoveSuper),
+            5 => Ok(AdminListActionType::AddModerator),
+            6 => Ok(AdminListActionType::RemoveModerator),
             _ => Err(IntentError::UnknownAdminListAction),
         }
     }
@@ -555,10 +570,11 @@ impl TryFrom<i32> for PermissionUpdateType {
 #[repr(i32)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum PermissionPolicyOption {
-    Allow = 1,          // Matches ADD_MEMBER in Protobuf
-    Deny = 2,           // Matches REMOVE_MEMBER in Protobuf
-    AdminOnly = 3,      // Matches ADD_ADMIN in Protobuf
-    SuperAdminOnly = 4, // Matches REMOVE_ADMIN in Protobuf
+    Allow = 1,            // Matches ADD_MEMBER in Protobuf
+    Deny = 2,             // Matches REMOVE_MEMBER in Protobuf
+    AdminOnly = 3,        // Matches ADD_ADMIN in Protobuf
+    SuperAdminOnly = 4,   // Matches REMOVE_ADMIN in Protobuf
+    ModeratorOrAbove = 5, // Matches MODERATOR_OR_ABOVE in Protobuf
 }
 
 impl TryFrom<i32> for PermissionPolicyOption {
@@ -570,6 +586,7 @@ impl TryFrom<i32> for PermissionPolicyOption {
             2 => Ok(PermissionPolicyOption::Deny),
             3 => Ok(PermissionPolicyOption::AdminOnly),
             4 => Ok(PermissionPolicyOption::SuperAdminOnly),
+            5 => Ok(PermissionPolicyOption::ModeratorOrAbove),
             _ => Err(IntentError::UnknownPermissionPolicyOption),
         }
     }
@@ -584,6 +601,9 @@ impl From<PermissionPolicyOption> for MembershipPolicies {
             PermissionPolicyOption::SuperAdminOnly => {
                 MembershipPolicies::allow_if_actor_super_admin()
             }
+            PermissionPolicyOption::ModeratorOrAbove => {
+                MembershipPolicies::allow_if_actor_moderator()
+            }
         }
     }
 }
@@ -597,6 +617,9 @@ impl From<PermissionPolicyOption> for MetadataPolicies {
             PermissionPolicyOption::SuperAdminOnly => {
                 MetadataPolicies::allow_if_actor_super_admin()
             }
+            PermissionPolicyOption::ModeratorOrAbove => {
+                MetadataPolicies::allow_if_actor_moderator()
+            }
         }
     }
 }
@@ -612,6 +635,12 @@ impl From<PermissionPolicyOption> for PermissionsPolicies {
             }
             PermissionPolicyOption::Deny => PermissionsPolicies::deny(),
             PermissionPolicyOption::AdminOnly => PermissionsPolicies::allow_if_actor_admin(),
+            PermissionPolicyOption::ModeratorOrAbove => {
+                tracing::error!(
+                    "PermissionPolicyOption::ModeratorOrAbove is not allowed for PermissionsPolicies, set to admin only instead"
+                );
+                PermissionsPolicies::allow_if_actor_admin()
+            }
             PermissionPolicyOption::SuperAdminOnly => {
                 PermissionsPolicies::allow_if_actor_super_admin()
             }
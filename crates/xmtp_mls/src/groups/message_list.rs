@@ -33,7 +33,12 @@ where
             &filter_out_hidden_message_types_from_query(query),
         )?;
 
-        enrich_messages(conn, &self.group_id, initial_messages)
+        enrich_messages(
+            conn,
+            &self.group_id,
+            initial_messages,
+            Some(self.context.inbox_id()),
+        )
     }
 }
 
@@ -43,6 +48,8 @@ fn filter_out_hidden_message_types_from_query(query: &MsgQueryArgs) -> MsgQueryA
         DbContentType::Reaction,
         DbContentType::ReadReceipt,
         DbContentType::DeleteMessage,
+        DbContentType::EditMessage,
+        DbContentType::PollVote,
     ];
 
     let excluded_content_types = match &query.exclude_content_types {
@@ -120,6 +127,8 @@ mod tests {
             inserted_at_ns: 0,
             should_push: true,
             idempotency_key: sent_at_ns.to_string(),
+            expire_after_ns: None,
+            view_once: false,
         }
     }
 
@@ -165,6 +174,8 @@ mod tests {
             inserted_at_ns: 0,
             should_push: true,
             idempotency_key: sent_at_ns.to_string(),
+            expire_after_ns: None,
+            view_once: false,
         }
     }
 
@@ -682,6 +693,171 @@ mod tests {
         assert_has_reaction(&messages[0], "👍");
     }
 
+    #[xmtp_common::test]
+    async fn test_find_messages_with_edits() {
+        let (group, context) = setup_test_group().await;
+        let conn = context.db();
+
+        let msg_id = vec![1];
+        let msg_id_hex = msg_id.encode_hex();
+        create_and_store_message(
+            &conn,
+            &group.group_id,
+            msg_id.clone(),
+            TestContentGenerator::text_content("Helo"),
+            0,
+            "sender1",
+        );
+
+        // Edits from the sender are applied in send order, regardless of arrival order
+        create_and_store_message(
+            &conn,
+            &group.group_id,
+            vec![3],
+            TestContentGenerator::edit_message_content(&msg_id_hex, "Hello!"),
+            2000,
+            "sender1",
+        );
+        create_and_store_message(
+            &conn,
+            &group.group_id,
+            vec![2],
+            TestContentGenerator::edit_message_content(&msg_id_hex, "Hello"),
+            1000,
+            "sender1",
+        );
+        // Edits from anyone else are ignored
+        create_and_store_message(
+            &conn,
+            &group.group_id,
+            vec![4],
+            TestContentGenerator::edit_message_content(&msg_id_hex, "Goodbye"),
+            3000,
+            "sender2",
+        );
+
+        let reply_id = create_and_store_message(
+            &conn,
+            &group.group_id,
+            vec![5],
+            TestContentGenerator::reply_content(
+                &msg_id_hex,
+                TextCodec::content_type(),
+                b"Hi".to_vec(),
+            ),
+            4000,
+            "sender2",
+        );
+
+        // Edits are hidden, only the original and the reply are listed
+        let messages = group.find_messages_v2(&MsgQueryArgs::default()).unwrap();
+        assert_message_count(&messages, 2);
+
+        let edited = find_message_by_id(&messages, &msg_id);
+        assert_text_content(edited, "Hello!");
+        assert!(edited.is_edited());
+        let history: Vec<_> = edited
+            .edit_history
+            .iter()
+            .map(|edit| match &edit.content {
+                MessageBody::Text(text) => text.content.clone(),
+                other => panic!("Expected text edit, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(history, vec!["Helo", "Hello", "Hello!"]);
+        assert_eq!(edited.edit_history[0].message_id, msg_id);
+        assert_eq!(edited.edit_history[2].message_id, vec![3]);
+
+        // Replies quote the latest version of the original
+        let reply = find_message_by_id(&messages, &reply_id);
+        assert!(!reply.is_edited());
+        let MessageBody::Reply(reply_body) = &reply.content else {
+            panic!("Expected reply message, got {:?}", reply.content);
+        };
+        assert_text_content(reply_body.in_reply_to.as_ref().unwrap(), "Hello!");
+    }
+
+    #[xmtp_common::test]
+    async fn test_find_messages_with_poll_votes() {
+        let (group, context) = setup_test_group().await;
+        let conn = context.db();
+        let me = context.inbox_id();
+
+        let poll_id = vec![1];
+        let poll_id_hex = poll_id.encode_hex();
+        create_and_store_message(
+            &conn,
+            &group.group_id,
+            poll_id.clone(),
+            TestContentGenerator::poll_content("Lunch?", &["Pizza", "Tacos"], false),
+            0,
+            "sender1",
+        );
+        let multi_poll_id = vec![2];
+        create_and_store_message(
+            &conn,
+            &group.group_id,
+            multi_poll_id.clone(),
+            TestContentGenerator::poll_content("Toppings?", &["Cheese", "Olives", "Ham"], true),
+            100,
+            "sender1",
+        );
+
+        let votes: [(u8, &str, &[u32], i64); 7] = [
+            // Only the latest vote of each voter counts, regardless of arrival order
+            (11, me, &[1], 2000),
+            (10, me, &[0], 1000),
+            // Single-choice polls ignore votes for several options
+            (12, "sender2", &[0], 1000),
+            (13, "sender2", &[0, 1], 2000),
+            // Votes for options the poll doesn't have are ignored
+            (14, "sender3", &[5], 1000),
+            // An empty vote retracts the earlier one
+            (15, "sender4", &[1], 1000),
+            (16, "sender4", &[], 2000),
+        ];
+        for (id, voter, option_indexes, offset) in votes {
+            create_and_store_message(
+                &conn,
+                &group.group_id,
+                vec![id],
+                TestContentGenerator::poll_vote_content(&poll_id_hex, option_indexes),
+                offset,
+                voter,
+            );
+        }
+        create_and_store_message(
+            &conn,
+            &group.group_id,
+            vec![17],
+            TestContentGenerator::poll_vote_content(&multi_poll_id.encode_hex(), &[0, 2, 2]),
+            3000,
+            "sender2",
+        );
+
+        // Votes are hidden, only the polls are listed
+        let messages = group.find_messages_v2(&MsgQueryArgs::default()).unwrap();
+        assert_message_count(&messages, 2);
+
+        let MessageBody::Poll(poll) = &find_message_by_id(&messages, &poll_id).content else {
+            panic!("Expected poll message");
+        };
+        assert_eq!(poll.question, "Lunch?");
+        let counts: Vec<_> = poll.options.iter().map(|o| o.vote_count).collect();
+        assert_eq!(counts, vec![1, 1]);
+        assert_eq!(poll.total_voters, 2);
+        assert_eq!(poll.my_vote, vec![1]);
+
+        let MessageBody::Poll(multi_poll) = &find_message_by_id(&messages, &multi_poll_id).content
+        else {
+            panic!("Expected poll message");
+        };
+        let counts: Vec<_> = multi_poll.options.iter().map(|o| o.vote_count).collect();
+        assert_eq!(counts, vec![1, 0, 1]);
+        assert_eq!(multi_poll.total_voters, 1);
+        assert!(multi_poll.my_vote.is_empty());
+    }
+
     #[xmtp_common::test]
     async fn test_find_messages_chain_of_replies() {
         let (group, context) = setup_test_group().await;
@@ -46,6 +46,13 @@ pub trait CommitLogStorer: std::marker::Sized {
         sequence_id: i64,
     ) -> Result<(), GroupMessageProcessingError>;
 
+    /// Merges the pending external commit this installation used to join the group.
+    fn merge_external_join_logged(
+        &mut self,
+        provider: &impl MlsProviderExt,
+        identity: &Identity,
+    ) -> Result<(), GroupError>;
+
     /// Marks a commit as failed in the commit log.
     /// Only call this when the status of the commit is final.
     /// Specifically, do not call this for retryable errors, or
@@ -242,6 +249,33 @@ impl CommitLogStorer for MlsGroup {
         Ok(())
     }
 
+    fn merge_external_join_logged(
+        &mut self,
+        provider: &impl MlsProviderExt,
+        identity: &Identity,
+    ) -> Result<(), GroupError> {
+        self.merge_pending_commit(provider)?;
+
+        if xmtp_configuration::ENABLE_COMMIT_LOG {
+            NewLocalCommitLog {
+                group_id: self.group_id().try_into()?,
+                // Like a welcome, the commit's cursor is not known until it is read back
+                commit_sequence_id: 0,
+                last_epoch_authenticator: vec![],
+                commit_result: CommitResult::Success,
+                applied_epoch_number: self.epoch().as_u64() as i64,
+                applied_epoch_authenticator: self.epoch_authenticator().as_slice().to_vec(),
+                sender_inbox_id: Some(identity.inbox_id().to_string()),
+                sender_installation_id: Some(identity.installation_id().to_vec()),
+                commit_type: Some(format!("{}", CommitType::ExternalJoin)),
+                error_message: None,
+            }
+            .store(&provider.key_store().db())?;
+        }
+
+        Ok(())
+    }
+
     fn mark_failed_commit_logged(
         &self,
         provider: &impl MlsProviderExt,
@@ -260,11 +294,12 @@ impl CommitLogStorer for MlsGroup {
         // Latest log may not exist if a client upgraded from a version without local commit logs
         if let Some(latest_log) = conn.get_latest_log_for_group(&group_id)?
             && latest_log.commit_type != Some(CommitType::Welcome.to_string())
+            && latest_log.commit_type != Some(CommitType::ExternalJoin.to_string())
         {
             maybe_recently_welcomed = false;
         }
         // If we've recently joined the group, we may get a bunch of wrong epoch errors
-        // until we 'catch up' to the commit that spawned the welcome. We can ignore these for now.
+        // until we 'catch up' to the commit that spawned the welcome or external join. We can ignore these for now.
         if commit_epoch.as_u64() <= last_epoch_number.as_u64() && maybe_recently_welcomed {
             return Ok(());
         }
@@ -1,5 +1,7 @@
 use openmls::{
-    group::{MlsGroupJoinConfig, StagedWelcome, WireFormatPolicy},
+    group::{
+        MIXED_CIPHERTEXT_WIRE_FORMAT_POLICY, MlsGroupJoinConfig, StagedWelcome, WireFormatPolicy,
+    },
     prelude::{
         BasicCredential, KeyPackageBundle, KeyPackageRef, MlsMessageBodyIn, MlsMessageIn, Welcome,
     },
@@ -258,6 +260,17 @@ pub(crate) fn build_group_join_config() -> MlsGroupJoinConfig {
         .build()
 }
 
+/// Join config for groups that admit external invites. Outgoing messages are
+/// still encrypted, but incoming public messages are accepted so that external
+/// commits from invitees can be processed.
+pub(crate) fn build_invite_group_join_config() -> MlsGroupJoinConfig {
+    MlsGroupJoinConfig::builder()
+        .wire_format_policy(MIXED_CIPHERTEXT_WIRE_FORMAT_POLICY)
+        .max_past_epochs(MAX_PAST_EPOCHS)
+        .use_ratchet_tree_extension(true)
+        .build()
+}
+
 fn deserialize_welcome(welcome_bytes: &Vec<u8>) -> Result<Welcome, ClientError> {
     let welcome = MlsMessageIn::tls_deserialize(&mut welcome_bytes.as_slice())?;
     match welcome.extract() {
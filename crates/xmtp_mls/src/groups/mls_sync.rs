@@ -3,6 +3,7 @@ use super::{
     build_extensions_for_metadata_update, build_extensions_for_permissions_update,
     build_group_membership_extension,
     change_callbacks::AppDataChange,
+    ephemeral::EphemeralMessage,
     group_permissions::extract_group_permissions,
     intents::{
         CommitPendingProposalsIntentData, Installation, IntentError, PostCommitAction,
@@ -127,7 +128,7 @@ use xmtp_proto::xmtp::mls::{
     database::{ProcessPendingSelfRemove, Task as TaskProto, task::Task as TaskKind},
     message_contents::{
         GroupUpdated, PlaintextEnvelope, WelcomePointer as WelcomePointerProto, group_updated,
-        plaintext_envelope::{Content, V1, V2},
+        plaintext_envelope::{Content, V1, V2, v2::MessageType},
     },
 };
 use xmtp_proto::{
@@ -1169,7 +1170,16 @@ where
             return Ok(None);
         };
         tracing::debug!("setting message @cursor=[{}] to published", envelope.cursor);
-        let message_expire_at_ns = Self::get_message_expire_at_ns(mls_group);
+        let expire_after_ns = storage
+            .db()
+            .get_group_message(&id)
+            .map_err(|err| IntentResolutionError {
+                processing_error: GroupMessageProcessingError::Db(err),
+                next_intent_state: IntentState::Error,
+            })?
+            .and_then(|message| message.expire_after_ns);
+        let message_expire_at_ns =
+            Self::message_expire_at_ns(mls_group, envelope_timestamp_ns, expire_after_ns);
         storage
             .db()
             .set_delivery_status_to_published(
@@ -1266,23 +1276,37 @@ where
             ProcessedMessageContent::StagedCommitMessage(staged_commit) => {
                 // OpenMLS already verified the framing signature against the
                 // sender's leaf during `process_message`, and `extract_message_sender`
-                // above asserted `Sender::Member` — so this match is exhaustive
-                // for the cases that reach here.
-                let committer_leaf_index = match processed_message.sender() {
-                    openmls::prelude::Sender::Member(idx) => *idx,
+                // above asserted `Sender::Member` or `Sender::NewMemberCommit` — so
+                // this match is exhaustive for the cases that reach here.
+                let result = match processed_message.sender() {
+                    openmls::prelude::Sender::Member(committer_leaf_index) => {
+                        ValidatedCommit::from_staged_commit(
+                            &self.context,
+                            staged_commit,
+                            *committer_leaf_index,
+                            mls_group,
+                        )
+                        .await
+                    }
+                    // An invitee joining through an external invite. The envelope
+                    // timestamp is used for the invite's expiry so every member
+                    // reaches the same decision.
+                    openmls::prelude::Sender::NewMemberCommit => {
+                        ValidatedCommit::from_external_commit(
+                            &self.context,
+                            staged_commit,
+                            processed_message.aad(),
+                            mls_group,
+                            envelope_timestamp_ns as u64,
+                        )
+                        .await
+                    }
                     _ => {
                         return Err(GroupMessageProcessingError::CommitValidation(
                             CommitValidationError::ActorNotMember,
                         ));
                     }
                 };
-                let result = ValidatedCommit::from_staged_commit(
-                    &self.context,
-                    staged_commit,
-                    committer_leaf_index,
-                    mls_group,
-                )
-                .await;
 
                 let validated_commit = match result {
                     Err(e) if !e.is_retryable() => {
@@ -1500,6 +1524,14 @@ where
                 self.context.version_info().pkg_semver(),
             )
             .map_err(GroupMessageProcessingError::from_app_data_processing)?;
+            // Members still encrypt everything, only external commits may be public
+            if matches!(message, ProtocolMessage::PublicMessage(_))
+                && !matches!(processed_message.sender(), Sender::NewMemberCommit)
+            {
+                return Err(GroupMessageProcessingError::UnsupportedMessageType(
+                    discriminant(message),
+                ));
+            }
             let identifier = self.process_external_message(
                 mls_group,
                 processed_message,
@@ -1561,6 +1593,8 @@ where
                     Some(Content::V1(V1 {
                         idempotency_key,
                         content,
+                        expire_after_ns,
+                        view_once,
                     })) => {
                         let message_id =
                             calculate_message_id(self.group_id, &content, &idempotency_key);
@@ -1583,12 +1617,18 @@ where
                             reference_id: queryable_content_fields.reference_id,
                             sequence_id: cursor.sequence_id as i64,
                             originator_id: cursor.originator_id as i64,
-                            expire_at_ns: Self::get_message_expire_at_ns(mls_group),
+                            expire_at_ns: Self::message_expire_at_ns(
+                                mls_group,
+                                envelope_timestamp_ns,
+                                expire_after_ns,
+                            ),
                             inserted_at_ns: 0, // Will be set by database
                             should_push: true,
                             // Persist the key from the wire envelope — the exact
                             // key this message id was derived from.
                             idempotency_key,
+                            expire_after_ns,
+                            view_once,
                         };
                         message.store_or_ignore(&storage.db())?;
                         identifier.internal_id(message_id);
@@ -1631,6 +1671,30 @@ where
 
                         Ok::<_, GroupMessageProcessingError>(())
                     }
+                    Some(Content::V2(V2 {
+                        message_type: Some(MessageType::Ephemeral(ephemeral)),
+                        ..
+                    })) => {
+                        // Ephemeral messages are never stored, only handed to live streams
+                        let message = EphemeralMessage {
+                            group_id: self.group_id,
+                            sender_inbox_id,
+                            sender_installation_id,
+                            content: ephemeral.content,
+                            sent_at_ns: envelope_timestamp_ns,
+                            expires_at_ns: ephemeral.expires_at_ns,
+                        };
+                        if message.is_expired() {
+                            tracing::debug!(
+                                group_id = %self.group_id,
+                                cursor = %cursor,
+                                "dropping expired ephemeral message"
+                            );
+                        } else {
+                            deferred_events.add_local_event(LocalEvents::EphemeralMessage(message));
+                        }
+                        Ok::<_, GroupMessageProcessingError>(())
+                    }
                     Some(Content::V2(V2 { .. })) => {
                         // V2 was used for DeviceSync V1, which is now removed.
                         // Device Sync V2 reverted back to using V1 envelopes.
@@ -1923,7 +1987,7 @@ where
 
         let original_msg_opt = storage.db().get_group_message(&target_message_id)?;
 
-        let is_super_admin_deletion = if let Some(ref original_msg) = original_msg_opt {
+        let deletion_grants = if let Some(ref original_msg) = original_msg_opt {
             if original_msg.group_id.as_slice() != self.group_id.as_slice() {
                 tracing::warn!(
                     "Cross-group deletion attempt: message {} from group {}",
@@ -1944,14 +2008,13 @@ where
             }
 
             let is_sender = original_msg.sender_inbox_id == message.sender_inbox_id;
-            let is_super_admin_deletion = if is_sender {
-                false
+            let (is_super_admin_deletion, is_moderator_deletion) = if is_sender {
+                (false, false)
             } else {
-                self.is_super_admin_without_lock(mls_group, message.sender_inbox_id.clone())
-                    .unwrap_or(false)
+                self.deletion_grants_without_lock(mls_group, &message.sender_inbox_id)
             };
 
-            let is_authorized = is_sender || is_super_admin_deletion;
+            let is_authorized = is_sender || is_super_admin_deletion || is_moderator_deletion;
             if !is_authorized {
                 tracing::warn!(
                     "Unauthorized deletion by {} for message {}",
@@ -1961,13 +2024,13 @@ where
                 return Ok(());
             }
 
-            is_super_admin_deletion
+            (is_super_admin_deletion, is_moderator_deletion)
         } else {
             // Out-of-order: deletion arrived before the message.
             // Authorization is validated at enrichment time via is_deletion_valid().
-            self.is_super_admin_without_lock(mls_group, message.sender_inbox_id.clone())
-                .unwrap_or(false)
+            self.deletion_grants_without_lock(mls_group, &message.sender_inbox_id)
         };
+        let (is_super_admin_deletion, is_moderator_deletion) = deletion_grants;
 
         let deletion = StoredMessageDeletion {
             id: message.id.clone(),
@@ -1976,6 +2039,7 @@ where
             deleted_by_inbox_id: message.sender_inbox_id.clone(),
             is_super_admin_deletion,
             deleted_at_ns: message.sent_at_ns,
+            is_moderator_deletion,
         };
 
         deletion.store_or_ignore(&storage.db())?;
@@ -1991,16 +2055,32 @@ where
         }
 
         tracing::info!(
-            "Message {} deleted by {} (super_admin: {}, out_of_order: {})",
+            "Message {} deleted by {} (super_admin: {}, moderator: {}, out_of_order: {})",
             delete_msg.message_id,
             message.sender_inbox_id,
             is_super_admin_deletion,
+            is_moderator_deletion,
             out_of_order
         );
 
         Ok(())
     }
 
+    /// Whether `inbox_id` may delete other members' messages as a super admin,
+    /// and whether as a moderator.
+    fn deletion_grants_without_lock(
+        &self,
+        mls_group: &OpenMlsGroup,
+        inbox_id: &String,
+    ) -> (bool, bool) {
+        let is_super_admin = self
+            .is_super_admin_without_lock(mls_group, inbox_id.clone())
+            .unwrap_or(false);
+        let can_moderate =
+            !is_super_admin && self.can_moderate_messages_without_lock(mls_group, inbox_id);
+        (is_super_admin, can_moderate)
+    }
+
     fn process_admin_pending_remove_actions(
         &self,
         mls_group: &OpenMlsGroup,
@@ -2192,6 +2272,22 @@ where
         Ok(())
     }
 
+    /// The earlier of the group's disappearing messages deadline and the
+    /// sender-requested lifetime of the message, counted from `sent_at_ns`.
+    fn message_expire_at_ns(
+        mls_group: &OpenMlsGroup,
+        sent_at_ns: i64,
+        expire_after_ns: Option<i64>,
+    ) -> Option<i64> {
+        let sender_expire_at_ns = expire_after_ns
+            .filter(|ns| *ns > 0)
+            .map(|ns| sent_at_ns.saturating_add(ns));
+        Self::get_message_expire_at_ns(mls_group)
+            .into_iter()
+            .chain(sender_expire_at_ns)
+            .min()
+    }
+
     fn get_message_expire_at_ns(mls_group: &OpenMlsGroup) -> Option<i64> {
         // A parse failure here must not look identical to "disappearing
         // messages disabled" — warn before treating it as None.
@@ -2270,9 +2366,14 @@ where
 
         let outcome = self
             .load_mls_group_with_lock_async(async |mut mls_group| {
-                // ensure we are processing a private message
+                // ensure we are processing a private message, or an invitee's
+                // external commit, which is published unencrypted
                 match &envelope.message {
                     ProtocolMessage::PrivateMessage(_) => (),
+                    ProtocolMessage::PublicMessage(_)
+                        if super::external_invites::admits_external_invites(
+                            mls_group.extensions(),
+                        ) => {}
                     other => {
                         return Err(GroupMessageProcessingError::UnsupportedMessageType(
                             discriminant(other),
@@ -2964,6 +3065,8 @@ where
             should_push: true,
             // Matches the key used to derive `message_id` above.
             idempotency_key: timestamp_ns.to_string(),
+            expire_after_ns: None,
+            view_once: false,
         };
 
         msg.store_or_ignore(&storage.db())?;
@@ -3407,10 +3510,26 @@ where
                     }));
                 }
 
+                // New invites must name the epoch this commit creates, which
+                // moves on whenever the intent loses an epoch race and is
+                // republished.
+                let field_value =
+                    if metadata_intent.field_name == MetadataField::ExternalInvites.as_str() {
+                        let committed =
+                            Self::read_metadata_field(openmls_group, &metadata_intent.field_name)
+                                .flatten();
+                        super::external_invites::stamp_new_invites(
+                            committed.as_deref(),
+                            &metadata_intent.field_value,
+                            openmls_group.epoch().as_u64() + 1,
+                        )
+                    } else {
+                        metadata_intent.field_value
+                    };
                 let mutable_metadata_extensions = build_extensions_for_metadata_update(
                     openmls_group,
                     metadata_intent.field_name,
-                    metadata_intent.field_value,
+                    field_value,
                 )?;
 
                 let keys = self.context.identity().installation_keys.clone();
@@ -4244,6 +4363,21 @@ where
                 Some(IntentKind::all().collect()),
             )?;
 
+        // Invitees can only join an epoch with its GroupInfo, so whoever commits
+        // publishes the new one for the group's invites. This runs before the
+        // intents are marked processed so that a failure is retried on the next sync.
+        let committed = intents.iter().any(|intent| {
+            !matches!(
+                intent.kind,
+                IntentKind::SendMessage
+                    | IntentKind::ProposeMemberUpdate
+                    | IntentKind::ProposeGroupContextExtensions
+            )
+        });
+        if committed {
+            self.publish_group_info(&self.invites()?).await?;
+        }
+
         for intent in intents {
             if let Some(post_commit_data) = intent.post_commit_data {
                 tracing::debug!(
@@ -4700,6 +4834,22 @@ fn extract_message_sender(
         return Ok((sender_inbox_id, member.signature_key));
     }
 
+    // External committers are not in the tree yet. Their leaf is the one
+    // carried in the commit's update path.
+    if let Sender::NewMemberCommit = decrypted_message.sender()
+        && let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+            decrypted_message.content()
+        && let Some(leaf_node) = staged_commit.update_path_leaf_node()
+        && leaf_node.credential().eq(decrypted_message.credential())
+    {
+        let basic_credential = BasicCredential::try_from(leaf_node.credential().clone())?;
+        let sender_inbox_id = parse_credential(basic_credential.identity())?;
+        return Ok((
+            sender_inbox_id,
+            leaf_node.signature_key().as_slice().to_vec(),
+        ));
+    }
+
     let basic_credential = BasicCredential::try_from(decrypted_message.credential().clone())?;
     Err(GroupMessageProcessingError::InvalidSender {
         message_time_ns: message_created_ns,
@@ -5138,6 +5288,8 @@ pub(crate) mod tests {
             inserted_at_ns: 0,
             should_push: false,
             idempotency_key: String::new(),
+            expire_after_ns: None,
+            view_once: false,
         };
 
         // Use load_mls_group_with_lock to get access to the MLS group and call process_delete_message
@@ -5206,6 +5358,8 @@ pub(crate) mod tests {
             inserted_at_ns: 0,
             should_push: false,
             idempotency_key: String::new(),
+            expire_after_ns: None,
+            view_once: false,
         };
 
         let storage = alix.context.mls_storage();
@@ -5281,6 +5435,8 @@ pub(crate) mod tests {
             inserted_at_ns: 0,
             should_push: false,
             idempotency_key: String::new(),
+            expire_after_ns: None,
+            view_once: false,
         };
 
         let storage = alix.context.mls_storage();
@@ -79,6 +79,27 @@ pub(crate) fn strip_unverified_new_adds(
     }
 }
 
+/// Drop installations that already have a leaf in the tree. A member who
+/// joined through an external commit has a leaf before its inbox is in the
+/// group membership, so admitting that inbox must not re-add the leaf. Run
+/// after [`strip_unverified_new_adds`], which counts these key packages as
+/// verification of the inbox.
+pub(crate) fn strip_existing_leaf_adds(
+    openmls_group: &OpenMlsGroup,
+    changes_with_kps: &mut MembershipDiffWithKeyPackages,
+) {
+    let existing: std::collections::HashSet<Vec<u8>> = openmls_group
+        .members()
+        .map(|member| member.signature_key)
+        .collect();
+    changes_with_kps
+        .new_key_packages
+        .retain(|kp| !existing.contains(kp.leaf_node().signature_key().as_slice()));
+    changes_with_kps
+        .new_installations
+        .retain(|installation| !existing.contains(&installation.installation_key));
+}
+
 /// Build the wire-level `TlsMapDelta<InboxId, VLBytes>` payload for an
 /// `AppDataUpdate(GROUP_MEMBERSHIP)` proposal from the diff between
 /// the old and new `GroupMembership` view. Shared between the commit-
@@ -184,6 +205,7 @@ pub(crate) async fn apply_update_group_membership_intent(
         &old_group_membership,
         &changes_with_kps.new_key_packages,
     );
+    strip_existing_leaf_adds(openmls_group, &mut changes_with_kps);
     let membership_diff = old_group_membership.diff(&new_group_membership);
 
     let leaf_nodes_to_remove: Vec<LeafNodeIndex> =
@@ -2,7 +2,11 @@ pub mod app_data;
 pub mod change_callbacks;
 pub mod commit_log;
 pub mod commit_log_key;
+pub mod conversation_settings;
+pub mod drafts;
+pub mod ephemeral;
 mod error;
+pub mod external_invites;
 pub mod group_membership;
 pub mod group_permissions;
 pub mod intents;
@@ -10,13 +14,17 @@ pub mod members;
 pub mod message_list;
 pub(super) mod mls_ext;
 pub(super) mod mls_sync;
+pub mod moderators;
 pub mod oneshot;
+pub mod read_state;
+pub mod scheduled_messages;
 pub mod send_message_opts;
 pub(super) mod subscriptions;
 pub mod summary;
 #[cfg(test)]
 mod tests;
 pub mod validated_commit;
+pub mod view_once;
 pub mod welcome_pointer;
 pub mod welcome_sync;
 mod welcomes;
@@ -26,9 +34,12 @@ pub use self::group_permissions::PreconfiguredPolicies;
 use self::{
     group_membership::GroupMembership,
     group_permissions::PolicySet,
-    group_permissions::{GroupMutablePermissions, extract_group_permissions},
+    group_permissions::{
+        GroupMutablePermissions, GroupMutablePermissionsError, MembershipPolicies,
+        extract_group_permissions,
+    },
     intents::{
-        AdminListActionType, PermissionPolicyOption, PermissionUpdateType,
+        AdminListActionType, AppDataUpdateIntentData, PermissionPolicyOption, PermissionUpdateType,
         UpdateAdminListIntentData, UpdateMetadataIntentData, UpdatePermissionIntentData,
     },
 };
@@ -70,15 +81,18 @@ use xmtp_configuration::{
     WELCOME_POINTEE_ENCRYPTION_AEAD_TYPES_EXTENSION_ID, WELCOME_WRAPPER_ENCRYPTION_EXTENSION_ID,
 };
 use xmtp_content_types::delete_message::DeleteMessageCodec;
+use xmtp_content_types::edit_message::EditMessageCodec;
 use xmtp_content_types::leave_request::LeaveRequestCodec;
+use xmtp_content_types::poll::PollVoteCodec;
 use xmtp_content_types::{ContentCodec, encoded_content_to_bytes};
 use xmtp_content_types::{
     reaction::{LegacyReaction, ReactionCodec},
     reply::ReplyCodec,
 };
 use xmtp_cryptography::configuration::ED25519_KEY_LENGTH;
-use xmtp_db::group_message::Deletable;
+use xmtp_db::group_message::{Deletable, Editable};
 use xmtp_db::message_deletion::{QueryMessageDeletion, StoredMessageDeletion};
+use xmtp_db::message_search::MessageSearchHit;
 use xmtp_db::pending_remove::QueryPendingRemove;
 use xmtp_db::prelude::*;
 use xmtp_db::user_preferences::HmacKey;
@@ -106,16 +120,20 @@ use xmtp_mls_common::{
     app_data::components::{
         inbox_id_set::{AdminListComponent, SuperAdminListComponent},
         metadata_attributes::{
-            AppDataComponent, GroupDescriptionComponent, GroupImageUrlComponent, GroupNameComponent,
+            AppDataComponent, GroupDescriptionComponent, GroupImageUrlComponent,
+            GroupNameComponent, PinnedMessagesComponent,
         },
     },
     group::{DMMetadataOptions, GroupMetadataOptions},
     group_metadata::{DmMembers, GroupMetadata, GroupMetadataError, extract_group_metadata},
     group_mutable_metadata::{
-        GroupMutableMetadata, GroupMutableMetadataError, MessageDisappearingSettings, MetadataField,
+        GroupMutableMetadata, GroupMutableMetadataError, MessageDisappearingSettings,
+        MetadataField, decode_pinned_message_ids, encode_pinned_message_ids,
     },
 };
-use xmtp_proto::xmtp::mls::message_contents::content_types::{DeleteMessage, LeaveRequest};
+use xmtp_proto::xmtp::mls::message_contents::content_types::{
+    DeleteMessage, EditMessage, LeaveRequest, PollVote,
+};
 use xmtp_proto::{
     types::{Cursor, GroupId},
     xmtp::mls::message_contents::{
@@ -129,6 +147,12 @@ const MAX_GROUP_DESCRIPTION_LENGTH: usize = 1000;
 const MAX_GROUP_NAME_LENGTH: usize = 100;
 const MAX_GROUP_IMAGE_URL_LENGTH: usize = 2048;
 const MAX_APP_DATA_LENGTH: usize = 8192;
+const MAX_PINNED_MESSAGES: usize = 50;
+// Each pin is a hex-encoded 32 byte message id plus a separator
+const MAX_PINNED_MESSAGE_IDS_LENGTH: usize = MAX_PINNED_MESSAGES * 65;
+const MAX_EXTERNAL_INVITES: usize = 20;
+// Each invite is a hex-encoded policy entry, an inbox id, an epoch and separators
+const MAX_EXTERNAL_INVITES_LENGTH: usize = MAX_EXTERNAL_INVITES * 256;
 
 /// An LibXMTP MlsGroup
 /// _NOTE:_ The Eq implementation compares [`GroupId`], so a dm group with the same identity will be
@@ -182,6 +206,14 @@ pub struct ConversationListItem<Context> {
     pub group: MlsGroup<Context>,
     pub last_message: Option<StoredGroupMessage>,
     pub is_commit_log_forked: Option<bool>,
+    pub has_draft: bool,
+    /// Number of messages from other inboxes after the read cursor.
+    pub unread_count: i64,
+    pub first_unread_message_id: Option<Vec<u8>>,
+    /// Notifications are muted until this time, if set.
+    pub muted_until_ns: Option<i64>,
+    pub is_archived: bool,
+    pub is_pinned: bool,
 }
 
 impl<Context: XmtpSharedContext> Clone for MlsGroup<Context> {
@@ -327,6 +359,16 @@ impl TryFrom<EncodedContent> for QueryableContentFields {
                     .ok()
                     .and_then(|delete_msg| hex::decode(delete_msg.message_id).ok())
             }
+            (EditMessageCodec::TYPE_ID, EditMessageCodec::MAJOR_VERSION) => {
+                EditMessage::decode(content.content.as_slice())
+                    .ok()
+                    .and_then(|edit_msg| hex::decode(edit_msg.message_id).ok())
+            }
+            (PollVoteCodec::TYPE_ID, PollVoteCodec::MAJOR_VERSION) => {
+                PollVote::decode(content.content.as_slice())
+                    .ok()
+                    .and_then(|vote| hex::decode(vote.poll_message_id).ok())
+            }
             _ => None,
         };
 
@@ -1300,8 +1342,11 @@ where
             conversation_type,
             oneshot_message,
         )?;
-        let mutable_metadata =
-            build_mutable_metadata_extension_default(creator_inbox_id, opts.clone())?;
+        let mutable_metadata = build_mutable_metadata_extension(
+            creator_inbox_id,
+            opts.clone(),
+            permissions_policy_set.required_min_version(),
+        )?;
         let group_membership = build_starting_group_membership_extension(creator_inbox_id, 0);
         let mutable_permissions = build_mutable_permissions_extension(permissions_policy_set)?;
         let group_config = build_group_config(
@@ -1454,8 +1499,12 @@ where
         // OpenMLS blocks message creation when there are pending proposals
         self.commit_pending_proposals_if_any().await?;
 
-        let message_id =
-            self.prepare_message(message, opts, |key| Self::into_envelope(message, key))?;
+        if opts.expires() {
+            self.ensure_group_min_version(xmtp_configuration::MESSAGE_EXPIRY_MIN_PROTOCOL_VERSION)
+                .await?;
+        }
+
+        let message_id = self.prepare_message(message, opts, Self::into_envelope)?;
 
         self.sync_until_last_intent_resolved().await?;
 
@@ -1522,8 +1571,7 @@ where
         message: &[u8],
         opts: send_message_opts::SendMessageOpts,
     ) -> Result<Vec<u8>, GroupError> {
-        let message_id =
-            self.prepare_message(message, opts, |key| Self::into_envelope(message, key))?;
+        let message_id = self.prepare_message(message, opts, Self::into_envelope)?;
         Ok(message_id)
     }
 
@@ -1545,10 +1593,35 @@ where
         should_push: bool,
         idempotency_key: Option<String>,
     ) -> Result<Vec<u8>, GroupError> {
+        let opts = SendMessageOpts {
+            should_push,
+            idempotency_key,
+            ..Default::default()
+        };
+        self.store_unpublished_message(message, opts)
+    }
+
+    /// Store the message locally with `Unpublished` delivery status, applying
+    /// all of the send options. Returns the message ID.
+    ///
+    /// Refuses view-once and expire-after messages unless the group's floor is
+    /// already at [`xmtp_configuration::MESSAGE_EXPIRY_MIN_PROTOCOL_VERSION`];
+    /// only [`Self::send_message`] raises it.
+    pub(crate) fn store_unpublished_message(
+        &self,
+        message: &[u8],
+        opts: SendMessageOpts,
+    ) -> Result<Vec<u8>, GroupError> {
+        let required = xmtp_configuration::MESSAGE_EXPIRY_MIN_PROTOCOL_VERSION;
+        if opts.expires() && !self.meets_group_min_version(required)? {
+            return Err(GroupError::MessageExpiryUnsupported {
+                required: required.to_string(),
+            });
+        }
         let now = now_ns();
         // Resolve the key once: a caller-supplied key makes the resulting id
         // deterministic; otherwise we fall back to the timestamp (always unique).
-        let idempotency_key = idempotency_key.unwrap_or_else(|| now.to_string());
+        let idempotency_key = opts.idempotency_key.unwrap_or_else(|| now.to_string());
         let queryable_content_fields = Self::extract_queryable_content_fields(message);
 
         let message_id = calculate_message_id(self.group_id, message, &idempotency_key);
@@ -1578,8 +1651,10 @@ where
             originator_id: 0,
             expire_at_ns: None,
             inserted_at_ns: 0,
-            should_push,
+            should_push: opts.should_push,
             idempotency_key,
+            expire_after_ns: opts.expire_after_ns.filter(|ns| *ns > 0),
+            view_once: opts.view_once,
         };
         group_message.store(&self.context.db())?;
 
@@ -1614,8 +1689,7 @@ where
 
         // Create envelope from stored message, reusing the idempotency key the
         // message id was derived from so receivers recompute the same id.
-        let plain_envelope =
-            Self::into_envelope(&message.decrypted_message_bytes, &message.idempotency_key);
+        let plain_envelope = Self::into_envelope(&message);
         let mut encoded_envelope = vec![];
         plain_envelope.encode(&mut encoded_envelope)?;
 
@@ -1676,8 +1750,11 @@ where
         let sender_inbox_id = self.context.inbox_id();
         let is_sender = original_msg.sender_inbox_id == sender_inbox_id;
         let is_super_admin = self.is_super_admin(sender_inbox_id.to_string())?;
+        // Moderators can delete anyone's messages only when the group's policies allow it
+        let can_moderate =
+            !is_super_admin && self.can_moderate_messages(&sender_inbox_id.to_string())?;
 
-        if !is_sender && !is_super_admin {
+        if !is_sender && !is_super_admin && !can_moderate {
             return Err(DeleteMessageError::NotAuthorized.into());
         }
 
@@ -1696,6 +1773,7 @@ where
         let deletion_message_id = self.send_message_optimistic(&buf, SendMessageOpts::default())?;
 
         let is_super_admin_deletion = !is_sender && is_super_admin;
+        let is_moderator_deletion = !is_sender && can_moderate;
 
         let deletion = StoredMessageDeletion {
             id: deletion_message_id.clone(),
@@ -1704,6 +1782,7 @@ where
             deleted_by_inbox_id: sender_inbox_id.to_string(),
             is_super_admin_deletion,
             deleted_at_ns: now_ns(),
+            is_moderator_deletion,
         };
 
         deletion.store(&conn)?;
@@ -1711,6 +1790,71 @@ where
         Ok(deletion_message_id)
     }
 
+    /// Edit a message by its ID. Returns the ID of the edit message.
+    ///
+    /// Only the original sender can edit a message, and only text content can be edited or used
+    /// as the replacement. Edits are stored as messages referencing the original, so
+    /// [`Self::find_enriched_messages`] shows the latest content along with the edit history.
+    /// Receivers apply the same authorization when enriching messages, so edits sent by anyone
+    /// other than the original sender are never shown.
+    ///
+    /// # Arguments
+    /// * `message_id` - The message ID as bytes
+    /// * `edited_content` - The content that replaces the original message content
+    ///
+    /// # Returns
+    /// The ID of the edit message
+    pub fn edit_message(
+        &self,
+        message_id: Vec<u8>,
+        edited_content: EncodedContent,
+    ) -> Result<Vec<u8>, GroupError> {
+        use error::EditMessageError;
+
+        let conn = self.context.db();
+
+        let original_msg = conn
+            .get_group_message(&message_id)?
+            .ok_or_else(|| EditMessageError::MessageNotFound(hex::encode(&message_id)))?;
+
+        // Validate message belongs to this group (prevent cross-group edits)
+        if original_msg.group_id.as_slice() != self.group_id.as_slice() {
+            return Err(EditMessageError::NotAuthorized.into());
+        }
+
+        if original_msg.sender_inbox_id != self.context.inbox_id() {
+            return Err(EditMessageError::NotAuthorized.into());
+        }
+
+        if !original_msg.kind.is_editable() || !original_msg.content_type.is_editable() {
+            return Err(EditMessageError::NonEditableMessage.into());
+        }
+
+        if conn.is_message_deleted(&message_id)? {
+            return Err(EditMessageError::MessageDeleted.into());
+        }
+
+        let edited_type_id = edited_content
+            .r#type
+            .as_ref()
+            .map(|content_type| content_type.type_id.clone())
+            .unwrap_or_default();
+        if !ContentType::from(edited_type_id.clone()).is_editable() {
+            return Err(EditMessageError::NonEditableContent(edited_type_id).into());
+        }
+
+        let edit_msg = EditMessage {
+            message_id: hex::encode(&message_id),
+            edited_content: Some(edited_content),
+        };
+
+        let encoded_edit = EditMessageCodec::encode(edit_msg)?;
+        let mut buf = Vec::new();
+        encoded_edit.encode(&mut buf)?;
+
+        self.send_message_optimistic(&buf, SendMessageOpts::default())
+    }
+
     /// Helper function to extract queryable content fields from a message
     fn extract_queryable_content_fields(message: &[u8]) -> QueryableContentFields {
         // Return early with default if decoding fails or type is missing
@@ -1735,7 +1879,7 @@ where
     /// * message: UTF-8 or encoded message bytes
     /// * opts: Options for sending the message
     /// * envelope: closure that returns context-specific [`PlaintextEnvelope`]. Closure accepts
-    ///   timestamp attached to intent & stored message.
+    ///   the stored message, carrying the idempotency key the message id was derived from.
     #[tracing::instrument(skip_all, level = "trace")]
     pub(crate) fn prepare_message<F>(
         &self,
@@ -1744,14 +1888,10 @@ where
         envelope: F,
     ) -> Result<Vec<u8>, GroupError>
     where
-        F: FnOnce(&str) -> PlaintextEnvelope,
+        F: FnOnce(&StoredGroupMessage) -> PlaintextEnvelope,
     {
         // Store the message locally first (with should_push preference)
-        let message_id = self.prepare_message_for_later_publish(
-            message,
-            opts.should_push,
-            opts.idempotency_key,
-        )?;
+        let message_id = self.store_unpublished_message(message, opts)?;
 
         // Fetch the stored message to get the resolved idempotency key
         let stored_message = self
@@ -1761,7 +1901,7 @@ where
             .ok_or_else(|| GroupError::NotFound(NotFound::MessageById(message_id.clone())))?;
 
         // Create envelope using the stored idempotency key so the id stays consistent
-        let plain_envelope = envelope(&stored_message.idempotency_key);
+        let plain_envelope = envelope(&stored_message);
         let mut encoded_envelope = vec![];
         plain_envelope.encode(&mut encoded_envelope)?;
 
@@ -1775,11 +1915,13 @@ where
         Ok(message_id)
     }
 
-    fn into_envelope(encoded_msg: &[u8], idempotency_key: &str) -> PlaintextEnvelope {
+    fn into_envelope(message: &StoredGroupMessage) -> PlaintextEnvelope {
         PlaintextEnvelope {
             content: Some(Content::V1(V1 {
-                content: encoded_msg.to_vec(),
-                idempotency_key: idempotency_key.to_string(),
+                content: message.decrypted_message_bytes.clone(),
+                idempotency_key: message.idempotency_key.clone(),
+                expire_after_ns: message.expire_after_ns,
+                view_once: message.view_once,
             })),
         }
     }
@@ -1802,6 +1944,20 @@ where
         Ok(count)
     }
 
+    /// Full-text search over the messages of this conversation, ordered by relevance.
+    ///
+    /// `args` narrows the search with the same filters as [`Self::find_messages`]; results are
+    /// always ranked, so its sort options are ignored.
+    pub fn search_messages(
+        &self,
+        query: &str,
+        args: &MsgQueryArgs,
+    ) -> Result<Vec<MessageSearchHit>, GroupError> {
+        let conn = self.context.db();
+        let hits = conn.search_messages(query, Some(&self.group_id), args)?;
+        Ok(hits)
+    }
+
     /// Query the database for stored messages. Optionally filtered by time, kind, delivery_status
     /// and limit
     pub fn find_messages_with_reactions(
@@ -1821,8 +1977,12 @@ where
     ) -> Result<Vec<crate::messages::decoded_message::DecodedMessage>, EnrichMessageError> {
         let conn = self.context.db();
         let messages = conn.get_group_messages(&self.group_id, args)?;
-        let enriched =
-            crate::messages::enrichment::enrich_messages(conn, &self.group_id, messages)?;
+        let enriched = crate::messages::enrichment::enrich_messages(
+            conn,
+            &self.group_id,
+            messages,
+            Some(self.context.inbox_id()),
+        )?;
         Ok(enriched)
     }
 
@@ -2012,19 +2172,8 @@ where
     ) -> Result<(), GroupError> {
         self.ensure_not_paused().await?;
 
-        let readd_min_version =
-            LibXMTPVersion::parse(xmtp_configuration::MIN_RECOVERY_REQUEST_VERSION)?;
-        let metadata = self.mutable_metadata()?;
-        let group_version = metadata
-            .attributes
-            .get(MetadataField::MinimumSupportedProtocolVersion.as_str());
-        let group_min_version =
-            LibXMTPVersion::parse(group_version.unwrap_or(&"0.0.0".to_string()))?;
-
-        if readd_min_version > group_min_version {
-            self.update_group_min_version(xmtp_configuration::MIN_RECOVERY_REQUEST_VERSION)
-                .await?;
-        }
+        self.ensure_group_min_version(xmtp_configuration::MIN_RECOVERY_REQUEST_VERSION)
+            .await?;
 
         let intent_data: Vec<u8> = ReaddInstallationsIntentData::new(installations.clone()).into();
         let intent = QueueIntent::readd_installations()
@@ -2036,6 +2185,30 @@ where
         Ok(())
     }
 
+    /// Raises the group's minimum supported protocol version to `version` if it is
+    /// currently lower, so that older clients pause before they are sent anything
+    /// they can't process.
+    pub(crate) async fn ensure_group_min_version(&self, version: &str) -> Result<(), GroupError> {
+        if !self.meets_group_min_version(version)? {
+            self.update_group_min_version(version).await?;
+        }
+        Ok(())
+    }
+
+    /// Whether the group's minimum supported protocol version is already at
+    /// least `version`.
+    pub(crate) fn meets_group_min_version(&self, version: &str) -> Result<bool, GroupError> {
+        let required_version = LibXMTPVersion::parse(version)?;
+        let metadata = self.mutable_metadata()?;
+        let group_version = metadata
+            .attributes
+            .get(MetadataField::MinimumSupportedProtocolVersion.as_str());
+        let group_min_version =
+            LibXMTPVersion::parse(group_version.unwrap_or(&"0.0.0".to_string()))?;
+
+        Ok(required_version <= group_min_version)
+    }
+
     /// Process this group's pending self-remove requests end-to-end: remove the
     /// members still in the group that requested removal, then clean up stale
     /// pending-remove rows. Idempotent and a no-op when this client is not a
@@ -2557,6 +2730,10 @@ where
         {
             return Err(MetadataPermissionsError::InvalidPermissionUpdate.into());
         }
+        if permission_policy == PermissionPolicyOption::ModeratorOrAbove {
+            self.ensure_group_min_version(xmtp_configuration::MODERATORS_MIN_PROTOCOL_VERSION)
+                .await?;
+        }
 
         let intent_data: Vec<u8> = UpdatePermissionIntentData::new(
             permission_update_type,
@@ -2573,6 +2750,57 @@ where
         Ok(())
     }
 
+    /// Replaces the add member policy of the group. Unlike
+    /// [`update_permission_policy`](Self::update_permission_policy), the policy
+    /// can gate the added inbox on an allowlist or an associated identifier.
+    ///
+    /// Only groups migrated to the app data dictionary can use membership
+    /// gates, and only super admins can change the policy. The group's minimum
+    /// supported protocol version is raised first, so clients that can't
+    /// evaluate the policy pause instead of forking.
+    pub async fn update_add_member_policy(
+        &self,
+        policy: MembershipPolicies,
+    ) -> Result<(), GroupError> {
+        self.ensure_not_paused().await?;
+
+        if self.metadata().await?.conversation_type == ConversationType::Dm {
+            return Err(MetadataPermissionsError::DmGroupMetadataForbidden.into());
+        }
+        if !self::app_data::is_migrated_extensions(self.load_group_context()?.extensions()) {
+            return Err(MetadataPermissionsError::from(
+                GroupMutablePermissionsError::InboxGatesRequireMigration,
+            )
+            .into());
+        }
+        if policy.has_inbox_gates() {
+            self.ensure_group_min_version(
+                xmtp_configuration::MEMBERSHIP_GATES_MIN_PROTOCOL_VERSION,
+            )
+            .await?;
+        }
+        if policy.uses_moderators() {
+            self.ensure_group_min_version(xmtp_configuration::MODERATORS_MIN_PROTOCOL_VERSION)
+                .await?;
+        }
+
+        let payload = self.load_mls_group_with_lock(self.context.mls_storage(), |mls_group| {
+            self::app_data::policy::add_member_policy_registry_update(&mls_group, &policy)
+                .map_err(GroupError::from)
+        })?;
+        let intent_data: Vec<u8> = AppDataUpdateIntentData::new(
+            xmtp_mls_common::app_data::component_id::ComponentId::COMPONENT_REGISTRY.as_u16(),
+            payload,
+        )
+        .into();
+        let intent = QueueIntent::app_data_update()
+            .data(intent_data)
+            .queue(self)?;
+
+        let _ = self.sync_until_intent_resolved(intent.id).await?;
+        Ok(())
+    }
+
     /// Retrieves the group name from the group's mutable metadata extension.
     pub fn group_name(&self) -> Result<String, GroupError> {
         self.read_single_component::<GroupNameComponent>()?
@@ -2629,6 +2857,84 @@ where
             })
     }
 
+    /// Pins a message so every member of the group sees it in [`Self::pinned_messages`].
+    ///
+    /// Who may pin is controlled by the `pinned_message_ids` metadata policy. Pinning a message
+    /// that is already pinned is a no-op. Concurrent pin changes from different members are
+    /// last-writer-wins, like every other metadata field.
+    #[cfg_attr(any(test, feature = "test-utils"), tracing::instrument(level = "info", fields(who = %self.context.inbox_id()), skip(self)))]
+    #[cfg_attr(
+        not(any(test, feature = "test-utils")),
+        tracing::instrument(level = "trace", skip(self))
+    )]
+    pub async fn pin_message(&self, message_id: &[u8]) -> Result<(), GroupError> {
+        use error::PinMessageError;
+
+        let message = self
+            .context
+            .db()
+            .get_group_message(message_id)?
+            .filter(|msg| msg.group_id.as_slice() == self.group_id.as_slice())
+            .ok_or_else(|| PinMessageError::MessageNotFound(hex::encode(message_id)))?;
+        if message.kind != GroupMessageKind::Application {
+            return Err(PinMessageError::NonPinnableMessage.into());
+        }
+
+        let mut pinned = self.pinned_messages()?;
+        if pinned.iter().any(|id| id == message_id) {
+            return Ok(());
+        }
+        if pinned.len() >= MAX_PINNED_MESSAGES {
+            return Err(PinMessageError::TooManyPinnedMessages(MAX_PINNED_MESSAGES).into());
+        }
+        pinned.push(message_id.to_vec());
+
+        self.update_pinned_messages(&pinned).await
+    }
+
+    /// Unpins a message. Unpinning a message that isn't pinned is a no-op.
+    #[cfg_attr(any(test, feature = "test-utils"), tracing::instrument(level = "info", fields(who = %self.context.inbox_id()), skip(self)))]
+    #[cfg_attr(
+        not(any(test, feature = "test-utils")),
+        tracing::instrument(level = "trace", skip(self))
+    )]
+    pub async fn unpin_message(&self, message_id: &[u8]) -> Result<(), GroupError> {
+        let mut pinned = self.pinned_messages()?;
+        let before = pinned.len();
+        pinned.retain(|id| id != message_id);
+        if pinned.len() == before {
+            return Ok(());
+        }
+
+        self.update_pinned_messages(&pinned).await
+    }
+
+    /// The ids of the group's pinned messages, in the order they were pinned.
+    pub fn pinned_messages(&self) -> Result<Vec<Vec<u8>>, GroupError> {
+        Ok(self
+            .read_single_component::<PinnedMessagesComponent>()?
+            .map(|value| decode_pinned_message_ids(&value))
+            .unwrap_or_default())
+    }
+
+    async fn update_pinned_messages(&self, pinned: &[Vec<u8>]) -> Result<(), GroupError> {
+        self.ensure_not_paused().await?;
+
+        if self.metadata().await?.conversation_type == ConversationType::Dm {
+            return Err(MetadataPermissionsError::DmGroupMetadataForbidden.into());
+        }
+        let intent_data: Vec<u8> = UpdateMetadataIntentData::new_update_pinned_message_ids(
+            encode_pinned_message_ids(pinned),
+        )
+        .into();
+        let intent = QueueIntent::metadata_update()
+            .data(intent_data)
+            .queue(self)?;
+
+        let _ = self.sync_until_intent_resolved(intent.id).await?;
+        Ok(())
+    }
+
     /// Updates the image URL (square) of the group.
     #[cfg_attr(any(test, feature = "test-utils"), tracing::instrument(level = "info", fields(who = %self.context.inbox_id()), skip(self)))]
     #[cfg_attr(
@@ -3460,16 +3766,33 @@ pub(crate) fn build_mutable_permissions_extension(
 pub fn build_mutable_metadata_extension_default(
     creator_inbox_id: &str,
     opts: GroupMetadataOptions,
+) -> Result<Extension, GroupError> {
+    build_mutable_metadata_extension(creator_inbox_id, opts, None)
+}
+
+/// Builds the default mutable metadata of a new group, starting at the
+/// `min_version` floor when the group's policies need one.
+pub(crate) fn build_mutable_metadata_extension(
+    creator_inbox_id: &str,
+    opts: GroupMetadataOptions,
+    min_version: Option<&str>,
 ) -> Result<Extension, GroupError> {
     let mut commit_log_signer = None;
     if xmtp_configuration::ENABLE_COMMIT_LOG {
         // Optional TODO(rich): Plumb in provider and use traits in commit_log_key.rs to generate and store secret
         commit_log_signer = Some(xmtp_cryptography::rand::rand_secret::<ED25519_KEY_LENGTH>());
     }
-    let mutable_metadata: Vec<u8> =
-        GroupMutableMetadata::new_default(creator_inbox_id.to_string(), commit_log_signer, opts)
-            .try_into()
-            .map_err(MetadataPermissionsError::from)?;
+    let mut metadata =
+        GroupMutableMetadata::new_default(creator_inbox_id.to_string(), commit_log_signer, opts);
+    if let Some(min_version) = min_version {
+        metadata.attributes.insert(
+            MetadataField::MinimumSupportedProtocolVersion.to_string(),
+            min_version.to_string(),
+        );
+    }
+    let mutable_metadata: Vec<u8> = metadata
+        .try_into()
+        .map_err(MetadataPermissionsError::from)?;
     let unknown_gc_extension = UnknownExtension(mutable_metadata);
 
     Ok(Extension::Unknown(
@@ -3516,6 +3839,7 @@ pub fn build_extensions_for_metadata_update(
         existing_metadata.admin_list,
         existing_metadata.super_admin_list,
     )
+    .with_moderator_list(existing_metadata.moderator_list)
     .try_into()?;
     let unknown_gc_extension = UnknownExtension(new_mutable_metadata);
     let extension = Extension::Unknown(MUTABLE_METADATA_EXTENSION_ID, unknown_gc_extension);
@@ -3599,6 +3923,7 @@ pub fn build_extensions_for_admin_lists_update(
     let attributes = existing_metadata.attributes.clone();
     let mut admin_list = existing_metadata.admin_list;
     let mut super_admin_list = existing_metadata.super_admin_list;
+    let mut moderator_list = existing_metadata.moderator_list;
     match admin_lists_update.action_type {
         AdminListActionType::Add => {
             if !admin_list.contains(&admin_lists_update.inbox_id) {
@@ -3614,9 +3939,19 @@ pub fn build_extensions_for_admin_lists_update(
         AdminListActionType::RemoveSuper => {
             super_admin_list.retain(|x| x != &admin_lists_update.inbox_id)
         }
+        AdminListActionType::AddModerator => {
+            if !moderator_list.contains(&admin_lists_update.inbox_id) {
+                moderator_list.push(admin_lists_update.inbox_id);
+            }
+        }
+        AdminListActionType::RemoveModerator => {
+            moderator_list.retain(|x| x != &admin_lists_update.inbox_id)
+        }
     }
     let new_mutable_metadata: Vec<u8> =
-        GroupMutableMetadata::new(attributes, admin_list, super_admin_list).try_into()?;
+        GroupMutableMetadata::new(attributes, admin_list, super_admin_list)
+            .with_moderator_list(moderator_list)
+            .try_into()?;
     let unknown_gc_extension = UnknownExtension(new_mutable_metadata);
     let extension = Extension::Unknown(MUTABLE_METADATA_EXTENSION_ID, unknown_gc_extension);
     let mut extensions = group.extensions().clone();
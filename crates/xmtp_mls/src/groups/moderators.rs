@@ -0,0 +1,108 @@
+//! Moderators sit between members and admins: they can remove members and
+//! delete other members' messages when the group's policies allow it, but
+//! can't change metadata or permissions.
+//!
+//! Deleting other members' messages is opt-in. Moderators, and the admins above
+//! them, can only do it while the remove member policy is set to moderators or
+//! above. Super admins can always do it.
+//!
+//! The moderator list lives in the legacy group mutable metadata extension.
+//! Groups migrated to the AppData dictionary have no moderator component, so
+//! the list is always empty there and can't be updated. Clients older than
+//! `MODERATORS_MIN_PROTOCOL_VERSION` can't parse moderator policies, so the
+//! group's minimum supported protocol version is raised before they are used.
+
+use super::{
+    GroupError, MetadataPermissionsError, MlsGroup,
+    group_permissions::GroupMutablePermissions,
+    intents::{AdminListActionType, QueueIntent, UpdateAdminListIntentData},
+};
+use crate::context::XmtpSharedContext;
+use openmls::{
+    extensions::Extensions,
+    group::{GroupContext, MlsGroup as OpenMlsGroup},
+};
+use xmtp_db::group::ConversationType;
+use xmtp_mls_common::group_mutable_metadata::GroupMutableMetadata;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateModeratorListType {
+    Add,
+    Remove,
+}
+
+impl<Context> MlsGroup<Context>
+where
+    Context: XmtpSharedContext,
+{
+    /// Retrieves the moderator list of the group from the group's mutable metadata extension.
+    pub fn moderator_list(&self) -> Result<Vec<String>, GroupError> {
+        Ok(self.mutable_metadata()?.moderator_list)
+    }
+
+    /// Checks if the given inbox ID is a moderator of the group at the most recently synced epoch.
+    pub fn is_moderator(&self, inbox_id: String) -> Result<bool, GroupError> {
+        Ok(self.mutable_metadata()?.is_moderator(&inbox_id))
+    }
+
+    /// Checks if the given inbox ID can delete other members' messages as a moderator.
+    pub(crate) fn can_moderate_messages(&self, inbox_id: &String) -> Result<bool, GroupError> {
+        let group_context = self.load_group_context()?;
+        Ok(moderator_deletion_allowed(
+            group_context.extensions(),
+            inbox_id,
+        ))
+    }
+
+    /// Checks if the given inbox ID can delete other members' messages as a
+    /// moderator of `mls_group`, for callers already holding the group lock.
+    pub(crate) fn can_moderate_messages_without_lock(
+        &self,
+        mls_group: &OpenMlsGroup,
+        inbox_id: &String,
+    ) -> bool {
+        moderator_deletion_allowed(mls_group.extensions(), inbox_id)
+    }
+
+    /// Updates the moderator list of the group and syncs the changes to the network.
+    /// Only admins and super admins can add or remove moderators.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub async fn update_moderator_list(
+        &self,
+        action_type: UpdateModeratorListType,
+        inbox_id: String,
+    ) -> Result<(), GroupError> {
+        if self.metadata().await?.conversation_type == ConversationType::Dm {
+            return Err(MetadataPermissionsError::DmGroupMetadataForbidden.into());
+        }
+        if super::app_data::is_migrated_extensions(self.load_group_context()?.extensions()) {
+            return Err(MetadataPermissionsError::ModeratorsNotSupported.into());
+        }
+        self.ensure_group_min_version(xmtp_configuration::MODERATORS_MIN_PROTOCOL_VERSION)
+            .await?;
+        let intent_action_type = match action_type {
+            UpdateModeratorListType::Add => AdminListActionType::AddModerator,
+            UpdateModeratorListType::Remove => AdminListActionType::RemoveModerator,
+        };
+        let intent_data: Vec<u8> =
+            UpdateAdminListIntentData::new(intent_action_type, inbox_id).into();
+        let intent = QueueIntent::update_admin_list()
+            .data(intent_data)
+            .queue(self)?;
+
+        let _ = self.sync_until_intent_resolved(intent.id).await?;
+        Ok(())
+    }
+}
+
+fn moderator_deletion_allowed(extensions: &Extensions<GroupContext>, inbox_id: &String) -> bool {
+    // Migrated groups have neither moderators nor the legacy extensions
+    if super::app_data::is_migrated_extensions(extensions) {
+        return false;
+    }
+    let allowed = GroupMutablePermissions::try_from(extensions)
+        .is_ok_and(|permissions| permissions.policies.allows_moderator_deletion());
+    allowed
+        && GroupMutableMetadata::try_from(extensions)
+            .is_ok_and(|metadata| metadata.is_moderator(inbox_id) || metadata.is_admin(inbox_id))
+}
@@ -0,0 +1,46 @@
+use super::{GroupError, MlsGroup};
+use crate::context::XmtpSharedContext;
+use crate::subscriptions::{LocalEvents, SyncWorkerEvent};
+use crate::worker::device_sync::preference_sync::PreferenceUpdate;
+use xmtp_db::NotFound;
+use xmtp_db::prelude::*;
+use xmtp_db::read_cursor::StoredReadCursor;
+
+impl<Context> MlsGroup<Context>
+where
+    Context: XmtpSharedContext,
+{
+    /// Mark every message up to and including `up_to_message_id` as read.
+    ///
+    /// The read cursor only moves forward, so marking an older message is a
+    /// no-op. When the cursor moves it is synced to the user's other
+    /// installations through device sync.
+    pub fn mark_read(&self, up_to_message_id: &[u8]) -> Result<(), GroupError> {
+        let db = self.context.db();
+        let message = db
+            .get_group_message(up_to_message_id)?
+            .filter(|message| message.group_id == self.group_id)
+            .ok_or_else(|| NotFound::MessageById(up_to_message_id.to_vec()))?;
+
+        let cursor = StoredReadCursor::new(self.group_id, message.id, message.sent_at_ns);
+        if db.insert_newer_read_cursor(&cursor)? {
+            let updates = vec![PreferenceUpdate::ReadCursor(cursor)];
+            // Dispatch an update event so it can be synced across devices
+            let _ = self
+                .context
+                .worker_events()
+                .send(SyncWorkerEvent::SyncPreferences(updates.clone()));
+            let _ = self
+                .context
+                .local_events()
+                .send(LocalEvents::PreferencesChanged(updates));
+        }
+
+        Ok(())
+    }
+
+    /// The newest message marked as read in this conversation, if any.
+    pub fn read_cursor(&self) -> Result<Option<StoredReadCursor>, GroupError> {
+        Ok(self.context.db().get_read_cursor(&self.group_id)?)
+    }
+}
@@ -0,0 +1,125 @@
+use super::{GroupError, MlsGroup, send_message_opts::SendMessageOpts};
+use crate::context::XmtpSharedContext;
+use prost::Message;
+use xmtp_common::NS_IN_DAY;
+use xmtp_db::NotFound;
+use xmtp_db::group_message::{DeliveryStatus, StoredGroupMessage};
+use xmtp_db::prelude::QueryGroupMessage;
+use xmtp_db::tasks::{NewTask, QueryTasks, Task as DbTask};
+use xmtp_proto::xmtp::mls::database::{PublishScheduledMessage, Task as TaskProto, task::Task};
+
+/// How long after its publish time a scheduled message keeps being retried
+/// before the task worker gives up on it.
+const SCHEDULED_MESSAGE_PUBLISH_WINDOW_NS: i64 = 7 * NS_IN_DAY;
+
+/// A message that is stored locally and waiting for the task worker to
+/// publish it at `publish_at_ns`.
+#[derive(Debug, Clone)]
+pub struct ScheduledMessage {
+    pub publish_at_ns: i64,
+    pub message: StoredGroupMessage,
+}
+
+impl<Context> MlsGroup<Context>
+where
+    Context: XmtpSharedContext,
+{
+    /// Store a message now and have the task worker publish it once
+    /// `publish_at_ns` has passed. The task is persisted, so publishing still
+    /// happens if the app restarts in between. Until then the message is listed
+    /// with `Unpublished` delivery status.
+    ///
+    /// Returns the message ID.
+    pub fn send_message_at(
+        &self,
+        message: &[u8],
+        publish_at_ns: i64,
+        opts: SendMessageOpts,
+    ) -> Result<Vec<u8>, GroupError> {
+        if !self.is_active()? {
+            return Err(GroupError::GroupInactive);
+        }
+        let message_id = self.store_unpublished_message(message, opts)?;
+
+        let task = NewTask::builder()
+            .originating_message_sequence_id(0)
+            .originating_message_originator_id(0)
+            .next_attempt_at_ns(publish_at_ns)
+            .expires_at_ns(publish_at_ns.saturating_add(SCHEDULED_MESSAGE_PUBLISH_WINDOW_NS))
+            .max_attempts(i32::MAX) // lifetime bounded by expires_at_ns, not retries
+            .build(TaskProto {
+                task: Some(Task::PublishScheduledMessage(PublishScheduledMessage {
+                    group_id: self.group_id.to_vec(),
+                    message_id: message_id.clone(),
+                    publish_at_ns,
+                })),
+            })?;
+        self.context.db().create_or_ignore_task(task)?;
+        self.context.task_channels().wake();
+
+        Ok(message_id)
+    }
+
+    /// Messages in this group still waiting to be published, ordered by their
+    /// publish time.
+    pub fn scheduled_messages(&self) -> Result<Vec<ScheduledMessage>, GroupError> {
+        let conn = self.context.db();
+        let mut scheduled = Vec::new();
+        for (_, task) in self.scheduled_message_tasks()? {
+            let Some(message) = conn.get_group_message(&task.message_id)? else {
+                continue;
+            };
+            if message.delivery_status != DeliveryStatus::Unpublished {
+                continue;
+            }
+            scheduled.push(ScheduledMessage {
+                publish_at_ns: task.publish_at_ns,
+                message,
+            });
+        }
+        scheduled.sort_by_key(|s| s.publish_at_ns);
+
+        Ok(scheduled)
+    }
+
+    /// Cancel a scheduled message before it is published, removing both the
+    /// pending task and the locally stored message.
+    ///
+    /// Returns an error if there is no unpublished scheduled message with this ID.
+    pub fn cancel_scheduled_message(&self, message_id: &[u8]) -> Result<(), GroupError> {
+        let conn = self.context.db();
+        let not_found = || GroupError::NotFound(NotFound::MessageById(message_id.to_vec()));
+        let (row, _) = self
+            .scheduled_message_tasks()?
+            .into_iter()
+            .find(|(_, task)| task.message_id == message_id)
+            .ok_or_else(not_found)?;
+        let message = conn.get_group_message(message_id)?.ok_or_else(not_found)?;
+        if message.delivery_status != DeliveryStatus::Unpublished {
+            return Err(not_found());
+        }
+        conn.delete_task(row.id)?;
+        conn.delete_message_by_id(message_id)?;
+
+        Ok(())
+    }
+
+    fn scheduled_message_tasks(
+        &self,
+    ) -> Result<Vec<(DbTask, PublishScheduledMessage)>, GroupError> {
+        let tasks = self
+            .context
+            .db()
+            .get_tasks()?
+            .into_iter()
+            .filter_map(|row| match TaskProto::decode(row.data.as_slice()) {
+                Ok(TaskProto {
+                    task: Some(Task::PublishScheduledMessage(task)),
+                }) if task.group_id == self.group_id.as_slice() => Some((row, task)),
+                _ => None,
+            })
+            .collect();
+
+        Ok(tasks)
+    }
+}
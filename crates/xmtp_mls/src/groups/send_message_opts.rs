@@ -10,6 +10,25 @@ pub struct SendMessageOpts {
     /// preserving the historical (always-unique) behavior.
     #[builder(default)]
     pub idempotency_key: Option<String>,
+    /// Delete the message this long after it was sent, on the sender and on
+    /// receivers, regardless of the group's disappearing messages settings.
+    /// When the group also has disappearing messages enabled, the earlier
+    /// deadline wins.
+    #[builder(default)]
+    pub expire_after_ns: Option<i64>,
+    /// Delete the message on a receiver once they have viewed it. See
+    /// [`MlsGroup::mark_viewed`](crate::groups::MlsGroup::mark_viewed).
+    #[builder(default)]
+    pub view_once: bool,
+}
+
+impl SendMessageOpts {
+    /// Whether the message is deleted on receivers at some point, which only
+    /// clients at [`xmtp_configuration::MESSAGE_EXPIRY_MIN_PROTOCOL_VERSION`]
+    /// honor.
+    pub(crate) fn expires(&self) -> bool {
+        self.view_once || self.expire_after_ns.is_some_and(|ns| ns > 0)
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,9 @@
-use super::MlsGroup;
+use super::{MlsGroup, ephemeral::EphemeralMessage};
 use crate::{
     context::XmtpSharedContext,
     cursor_store::SqliteCursorStore,
     subscriptions::{
-        Result, SubscribeError,
+        Result, StreamMessages, SubscribeError,
         d14n_compat::{V3OrD14n, decode_group_message},
         process_message::{ProcessFutureFactory, ProcessMessageFuture},
         stream_messages::StreamGroupMessages,
@@ -141,6 +141,66 @@ where
             on_close,
         )
     }
+
+    /// Stream the ephemeral messages, such as typing indicators, sent to this group.
+    /// Only messages that arrive while the stream is open are delivered.
+    #[tracing::instrument(err, skip_all, fields(operation = "stream.stream_ephemeral"))]
+    pub async fn stream_ephemeral(
+        &self,
+    ) -> Result<impl Stream<Item = Result<EphemeralMessage>> + 'static>
+    where
+        Context: 'static,
+        Context::ApiClient: XmtpMlsStreams + 'static,
+        Context::Db: 'static,
+    {
+        stream_ephemeral(self.context.clone(), self.group_id).await
+    }
+
+    pub fn stream_ephemeral_with_callback(
+        context: Context,
+        group_id: GroupId,
+        callback: impl FnMut(Result<EphemeralMessage>) + MaybeSend + 'static,
+        on_close: impl FnOnce() + MaybeSend + 'static,
+    ) -> impl StreamHandle<StreamOutput = Result<()>>
+    where
+        Context: 'static,
+        Context::ApiClient: XmtpMlsStreams + 'static,
+        Context::Db: 'static,
+    {
+        let cancel = context.cancellation_token().clone();
+        spawn_watchdog_stream(
+            cancel,
+            "stream_ephemeral",
+            move || {
+                let context = context.clone();
+                async move { stream_ephemeral(context, group_id).await }
+            },
+            callback,
+            on_close,
+        )
+    }
+}
+
+/// Ephemeral messages surface as local events while the group's messages are
+/// processed, so the group's message stream is driven alongside them.
+async fn stream_ephemeral<Context>(
+    context: Context,
+    group_id: GroupId,
+) -> Result<impl Stream<Item = Result<EphemeralMessage>> + 'static>
+where
+    Context: XmtpSharedContext + 'static,
+    Context::ApiClient: XmtpMlsStreams + 'static,
+    Context::Db: 'static,
+{
+    // Subscribe before the network stream starts so nothing processed in between is missed
+    let ephemeral = context
+        .local_events()
+        .subscribe()
+        .stream_ephemeral_messages(group_id);
+    let messages = StreamGroupMessages::new_owned(context, vec![group_id]).await?;
+    // Stored messages are delivered by the regular message stream, only surface errors
+    let errors = messages.filter_map(|message| future::ready(message.err().map(Err)));
+    Ok(future_stream::select(ephemeral, errors))
 }
 
 // TODO: there's a better way than #[cfg]
@@ -335,6 +395,8 @@ pub(crate) mod tests {
                         inserted_at_ns: 0,
                         should_push: true,
                         idempotency_key: timestamp.to_string(),
+                        expire_after_ns: None,
+                        view_once: false,
                     }))
                 });
             mock_db
@@ -426,6 +488,8 @@ pub(crate) mod tests {
                         inserted_at_ns: 0,
                         should_push: true,
                         idempotency_key: timestamp.to_string(),
+                        expire_after_ns: None,
+                        view_once: false,
                     }))
                 });
             mock_db.expect_future_dependents().returning(|_| Ok(vec![]));
@@ -4,23 +4,36 @@ mod test_commit_log_local;
 mod test_commit_log_readd_requests;
 mod test_commit_log_remote;
 mod test_consent;
+mod test_conversation_settings;
 mod test_delete_message;
 mod test_dm;
+mod test_drafts;
+mod test_edit_message;
+mod test_ephemeral;
+mod test_external_invites;
 mod test_extract_readded_installations;
 #[cfg(not(target_arch = "wasm32"))]
 mod test_failed_installations;
 mod test_group_updated;
 mod test_libxmtp_version;
+mod test_membership_gates;
 mod test_message_disappearing_settings;
+mod test_message_expiry;
 #[cfg(not(target_arch = "wasm32"))]
 mod test_metadata_read_amplification;
+mod test_moderators;
 #[cfg(not(target_arch = "wasm32"))]
 mod test_network;
+mod test_pinned_messages;
 mod test_prepare_message_for_later_publish;
 mod test_proposals;
+mod test_read_state;
+mod test_scheduled_messages;
+mod test_search_messages;
 mod test_send_message_opts;
 mod test_starting_membership_sequence_id;
 mod test_validate_app_data_update;
+mod test_view_once;
 mod test_welcome_pointers;
 mod test_welcomes;
 
@@ -0,0 +1,84 @@
+use crate::context::XmtpSharedContext;
+use crate::subscriptions::LocalEvents;
+use crate::tester;
+use crate::worker::device_sync::preference_sync::PreferenceUpdate;
+use xmtp_db::group::GroupQueryArgs;
+
+/// Test that conversation settings are stored, filterable and announced locally
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_conversation_settings() {
+    tester!(alix);
+    let archived = alix.create_group(None, None)?;
+    let pinned = alix.create_group(None, None)?;
+    let plain = alix.create_group(None, None)?;
+    let mut events = alix.context.local_events().subscribe();
+
+    let defaults = archived.conversation_settings()?;
+    assert!(!defaults.archived && !defaults.pinned && !defaults.is_muted());
+
+    archived.set_archived(true)?;
+    pinned.set_pinned(true)?;
+    pinned.set_muted_until(Some(i64::MAX))?;
+
+    let LocalEvents::PreferencesChanged(updates) = events.recv().await? else {
+        panic!("expected a preference update");
+    };
+    assert!(matches!(
+        &updates[..],
+        [PreferenceUpdate::ConversationSettings(settings)]
+            if settings.group_id == archived.group_id && settings.archived
+    ));
+
+    let ids = |args: GroupQueryArgs| {
+        alix.list_conversations(args)
+            .unwrap()
+            .into_iter()
+            .map(|item| item.group.group_id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        ids(GroupQueryArgs {
+            archived: Some(true),
+            ..Default::default()
+        }),
+        vec![archived.group_id]
+    );
+    assert_eq!(
+        ids(GroupQueryArgs {
+            pinned: Some(true),
+            muted: Some(true),
+            ..Default::default()
+        }),
+        vec![pinned.group_id]
+    );
+    let mut inbox = ids(GroupQueryArgs {
+        archived: Some(false),
+        ..Default::default()
+    });
+    inbox.sort();
+    let mut expected = vec![pinned.group_id, plain.group_id];
+    expected.sort();
+    assert_eq!(inbox, expected);
+
+    let item = alix
+        .list_conversations(GroupQueryArgs {
+            pinned: Some(true),
+            ..Default::default()
+        })?
+        .remove(0);
+    assert!(item.is_pinned);
+    assert_eq!(item.muted_until_ns, Some(i64::MAX));
+
+    // Unmuting keeps the other settings
+    pinned.set_muted_until(None)?;
+    let settings = pinned.conversation_settings()?;
+    assert!(settings.pinned);
+    assert!(!settings.is_muted());
+    assert_eq!(
+        ids(GroupQueryArgs {
+            muted: Some(true),
+            ..Default::default()
+        }),
+        vec![]
+    );
+}
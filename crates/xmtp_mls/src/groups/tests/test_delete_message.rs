@@ -281,6 +281,8 @@ async fn test_true_out_of_order_deletion_by_sender() {
         inserted_at_ns: 0,
         should_push: false,
         idempotency_key: String::new(),
+        expire_after_ns: None,
+        view_once: false,
     };
     delete_message.store(&alix_conn)?;
 
@@ -293,6 +295,7 @@ async fn test_true_out_of_order_deletion_by_sender() {
         deleted_by_inbox_id: alix_inbox_id.clone(), // Sender deleting their own message
         is_super_admin_deletion: false,             // Regular user deletion
         deleted_at_ns: xmtp_common::time::now_ns(),
+        is_moderator_deletion: false,
     };
     deletion.store(&alix_conn)?;
 
@@ -328,6 +331,8 @@ async fn test_true_out_of_order_deletion_by_sender() {
         inserted_at_ns: 0,
         should_push: false,
         idempotency_key: String::new(),
+        expire_after_ns: None,
+        view_once: false,
     };
     message.store(&alix_conn)?;
 
@@ -403,6 +408,8 @@ async fn test_out_of_order_unauthorized_deletion_rejected() {
         inserted_at_ns: 0,
         should_push: false,
         idempotency_key: String::new(),
+        expire_after_ns: None,
+        view_once: false,
     };
     malicious_delete_message.store(&bo_conn)?;
 
@@ -415,6 +422,7 @@ async fn test_out_of_order_unauthorized_deletion_rejected() {
         deleted_by_inbox_id: bo_inbox_id.clone(), // Bo trying to delete
         is_super_admin_deletion: false,           // Bo is not super admin
         deleted_at_ns: xmtp_common::time::now_ns(),
+        is_moderator_deletion: false,
     };
     malicious_deletion.store(&bo_conn)?;
 
@@ -442,6 +450,8 @@ async fn test_out_of_order_unauthorized_deletion_rejected() {
         inserted_at_ns: 0,
         should_push: false,
         idempotency_key: String::new(),
+        expire_after_ns: None,
+        view_once: false,
     };
     message.store(&bo_conn)?;
 
@@ -1011,6 +1021,8 @@ async fn test_out_of_order_sender_deletion_shows_correct_deleted_by() {
         inserted_at_ns: 0,
         should_push: false,
         idempotency_key: String::new(),
+        expire_after_ns: None,
+        view_once: false,
     };
     delete_message.store(&alix_conn)?;
 
@@ -1022,6 +1034,7 @@ async fn test_out_of_order_sender_deletion_shows_correct_deleted_by() {
         deleted_by_inbox_id: alix_inbox_id.clone(),
         is_super_admin_deletion: true,
         deleted_at_ns: xmtp_common::time::now_ns(),
+        is_moderator_deletion: false,
     };
     deletion.store(&alix_conn)?;
 
@@ -1049,6 +1062,8 @@ async fn test_out_of_order_sender_deletion_shows_correct_deleted_by() {
         inserted_at_ns: 0,
         should_push: false,
         idempotency_key: String::new(),
+        expire_after_ns: None,
+        view_once: false,
     };
     original_message.store(&alix_conn)?;
 
@@ -0,0 +1,75 @@
+use crate::tester;
+use xmtp_content_types::{ContentCodec, text::TextCodec};
+use xmtp_db::group::GroupQueryArgs;
+use xmtp_db::group_intent::IntentKind;
+use xmtp_db::group_message::{GroupMessageKind, MsgQueryArgs};
+use xmtp_db::prelude::QueryGroupIntent;
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_set_get_and_clear_draft() {
+    tester!(alix);
+    let group = alix.create_group(None, None)?;
+    assert!(group.get_draft()?.is_none());
+
+    let first = TextCodec::encode("first".to_string())?;
+    group.set_draft(first, None)?;
+    let second = TextCodec::encode("second".to_string())?;
+    group.set_draft(second.clone(), Some(vec![1, 2, 3]))?;
+
+    let draft = group.get_draft()??;
+    assert_eq!(draft.content, second);
+    assert_eq!(draft.reply_to_message_id, Some(vec![1, 2, 3]));
+
+    group.clear_draft()?;
+    assert!(group.get_draft()?.is_none());
+    // Clearing again is a no-op
+    group.clear_draft()?;
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_draft_is_not_sent() {
+    tester!(alix);
+    tester!(bo);
+    let group = alix.create_group(None, None)?;
+    group.add_members(&[bo.inbox_id()]).await?;
+
+    group.set_draft(TextCodec::encode("unsent".to_string())?, None)?;
+
+    let application_messages = MsgQueryArgs {
+        kind: Some(GroupMessageKind::Application),
+        ..Default::default()
+    };
+    assert!(group.find_messages(&application_messages)?.is_empty());
+    let intents = alix
+        .context
+        .db()
+        .find_group_intents(group.group_id, None, None)?;
+    assert!(intents.iter().all(|i| i.kind != IntentKind::SendMessage));
+
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+    assert!(bo_group.find_messages(&application_messages)?.is_empty());
+    assert!(bo_group.get_draft()?.is_none());
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_list_conversations_reports_draft() {
+    tester!(alix);
+    let with_draft = alix.create_group(None, None)?;
+    let without_draft = alix.create_group(None, None)?;
+    with_draft.set_draft(TextCodec::encode("hi".to_string())?, None)?;
+
+    let has_draft = |group_id| -> bool {
+        alix.list_conversations(GroupQueryArgs::default())
+            .unwrap()
+            .into_iter()
+            .find(|item| item.group.group_id == group_id)
+            .unwrap()
+            .has_draft
+    };
+    assert!(has_draft(with_draft.group_id));
+    assert!(!has_draft(without_draft.group_id));
+
+    with_draft.clear_draft()?;
+    assert!(!has_draft(with_draft.group_id));
+}
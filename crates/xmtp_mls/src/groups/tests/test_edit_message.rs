@@ -0,0 +1,171 @@
+use crate::groups::GroupError;
+use crate::groups::error::EditMessageError;
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::messages::decoded_message::{DecodedMessage, MessageBody};
+use crate::tester;
+use xmtp_content_types::edit_message::EditMessageCodec;
+use xmtp_content_types::test_utils::TestContentGenerator;
+use xmtp_content_types::{ContentCodec, encoded_content_to_bytes, text::TextCodec};
+use xmtp_db::group_message::{GroupMessageKind, MsgQueryArgs};
+use xmtp_proto::xmtp::mls::message_contents::content_types::{EditMessage, ReactionAction};
+
+fn text(content: &str) -> Vec<u8> {
+    encoded_content_to_bytes(TextCodec::encode(content.to_string()).expect("text encodes"))
+}
+
+fn find<'a>(messages: &'a [DecodedMessage], id: &[u8]) -> &'a DecodedMessage {
+    messages
+        .iter()
+        .find(|m| m.metadata.id == id)
+        .expect("message is listed")
+}
+
+fn text_of(message: &DecodedMessage) -> &str {
+    match &message.content {
+        MessageBody::Text(text) => &text.content,
+        other => panic!("Expected text message, got {:?}", other),
+    }
+}
+
+/// Test that an edit by the sender replaces the content for every member
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_edit_message_by_sender() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+
+    let message_id = alix_group
+        .send_message(&text("See you at 5"), SendMessageOpts::default())
+        .await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+
+    let edit_id = alix_group.edit_message(
+        message_id.clone(),
+        TextCodec::encode("See you at 6".into())?,
+    )?;
+    assert!(!edit_id.is_empty());
+    alix_group.publish_messages().await?;
+    bo_group.sync().await?;
+
+    for group in [&alix_group, &bo_group] {
+        let messages = group.find_messages_v2(&MsgQueryArgs::default())?;
+        let edited = find(&messages, &message_id);
+        assert_eq!(text_of(edited), "See you at 6");
+        assert_eq!(edited.edit_history.len(), 2);
+        assert_eq!(edited.edit_history[1].message_id, edit_id);
+        assert!(edited.edited_at_ns().is_some());
+        // The edit itself is not listed
+        assert!(messages.iter().all(|m| m.metadata.id != edit_id));
+    }
+
+    let hits = bo_group.search_messages("6", &MsgQueryArgs::default())?;
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].message.id, message_id);
+}
+
+/// Test that members cannot edit messages they did not send
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_edit_message_authorization_failure() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+
+    let message_id = alix_group
+        .send_message(&text("Alix's message"), SendMessageOpts::default())
+        .await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+
+    let result = bo_group.edit_message(message_id.clone(), TextCodec::encode("Bo's now".into())?);
+    assert!(matches!(
+        result,
+        Err(GroupError::EditMessage(EditMessageError::NotAuthorized))
+    ));
+
+    // An edit sent without going through `edit_message` is ignored by receivers
+    let forged = EditMessageCodec::encode(EditMessage {
+        message_id: hex::encode(&message_id),
+        edited_content: Some(TextCodec::encode("Bo's now".into())?),
+    })?;
+    bo_group
+        .send_message(
+            &encoded_content_to_bytes(forged),
+            SendMessageOpts::default(),
+        )
+        .await?;
+    alix_group.sync().await?;
+
+    let messages = alix_group.find_messages_v2(&MsgQueryArgs::default())?;
+    let original = find(&messages, &message_id);
+    assert_eq!(text_of(original), "Alix's message");
+    assert!(!original.is_edited());
+}
+
+/// Test that only text can be edited, and only into text
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_cannot_edit_non_text_content() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+
+    let membership_message_id = alix_group
+        .find_messages(&MsgQueryArgs {
+            kind: Some(GroupMessageKind::MembershipChange),
+            ..Default::default()
+        })?
+        .remove(0)
+        .id;
+    let result = alix_group.edit_message(membership_message_id, TextCodec::encode("Hello".into())?);
+    assert!(matches!(
+        result,
+        Err(GroupError::EditMessage(
+            EditMessageError::NonEditableMessage
+        ))
+    ));
+
+    let message_id = alix_group
+        .send_message(&text("Hello"), SendMessageOpts::default())
+        .await?;
+    let reaction = TestContentGenerator::reaction_content(
+        &hex::encode(&message_id),
+        "👍",
+        ReactionAction::Added,
+    );
+    let result = alix_group.edit_message(message_id, reaction);
+    assert!(matches!(
+        result,
+        Err(GroupError::EditMessage(
+            EditMessageError::NonEditableContent(_)
+        ))
+    ));
+}
+
+/// Test that deleted messages can no longer be edited
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_cannot_edit_deleted_message() {
+    tester!(alix);
+    let alix_group = alix.create_group(None, None)?;
+
+    let message_id = alix_group
+        .send_message(&text("Oops"), SendMessageOpts::default())
+        .await?;
+    alix_group.delete_message(message_id.clone())?;
+
+    let result = alix_group.edit_message(message_id.clone(), TextCodec::encode("Fixed".into())?);
+    assert!(matches!(
+        result,
+        Err(GroupError::EditMessage(EditMessageError::MessageDeleted))
+    ));
+
+    let result = alix_group.edit_message(vec![1, 2, 3], TextCodec::encode("Fixed".into())?);
+    assert!(matches!(
+        result,
+        Err(GroupError::EditMessage(EditMessageError::MessageNotFound(
+            _
+        )))
+    ));
+}
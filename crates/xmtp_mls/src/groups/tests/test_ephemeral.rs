@@ -0,0 +1,105 @@
+use crate::context::XmtpSharedContext;
+use crate::groups::intents::{QueueIntent, SendMessageIntentData};
+use crate::subscriptions::LocalEvents;
+use crate::tester;
+use futures::StreamExt;
+use prost::Message;
+use std::time::Duration;
+use xmtp_common::time::now_ns;
+use xmtp_db::group_message::{GroupMessageKind, MsgQueryArgs};
+use xmtp_proto::xmtp::mls::message_contents::{
+    EphemeralContent, PlaintextEnvelope,
+    plaintext_envelope::{Content, V2, v2::MessageType},
+};
+
+fn application_messages() -> MsgQueryArgs {
+    MsgQueryArgs {
+        kind: Some(GroupMessageKind::Application),
+        ..Default::default()
+    }
+}
+
+/// Test that ephemeral messages reach live listeners but are never stored
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_ephemeral_messages_are_not_stored() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+    let mut events = bo.context.local_events().subscribe();
+
+    alix_group.send_ephemeral(b"typing").await?;
+    bo_group.sync().await?;
+
+    let message = loop {
+        if let LocalEvents::EphemeralMessage(message) = events.recv().await? {
+            break message;
+        }
+    };
+    assert_eq!(message.group_id, bo_group.group_id);
+    assert_eq!(message.sender_inbox_id, alix.inbox_id());
+    assert_eq!(message.content, b"typing");
+    assert!(!message.is_expired());
+
+    assert!(
+        alix_group
+            .find_messages(&application_messages())?
+            .is_empty()
+    );
+    assert!(bo_group.find_messages(&application_messages())?.is_empty());
+}
+
+/// Test that receivers drop ephemeral messages that expired before they were processed
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_expired_ephemeral_messages_are_dropped() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+    let mut events = bo.context.local_events().subscribe();
+
+    let envelope = PlaintextEnvelope {
+        content: Some(Content::V2(V2 {
+            idempotency_key: now_ns().to_string(),
+            message_type: Some(MessageType::Ephemeral(EphemeralContent {
+                content: b"typing".to_vec(),
+                expires_at_ns: now_ns() - 1,
+            })),
+        })),
+    };
+    QueueIntent::send_message()
+        .data(SendMessageIntentData::new(envelope.encode_to_vec()))
+        .queue(&alix_group)?;
+    alix_group.publish_messages().await?;
+    bo_group.sync().await?;
+
+    while let Ok(event) = events.try_recv() {
+        assert!(!matches!(event, LocalEvents::EphemeralMessage(_)));
+    }
+    assert!(bo_group.find_messages(&application_messages())?.is_empty());
+}
+
+#[xmtp_common::timeout(Duration::from_secs(10))]
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_stream_ephemeral() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+
+    let stream = bo_group.stream_ephemeral().await?;
+    futures::pin_mut!(stream);
+
+    // Regular messages are not part of the ephemeral stream
+    alix_group
+        .send_message(b"hello", Default::default())
+        .await?;
+    alix_group.send_ephemeral(b"typing").await?;
+
+    let message = stream.next().await??;
+    assert_eq!(message.content, b"typing");
+    assert_eq!(message.sender_inbox_id, alix.inbox_id());
+}
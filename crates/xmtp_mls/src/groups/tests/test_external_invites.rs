@@ -0,0 +1,413 @@
+use crate::context::XmtpSharedContext;
+use crate::groups::app_data::process_message_with_app_data;
+use crate::groups::intents::{
+    PermissionPolicyOption, PermissionUpdateType, QueueIntent, UpdateMetadataIntentData,
+};
+use crate::groups::mls_sync::GroupMessageProcessingError;
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::groups::validated_commit::{CommitValidationError, ValidatedCommit};
+use crate::groups::{
+    GroupError, InviteError, MlsGroup, PreconfiguredPolicies, UpdateAdminListType,
+};
+use crate::tester;
+use openmls::group::{MIXED_CIPHERTEXT_WIRE_FORMAT_POLICY, WireFormatPolicy};
+use openmls::prelude::{MlsMessageIn, ProcessedMessageContent};
+use openmls_traits::OpenMlsProvider;
+use tls_codec::Deserialize;
+use xmtp_common::time::now_ns;
+use xmtp_configuration::EXTERNAL_INVITES_MIN_PROTOCOL_VERSION;
+use xmtp_content_types::{ContentCodec, encoded_content_to_bytes, text::TextCodec};
+use xmtp_db::group_message::MsgQueryArgs;
+use xmtp_mls_common::group_mutable_metadata::MetadataField;
+use xmtp_mls_common::invite::{
+    group_invites::{GroupInvite, encode_group_invites},
+    payload,
+};
+use xmtp_proto::xmtp::mls::message_contents::ExternalCommitPolicyV1;
+
+const HOUR_NS: i64 = 3_600_000_000_000;
+
+fn text(content: &str) -> Vec<u8> {
+    encoded_content_to_bytes(TextCodec::encode(content.to_string()).expect("text encodes"))
+}
+
+/// Builds `joiner`'s external commit against a GroupInfo exported at the group's
+/// current epoch, and validates it as a member of `group` would at `commit_time_ns`.
+/// Neither side merges the commit.
+async fn validate_external_join<Context: XmtpSharedContext>(
+    group: &MlsGroup<Context>,
+    joiner: &Context,
+    external_group_id: &[u8],
+    commit_time_ns: u64,
+) -> Result<ValidatedCommit, CommitValidationError> {
+    let (group_info, _, _) = group.export_group_info().expect("group info exports");
+    let (joiner_group, commit) =
+        MlsGroup::<Context>::build_external_join(joiner, &group_info, external_group_id)
+            .expect("external commit builds");
+    joiner_group
+        .delete(joiner.mls_provider().storage())
+        .expect("pending join is discarded");
+    let message = MlsMessageIn::tls_deserialize(&mut commit.as_slice())
+        .expect("commit deserializes")
+        .try_into_protocol_message()
+        .expect("commit is a protocol message");
+
+    group
+        .load_mls_group_with_lock_async(async |mut mls_group| {
+            let provider = group.context.mls_provider();
+            let processed = process_message_with_app_data(
+                &mut mls_group,
+                &provider,
+                message,
+                group.context.version_info().pkg_semver(),
+            )
+            .map_err(GroupMessageProcessingError::from)?;
+            let aad = processed.aad().to_vec();
+            let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+                processed.into_content()
+            else {
+                panic!("external commit stages");
+            };
+            Ok::<_, GroupError>(
+                ValidatedCommit::from_external_commit(
+                    &group.context,
+                    &staged_commit,
+                    &aad,
+                    &mls_group,
+                    commit_time_ns,
+                )
+                .await,
+            )
+        })
+        .await
+        .expect("external commit processes")
+}
+
+fn is_not_allowed(result: Result<ValidatedCommit, CommitValidationError>) -> bool {
+    matches!(
+        result,
+        Err(CommitValidationError::ExternalCommitNotAllowed(_))
+    )
+}
+
+/// Test that invites can be listed and revoked by every member
+#[xmtp_common::test(unwrap_try = true)]
+#[cfg_attr(target_arch = "wasm32", ignore)]
+async fn test_create_and_revoke_invite() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    assert!(alix_group.invites()?.is_empty());
+
+    let invite = alix_group.create_invite(None).await?;
+    let invite = payload::validate(&invite)?.clone();
+    let invites = alix_group.invites()?;
+    assert_eq!(invites.len(), 1);
+    assert_eq!(invites[0].external_group_id(), invite.external_group_id);
+    assert_eq!(invites[0].created_by_inbox_id, alix.inbox_id());
+    assert_eq!(invites[0].group_info_epoch, alix_group.epoch().await?);
+
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+    assert_eq!(bo_group.invites()?, invites);
+
+    alix_group.revoke_invite(&invite.external_group_id).await?;
+    assert!(alix_group.invites()?.is_empty());
+    let result = alix_group.revoke_invite(&invite.external_group_id).await;
+    assert!(matches!(
+        result,
+        Err(GroupError::Invite(InviteError::InviteNotFound))
+    ));
+
+    let result = alix_group.create_invite(Some(now_ns() - 1)).await;
+    assert!(matches!(
+        result,
+        Err(GroupError::Invite(InviteError::InvalidExpiry))
+    ));
+}
+
+/// Test that creating an invite raises the group's minimum supported protocol
+/// version, and that only then do members accept public messages
+#[xmtp_common::test(unwrap_try = true)]
+#[cfg_attr(target_arch = "wasm32", ignore)]
+async fn test_create_invite_raises_min_version() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    let wire_format_policy = |group: &MlsGroup<_>| {
+        group.load_mls_group_with_lock(group.context.mls_storage(), |mls_group| {
+            Ok::<_, GroupError>(mls_group.configuration().wire_format_policy())
+        })
+    };
+    assert_eq!(wire_format_policy(&bo_group)?, WireFormatPolicy::default());
+
+    alix_group.create_invite(None).await?;
+    assert_eq!(
+        alix_group
+            .mutable_metadata()?
+            .attributes
+            .get(MetadataField::MinimumSupportedProtocolVersion.as_str())
+            .map(String::as_str),
+        Some(EXTERNAL_INVITES_MIN_PROTOCOL_VERSION)
+    );
+
+    bo_group.sync().await?;
+    assert_eq!(
+        wire_format_policy(&bo_group)?,
+        MIXED_CIPHERTEXT_WIRE_FORMAT_POLICY
+    );
+}
+
+/// Test that a commit writing an invite is rejected while the group's minimum
+/// supported protocol version still admits clients that can't process joins
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_invites_require_min_version() {
+    tester!(alix);
+    let group = alix.create_group(None, None)?;
+
+    // Write an invite without the floor raise that `create_invite` does first
+    let invite = GroupInvite {
+        policy: ExternalCommitPolicyV1 {
+            allow_external_commit: true,
+            expires_at_ns: 0,
+            expire_in_ns: 0,
+            symmetric_key: vec![1; 32],
+            external_group_id: vec![1; 16],
+        },
+        created_by_inbox_id: alix.inbox_id().to_string(),
+        group_info_epoch: group.epoch().await? + 1,
+    };
+    let intent_data: Vec<u8> =
+        UpdateMetadataIntentData::new_update_external_invites(encode_group_invites(&[invite]))
+            .into();
+    let intent = QueueIntent::metadata_update()
+        .data(intent_data)
+        .queue(&group)?;
+    let result = group.sync_until_intent_resolved(intent.id).await;
+    let Err(GroupError::Sync(summary)) = result else {
+        panic!("expected the invite commit to fail validation");
+    };
+    assert!(summary.process.errored.iter().any(|(_, e)| matches!(
+        e,
+        GroupMessageProcessingError::CommitValidation(CommitValidationError::MinVersionRequired {
+            required
+        }) if required == EXTERNAL_INVITES_MIN_PROTOCOL_VERSION
+    )));
+}
+
+/// Test that an admin can't write an invite naming a super admin as its creator to
+/// get joiners past a super admin only add member policy
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_invite_with_forged_creator_is_rejected() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    alix_group
+        .update_permission_policy(
+            PermissionUpdateType::AddMember,
+            PermissionPolicyOption::SuperAdminOnly,
+            None,
+        )
+        .await?;
+    alix_group
+        .update_admin_list(UpdateAdminListType::Add, bo.inbox_id().to_string())
+        .await?;
+    alix_group
+        .ensure_group_min_version(EXTERNAL_INVITES_MIN_PROTOCOL_VERSION)
+        .await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+
+    let forged = GroupInvite {
+        policy: ExternalCommitPolicyV1 {
+            allow_external_commit: true,
+            expires_at_ns: 0,
+            expire_in_ns: 0,
+            symmetric_key: vec![1; 32],
+            external_group_id: vec![1; 16],
+        },
+        created_by_inbox_id: alix.inbox_id().to_string(),
+        group_info_epoch: bo_group.epoch().await? + 1,
+    };
+    let intent_data: Vec<u8> =
+        UpdateMetadataIntentData::new_update_external_invites(encode_group_invites(&[forged]))
+            .into();
+    let intent = QueueIntent::metadata_update()
+        .data(intent_data)
+        .queue(&bo_group)?;
+    let result = bo_group.sync_until_intent_resolved(intent.id).await;
+    let Err(GroupError::Sync(summary)) = result else {
+        panic!("expected the forged invite commit to fail validation");
+    };
+    assert!(summary.process.errored.iter().any(|(_, e)| matches!(
+        e,
+        GroupMessageProcessingError::CommitValidation(CommitValidationError::InvalidInvite)
+    )));
+
+    alix_group.sync().await?;
+    assert!(alix_group.invites()?.is_empty());
+}
+
+/// Test that an invitee can join in a single commit, is listed as a member and can
+/// message the group
+#[xmtp_common::test(unwrap_try = true)]
+#[cfg_attr(target_arch = "wasm32", ignore)]
+async fn test_join_with_invite() {
+    tester!(alix);
+    tester!(bo);
+    tester!(caro);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let invite = alix_group.create_invite(None).await?;
+    let epoch = alix_group.epoch().await?;
+
+    let caro_group = caro.join_with_invite(&invite).await?;
+    assert_eq!(caro_group.group_id, alix_group.group_id);
+    assert_eq!(caro_group.epoch().await?, epoch + 1);
+
+    alix_group.sync().await?;
+    assert_eq!(alix_group.epoch().await?, epoch + 1);
+    let members = alix_group.members().await?;
+    assert!(members.iter().any(|m| m.inbox_id == caro.inbox_id()));
+
+    caro_group
+        .send_message(&text("hi from caro"), SendMessageOpts::default())
+        .await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+    let messages = bo_group.find_messages(&MsgQueryArgs::default())?;
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.sender_inbox_id == caro.inbox_id())
+    );
+}
+
+/// Test that a revoked invite no longer admits joiners
+#[xmtp_common::test(unwrap_try = true)]
+#[cfg_attr(target_arch = "wasm32", ignore)]
+async fn test_join_with_revoked_invite_fails() {
+    tester!(alix);
+    tester!(caro);
+    let alix_group = alix.create_group(None, None)?;
+
+    let revoked = alix_group.create_invite(None).await?;
+    let revoked_id = payload::validate(&revoked)?.external_group_id.clone();
+    alix_group.revoke_invite(&revoked_id).await?;
+    let result = caro.join_with_invite(&revoked).await;
+    assert!(matches!(
+        result,
+        Err(GroupError::Invite(InviteError::InviteNotFound))
+    ));
+
+    alix_group.sync().await?;
+    let members = alix_group.members().await?;
+    assert!(!members.iter().any(|m| m.inbox_id == caro.inbox_id()));
+}
+
+/// Test that an invite keeps admitting joiners after the group moves on, whoever
+/// committed, since every committer publishes the new epoch's GroupInfo
+#[xmtp_common::test(unwrap_try = true)]
+#[cfg_attr(target_arch = "wasm32", ignore)]
+async fn test_invite_survives_later_commits() {
+    tester!(alix);
+    tester!(bo);
+    tester!(caro);
+    tester!(devon);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let invite = alix_group.create_invite(None).await?;
+    let invite_id = payload::validate(&invite)?.external_group_id.clone();
+
+    alix_group.update_group_name("renamed".to_string()).await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+    bo_group
+        .update_group_description("described".to_string())
+        .await?;
+    assert_eq!(bo_group.invites()?[0].external_group_id(), invite_id);
+
+    let caro_group = caro.join_with_invite(&invite).await?;
+    assert_eq!(caro_group.epoch().await?, bo_group.epoch().await? + 1);
+    // The joiner publishes the GroupInfo for its own commit too
+    let devon_group = devon.join_with_invite(&invite).await?;
+    assert_eq!(devon_group.epoch().await?, caro_group.epoch().await? + 1);
+
+    alix_group.sync().await?;
+    let members = alix_group.members().await?;
+    assert!(members.iter().any(|m| m.inbox_id == caro.inbox_id()));
+    assert!(members.iter().any(|m| m.inbox_id == devon.inbox_id()));
+}
+
+/// Test that a revoked invite is rejected even when the joiner has a GroupInfo
+/// for the group's current epoch
+#[xmtp_common::test(unwrap_try = true)]
+#[cfg_attr(target_arch = "wasm32", ignore)]
+async fn test_external_commit_rejects_revoked_invite() {
+    tester!(alix);
+    tester!(caro);
+    let alix_group = alix.create_group(None, None)?;
+
+    let revoked = alix_group.create_invite(None).await?;
+    let revoked_id = payload::validate(&revoked)?.external_group_id.clone();
+    alix_group.revoke_invite(&revoked_id).await?;
+    let active = alix_group.create_invite(None).await?;
+    let active_id = payload::validate(&active)?.external_group_id.clone();
+
+    let now = now_ns() as u64;
+    let result = validate_external_join(&alix_group, &caro.context, &revoked_id, now).await;
+    assert!(is_not_allowed(result));
+
+    let validated = validate_external_join(&alix_group, &caro.context, &active_id, now).await?;
+    assert_eq!(validated.actor_inbox_id(), caro.inbox_id());
+    assert_eq!(validated.added_inboxes.len(), 1);
+    assert_eq!(validated.added_inboxes[0].inbox_id, caro.inbox_id());
+}
+
+/// Test that an invite's expiry is checked against the commit time, at the group's
+/// current epoch
+#[xmtp_common::test(unwrap_try = true)]
+#[cfg_attr(target_arch = "wasm32", ignore)]
+async fn test_external_commit_respects_invite_expiry() {
+    tester!(alix);
+    tester!(caro);
+    let alix_group = alix.create_group(None, None)?;
+
+    let expires_at_ns = now_ns() + HOUR_NS;
+    let invite = alix_group.create_invite(Some(expires_at_ns)).await?;
+    let invite_id = payload::validate(&invite)?.external_group_id.clone();
+    let expires_at_ns = expires_at_ns as u64;
+
+    validate_external_join(&alix_group, &caro.context, &invite_id, expires_at_ns - 1).await?;
+    let result =
+        validate_external_join(&alix_group, &caro.context, &invite_id, expires_at_ns).await;
+    assert!(is_not_allowed(result));
+}
+
+/// Test that the external invites policy controls who may create invites
+#[xmtp_common::test(unwrap_try = true)]
+#[cfg_attr(target_arch = "wasm32", ignore)]
+async fn test_create_invite_permissions() {
+    tester!(alix);
+    tester!(bo);
+    let policy_set = Some(PreconfiguredPolicies::Default.to_policy_set());
+    let alix_group = alix.create_group(policy_set, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+
+    assert!(bo_group.create_invite(None).await.is_err());
+    assert!(bo_group.invites()?.is_empty());
+
+    let dm = alix
+        .find_or_create_dm(bo.inbox_id().to_string(), None)
+        .await?;
+    let result = dm.create_invite(None).await;
+    assert!(matches!(
+        result,
+        Err(GroupError::Invite(InviteError::Unsupported))
+    ));
+}
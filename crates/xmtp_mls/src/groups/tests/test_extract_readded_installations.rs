@@ -9,6 +9,7 @@ fn create_test_actor(is_super_admin: bool) -> CommitParticipant {
         is_creator: false,
         is_admin: false,
         is_super_admin,
+        is_moderator: false,
     }
 }
 
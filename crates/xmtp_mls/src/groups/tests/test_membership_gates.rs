@@ -0,0 +1,167 @@
+use crate::groups::{
+    EnableProposalsOptions, GroupError, MetadataPermissionsError,
+    app_data::policy::add_member_policy_registry_update,
+    group_permissions::{GroupMutablePermissionsError, MembershipPolicies, PolicySet},
+    intents::{AppDataUpdateIntentData, QueueIntent},
+    mls_sync::GroupMessageProcessingError,
+    validated_commit::CommitValidationError,
+};
+use crate::{client::ClientError, tester};
+use xmtp_configuration::MEMBERSHIP_GATES_MIN_PROTOCOL_VERSION;
+use xmtp_mls_common::app_data::component_id::ComponentId;
+use xmtp_mls_common::group_mutable_metadata::MetadataField;
+use xmtp_proto::xmtp::identity::associations::IdentifierKind;
+
+fn gated_policy_set(add_member_policy: MembershipPolicies) -> PolicySet {
+    PolicySet {
+        add_member_policy,
+        ..PolicySet::default()
+    }
+}
+
+fn is_commit_validation_error<T>(
+    result: Result<T, GroupError>,
+    predicate: impl Fn(&CommitValidationError) -> bool,
+) -> bool {
+    let Err(GroupError::Sync(summary)) = result else {
+        return false;
+    };
+    summary.process.errored.iter().any(
+        |(_, e)| matches!(e, GroupMessageProcessingError::CommitValidation(err) if predicate(err)),
+    )
+}
+
+fn is_requirement_not_met<T>(result: Result<T, GroupError>, inbox_id: &str) -> bool {
+    is_commit_validation_error(result, |err| {
+        matches!(
+            err,
+            CommitValidationError::MembershipRequirementNotMet(rejected) if rejected == inbox_id
+        )
+    })
+}
+
+fn requires_migration(err: &GroupError) -> bool {
+    matches!(
+        err,
+        GroupError::MetadataPermissionsError(MetadataPermissionsError::Permissions(
+            GroupMutablePermissionsError::InboxGatesRequireMigration
+        ))
+    )
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_membership_gates_require_migration() {
+    tester!(alix);
+    let passkey = MembershipPolicies::allow_if_inbox_has_identifier(IdentifierKind::Passkey, None);
+
+    // The legacy permissions extension can't carry gates
+    let result = alix.create_group(Some(gated_policy_set(passkey.clone())), None);
+    assert!(matches!(result, Err(ClientError::Group(err)) if requires_migration(&err)));
+
+    let group = alix.create_group(None, None)?;
+    let result = group.update_add_member_policy(passkey).await;
+    assert!(matches!(result, Err(err) if requires_migration(&err)));
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_identifier_requirement_gates_added_inboxes() {
+    tester!(alix);
+    tester!(bo);
+    tester!(caro);
+
+    let passkey_group = alix.create_group(None, None)?;
+    passkey_group
+        .enable_proposals(EnableProposalsOptions::test_default())
+        .await?;
+    passkey_group
+        .update_add_member_policy(MembershipPolicies::allow_if_inbox_has_identifier(
+            IdentifierKind::Passkey,
+            None,
+        ))
+        .await?;
+    assert_eq!(
+        passkey_group
+            .mutable_metadata()?
+            .attributes
+            .get(MetadataField::MinimumSupportedProtocolVersion.as_str())
+            .map(String::as_str),
+        Some(MEMBERSHIP_GATES_MIN_PROTOCOL_VERSION)
+    );
+
+    // Testers only hold wallet identifiers
+    let result = passkey_group.add_members(&[bo.inbox_id()]).await;
+    assert!(is_requirement_not_met(result, bo.inbox_id()));
+    assert_eq!(passkey_group.members().await?.len(), 1);
+
+    let wallet_group = alix.create_group(None, None)?;
+    wallet_group
+        .enable_proposals(EnableProposalsOptions::test_default())
+        .await?;
+    wallet_group
+        .update_add_member_policy(MembershipPolicies::and(vec![
+            MembershipPolicies::allow_if_actor_admin(),
+            MembershipPolicies::allow_if_inbox_has_identifier(IdentifierKind::Ethereum, None),
+        ]))
+        .await?;
+    wallet_group.add_members(&[bo.inbox_id()]).await?;
+
+    // Bo meets the requirement but isn't an admin, so caro is turned away
+    // for a different reason
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+    let result = bo_group.add_members(&[caro.inbox_id()]).await;
+    assert!(result.is_err());
+    assert!(!is_requirement_not_met(result, caro.inbox_id()));
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_allowlist_gates_added_inboxes() {
+    tester!(alix);
+    tester!(bo);
+
+    let group = alix.create_group(None, None)?;
+    group
+        .enable_proposals(EnableProposalsOptions::test_default())
+        .await?;
+    group
+        .update_add_member_policy(MembershipPolicies::any(vec![
+            MembershipPolicies::allow_if_inbox_in_allowlist(ComponentId::new(0xC001)),
+            MembershipPolicies::allow_if_inbox_has_identifier(
+                IdentifierKind::Passkey,
+                Some("xmtp.org".to_string()),
+            ),
+        ]))
+        .await?;
+
+    // Nothing has been written to the allowlist component
+    let result = group.add_members(&[bo.inbox_id()]).await;
+    assert!(is_requirement_not_met(result, bo.inbox_id()));
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_membership_gates_require_min_version() {
+    tester!(alix);
+
+    let group = alix.create_group(None, None)?;
+    group
+        .enable_proposals(EnableProposalsOptions::test_default())
+        .await?;
+
+    // Write the gated policy without the floor raise that
+    // `update_add_member_policy` does first
+    let policy = MembershipPolicies::allow_if_inbox_has_identifier(IdentifierKind::Passkey, None);
+    let payload = group.load_mls_group_with_lock(alix.context.mls_storage(), |mls_group| {
+        add_member_policy_registry_update(&mls_group, &policy).map_err(GroupError::from)
+    })?;
+    let intent = QueueIntent::app_data_update()
+        .data(AppDataUpdateIntentData::new(
+            ComponentId::COMPONENT_REGISTRY.as_u16(),
+            payload,
+        ))
+        .queue(&group)?;
+    let result = group.sync_until_intent_resolved(intent.id).await;
+    assert!(is_commit_validation_error(result, |err| matches!(
+        err,
+        CommitValidationError::MinVersionRequired { required }
+            if required == MEMBERSHIP_GATES_MIN_PROTOCOL_VERSION
+    )));
+}
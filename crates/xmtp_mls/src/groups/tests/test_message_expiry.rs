@@ -0,0 +1,168 @@
+use crate::context::XmtpSharedContext;
+use crate::groups::GroupError;
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::tester;
+use xmtp_configuration::MESSAGE_EXPIRY_MIN_PROTOCOL_VERSION;
+use xmtp_db::prelude::*;
+use xmtp_mls_common::group_mutable_metadata::{MessageDisappearingSettings, MetadataField};
+
+const HOUR_NS: i64 = 60 * 60 * 1_000_000_000;
+
+/// Test that the sender-requested lifetime of a message sets its deadline on
+/// both the sender and the receivers, without any group disappearing settings
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_per_message_expiry() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+
+    let message_id = alix_group
+        .send_message(
+            b"expiring",
+            SendMessageOpts {
+                expire_after_ns: Some(HOUR_NS),
+                ..Default::default()
+            },
+        )
+        .await?;
+    bo_group.sync().await?;
+
+    let sent = alix.context.db().get_group_message(&message_id)??;
+    assert_eq!(sent.expire_after_ns, Some(HOUR_NS));
+    assert_eq!(sent.expire_at_ns, Some(sent.sent_at_ns + HOUR_NS));
+
+    let received = bo.context.db().get_group_message(&message_id)??;
+    assert_eq!(received.expire_after_ns, Some(HOUR_NS));
+    assert_eq!(received.expire_at_ns, Some(received.sent_at_ns + HOUR_NS));
+
+    // Messages without a lifetime never expire in a group without disappearing settings
+    let regular_id = alix_group
+        .send_message(b"regular", Default::default())
+        .await?;
+    bo_group.sync().await?;
+    assert_eq!(
+        bo.context
+            .db()
+            .get_group_message(&regular_id)??
+            .expire_at_ns,
+        None
+    );
+}
+
+/// Test that messages are deleted once their sender-requested lifetime has passed
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_per_message_expiry_deletes_expired_messages() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+
+    let expired_id = alix_group
+        .send_message(
+            b"gone",
+            SendMessageOpts {
+                expire_after_ns: Some(1),
+                ..Default::default()
+            },
+        )
+        .await?;
+    let kept_id = alix_group.send_message(b"kept", Default::default()).await?;
+    bo_group.sync().await?;
+
+    // The disappearing messages worker may get to it first
+    for client in [&alix, &bo] {
+        client.context.db().delete_expired_messages()?;
+        assert!(
+            client
+                .context
+                .db()
+                .get_group_message(&expired_id)?
+                .is_none()
+        );
+        assert!(client.context.db().get_group_message(&kept_id)?.is_some());
+    }
+}
+
+/// Test that the earlier of the group and the per-message deadline wins
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_per_message_expiry_with_group_disappearing_settings() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    alix_group
+        .update_conversation_message_disappearing_settings(MessageDisappearingSettings::new(
+            1,
+            2 * HOUR_NS,
+        ))
+        .await?;
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+    bo_group.sync().await?;
+
+    let shorter_id = alix_group
+        .send_message(
+            b"shorter",
+            SendMessageOpts {
+                expire_after_ns: Some(HOUR_NS),
+                ..Default::default()
+            },
+        )
+        .await?;
+    let longer_id = alix_group
+        .send_message(
+            b"longer",
+            SendMessageOpts {
+                expire_after_ns: Some(4 * HOUR_NS),
+                ..Default::default()
+            },
+        )
+        .await?;
+    bo_group.sync().await?;
+
+    let shorter = bo.context.db().get_group_message(&shorter_id)??;
+    assert_eq!(shorter.expire_at_ns, Some(shorter.sent_at_ns + HOUR_NS));
+
+    let longer = bo.context.db().get_group_message(&longer_id)??;
+    let expire_at_ns = longer.expire_at_ns?;
+    assert!(expire_at_ns < longer.sent_at_ns + 4 * HOUR_NS);
+}
+
+/// Test that expiring messages are only sent once the group's floor keeps older
+/// clients, which would ignore the lifetime, from receiving them
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_per_message_expiry_raises_group_min_version() {
+    tester!(alix);
+    let alix_group = alix.create_group(None, None)?;
+    let min_version = |group: &crate::groups::MlsGroup<_>| {
+        group
+            .mutable_metadata()
+            .ok()?
+            .attributes
+            .get(MetadataField::MinimumSupportedProtocolVersion.as_str())
+            .cloned()
+    };
+
+    let opts = SendMessageOpts {
+        view_once: true,
+        ..Default::default()
+    };
+    let result = alix_group.send_message_optimistic(b"too early", opts.clone());
+    assert!(matches!(
+        result,
+        Err(GroupError::MessageExpiryUnsupported { .. })
+    ));
+    assert_ne!(
+        min_version(&alix_group).as_deref(),
+        Some(MESSAGE_EXPIRY_MIN_PROTOCOL_VERSION)
+    );
+
+    alix_group.send_message(b"view once", opts.clone()).await?;
+    assert_eq!(
+        min_version(&alix_group).as_deref(),
+        Some(MESSAGE_EXPIRY_MIN_PROTOCOL_VERSION)
+    );
+    alix_group.send_message_optimistic(b"now allowed", opts)?;
+}
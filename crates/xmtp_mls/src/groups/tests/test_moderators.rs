@@ -0,0 +1,198 @@
+use crate::groups::UpdateAdminListType;
+use crate::groups::intents::{PermissionPolicyOption, PermissionUpdateType};
+use crate::groups::moderators::UpdateModeratorListType;
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::tester;
+use xmtp_configuration::MODERATORS_MIN_PROTOCOL_VERSION;
+use xmtp_content_types::{ContentCodec, text::TextCodec};
+use xmtp_db::message_deletion::QueryMessageDeletion;
+use xmtp_mls_common::group_mutable_metadata::MetadataField;
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_moderator_can_remove_members() {
+    tester!(alix);
+    tester!(bo);
+    tester!(caro);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group
+        .add_members(&[bo.inbox_id(), caro.inbox_id()])
+        .await?;
+    alix_group
+        .update_permission_policy(
+            PermissionUpdateType::RemoveMember,
+            PermissionPolicyOption::ModeratorOrAbove,
+            None,
+        )
+        .await?;
+
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+    bo_group.sync().await?;
+    assert!(bo_group.remove_members(&[caro.inbox_id()]).await.is_err());
+
+    alix_group
+        .update_moderator_list(UpdateModeratorListType::Add, bo.inbox_id().to_string())
+        .await?;
+    assert!(alix_group.is_moderator(bo.inbox_id().to_string())?);
+    assert_eq!(
+        alix_group.moderator_list()?,
+        vec![bo.inbox_id().to_string()]
+    );
+
+    bo_group.sync().await?;
+    assert!(bo_group.is_moderator(bo.inbox_id().to_string())?);
+    // Moderators can't remove admins
+    assert!(bo_group.remove_members(&[alix.inbox_id()]).await.is_err());
+    bo_group.remove_members(&[caro.inbox_id()]).await?;
+
+    alix_group.sync().await?;
+    let members = alix_group.members().await?;
+    assert!(!members.iter().any(|m| m.inbox_id == caro.inbox_id()));
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_moderator_cannot_change_metadata_or_moderators() {
+    tester!(alix);
+    tester!(bo);
+    tester!(caro);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group
+        .add_members(&[bo.inbox_id(), caro.inbox_id()])
+        .await?;
+    alix_group
+        .update_moderator_list(UpdateModeratorListType::Add, bo.inbox_id().to_string())
+        .await?;
+
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+    bo_group.sync().await?;
+    assert!(
+        bo_group
+            .update_group_name("Renamed".to_string())
+            .await
+            .is_err()
+    );
+    assert!(
+        bo_group
+            .update_moderator_list(UpdateModeratorListType::Add, caro.inbox_id().to_string())
+            .await
+            .is_err()
+    );
+
+    let caro_group = caro.sync_welcomes().await?.pop()?;
+    caro_group.sync().await?;
+    assert!(
+        caro_group
+            .update_moderator_list(UpdateModeratorListType::Remove, bo.inbox_id().to_string())
+            .await
+            .is_err()
+    );
+
+    alix_group.sync().await?;
+    assert_eq!(
+        alix_group.moderator_list()?,
+        vec![bo.inbox_id().to_string()]
+    );
+    alix_group
+        .update_moderator_list(UpdateModeratorListType::Remove, bo.inbox_id().to_string())
+        .await?;
+    assert!(alix_group.moderator_list()?.is_empty());
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_moderator_can_delete_others_messages() {
+    tester!(alix);
+    tester!(bo);
+    tester!(caro);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group
+        .add_members(&[bo.inbox_id(), caro.inbox_id()])
+        .await?;
+    alix_group
+        .update_moderator_list(UpdateModeratorListType::Add, bo.inbox_id().to_string())
+        .await?;
+    alix_group
+        .update_permission_policy(
+            PermissionUpdateType::RemoveMember,
+            PermissionPolicyOption::ModeratorOrAbove,
+            None,
+        )
+        .await?;
+
+    let caro_group = caro.sync_welcomes().await?.pop()?;
+    let text = xmtp_content_types::encoded_content_to_bytes(TextCodec::encode(
+        "Message from Caro".to_string(),
+    )?);
+    let message_id = caro_group
+        .send_message(&text, SendMessageOpts::default())
+        .await?;
+
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+    bo_group.sync().await?;
+    bo_group.delete_message(message_id.clone())?;
+    bo_group.publish_messages().await?;
+
+    caro_group.sync().await?;
+    let deletion = caro
+        .context
+        .db()
+        .get_deletion_by_deleted_message_id(&message_id)?;
+    let deletion = deletion?;
+    assert_eq!(deletion.deleted_by_inbox_id, bo.inbox_id());
+    assert!(deletion.is_moderator_deletion);
+    assert!(!deletion.is_super_admin_deletion);
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_moderator_deletion_requires_moderator_policy() {
+    tester!(alix);
+    tester!(bo);
+    tester!(caro);
+    tester!(devon);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group
+        .add_members(&[bo.inbox_id(), caro.inbox_id(), devon.inbox_id()])
+        .await?;
+    alix_group
+        .update_moderator_list(UpdateModeratorListType::Add, bo.inbox_id().to_string())
+        .await?;
+    alix_group
+        .update_admin_list(UpdateAdminListType::Add, devon.inbox_id().to_string())
+        .await?;
+
+    let caro_group = caro.sync_welcomes().await?.pop()?;
+    let text = xmtp_content_types::encoded_content_to_bytes(TextCodec::encode(
+        "Message from Caro".to_string(),
+    )?);
+    let message_id = caro_group
+        .send_message(&text, SendMessageOpts::default())
+        .await?;
+
+    // Neither moderators nor plain admins can delete others' messages by default
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+    bo_group.sync().await?;
+    assert!(bo_group.delete_message(message_id.clone()).is_err());
+    let devon_group = devon.sync_welcomes().await?.pop()?;
+    devon_group.sync().await?;
+    assert!(devon_group.delete_message(message_id.clone()).is_err());
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_moderator_policy_raises_min_version() {
+    tester!(alix);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group
+        .update_permission_policy(
+            PermissionUpdateType::RemoveMember,
+            PermissionPolicyOption::ModeratorOrAbove,
+            None,
+        )
+        .await?;
+
+    let metadata = alix_group.mutable_metadata()?;
+    assert_eq!(
+        metadata
+            .attributes
+            .get(MetadataField::MinimumSupportedProtocolVersion.as_str())
+            .map(String::as_str),
+        Some(MODERATORS_MIN_PROTOCOL_VERSION)
+    );
+}
@@ -0,0 +1,135 @@
+use crate::groups::error::PinMessageError;
+use crate::groups::intents::{PermissionPolicyOption, PermissionUpdateType};
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::groups::{GroupError, PreconfiguredPolicies};
+use crate::tester;
+use prost::Message;
+use xmtp_content_types::{
+    ContentCodec, encoded_content_to_bytes, group_updated::GroupUpdatedCodec, text::TextCodec,
+};
+use xmtp_db::group_message::MsgQueryArgs;
+use xmtp_mls_common::group_mutable_metadata::MetadataField;
+use xmtp_proto::xmtp::mls::message_contents::{EncodedContent, GroupUpdated};
+
+fn text(content: &str) -> Vec<u8> {
+    encoded_content_to_bytes(TextCodec::encode(content.to_string()).expect("text encodes"))
+}
+
+fn last_group_updated<C>(group: &crate::groups::MlsGroup<C>) -> GroupUpdated
+where
+    C: crate::context::XmtpSharedContext,
+{
+    let messages = group.find_messages(&MsgQueryArgs::default()).unwrap();
+    let last = messages.last().expect("group has messages");
+    let encoded = EncodedContent::decode(last.decrypted_message_bytes.as_slice()).unwrap();
+    GroupUpdatedCodec::decode(encoded).expect("last message is a GroupUpdated")
+}
+
+/// Test that pins are shared by every member and recorded in GroupUpdated messages
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_pin_and_unpin_message() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+
+    let first = alix_group
+        .send_message(&text("Meeting at 5"), SendMessageOpts::default())
+        .await?;
+    let second = alix_group
+        .send_message(&text("Bring snacks"), SendMessageOpts::default())
+        .await?;
+    assert!(alix_group.pinned_messages()?.is_empty());
+
+    alix_group.pin_message(&first).await?;
+    alix_group.pin_message(&second).await?;
+    // Pinning twice is a no-op
+    alix_group.pin_message(&first).await?;
+    assert_eq!(
+        alix_group.pinned_messages()?,
+        vec![first.clone(), second.clone()]
+    );
+
+    let update = last_group_updated(&alix_group);
+    assert_eq!(update.metadata_field_changes.len(), 1);
+    assert_eq!(
+        update.metadata_field_changes[0].field_name,
+        MetadataField::PinnedMessageIds.as_str()
+    );
+
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+    assert_eq!(
+        bo_group.pinned_messages()?,
+        vec![first.clone(), second.clone()]
+    );
+
+    // Any member can change pins under the default policy
+    bo_group.unpin_message(&first).await?;
+    alix_group.sync().await?;
+    assert_eq!(alix_group.pinned_messages()?, vec![second]);
+}
+
+/// Test that the pinned messages policy controls who may pin
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_pin_message_permissions() {
+    tester!(alix);
+    tester!(bo);
+    let policy_set = Some(PreconfiguredPolicies::AdminsOnly.to_policy_set());
+    let alix_group = alix.create_group(policy_set, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+
+    let message_id = alix_group
+        .send_message(&text("Read the rules"), SendMessageOpts::default())
+        .await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+
+    assert!(bo_group.pin_message(&message_id).await.is_err());
+    assert!(bo_group.pinned_messages()?.is_empty());
+
+    alix_group
+        .update_permission_policy(
+            PermissionUpdateType::UpdateMetadata,
+            PermissionPolicyOption::Allow,
+            Some(MetadataField::PinnedMessageIds),
+        )
+        .await?;
+    bo_group.sync().await?;
+
+    bo_group.pin_message(&message_id).await?;
+    alix_group.sync().await?;
+    assert_eq!(alix_group.pinned_messages()?, vec![message_id]);
+}
+
+/// Test that only application messages from this group can be pinned
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_pin_message_validation() {
+    tester!(alix);
+    let alix_group = alix.create_group(None, None)?;
+    let other_group = alix.create_group(None, None)?;
+
+    let result = alix_group.pin_message(&[1; 32]).await;
+    assert!(matches!(
+        result,
+        Err(GroupError::PinMessage(PinMessageError::MessageNotFound(_)))
+    ));
+
+    let other_message = other_group
+        .send_message(&text("Elsewhere"), SendMessageOpts::default())
+        .await?;
+    let result = alix_group.pin_message(&other_message).await;
+    assert!(matches!(
+        result,
+        Err(GroupError::PinMessage(PinMessageError::MessageNotFound(_)))
+    ));
+
+    alix_group.update_group_name("Pins".to_string()).await?;
+    let messages = alix_group.find_messages(&MsgQueryArgs::default())?;
+    let group_update = messages.last().expect("group has messages");
+    let result = alix_group.pin_message(&group_update.id).await;
+    assert!(matches!(
+        result,
+        Err(GroupError::PinMessage(PinMessageError::NonPinnableMessage))
+    ));
+}
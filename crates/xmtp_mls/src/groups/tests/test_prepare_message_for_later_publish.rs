@@ -210,6 +210,7 @@ async fn test_idempotency_key_crosses_the_wire() {
             SendMessageOpts {
                 should_push: true,
                 idempotency_key: Some(key.clone()),
+                ..Default::default()
             },
         )
         .await?;
@@ -0,0 +1,72 @@
+use crate::groups::GroupError;
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::tester;
+use xmtp_content_types::{ContentCodec, encoded_content_to_bytes, text::TextCodec};
+use xmtp_db::NotFound;
+use xmtp_db::group::GroupQueryArgs;
+
+fn text(content: &str) -> Vec<u8> {
+    encoded_content_to_bytes(TextCodec::encode(content.to_string()).expect("text encodes"))
+}
+
+/// Test that unread counts follow the read cursor and ignore our own messages
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_mark_read_updates_unread_count() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+
+    let first = bo_group
+        .send_message(&text("one"), SendMessageOpts::default())
+        .await?;
+    let second = bo_group
+        .send_message(&text("two"), SendMessageOpts::default())
+        .await?;
+    alix_group.sync().await?;
+    alix_group
+        .send_message(&text("mine"), SendMessageOpts::default())
+        .await?;
+
+    let unread = || {
+        let item = alix
+            .list_conversations(GroupQueryArgs::default())
+            .unwrap()
+            .into_iter()
+            .find(|item| item.group.group_id == alix_group.group_id)
+            .unwrap();
+        (item.unread_count, item.first_unread_message_id)
+    };
+    assert_eq!(unread(), (2, Some(first.clone())));
+
+    alix_group.mark_read(&first)?;
+    assert_eq!(unread(), (1, Some(second.clone())));
+    assert_eq!(alix_group.read_cursor()??.message_id, first);
+
+    alix_group.mark_read(&second)?;
+    assert_eq!(unread(), (0, None));
+
+    // Marking an older message does not move the cursor back
+    alix_group.mark_read(&first)?;
+    assert_eq!(unread(), (0, None));
+    assert_eq!(alix_group.read_cursor()??.message_id, second);
+}
+
+/// Test that only messages in the group can be marked as read
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_mark_read_unknown_message() {
+    tester!(alix);
+    let group = alix.create_group(None, None)?;
+    let other = alix.create_group(None, None)?;
+    let message_id = other
+        .send_message(&text("elsewhere"), SendMessageOpts::default())
+        .await?;
+
+    let result = group.mark_read(&message_id);
+    assert!(matches!(
+        result,
+        Err(GroupError::NotFound(NotFound::MessageById(_)))
+    ));
+    assert!(group.read_cursor()?.is_none());
+}
@@ -0,0 +1,143 @@
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::groups::{DeliveryStatus, GroupError};
+use crate::tester;
+use crate::worker::tasks::TaskWorker;
+use crate::worker::{WorkerConfig, WorkerKind};
+use xmtp_db::NotFound;
+use xmtp_db::group_message::{GroupMessageKind, MsgQueryArgs};
+use xmtp_db::prelude::QueryGroupMessage;
+use xmtp_db::tasks::QueryTasks;
+
+/// Keep the background task runner off so each test drives the worker itself.
+fn no_runner_cfg() -> WorkerConfig {
+    let mut cfg = WorkerConfig::default();
+    cfg.enabled.insert(WorkerKind::TaskRunner, false);
+    cfg
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_scheduled_message_is_listed_until_cancelled() {
+    tester!(alix, worker_config: no_runner_cfg());
+    let group = alix.create_group(None, None)?;
+    let now = xmtp_common::time::now_ns();
+
+    let later = group.send_message_at(
+        b"later",
+        now + xmtp_common::NS_IN_HOUR,
+        SendMessageOpts::default(),
+    )?;
+    let sooner = group.send_message_at(
+        b"sooner",
+        now + xmtp_common::NS_IN_SEC,
+        SendMessageOpts::default(),
+    )?;
+
+    let scheduled = group.scheduled_messages()?;
+    assert_eq!(scheduled.len(), 2);
+    assert_eq!(scheduled[0].message.id, sooner);
+    assert_eq!(scheduled[1].message.id, later);
+    assert_eq!(
+        scheduled[0].message.delivery_status,
+        DeliveryStatus::Unpublished
+    );
+
+    group.cancel_scheduled_message(&later)?;
+
+    let scheduled = group.scheduled_messages()?;
+    assert_eq!(scheduled.len(), 1);
+    assert_eq!(scheduled[0].message.id, sooner);
+    assert!(alix.context.db().get_group_message(&later)?.is_none());
+    assert_eq!(alix.context.db().get_tasks()?.len(), 1);
+
+    let result = group.cancel_scheduled_message(&later);
+    assert!(matches!(
+        result,
+        Err(GroupError::NotFound(NotFound::MessageById(_)))
+    ));
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_task_worker_publishes_due_scheduled_message() {
+    tester!(alix, worker_config: no_runner_cfg());
+    let group = alix.create_group(None, None)?;
+    let now = xmtp_common::time::now_ns();
+
+    let message_id = group.send_message_at(b"due", now - 1, SendMessageOpts::default())?;
+    let task = alix
+        .context
+        .db()
+        .get_tasks()?
+        .pop()
+        .expect("task was stored");
+
+    TaskWorker::run_and_reschedule_task(task, &alix.context).await?;
+    group.sync().await?;
+
+    let messages = group.find_messages(&MsgQueryArgs {
+        kind: Some(GroupMessageKind::Application),
+        ..Default::default()
+    })?;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].id, message_id);
+    assert_eq!(messages[0].delivery_status, DeliveryStatus::Published);
+    assert!(group.scheduled_messages()?.is_empty());
+    assert!(alix.context.db().get_tasks()?.is_empty());
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_scheduled_message_is_not_published_early() {
+    tester!(alix, worker_config: no_runner_cfg());
+    let group = alix.create_group(None, None)?;
+    let now = xmtp_common::time::now_ns();
+
+    let message_id = group.send_message_at(
+        b"not yet",
+        now + xmtp_common::NS_IN_HOUR,
+        SendMessageOpts::default(),
+    )?;
+    let task = alix
+        .context
+        .db()
+        .get_tasks()?
+        .pop()
+        .expect("task was stored");
+
+    TaskWorker::run_and_reschedule_task(task, &alix.context).await?;
+
+    let message = alix
+        .context
+        .db()
+        .get_group_message(&message_id)?
+        .expect("message is still stored");
+    assert_eq!(message.delivery_status, DeliveryStatus::Unpublished);
+    assert_eq!(group.scheduled_messages()?.len(), 1);
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_cancelled_scheduled_message_task_is_dropped() {
+    tester!(alix, worker_config: no_runner_cfg());
+    let group = alix.create_group(None, None)?;
+    let now = xmtp_common::time::now_ns();
+
+    let message_id = group.send_message_at(b"cancelled", now - 1, SendMessageOpts::default())?;
+    let task = alix
+        .context
+        .db()
+        .get_tasks()?
+        .pop()
+        .expect("task was stored");
+    // Simulate a cancel racing the worker: the message is gone, the row is not.
+    alix.context.db().delete_message_by_id(&message_id)?;
+
+    TaskWorker::run_and_reschedule_task(task, &alix.context).await?;
+
+    assert!(alix.context.db().get_tasks()?.is_empty());
+    assert!(
+        group
+            .find_messages(&MsgQueryArgs {
+                kind: Some(GroupMessageKind::Application),
+                ..Default::default()
+            })?
+            .is_empty()
+    );
+}
@@ -0,0 +1,83 @@
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::tester;
+use xmtp_content_types::{ContentCodec, encoded_content_to_bytes, text::TextCodec};
+use xmtp_db::group_message::MsgQueryArgs;
+
+fn text(content: &str) -> Vec<u8> {
+    encoded_content_to_bytes(TextCodec::encode(content.to_string()).expect("text encodes"))
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_search_received_messages() {
+    tester!(alix);
+    tester!(bo);
+
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let other_group = alix.create_group(None, None)?;
+    other_group.add_members(&[bo.inbox_id()]).await?;
+
+    alix_group
+        .send_message(&text("Dinner on Friday?"), SendMessageOpts::default())
+        .await?;
+    other_group
+        .send_message(&text("friday works for me"), SendMessageOpts::default())
+        .await?;
+
+    bo.sync_all_welcomes_and_groups(None).await?;
+
+    let hits = bo.search_messages("friday", &MsgQueryArgs::default())?;
+    assert_eq!(hits.len(), 2);
+    assert!(
+        hits.iter()
+            .all(|hit| hit.snippet.to_lowercase().contains("<mark>friday</mark>"))
+    );
+
+    let bo_group = bo.group(&alix_group.group_id)?;
+    let hits = bo_group.search_messages("friday", &MsgQueryArgs::default())?;
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].message.group_id, alix_group.group_id);
+
+    // Membership changes are never indexed
+    assert!(
+        bo_group
+            .search_messages("added", &MsgQueryArgs::default())?
+            .is_empty()
+    );
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_deleted_messages_leave_search_results() {
+    tester!(alix);
+    tester!(bo);
+
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let message_id = alix_group
+        .send_message(&text("secret plans"), SendMessageOpts::default())
+        .await?;
+
+    let bo_group = bo.sync_welcomes().await?.remove(0);
+    bo_group.sync().await?;
+    assert_eq!(
+        bo_group
+            .search_messages("secret", &MsgQueryArgs::default())?
+            .len(),
+        1
+    );
+
+    alix_group.delete_message(message_id)?;
+    alix_group.publish_messages().await?;
+    bo_group.sync().await?;
+
+    assert!(
+        alix_group
+            .search_messages("secret", &MsgQueryArgs::default())?
+            .is_empty()
+    );
+    assert!(
+        bo_group
+            .search_messages("secret", &MsgQueryArgs::default())?
+            .is_empty()
+    );
+}
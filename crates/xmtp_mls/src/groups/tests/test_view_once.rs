@@ -0,0 +1,60 @@
+use crate::context::XmtpSharedContext;
+use crate::groups::send_message_opts::SendMessageOpts;
+use crate::subscriptions::LocalEvents;
+use crate::tester;
+use xmtp_db::prelude::*;
+
+/// Test that view-once messages are deleted on the receiver once viewed, while
+/// the sender keeps their copy
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_mark_viewed_deletes_view_once_message() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+
+    let message_id = alix_group
+        .send_message(
+            b"secret",
+            SendMessageOpts {
+                view_once: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    bo_group.sync().await?;
+    assert!(bo.context.db().get_group_message(&message_id)??.view_once);
+
+    let mut events = bo.context.local_events().subscribe();
+    assert!(bo_group.mark_viewed(&message_id)?);
+    assert!(bo.context.db().get_group_message(&message_id)?.is_none());
+    let deleted = loop {
+        if let LocalEvents::MsgsDeleted(messages) = events.recv().await? {
+            break messages;
+        }
+    };
+    assert_eq!(deleted[0].id, message_id);
+
+    // The sender is not affected by viewing their own message
+    assert!(!alix_group.mark_viewed(&message_id)?);
+    assert!(alix.context.db().get_group_message(&message_id)?.is_some());
+}
+
+#[xmtp_common::test(unwrap_try = true)]
+async fn test_mark_viewed_keeps_regular_messages() {
+    tester!(alix);
+    tester!(bo);
+    let alix_group = alix.create_group(None, None)?;
+    alix_group.add_members(&[bo.inbox_id()]).await?;
+    let bo_group = bo.sync_welcomes().await?.pop()?;
+
+    let message_id = alix_group
+        .send_message(b"hello", Default::default())
+        .await?;
+    bo_group.sync().await?;
+
+    assert!(!bo_group.mark_viewed(&message_id)?);
+    assert!(bo.context.db().get_group_message(&message_id)?.is_some());
+    assert!(bo_group.mark_viewed(b"unknown").is_err());
+}
@@ -1,15 +1,17 @@
 use super::{
-    MAX_APP_DATA_LENGTH, MAX_GROUP_DESCRIPTION_LENGTH, MAX_GROUP_IMAGE_URL_LENGTH,
-    MAX_GROUP_NAME_LENGTH,
+    MAX_APP_DATA_LENGTH, MAX_EXTERNAL_INVITES_LENGTH, MAX_GROUP_DESCRIPTION_LENGTH,
+    MAX_GROUP_IMAGE_URL_LENGTH, MAX_GROUP_NAME_LENGTH, MAX_PINNED_MESSAGE_IDS_LENGTH,
     group_membership::{GroupMembership, MembershipDiff},
     group_permissions::{
-        GroupMutablePermissions, GroupMutablePermissionsError, MembershipPolicy, MetadataPolicy,
-        PermissionsPolicy, PolicySet, extract_group_permissions,
+        GroupMutablePermissions, GroupMutablePermissionsError, MembershipPolicies,
+        MembershipPolicy, MetadataPolicy, PermissionsPolicy, PolicySet, extract_group_permissions,
     },
 };
 use crate::{
     context::XmtpSharedContext,
-    identity_updates::{IdentityUpdates, InstallationDiff, InstallationDiffError},
+    identity_updates::{
+        IdentityUpdates, InstallationDiff, InstallationDiffError, load_identity_updates,
+    },
 };
 use openmls::{
     credentials::{BasicCredential, Credential as OpenMlsCredential, errors::BasicCredentialError},
@@ -27,16 +29,22 @@ use std::collections::HashSet;
 use thiserror::Error;
 use xmtp_common::{retry::RetryableError, retryable};
 use xmtp_db::StorageError;
+use xmtp_db::group::ConversationType;
 use xmtp_db::local_commit_log::CommitType;
 #[cfg(doc)]
 use xmtp_id::associations::AssociationState;
-use xmtp_id::{InboxId, associations::MemberIdentifier};
+use xmtp_id::{
+    InboxId,
+    associations::{Identifier, MemberIdentifier},
+};
 use xmtp_mls_common::{
+    app_data::component_id::ComponentId,
     group_metadata::{DmMembers, GroupMetadata, GroupMetadataError},
     group_mutable_metadata::{
         GroupMutableMetadata, GroupMutableMetadataError, MetadataField,
         find_mutable_metadata_extension,
     },
+    invite::group_invites::{decode_group_invites, find_active_invite},
 };
 use xmtp_proto::types::GroupId;
 use xmtp_proto::xmtp::{
@@ -60,9 +68,22 @@ pub enum CommitValidationError {
     InvalidVersionFormat(String),
     #[error("Minimum supported protocol version {0} exceeds current version")]
     ProtocolVersionTooLow(String),
-    // TODO: We will need to relax this once we support external joins
+    /// The commit uses something clients older than `required` can't parse,
+    /// such as moderators, membership gates or external invites, while the
+    /// group's floor still admits them.
+    #[error("Commit requires a minimum supported protocol version of at least {required}")]
+    MinVersionRequired { required: String },
+    // External commits are validated separately, see `ValidatedCommit::from_external_commit`
     #[error("Actor not a member of the group")]
     ActorNotMember,
+    /// An external commit did not reference an active invite, or the group
+    /// does not accept external commits at all.
+    #[error("External commit is not allowed: {0}")]
+    ExternalCommitNotAllowed(String),
+    /// A commit added or changed an invite that names another inbox as its
+    /// creator, or an epoch other than the one the commit creates.
+    #[error("Invites must be created by the committer in the epoch the commit creates")]
+    InvalidInvite,
     #[error("Subject not a member of the group")]
     SubjectDoesNotExist,
     // Current behaviour is to error out if a Commit includes proposals from multiple actors
@@ -103,6 +124,10 @@ pub enum CommitValidationError {
     ProposerNotFound,
     #[error("Proposals are not enabled on this group")]
     ProposalsNotEnabled,
+    /// An added inbox is not on the group's allowlist or lacks the
+    /// identifier the add member policy requires.
+    #[error("Inbox {0} does not meet the membership requirements of the group")]
+    MembershipRequirementNotMet(String),
     /// Sender published an `AppDataUpdate(Update)` against
     /// `MIN_SUPPORTED_PROTOCOL_VERSION` whose new value is below the
     /// existing floor. Monotonic-only: a downgrade silently unpauses
@@ -150,6 +175,7 @@ pub struct CommitParticipant {
     pub is_creator: bool,
     pub is_admin: bool,
     pub is_super_admin: bool,
+    pub is_moderator: bool,
 }
 
 impl std::fmt::Debug for CommitParticipant {
@@ -160,15 +186,17 @@ impl std::fmt::Debug for CommitParticipant {
             is_creator,
             is_admin,
             is_super_admin,
+            is_moderator,
         } = &self;
         write!(
             f,
-            "CommitParticipant {{ inbox_id={}, installation_id={}, is_creator={}, is_admin={}, is_super_admin={} }}",
+            "CommitParticipant {{ inbox_id={}, installation_id={}, is_creator={}, is_admin={}, is_super_admin={}, is_moderator={} }}",
             inbox_id,
             hex::encode(installation_id),
             is_creator,
             is_admin,
             is_super_admin,
+            is_moderator,
         )
     }
 }
@@ -183,6 +211,7 @@ impl CommitParticipant {
         let is_creator = inbox_id == immutable_metadata.creator_inbox_id;
         let is_admin = mutable_metadata.is_admin(&inbox_id);
         let is_super_admin = mutable_metadata.is_super_admin(&inbox_id);
+        let is_moderator = mutable_metadata.is_moderator(&inbox_id);
 
         Self {
             inbox_id,
@@ -190,9 +219,15 @@ impl CommitParticipant {
             is_creator,
             is_admin,
             is_super_admin,
+            is_moderator,
         }
     }
 
+    /// Whether the participant is a moderator, an admin or a super admin.
+    pub fn is_moderator_or_above(&self) -> bool {
+        self.is_moderator || self.is_admin || self.is_super_admin
+    }
+
     pub fn from_leaf_node(
         leaf_node: &LeafNode,
         immutable_metadata: &GroupMetadata,
@@ -232,6 +267,8 @@ pub struct MutableMetadataValidationInfo {
     pub admins_removed: Vec<Inbox>,
     pub super_admins_added: Vec<Inbox>,
     pub super_admins_removed: Vec<Inbox>,
+    pub moderators_added: Vec<Inbox>,
+    pub moderators_removed: Vec<Inbox>,
     pub num_super_admins: u32,
     pub minimum_supported_protocol_version: Option<String>,
 }
@@ -243,6 +280,8 @@ impl MutableMetadataValidationInfo {
             && self.admins_removed.is_empty()
             && self.super_admins_added.is_empty()
             && self.super_admins_removed.is_empty()
+            && self.moderators_added.is_empty()
+            && self.moderators_removed.is_empty()
             && self.minimum_supported_protocol_version.is_none()
     }
 }
@@ -257,6 +296,19 @@ pub struct Inbox {
     /// The proposer who requested this inbox change (if from a proposal)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proposer: Option<CommitParticipant>,
+    /// What the group's membership gates know about the inbox. Only loaded
+    /// for added inboxes, when the add member policy has gates.
+    #[serde(skip)]
+    pub attributes: InboxAttributes,
+}
+
+/// Attributes of an added inbox that membership gates are evaluated against.
+#[derive(Debug, Clone, Default)]
+pub struct InboxAttributes {
+    /// The allowlist components of the group that list the inbox
+    pub allowlists: HashSet<ComponentId>,
+    /// The identifiers associated with the inbox
+    pub identifiers: Vec<Identifier>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -332,6 +384,9 @@ impl std::fmt::Display for LibXMTPVersion {
  * 6. No PSK proposals will be allowed
  * 7. New installations may be missing from the commit but still be present in the expected diff.
  * 8. Confirms metadata character limit is not exceeded
+ * 9. Policies, roles and external invites that older clients can't handle are only used once the group's minimum
+ *    supported protocol version excludes those clients
+ * 10. Membership gates are only used on groups migrated to the app data dictionary
  */
 #[derive(Debug, Clone, Serialize)]
 pub struct ValidatedCommit {
@@ -587,6 +642,20 @@ impl ValidatedCommit {
                             length: MAX_APP_DATA_LENGTH,
                         });
                     }
+                    val if val == MetadataField::PinnedMessageIds.as_str()
+                        && new_value.len() > MAX_PINNED_MESSAGE_IDS_LENGTH =>
+                    {
+                        return Err(CommitValidationError::TooManyCharacters {
+                            length: MAX_PINNED_MESSAGE_IDS_LENGTH,
+                        });
+                    }
+                    val if val == MetadataField::ExternalInvites.as_str()
+                        && new_value.len() > MAX_EXTERNAL_INVITES_LENGTH =>
+                    {
+                        return Err(CommitValidationError::TooManyCharacters {
+                            length: MAX_EXTERNAL_INVITES_LENGTH,
+                        });
+                    }
                     _ => {}
                 }
             }
@@ -614,6 +683,11 @@ impl ValidatedCommit {
         )?;
 
         reject_psk_proposals(staged_commit)?;
+        validate_invite_changes(
+            &metadata_validation_info,
+            &actor,
+            staged_commit.group_context().epoch().as_u64(),
+        )?;
 
         // AppDataUpdate proposals carried by a commit (inline OR by
         // reference, since `staged_commit.app_data_update_proposals()`
@@ -725,7 +799,7 @@ impl ValidatedCommit {
             }
         }
 
-        let verified_commit = Self {
+        let mut verified_commit = Self {
             actor,
             proposers,
             added_inboxes,
@@ -749,9 +823,65 @@ impl ValidatedCommit {
         } else {
             extract_group_permissions(openmls_group)?
         };
+        let add_member_policy = &policy_set.policies.add_member_policy;
+        if add_member_policy.has_inbox_gates() {
+            load_inbox_attributes(
+                context,
+                openmls_group,
+                add_member_policy,
+                &new_group_membership,
+                &mut verified_commit.added_inboxes,
+            )
+            .await?;
+        }
+        check_membership_requirements(add_member_policy, &verified_commit)?;
         if !policy_set.policies.evaluate_commit(&verified_commit) {
             return Err(CommitValidationError::InsufficientPermissions);
         }
+        let mut required_min_versions = vec![];
+        if verified_commit.permissions_changed {
+            let new_permissions = GroupMutablePermissions::try_from(new_group_extensions)?;
+            required_min_versions.extend(new_permissions.policies.required_min_version());
+        }
+        if !verified_commit
+            .metadata_validation_info
+            .moderators_added
+            .is_empty()
+        {
+            required_min_versions.push(xmtp_configuration::MODERATORS_MIN_PROTOCOL_VERSION);
+        }
+        if verified_commit
+            .metadata_validation_info
+            .metadata_field_changes
+            .iter()
+            .any(|change| {
+                change.field_name == MetadataField::ExternalInvites.as_str()
+                    && change
+                        .new_value
+                        .as_ref()
+                        .is_some_and(|value| !value.is_empty())
+            })
+        {
+            required_min_versions.push(xmtp_configuration::EXTERNAL_INVITES_MIN_PROTOCOL_VERSION);
+        }
+        if let Some(registry) = &migrated_registry
+            && super::app_data::policy::commit_sets_membership_gates(
+                openmls_group,
+                staged_commit,
+                registry,
+            )?
+        {
+            required_min_versions.push(xmtp_configuration::MEMBERSHIP_GATES_MIN_PROTOCOL_VERSION);
+        }
+        for required in required_min_versions {
+            require_min_version_floor(
+                required,
+                verified_commit
+                    .metadata_validation_info
+                    .minimum_supported_protocol_version
+                    .as_deref(),
+            )?;
+        }
         if let Some(min_version) = &verified_commit
             .metadata_validation_info
             .minimum_supported_protocol_version
@@ -773,6 +903,161 @@ impl ValidatedCommit {
         Ok(verified_commit)
     }
 
+    /// Validate an external commit created by a joiner holding an invite.
+    ///
+    /// External Commit Validation Rules:
+    /// 1. The group must be an unmigrated `Group` conversation whose minimum supported
+    ///    protocol version admits external invites
+    /// 2. The commit's AAD must name an invite in the group's `external_invites` attribute
+    ///    that is active at `commit_time_ns`
+    /// 3. The commit may only carry `ExternalInit`, `GroupContextExtensions` and `Remove`
+    ///    proposals, where a `Remove` may only target another installation of the joiner
+    ///    (a resync)
+    /// 4. The commit must record the joiner's inbox in the group membership, and change
+    ///    nothing else in the group context extensions
+    /// 5. The joiner's installation must be present in its inbox's [`AssociationState`]
+    ///    at the recorded sequence id
+    /// 6. The joiner is checked against the add member policy as if the invite's creator
+    ///    had added them
+    ///
+    /// `commit_time_ns` must be the envelope timestamp so that every member reaches the
+    /// same decision.
+    pub async fn from_external_commit(
+        context: &impl XmtpSharedContext,
+        staged_commit: &StagedCommit,
+        aad: &[u8],
+        openmls_group: &OpenMlsGroup,
+        commit_time_ns: u64,
+    ) -> Result<Self, CommitValidationError> {
+        let not_allowed =
+            |reason: &str| CommitValidationError::ExternalCommitNotAllowed(reason.to_string());
+        let extensions = openmls_group.extensions();
+        if super::app_data::is_migrated_extensions(extensions) {
+            return Err(not_allowed("group has migrated to AppData"));
+        }
+        let immutable_metadata: GroupMetadata = extensions.try_into()?;
+        let mutable_metadata: GroupMutableMetadata = extensions.try_into()?;
+        if immutable_metadata.conversation_type != ConversationType::Group {
+            return Err(not_allowed("only groups accept external commits"));
+        }
+        if !super::external_invites::admits_external_invites(extensions) {
+            return Err(CommitValidationError::MinVersionRequired {
+                required: xmtp_configuration::EXTERNAL_INVITES_MIN_PROTOCOL_VERSION.to_string(),
+            });
+        }
+
+        reject_psk_proposals(staged_commit)?;
+
+        let leaf_node = staged_commit
+            .update_path_leaf_node()
+            .ok_or(CommitValidationError::ActorCouldNotBeFound)?;
+        let actor =
+            CommitParticipant::from_leaf_node(leaf_node, &immutable_metadata, &mutable_metadata)?;
+
+        for proposal in staged_commit.queued_proposals() {
+            match proposal.proposal() {
+                Proposal::ExternalInit(_) | Proposal::GroupContextExtensions(_) => {}
+                Proposal::Remove(remove) => {
+                    let removed = openmls_group
+                        .member_at(remove.removed())
+                        .ok_or(CommitValidationError::SubjectDoesNotExist)?;
+                    if inbox_id_from_credential(&removed.credential)? != actor.inbox_id {
+                        return Err(not_allowed("joiner may only remove its own installations"));
+                    }
+                }
+                other => {
+                    return Err(CommitValidationError::UnsupportedProposalType(
+                        other.proposal_type(),
+                    ));
+                }
+            }
+        }
+
+        let invites = mutable_metadata
+            .attributes
+            .get(MetadataField::ExternalInvites.as_str())
+            .map(|value| decode_group_invites(value))
+            .unwrap_or_default();
+        let invite = find_active_invite(&invites, aad, commit_time_ns)
+            .ok_or_else(|| not_allowed("no active invite matches the commit"))?;
+
+        // Satisfies Rule 4
+        let group_membership = extract_group_membership(extensions)?;
+        let new_extensions = staged_commit.group_context().extensions();
+        let new_group_membership = extract_group_membership(new_extensions)?;
+        let joiner_sequence_id = *new_group_membership
+            .get(&actor.inbox_id)
+            .ok_or_else(|| not_allowed("joiner must record its inbox in the group membership"))?;
+        let mut expected_membership = group_membership.clone();
+        expected_membership.add(actor.inbox_id.clone(), joiner_sequence_id);
+        if new_group_membership != expected_membership
+            || !same_extensions_except_membership(extensions, new_extensions)
+        {
+            return Err(not_allowed(
+                "joiner may only record its own inbox in the group context",
+            ));
+        }
+
+        let inviter = CommitParticipant::build(
+            invite.created_by_inbox_id.clone(),
+            vec![],
+            &immutable_metadata,
+            &mutable_metadata,
+        );
+        let added_inboxes = if group_membership.get(&actor.inbox_id).is_some() {
+            vec![]
+        } else {
+            vec![build_inbox_with_proposer(
+                &actor.inbox_id,
+                &immutable_metadata,
+                &mutable_metadata,
+                inviter,
+            )]
+        };
+
+        // Satisfies Rule 5
+        let conn = context.db();
+        let needs_updates = super::filter_inbox_ids_needing_updates(
+            &conn,
+            &[(actor.inbox_id.as_str(), joiner_sequence_id as i64)],
+        )
+        .map_err(InstallationDiffError::from)?;
+        load_identity_updates(context.api(), &conn, &needs_updates)
+            .await
+            .map_err(InstallationDiffError::from)?;
+        let inbox_state = IdentityUpdates::new(&context)
+            .get_association_state(&conn, &actor.inbox_id, Some(joiner_sequence_id as i64))
+            .await
+            .map_err(InstallationDiffError::from)?;
+        if inbox_state
+            .get(&MemberIdentifier::installation(
+                actor.installation_id.clone(),
+            ))
+            .is_none()
+        {
+            return Err(CommitValidationError::InboxValidationFailed(
+                actor.inbox_id.clone(),
+            ));
+        }
+
+        let verified_commit = Self {
+            actor,
+            proposers: vec![],
+            added_inboxes,
+            removed_inboxes: vec![],
+            readded_installations: HashSet::new(),
+            metadata_validation_info: MutableMetadataValidationInfo::default(),
+            installations_changed: true,
+            permissions_changed: false,
+            dm_members: None,
+        };
+        let policy_set = extract_group_permissions(openmls_group)?;
+        if !policy_set.policies.evaluate_commit(&verified_commit) {
+            return Err(CommitValidationError::InsufficientPermissions);
+        }
+        Ok(verified_commit)
+    }
+
     // Reuse intent kind here to represent the commit type, even if it's an external commit
     // This is for debugging purposes only, so an approximation is fine
     pub fn debug_commit_type(&self) -> CommitType {
@@ -788,6 +1073,8 @@ impl ValidatedCommit {
             || !metadata_info.admins_removed.is_empty()
             || !metadata_info.super_admins_added.is_empty()
             || !metadata_info.super_admins_removed.is_empty()
+            || !metadata_info.moderators_added.is_empty()
+            || !metadata_info.moderators_removed.is_empty()
         {
             CommitType::UpdateAdminList
         } else if !metadata_info.metadata_field_changes.is_empty() {
@@ -1674,7 +1961,7 @@ pub(super) fn extract_commit_participant(
             mutable_metadata,
         ))
     } else {
-        // TODO: Handle external joins/commits
+        // External committers are not in the tree yet, see `ValidatedCommit::from_external_commit`
         Err(CommitValidationError::ActorNotMember)
     }
 }
@@ -1779,6 +2066,18 @@ fn extract_metadata_changes(
             immutable_metadata,
             old_mutable_metadata,
         ),
+        moderators_added: get_added_members(
+            &old_mutable_metadata.moderator_list,
+            &new_mutable_metadata.moderator_list,
+            immutable_metadata,
+            old_mutable_metadata,
+        ),
+        moderators_removed: get_removed_members(
+            &old_mutable_metadata.moderator_list,
+            &new_mutable_metadata.moderator_list,
+            immutable_metadata,
+            old_mutable_metadata,
+        ),
         num_super_admins: new_mutable_metadata.super_admin_list.len() as u32,
         minimum_supported_protocol_version: new_mutable_metadata
             .attributes
@@ -1787,6 +2086,25 @@ fn extract_metadata_changes(
     })
 }
 
+/// Rejects a commit that uses something clients below `required` can't parse
+/// while the group's minimum supported protocol version still admits them.
+fn require_min_version_floor(
+    required: &str,
+    floor: Option<&str>,
+) -> Result<(), CommitValidationError> {
+    let required_version = LibXMTPVersion::parse(required)?;
+    let admits_older_clients = match floor {
+        Some(floor) => LibXMTPVersion::parse(floor)? < required_version,
+        None => true,
+    };
+    if admits_older_clients {
+        return Err(CommitValidationError::MinVersionRequired {
+            required: required.to_string(),
+        });
+    }
+    Ok(())
+}
+
 // Returns true if the permissions have changed, false otherwise
 fn extract_permissions_changed(
     old_group_permissions: &GroupMutablePermissions,
@@ -1796,6 +2114,68 @@ fn extract_permissions_changed(
     Ok(!old_group_permissions.eq(&new_group_permissions))
 }
 
+/// Invites record who created them and the epoch they were created in, and
+/// external joins are checked against the add member policy as that creator.
+/// So every invite a commit adds or changes must name the committer and the
+/// epoch the commit creates. Removing invites is left to the field policy.
+fn validate_invite_changes(
+    metadata_validation_info: &MutableMetadataValidationInfo,
+    actor: &CommitParticipant,
+    new_epoch: u64,
+) -> Result<(), CommitValidationError> {
+    let Some(change) = metadata_validation_info
+        .metadata_field_changes
+        .iter()
+        .find(|change| change.field_name == MetadataField::ExternalInvites.as_str())
+    else {
+        return Ok(());
+    };
+    let old_invites = change
+        .old_value
+        .as_deref()
+        .map(decode_group_invites)
+        .unwrap_or_default();
+    let new_invites = change
+        .new_value
+        .as_deref()
+        .map(decode_group_invites)
+        .unwrap_or_default();
+    let forged = new_invites
+        .iter()
+        .filter(|invite| !old_invites.contains(invite))
+        .any(|invite| {
+            invite.created_by_inbox_id != actor.inbox_id || invite.group_info_epoch != new_epoch
+        });
+    if forged {
+        return Err(CommitValidationError::InvalidInvite);
+    }
+    Ok(())
+}
+
+/// Whether two sets of group context extensions match once the group
+/// membership extension is left out. Order is ignored, since replacing the
+/// membership extension may move it.
+fn same_extensions_except_membership(
+    old_extensions: &Extensions<GroupContext>,
+    new_extensions: &Extensions<GroupContext>,
+) -> bool {
+    let without_membership = |extensions: &Extensions<GroupContext>| {
+        extensions
+            .iter()
+            .filter(|extension| {
+                !matches!(
+                    extension,
+                    Extension::Unknown(xmtp_configuration::GROUP_MEMBERSHIP_EXTENSION_ID, _)
+                )
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let old = without_membership(old_extensions);
+    let new = without_membership(new_extensions);
+    old.len() == new.len() && old.iter().all(|extension| new.contains(extension))
+}
+
 fn find_unknown_extension(
     extensions: &Extensions<GroupContext>,
     extension_type: u16,
@@ -1840,6 +2220,71 @@ fn get_removed_members(
         .collect()
 }
 
+/// Load the attributes the inbox gates of `policy` are evaluated against
+/// into `inboxes`. Allowlists are read from the pre-commit AppData
+/// dictionary and identifiers at each inbox's sequence id in
+/// `group_membership`.
+async fn load_inbox_attributes(
+    context: &impl XmtpSharedContext,
+    openmls_group: &OpenMlsGroup,
+    policy: &MembershipPolicies,
+    group_membership: &GroupMembership,
+    inboxes: &mut [Inbox],
+) -> Result<(), CommitValidationError> {
+    let allowlists = policy
+        .allowlist_components()
+        .into_iter()
+        .map(|id| {
+            super::app_data::component_source::read_inbox_allowlist_from_extensions(
+                id,
+                openmls_group.extensions(),
+            )
+            .map(|inbox_ids| (id, inbox_ids))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let conn = context.db();
+    for inbox in inboxes {
+        inbox.attributes.allowlists = allowlists
+            .iter()
+            .filter(|(_, inbox_ids)| inbox_ids.contains(&inbox.inbox_id))
+            .map(|(id, _)| *id)
+            .collect();
+        if policy.requires_identifiers() {
+            let sequence_id = match group_membership.get(&inbox.inbox_id) {
+                None | Some(0) => None,
+                Some(sequence_id) => Some(*sequence_id as i64),
+            };
+            let inbox_state = IdentityUpdates::new(&context)
+                .get_association_state(&conn, &inbox.inbox_id, sequence_id)
+                .await
+                .map_err(InstallationDiffError::from)?;
+            inbox.attributes.identifiers = inbox_state.identifiers();
+        }
+    }
+    Ok(())
+}
+
+/// Reject the commit if an added inbox would be admitted by the add member
+/// policy, except that it fails one of the policy's inbox gates.
+fn check_membership_requirements(
+    policy: &MembershipPolicies,
+    commit: &ValidatedCommit,
+) -> Result<(), CommitValidationError> {
+    if !policy.has_inbox_gates() {
+        return Ok(());
+    }
+    let ungated_policy = policy.with_inbox_gates_satisfied();
+    for inbox in &commit.added_inboxes {
+        let actor = inbox.proposer.as_ref().unwrap_or(&commit.actor);
+        if !policy.evaluate(actor, inbox) && ungated_policy.evaluate(actor, inbox) {
+            return Err(CommitValidationError::MembershipRequirementNotMet(
+                inbox.inbox_id.clone(),
+            ));
+        }
+    }
+    Ok(())
+}
+
 fn build_inbox(
     inbox_id: &String,
     immutable_metadata: &GroupMetadata,
@@ -1851,6 +2296,7 @@ fn build_inbox(
         is_super_admin: mutable_metadata.is_super_admin(inbox_id),
         is_creator: immutable_metadata.creator_inbox_id.eq(inbox_id),
         proposer: None,
+        attributes: InboxAttributes::default(),
     }
 }
 
@@ -1866,6 +2312,7 @@ fn build_inbox_with_proposer(
         is_super_admin: mutable_metadata.is_super_admin(inbox_id),
         is_creator: immutable_metadata.creator_inbox_id.eq(inbox_id),
         proposer: Some(proposer),
+        attributes: InboxAttributes::default(),
     }
 }
 
@@ -2013,8 +2460,15 @@ pub fn validate_proposal(
                 is_admin: false,
                 is_super_admin: false,
                 proposer: Some(proposer.clone()),
+                attributes: InboxAttributes::default(),
             };
-            if !policy_set.add_member_policy.evaluate(&proposer, &inbox) {
+            // Inbox gates are checked once the proposal is committed, see
+            // `check_membership_requirements`
+            if !policy_set
+                .add_member_policy
+                .with_inbox_gates_satisfied()
+                .evaluate(&proposer, &inbox)
+            {
                 // DM bypass: allow adding the other DM participant even if policy denies
                 let is_dm_add = immutable_metadata.dm_members.as_ref().is_some_and(|dm| {
                     (added_inbox_id == dm.member_one_inbox_id.as_ref()
@@ -2056,6 +2510,7 @@ pub fn validate_proposal(
                 is_admin: removed_is_admin,
                 is_super_admin: removed_is_super_admin,
                 proposer: Some(proposer.clone()),
+                attributes: InboxAttributes::default(),
             };
 
             if !policy_set
@@ -2136,6 +2591,17 @@ pub fn validate_proposal(
                     );
                     return Err(CommitValidationError::InsufficientPermissions);
                 }
+
+                if (!metadata_changes.moderators_added.is_empty()
+                    || !metadata_changes.moderators_removed.is_empty())
+                    && !(proposer.is_admin || proposer.is_super_admin)
+                {
+                    tracing::warn!(
+                        proposer_inbox_id = %proposer.inbox_id,
+                        "GCE proposal rejected: only admins can modify moderator list"
+                    );
+                    return Err(CommitValidationError::InsufficientPermissions);
+                }
             }
 
             // Check for permission changes (only super admin can
@@ -0,0 +1,40 @@
+use super::{GroupError, MlsGroup};
+use crate::context::XmtpSharedContext;
+use crate::subscriptions::LocalEvents;
+use xmtp_db::NotFound;
+use xmtp_db::prelude::*;
+
+impl<Context> MlsGroup<Context>
+where
+    Context: XmtpSharedContext,
+{
+    /// Mark a message as viewed by the user. Messages sent with
+    /// [`SendMessageOpts::view_once`](super::send_message_opts::SendMessageOpts::view_once)
+    /// by another member are deleted from this installation.
+    ///
+    /// Returns whether the message was deleted.
+    pub fn mark_viewed(&self, message_id: &[u8]) -> Result<bool, GroupError> {
+        let conn = self.context.db();
+        let message = conn
+            .get_group_message(message_id)?
+            .ok_or_else(|| GroupError::NotFound(NotFound::MessageById(message_id.to_vec())))?;
+
+        // The sender keeps their copy, it only disappears for the viewers
+        if message.group_id != self.group_id
+            || !message.view_once
+            || message.sender_inbox_id == self.context.inbox_id()
+        {
+            return Ok(false);
+        }
+
+        if conn.delete_message_by_id(message_id)? == 0 {
+            return Ok(false);
+        }
+        let _ = self
+            .context
+            .local_events()
+            .send(LocalEvents::MsgsDeleted(vec![message]));
+
+        Ok(true)
+    }
+}
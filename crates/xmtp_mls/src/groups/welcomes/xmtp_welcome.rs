@@ -593,6 +593,8 @@ where
             should_push: true,
             // Matches the key used to derive `added_message_id` above.
             idempotency_key: added_idempotency_key,
+            expire_after_ns: None,
+            view_once: false,
         };
 
         added_msg.store_or_ignore(&db)?;
@@ -839,54 +839,7 @@ impl XmtpKeyPackageBuilder {
             Extension::ApplicationId(ApplicationIdExtension::new(this.inbox_id.as_bytes()));
         let leaf_node_extensions = Extensions::<LeafNode>::single(application_id)?;
 
-        #[allow(unused_mut)]
-        let mut capability_extensions = vec![
-            ExtensionType::LastResort,
-            ExtensionType::ApplicationId,
-            ExtensionType::ImmutableMetadata,
-            // Advertise AppDataDictionary so the bootstrap commit
-            // (and later AppDataUpdate proposals) are accepted —
-            // OpenMLS rejects commits whose new extension set isn't
-            // covered by every leaf's capabilities. Advertising
-            // unconditionally is the simplest path: required-vs-
-            // supported is enforced by RequiredCapabilities, not by
-            // this leaf-node list. This advertisement also doubles
-            // as the migration-eligibility signal that
-            // `all_members_support_proposals` reads.
-            ExtensionType::AppDataDictionary,
-            ExtensionType::Unknown(GROUP_PERMISSIONS_EXTENSION_ID),
-            ExtensionType::Unknown(MUTABLE_METADATA_EXTENSION_ID),
-            ExtensionType::Unknown(GROUP_MEMBERSHIP_EXTENSION_ID),
-            ExtensionType::Unknown(WELCOME_WRAPPER_ENCRYPTION_EXTENSION_ID),
-            ExtensionType::Unknown(WELCOME_POINTEE_ENCRYPTION_AEAD_TYPES_EXTENSION_ID),
-        ];
-        // Test-only opt-out: tests that simulate an "old client
-        // without AppData support" drop the advertisement so the
-        // member-support check fails for that installation.
-        #[cfg(any(test, feature = "test-utils"))]
-        {
-            if !ENABLE_APP_DATA_DICTIONARY_BROADCAST
-                .try_with(|v| *v)
-                .unwrap_or(true)
-            {
-                capability_extensions.retain(|e| *e != ExtensionType::AppDataDictionary);
-            }
-        }
-        // Advertise both `GroupContextExtensions` (required by all groups)
-        // and `AppDataUpdate` (used by the new app-data path) so this client
-        // can join groups that commit AppDataUpdate proposals. Required-vs-
-        // supported is enforced by the group's RequiredCapabilities, not by
-        // this leaf-node list, so advertising more is always safe.
-        let capabilities = Capabilities::new(
-            None,
-            Some(&[CIPHERSUITE]),
-            Some(&capability_extensions),
-            Some(&[
-                ProposalType::GroupContextExtensions,
-                ProposalType::AppDataUpdate,
-            ]),
-            None,
-        );
+        let capabilities = installation_capabilities();
 
         let kp_builder = KeyPackage::builder()
             .leaf_node_capabilities(capabilities)
@@ -924,6 +877,59 @@ impl XmtpKeyPackageBuilder {
     }
 }
 
+/// The capabilities every installation advertises on its leaf node, whether it
+/// enters a group through a key package or an external commit.
+pub(crate) fn installation_capabilities() -> Capabilities {
+    #[allow(unused_mut)]
+    let mut capability_extensions = vec![
+        ExtensionType::LastResort,
+        ExtensionType::ApplicationId,
+        ExtensionType::ImmutableMetadata,
+        // Advertise AppDataDictionary so the bootstrap commit
+        // (and later AppDataUpdate proposals) are accepted —
+        // OpenMLS rejects commits whose new extension set isn't
+        // covered by every leaf's capabilities. Advertising
+        // unconditionally is the simplest path: required-vs-
+        // supported is enforced by RequiredCapabilities, not by
+        // this leaf-node list. This advertisement also doubles
+        // as the migration-eligibility signal that
+        // `all_members_support_proposals` reads.
+        ExtensionType::AppDataDictionary,
+        ExtensionType::Unknown(GROUP_PERMISSIONS_EXTENSION_ID),
+        ExtensionType::Unknown(MUTABLE_METADATA_EXTENSION_ID),
+        ExtensionType::Unknown(GROUP_MEMBERSHIP_EXTENSION_ID),
+        ExtensionType::Unknown(WELCOME_WRAPPER_ENCRYPTION_EXTENSION_ID),
+        ExtensionType::Unknown(WELCOME_POINTEE_ENCRYPTION_AEAD_TYPES_EXTENSION_ID),
+    ];
+    // Test-only opt-out: tests that simulate an "old client
+    // without AppData support" drop the advertisement so the
+    // member-support check fails for that installation.
+    #[cfg(any(test, feature = "test-utils"))]
+    {
+        if !ENABLE_APP_DATA_DICTIONARY_BROADCAST
+            .try_with(|v| *v)
+            .unwrap_or(true)
+        {
+            capability_extensions.retain(|e| *e != ExtensionType::AppDataDictionary);
+        }
+    }
+    // Advertise both `GroupContextExtensions` (required by all groups)
+    // and `AppDataUpdate` (used by the new app-data path) so this client
+    // can join groups that commit AppDataUpdate proposals. Required-vs-
+    // supported is enforced by the group's RequiredCapabilities, not by
+    // this leaf-node list, so advertising more is always safe.
+    Capabilities::new(
+        None,
+        Some(&[CIPHERSUITE]),
+        Some(&capability_extensions),
+        Some(&[
+            ProposalType::GroupContextExtensions,
+            ProposalType::AppDataUpdate,
+        ]),
+        None,
+    )
+}
+
 /// Serialize the key package hash ref to a bincode friendly format that is compatible with `read` in the KeyStore
 pub(crate) fn serialize_key_package_hash_ref(
     kp: &KeyPackage,
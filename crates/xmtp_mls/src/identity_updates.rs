@@ -71,6 +71,23 @@ impl RetryableError for InstallationDiffError {
     }
 }
 
+/// A point in an inbox's identity history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InboxStatePoint {
+    /// After every identity update with a sequence ID at or below this value
+    SequenceId(i64),
+    /// After every identity update with a server timestamp at or below this value
+    TimestampNs(i64),
+}
+
+/// The change a single identity update made to an inbox's association state
+#[derive(Debug, Clone)]
+pub struct AssociationStateChange {
+    pub sequence_id: i64,
+    pub server_timestamp_ns: i64,
+    pub diff: AssociationStateDiff,
+}
+
 pub struct IdentityUpdates<Context> {
     context: Context,
 }
@@ -122,6 +139,65 @@ pub async fn get_association_state_with_verifier(
     Ok(association_state)
 }
 
+/// Get the association state for a given inbox_id as it was at `at`, using the identity updates
+/// stored in the database. Errors with [`AssociationError::NotCreated`] if the inbox did not exist yet.
+pub async fn get_association_state_at_with_verifier(
+    conn: &impl DbQuery,
+    inbox_id: &str,
+    at: InboxStatePoint,
+    scw_verifier: &impl SmartContractSignatureVerifier,
+) -> Result<AssociationState, ClientError> {
+    let sequence_id = match at {
+        InboxStatePoint::SequenceId(sequence_id) => {
+            conn.get_sequence_id_at(inbox_id, Some(sequence_id), None)?
+        }
+        InboxStatePoint::TimestampNs(timestamp_ns) => {
+            conn.get_sequence_id_at(inbox_id, None, Some(timestamp_ns))?
+        }
+    }
+    .ok_or::<ClientError>(AssociationError::NotCreated.into())?;
+
+    get_association_state_with_verifier(conn, inbox_id, Some(sequence_id), scw_verifier).await
+}
+
+/// Get every change made to the association state of `inbox_id`, one entry per identity update
+/// stored in the database, ordered from oldest to newest
+pub async fn get_association_state_timeline_with_verifier(
+    conn: &impl DbQuery,
+    inbox_id: &str,
+    scw_verifier: &impl SmartContractSignatureVerifier,
+) -> Result<Vec<AssociationStateChange>, ClientError> {
+    let stored_updates = conn.get_identity_updates(inbox_id, None, None)?;
+    let points: Vec<(i64, i64)> = stored_updates
+        .iter()
+        .map(|update| (update.sequence_id, update.server_timestamp_ns))
+        .collect();
+    let unverified_updates = stored_updates
+        .into_iter()
+        .map(StoredIdentityUpdate::to_unverified)
+        .collect::<Result<Vec<UnverifiedIdentityUpdate>, AssociationError>>()?;
+    let updates = verify_updates(unverified_updates, scw_verifier).await?;
+
+    let mut association_state: Option<AssociationState> = None;
+    let mut timeline = Vec::with_capacity(updates.len());
+    for ((sequence_id, server_timestamp_ns), update) in points.into_iter().zip(updates) {
+        let new_state =
+            update.update_state(association_state.clone(), update.client_timestamp_ns)?;
+        let diff = match &association_state {
+            Some(previous_state) => previous_state.diff(&new_state),
+            None => new_state.as_diff(),
+        };
+        timeline.push(AssociationStateChange {
+            sequence_id,
+            server_timestamp_ns,
+            diff,
+        });
+        association_state = Some(new_state);
+    }
+
+    Ok(timeline)
+}
+
 /// Revoke the given installations from the association state for the client's inbox
 pub fn revoke_installations_with_verifier(
     identifier: &Identifier,
@@ -235,6 +311,27 @@ where
         .await
     }
 
+    /// Get the association state for a given inbox_id as it was at `at`
+    pub async fn get_association_state_at(
+        &self,
+        conn: &impl DbQuery,
+        inbox_id: InboxIdRef<'a>,
+        at: InboxStatePoint,
+    ) -> Result<AssociationState, ClientError> {
+        get_association_state_at_with_verifier(conn, inbox_id, at, &self.context.scw_verifier())
+            .await
+    }
+
+    /// Get every change made to the association state of `inbox_id`, oldest first
+    pub async fn get_association_state_timeline(
+        &self,
+        conn: &impl DbQuery,
+        inbox_id: InboxIdRef<'a>,
+    ) -> Result<Vec<AssociationStateChange>, ClientError> {
+        get_association_state_timeline_with_verifier(conn, inbox_id, &self.context.scw_verifier())
+            .await
+    }
+
     /// Calculate the changes between the `starting_sequence_id` and `ending_sequence_id` for the
     /// provided `inbox_id`
     pub(crate) async fn get_association_state_diff(
@@ -745,7 +842,9 @@ pub(crate) mod tests {
 
     use xmtp_common::rand_vec;
 
-    use super::{is_member_of_association_state, load_identity_updates};
+    use super::{InboxStatePoint, is_member_of_association_state, load_identity_updates};
+    use crate::client::ClientError;
+    use xmtp_id::associations::AssociationError;
 
     async fn get_association_state<Context>(
         client: &Client<Context>,
@@ -900,6 +999,71 @@ pub(crate) mod tests {
         assert!(association_state.get(&wallet2_ident.into()).is_some());
     }
 
+    #[rstest::rstest]
+    #[xmtp_common::test]
+    async fn association_state_history() {
+        let wallet_2 = generate_local_wallet();
+
+        tester!(client);
+        let inbox_id = client.inbox_id();
+
+        let mut add_association_request = client
+            .identity_updates()
+            .associate_identity(wallet_2.identifier())
+            .await
+            .unwrap();
+        add_wallet_signature(&mut add_association_request, &wallet_2).await;
+        client
+            .identity_updates()
+            .apply_signature_request(add_association_request)
+            .await
+            .unwrap();
+
+        let timeline = client.inbox_state_timeline(true, inbox_id).await.unwrap();
+        assert_eq!(timeline.len(), 2);
+        let (created, associated) = (&timeline[0], &timeline[1]);
+        assert!(created.sequence_id < associated.sequence_id);
+        assert_eq!(created.diff.new_members.len(), 2);
+        assert_eq!(
+            associated.diff.new_members,
+            vec![wallet_2.member_identifier()]
+        );
+        assert!(associated.diff.removed_members.is_empty());
+
+        let at_creation = client
+            .inbox_state_at(
+                false,
+                inbox_id,
+                InboxStatePoint::SequenceId(created.sequence_id),
+            )
+            .await
+            .unwrap();
+        assert_eq!(at_creation.members().len(), 2);
+        assert!(at_creation.get(&wallet_2.member_identifier()).is_none());
+
+        let latest = client
+            .inbox_state_at(
+                false,
+                inbox_id,
+                InboxStatePoint::TimestampNs(associated.server_timestamp_ns),
+            )
+            .await
+            .unwrap();
+        assert_eq!(latest.members().len(), 3);
+
+        let before_creation = client
+            .inbox_state_at(
+                false,
+                inbox_id,
+                InboxStatePoint::TimestampNs(created.server_timestamp_ns - 1),
+            )
+            .await;
+        assert!(matches!(
+            before_creation,
+            Err(ClientError::Association(AssociationError::NotCreated))
+        ));
+    }
+
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     #[cfg(not(target_arch = "wasm32"))]
     fn cache_association_state() {
@@ -6,6 +6,7 @@ use xmtp_content_types::group_updated::GroupUpdatedCodec;
 use xmtp_content_types::intent::{Intent, IntentCodec};
 use xmtp_content_types::leave_request::LeaveRequestCodec;
 use xmtp_content_types::multi_remote_attachment::MultiRemoteAttachmentCodec;
+use xmtp_content_types::poll::PollCodec;
 use xmtp_content_types::reaction::{LegacyReactionCodec, ReactionCodec};
 use xmtp_content_types::read_receipt::ReadReceiptCodec;
 use xmtp_content_types::remote_attachment::RemoteAttachmentCodec;
@@ -50,6 +51,28 @@ pub struct Markdown {
     pub content: String,
 }
 
+/// A poll option and the number of voters currently selecting it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollOption {
+    pub text: String,
+    pub vote_count: u32,
+}
+
+/// A poll with the tallies of its votes.
+///
+/// Each voter's latest valid vote replaces their earlier ones, so a voter is counted once
+/// per option no matter how many times they change their mind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poll {
+    pub question: String,
+    pub options: Vec<PollOption>,
+    pub allows_multiple_choices: bool,
+    // The number of voters whose latest vote selects at least one option
+    pub total_voters: u32,
+    // The option indexes selected by the current user's latest vote, empty if they haven't voted
+    pub my_vote: Vec<u32>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DeletedBy {
     /// Deleted by the original sender
@@ -74,6 +97,7 @@ pub enum MessageBody {
     Intent(Option<Intent>),
     Actions(Option<Actions>),
     LeaveRequest(LeaveRequest),
+    Poll(Poll),
     /// Placeholder for a message that has been deleted (shown in message lists)
     DeletedMessage {
         deleted_by: DeletedBy,
@@ -81,6 +105,20 @@ pub enum MessageBody {
     Custom(EncodedContent),
 }
 
+/// One version of an edited message's content
+#[derive(Debug, Clone)]
+pub struct MessageEdit {
+    // The ID of the message that introduced this version. For the original content, this is
+    // the ID of the edited message itself.
+    pub message_id: Vec<u8>,
+    // The timestamp this version was sent at in nanoseconds
+    pub sent_at_ns: i64,
+    // The content type of this version
+    pub content_type: ContentTypeId,
+    // The content of this version
+    pub content: MessageBody,
+}
+
 #[derive(Debug, Clone)]
 pub struct DecodedMessageMetadata {
     // The message ID
@@ -103,6 +141,8 @@ pub struct DecodedMessageMetadata {
     pub inserted_at_ns: i64,
     // Timestamp (in NS) after which the message must be deleted
     pub expires_at_ns: Option<i64>,
+    // Whether the message is deleted once it has been viewed
+    pub view_once: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -116,6 +156,21 @@ pub struct DecodedMessage {
     pub reactions: Vec<DecodedMessage>,
     // The number of replies to the message available
     pub num_replies: usize,
+    // Every version of the content, oldest first, starting with the original.
+    // Empty if the message was never edited.
+    pub edit_history: Vec<MessageEdit>,
+}
+
+impl DecodedMessage {
+    /// Whether the sender edited this message after sending it
+    pub fn is_edited(&self) -> bool {
+        !self.edit_history.is_empty()
+    }
+
+    /// Timestamp of the latest edit in nanoseconds, if the message was edited
+    pub fn edited_at_ns(&self) -> Option<i64> {
+        self.edit_history.last().map(|edit| edit.sent_at_ns)
+    }
 }
 
 impl TryFrom<EncodedContent> for MessageBody {
@@ -199,6 +254,23 @@ impl TryFrom<EncodedContent> for MessageBody {
                 let leave_request = LeaveRequestCodec::decode(value)?;
                 Ok(MessageBody::LeaveRequest(leave_request))
             }
+            (PollCodec::TYPE_ID, PollCodec::MAJOR_VERSION) => {
+                let poll = PollCodec::decode(value)?;
+                Ok(MessageBody::Poll(Poll {
+                    question: poll.question,
+                    options: poll
+                        .options
+                        .into_iter()
+                        .map(|text| PollOption {
+                            text,
+                            vote_count: 0,
+                        })
+                        .collect(),
+                    allows_multiple_choices: poll.allows_multiple_choices,
+                    total_voters: 0,
+                    my_vote: Vec::new(),
+                }))
+            }
 
             _ => Err(CodecError::CodecNotFound(content_type.clone()).into()),
         }
@@ -239,6 +311,7 @@ impl TryFrom<StoredGroupMessage> for DecodedMessage {
             content_type: content_type_id,
             inserted_at_ns: value.inserted_at_ns,
             expires_at_ns: value.expire_at_ns,
+            view_once: value.view_once,
         };
 
         // For now, we'll set default values for reactions and replies
@@ -252,6 +325,7 @@ impl TryFrom<StoredGroupMessage> for DecodedMessage {
             fallback_text: fallback,
             reactions,
             num_replies,
+            edit_history: Vec::new(),
         })
     }
 }
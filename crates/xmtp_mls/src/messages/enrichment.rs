@@ -1,14 +1,19 @@
-use crate::messages::decoded_message::{DecodedMessage, DeletedBy, MessageBody};
+use crate::messages::decoded_message::{DecodedMessage, DeletedBy, MessageBody, MessageEdit, Poll};
 use hex::ToHexExt;
+use prost::Message;
 use std::collections::HashMap;
 use thiserror::Error;
 use xmtp_common::{ErrorCode, RetryableError};
+use xmtp_content_types::ContentCodec;
+use xmtp_content_types::edit_message::EditMessageCodec;
+use xmtp_content_types::poll::PollVoteCodec;
 use xmtp_db::DbQuery;
 use xmtp_db::group_message::{
-    ContentType as DbContentType, Deletable, RelationCounts, RelationQuery, StoredGroupMessage,
+    ContentType as DbContentType, Deletable, Editable, RelationCounts, RelationQuery,
+    StoredGroupMessage,
 };
 use xmtp_db::message_deletion::StoredMessageDeletion;
-use xmtp_proto::xmtp::mls::message_contents::ContentTypeId;
+use xmtp_proto::xmtp::mls::message_contents::{ContentTypeId, EncodedContent};
 
 use xmtp_proto::types::GroupId;
 /// Content type ID for deleted message placeholders shown in enriched message lists
@@ -54,6 +59,10 @@ type ReactionMap = HashMap<Vec<u8>, Vec<DecodedMessage>>;
 type ReferencedMessageMap = HashMap<Vec<u8>, (StoredGroupMessage, DecodedMessage)>;
 // Mapping of deletions, keyed by the ID of the deleted message
 type DeletionMap = HashMap<Vec<u8>, StoredMessageDeletion>;
+// Mapping of edits in ascending send order, keyed by the ID of the edited message
+type EditMap = HashMap<Vec<u8>, Vec<StoredGroupMessage>>;
+// Mapping of poll votes, keyed by the ID of the poll being voted on
+type PollVoteMap = HashMap<Vec<u8>, Vec<StoredGroupMessage>>;
 
 /// Validates if a deletion should be applied. Checks group membership and authorization.
 pub(crate) fn is_deletion_valid(
@@ -74,14 +83,154 @@ pub(crate) fn is_deletion_valid(
     }
 
     let is_sender = deletion.deleted_by_inbox_id == message.sender_inbox_id;
-    is_sender || deletion.is_super_admin_deletion
+    is_sender || deletion.is_super_admin_deletion || deletion.is_moderator_deletion
 }
 
+/// Decode the content an EditMessage replaces its original with.
+///
+/// Returns `None` for edits that can't be decoded or that replace the content with a
+/// non-editable content type.
+fn decode_edit(edit: &StoredGroupMessage) -> Option<MessageEdit> {
+    let edited_content = EncodedContent::decode(edit.decrypted_message_bytes.as_slice())
+        .map_err(|e| xmtp_content_types::CodecError::Decode(e.to_string()))
+        .and_then(EditMessageCodec::decode)
+        .inspect_err(|err| {
+            tracing::warn!(
+                message_id = %hex::encode(&edit.id),
+                "Failed to decode EditMessage: {:?}",
+                err
+            )
+        })
+        .ok()?
+        .edited_content?;
+    let content_type = edited_content.r#type.clone()?;
+    if !DbContentType::from(content_type.type_id.clone()).is_editable() {
+        return None;
+    }
+    let content = MessageBody::try_from(edited_content).ok()?;
+
+    Some(MessageEdit {
+        message_id: edit.id.clone(),
+        sent_at_ns: edit.sent_at_ns,
+        content_type,
+        content,
+    })
+}
+
+/// Replace the content of `decoded` with its latest valid edit and record the edit history.
+///
+/// Edits by anyone other than the original sender are ignored.
+fn apply_edits(
+    decoded: &mut DecodedMessage,
+    original: &StoredGroupMessage,
+    edits: Option<&Vec<StoredGroupMessage>>,
+) {
+    let mut versions: Vec<MessageEdit> = edits
+        .into_iter()
+        .flatten()
+        .filter(|edit| edit.is_valid_edit_of(original))
+        .filter_map(decode_edit)
+        .collect();
+    // Relations are loaded in send order; ties are broken by ID so every installation agrees
+    versions.sort_by(|a, b| {
+        a.sent_at_ns
+            .cmp(&b.sent_at_ns)
+            .then_with(|| a.message_id.cmp(&b.message_id))
+    });
+    let Some(latest) = versions.last().cloned() else {
+        return;
+    };
+
+    versions.insert(
+        0,
+        MessageEdit {
+            message_id: original.id.clone(),
+            sent_at_ns: original.sent_at_ns,
+            content_type: decoded.metadata.content_type.clone(),
+            content: decoded.content.clone(),
+        },
+    );
+    decoded.content = latest.content;
+    decoded.metadata.content_type = latest.content_type;
+    decoded.edit_history = versions;
+}
+
+/// Decode the option indexes selected by a PollVote.
+///
+/// Returns `None` for votes that can't be decoded, select an option the poll doesn't have,
+/// or select more than one option of a single-choice poll. An empty selection retracts
+/// the voter's earlier votes.
+fn decode_poll_vote(vote: &StoredGroupMessage, poll: &Poll) -> Option<Vec<u32>> {
+    let mut selection = EncodedContent::decode(vote.decrypted_message_bytes.as_slice())
+        .map_err(|e| xmtp_content_types::CodecError::Decode(e.to_string()))
+        .and_then(PollVoteCodec::decode)
+        .inspect_err(|err| {
+            tracing::warn!(
+                message_id = %hex::encode(&vote.id),
+                "Failed to decode PollVote: {:?}",
+                err
+            )
+        })
+        .ok()?
+        .option_indexes;
+    selection.sort_unstable();
+    selection.dedup();
+
+    let in_range = selection
+        .iter()
+        .all(|index| (*index as usize) < poll.options.len());
+    if !in_range || (!poll.allows_multiple_choices && selection.len() > 1) {
+        return None;
+    }
+    Some(selection)
+}
+
+/// Tally the votes cast on `poll`, counting only each voter's latest valid vote.
+fn tally_poll_votes(
+    poll: &mut Poll,
+    votes: Option<&Vec<StoredGroupMessage>>,
+    self_inbox_id: Option<&str>,
+) {
+    let mut valid_votes: Vec<(&StoredGroupMessage, Vec<u32>)> = votes
+        .into_iter()
+        .flatten()
+        .filter_map(|vote| decode_poll_vote(vote, poll).map(|selection| (vote, selection)))
+        .collect();
+    // Ties are broken by ID so every installation agrees on which vote is latest
+    valid_votes.sort_by(|(a, _), (b, _)| {
+        a.sent_at_ns
+            .cmp(&b.sent_at_ns)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut latest_by_voter: HashMap<&str, Vec<u32>> = HashMap::new();
+    for (vote, selection) in valid_votes {
+        latest_by_voter.insert(vote.sender_inbox_id.as_str(), selection);
+    }
+
+    for (voter, selection) in latest_by_voter {
+        if selection.is_empty() {
+            continue;
+        }
+        for index in &selection {
+            poll.options[*index as usize].vote_count += 1;
+        }
+        poll.total_voters += 1;
+        if self_inbox_id == Some(voter) {
+            poll.my_vote = selection;
+        }
+    }
+}
+
+/// Decode `messages` and attach their reactions, replies, edits, deletions and poll votes.
+///
+/// `self_inbox_id` identifies the viewer, whose own vote is reported on each poll.
 #[xmtp_common::mls_span]
 pub fn enrich_messages(
     conn: impl DbQuery,
     group_id: &GroupId,
     messages: Vec<StoredGroupMessage>,
+    self_inbox_id: Option<&str>,
 ) -> Result<Vec<DecodedMessage>, EnrichMessageError> {
     let initial_message_ids: Vec<&[u8]> = messages.iter().map(|m| m.id.as_ref()).collect();
 
@@ -128,6 +277,20 @@ pub fn enrich_messages(
                     .cloned()
                     .unwrap_or(0);
 
+                apply_edits(
+                    &mut decoded,
+                    &stored_message,
+                    relations.edits.get(&stored_message.id),
+                );
+
+                if let MessageBody::Poll(poll) = &mut decoded.content {
+                    tally_poll_votes(
+                        poll,
+                        relations.poll_votes.get(&stored_message.id),
+                        self_inbox_id,
+                    );
+                }
+
                 // Handle Reply messages - populate in_reply_to field
                 if let MessageBody::Reply(mut reply_body) = decoded.content {
                     let _ = hex::decode(&reply_body.reference_id)
@@ -152,21 +315,25 @@ pub fn enrich_messages(
                                 .map(|(_, decoded)| decoded.clone());
 
                             if let Some(msg) = in_reply_to.as_mut()
-                                && let Some(deletion) = relations.deletions.get(id)
                                 && let Some((stored_msg, _)) = relations.referenced_messages.get(id)
-                                && is_deletion_valid(deletion, stored_msg, group_id)
                             {
-                                let is_sender =
-                                    deletion.deleted_by_inbox_id == stored_msg.sender_inbox_id;
-                                msg.content = MessageBody::DeletedMessage {
-                                    deleted_by: if is_sender {
-                                        DeletedBy::Sender
-                                    } else {
-                                        DeletedBy::Admin(deletion.deleted_by_inbox_id.clone())
-                                    },
-                                };
-                                msg.reactions = Vec::new();
-                                msg.num_replies = 0;
+                                if let Some(deletion) = relations.deletions.get(id)
+                                    && is_deletion_valid(deletion, stored_msg, group_id)
+                                {
+                                    let is_sender =
+                                        deletion.deleted_by_inbox_id == stored_msg.sender_inbox_id;
+                                    msg.content = MessageBody::DeletedMessage {
+                                        deleted_by: if is_sender {
+                                            DeletedBy::Sender
+                                        } else {
+                                            DeletedBy::Admin(deletion.deleted_by_inbox_id.clone())
+                                        },
+                                    };
+                                    msg.reactions = Vec::new();
+                                    msg.num_replies = 0;
+                                } else {
+                                    apply_edits(msg, stored_msg, relations.edits.get(id));
+                                }
                             }
                             reply_body.in_reply_to = in_reply_to.map(Box::new);
                         });
@@ -193,6 +360,8 @@ fn get_relations(
             referenced_messages: HashMap::new(),
             reply_counts: HashMap::new(),
             deletions: HashMap::new(),
+            edits: HashMap::new(),
+            poll_votes: HashMap::new(),
         });
     }
 
@@ -206,6 +375,16 @@ fn get_relations(
         .build()
         .unwrap_or_default();
 
+    let edits_relations_query = RelationQuery::builder()
+        .content_types(Some(vec![DbContentType::EditMessage]))
+        .build()
+        .unwrap_or_default();
+
+    let poll_votes_relations_query = RelationQuery::builder()
+        .content_types(Some(vec![DbContentType::PollVote]))
+        .build()
+        .unwrap_or_default();
+
     let reactions = conn.get_inbound_relations(group_id, message_ids, reactions_relations_query)?;
     let referenced_messages = conn.get_outbound_relations(group_id, reference_ids)?;
     let reply_counts =
@@ -218,11 +397,19 @@ fn get_relations(
     all_ids.extend(reference_ids.iter().map(|id| id.to_vec()));
     let deletions = conn.get_deletions_for_messages(all_ids)?;
 
+    // Edits are loaded for referenced messages too, so replies quote the latest content
+    let edited_ids: Vec<&[u8]> = message_ids.iter().chain(reference_ids).copied().collect();
+    let edits = conn.get_inbound_relations(group_id, &edited_ids, edits_relations_query)?;
+    let poll_votes =
+        conn.get_inbound_relations(group_id, message_ids, poll_votes_relations_query)?;
+
     Ok(GetRelationsResults {
         reactions: get_reactions(reactions),
         referenced_messages: get_referenced_messages(referenced_messages),
         reply_counts,
         deletions: get_deletions(deletions),
+        edits,
+        poll_votes,
     })
 }
 
@@ -231,6 +418,8 @@ struct GetRelationsResults {
     referenced_messages: ReferencedMessageMap,
     reply_counts: RelationCounts,
     deletions: DeletionMap,
+    edits: EditMap,
+    poll_votes: PollVoteMap,
 }
 
 fn get_referenced_messages(messages: HashMap<Vec<u8>, StoredGroupMessage>) -> ReferencedMessageMap {
@@ -33,6 +33,8 @@ fn create_test_message(
         inserted_at_ns: 0,
         should_push: false,
         idempotency_key: 1000.to_string(),
+        expire_after_ns: None,
+        view_once: false,
     }
 }
 
@@ -51,6 +53,7 @@ fn create_test_deletion(
         deleted_by_inbox_id: deleted_by_inbox_id.to_string(),
         is_super_admin_deletion: is_super_admin,
         deleted_at_ns: 2000,
+        is_moderator_deletion: false,
     }
 }
 
@@ -103,6 +106,31 @@ fn test_valid_deletion_by_super_admin() {
     assert!(is_deletion_valid(&deletion, &message, &group_id));
 }
 
+#[test]
+fn test_valid_deletion_by_moderator() {
+    let group_id = GroupId::from([0x01u8; 16]);
+    let message_id = vec![4, 5, 6];
+
+    let message = create_test_message(
+        message_id.clone(),
+        group_id,
+        "original_sender",
+        ContentType::Text,
+        GroupMessageKind::Application,
+    );
+
+    let mut deletion = create_test_deletion(
+        vec![7, 8, 9],
+        group_id,
+        message_id.clone(),
+        "moderator_inbox", // Different person, allowed to delete as a moderator
+        false,
+    );
+    deletion.is_moderator_deletion = true;
+
+    assert!(is_deletion_valid(&deletion, &message, &group_id));
+}
+
 #[test]
 fn test_invalid_deletion_unauthorized() {
     let group_id = GroupId::from([0x01u8; 16]);
@@ -62,7 +62,9 @@ use crate::worker::device_sync::preference_sync::PreferenceUpdate;
 use crate::{
     Client,
     context::XmtpSharedContext,
-    groups::{GroupError, MlsGroup, mls_sync::GroupMessageProcessingError},
+    groups::{
+        GroupError, MlsGroup, ephemeral::EphemeralMessage, mls_sync::GroupMessageProcessingError,
+    },
     messages::decoded_message::DecodedMessage,
     subscriptions::d14n_compat::{V3OrD14n, decode_welcome_message},
 };
@@ -98,6 +100,8 @@ pub enum LocalEvents {
     PreferencesChanged(Vec<PreferenceUpdate>),
     // a message was deleted (contains the decoded message that was deleted)
     MsgsDeleted(Vec<StoredGroupMessage>),
+    // an ephemeral message was received, it is never stored
+    EphemeralMessage(EphemeralMessage),
 }
 
 #[derive(Clone)]
@@ -165,12 +169,23 @@ impl LocalEvents {
             _ => None,
         }
     }
+
+    fn ephemeral_filter(self) -> Option<EphemeralMessage> {
+        match self {
+            Self::EphemeralMessage(msg) => Some(msg),
+            _ => None,
+        }
+    }
 }
 
 pub(crate) trait StreamMessages {
     fn stream_consent_updates(self) -> impl Stream<Item = Result<Vec<StoredConsentRecord>>>;
     fn stream_preference_updates(self) -> impl Stream<Item = Result<Vec<PreferenceUpdate>>>;
     fn stream_message_deletions(self) -> impl Stream<Item = Result<DecodedMessage>>;
+    fn stream_ephemeral_messages(
+        self,
+        group_id: GroupId,
+    ) -> impl Stream<Item = Result<EphemeralMessage>>;
 }
 
 impl StreamMessages for broadcast::Receiver<LocalEvents> {
@@ -205,6 +220,19 @@ impl StreamMessages for broadcast::Receiver<LocalEvents> {
             // this should be rare since the message already in db
             .map(|m| DecodedMessage::try_from(m).map_err(Into::into))
     }
+
+    #[instrument(level = "trace", skip_all)]
+    fn stream_ephemeral_messages(
+        self,
+        group_id: GroupId,
+    ) -> impl Stream<Item = Result<EphemeralMessage>> {
+        BroadcastStream::new(self).filter_map(move |event| async move {
+            xmtp_common::optify!(event, "Missed message due to event queue lag")
+                .and_then(LocalEvents::ephemeral_filter)
+                .filter(|msg| msg.group_id == group_id && !msg.is_expired())
+                .map(Result::Ok)
+        })
+    }
 }
 
 #[derive(thiserror::Error, Debug, ErrorCode)]
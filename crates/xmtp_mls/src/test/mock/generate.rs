@@ -181,5 +181,7 @@ pub fn generate_stored_msg(
         inserted_at_ns: 0,
         should_push: true,
         idempotency_key: 100.to_string(),
+        expire_after_ns: None,
+        view_once: false,
     }
 }
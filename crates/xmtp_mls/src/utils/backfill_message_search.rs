@@ -0,0 +1,77 @@
+use tracing::info;
+use xmtp_db::diesel::prelude::*;
+use xmtp_db::user_preferences::StoredUserPreferences;
+use xmtp_db::{ConnectionExt, DbConnection, StorageError, prelude::QueryMessageSearch};
+
+const BATCH_SIZE: i64 = 200;
+
+/// Index messages that were stored before full-text search was introduced.
+pub async fn perform<C>(db: DbConnection<C>)
+where
+    C: ConnectionExt,
+{
+    if let Err(err) = perform_inner(db).await {
+        tracing::error!("Message search backfill failed: {err:?}");
+    }
+}
+
+async fn perform_inner<C>(db: DbConnection<C>) -> Result<(), StorageError>
+where
+    C: ConnectionExt,
+{
+    let prefs = StoredUserPreferences::load(&db)?;
+    if prefs.message_search_backfilled {
+        info!("Message search index has already been backfilled. Skipping.");
+        return Ok(());
+    }
+
+    let mut cursor = None;
+    while let Some(last_id) = db.index_messages_for_search(cursor.as_deref(), BATCH_SIZE)? {
+        cursor = Some(last_id);
+        tokio::task::yield_now().await;
+    }
+
+    db.raw_query(|conn| {
+        xmtp_db::diesel::update(xmtp_db::schema::user_preferences::table)
+            .set(xmtp_db::schema::user_preferences::message_search_backfilled.eq(true))
+            .execute(conn)
+    })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tester;
+    use xmtp_content_types::{ContentCodec, encoded_content_to_bytes, text::TextCodec};
+    use xmtp_db::group_message::MsgQueryArgs;
+    use xmtp_db::schema::message_search_content;
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_backfill_indexes_existing_messages() {
+        tester!(alix);
+
+        let group = alix.create_group(None, None)?;
+        let text = TextCodec::encode("needle in a haystack".to_string())?;
+        group.send_message_optimistic(&encoded_content_to_bytes(text), Default::default())?;
+
+        // Forget the index and the backfill marker, as on a database from before search
+        alix.db().raw_query(|conn| {
+            xmtp_db::diesel::delete(message_search_content::table).execute(conn)?;
+            xmtp_db::diesel::update(xmtp_db::schema::user_preferences::table)
+                .set(xmtp_db::schema::user_preferences::message_search_backfilled.eq(false))
+                .execute(conn)
+        })?;
+        assert!(
+            alix.search_messages("needle", &MsgQueryArgs::default())?
+                .is_empty()
+        );
+
+        perform(alix.db()).await;
+
+        let hits = alix.search_messages("needle", &MsgQueryArgs::default())?;
+        assert_eq!(hits.len(), 1);
+        assert!(StoredUserPreferences::load(alix.db())?.message_search_backfilled);
+    }
+}
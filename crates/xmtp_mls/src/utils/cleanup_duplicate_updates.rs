@@ -75,7 +75,7 @@ where
                     sent_after_ns = Some(msg.sent_at_ns);
                 }
 
-                let msgs = enrich_messages(&db, &group.id, msgs)?;
+                let msgs = enrich_messages(&db, &group.id, msgs, None)?;
 
                 for msg in msgs {
                     let MessageBody::GroupUpdated(update) = msg.content else {
@@ -161,6 +161,8 @@ mod tests {
                 inserted_at_ns: 0,
                 should_push: true,
                 idempotency_key: String::new(),
+                expire_after_ns: None,
+                view_once: false,
             }
         };
 
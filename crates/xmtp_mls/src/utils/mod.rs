@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::groups::validated_commit::LibXMTPVersion;
 
+pub mod backfill_message_search;
 #[cfg(feature = "bench")]
 pub mod bench;
 pub mod cleanup_duplicate_updates;
@@ -74,6 +75,7 @@ pub mod id {
                 Some(Content::V1(V1 {
                     content: message,
                     idempotency_key: key,
+                    ..
                 })),
         } = envelope
         else {
@@ -33,6 +33,7 @@ use passkey::{
 };
 use public_suffix::PublicSuffixList;
 use std::{
+    collections::HashMap,
     ops::Deref,
     path::{Path, PathBuf},
     sync::{
@@ -52,7 +53,11 @@ use xmtp_archive::{ArchiveImporter, exporter::ArchiveExporter};
 use xmtp_common::StreamHandle;
 use xmtp_configuration::DockerUrls;
 use xmtp_configuration::{KEY_PACKAGE_ROTATION_INTERVAL_NS, LOCALHOST};
-use xmtp_cryptography::{signature::SignatureError, utils::generate_local_wallet};
+use xmtp_cryptography::{
+    hash::sha256_array,
+    signature::SignatureError,
+    utils::{generate_local_wallet, generate_seeded_wallet},
+};
 #[cfg(not(target_arch = "wasm32"))]
 use xmtp_db::NativeDb;
 #[cfg(target_arch = "wasm32")]
@@ -135,6 +140,42 @@ where
     }
 }
 
+/// Wallets handed out so far, per test
+static TESTER_WALLETS: LazyLock<Mutex<HashMap<String, u64>>> = LazyLock::new(Default::default);
+
+/// A wallet for a new tester. While the test's traffic is recorded or replayed
+/// (see [`xmtp_api_d14n::RECORD_MODE_ENV`]) the n-th wallet of a test is seeded from the
+/// test name and `n`, so every run registers the same inboxes and the identity
+/// requests match the recording. The first wallet also seeds
+/// [`xmtp_cryptography::rand`], so installation keys repeat as well. Otherwise the
+/// wallet is random.
+pub fn tester_wallet() -> PrivateKeySigner {
+    if std::env::var(xmtp_api_d14n::RECORD_MODE_ENV).is_err() {
+        return generate_local_wallet();
+    }
+    let name = std::thread::current()
+        .name()
+        .unwrap_or("unnamed")
+        .to_string();
+    let index = {
+        let mut wallets = TESTER_WALLETS.lock();
+        let index = wallets.entry(name.clone()).or_default();
+        *index += 1;
+        *index
+    };
+    let seed = |label: &str| {
+        let digest = sha256_array(label.as_bytes());
+        let mut seed = [0u8; 8];
+        seed.copy_from_slice(&digest[..8]);
+        u64::from_le_bytes(seed)
+    };
+    // the first tester of a test also seeds the rng its installation keys come from
+    if index == 1 {
+        xmtp_cryptography::rand::seed(seed(&name));
+    }
+    generate_seeded_wallet(seed(&format!("{name}:{index}")))
+}
+
 #[derive(QueryableByName)]
 struct TableName {
     #[diesel(sql_type = diesel::sql_types::Text)]
@@ -151,7 +192,7 @@ pub trait LocalTester {
 #[xmtp_common::async_trait]
 impl LocalTester for Tester<PrivateKeySigner, FullXmtpClient> {
     async fn new() -> Self {
-        let wallet = generate_local_wallet();
+        let wallet = tester_wallet();
         Tester::new_with_owner(wallet).await
     }
 
@@ -445,7 +486,7 @@ impl TesterBuilder<PrivateKeySigner> {
 impl Default for TesterBuilder<PrivateKeySigner> {
     fn default() -> Self {
         Self {
-            owner: generate_local_wallet(),
+            owner: tester_wallet(),
             sync_mode: DeviceSyncMode::Disabled,
             fork_recovery_opts: None,
             wait_for_init: true,
@@ -11,6 +11,7 @@ pub use xmtp_archive::*;
 use xmtp_db::{
     ConnectionExt, StoreOrIgnore,
     consent_record::StoredConsentRecord,
+    draft::StoredDraft,
     group::{ConversationType, DmIdExt, GroupMembershipState},
     group_message::StoredGroupMessage,
     prelude::*,
@@ -62,6 +63,23 @@ pub async fn insert_importer(
     Ok(())
 }
 
+/// Import a full archive followed by the incremental archives chained to it, in order.
+/// Pass the metadata of the last archive already imported as `applied` to continue a chain.
+pub async fn insert_importer_chain(
+    importers: &mut [ArchiveImporter],
+    applied: Option<&BackupMetadata>,
+    context: &impl XmtpSharedContext,
+) -> Result<(), DeviceSyncError> {
+    let chain: Vec<&BackupMetadata> = importers.iter().map(|i| &i.metadata).collect();
+    check_chain(applied, &chain)?;
+
+    for importer in importers {
+        insert_importer(importer, context).await?;
+    }
+
+    Ok(())
+}
+
 fn insert(
     element: BackupElement,
     context: &impl XmtpSharedContext,
@@ -161,6 +179,11 @@ fn insert(
             let message: StoredGroupMessage = message.try_into()?;
             message.store_or_ignore(&context.db())?;
         }
+        Element::Draft(draft) => {
+            // Keep a local draft that is newer than the archived one.
+            let draft: StoredDraft = draft.try_into()?;
+            context.db().insert_newer_draft(&draft)?;
+        }
         _ => {}
     }
 
@@ -224,6 +247,7 @@ mod tests {
                 BackupElementSelection::Consent,
             ],
             exclude_disappearing_messages: false,
+            ..Default::default()
         };
         let export = {
             let mut file = vec![];
@@ -287,6 +311,7 @@ mod tests {
                 BackupElementSelection::Consent,
             ],
             exclude_disappearing_messages: false,
+            ..Default::default()
         };
         let export = {
             let mut file = vec![];
@@ -351,6 +376,7 @@ mod tests {
                 BackupElementSelection::Consent,
             ],
             exclude_disappearing_messages: false,
+            ..Default::default()
         };
 
         let key = vec![7; 32];
@@ -449,6 +475,7 @@ mod tests {
                 BackupElementSelection::Consent,
             ],
             exclude_disappearing_messages: false,
+            ..Default::default()
         };
 
         let key = xmtp_common::rand_vec::<32>();
@@ -617,6 +644,7 @@ mod tests {
                 BackupElementSelection::Consent,
             ],
             exclude_disappearing_messages: false,
+            ..Default::default()
         };
         let export = {
             let mut file = vec![];
@@ -658,4 +686,495 @@ mod tests {
         let restored_legacy = alix2.group(&legacy_group.group_id)?;
         assert_eq!(restored_legacy.group_name()?, "legacy name");
     }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_group_filtered_archive() {
+        use futures::StreamExt;
+        use xmtp_db::consent_record::ConsentState;
+        use xmtp_proto::xmtp::device_sync::backup_element::Element;
+
+        tester!(alix, disable_workers);
+        tester!(bo, disable_workers);
+
+        let kept = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        let excluded = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        let denied = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        for group in [&kept, &excluded, &denied] {
+            group.send_message(b"hi", Default::default()).await?;
+        }
+        denied.update_consent_state(ConsentState::Denied)?;
+
+        let key = vec![7; 32];
+        let opts = ArchiveOptions {
+            elements: vec![BackupElementSelection::Messages],
+            exclude_group_ids: vec![excluded.group_id.to_vec()],
+            consent_states: vec![ConsentState::Allowed, ConsentState::Unknown],
+            ..Default::default()
+        };
+        let export = {
+            let mut file = vec![];
+            let mut exporter = ArchiveExporter::new(opts, alix.db(), &key);
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+
+        let reader = Box::pin(BufReader::new(Cursor::new(export)));
+        let mut importer = ArchiveImporter::load(reader, &key).await?;
+        assert!(importer.metadata.is_partial());
+        assert!(importer.metadata.include_group_ids.is_empty());
+        assert_eq!(
+            importer.metadata.exclude_group_ids,
+            vec![excluded.group_id.to_vec()]
+        );
+        assert_eq!(
+            importer.metadata.consent_states,
+            vec![ConsentState::Allowed, ConsentState::Unknown]
+        );
+
+        let mut group_ids = vec![];
+        let mut message_group_ids = vec![];
+        while let Some(element) = importer.next().await {
+            match element?.element? {
+                Element::Group(group) => group_ids.push(group.id),
+                Element::GroupMessage(message) => message_group_ids.push(message.group_id),
+                _ => {}
+            }
+        }
+        assert_eq!(group_ids, vec![kept.group_id.to_vec()]);
+        assert!(!message_group_ids.is_empty());
+        assert!(
+            message_group_ids
+                .iter()
+                .all(|id| *id == kept.group_id.to_vec())
+        );
+
+        // An include filter narrows the export down to just that conversation.
+        let opts = ArchiveOptions {
+            elements: vec![BackupElementSelection::Messages],
+            include_group_ids: vec![denied.group_id.to_vec()],
+            ..Default::default()
+        };
+        let export = {
+            let mut file = vec![];
+            let mut exporter = ArchiveExporter::new(opts, alix.db(), &key);
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+
+        tester!(alix2, from: alix);
+        let reader = Box::pin(BufReader::new(Cursor::new(export)));
+        let mut importer = ArchiveImporter::load(reader, &key).await?;
+        assert_eq!(
+            importer.metadata.include_group_ids,
+            vec![denied.group_id.to_vec()]
+        );
+        insert_importer(&mut importer, &alix2.context).await?;
+
+        assert!(alix2.db().find_group(&denied.group_id)?.is_some());
+        assert!(alix2.db().find_group(&kept.group_id)?.is_none());
+        assert!(alix2.db().find_group(&excluded.group_id)?.is_none());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_group_filtered_archive_consent() {
+        use futures::StreamExt;
+        use xmtp_db::consent_record::{ConsentState, ConsentType, QueryConsentRecord};
+        use xmtp_proto::xmtp::device_sync::backup_element::Element;
+
+        tester!(alix, disable_workers);
+        tester!(bo, disable_workers);
+
+        let kept = alix.create_group(None, None)?;
+        let excluded = alix.create_group(None, None)?;
+        let denied = alix.create_group(None, None)?;
+        let unlisted = alix.create_group(None, None)?;
+        denied.update_consent_state(ConsentState::Denied)?;
+        alix.context.db().insert_or_replace_consent_records(&[
+            StoredConsentRecord::new(
+                ConsentType::InboxId,
+                ConsentState::Allowed,
+                bo.inbox_id().to_string(),
+            ),
+            StoredConsentRecord::new(
+                ConsentType::InboxId,
+                ConsentState::Denied,
+                "denied_inbox".to_string(),
+            ),
+        ])?;
+
+        let key = vec![7; 32];
+        let opts = ArchiveOptions {
+            elements: vec![BackupElementSelection::Consent],
+            include_group_ids: vec![
+                kept.group_id.to_vec(),
+                excluded.group_id.to_vec(),
+                denied.group_id.to_vec(),
+            ],
+            exclude_group_ids: vec![excluded.group_id.to_vec()],
+            consent_states: vec![ConsentState::Allowed],
+            ..Default::default()
+        };
+        let export = {
+            let mut file = vec![];
+            let mut exporter = ArchiveExporter::new(opts, alix.db(), &key);
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+
+        let reader = Box::pin(BufReader::new(Cursor::new(export)));
+        let mut importer = ArchiveImporter::load(reader, &key).await?;
+        let mut entities = vec![];
+        while let Some(element) = importer.next().await {
+            if let Element::Consent(consent) = element?.element? {
+                entities.push(consent.entity);
+            }
+        }
+        entities.sort();
+        let mut expected = vec![hex::encode(&kept.group_id), bo.inbox_id().to_string()];
+        expected.sort();
+        // Excluded, denied and unlisted conversations, and denied inboxes, are left out
+        assert_eq!(entities, expected);
+        assert!(!entities.contains(&hex::encode(&unlisted.group_id)));
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_incremental_archive_chain() {
+        use futures::StreamExt;
+        use xmtp_db::group_message::GroupMessageKind;
+        use xmtp_proto::xmtp::device_sync::backup_element::Element;
+
+        tester!(alix, disable_workers);
+        tester!(bo, disable_workers);
+
+        let old_group = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        old_group.send_message(b"old", Default::default()).await?;
+
+        let key = vec![7; 32];
+        let opts = ArchiveOptions {
+            elements: vec![BackupElementSelection::Messages],
+            ..Default::default()
+        };
+        let base = {
+            let mut file = vec![];
+            let mut exporter = ArchiveExporter::new(opts.clone(), alix.db(), &key);
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+        let reader = Box::pin(BufReader::new(Cursor::new(base.clone())));
+        let base_metadata = ArchiveImporter::load(reader, &key).await?.metadata;
+        assert!(!base_metadata.is_incremental());
+        assert!(!base_metadata.archive_id.is_empty());
+
+        let new_group = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        new_group.send_message(b"new", Default::default()).await?;
+        old_group.send_message(b"newer", Default::default()).await?;
+
+        let delta = {
+            let mut file = vec![];
+            let mut exporter = ArchiveExporter::incremental(opts, &base_metadata, alix.db(), &key)?;
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+
+        // The delta only holds what changed since the base archive.
+        let reader = Box::pin(BufReader::new(Cursor::new(delta.clone())));
+        let mut importer = ArchiveImporter::load(reader, &key).await?;
+        assert!(importer.metadata.is_incremental());
+        assert_eq!(
+            importer.metadata.previous_archive_id.as_ref(),
+            Some(&base_metadata.archive_id)
+        );
+        assert_eq!(
+            importer.metadata.since_ns,
+            Some(base_metadata.exported_at_ns)
+        );
+        let mut group_ids = vec![];
+        while let Some(element) = importer.next().await {
+            match element?.element? {
+                Element::Group(group) => group_ids.push(group.id),
+                Element::GroupMessage(message) => {
+                    assert!(message.sent_at_ns > base_metadata.exported_at_ns);
+                }
+                _ => {}
+            }
+        }
+        group_ids.sort();
+        let mut expected = vec![old_group.group_id.to_vec(), new_group.group_id.to_vec()];
+        expected.sort();
+        assert_eq!(group_ids, expected);
+
+        let load = |bytes: Vec<u8>| {
+            let key = key.clone();
+            async move {
+                let reader = Box::pin(BufReader::new(Cursor::new(bytes)));
+                ArchiveImporter::load(reader, &key).await
+            }
+        };
+
+        // A delta can't be imported without its base.
+        tester!(alix2, from: alix);
+        let mut importers = vec![load(delta.clone()).await?];
+        let result = insert_importer_chain(&mut importers, None, &alix2.context).await;
+        assert!(matches!(
+            result,
+            Err(DeviceSyncError::Archive(ArchiveError::BrokenChain(_)))
+        ));
+        assert!(alix2.db().find_group(&new_group.group_id)?.is_none());
+
+        let mut importers = vec![load(base).await?, load(delta).await?];
+        insert_importer_chain(&mut importers, None, &alix2.context).await?;
+
+        let old_messages = alix2
+            .group(&old_group.group_id)?
+            .find_messages(&MsgQueryArgs {
+                kind: Some(GroupMessageKind::Application),
+                ..Default::default()
+            })?;
+        assert_eq!(old_messages.len(), 2);
+        assert!(alix2.db().find_group(&new_group.group_id)?.is_some());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_passphrase_archive() {
+        tester!(alix, disable_workers);
+        tester!(bo, disable_workers);
+
+        let group = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        group.send_message(b"hi", Default::default()).await?;
+
+        let opts = ArchiveOptions {
+            elements: vec![BackupElementSelection::Messages],
+            ..Default::default()
+        };
+        let export = {
+            let mut file = vec![];
+            let mut exporter =
+                ArchiveExporter::with_passphrase(opts, alix.db(), "correct horse").await?;
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+        assert_eq!(
+            u16::from_le_bytes([export[0], export[1]]),
+            xmtp_archive::BACKUP_VERSION
+        );
+
+        let load = |passphrase: &'static str| {
+            let reader = Box::pin(BufReader::new(Cursor::new(export.clone())));
+            ArchiveImporter::load_with_passphrase(reader, passphrase)
+        };
+        assert!(load("battery staple").await.is_err());
+        let reader = Box::pin(BufReader::new(Cursor::new(export.clone())));
+        assert!(matches!(
+            ArchiveImporter::load(reader, &[0; 32]).await,
+            Err(ArchiveError::PassphraseRequired)
+        ));
+
+        tester!(alix2, from: alix);
+        let mut importer = load("correct horse").await?;
+        assert_eq!(
+            importer.metadata.backup_version,
+            xmtp_archive::BACKUP_VERSION
+        );
+        insert_importer(&mut importer, &alix2.context).await?;
+        assert!(alix2.db().find_group(&group.group_id)?.is_some());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_incremental_passphrase_archive() {
+        tester!(alix, disable_workers);
+        tester!(bo, disable_workers);
+
+        let old_group = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        old_group.send_message(b"old", Default::default()).await?;
+
+        let opts = ArchiveOptions {
+            elements: vec![BackupElementSelection::Messages],
+            ..Default::default()
+        };
+        let base = {
+            let mut file = vec![];
+            let mut exporter =
+                ArchiveExporter::with_passphrase(opts.clone(), alix.db(), "correct horse").await?;
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+        let load = |bytes: Vec<u8>| {
+            let reader = Box::pin(BufReader::new(Cursor::new(bytes)));
+            ArchiveImporter::load_with_passphrase(reader, "correct horse")
+        };
+        let base_metadata = load(base.clone()).await?.metadata;
+
+        let new_group = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        new_group.send_message(b"new", Default::default()).await?;
+
+        let delta = {
+            let mut file = vec![];
+            let mut exporter = ArchiveExporter::incremental_with_passphrase(
+                opts,
+                &base_metadata,
+                alix.db(),
+                "correct horse",
+            )
+            .await?;
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+        let reader = Box::pin(BufReader::new(Cursor::new(delta.clone())));
+        assert!(matches!(
+            ArchiveImporter::load(reader, &[0; 32]).await,
+            Err(ArchiveError::PassphraseRequired)
+        ));
+
+        tester!(alix2, from: alix);
+        let mut importers = vec![load(base).await?, load(delta).await?];
+        assert!(importers[1].metadata.is_incremental());
+        assert_eq!(
+            importers[1].metadata.backup_version,
+            xmtp_archive::BACKUP_VERSION
+        );
+        insert_importer_chain(&mut importers, None, &alix2.context).await?;
+        assert!(alix2.db().find_group(&old_group.group_id)?.is_some());
+        assert!(alix2.db().find_group(&new_group.group_id)?.is_some());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_verify_archive() {
+        tester!(alix, disable_workers);
+        tester!(bo, disable_workers);
+
+        let group = alix
+            .create_group_with_members(&[bo.inbox_id()], None, None)
+            .await?;
+        group.send_message(b"one", Default::default()).await?;
+        group.send_message(b"two", Default::default()).await?;
+
+        let key = vec![7; 32];
+        let opts = ArchiveOptions {
+            elements: vec![
+                BackupElementSelection::Messages,
+                BackupElementSelection::Consent,
+            ],
+            ..Default::default()
+        };
+        let export = {
+            let mut file = vec![];
+            let mut exporter = ArchiveExporter::new(opts, alix.db(), &key);
+            exporter.read_to_end(&mut file).await?;
+            file
+        };
+        // Raw key archives keep the version 0 header, so older importers can still read them.
+        assert_eq!(
+            u16::from_le_bytes([export[0], export[1]]),
+            xmtp_archive::RAW_KEY_BACKUP_VERSION
+        );
+        let load = |bytes: Vec<u8>| {
+            let reader = Box::pin(BufReader::new(Cursor::new(bytes)));
+            ArchiveImporter::load(reader, &key)
+        };
+
+        let messages = alix
+            .db()
+            .group_messages_paged(&MsgQueryArgs::default(), 0)?;
+        let report = load(export.clone()).await?.verify().await;
+        assert!(report.is_valid(), "{:?}", report.failures);
+        assert!(report.manifest_verified);
+        assert_eq!(report.group_count, 1);
+        assert_eq!(report.group_message_count, messages.len() as u64);
+        assert_eq!(
+            report.consent_count,
+            alix.db().consent_records()?.len() as u64
+        );
+        assert_eq!(
+            report.earliest_message_ns,
+            messages.iter().map(|m| m.sent_at_ns).min()
+        );
+        assert_eq!(
+            report.latest_message_ns,
+            messages.iter().map(|m| m.sent_at_ns).max()
+        );
+
+        // Verifying doesn't touch the database.
+        tester!(alix2, from: alix);
+        assert!(alix2.db().find_group(&group.group_id)?.is_none());
+
+        // A truncated archive is reported, and fails to import.
+        let truncated = export[..export.len() - 20].to_vec();
+        let report = load(truncated.clone()).await?.verify().await;
+        assert!(!report.is_valid());
+        assert!(!report.manifest_verified);
+        let result = insert_importer(&mut load(truncated).await?, &alix2.context).await;
+        assert!(result.is_err());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_draft_archive() {
+        use xmtp_proto::xmtp::mls::message_contents::EncodedContent;
+
+        tester!(alix, disable_workers);
+
+        let group = alix.create_group(None, None)?;
+        let content = EncodedContent {
+            content: b"unsent".to_vec(),
+            ..Default::default()
+        };
+        group.set_draft(content.clone(), None)?;
+
+        let key = vec![7; 32];
+        let export = |elements| {
+            let opts = ArchiveOptions {
+                elements,
+                ..Default::default()
+            };
+            ArchiveExporter::new(opts, alix.db(), &key)
+        };
+        let mut without_drafts = vec![];
+        export(vec![BackupElementSelection::Messages])
+            .read_to_end(&mut without_drafts)
+            .await?;
+        let mut with_drafts = vec![];
+        export(vec![
+            BackupElementSelection::Messages,
+            BackupElementSelection::Drafts,
+        ])
+        .read_to_end(&mut with_drafts)
+        .await?;
+        let load = |bytes: Vec<u8>| {
+            let reader = Box::pin(BufReader::new(Cursor::new(bytes)));
+            ArchiveImporter::load(reader, &key)
+        };
+
+        // Drafts are left out unless they are selected.
+        tester!(alix2, from: alix);
+        insert_importer(&mut load(without_drafts).await?, &alix2.context).await?;
+        assert!(alix2.db().get_draft(&group.group_id)?.is_none());
+
+        insert_importer(&mut load(with_drafts.clone()).await?, &alix2.context).await?;
+        let alix2_group = alix2.group(&group.group_id)?;
+        assert_eq!(alix2_group.get_draft()??.content, content);
+
+        // A newer local draft is not overwritten by the archived one.
+        let newer = EncodedContent {
+            content: b"newer".to_vec(),
+            ..Default::default()
+        };
+        alix2_group.set_draft(newer.clone(), None)?;
+        insert_importer(&mut load(with_drafts).await?, &alix2.context).await?;
+        assert_eq!(alix2_group.get_draft()??.content, newer);
+    }
 }
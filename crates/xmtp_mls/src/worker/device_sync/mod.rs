@@ -328,12 +328,13 @@ where
             &content_bytes,
             send_message_opts::SendMessageOpts {
                 should_push: false,
-                idempotency_key: None,
+                ..Default::default()
             },
-            |key| PlaintextEnvelope {
+            |message| PlaintextEnvelope {
                 content: Some(Content::V1(V1 {
                     content: content_bytes.clone(),
-                    idempotency_key: key.to_string(),
+                    idempotency_key: message.idempotency_key.clone(),
+                    ..Default::default()
                 })),
             },
         )?;
@@ -1,6 +1,8 @@
 use super::*;
 use xmtp_common::time::now_ns;
 use xmtp_db::consent_record::StoredConsentRecord;
+use xmtp_db::conversation_settings::StoredConversationSettings;
+use xmtp_db::read_cursor::StoredReadCursor;
 use xmtp_db::user_preferences::{HmacKey, StoredUserPreferences};
 use xmtp_proto::ConversionError;
 use xmtp_proto::xmtp::device_sync::content::HmacKeyUpdate as HmacKeyUpdateProto;
@@ -13,6 +15,8 @@ use xmtp_proto::xmtp::device_sync::content::{
 pub enum PreferenceUpdate {
     Consent(StoredConsentRecord),
     Hmac { key: Vec<u8>, cycled_at_ns: i64 },
+    ReadCursor(StoredReadCursor),
+    ConversationSettings(StoredConversationSettings),
 }
 
 impl<Context> DeviceSyncClient<Context>
@@ -31,6 +35,12 @@ where
         updates.iter().for_each(|update| match update {
             PreferenceUpdate::Consent(_) => self.metrics.increment_metric(SyncMetric::ConsentSent),
             PreferenceUpdate::Hmac { .. } => self.metrics.increment_metric(SyncMetric::HmacSent),
+            PreferenceUpdate::ReadCursor(_) => {
+                self.metrics.increment_metric(SyncMetric::ReadCursorSent)
+            }
+            PreferenceUpdate::ConversationSettings(_) => self
+                .metrics
+                .increment_metric(SyncMetric::ConversationSettingsSent),
         });
 
         Ok(updates)
@@ -81,6 +91,20 @@ pub(super) fn store_preference_updates(
                 changed.push(PreferenceUpdate::Hmac { key, cycled_at_ns });
                 handle.increment_metric(SyncMetric::HmacReceived);
             }
+            UpdateProto::ReadCursor(read_cursor) => {
+                let read_cursor: StoredReadCursor = read_cursor.try_into()?;
+                if conn.insert_newer_read_cursor(&read_cursor)? {
+                    changed.push(PreferenceUpdate::ReadCursor(read_cursor));
+                }
+                handle.increment_metric(SyncMetric::ReadCursorReceived);
+            }
+            UpdateProto::ConversationSettings(settings) => {
+                let settings: StoredConversationSettings = settings.try_into()?;
+                if conn.insert_newer_conversation_settings(&settings)? {
+                    changed.push(PreferenceUpdate::ConversationSettings(settings));
+                }
+                handle.increment_metric(SyncMetric::ConversationSettingsReceived);
+            }
         }
     }
 
@@ -104,6 +128,10 @@ impl TryFrom<UpdateProto> for PreferenceUpdate {
             UpdateProto::Hmac(HmacKeyUpdateProto { key, cycled_at_ns }) => {
                 Self::Hmac { key, cycled_at_ns }
             }
+            UpdateProto::ReadCursor(read_cursor) => Self::ReadCursor(read_cursor.try_into()?),
+            UpdateProto::ConversationSettings(settings) => {
+                Self::ConversationSettings(settings.try_into()?)
+            }
         };
         Ok(update)
     }
@@ -117,6 +145,12 @@ impl From<PreferenceUpdate> for PreferenceUpdateProto {
                 PreferenceUpdate::Hmac { key, cycled_at_ns } => {
                     UpdateProto::Hmac(HmacKeyUpdateProto { key, cycled_at_ns })
                 }
+                PreferenceUpdate::ReadCursor(read_cursor) => {
+                    UpdateProto::ReadCursor(read_cursor.into())
+                }
+                PreferenceUpdate::ConversationSettings(settings) => {
+                    UpdateProto::ConversationSettings(settings.into())
+                }
             }),
         }
     }
@@ -124,6 +158,7 @@ impl From<PreferenceUpdate> for PreferenceUpdateProto {
 
 #[cfg(test)]
 mod tests {
+    use crate::groups::send_message_opts::SendMessageOpts;
     use crate::{tester, worker::device_sync::worker::SyncMetric};
     use xmtp_db::user_preferences::StoredUserPreferences;
 
@@ -181,4 +216,72 @@ mod tests {
         let new_pref_a = StoredUserPreferences::load(amal_a.context.db())?;
         assert_ne!(pref_a.hmac_key, new_pref_a.hmac_key);
     }
+
+    #[rstest::rstest]
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_read_cursor_sync() {
+        tester!(amal_a, sync_worker);
+        tester!(amal_b, from: amal_a);
+        tester!(bo);
+
+        amal_a.test_has_same_sync_group_as(&amal_b).await?;
+
+        let bo_group = bo
+            .create_group_with_members(&[amal_a.inbox_id()], None, None)
+            .await?;
+        let message_id = bo_group
+            .send_message(b"hello", SendMessageOpts::default())
+            .await?;
+
+        amal_a.sync_all_welcomes_and_groups(None).await?;
+        amal_b.sync_all_welcomes_and_groups(None).await?;
+        amal_a.group(&bo_group.group_id)?.mark_read(&message_id)?;
+        amal_a
+            .worker()
+            .register_interest(SyncMetric::ReadCursorSent, 1)
+            .wait()
+            .await?;
+
+        amal_b.sync_all_device_sync_groups().await?;
+        amal_b
+            .worker()
+            .register_interest(SyncMetric::ReadCursorReceived, 1)
+            .wait()
+            .await?;
+
+        let cursor = amal_b.group(&bo_group.group_id)?.read_cursor()??;
+        assert_eq!(cursor.message_id, message_id);
+    }
+
+    #[rstest::rstest]
+    #[xmtp_common::test(unwrap_try = true)]
+    async fn test_conversation_settings_sync() {
+        tester!(amal_a, sync_worker);
+        tester!(amal_b, from: amal_a);
+
+        amal_a.test_has_same_sync_group_as(&amal_b).await?;
+
+        let group = amal_a.create_group(None, None)?;
+        amal_b.sync_all_welcomes_and_groups(None).await?;
+
+        group.set_pinned(true)?;
+        group.set_archived(true)?;
+        amal_a
+            .worker()
+            .register_interest(SyncMetric::ConversationSettingsSent, 2)
+            .wait()
+            .await?;
+
+        amal_b.sync_all_device_sync_groups().await?;
+        amal_b
+            .worker()
+            .register_interest(SyncMetric::ConversationSettingsReceived, 2)
+            .wait()
+            .await?;
+
+        let settings = amal_b.group(&group.group_id)?.conversation_settings()?;
+        assert!(settings.pinned);
+        assert!(settings.archived);
+        assert_eq!(settings, group.conversation_settings()?);
+    }
 }
@@ -285,6 +285,12 @@ where
         updates.iter().for_each(|update| match update {
             PreferenceUpdate::Consent(_) => self.metrics.increment_metric(SyncMetric::ConsentSent),
             PreferenceUpdate::Hmac { .. } => self.metrics.increment_metric(SyncMetric::HmacSent),
+            PreferenceUpdate::ReadCursor(_) => {
+                self.metrics.increment_metric(SyncMetric::ReadCursorSent)
+            }
+            PreferenceUpdate::ConversationSettings(_) => self
+                .metrics
+                .increment_metric(SyncMetric::ConversationSettingsSent),
         });
         Ok(())
     }
@@ -747,6 +753,10 @@ pub enum SyncMetric {
     HmacReceived,
     ConsentSent,
     ConsentReceived,
+    ReadCursorSent,
+    ReadCursorReceived,
+    ConversationSettingsSent,
+    ConversationSettingsReceived,
 }
 
 impl WorkerMetrics<SyncMetric> {
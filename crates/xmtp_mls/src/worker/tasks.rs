@@ -10,7 +10,7 @@ use prost::Message;
 use std::sync::Arc;
 use xmtp_common::Event;
 use xmtp_configuration::KEY_PACKAGE_ROTATION_INTERVAL_NS;
-use xmtp_db::prelude::{QueryIdentity, QueryKeyPackageHistory};
+use xmtp_db::prelude::{QueryGroupMessage, QueryIdentity, QueryKeyPackageHistory};
 use xmtp_db::tasks::{NewTask as DbNewTask, QueryTasks, Task as DbTask, TaskDataHash};
 use xmtp_db::{StorageError, diesel};
 use xmtp_macro::log_event;
@@ -447,6 +447,11 @@ where
             Some(xmtp_proto::xmtp::mls::database::task::Task::AddMissingInstallations(add)) => {
                 Self::run_add_missing_installations(task, add, context).await?;
             }
+            Some(xmtp_proto::xmtp::mls::database::task::Task::PublishScheduledMessage(
+                scheduled,
+            )) => {
+                Self::publish_scheduled_message(task, scheduled, context).await?;
+            }
             Some(xmtp_proto::xmtp::mls::database::task::Task::KpLiveness(_)) => {
                 // The variant exists in the regenerated protos but nothing in
                 // this crate schedules it yet, so a row can only appear from a
@@ -555,6 +560,54 @@ where
             Err(e) => Err(e.into()),
         }
     }
+    /// Run a `PublishScheduledMessage` task: load the group and publish the
+    /// stored message. A message that is gone was cancelled, and one that is
+    /// already published was sent by an earlier attempt, so both finish the task.
+    async fn publish_scheduled_message(
+        task: &DbTask,
+        scheduled: xmtp_proto::xmtp::mls::database::PublishScheduledMessage,
+        context: &Context,
+    ) -> Result<(), TaskWorkerError> {
+        // A malformed group_id can never succeed — drop the task, don't retry.
+        let Ok(group_id) = xmtp_proto::types::GroupId::try_from(scheduled.group_id.as_slice())
+        else {
+            tracing::warn!(
+                "Task {} has a malformed group_id for PublishScheduledMessage. Deleting.",
+                task.id
+            );
+            context.db().delete_task(task.id)?;
+            return Ok(());
+        };
+        if context
+            .db()
+            .get_group_message(&scheduled.message_id)?
+            .is_none()
+        {
+            tracing::debug!(
+                "Task {} targets a scheduled message that no longer exists. Deleting.",
+                task.id
+            );
+            context.db().delete_task(task.id)?;
+            return Ok(());
+        }
+        match crate::mls_store::MlsStore::new(context.clone()).group(&group_id) {
+            Ok(group) => {
+                // No-op once published, so retries are safe.
+                group.publish_stored_message(&scheduled.message_id).await?;
+                Ok(())
+            }
+            Err(crate::mls_store::MlsStoreError::NotFound(_)) => {
+                tracing::debug!(
+                    "Task {} targets a group that no longer exists. Deleting.",
+                    task.id
+                );
+                context.db().delete_task(task.id)?;
+                Ok(())
+            }
+            // A DB/connection error is transient — let it retry.
+            Err(e) => Err(e.into()),
+        }
+    }
     async fn process_welcome_pointer(
         task: &DbTask,
         welcome_pointer: xmtp_proto::xmtp::mls::message_contents::WelcomePointer,
@@ -82,6 +82,8 @@ impl ComponentId {
     pub const APP_DATA: Self = Self(0x8009);
     pub const MIN_SUPPORTED_PROTOCOL_VERSION: Self = Self(0x800A);
     pub const COMMIT_LOG_SIGNER: Self = Self(0x800B);
+    pub const PINNED_MESSAGES: Self = Self(0x800C);
+    pub const EXTERNAL_INVITES: Self = Self(0x800D);
 
     // === Well-Known Immutable XMTP Component IDs (counting down from 0xBFFF) ===
 
@@ -246,6 +248,10 @@ mod tests {
         assert!(!ComponentId::MIN_SUPPORTED_PROTOCOL_VERSION.is_immutable());
         assert!(ComponentId::COMMIT_LOG_SIGNER.is_xmtp_range());
         assert!(!ComponentId::COMMIT_LOG_SIGNER.is_immutable());
+        assert!(ComponentId::PINNED_MESSAGES.is_xmtp_range());
+        assert!(!ComponentId::PINNED_MESSAGES.is_immutable());
+        assert!(ComponentId::EXTERNAL_INVITES.is_xmtp_range());
+        assert!(!ComponentId::EXTERNAL_INVITES.is_immutable());
 
         // Immutable XMTP
         assert!(ComponentId::CONVERSATION_TYPE.is_immutable());
@@ -340,6 +346,8 @@ mod tests {
             ComponentId::GROUP_NAME,
             ComponentId::MIN_SUPPORTED_PROTOCOL_VERSION,
             ComponentId::COMMIT_LOG_SIGNER,
+            ComponentId::PINNED_MESSAGES,
+            ComponentId::EXTERNAL_INVITES,
             ComponentId::CONVERSATION_TYPE,
             ComponentId::DM_MEMBERS,
             ComponentId::ONESHOT_MESSAGE,
@@ -409,6 +409,9 @@ impl ComponentRegistry {
             // wrapper itself is not on the constrained-component allowlist.
             Some(MetadataPolicyKind::AndCondition(_)) => false,
             Some(MetadataPolicyKind::AnyCondition(_)) => false,
+            // Membership gates don't restrict the actor at all
+            Some(MetadataPolicyKind::InboxAllowlist(_)) => false,
+            Some(MetadataPolicyKind::IdentifierRequirement(_)) => false,
             None => false,
         }
     }
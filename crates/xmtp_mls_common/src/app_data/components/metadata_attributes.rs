@@ -1,11 +1,11 @@
-//! [`Component`] impls for the eight `GroupMutableMetadata`-backed
+//! [`Component`] impls for the ten `GroupMutableMetadata`-backed
 //! attribute components.
 //!
 //! Three flavours, distinguished by the typed `Value` they expose:
 //!
 //! - **String** (`GROUP_NAME`, `GROUP_DESCRIPTION`, `GROUP_IMAGE_URL`,
-//!   `APP_DATA`, `MIN_SUPPORTED_PROTOCOL_VERSION`): `Value = String`,
-//!   wire bytes are UTF-8.
+//!   `APP_DATA`, `MIN_SUPPORTED_PROTOCOL_VERSION`, `PINNED_MESSAGES`,
+//!   `EXTERNAL_INVITES`): `Value = String`, wire bytes are UTF-8.
 //! - **Big-endian `i64`** (`MESSAGE_DISAPPEAR_FROM_NS`,
 //!   `MESSAGE_DISAPPEAR_IN_NS`): `Value = i64`, wire bytes are exactly
 //!   8 bytes (`i64::to_be_bytes`). The legacy `GroupMutableMetadata`
@@ -227,6 +227,8 @@ passthrough_string_component!(
     MinSupportedProtocolVersionComponent,
     ComponentId::MIN_SUPPORTED_PROTOCOL_VERSION
 );
+passthrough_string_component!(PinnedMessagesComponent, ComponentId::PINNED_MESSAGES);
+passthrough_string_component!(ExternalInvitesComponent, ComponentId::EXTERNAL_INVITES);
 
 be_i64_component!(
     MessageDisappearFromNsComponent,
@@ -468,6 +470,8 @@ mod tests {
             ComponentId::MESSAGE_DISAPPEAR_IN_NS
         );
         assert_eq!(CommitLogSignerComponent::ID, ComponentId::COMMIT_LOG_SIGNER);
+        assert_eq!(PinnedMessagesComponent::ID, ComponentId::PINNED_MESSAGES);
+        assert_eq!(ExternalInvitesComponent::ID, ComponentId::EXTERNAL_INVITES);
     }
 
     #[xmtp_common::test(unwrap_try = true)]
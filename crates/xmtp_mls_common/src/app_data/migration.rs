@@ -227,6 +227,7 @@ fn build_registry(
     // enforcement.
     let known: std::collections::HashSet<&'static str> = metadata_field_registry_mapping()
         .iter()
+        .chain(optional_metadata_field_registry_mapping())
         .map(|(f, _, _)| f.as_str())
         .collect();
     for key in policy_set.update_metadata_policy.keys() {
@@ -275,6 +276,26 @@ fn build_registry(
         )?;
     }
 
+    // Fields added after the bootstrap encoding froze are registered
+    // only when the PolicySet names them, so groups that never carried
+    // one synthesize exactly what already-shipped receivers expect.
+    for (field, component_id, component_type) in optional_metadata_field_registry_mapping() {
+        let Some(policy) = policy_set.update_metadata_policy.get(field.as_str()) else {
+            continue;
+        };
+        registry.set(
+            *component_id,
+            new_component_metadata(
+                ComponentPermissions {
+                    insert_policy: Some(policy.clone()),
+                    update_policy: Some(policy.clone()),
+                    delete_policy: Some(metadata_policy(MetadataBasePolicy::AllowIfSuperAdmin)),
+                },
+                *component_type,
+            ),
+        )?;
+    }
+
     // COMMIT_LOG_SIGNER: super-admin-only regardless of PolicySet
     // shape. The field DOES change post-creation (see
     // `Group::update_commit_log_signer`), but its enforcement on the
@@ -420,6 +441,26 @@ fn metadata_field_registry_mapping() -> &'static [(MetadataField, ComponentId, C
     ]
 }
 
+/// Mutable scalar metadata fields that postdate the frozen bootstrap
+/// encoding. Unlike [`metadata_field_registry_mapping`], these get a
+/// registry entry only when the group's `update_metadata_policy` has a
+/// key for them, and are seeded only when the legacy GMM has a value.
+fn optional_metadata_field_registry_mapping()
+-> &'static [(MetadataField, ComponentId, ComponentType)] {
+    &[
+        (
+            MetadataField::PinnedMessageIds,
+            ComponentId::PINNED_MESSAGES,
+            ComponentType::String,
+        ),
+        (
+            MetadataField::ExternalInvites,
+            ComponentId::EXTERNAL_INVITES,
+            ComponentType::String,
+        ),
+    ]
+}
+
 fn metadata_policy(base: MetadataBasePolicy) -> MetadataPolicyProto {
     MetadataPolicyProto {
         kind: Some(MetadataPolicyKind::Base(base as i32)),
@@ -454,6 +495,7 @@ fn admin_list_policy_to_metadata_policy(
 /// Convert a legacy `MembershipPolicy` to a `MetadataPolicy`.
 /// `AllowIfAdminOrSuperAdmin` collapses to `AllowIfAdmin` because
 /// `MetadataPolicy::AllowIfAdmin` already means "admin or super admin".
+/// Inbox gates carry over unchanged.
 /// Combinators and unknown base values fail loud rather than silently
 /// collapsing to Deny.
 fn membership_policy_to_metadata_policy(
@@ -474,6 +516,14 @@ fn membership_policy_to_metadata_policy(
             };
             Ok(metadata_policy(mapped))
         }
+        Some(MembershipPolicyKind::InboxAllowlist(allowlist)) => Ok(MetadataPolicyProto {
+            kind: Some(MetadataPolicyKind::InboxAllowlist(*allowlist)),
+        }),
+        Some(MembershipPolicyKind::IdentifierRequirement(requirement)) => Ok(MetadataPolicyProto {
+            kind: Some(MetadataPolicyKind::IdentifierRequirement(
+                requirement.clone(),
+            )),
+        }),
         Some(MembershipPolicyKind::AndCondition(_))
         | Some(MembershipPolicyKind::AnyCondition(_))
         | None => Err(MigrationError::UnknownMembershipPolicy(None)),
@@ -580,7 +630,10 @@ pub fn synthesize_canonical_subset_from_extensions(
     // bytes, COMMIT_LOG_SIGNER expects 32) and emitting absent
     // entries here would only pollute the dict with values readers
     // would surface as `MissingExtension` anyway.
-    for (field, component_id, _) in metadata_field_registry_mapping() {
+    for (field, component_id, _) in metadata_field_registry_mapping()
+        .iter()
+        .chain(optional_metadata_field_registry_mapping())
+    {
         if let Some(s) = gmm.attributes.get(field.as_str()) {
             strict.insert(
                 *component_id,
@@ -1206,6 +1259,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn synthesis_registers_optional_fields_only_when_policy_present() {
+        let registry = synthesize_registry_from_policy_set(&minimal_default_policy_set()).unwrap();
+        assert!(!registry.contains(&ComponentId::PINNED_MESSAGES));
+
+        let mut policy_set = minimal_default_policy_set();
+        policy_set.update_metadata_policy.insert(
+            MetadataField::PinnedMessageIds.to_string(),
+            metadata_policy(MetadataBasePolicy::Allow),
+        );
+        let registry = synthesize_registry_from_policy_set(&policy_set).unwrap();
+        let meta = registry
+            .get(&ComponentId::PINNED_MESSAGES)
+            .unwrap()
+            .unwrap();
+        assert_eq!(meta.component_type, ComponentType::String as i32);
+    }
+
     #[test]
     fn synthesis_deterministic_bytes() {
         // Bit-identical output from two calls on the same input is the
@@ -24,9 +24,10 @@ use crate::app_data::{
     components::{
         inbox_id_set::{AdminListComponent, DmMembersComponent, SuperAdminListComponent},
         metadata_attributes::{
-            AppDataComponent, CommitLogSignerComponent, GroupDescriptionComponent,
-            GroupImageUrlComponent, GroupNameComponent, MessageDisappearFromNsComponent,
-            MessageDisappearInNsComponent, MinSupportedProtocolVersionComponent,
+            AppDataComponent, CommitLogSignerComponent, ExternalInvitesComponent,
+            GroupDescriptionComponent, GroupImageUrlComponent, GroupNameComponent,
+            MessageDisappearFromNsComponent, MessageDisappearInNsComponent,
+            MinSupportedProtocolVersionComponent, PinnedMessagesComponent,
         },
         tls_map_components::{ComponentRegistryComponent, GroupMembershipComponent},
     },
@@ -143,6 +144,8 @@ pub static WELL_KNOWN: &[(ComponentId, &'static dyn ErasedComponent)] = &[
         &MinSupportedProtocolVersionComponent,
     ),
     (ComponentId::COMMIT_LOG_SIGNER, &CommitLogSignerComponent),
+    (ComponentId::PINNED_MESSAGES, &PinnedMessagesComponent),
+    (ComponentId::EXTERNAL_INVITES, &ExternalInvitesComponent),
     (ComponentId::DM_MEMBERS, &DmMembersComponent),
 ];
 
@@ -224,6 +227,8 @@ mod tests {
                 ComponentType::String,
             ),
             (ComponentId::COMMIT_LOG_SIGNER, ComponentType::Bytes),
+            (ComponentId::PINNED_MESSAGES, ComponentType::String),
+            (ComponentId::EXTERNAL_INVITES, ComponentType::String),
             (ComponentId::DM_MEMBERS, ComponentType::TlsSetInboxId),
         ];
         for (id, expected_type) in cases {
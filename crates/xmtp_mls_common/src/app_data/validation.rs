@@ -217,6 +217,16 @@ fn evaluate_policy_proto(
             }
             PolicyOutcome::Deny
         }
+        // Membership gates look at the added inbox, which commit validation
+        // checks. They don't restrict the actor writing the membership.
+        Some(MetadataPolicyKind::InboxAllowlist(_))
+        | Some(MetadataPolicyKind::IdentifierRequirement(_)) => {
+            if change.component_id == ComponentId::GROUP_MEMBERSHIP {
+                PolicyOutcome::Allow
+            } else {
+                PolicyOutcome::Invalid
+            }
+        }
         None => PolicyOutcome::Invalid,
     }
 }
@@ -233,6 +243,10 @@ fn evaluate_base_policy(base: i32, actor: ActorAuthority) -> PolicyOutcome {
             PolicyOutcome::from_bool(actor.is_admin || actor.is_super_admin)
         }
         MetadataBasePolicy::AllowIfSuperAdmin => PolicyOutcome::from_bool(actor.is_super_admin),
+        // The dictionary carries no moderator list, so only admins qualify
+        MetadataBasePolicy::AllowIfModerator => {
+            PolicyOutcome::from_bool(actor.is_admin || actor.is_super_admin)
+        }
         MetadataBasePolicy::Unspecified => PolicyOutcome::Invalid,
     }
 }
@@ -69,6 +69,8 @@ pub enum MetadataField {
     MinimumSupportedProtocolVersion,
     CommitLogSigner,
     AppData,
+    PinnedMessageIds,
+    ExternalInvites,
 }
 
 impl MetadataField {
@@ -84,6 +86,8 @@ impl MetadataField {
             // Uses SUPER_ADMIN_METADATA_PREFIX ("_") to make this field super-admin only
             MetadataField::CommitLogSigner => "_commit_log_signer",
             MetadataField::AppData => "app_data",
+            MetadataField::PinnedMessageIds => "pinned_message_ids",
+            MetadataField::ExternalInvites => "external_invites",
         }
     }
 }
@@ -116,6 +120,25 @@ impl MessageDisappearingSettings {
     }
 }
 
+/// Decodes the `pinned_message_ids` attribute: hex message ids separated by commas,
+/// in the order they were pinned. Entries that are not valid hex are skipped.
+pub fn decode_pinned_message_ids(value: &str) -> Vec<Vec<u8>> {
+    value
+        .split(',')
+        .filter_map(|id| hex::decode(id).ok())
+        .filter(|id| !id.is_empty())
+        .collect()
+}
+
+/// Encodes message ids into the `pinned_message_ids` attribute format.
+pub fn encode_pinned_message_ids(message_ids: &[Vec<u8>]) -> String {
+    message_ids
+        .iter()
+        .map(hex::encode)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 /// Represents the mutable metadata for a group.
 ///
 /// This struct is stored as an MLS Unknown Group Context Extension.
@@ -130,6 +153,10 @@ pub struct GroupMutableMetadata {
     /// List of super admin inbox IDs for this group.
     /// See `GroupMutablePermissions` for more details on super admin permissions.
     pub super_admin_list: Vec<String>,
+    /// List of moderator inbox IDs for this group.
+    /// Moderators rank between members and admins: policies can allow them to remove members,
+    /// and they can delete messages sent by others.
+    pub moderator_list: Vec<String>,
 }
 
 impl GroupMutableMetadata {
@@ -143,9 +170,16 @@ impl GroupMutableMetadata {
             attributes,
             admin_list,
             super_admin_list,
+            moderator_list: vec![],
         }
     }
 
+    /// Sets the moderator list.
+    pub fn with_moderator_list(mut self, moderator_list: Vec<String>) -> Self {
+        self.moderator_list = moderator_list;
+        self
+    }
+
     /// Creates a new GroupMutableMetadata instance with default values.
     /// The creator is automatically added as a super admin.
     /// See `GroupMutablePermissions` for more details on super admin permissions.
@@ -194,11 +228,7 @@ impl GroupMutableMetadata {
 
         let admin_list = vec![];
         let super_admin_list = vec![creator_inbox_id.clone()];
-        Self {
-            attributes,
-            admin_list,
-            super_admin_list,
-        }
+        Self::new(attributes, admin_list, super_admin_list)
     }
 
     // Admin / super admin is not needed for a DM
@@ -242,11 +272,7 @@ impl GroupMutableMetadata {
 
         let admin_list = vec![];
         let super_admin_list = vec![];
-        Self {
-            attributes,
-            admin_list,
-            super_admin_list,
-        }
+        Self::new(attributes, admin_list, super_admin_list)
     }
 
     /// Returns a vector of supported metadata fields.
@@ -261,6 +287,8 @@ impl GroupMutableMetadata {
             MetadataField::MessageDisappearInNS,
             MetadataField::MinimumSupportedProtocolVersion,
             MetadataField::AppData,
+            MetadataField::PinnedMessageIds,
+            MetadataField::ExternalInvites,
         ]
     }
 
@@ -274,6 +302,11 @@ impl GroupMutableMetadata {
         self.super_admin_list.contains(inbox_id)
     }
 
+    /// Checks if the given inbox ID is a moderator.
+    pub fn is_moderator(&self, inbox_id: &String) -> bool {
+        self.moderator_list.contains(inbox_id)
+    }
+
     /// Retrieves the commit log signer secret from the metadata attributes.
     /// Returns None if the field is not present or if hex decoding fails.
     pub fn commit_log_signer(&self) -> Option<Secret> {
@@ -298,6 +331,10 @@ impl TryFrom<GroupMutableMetadata> for Vec<u8> {
             super_admin_list: Some(InboxesProto {
                 inbox_ids: value.super_admin_list,
             }),
+            // Left unset when empty so groups without moderators keep their existing encoding
+            moderator_list: (!value.moderator_list.is_empty()).then_some(InboxesProto {
+                inbox_ids: value.moderator_list,
+            }),
         };
         proto_val.encode(&mut buf)?;
 
@@ -330,11 +367,16 @@ impl TryFrom<GroupMutableMetadataProto> for GroupMutableMetadata {
             .ok_or(GroupMutableMetadataError::MissingMetadataField)?
             .inbox_ids;
 
-        Ok(Self::new(
-            value.attributes.clone(),
-            admin_list,
-            super_admin_list,
-        ))
+        // Groups created before moderators existed have no moderator list
+        let moderator_list = value
+            .moderator_list
+            .map(|inboxes| inboxes.inbox_ids)
+            .unwrap_or_default();
+
+        Ok(
+            Self::new(value.attributes.clone(), admin_list, super_admin_list)
+                .with_moderator_list(moderator_list),
+        )
     }
 }
 
@@ -445,6 +487,14 @@ pub const METADATA_FIELD_COMPONENT_MAP: &[(
         MetadataField::AppData,
         super::app_data::component_id::ComponentId::APP_DATA,
     ),
+    (
+        MetadataField::PinnedMessageIds,
+        super::app_data::component_id::ComponentId::PINNED_MESSAGES,
+    ),
+    (
+        MetadataField::ExternalInvites,
+        super::app_data::component_id::ComponentId::EXTERNAL_INVITES,
+    ),
 ];
 
 /// Production migration predicate over raw extensions: the group is
@@ -650,6 +700,40 @@ mod tests {
         assert!(bad_metadata.commit_log_signer().is_none());
     }
 
+    #[test]
+    fn test_pinned_message_ids_round_trip() {
+        let ids = vec![vec![1u8; 32], vec![2u8; 32]];
+        let encoded = encode_pinned_message_ids(&ids);
+        assert_eq!(decode_pinned_message_ids(&encoded), ids);
+
+        assert!(decode_pinned_message_ids("").is_empty());
+        assert_eq!(
+            decode_pinned_message_ids(&format!("{encoded},not_hex")),
+            ids
+        );
+    }
+
+    #[test]
+    fn test_moderator_list_round_trip() {
+        let metadata = GroupMutableMetadata::new(HashMap::new(), vec![], vec!["creator".into()]);
+        let bytes: Vec<u8> = metadata.clone().try_into().unwrap();
+        // Metadata without moderators encodes exactly as it did before moderators existed
+        assert!(
+            GroupMutableMetadataProto::decode(bytes.as_slice())
+                .unwrap()
+                .moderator_list
+                .is_none()
+        );
+        assert_eq!(GroupMutableMetadata::try_from(&bytes).unwrap(), metadata);
+
+        let metadata = metadata.with_moderator_list(vec!["moderator".into()]);
+        let bytes: Vec<u8> = metadata.clone().try_into().unwrap();
+        let decoded = GroupMutableMetadata::try_from(&bytes).unwrap();
+        assert_eq!(decoded, metadata);
+        assert!(decoded.is_moderator(&"moderator".to_string()));
+        assert!(!decoded.is_moderator(&"creator".to_string()));
+    }
+
     #[xmtp_common::test]
     fn test_lossy_merge_applies_good_fields_and_reports_bad_ones() {
         use super::super::app_data::component_id::ComponentId;
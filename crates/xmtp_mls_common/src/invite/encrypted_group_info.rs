@@ -0,0 +1,211 @@
+//! Encryption envelope for the [`EncryptedGroupInfoBlob`] proto.
+//!
+//! A member serializes an `MlsMessageOut(GroupInfo)`, wraps it with the
+//! invite's symmetric key under a fresh nonce and publishes the resulting
+//! blob to the welcome topic at the invite's [`destination`], which the
+//! invite's `service_pointer` carries. A joiner fetches the blobs there,
+//! [`validate`]s the envelope and [`decrypt`]s it with the key from the
+//! [`ExternalInvitePayload`] it scanned.
+//!
+//! The blob's `epoch` and `group_state_hash` are plaintext so that blobs can
+//! be ordered without the key. They are not bound into the AEAD, so joiners
+//! must check them against the decrypted GroupInfo before using it.
+//!
+//! [`EncryptedGroupInfoBlob`]: xmtp_proto::xmtp::mls::message_contents::EncryptedGroupInfoBlob
+//! [`ExternalInvitePayload`]: xmtp_proto::xmtp::mls::message_contents::ExternalInvitePayload
+
+use openmls::prelude::AeadType;
+use thiserror::Error;
+use xmtp_proto::xmtp::mls::message_contents::{
+    EncryptedGroupInfoBlob, EncryptedGroupInfoBlobV1,
+    encrypted_group_info_blob::Version as EncryptedGroupInfoBlobVersion,
+};
+
+use super::payload::{NONCE_LEN, SYMMETRIC_KEY_LEN, generate_nonce};
+use crate::mls_ext::payload_encryption::{
+    UnwrapPayloadError, WrapPayloadError, unwrap_payload_symmetric, wrap_payload_symmetric,
+};
+
+/// Errors returned when building or opening an [`EncryptedGroupInfoBlob`].
+#[derive(Debug, Error)]
+pub enum EncryptedGroupInfoError {
+    /// The blob's `version` oneof carries a variant this build does not
+    /// recognize, or is unset entirely.
+    #[error("unsupported or missing encrypted group info blob version")]
+    UnsupportedVersion,
+    /// `nonce` was not exactly [`NONCE_LEN`] bytes.
+    #[error("nonce must be exactly {NONCE_LEN} bytes (got {0})")]
+    InvalidNonceLength(usize),
+    /// `symmetric_key` was not exactly [`SYMMETRIC_KEY_LEN`] bytes.
+    #[error("symmetric_key must be exactly {SYMMETRIC_KEY_LEN} bytes (got {0})")]
+    InvalidSymmetricKeyLength(usize),
+    /// The blob's `expires_at_ns` is in the past.
+    #[error("encrypted group info expired at {expires_at_ns}")]
+    Expired {
+        /// The blob's expiry, in ns since the UNIX epoch.
+        expires_at_ns: u64,
+    },
+    #[error(transparent)]
+    Wrap(#[from] WrapPayloadError),
+    #[error(transparent)]
+    Unwrap(#[from] UnwrapPayloadError),
+}
+
+/// Domain separation label for [`destination`].
+const DESTINATION_LABEL: &[u8] = b"XMTP_INVITE_GROUP_INFO";
+
+/// The welcome topic blobs for an invite are published to. It is derived from
+/// the invite's key, so every member can publish a new blob after a commit and
+/// only invite holders can find them.
+pub fn destination(symmetric_key: &[u8; SYMMETRIC_KEY_LEN]) -> [u8; 32] {
+    xmtp_cryptography::hash::sha256_array(&[DESTINATION_LABEL, symmetric_key].concat())
+}
+
+/// Encrypt a TLS-serialized `MlsMessageOut(GroupInfo)` under `symmetric_key`
+/// with a freshly generated nonce.
+///
+/// * `epoch` / `group_state_hash` — plaintext metadata describing the
+///   wrapped GroupInfo, see the proto docs for how the service uses them.
+/// * `expires_at_ns` — wall-clock expiry of the blob. `0` means no expiry.
+pub fn encrypt(
+    group_info: &[u8],
+    symmetric_key: &[u8; SYMMETRIC_KEY_LEN],
+    epoch: u64,
+    group_state_hash: Vec<u8>,
+    expires_at_ns: u64,
+) -> Result<EncryptedGroupInfoBlob, EncryptedGroupInfoError> {
+    let nonce = generate_nonce();
+    let ciphertext = wrap_payload_symmetric(
+        group_info,
+        AeadType::ChaCha20Poly1305,
+        symmetric_key,
+        &nonce,
+    )?;
+    Ok(EncryptedGroupInfoBlob {
+        version: Some(EncryptedGroupInfoBlobVersion::V1(
+            EncryptedGroupInfoBlobV1 {
+                nonce: nonce.to_vec(),
+                ciphertext,
+                epoch,
+                group_state_hash,
+                expires_at_ns,
+            },
+        )),
+    })
+}
+
+/// Validate that `blob.version` carries a recognised variant with a
+/// well-formed nonce. Unknown variants are rejected (fail closed).
+pub fn validate(
+    blob: &EncryptedGroupInfoBlob,
+) -> Result<&EncryptedGroupInfoBlobV1, EncryptedGroupInfoError> {
+    let v1 = match &blob.version {
+        Some(EncryptedGroupInfoBlobVersion::V1(v1)) => v1,
+        None => return Err(EncryptedGroupInfoError::UnsupportedVersion),
+    };
+    if v1.nonce.len() != NONCE_LEN {
+        return Err(EncryptedGroupInfoError::InvalidNonceLength(v1.nonce.len()));
+    }
+    Ok(v1)
+}
+
+/// Decrypt a validated blob, returning the TLS-serialized
+/// `MlsMessageOut(GroupInfo)`.
+///
+/// Blobs whose `expires_at_ns` is at or before `now_ns` are refused even if
+/// the service is still serving them.
+pub fn decrypt(
+    blob: &EncryptedGroupInfoBlobV1,
+    symmetric_key: &[u8],
+    now_ns: u64,
+) -> Result<Vec<u8>, EncryptedGroupInfoError> {
+    if symmetric_key.len() != SYMMETRIC_KEY_LEN {
+        return Err(EncryptedGroupInfoError::InvalidSymmetricKeyLength(
+            symmetric_key.len(),
+        ));
+    }
+    if blob.expires_at_ns != 0 && blob.expires_at_ns <= now_ns {
+        return Err(EncryptedGroupInfoError::Expired {
+            expires_at_ns: blob.expires_at_ns,
+        });
+    }
+    Ok(unwrap_payload_symmetric(
+        &blob.ciphertext,
+        AeadType::ChaCha20Poly1305,
+        symmetric_key,
+        &blob.nonce,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::invite::payload::generate_symmetric_key;
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn round_trip() {
+        let key = generate_symmetric_key();
+        let blob = encrypt(b"group info", &key, 7, vec![1, 2, 3], 0)?;
+        let v1 = validate(&blob)?;
+        assert_eq!(v1.epoch, 7);
+        assert_eq!(v1.group_state_hash, vec![1, 2, 3]);
+        assert_eq!(decrypt(v1, &key, 1)?, b"group info".to_vec());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn fresh_nonce_per_encryption() {
+        let key = generate_symmetric_key();
+        let first = encrypt(b"group info", &key, 1, vec![], 0)?;
+        let second = encrypt(b"group info", &key, 1, vec![], 0)?;
+        assert_ne!(validate(&first)?.nonce, validate(&second)?.nonce);
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn wrong_key_fails() {
+        let blob = encrypt(b"group info", &generate_symmetric_key(), 1, vec![], 0)?;
+        let result = decrypt(validate(&blob)?, &generate_symmetric_key(), 1);
+        assert!(matches!(result, Err(EncryptedGroupInfoError::Unwrap(_))));
+    }
+
+    #[xmtp_common::test]
+    fn destination_is_stable_per_key() {
+        let key = generate_symmetric_key();
+        assert_eq!(destination(&key), destination(&key));
+        assert_ne!(destination(&key), destination(&generate_symmetric_key()));
+        assert_ne!(destination(&key).as_slice(), key.as_slice());
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn expired_blob_is_refused() {
+        let key = generate_symmetric_key();
+        let blob = encrypt(b"group info", &key, 1, vec![], 100)?;
+        let v1 = validate(&blob)?;
+        assert!(decrypt(v1, &key, 99).is_ok());
+        assert!(matches!(
+            decrypt(v1, &key, 100),
+            Err(EncryptedGroupInfoError::Expired { expires_at_ns: 100 })
+        ));
+    }
+
+    #[xmtp_common::test(unwrap_try = true)]
+    fn validate_rejects_missing_version_and_bad_nonce() {
+        let blob = EncryptedGroupInfoBlob { version: None };
+        assert!(matches!(
+            validate(&blob),
+            Err(EncryptedGroupInfoError::UnsupportedVersion)
+        ));
+
+        let blob = EncryptedGroupInfoBlob {
+            version: Some(EncryptedGroupInfoBlobVersion::V1(
+                EncryptedGroupInfoBlobV1 {
+                    nonce: vec![0; NONCE_LEN - 1],
+                    ..Default::default()
+                },
+            )),
+        };
+        assert!(matches!(
+            validate(&blob),
+            Err(EncryptedGroupInfoError::InvalidNonceLength(len)) if len == NONCE_LEN - 1
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+//! The outstanding invites a group carries in its `external_invites`
+//! metadata attribute.
+//!
+//! Each invite is an [`ExternalCommitPolicyEntry`] plus the inbox that
+//! issued it and the epoch of the commit that recorded it. Entries are
+//! stored as `{hex(entry)}:{inbox_id}:{epoch}` separated by commas. External
+//! commits are only accepted when their AAD names the `external_group_id` of
+//! an [active](GroupInvite::is_active) entry.
+//!
+//! [`ExternalCommitPolicyEntry`]: xmtp_proto::xmtp::mls::message_contents::ExternalCommitPolicyEntry
+
+use prost::Message;
+use xmtp_proto::xmtp::mls::message_contents::{
+    ExternalCommitPolicyEntry, ExternalCommitPolicyV1,
+    external_commit_policy_entry::Version as ExternalCommitPolicyVersion,
+};
+
+/// An invite issued by a group member, as recorded in the group state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupInvite {
+    pub policy: ExternalCommitPolicyV1,
+    /// The inbox that created the invite. External commits using it are
+    /// checked against the group's add-member policy as if this inbox had
+    /// added the joiner.
+    pub created_by_inbox_id: String,
+    /// The epoch the commit that recorded this invite created, which is the
+    /// epoch of the first GroupInfo published for it. Members only accept new
+    /// invites that name their committer and this epoch.
+    pub group_info_epoch: u64,
+}
+
+impl GroupInvite {
+    pub fn external_group_id(&self) -> &[u8] {
+        &self.policy.external_group_id
+    }
+
+    /// Whether the invite still admits external commits at `now_ns`.
+    pub fn is_active(&self, now_ns: u64) -> bool {
+        self.policy.allow_external_commit
+            && (self.policy.expires_at_ns == 0 || now_ns < self.policy.expires_at_ns)
+    }
+}
+
+/// Decodes the `external_invites` attribute. Malformed or unrecognised
+/// entries are skipped.
+pub fn decode_group_invites(value: &str) -> Vec<GroupInvite> {
+    value
+        .split(',')
+        .filter_map(|entry| {
+            let (policy, rest) = entry.split_once(':')?;
+            let (created_by_inbox_id, group_info_epoch) = rest.split_once(':')?;
+            let group_info_epoch = group_info_epoch.parse().ok()?;
+            let policy =
+                ExternalCommitPolicyEntry::decode(hex::decode(policy).ok()?.as_slice()).ok()?;
+            let Some(ExternalCommitPolicyVersion::V1(policy)) = policy.version else {
+                return None;
+            };
+            Some(GroupInvite {
+                policy,
+                created_by_inbox_id: created_by_inbox_id.to_string(),
+                group_info_epoch,
+            })
+        })
+        .collect()
+}
+
+/// Encodes invites into the `external_invites` attribute format.
+pub fn encode_group_invites(invites: &[GroupInvite]) -> String {
+    invites
+        .iter()
+        .map(|invite| {
+            let entry = ExternalCommitPolicyEntry {
+                version: Some(ExternalCommitPolicyVersion::V1(invite.policy.clone())),
+            };
+            format!(
+                "{}:{}:{}",
+                hex::encode(entry.encode_to_vec()),
+                invite.created_by_inbox_id,
+                invite.group_info_epoch
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Finds the invite with the given `external_group_id` that is active at
+/// `now_ns`.
+pub fn find_active_invite<'a>(
+    invites: &'a [GroupInvite],
+    external_group_id: &[u8],
+    now_ns: u64,
+) -> Option<&'a GroupInvite> {
+    invites
+        .iter()
+        .find(|invite| invite.external_group_id() == external_group_id && invite.is_active(now_ns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invite(id: u8, expires_at_ns: u64) -> GroupInvite {
+        GroupInvite {
+            policy: ExternalCommitPolicyV1 {
+                allow_external_commit: true,
+                expires_at_ns,
+                expire_in_ns: 0,
+                symmetric_key: vec![id; 32],
+                external_group_id: vec![id; 16],
+            },
+            created_by_inbox_id: format!("inbox_{id}"),
+            group_info_epoch: 7,
+        }
+    }
+
+    #[xmtp_common::test]
+    fn round_trip() {
+        let invites = vec![invite(1, 0), invite(2, 500)];
+        let encoded = encode_group_invites(&invites);
+        assert_eq!(decode_group_invites(&encoded), invites);
+
+        assert!(decode_group_invites("").is_empty());
+        let single = encode_group_invites(&[invite(3, 0)]);
+        let (missing_epoch, _) = single.rsplit_once(':').unwrap();
+        assert_eq!(
+            decode_group_invites(&format!(
+                "{encoded},not_hex:inbox:7,missing_separator,{missing_epoch}"
+            )),
+            invites
+        );
+    }
+
+    #[xmtp_common::test]
+    fn find_active_invite_respects_expiry_and_switch() {
+        let mut disabled = invite(3, 0);
+        disabled.policy.allow_external_commit = false;
+        let invites = vec![invite(1, 0), invite(2, 500), disabled];
+
+        assert!(find_active_invite(&invites, &[1; 16], u64::MAX).is_some());
+        assert!(find_active_invite(&invites, &[2; 16], 499).is_some());
+        assert!(find_active_invite(&invites, &[2; 16], 500).is_none());
+        assert!(find_active_invite(&invites, &[3; 16], 0).is_none());
+        assert!(find_active_invite(&invites, &[4; 16], 0).is_none());
+    }
+}
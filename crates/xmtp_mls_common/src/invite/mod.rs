@@ -2,9 +2,12 @@
 //! commits.
 //!
 //! The [`payload`] module provides helpers for the
-//! [`ExternalInvitePayload`] proto. The encryption envelope lives in the
-//! sibling `encrypted_group_info` module (added by a separate PR).
+//! [`ExternalInvitePayload`] proto, [`encrypted_group_info`] owns the
+//! encryption envelope for the GroupInfo blob the payload points at, and
+//! [`group_invites`] encodes the invites a group has outstanding.
 //!
 //! [`ExternalInvitePayload`]: xmtp_proto::xmtp::mls::message_contents::ExternalInvitePayload
 
+pub mod encrypted_group_info;
+pub mod group_invites;
 pub mod payload;
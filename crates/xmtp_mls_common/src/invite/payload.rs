@@ -8,9 +8,9 @@
 //! * a [`build_payload`] convenience constructor
 //!
 //! The actual encryption of the [`GroupInfo`] blob is performed by the
-//! sibling `encrypted_group_info` module (which also owns the blob-side
-//! expiry semantics, since `expires_at_ns` lives on the
-//! [`EncryptedGroupInfoBlob`] envelope and not the payload).
+//! sibling [`encrypted_group_info`](super::encrypted_group_info) module
+//! (which also owns the blob-side expiry semantics, since `expires_at_ns`
+//! lives on the [`EncryptedGroupInfoBlob`] envelope and not the payload).
 //!
 //! [`ExternalInvitePayload`]: xmtp_proto::xmtp::mls::message_contents::ExternalInvitePayload
 //! [`EncryptedGroupInfoBlob`]: xmtp_proto::xmtp::mls::message_contents::EncryptedGroupInfoBlob
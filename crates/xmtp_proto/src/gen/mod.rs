@@ -10,6 +10,10 @@ pub mod xmtp {
             include!("xmtp.device_sync.content.rs");
             include!("xmtp.device_sync.content.serde.rs");
         }
+        pub mod draft_backup {
+            include!("xmtp.device_sync.draft_backup.rs");
+            include!("xmtp.device_sync.draft_backup.serde.rs");
+        }
         pub mod event_backup {
             include!("xmtp.device_sync.event_backup.rs");
             include!("xmtp.device_sync.event_backup.serde.rs");
@@ -64,7 +64,7 @@ impl ::prost::Name for PreferenceUpdates {
 /// Preference update
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PreferenceUpdate {
-    #[prost(oneof = "preference_update::Update", tags = "1, 2")]
+    #[prost(oneof = "preference_update::Update", tags = "1, 2, 3, 4")]
     pub update: ::core::option::Option<preference_update::Update>,
 }
 /// Nested message and enum types in `PreferenceUpdate`.
@@ -75,6 +75,10 @@ pub mod preference_update {
         Consent(super::super::consent_backup::ConsentSave),
         #[prost(message, tag = "2")]
         Hmac(super::HmacKeyUpdate),
+        #[prost(message, tag = "3")]
+        ReadCursor(super::ReadCursorUpdate),
+        #[prost(message, tag = "4")]
+        ConversationSettings(super::ConversationSettingsUpdate),
     }
 }
 impl ::prost::Name for PreferenceUpdate {
@@ -120,6 +124,52 @@ impl ::prost::Name for HmacKeyUpdate {
         "/xmtp.device_sync.content.HmacKeyUpdate".into()
     }
 }
+/// Read cursor update. The cursor points at the newest message read in a
+/// conversation and only moves forward.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReadCursorUpdate {
+    #[prost(bytes = "vec", tag = "1")]
+    pub group_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub message_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub sent_at_ns: i64,
+}
+impl ::prost::Name for ReadCursorUpdate {
+    const NAME: &'static str = "ReadCursorUpdate";
+    const PACKAGE: &'static str = "xmtp.device_sync.content";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.device_sync.content.ReadCursorUpdate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.device_sync.content.ReadCursorUpdate".into()
+    }
+}
+/// Inbox organization settings for a conversation. The most recently updated
+/// settings win.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ConversationSettingsUpdate {
+    #[prost(bytes = "vec", tag = "1")]
+    pub group_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, optional, tag = "2")]
+    pub muted_until_ns: ::core::option::Option<i64>,
+    #[prost(bool, tag = "3")]
+    pub archived: bool,
+    #[prost(bool, tag = "4")]
+    pub pinned: bool,
+    #[prost(int64, tag = "5")]
+    pub updated_at_ns: i64,
+}
+impl ::prost::Name for ConversationSettingsUpdate {
+    const NAME: &'static str = "ConversationSettingsUpdate";
+    const PACKAGE: &'static str = "xmtp.device_sync.content";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.device_sync.content.ConversationSettingsUpdate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.device_sync.content.ConversationSettingsUpdate".into()
+    }
+}
 /// Initiator or new installation id requesting a sync payload send a request
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeviceSyncRequest {
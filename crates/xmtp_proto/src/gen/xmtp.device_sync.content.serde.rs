@@ -1,3 +1,181 @@
+impl serde::Serialize for ConversationSettingsUpdate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.group_id.is_empty() {
+            len += 1;
+        }
+        if self.muted_until_ns.is_some() {
+            len += 1;
+        }
+        if self.archived {
+            len += 1;
+        }
+        if self.pinned {
+            len += 1;
+        }
+        if self.updated_at_ns != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.device_sync.content.ConversationSettingsUpdate", len)?;
+        if !self.group_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("group_id", pbjson::private::base64::encode(&self.group_id).as_str())?;
+        }
+        if let Some(v) = self.muted_until_ns.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("muted_until_ns", ToString::to_string(&v).as_str())?;
+        }
+        if self.archived {
+            struct_ser.serialize_field("archived", &self.archived)?;
+        }
+        if self.pinned {
+            struct_ser.serialize_field("pinned", &self.pinned)?;
+        }
+        if self.updated_at_ns != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("updated_at_ns", ToString::to_string(&self.updated_at_ns).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConversationSettingsUpdate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "group_id",
+            "groupId",
+            "muted_until_ns",
+            "mutedUntilNs",
+            "archived",
+            "pinned",
+            "updated_at_ns",
+            "updatedAtNs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GroupId,
+            MutedUntilNs,
+            Archived,
+            Pinned,
+            UpdatedAtNs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "groupId" | "group_id" => Ok(GeneratedField::GroupId),
+                            "mutedUntilNs" | "muted_until_ns" => Ok(GeneratedField::MutedUntilNs),
+                            "archived" => Ok(GeneratedField::Archived),
+                            "pinned" => Ok(GeneratedField::Pinned),
+                            "updatedAtNs" | "updated_at_ns" => Ok(GeneratedField::UpdatedAtNs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConversationSettingsUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.device_sync.content.ConversationSettingsUpdate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConversationSettingsUpdate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut group_id__ = None;
+                let mut muted_until_ns__ = None;
+                let mut archived__ = None;
+                let mut pinned__ = None;
+                let mut updated_at_ns__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GroupId => {
+                            if group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("groupId"));
+                            }
+                            group_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MutedUntilNs => {
+                            if muted_until_ns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mutedUntilNs"));
+                            }
+                            muted_until_ns__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Archived => {
+                            if archived__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("archived"));
+                            }
+                            archived__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Pinned => {
+                            if pinned__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pinned"));
+                            }
+                            pinned__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UpdatedAtNs => {
+                            if updated_at_ns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatedAtNs"));
+                            }
+                            updated_at_ns__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConversationSettingsUpdate {
+                    group_id: group_id__.unwrap_or_default(),
+                    muted_until_ns: muted_until_ns__,
+                    archived: archived__.unwrap_or_default(),
+                    pinned: pinned__.unwrap_or_default(),
+                    updated_at_ns: updated_at_ns__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.device_sync.content.ConversationSettingsUpdate", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DeviceSyncAcknowledge {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -816,6 +994,12 @@ impl serde::Serialize for PreferenceUpdate {
                 preference_update::Update::Hmac(v) => {
                     struct_ser.serialize_field("hmac", v)?;
                 }
+                preference_update::Update::ReadCursor(v) => {
+                    struct_ser.serialize_field("read_cursor", v)?;
+                }
+                preference_update::Update::ConversationSettings(v) => {
+                    struct_ser.serialize_field("conversation_settings", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -830,12 +1014,18 @@ impl<'de> serde::Deserialize<'de> for PreferenceUpdate {
         const FIELDS: &[&str] = &[
             "consent",
             "hmac",
+            "read_cursor",
+            "readCursor",
+            "conversation_settings",
+            "conversationSettings",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Consent,
             Hmac,
+            ReadCursor,
+            ConversationSettings,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -860,6 +1050,8 @@ impl<'de> serde::Deserialize<'de> for PreferenceUpdate {
                         match value {
                             "consent" => Ok(GeneratedField::Consent),
                             "hmac" => Ok(GeneratedField::Hmac),
+                            "readCursor" | "read_cursor" => Ok(GeneratedField::ReadCursor),
+                            "conversationSettings" | "conversation_settings" => Ok(GeneratedField::ConversationSettings),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -894,6 +1086,20 @@ impl<'de> serde::Deserialize<'de> for PreferenceUpdate {
                                 return Err(serde::de::Error::duplicate_field("hmac"));
                             }
                             update__ = map_.next_value::<::std::option::Option<_>>()?.map(preference_update::Update::Hmac)
+;
+                        }
+                        GeneratedField::ReadCursor => {
+                            if update__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("readCursor"));
+                            }
+                            update__ = map_.next_value::<::std::option::Option<_>>()?.map(preference_update::Update::ReadCursor)
+;
+                        }
+                        GeneratedField::ConversationSettings => {
+                            if update__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("conversationSettings"));
+                            }
+                            update__ = map_.next_value::<::std::option::Option<_>>()?.map(preference_update::Update::ConversationSettings)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -1004,6 +1210,150 @@ impl<'de> serde::Deserialize<'de> for PreferenceUpdates {
         deserializer.deserialize_struct("xmtp.device_sync.content.PreferenceUpdates", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ReadCursorUpdate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.group_id.is_empty() {
+            len += 1;
+        }
+        if !self.message_id.is_empty() {
+            len += 1;
+        }
+        if self.sent_at_ns != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.device_sync.content.ReadCursorUpdate", len)?;
+        if !self.group_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("group_id", pbjson::private::base64::encode(&self.group_id).as_str())?;
+        }
+        if !self.message_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("message_id", pbjson::private::base64::encode(&self.message_id).as_str())?;
+        }
+        if self.sent_at_ns != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("sent_at_ns", ToString::to_string(&self.sent_at_ns).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReadCursorUpdate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "group_id",
+            "groupId",
+            "message_id",
+            "messageId",
+            "sent_at_ns",
+            "sentAtNs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GroupId,
+            MessageId,
+            SentAtNs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "groupId" | "group_id" => Ok(GeneratedField::GroupId),
+                            "messageId" | "message_id" => Ok(GeneratedField::MessageId),
+                            "sentAtNs" | "sent_at_ns" => Ok(GeneratedField::SentAtNs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReadCursorUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.device_sync.content.ReadCursorUpdate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReadCursorUpdate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut group_id__ = None;
+                let mut message_id__ = None;
+                let mut sent_at_ns__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GroupId => {
+                            if group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("groupId"));
+                            }
+                            group_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MessageId => {
+                            if message_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("messageId"));
+                            }
+                            message_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SentAtNs => {
+                            if sent_at_ns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sentAtNs"));
+                            }
+                            sent_at_ns__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ReadCursorUpdate {
+                    group_id: group_id__.unwrap_or_default(),
+                    message_id: message_id__.unwrap_or_default(),
+                    sent_at_ns: sent_at_ns__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.device_sync.content.ReadCursorUpdate", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for V1UserPreferenceUpdate {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -0,0 +1,24 @@
+// This file is @generated by prost-build.
+/// Proto representation of a conversation draft save
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DraftSave {
+    #[prost(bytes = "vec", tag = "1")]
+    pub group_id: ::prost::alloc::vec::Vec<u8>,
+    /// Serialized EncodedContent of the draft
+    #[prost(bytes = "vec", tag = "2")]
+    pub content: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub reply_to_message_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(int64, tag = "4")]
+    pub updated_at_ns: i64,
+}
+impl ::prost::Name for DraftSave {
+    const NAME: &'static str = "DraftSave";
+    const PACKAGE: &'static str = "xmtp.device_sync.draft_backup";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.device_sync.draft_backup.DraftSave".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.device_sync.draft_backup.DraftSave".into()
+    }
+}
@@ -2,7 +2,7 @@
 /// Union type representing everything that can be serialied and saved in a backup archive.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BackupElement {
-    #[prost(oneof = "backup_element::Element", tags = "1, 2, 3, 4, 5")]
+    #[prost(oneof = "backup_element::Element", tags = "1, 2, 3, 4, 5, 6, 7")]
     pub element: ::core::option::Option<backup_element::Element>,
 }
 /// Nested message and enum types in `BackupElement`.
@@ -20,6 +20,10 @@ pub mod backup_element {
         #[deprecated]
         #[prost(message, tag = "5")]
         Event(super::event_backup::EventSave),
+        #[prost(message, tag = "6")]
+        Manifest(super::BackupManifestSave),
+        #[prost(message, tag = "7")]
+        Draft(super::draft_backup::DraftSave),
     }
 }
 impl ::prost::Name for BackupElement {
@@ -44,6 +48,21 @@ pub struct BackupMetadataSave {
     pub start_ns: ::core::option::Option<i64>,
     #[prost(int64, optional, tag = "5")]
     pub end_ns: ::core::option::Option<i64>,
+    #[prost(bytes = "vec", repeated, tag = "6")]
+    pub include_group_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "7")]
+    pub exclude_group_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(enumeration = "consent_backup::ConsentStateSave", repeated, tag = "8")]
+    pub consent_states: ::prost::alloc::vec::Vec<i32>,
+    /// Random id of this archive, referenced by incremental archives that follow it
+    #[prost(bytes = "vec", tag = "9")]
+    pub archive_id: ::prost::alloc::vec::Vec<u8>,
+    /// Id of the archive this incremental archive follows. Unset for full archives.
+    #[prost(bytes = "vec", optional, tag = "10")]
+    pub previous_archive_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// Records inserted or changed after this time are included in an incremental archive
+    #[prost(int64, optional, tag = "11")]
+    pub since_ns: ::core::option::Option<i64>,
 }
 impl ::prost::Name for BackupMetadataSave {
     const NAME: &'static str = "BackupMetadataSave";
@@ -55,6 +74,32 @@ impl ::prost::Name for BackupMetadataSave {
         "/xmtp.device_sync.BackupMetadataSave".into()
     }
 }
+/// Written as the last element of an archive so importers can detect truncation or tampering.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BackupManifestSave {
+    #[prost(uint64, tag = "1")]
+    pub group_count: u64,
+    #[prost(uint64, tag = "2")]
+    pub group_message_count: u64,
+    #[prost(uint64, tag = "3")]
+    pub consent_count: u64,
+    /// SHA-256 over every encoded element before the manifest, in order.
+    #[prost(bytes = "vec", tag = "4")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// HMAC-SHA256 over this manifest with an empty mac, keyed from the archive key.
+    #[prost(bytes = "vec", tag = "5")]
+    pub mac: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for BackupManifestSave {
+    const NAME: &'static str = "BackupManifestSave";
+    const PACKAGE: &'static str = "xmtp.device_sync";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.device_sync.BackupManifestSave".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.device_sync.BackupManifestSave".into()
+    }
+}
 /// Backup Options
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ArchiveOptions {
@@ -66,6 +111,12 @@ pub struct ArchiveOptions {
     pub end_ns: ::core::option::Option<i64>,
     #[prost(bool, tag = "4")]
     pub exclude_disappearing_messages: bool,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub include_group_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "6")]
+    pub exclude_group_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(enumeration = "consent_backup::ConsentStateSave", repeated, tag = "7")]
+    pub consent_states: ::prost::alloc::vec::Vec<i32>,
 }
 impl ::prost::Name for ArchiveOptions {
     const NAME: &'static str = "ArchiveOptions";
@@ -86,6 +137,7 @@ pub enum BackupElementSelection {
     Consent = 2,
     #[deprecated]
     Event = 3,
+    Drafts = 4,
 }
 impl BackupElementSelection {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -99,6 +151,7 @@ impl BackupElementSelection {
             Self::Consent => "BACKUP_ELEMENT_SELECTION_CONSENT",
             #[allow(deprecated)]
             Self::Event => "BACKUP_ELEMENT_SELECTION_EVENT",
+            Self::Drafts => "BACKUP_ELEMENT_SELECTION_DRAFTS",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -108,6 +161,7 @@ impl BackupElementSelection {
             "BACKUP_ELEMENT_SELECTION_MESSAGES" => Some(Self::Messages),
             "BACKUP_ELEMENT_SELECTION_CONSENT" => Some(Self::Consent),
             "BACKUP_ELEMENT_SELECTION_EVENT" => Some(#[allow(deprecated)] Self::Event),
+            "BACKUP_ELEMENT_SELECTION_DRAFTS" => Some(Self::Drafts),
             _ => None,
         }
     }
@@ -18,6 +18,15 @@ impl serde::Serialize for ArchiveOptions {
         if self.exclude_disappearing_messages {
             len += 1;
         }
+        if !self.include_group_ids.is_empty() {
+            len += 1;
+        }
+        if !self.exclude_group_ids.is_empty() {
+            len += 1;
+        }
+        if !self.consent_states.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("xmtp.device_sync.ArchiveOptions", len)?;
         if !self.elements.is_empty() {
             let v = self.elements.iter().cloned().map(|v| {
@@ -39,6 +48,19 @@ impl serde::Serialize for ArchiveOptions {
         if self.exclude_disappearing_messages {
             struct_ser.serialize_field("exclude_disappearing_messages", &self.exclude_disappearing_messages)?;
         }
+        if !self.include_group_ids.is_empty() {
+            struct_ser.serialize_field("include_group_ids", &self.include_group_ids.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if !self.exclude_group_ids.is_empty() {
+            struct_ser.serialize_field("exclude_group_ids", &self.exclude_group_ids.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if !self.consent_states.is_empty() {
+            let v = self.consent_states.iter().cloned().map(|v| {
+                consent_backup::ConsentStateSave::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<std::result::Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("consent_states", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -56,6 +78,12 @@ impl<'de> serde::Deserialize<'de> for ArchiveOptions {
             "endNs",
             "exclude_disappearing_messages",
             "excludeDisappearingMessages",
+            "include_group_ids",
+            "includeGroupIds",
+            "exclude_group_ids",
+            "excludeGroupIds",
+            "consent_states",
+            "consentStates",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -64,6 +92,9 @@ impl<'de> serde::Deserialize<'de> for ArchiveOptions {
             StartNs,
             EndNs,
             ExcludeDisappearingMessages,
+            IncludeGroupIds,
+            ExcludeGroupIds,
+            ConsentStates,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -90,6 +121,9 @@ impl<'de> serde::Deserialize<'de> for ArchiveOptions {
                             "startNs" | "start_ns" => Ok(GeneratedField::StartNs),
                             "endNs" | "end_ns" => Ok(GeneratedField::EndNs),
                             "excludeDisappearingMessages" | "exclude_disappearing_messages" => Ok(GeneratedField::ExcludeDisappearingMessages),
+                            "includeGroupIds" | "include_group_ids" => Ok(GeneratedField::IncludeGroupIds),
+                            "excludeGroupIds" | "exclude_group_ids" => Ok(GeneratedField::ExcludeGroupIds),
+                            "consentStates" | "consent_states" => Ok(GeneratedField::ConsentStates),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -113,6 +147,9 @@ impl<'de> serde::Deserialize<'de> for ArchiveOptions {
                 let mut start_ns__ = None;
                 let mut end_ns__ = None;
                 let mut exclude_disappearing_messages__ = None;
+                let mut include_group_ids__ = None;
+                let mut exclude_group_ids__ = None;
+                let mut consent_states__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Elements => {
@@ -143,6 +180,30 @@ impl<'de> serde::Deserialize<'de> for ArchiveOptions {
                             }
                             exclude_disappearing_messages__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::IncludeGroupIds => {
+                            if include_group_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeGroupIds"));
+                            }
+                            include_group_ids__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::ExcludeGroupIds => {
+                            if exclude_group_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("excludeGroupIds"));
+                            }
+                            exclude_group_ids__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::ConsentStates => {
+                            if consent_states__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consentStates"));
+                            }
+                            consent_states__ = Some(map_.next_value::<Vec<consent_backup::ConsentStateSave>>()?.into_iter().map(|x| x as i32).collect());
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -153,6 +214,9 @@ impl<'de> serde::Deserialize<'de> for ArchiveOptions {
                     start_ns: start_ns__,
                     end_ns: end_ns__,
                     exclude_disappearing_messages: exclude_disappearing_messages__.unwrap_or_default(),
+                    include_group_ids: include_group_ids__.unwrap_or_default(),
+                    exclude_group_ids: exclude_group_ids__.unwrap_or_default(),
+                    consent_states: consent_states__.unwrap_or_default(),
                 })
             }
         }
@@ -188,6 +252,12 @@ impl serde::Serialize for BackupElement {
                 backup_element::Element::Event(v) => {
                     struct_ser.serialize_field("event", v)?;
                 }
+                backup_element::Element::Manifest(v) => {
+                    struct_ser.serialize_field("manifest", v)?;
+                }
+                backup_element::Element::Draft(v) => {
+                    struct_ser.serialize_field("draft", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -206,6 +276,8 @@ impl<'de> serde::Deserialize<'de> for BackupElement {
             "groupMessage",
             "consent",
             "event",
+            "manifest",
+            "draft",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -215,6 +287,8 @@ impl<'de> serde::Deserialize<'de> for BackupElement {
             GroupMessage,
             Consent,
             Event,
+            Manifest,
+            Draft,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -242,6 +316,8 @@ impl<'de> serde::Deserialize<'de> for BackupElement {
                             "groupMessage" | "group_message" => Ok(GeneratedField::GroupMessage),
                             "consent" => Ok(GeneratedField::Consent),
                             "event" => Ok(GeneratedField::Event),
+                            "manifest" => Ok(GeneratedField::Manifest),
+                            "draft" => Ok(GeneratedField::Draft),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -297,6 +373,20 @@ impl<'de> serde::Deserialize<'de> for BackupElement {
                                 return Err(serde::de::Error::duplicate_field("event"));
                             }
                             element__ = map_.next_value::<::std::option::Option<_>>()?.map(backup_element::Element::Event)
+;
+                        }
+                        GeneratedField::Manifest => {
+                            if element__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("manifest"));
+                            }
+                            element__ = map_.next_value::<::std::option::Option<_>>()?.map(backup_element::Element::Manifest)
+;
+                        }
+                        GeneratedField::Draft => {
+                            if element__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("draft"));
+                            }
+                            element__ = map_.next_value::<::std::option::Option<_>>()?.map(backup_element::Element::Draft)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -323,6 +413,7 @@ impl serde::Serialize for BackupElementSelection {
             Self::Messages => "BACKUP_ELEMENT_SELECTION_MESSAGES",
             Self::Consent => "BACKUP_ELEMENT_SELECTION_CONSENT",
             Self::Event => "BACKUP_ELEMENT_SELECTION_EVENT",
+            Self::Drafts => "BACKUP_ELEMENT_SELECTION_DRAFTS",
         };
         serializer.serialize_str(variant)
     }
@@ -338,6 +429,7 @@ impl<'de> serde::Deserialize<'de> for BackupElementSelection {
             "BACKUP_ELEMENT_SELECTION_MESSAGES",
             "BACKUP_ELEMENT_SELECTION_CONSENT",
             "BACKUP_ELEMENT_SELECTION_EVENT",
+            "BACKUP_ELEMENT_SELECTION_DRAFTS",
         ];
 
         struct GeneratedVisitor;
@@ -382,6 +474,7 @@ impl<'de> serde::Deserialize<'de> for BackupElementSelection {
                     "BACKUP_ELEMENT_SELECTION_MESSAGES" => Ok(BackupElementSelection::Messages),
                     "BACKUP_ELEMENT_SELECTION_CONSENT" => Ok(BackupElementSelection::Consent),
                     "BACKUP_ELEMENT_SELECTION_EVENT" => Ok(BackupElementSelection::Event),
+                    "BACKUP_ELEMENT_SELECTION_DRAFTS" => Ok(BackupElementSelection::Drafts),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -389,6 +482,192 @@ impl<'de> serde::Deserialize<'de> for BackupElementSelection {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for BackupManifestSave {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.group_count != 0 {
+            len += 1;
+        }
+        if self.group_message_count != 0 {
+            len += 1;
+        }
+        if self.consent_count != 0 {
+            len += 1;
+        }
+        if !self.hash.is_empty() {
+            len += 1;
+        }
+        if !self.mac.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.device_sync.BackupManifestSave", len)?;
+        if self.group_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("group_count", ToString::to_string(&self.group_count).as_str())?;
+        }
+        if self.group_message_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("group_message_count", ToString::to_string(&self.group_message_count).as_str())?;
+        }
+        if self.consent_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("consent_count", ToString::to_string(&self.consent_count).as_str())?;
+        }
+        if !self.hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("hash", pbjson::private::base64::encode(&self.hash).as_str())?;
+        }
+        if !self.mac.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("mac", pbjson::private::base64::encode(&self.mac).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BackupManifestSave {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "group_count",
+            "groupCount",
+            "group_message_count",
+            "groupMessageCount",
+            "consent_count",
+            "consentCount",
+            "hash",
+            "mac",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GroupCount,
+            GroupMessageCount,
+            ConsentCount,
+            Hash,
+            Mac,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "groupCount" | "group_count" => Ok(GeneratedField::GroupCount),
+                            "groupMessageCount" | "group_message_count" => Ok(GeneratedField::GroupMessageCount),
+                            "consentCount" | "consent_count" => Ok(GeneratedField::ConsentCount),
+                            "hash" => Ok(GeneratedField::Hash),
+                            "mac" => Ok(GeneratedField::Mac),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BackupManifestSave;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.device_sync.BackupManifestSave")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BackupManifestSave, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut group_count__ = None;
+                let mut group_message_count__ = None;
+                let mut consent_count__ = None;
+                let mut hash__ = None;
+                let mut mac__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GroupCount => {
+                            if group_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("groupCount"));
+                            }
+                            group_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GroupMessageCount => {
+                            if group_message_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("groupMessageCount"));
+                            }
+                            group_message_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ConsentCount => {
+                            if consent_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consentCount"));
+                            }
+                            consent_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Hash => {
+                            if hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hash"));
+                            }
+                            hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Mac => {
+                            if mac__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mac"));
+                            }
+                            mac__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BackupManifestSave {
+                    group_count: group_count__.unwrap_or_default(),
+                    group_message_count: group_message_count__.unwrap_or_default(),
+                    consent_count: consent_count__.unwrap_or_default(),
+                    hash: hash__.unwrap_or_default(),
+                    mac: mac__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.device_sync.BackupManifestSave", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for BackupMetadataSave {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -409,6 +688,24 @@ impl serde::Serialize for BackupMetadataSave {
         if self.end_ns.is_some() {
             len += 1;
         }
+        if !self.include_group_ids.is_empty() {
+            len += 1;
+        }
+        if !self.exclude_group_ids.is_empty() {
+            len += 1;
+        }
+        if !self.consent_states.is_empty() {
+            len += 1;
+        }
+        if !self.archive_id.is_empty() {
+            len += 1;
+        }
+        if self.previous_archive_id.is_some() {
+            len += 1;
+        }
+        if self.since_ns.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("xmtp.device_sync.BackupMetadataSave", len)?;
         if !self.elements.is_empty() {
             let v = self.elements.iter().cloned().map(|v| {
@@ -432,6 +729,34 @@ impl serde::Serialize for BackupMetadataSave {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("end_ns", ToString::to_string(&v).as_str())?;
         }
+        if !self.include_group_ids.is_empty() {
+            struct_ser.serialize_field("include_group_ids", &self.include_group_ids.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if !self.exclude_group_ids.is_empty() {
+            struct_ser.serialize_field("exclude_group_ids", &self.exclude_group_ids.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if !self.consent_states.is_empty() {
+            let v = self.consent_states.iter().cloned().map(|v| {
+                consent_backup::ConsentStateSave::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<std::result::Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("consent_states", &v)?;
+        }
+        if !self.archive_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("archive_id", pbjson::private::base64::encode(&self.archive_id).as_str())?;
+        }
+        if let Some(v) = self.previous_archive_id.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("previous_archive_id", pbjson::private::base64::encode(&v).as_str())?;
+        }
+        if let Some(v) = self.since_ns.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("since_ns", ToString::to_string(&v).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -449,6 +774,18 @@ impl<'de> serde::Deserialize<'de> for BackupMetadataSave {
             "startNs",
             "end_ns",
             "endNs",
+            "include_group_ids",
+            "includeGroupIds",
+            "exclude_group_ids",
+            "excludeGroupIds",
+            "consent_states",
+            "consentStates",
+            "archive_id",
+            "archiveId",
+            "previous_archive_id",
+            "previousArchiveId",
+            "since_ns",
+            "sinceNs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -457,6 +794,12 @@ impl<'de> serde::Deserialize<'de> for BackupMetadataSave {
             ExportedAtNs,
             StartNs,
             EndNs,
+            IncludeGroupIds,
+            ExcludeGroupIds,
+            ConsentStates,
+            ArchiveId,
+            PreviousArchiveId,
+            SinceNs,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -483,6 +826,12 @@ impl<'de> serde::Deserialize<'de> for BackupMetadataSave {
                             "exportedAtNs" | "exported_at_ns" => Ok(GeneratedField::ExportedAtNs),
                             "startNs" | "start_ns" => Ok(GeneratedField::StartNs),
                             "endNs" | "end_ns" => Ok(GeneratedField::EndNs),
+                            "includeGroupIds" | "include_group_ids" => Ok(GeneratedField::IncludeGroupIds),
+                            "excludeGroupIds" | "exclude_group_ids" => Ok(GeneratedField::ExcludeGroupIds),
+                            "consentStates" | "consent_states" => Ok(GeneratedField::ConsentStates),
+                            "archiveId" | "archive_id" => Ok(GeneratedField::ArchiveId),
+                            "previousArchiveId" | "previous_archive_id" => Ok(GeneratedField::PreviousArchiveId),
+                            "sinceNs" | "since_ns" => Ok(GeneratedField::SinceNs),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -506,6 +855,12 @@ impl<'de> serde::Deserialize<'de> for BackupMetadataSave {
                 let mut exported_at_ns__ = None;
                 let mut start_ns__ = None;
                 let mut end_ns__ = None;
+                let mut include_group_ids__ = None;
+                let mut exclude_group_ids__ = None;
+                let mut consent_states__ = None;
+                let mut archive_id__ = None;
+                let mut previous_archive_id__ = None;
+                let mut since_ns__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Elements => {
@@ -538,6 +893,54 @@ impl<'de> serde::Deserialize<'de> for BackupMetadataSave {
                                 map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
                             ;
                         }
+                        GeneratedField::IncludeGroupIds => {
+                            if include_group_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeGroupIds"));
+                            }
+                            include_group_ids__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::ExcludeGroupIds => {
+                            if exclude_group_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("excludeGroupIds"));
+                            }
+                            exclude_group_ids__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::ConsentStates => {
+                            if consent_states__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consentStates"));
+                            }
+                            consent_states__ = Some(map_.next_value::<Vec<consent_backup::ConsentStateSave>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                        GeneratedField::ArchiveId => {
+                            if archive_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("archiveId"));
+                            }
+                            archive_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PreviousArchiveId => {
+                            if previous_archive_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousArchiveId"));
+                            }
+                            previous_archive_id__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::SinceNs => {
+                            if since_ns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sinceNs"));
+                            }
+                            since_ns__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -548,6 +951,12 @@ impl<'de> serde::Deserialize<'de> for BackupMetadataSave {
                     exported_at_ns: exported_at_ns__.unwrap_or_default(),
                     start_ns: start_ns__,
                     end_ns: end_ns__,
+                    include_group_ids: include_group_ids__.unwrap_or_default(),
+                    exclude_group_ids: exclude_group_ids__.unwrap_or_default(),
+                    consent_states: consent_states__.unwrap_or_default(),
+                    archive_id: archive_id__.unwrap_or_default(),
+                    previous_archive_id: previous_archive_id__,
+                    since_ns: since_ns__,
                 })
             }
         }
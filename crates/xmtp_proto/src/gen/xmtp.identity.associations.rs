@@ -116,7 +116,7 @@ pub struct Signature {
     ///    recoverable, or specified as a field.
     /// 1. The signer certifies that the signing payload is correct. The payload
     ///    must be inferred from the context in which the signature is provided.
-    #[prost(oneof = "signature::Signature", tags = "1, 2, 3, 4, 5")]
+    #[prost(oneof = "signature::Signature", tags = "1, 2, 3, 4, 5, 6")]
     pub signature: ::core::option::Option<signature::Signature>,
 }
 /// Nested message and enum types in `Signature`.
@@ -139,6 +139,9 @@ pub mod signature {
         DelegatedErc191(super::LegacyDelegatedSignature),
         #[prost(message, tag = "5")]
         Passkey(super::RecoverablePasskeySignature),
+        /// Signature over the raw text by an ed25519 wallet, such as solana
+        #[prost(message, tag = "6")]
+        Ed25519Wallet(super::RecoverableEd25519Signature),
     }
 }
 impl ::prost::Name for Signature {
@@ -154,7 +157,7 @@ impl ::prost::Name for Signature {
 /// The identifier for a member of an XID
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct MemberIdentifier {
-    #[prost(oneof = "member_identifier::Kind", tags = "1, 2, 3")]
+    #[prost(oneof = "member_identifier::Kind", tags = "1, 2, 3, 4")]
     pub kind: ::core::option::Option<member_identifier::Kind>,
 }
 /// Nested message and enum types in `MemberIdentifier`.
@@ -167,6 +170,9 @@ pub mod member_identifier {
         InstallationPublicKey(::prost::alloc::vec::Vec<u8>),
         #[prost(message, tag = "3")]
         Passkey(super::Passkey),
+        /// Base58 encoded ed25519 public key
+        #[prost(string, tag = "4")]
+        SolanaAddress(::prost::alloc::string::String),
     }
 }
 impl ::prost::Name for MemberIdentifier {
@@ -445,6 +451,7 @@ pub enum IdentifierKind {
     Unspecified = 0,
     Ethereum = 1,
     Passkey = 2,
+    Solana = 3,
 }
 impl IdentifierKind {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -456,6 +463,7 @@ impl IdentifierKind {
             Self::Unspecified => "IDENTIFIER_KIND_UNSPECIFIED",
             Self::Ethereum => "IDENTIFIER_KIND_ETHEREUM",
             Self::Passkey => "IDENTIFIER_KIND_PASSKEY",
+            Self::Solana => "IDENTIFIER_KIND_SOLANA",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -464,6 +472,7 @@ impl IdentifierKind {
             "IDENTIFIER_KIND_UNSPECIFIED" => Some(Self::Unspecified),
             "IDENTIFIER_KIND_ETHEREUM" => Some(Self::Ethereum),
             "IDENTIFIER_KIND_PASSKEY" => Some(Self::Passkey),
+            "IDENTIFIER_KIND_SOLANA" => Some(Self::Solana),
             _ => None,
         }
     }
@@ -787,6 +787,7 @@ impl serde::Serialize for IdentifierKind {
             Self::Unspecified => "IDENTIFIER_KIND_UNSPECIFIED",
             Self::Ethereum => "IDENTIFIER_KIND_ETHEREUM",
             Self::Passkey => "IDENTIFIER_KIND_PASSKEY",
+            Self::Solana => "IDENTIFIER_KIND_SOLANA",
         };
         serializer.serialize_str(variant)
     }
@@ -801,6 +802,7 @@ impl<'de> serde::Deserialize<'de> for IdentifierKind {
             "IDENTIFIER_KIND_UNSPECIFIED",
             "IDENTIFIER_KIND_ETHEREUM",
             "IDENTIFIER_KIND_PASSKEY",
+            "IDENTIFIER_KIND_SOLANA",
         ];
 
         struct GeneratedVisitor;
@@ -844,6 +846,7 @@ impl<'de> serde::Deserialize<'de> for IdentifierKind {
                     "IDENTIFIER_KIND_UNSPECIFIED" => Ok(IdentifierKind::Unspecified),
                     "IDENTIFIER_KIND_ETHEREUM" => Ok(IdentifierKind::Ethereum),
                     "IDENTIFIER_KIND_PASSKEY" => Ok(IdentifierKind::Passkey),
+                    "IDENTIFIER_KIND_SOLANA" => Ok(IdentifierKind::Solana),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -1422,6 +1425,9 @@ impl serde::Serialize for MemberIdentifier {
                 member_identifier::Kind::Passkey(v) => {
                     struct_ser.serialize_field("passkey", v)?;
                 }
+                member_identifier::Kind::SolanaAddress(v) => {
+                    struct_ser.serialize_field("solana_address", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -1439,6 +1445,8 @@ impl<'de> serde::Deserialize<'de> for MemberIdentifier {
             "installation_public_key",
             "installationPublicKey",
             "passkey",
+            "solana_address",
+            "solanaAddress",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1446,6 +1454,7 @@ impl<'de> serde::Deserialize<'de> for MemberIdentifier {
             EthereumAddress,
             InstallationPublicKey,
             Passkey,
+            SolanaAddress,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1471,6 +1480,7 @@ impl<'de> serde::Deserialize<'de> for MemberIdentifier {
                             "ethereumAddress" | "ethereum_address" => Ok(GeneratedField::EthereumAddress),
                             "installationPublicKey" | "installation_public_key" => Ok(GeneratedField::InstallationPublicKey),
                             "passkey" => Ok(GeneratedField::Passkey),
+                            "solanaAddress" | "solana_address" => Ok(GeneratedField::SolanaAddress),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1512,6 +1522,12 @@ impl<'de> serde::Deserialize<'de> for MemberIdentifier {
                             kind__ = map_.next_value::<::std::option::Option<_>>()?.map(member_identifier::Kind::Passkey)
 ;
                         }
+                        GeneratedField::SolanaAddress => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("solanaAddress"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(member_identifier::Kind::SolanaAddress);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2282,6 +2298,9 @@ impl serde::Serialize for Signature {
                 signature::Signature::Passkey(v) => {
                     struct_ser.serialize_field("passkey", v)?;
                 }
+                signature::Signature::Ed25519Wallet(v) => {
+                    struct_ser.serialize_field("ed25519_wallet", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -2303,6 +2322,8 @@ impl<'de> serde::Deserialize<'de> for Signature {
             "delegated_erc_191",
             "delegatedErc191",
             "passkey",
+            "ed25519_wallet",
+            "ed25519Wallet",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2312,6 +2333,7 @@ impl<'de> serde::Deserialize<'de> for Signature {
             InstallationKey,
             DelegatedErc191,
             Passkey,
+            Ed25519Wallet,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2339,6 +2361,7 @@ impl<'de> serde::Deserialize<'de> for Signature {
                             "installationKey" | "installation_key" => Ok(GeneratedField::InstallationKey),
                             "delegatedErc191" | "delegated_erc_191" => Ok(GeneratedField::DelegatedErc191),
                             "passkey" => Ok(GeneratedField::Passkey),
+                            "ed25519Wallet" | "ed25519_wallet" => Ok(GeneratedField::Ed25519Wallet),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2394,6 +2417,13 @@ impl<'de> serde::Deserialize<'de> for Signature {
                                 return Err(serde::de::Error::duplicate_field("passkey"));
                             }
                             signature__ = map_.next_value::<::std::option::Option<_>>()?.map(signature::Signature::Passkey)
+;
+                        }
+                        GeneratedField::Ed25519Wallet => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ed25519Wallet"));
+                            }
+                            signature__ = map_.next_value::<::std::option::Option<_>>()?.map(signature::Signature::Ed25519Wallet)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -685,6 +685,8 @@ pub enum AdminListUpdateType {
     RemoveAdmin = 2,
     AddSuperAdmin = 3,
     RemoveSuperAdmin = 4,
+    AddModerator = 5,
+    RemoveModerator = 6,
 }
 impl AdminListUpdateType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -698,6 +700,8 @@ impl AdminListUpdateType {
             Self::RemoveAdmin => "ADMIN_LIST_UPDATE_TYPE_REMOVE_ADMIN",
             Self::AddSuperAdmin => "ADMIN_LIST_UPDATE_TYPE_ADD_SUPER_ADMIN",
             Self::RemoveSuperAdmin => "ADMIN_LIST_UPDATE_TYPE_REMOVE_SUPER_ADMIN",
+            Self::AddModerator => "ADMIN_LIST_UPDATE_TYPE_ADD_MODERATOR",
+            Self::RemoveModerator => "ADMIN_LIST_UPDATE_TYPE_REMOVE_MODERATOR",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -708,6 +712,8 @@ impl AdminListUpdateType {
             "ADMIN_LIST_UPDATE_TYPE_REMOVE_ADMIN" => Some(Self::RemoveAdmin),
             "ADMIN_LIST_UPDATE_TYPE_ADD_SUPER_ADMIN" => Some(Self::AddSuperAdmin),
             "ADMIN_LIST_UPDATE_TYPE_REMOVE_SUPER_ADMIN" => Some(Self::RemoveSuperAdmin),
+            "ADMIN_LIST_UPDATE_TYPE_ADD_MODERATOR" => Some(Self::AddModerator),
+            "ADMIN_LIST_UPDATE_TYPE_REMOVE_MODERATOR" => Some(Self::RemoveModerator),
             _ => None,
         }
     }
@@ -760,6 +766,7 @@ pub enum PermissionPolicyOption {
     Deny = 2,
     AdminOnly = 3,
     SuperAdminOnly = 4,
+    ModeratorOrAbove = 5,
 }
 impl PermissionPolicyOption {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -773,6 +780,7 @@ impl PermissionPolicyOption {
             Self::Deny => "PERMISSION_POLICY_OPTION_DENY",
             Self::AdminOnly => "PERMISSION_POLICY_OPTION_ADMIN_ONLY",
             Self::SuperAdminOnly => "PERMISSION_POLICY_OPTION_SUPER_ADMIN_ONLY",
+            Self::ModeratorOrAbove => "PERMISSION_POLICY_OPTION_MODERATOR_OR_ABOVE",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -783,13 +791,14 @@ impl PermissionPolicyOption {
             "PERMISSION_POLICY_OPTION_DENY" => Some(Self::Deny),
             "PERMISSION_POLICY_OPTION_ADMIN_ONLY" => Some(Self::AdminOnly),
             "PERMISSION_POLICY_OPTION_SUPER_ADMIN_ONLY" => Some(Self::SuperAdminOnly),
+            "PERMISSION_POLICY_OPTION_MODERATOR_OR_ABOVE" => Some(Self::ModeratorOrAbove),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct Task {
-    #[prost(oneof = "task::Task", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    #[prost(oneof = "task::Task", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
     pub task: ::core::option::Option<task::Task>,
 }
 /// Nested message and enum types in `Task`.
@@ -812,6 +821,8 @@ pub mod task {
         AddMissingInstallations(super::AddMissingInstallations),
         #[prost(message, tag = "8")]
         KpLiveness(super::KpLiveness),
+        #[prost(message, tag = "9")]
+        PublishScheduledMessage(super::PublishScheduledMessage),
     }
 }
 impl ::prost::Name for Task {
@@ -958,3 +969,26 @@ impl ::prost::Name for AddMissingInstallations {
         "/xmtp.mls.database.AddMissingInstallations".into()
     }
 }
+/// Durable TaskRunner intent: publish a message that was stored with
+/// `Unpublished` delivery status once `publish_at_ns` is reached. The task row's
+/// next_attempt_at_ns starts at publish_at_ns; retries back off from there.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PublishScheduledMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub group_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub message_id: ::prost::alloc::vec::Vec<u8>,
+    /// When the message was scheduled to be published, in nanoseconds
+    #[prost(int64, tag = "3")]
+    pub publish_at_ns: i64,
+}
+impl ::prost::Name for PublishScheduledMessage {
+    const NAME: &'static str = "PublishScheduledMessage";
+    const PACKAGE: &'static str = "xmtp.mls.database";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.mls.database.PublishScheduledMessage".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.mls.database.PublishScheduledMessage".into()
+    }
+}
@@ -517,6 +517,8 @@ impl serde::Serialize for AdminListUpdateType {
             Self::RemoveAdmin => "ADMIN_LIST_UPDATE_TYPE_REMOVE_ADMIN",
             Self::AddSuperAdmin => "ADMIN_LIST_UPDATE_TYPE_ADD_SUPER_ADMIN",
             Self::RemoveSuperAdmin => "ADMIN_LIST_UPDATE_TYPE_REMOVE_SUPER_ADMIN",
+            Self::AddModerator => "ADMIN_LIST_UPDATE_TYPE_ADD_MODERATOR",
+            Self::RemoveModerator => "ADMIN_LIST_UPDATE_TYPE_REMOVE_MODERATOR",
         };
         serializer.serialize_str(variant)
     }
@@ -533,6 +535,8 @@ impl<'de> serde::Deserialize<'de> for AdminListUpdateType {
             "ADMIN_LIST_UPDATE_TYPE_REMOVE_ADMIN",
             "ADMIN_LIST_UPDATE_TYPE_ADD_SUPER_ADMIN",
             "ADMIN_LIST_UPDATE_TYPE_REMOVE_SUPER_ADMIN",
+            "ADMIN_LIST_UPDATE_TYPE_ADD_MODERATOR",
+            "ADMIN_LIST_UPDATE_TYPE_REMOVE_MODERATOR",
         ];
 
         struct GeneratedVisitor;
@@ -578,6 +582,8 @@ impl<'de> serde::Deserialize<'de> for AdminListUpdateType {
                     "ADMIN_LIST_UPDATE_TYPE_REMOVE_ADMIN" => Ok(AdminListUpdateType::RemoveAdmin),
                     "ADMIN_LIST_UPDATE_TYPE_ADD_SUPER_ADMIN" => Ok(AdminListUpdateType::AddSuperAdmin),
                     "ADMIN_LIST_UPDATE_TYPE_REMOVE_SUPER_ADMIN" => Ok(AdminListUpdateType::RemoveSuperAdmin),
+                    "ADMIN_LIST_UPDATE_TYPE_ADD_MODERATOR" => Ok(AdminListUpdateType::AddModerator),
+                    "ADMIN_LIST_UPDATE_TYPE_REMOVE_MODERATOR" => Ok(AdminListUpdateType::RemoveModerator),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -1330,6 +1336,7 @@ impl serde::Serialize for PermissionPolicyOption {
             Self::Deny => "PERMISSION_POLICY_OPTION_DENY",
             Self::AdminOnly => "PERMISSION_POLICY_OPTION_ADMIN_ONLY",
             Self::SuperAdminOnly => "PERMISSION_POLICY_OPTION_SUPER_ADMIN_ONLY",
+            Self::ModeratorOrAbove => "PERMISSION_POLICY_OPTION_MODERATOR_OR_ABOVE",
         };
         serializer.serialize_str(variant)
     }
@@ -1346,6 +1353,7 @@ impl<'de> serde::Deserialize<'de> for PermissionPolicyOption {
             "PERMISSION_POLICY_OPTION_DENY",
             "PERMISSION_POLICY_OPTION_ADMIN_ONLY",
             "PERMISSION_POLICY_OPTION_SUPER_ADMIN_ONLY",
+            "PERMISSION_POLICY_OPTION_MODERATOR_OR_ABOVE",
         ];
 
         struct GeneratedVisitor;
@@ -1391,6 +1399,7 @@ impl<'de> serde::Deserialize<'de> for PermissionPolicyOption {
                     "PERMISSION_POLICY_OPTION_DENY" => Ok(PermissionPolicyOption::Deny),
                     "PERMISSION_POLICY_OPTION_ADMIN_ONLY" => Ok(PermissionPolicyOption::AdminOnly),
                     "PERMISSION_POLICY_OPTION_SUPER_ADMIN_ONLY" => Ok(PermissionPolicyOption::SuperAdminOnly),
+                    "PERMISSION_POLICY_OPTION_MODERATOR_OR_ABOVE" => Ok(PermissionPolicyOption::ModeratorOrAbove),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -2379,6 +2388,150 @@ impl<'de> serde::Deserialize<'de> for propose_member_update_data::V1 {
         deserializer.deserialize_struct("xmtp.mls.database.ProposeMemberUpdateData.V1", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for PublishScheduledMessage {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.group_id.is_empty() {
+            len += 1;
+        }
+        if !self.message_id.is_empty() {
+            len += 1;
+        }
+        if self.publish_at_ns != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.mls.database.PublishScheduledMessage", len)?;
+        if !self.group_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("group_id", pbjson::private::base64::encode(&self.group_id).as_str())?;
+        }
+        if !self.message_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("message_id", pbjson::private::base64::encode(&self.message_id).as_str())?;
+        }
+        if self.publish_at_ns != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("publish_at_ns", ToString::to_string(&self.publish_at_ns).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PublishScheduledMessage {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "group_id",
+            "groupId",
+            "message_id",
+            "messageId",
+            "publish_at_ns",
+            "publishAtNs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GroupId,
+            MessageId,
+            PublishAtNs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "groupId" | "group_id" => Ok(GeneratedField::GroupId),
+                            "messageId" | "message_id" => Ok(GeneratedField::MessageId),
+                            "publishAtNs" | "publish_at_ns" => Ok(GeneratedField::PublishAtNs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PublishScheduledMessage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.mls.database.PublishScheduledMessage")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PublishScheduledMessage, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut group_id__ = None;
+                let mut message_id__ = None;
+                let mut publish_at_ns__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GroupId => {
+                            if group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("groupId"));
+                            }
+                            group_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MessageId => {
+                            if message_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("messageId"));
+                            }
+                            message_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PublishAtNs => {
+                            if publish_at_ns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publishAtNs"));
+                            }
+                            publish_at_ns__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PublishScheduledMessage {
+                    group_id: group_id__.unwrap_or_default(),
+                    message_id: message_id__.unwrap_or_default(),
+                    publish_at_ns: publish_at_ns__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.mls.database.PublishScheduledMessage", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for PullInDeadline {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3286,6 +3439,9 @@ impl serde::Serialize for Task {
                 task::Task::KpLiveness(v) => {
                     struct_ser.serialize_field("kp_liveness", v)?;
                 }
+                task::Task::PublishScheduledMessage(v) => {
+                    struct_ser.serialize_field("publish_scheduled_message", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -3314,6 +3470,8 @@ impl<'de> serde::Deserialize<'de> for Task {
             "addMissingInstallations",
             "kp_liveness",
             "kpLiveness",
+            "publish_scheduled_message",
+            "publishScheduledMessage",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3326,6 +3484,7 @@ impl<'de> serde::Deserialize<'de> for Task {
             KpDeletion,
             AddMissingInstallations,
             KpLiveness,
+            PublishScheduledMessage,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3356,6 +3515,7 @@ impl<'de> serde::Deserialize<'de> for Task {
                             "kpDeletion" | "kp_deletion" => Ok(GeneratedField::KpDeletion),
                             "addMissingInstallations" | "add_missing_installations" => Ok(GeneratedField::AddMissingInstallations),
                             "kpLiveness" | "kp_liveness" => Ok(GeneratedField::KpLiveness),
+                            "publishScheduledMessage" | "publish_scheduled_message" => Ok(GeneratedField::PublishScheduledMessage),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3432,6 +3592,13 @@ impl<'de> serde::Deserialize<'de> for Task {
                                 return Err(serde::de::Error::duplicate_field("kpLiveness"));
                             }
                             task__ = map_.next_value::<::std::option::Option<_>>()?.map(task::Task::KpLiveness)
+;
+                        }
+                        GeneratedField::PublishScheduledMessage => {
+                            if task__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publishScheduledMessage"));
+                            }
+                            task__ = map_.next_value::<::std::option::Option<_>>()?.map(task::Task::PublishScheduledMessage)
 ;
                         }
                         GeneratedField::__SkipField__ => {
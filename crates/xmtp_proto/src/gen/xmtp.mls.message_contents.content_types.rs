@@ -107,6 +107,49 @@ impl ::prost::Name for RemoteAttachmentInfo {
         "/xmtp.mls.message_contents.content_types.RemoteAttachmentInfo".into()
     }
 }
+/// Poll message type
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Poll {
+    /// The question being asked
+    #[prost(string, tag = "1")]
+    pub question: ::prost::alloc::string::String,
+    /// The options voters can choose from
+    #[prost(string, repeated, tag = "2")]
+    pub options: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Whether a single vote may select more than one option
+    #[prost(bool, tag = "3")]
+    pub allows_multiple_choices: bool,
+}
+impl ::prost::Name for Poll {
+    const NAME: &'static str = "Poll";
+    const PACKAGE: &'static str = "xmtp.mls.message_contents.content_types";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.mls.message_contents.content_types.Poll".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.mls.message_contents.content_types.Poll".into()
+    }
+}
+/// PollVote message type
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PollVote {
+    /// ID of the poll message being voted on
+    #[prost(string, tag = "1")]
+    pub poll_message_id: ::prost::alloc::string::String,
+    /// Indexes of the selected options. Replaces any earlier vote from the same sender
+    #[prost(uint32, repeated, tag = "2")]
+    pub option_indexes: ::prost::alloc::vec::Vec<u32>,
+}
+impl ::prost::Name for PollVote {
+    const NAME: &'static str = "PollVote";
+    const PACKAGE: &'static str = "xmtp.mls.message_contents.content_types";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.mls.message_contents.content_types.PollVote".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.mls.message_contents.content_types.PollVote".into()
+    }
+}
 /// Reaction message type
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ReactionV2 {
@@ -568,6 +568,253 @@ impl<'de> serde::Deserialize<'de> for MultiRemoteAttachment {
         deserializer.deserialize_struct("xmtp.mls.message_contents.content_types.MultiRemoteAttachment", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Poll {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.question.is_empty() {
+            len += 1;
+        }
+        if !self.options.is_empty() {
+            len += 1;
+        }
+        if self.allows_multiple_choices {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.mls.message_contents.content_types.Poll", len)?;
+        if !self.question.is_empty() {
+            struct_ser.serialize_field("question", &self.question)?;
+        }
+        if !self.options.is_empty() {
+            struct_ser.serialize_field("options", &self.options)?;
+        }
+        if self.allows_multiple_choices {
+            struct_ser.serialize_field("allows_multiple_choices", &self.allows_multiple_choices)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Poll {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "question",
+            "options",
+            "allows_multiple_choices",
+            "allowsMultipleChoices",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Question,
+            Options,
+            AllowsMultipleChoices,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "question" => Ok(GeneratedField::Question),
+                            "options" => Ok(GeneratedField::Options),
+                            "allowsMultipleChoices" | "allows_multiple_choices" => Ok(GeneratedField::AllowsMultipleChoices),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Poll;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.mls.message_contents.content_types.Poll")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Poll, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut question__ = None;
+                let mut options__ = None;
+                let mut allows_multiple_choices__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Question => {
+                            if question__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("question"));
+                            }
+                            question__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Options => {
+                            if options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("options"));
+                            }
+                            options__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AllowsMultipleChoices => {
+                            if allows_multiple_choices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowsMultipleChoices"));
+                            }
+                            allows_multiple_choices__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Poll {
+                    question: question__.unwrap_or_default(),
+                    options: options__.unwrap_or_default(),
+                    allows_multiple_choices: allows_multiple_choices__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.mls.message_contents.content_types.Poll", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PollVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.poll_message_id.is_empty() {
+            len += 1;
+        }
+        if !self.option_indexes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.mls.message_contents.content_types.PollVote", len)?;
+        if !self.poll_message_id.is_empty() {
+            struct_ser.serialize_field("poll_message_id", &self.poll_message_id)?;
+        }
+        if !self.option_indexes.is_empty() {
+            struct_ser.serialize_field("option_indexes", &self.option_indexes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PollVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "poll_message_id",
+            "pollMessageId",
+            "option_indexes",
+            "optionIndexes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PollMessageId,
+            OptionIndexes,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pollMessageId" | "poll_message_id" => Ok(GeneratedField::PollMessageId),
+                            "optionIndexes" | "option_indexes" => Ok(GeneratedField::OptionIndexes),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PollVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.mls.message_contents.content_types.PollVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PollVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut poll_message_id__ = None;
+                let mut option_indexes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PollMessageId => {
+                            if poll_message_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pollMessageId"));
+                            }
+                            poll_message_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OptionIndexes => {
+                            if option_indexes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("optionIndexes"));
+                            }
+                            option_indexes__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PollVote {
+                    poll_message_id: poll_message_id__.unwrap_or_default(),
+                    option_indexes: option_indexes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.mls.message_contents.content_types.PollVote", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ReactionAction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -323,7 +323,7 @@ impl ::prost::Name for PolicySet {
 /// A policy that governs adding/removing members or installations
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MembershipPolicy {
-    #[prost(oneof = "membership_policy::Kind", tags = "1, 2, 3")]
+    #[prost(oneof = "membership_policy::Kind", tags = "1, 2, 3, 4, 5")]
     pub kind: ::core::option::Option<membership_policy::Kind>,
 }
 /// Nested message and enum types in `MembershipPolicy`.
@@ -379,6 +379,7 @@ pub mod membership_policy {
         Deny = 2,
         AllowIfAdminOrSuperAdmin = 3,
         AllowIfSuperAdmin = 4,
+        AllowIfModeratorOrAbove = 5,
     }
     impl BasePolicy {
         /// String value of the enum field names used in the ProtoBuf definition.
@@ -394,6 +395,9 @@ pub mod membership_policy {
                     "BASE_POLICY_ALLOW_IF_ADMIN_OR_SUPER_ADMIN"
                 }
                 Self::AllowIfSuperAdmin => "BASE_POLICY_ALLOW_IF_SUPER_ADMIN",
+                Self::AllowIfModeratorOrAbove => {
+                    "BASE_POLICY_ALLOW_IF_MODERATOR_OR_ABOVE"
+                }
             }
         }
         /// Creates an enum from field names used in the ProtoBuf definition.
@@ -406,6 +410,9 @@ pub mod membership_policy {
                     Some(Self::AllowIfAdminOrSuperAdmin)
                 }
                 "BASE_POLICY_ALLOW_IF_SUPER_ADMIN" => Some(Self::AllowIfSuperAdmin),
+                "BASE_POLICY_ALLOW_IF_MODERATOR_OR_ABOVE" => {
+                    Some(Self::AllowIfModeratorOrAbove)
+                }
                 _ => None,
             }
         }
@@ -418,6 +425,10 @@ pub mod membership_policy {
         AndCondition(AndCondition),
         #[prost(message, tag = "3")]
         AnyCondition(AnyCondition),
+        #[prost(message, tag = "4")]
+        InboxAllowlist(super::InboxAllowlistPolicy),
+        #[prost(message, tag = "5")]
+        IdentifierRequirement(super::IdentifierRequirementPolicy),
     }
 }
 impl ::prost::Name for MembershipPolicy {
@@ -430,10 +441,50 @@ impl ::prost::Name for MembershipPolicy {
         "/xmtp.mls.message_contents.MembershipPolicy".into()
     }
 }
+/// Admits only inboxes listed in an inbox id set component of the group's
+/// AppData dictionary
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct InboxAllowlistPolicy {
+    #[prost(uint32, tag = "1")]
+    pub component_id: u32,
+}
+impl ::prost::Name for InboxAllowlistPolicy {
+    const NAME: &'static str = "InboxAllowlistPolicy";
+    const PACKAGE: &'static str = "xmtp.mls.message_contents";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.mls.message_contents.InboxAllowlistPolicy".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.mls.message_contents.InboxAllowlistPolicy".into()
+    }
+}
+/// Admits only inboxes associated with an identifier of the given kind
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdentifierRequirementPolicy {
+    #[prost(
+        enumeration = "super::super::identity::associations::IdentifierKind",
+        tag = "1"
+    )]
+    pub kind: i32,
+    /// When set, the identifier must belong to this domain, e.g. the relying
+    /// party of a passkey
+    #[prost(string, optional, tag = "2")]
+    pub domain: ::core::option::Option<::prost::alloc::string::String>,
+}
+impl ::prost::Name for IdentifierRequirementPolicy {
+    const NAME: &'static str = "IdentifierRequirementPolicy";
+    const PACKAGE: &'static str = "xmtp.mls.message_contents";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.mls.message_contents.IdentifierRequirementPolicy".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.mls.message_contents.IdentifierRequirementPolicy".into()
+    }
+}
 /// A policy that governs updating metadata
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MetadataPolicy {
-    #[prost(oneof = "metadata_policy::Kind", tags = "1, 2, 3")]
+    #[prost(oneof = "metadata_policy::Kind", tags = "1, 2, 3, 4, 5")]
     pub kind: ::core::option::Option<metadata_policy::Kind>,
 }
 /// Nested message and enum types in `MetadataPolicy`.
@@ -489,6 +540,7 @@ pub mod metadata_policy {
         Deny = 2,
         AllowIfAdmin = 3,
         AllowIfSuperAdmin = 4,
+        AllowIfModerator = 5,
     }
     impl MetadataBasePolicy {
         /// String value of the enum field names used in the ProtoBuf definition.
@@ -502,6 +554,7 @@ pub mod metadata_policy {
                 Self::Deny => "METADATA_BASE_POLICY_DENY",
                 Self::AllowIfAdmin => "METADATA_BASE_POLICY_ALLOW_IF_ADMIN",
                 Self::AllowIfSuperAdmin => "METADATA_BASE_POLICY_ALLOW_IF_SUPER_ADMIN",
+                Self::AllowIfModerator => "METADATA_BASE_POLICY_ALLOW_IF_MODERATOR",
             }
         }
         /// Creates an enum from field names used in the ProtoBuf definition.
@@ -514,6 +567,7 @@ pub mod metadata_policy {
                 "METADATA_BASE_POLICY_ALLOW_IF_SUPER_ADMIN" => {
                     Some(Self::AllowIfSuperAdmin)
                 }
+                "METADATA_BASE_POLICY_ALLOW_IF_MODERATOR" => Some(Self::AllowIfModerator),
                 _ => None,
             }
         }
@@ -526,6 +580,12 @@ pub mod metadata_policy {
         AndCondition(AndCondition),
         #[prost(message, tag = "3")]
         AnyCondition(AnyCondition),
+        /// Only valid on the GROUP_MEMBERSHIP component
+        #[prost(message, tag = "4")]
+        InboxAllowlist(super::InboxAllowlistPolicy),
+        /// Only valid on the GROUP_MEMBERSHIP component
+        #[prost(message, tag = "5")]
+        IdentifierRequirement(super::IdentifierRequirementPolicy),
     }
 }
 impl ::prost::Name for MetadataPolicy {
@@ -835,6 +895,13 @@ pub mod plaintext_envelope {
         /// produce different hashes. May be the sender timestamp.
         #[prost(string, tag = "2")]
         pub idempotency_key: ::prost::alloc::string::String,
+        /// Receivers delete the message this long after it was sent, in addition
+        /// to any disappearing messages settings of the group
+        #[prost(int64, optional, tag = "3")]
+        pub expire_after_ns: ::core::option::Option<i64>,
+        /// Receivers delete the message once it has been viewed
+        #[prost(bool, tag = "4")]
+        pub view_once: bool,
     }
     impl ::prost::Name for V1 {
         const NAME: &'static str = "V1";
@@ -853,7 +920,7 @@ pub mod plaintext_envelope {
         /// produce different hashes. May be the sender timestamp.
         #[prost(string, tag = "1")]
         pub idempotency_key: ::prost::alloc::string::String,
-        #[prost(oneof = "v2::MessageType", tags = "2, 3, 4, 5")]
+        #[prost(oneof = "v2::MessageType", tags = "2, 3, 4, 5, 6")]
         pub message_type: ::core::option::Option<v2::MessageType>,
     }
     /// Nested message and enum types in `V2`.
@@ -878,6 +945,10 @@ pub mod plaintext_envelope {
             UserPreferenceUpdate(
                 super::super::super::super::device_sync::content::V1UserPreferenceUpdate,
             ),
+            /// A short-lived signal, such as a typing indicator, that is only
+            /// delivered to live streams and never persisted
+            #[prost(message, tag = "6")]
+            Ephemeral(super::super::EphemeralContent),
         }
     }
     impl ::prost::Name for V2 {
@@ -910,6 +981,27 @@ impl ::prost::Name for PlaintextEnvelope {
         "/xmtp.mls.message_contents.PlaintextEnvelope".into()
     }
 }
+/// A short-lived signal sent to the group, such as a typing indicator.
+/// Receivers deliver it to live streams only, and drop it once expired.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EphemeralContent {
+    /// Expected to be EncodedContent
+    #[prost(bytes = "vec", tag = "1")]
+    pub content: ::prost::alloc::vec::Vec<u8>,
+    /// Time in nanoseconds after which receivers should drop the signal
+    #[prost(int64, tag = "2")]
+    pub expires_at_ns: i64,
+}
+impl ::prost::Name for EphemeralContent {
+    const NAME: &'static str = "EphemeralContent";
+    const PACKAGE: &'static str = "xmtp.mls.message_contents";
+    fn full_name() -> ::prost::alloc::string::String {
+        "xmtp.mls.message_contents.EphemeralContent".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/xmtp.mls.message_contents.EphemeralContent".into()
+    }
+}
 /// Recognized compression algorithms
 /// protolint:disable ENUM_FIELD_NAMES_ZERO_VALUE_END_WITH
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -1476,6 +1568,10 @@ pub struct GroupMutableMetadataV1 {
     /// Only super_admin can add/remove other super_admin
     #[prost(message, optional, tag = "3")]
     pub super_admin_list: ::core::option::Option<Inboxes>,
+    /// Moderators can remove members and delete messages, but can not
+    /// change metadata or permissions
+    #[prost(message, optional, tag = "4")]
+    pub moderator_list: ::core::option::Option<Inboxes>,
 }
 impl ::prost::Name for GroupMutableMetadataV1 {
     const NAME: &'static str = "GroupMutableMetadataV1";
@@ -1443,6 +1443,127 @@ impl<'de> serde::Deserialize<'de> for EncryptedGroupInfoBlobV1 {
         deserializer.deserialize_struct("xmtp.mls.message_contents.EncryptedGroupInfoBlobV1", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for EphemeralContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.content.is_empty() {
+            len += 1;
+        }
+        if self.expires_at_ns != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.mls.message_contents.EphemeralContent", len)?;
+        if !self.content.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("content", pbjson::private::base64::encode(&self.content).as_str())?;
+        }
+        if self.expires_at_ns != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("expires_at_ns", ToString::to_string(&self.expires_at_ns).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EphemeralContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "content",
+            "expires_at_ns",
+            "expiresAtNs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Content,
+            ExpiresAtNs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "content" => Ok(GeneratedField::Content),
+                            "expiresAtNs" | "expires_at_ns" => Ok(GeneratedField::ExpiresAtNs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EphemeralContent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.mls.message_contents.EphemeralContent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralContent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut content__ = None;
+                let mut expires_at_ns__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Content => {
+                            if content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("content"));
+                            }
+                            content__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExpiresAtNs => {
+                            if expires_at_ns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAtNs"));
+                            }
+                            expires_at_ns__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EphemeralContent {
+                    content: content__.unwrap_or_default(),
+                    expires_at_ns: expires_at_ns__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.mls.message_contents.EphemeralContent", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ExternalCommitPolicyEntry {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2650,6 +2771,9 @@ impl serde::Serialize for GroupMutableMetadataV1 {
         if self.super_admin_list.is_some() {
             len += 1;
         }
+        if self.moderator_list.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("xmtp.mls.message_contents.GroupMutableMetadataV1", len)?;
         if !self.attributes.is_empty() {
             struct_ser.serialize_field("attributes", &self.attributes)?;
@@ -2660,6 +2784,9 @@ impl serde::Serialize for GroupMutableMetadataV1 {
         if let Some(v) = self.super_admin_list.as_ref() {
             struct_ser.serialize_field("super_admin_list", v)?;
         }
+        if let Some(v) = self.moderator_list.as_ref() {
+            struct_ser.serialize_field("moderator_list", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2675,6 +2802,8 @@ impl<'de> serde::Deserialize<'de> for GroupMutableMetadataV1 {
             "adminList",
             "super_admin_list",
             "superAdminList",
+            "moderator_list",
+            "moderatorList",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2682,6 +2811,7 @@ impl<'de> serde::Deserialize<'de> for GroupMutableMetadataV1 {
             Attributes,
             AdminList,
             SuperAdminList,
+            ModeratorList,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2707,6 +2837,7 @@ impl<'de> serde::Deserialize<'de> for GroupMutableMetadataV1 {
                             "attributes" => Ok(GeneratedField::Attributes),
                             "adminList" | "admin_list" => Ok(GeneratedField::AdminList),
                             "superAdminList" | "super_admin_list" => Ok(GeneratedField::SuperAdminList),
+                            "moderatorList" | "moderator_list" => Ok(GeneratedField::ModeratorList),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2729,6 +2860,7 @@ impl<'de> serde::Deserialize<'de> for GroupMutableMetadataV1 {
                 let mut attributes__ = None;
                 let mut admin_list__ = None;
                 let mut super_admin_list__ = None;
+                let mut moderator_list__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Attributes => {
@@ -2751,6 +2883,12 @@ impl<'de> serde::Deserialize<'de> for GroupMutableMetadataV1 {
                             }
                             super_admin_list__ = map_.next_value()?;
                         }
+                        GeneratedField::ModeratorList => {
+                            if moderator_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("moderatorList"));
+                            }
+                            moderator_list__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2760,6 +2898,7 @@ impl<'de> serde::Deserialize<'de> for GroupMutableMetadataV1 {
                     attributes: attributes__.unwrap_or_default(),
                     admin_list: admin_list__,
                     super_admin_list: super_admin_list__,
+                    moderator_list: moderator_list__,
                 })
             }
         }
@@ -3329,6 +3468,120 @@ impl<'de> serde::Deserialize<'de> for group_updated::MetadataFieldChange {
         deserializer.deserialize_struct("xmtp.mls.message_contents.GroupUpdated.MetadataFieldChange", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for IdentifierRequirementPolicy {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.kind != 0 {
+            len += 1;
+        }
+        if self.domain.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.mls.message_contents.IdentifierRequirementPolicy", len)?;
+        if self.kind != 0 {
+            let v = super::super::identity::associations::IdentifierKind::try_from(self.kind)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.kind)))?;
+            struct_ser.serialize_field("kind", &v)?;
+        }
+        if let Some(v) = self.domain.as_ref() {
+            struct_ser.serialize_field("domain", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IdentifierRequirementPolicy {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "kind",
+            "domain",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Kind,
+            Domain,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "kind" => Ok(GeneratedField::Kind),
+                            "domain" => Ok(GeneratedField::Domain),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IdentifierRequirementPolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.mls.message_contents.IdentifierRequirementPolicy")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IdentifierRequirementPolicy, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut kind__ = None;
+                let mut domain__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Kind => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kind"));
+                            }
+                            kind__ = Some(map_.next_value::<super::super::identity::associations::IdentifierKind>()? as i32);
+                        }
+                        GeneratedField::Domain => {
+                            if domain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("domain"));
+                            }
+                            domain__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IdentifierRequirementPolicy {
+                    kind: kind__.unwrap_or_default(),
+                    domain: domain__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.mls.message_contents.IdentifierRequirementPolicy", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Inbox {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3425,6 +3678,104 @@ impl<'de> serde::Deserialize<'de> for Inbox {
         deserializer.deserialize_struct("xmtp.mls.message_contents.Inbox", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for InboxAllowlistPolicy {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.component_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("xmtp.mls.message_contents.InboxAllowlistPolicy", len)?;
+        if self.component_id != 0 {
+            struct_ser.serialize_field("component_id", &self.component_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InboxAllowlistPolicy {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "component_id",
+            "componentId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ComponentId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "componentId" | "component_id" => Ok(GeneratedField::ComponentId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InboxAllowlistPolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct xmtp.mls.message_contents.InboxAllowlistPolicy")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InboxAllowlistPolicy, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut component_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ComponentId => {
+                            if component_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("componentId"));
+                            }
+                            component_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(InboxAllowlistPolicy {
+                    component_id: component_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("xmtp.mls.message_contents.InboxAllowlistPolicy", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Inboxes {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3681,6 +4032,12 @@ impl serde::Serialize for MembershipPolicy {
                 membership_policy::Kind::AnyCondition(v) => {
                     struct_ser.serialize_field("any_condition", v)?;
                 }
+                membership_policy::Kind::InboxAllowlist(v) => {
+                    struct_ser.serialize_field("inbox_allowlist", v)?;
+                }
+                membership_policy::Kind::IdentifierRequirement(v) => {
+                    struct_ser.serialize_field("identifier_requirement", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -3698,6 +4055,10 @@ impl<'de> serde::Deserialize<'de> for MembershipPolicy {
             "andCondition",
             "any_condition",
             "anyCondition",
+            "inbox_allowlist",
+            "inboxAllowlist",
+            "identifier_requirement",
+            "identifierRequirement",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3705,6 +4066,8 @@ impl<'de> serde::Deserialize<'de> for MembershipPolicy {
             Base,
             AndCondition,
             AnyCondition,
+            InboxAllowlist,
+            IdentifierRequirement,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3730,6 +4093,8 @@ impl<'de> serde::Deserialize<'de> for MembershipPolicy {
                             "base" => Ok(GeneratedField::Base),
                             "andCondition" | "and_condition" => Ok(GeneratedField::AndCondition),
                             "anyCondition" | "any_condition" => Ok(GeneratedField::AnyCondition),
+                            "inboxAllowlist" | "inbox_allowlist" => Ok(GeneratedField::InboxAllowlist),
+                            "identifierRequirement" | "identifier_requirement" => Ok(GeneratedField::IdentifierRequirement),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3770,6 +4135,20 @@ impl<'de> serde::Deserialize<'de> for MembershipPolicy {
                                 return Err(serde::de::Error::duplicate_field("anyCondition"));
                             }
                             kind__ = map_.next_value::<::std::option::Option<_>>()?.map(membership_policy::Kind::AnyCondition)
+;
+                        }
+                        GeneratedField::InboxAllowlist => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inboxAllowlist"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(membership_policy::Kind::InboxAllowlist)
+;
+                        }
+                        GeneratedField::IdentifierRequirement => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identifierRequirement"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(membership_policy::Kind::IdentifierRequirement)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -3987,6 +4366,7 @@ impl serde::Serialize for membership_policy::BasePolicy {
             Self::Deny => "BASE_POLICY_DENY",
             Self::AllowIfAdminOrSuperAdmin => "BASE_POLICY_ALLOW_IF_ADMIN_OR_SUPER_ADMIN",
             Self::AllowIfSuperAdmin => "BASE_POLICY_ALLOW_IF_SUPER_ADMIN",
+            Self::AllowIfModeratorOrAbove => "BASE_POLICY_ALLOW_IF_MODERATOR_OR_ABOVE",
         };
         serializer.serialize_str(variant)
     }
@@ -4003,6 +4383,7 @@ impl<'de> serde::Deserialize<'de> for membership_policy::BasePolicy {
             "BASE_POLICY_DENY",
             "BASE_POLICY_ALLOW_IF_ADMIN_OR_SUPER_ADMIN",
             "BASE_POLICY_ALLOW_IF_SUPER_ADMIN",
+            "BASE_POLICY_ALLOW_IF_MODERATOR_OR_ABOVE",
         ];
 
         struct GeneratedVisitor;
@@ -4048,6 +4429,7 @@ impl<'de> serde::Deserialize<'de> for membership_policy::BasePolicy {
                     "BASE_POLICY_DENY" => Ok(membership_policy::BasePolicy::Deny),
                     "BASE_POLICY_ALLOW_IF_ADMIN_OR_SUPER_ADMIN" => Ok(membership_policy::BasePolicy::AllowIfAdminOrSuperAdmin),
                     "BASE_POLICY_ALLOW_IF_SUPER_ADMIN" => Ok(membership_policy::BasePolicy::AllowIfSuperAdmin),
+                    "BASE_POLICY_ALLOW_IF_MODERATOR_OR_ABOVE" => Ok(membership_policy::BasePolicy::AllowIfModeratorOrAbove),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -4080,6 +4462,12 @@ impl serde::Serialize for MetadataPolicy {
                 metadata_policy::Kind::AnyCondition(v) => {
                     struct_ser.serialize_field("any_condition", v)?;
                 }
+                metadata_policy::Kind::InboxAllowlist(v) => {
+                    struct_ser.serialize_field("inbox_allowlist", v)?;
+                }
+                metadata_policy::Kind::IdentifierRequirement(v) => {
+                    struct_ser.serialize_field("identifier_requirement", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -4097,6 +4485,10 @@ impl<'de> serde::Deserialize<'de> for MetadataPolicy {
             "andCondition",
             "any_condition",
             "anyCondition",
+            "inbox_allowlist",
+            "inboxAllowlist",
+            "identifier_requirement",
+            "identifierRequirement",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4104,6 +4496,8 @@ impl<'de> serde::Deserialize<'de> for MetadataPolicy {
             Base,
             AndCondition,
             AnyCondition,
+            InboxAllowlist,
+            IdentifierRequirement,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4129,6 +4523,8 @@ impl<'de> serde::Deserialize<'de> for MetadataPolicy {
                             "base" => Ok(GeneratedField::Base),
                             "andCondition" | "and_condition" => Ok(GeneratedField::AndCondition),
                             "anyCondition" | "any_condition" => Ok(GeneratedField::AnyCondition),
+                            "inboxAllowlist" | "inbox_allowlist" => Ok(GeneratedField::InboxAllowlist),
+                            "identifierRequirement" | "identifier_requirement" => Ok(GeneratedField::IdentifierRequirement),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4169,6 +4565,20 @@ impl<'de> serde::Deserialize<'de> for MetadataPolicy {
                                 return Err(serde::de::Error::duplicate_field("anyCondition"));
                             }
                             kind__ = map_.next_value::<::std::option::Option<_>>()?.map(metadata_policy::Kind::AnyCondition)
+;
+                        }
+                        GeneratedField::InboxAllowlist => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inboxAllowlist"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(metadata_policy::Kind::InboxAllowlist)
+;
+                        }
+                        GeneratedField::IdentifierRequirement => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identifierRequirement"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(metadata_policy::Kind::IdentifierRequirement)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -4386,6 +4796,7 @@ impl serde::Serialize for metadata_policy::MetadataBasePolicy {
             Self::Deny => "METADATA_BASE_POLICY_DENY",
             Self::AllowIfAdmin => "METADATA_BASE_POLICY_ALLOW_IF_ADMIN",
             Self::AllowIfSuperAdmin => "METADATA_BASE_POLICY_ALLOW_IF_SUPER_ADMIN",
+            Self::AllowIfModerator => "METADATA_BASE_POLICY_ALLOW_IF_MODERATOR",
         };
         serializer.serialize_str(variant)
     }
@@ -4402,6 +4813,7 @@ impl<'de> serde::Deserialize<'de> for metadata_policy::MetadataBasePolicy {
             "METADATA_BASE_POLICY_DENY",
             "METADATA_BASE_POLICY_ALLOW_IF_ADMIN",
             "METADATA_BASE_POLICY_ALLOW_IF_SUPER_ADMIN",
+            "METADATA_BASE_POLICY_ALLOW_IF_MODERATOR",
         ];
 
         struct GeneratedVisitor;
@@ -4447,6 +4859,7 @@ impl<'de> serde::Deserialize<'de> for metadata_policy::MetadataBasePolicy {
                     "METADATA_BASE_POLICY_DENY" => Ok(metadata_policy::MetadataBasePolicy::Deny),
                     "METADATA_BASE_POLICY_ALLOW_IF_ADMIN" => Ok(metadata_policy::MetadataBasePolicy::AllowIfAdmin),
                     "METADATA_BASE_POLICY_ALLOW_IF_SUPER_ADMIN" => Ok(metadata_policy::MetadataBasePolicy::AllowIfSuperAdmin),
+                    "METADATA_BASE_POLICY_ALLOW_IF_MODERATOR" => Ok(metadata_policy::MetadataBasePolicy::AllowIfModerator),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -5286,6 +5699,12 @@ impl serde::Serialize for plaintext_envelope::V1 {
         if !self.idempotency_key.is_empty() {
             len += 1;
         }
+        if self.expire_after_ns.is_some() {
+            len += 1;
+        }
+        if self.view_once {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("xmtp.mls.message_contents.PlaintextEnvelope.V1", len)?;
         if !self.content.is_empty() {
             #[allow(clippy::needless_borrow)]
@@ -5295,6 +5714,14 @@ impl serde::Serialize for plaintext_envelope::V1 {
         if !self.idempotency_key.is_empty() {
             struct_ser.serialize_field("idempotency_key", &self.idempotency_key)?;
         }
+        if let Some(v) = self.expire_after_ns.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("expire_after_ns", ToString::to_string(&v).as_str())?;
+        }
+        if self.view_once {
+            struct_ser.serialize_field("view_once", &self.view_once)?;
+        }
         struct_ser.end()
     }
 }
@@ -5308,12 +5735,18 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V1 {
             "content",
             "idempotency_key",
             "idempotencyKey",
+            "expire_after_ns",
+            "expireAfterNs",
+            "view_once",
+            "viewOnce",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Content,
             IdempotencyKey,
+            ExpireAfterNs,
+            ViewOnce,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5338,6 +5771,8 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V1 {
                         match value {
                             "content" => Ok(GeneratedField::Content),
                             "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
+                            "expireAfterNs" | "expire_after_ns" => Ok(GeneratedField::ExpireAfterNs),
+                            "viewOnce" | "view_once" => Ok(GeneratedField::ViewOnce),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5359,6 +5794,8 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V1 {
             {
                 let mut content__ = None;
                 let mut idempotency_key__ = None;
+                let mut expire_after_ns__ = None;
+                let mut view_once__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Content => {
@@ -5375,6 +5812,20 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V1 {
                             }
                             idempotency_key__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::ExpireAfterNs => {
+                            if expire_after_ns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expireAfterNs"));
+                            }
+                            expire_after_ns__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::ViewOnce => {
+                            if view_once__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("viewOnce"));
+                            }
+                            view_once__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -5383,6 +5834,8 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V1 {
                 Ok(plaintext_envelope::V1 {
                     content: content__.unwrap_or_default(),
                     idempotency_key: idempotency_key__.unwrap_or_default(),
+                    expire_after_ns: expire_after_ns__,
+                    view_once: view_once__.unwrap_or_default(),
                 })
             }
         }
@@ -5423,6 +5876,9 @@ impl serde::Serialize for plaintext_envelope::V2 {
                 plaintext_envelope::v2::MessageType::UserPreferenceUpdate(v) => {
                     struct_ser.serialize_field("user_preference_update", v)?;
                 }
+                plaintext_envelope::v2::MessageType::Ephemeral(v) => {
+                    struct_ser.serialize_field("ephemeral", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -5444,6 +5900,7 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V2 {
             "deviceSyncReply",
             "user_preference_update",
             "userPreferenceUpdate",
+            "ephemeral",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5453,6 +5910,7 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V2 {
             DeviceSyncRequest,
             DeviceSyncReply,
             UserPreferenceUpdate,
+            Ephemeral,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5480,6 +5938,7 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V2 {
                             "deviceSyncRequest" | "device_sync_request" => Ok(GeneratedField::DeviceSyncRequest),
                             "deviceSyncReply" | "device_sync_reply" => Ok(GeneratedField::DeviceSyncReply),
                             "userPreferenceUpdate" | "user_preference_update" => Ok(GeneratedField::UserPreferenceUpdate),
+                            "ephemeral" => Ok(GeneratedField::Ephemeral),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5534,6 +5993,13 @@ impl<'de> serde::Deserialize<'de> for plaintext_envelope::V2 {
                                 return Err(serde::de::Error::duplicate_field("userPreferenceUpdate"));
                             }
                             message_type__ = map_.next_value::<::std::option::Option<_>>()?.map(plaintext_envelope::v2::MessageType::UserPreferenceUpdate)
+;
+                        }
+                        GeneratedField::Ephemeral => {
+                            if message_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ephemeral"));
+                            }
+                            message_type__ = map_.next_value::<::std::option::Option<_>>()?.map(plaintext_envelope::v2::MessageType::Ephemeral)
 ;
                         }
                         GeneratedField::__SkipField__ => {
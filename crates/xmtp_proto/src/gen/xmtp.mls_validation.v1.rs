@@ -267,6 +267,24 @@ pub struct GetAssociationStateRequest {
     pub new_updates: ::prost::alloc::vec::Vec<
         super::super::identity::associations::IdentityUpdate,
     >,
+    /// Only apply updates up to this point. Applies every update when unset
+    #[prost(oneof = "get_association_state_request::PointInTime", tags = "3, 4")]
+    pub point_in_time: ::core::option::Option<
+        get_association_state_request::PointInTime,
+    >,
+}
+/// Nested message and enum types in `GetAssociationStateRequest`.
+pub mod get_association_state_request {
+    /// Only apply updates up to this point. Applies every update when unset
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum PointInTime {
+        /// Apply only the first N updates of old_updates followed by new_updates
+        #[prost(uint64, tag = "3")]
+        UpdateCount(u64),
+        /// Apply only updates with a client_timestamp_ns at or before this value
+        #[prost(uint64, tag = "4")]
+        TimestampNs(u64),
+    }
 }
 impl ::prost::Name for GetAssociationStateRequest {
     const NAME: &'static str = "GetAssociationStateRequest";
@@ -12,6 +12,9 @@ impl serde::Serialize for GetAssociationStateRequest {
         if !self.new_updates.is_empty() {
             len += 1;
         }
+        if self.point_in_time.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("xmtp.mls_validation.v1.GetAssociationStateRequest", len)?;
         if !self.old_updates.is_empty() {
             struct_ser.serialize_field("old_updates", &self.old_updates)?;
@@ -19,6 +22,20 @@ impl serde::Serialize for GetAssociationStateRequest {
         if !self.new_updates.is_empty() {
             struct_ser.serialize_field("new_updates", &self.new_updates)?;
         }
+        if let Some(v) = self.point_in_time.as_ref() {
+            match v {
+                get_association_state_request::PointInTime::UpdateCount(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    #[allow(clippy::needless_borrows_for_generic_args)]
+                    struct_ser.serialize_field("update_count", ToString::to_string(&v).as_str())?;
+                }
+                get_association_state_request::PointInTime::TimestampNs(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    #[allow(clippy::needless_borrows_for_generic_args)]
+                    struct_ser.serialize_field("timestamp_ns", ToString::to_string(&v).as_str())?;
+                }
+            }
+        }
         struct_ser.end()
     }
 }
@@ -33,12 +50,18 @@ impl<'de> serde::Deserialize<'de> for GetAssociationStateRequest {
             "oldUpdates",
             "new_updates",
             "newUpdates",
+            "update_count",
+            "updateCount",
+            "timestamp_ns",
+            "timestampNs",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             OldUpdates,
             NewUpdates,
+            UpdateCount,
+            TimestampNs,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -63,6 +86,8 @@ impl<'de> serde::Deserialize<'de> for GetAssociationStateRequest {
                         match value {
                             "oldUpdates" | "old_updates" => Ok(GeneratedField::OldUpdates),
                             "newUpdates" | "new_updates" => Ok(GeneratedField::NewUpdates),
+                            "updateCount" | "update_count" => Ok(GeneratedField::UpdateCount),
+                            "timestampNs" | "timestamp_ns" => Ok(GeneratedField::TimestampNs),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -84,6 +109,7 @@ impl<'de> serde::Deserialize<'de> for GetAssociationStateRequest {
             {
                 let mut old_updates__ = None;
                 let mut new_updates__ = None;
+                let mut point_in_time__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::OldUpdates => {
@@ -98,6 +124,18 @@ impl<'de> serde::Deserialize<'de> for GetAssociationStateRequest {
                             }
                             new_updates__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::UpdateCount => {
+                            if point_in_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateCount"));
+                            }
+                            point_in_time__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| get_association_state_request::PointInTime::UpdateCount(x.0));
+                        }
+                        GeneratedField::TimestampNs => {
+                            if point_in_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestampNs"));
+                            }
+                            point_in_time__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| get_association_state_request::PointInTime::TimestampNs(x.0));
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -106,6 +144,7 @@ impl<'de> serde::Deserialize<'de> for GetAssociationStateRequest {
                 Ok(GetAssociationStateRequest {
                     old_updates: old_updates__.unwrap_or_default(),
                     new_updates: new_updates__.unwrap_or_default(),
+                    point_in_time: point_in_time__,
                 })
             }
         }
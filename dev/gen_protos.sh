@@ -10,7 +10,27 @@ BRANCH=${1:-main}
 REV=$(git ls-remote https://github.com/xmtp/proto "$BRANCH" | awk '{print $1}')
 WORKSPACE_MANIFEST="$(cargo locate-project --workspace --message-format=plain)"
 WORKSPACE_PATH="$(dirname "$WORKSPACE_MANIFEST")"
+PROTO_CRATE="$WORKSPACE_PATH"/crates/xmtp_proto
 
 export GEN_PROTOS=1
-echo "$REV" > "$WORKSPACE_PATH"/crates/xmtp_proto/proto_version
+echo "$REV" > "$PROTO_CRATE"/proto_version
 cargo build -p xmtp_proto --features grpc_server_impls
+
+# Definitions listed in pending_upstream were added to src/gen ahead of
+# xmtp/proto. Fail loudly if this revision does not have them yet, instead
+# of silently dropping them.
+MISSING=0
+while read -r FILE NEEDLE; do
+  case "$FILE" in '' | '#'*) continue ;; esac
+  if ! grep -qF -- "$NEEDLE" "$PROTO_CRATE/src/gen/$FILE"; then
+    echo "$FILE no longer contains \`$NEEDLE\`" >&2
+    MISSING=1
+  fi
+done < "$PROTO_CRATE"/pending_upstream
+
+if [ "$MISSING" -ne 0 ]; then
+  echo "xmtp/proto@$REV is missing definitions from crates/xmtp_proto/PENDING_UPSTREAM.md." >&2
+  echo "Land them upstream first, or restore the previous output with:" >&2
+  echo "  git checkout -- crates/xmtp_proto/proto_version crates/xmtp_proto/src/gen" >&2
+  exit 1
+fi